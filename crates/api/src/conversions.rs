@@ -178,6 +178,7 @@ fn finish_reason_to_string(reason: &FinishReason) -> String {
         FinishReason::Length => "length".to_string(),
         FinishReason::ContentFilter => "content_filter".to_string(),
         FinishReason::ToolCalls => "tool_calls".to_string(),
+        FinishReason::Other(reason) => reason.clone(),
     }
 }
 
@@ -190,7 +191,7 @@ impl From<&TokenUsage> for crate::models::CompletionUsage {
             }),
             completion_tokens: usage.completion_tokens,
             completion_tokens_details: Some(OutputTokensDetails {
-                reasoning_tokens: 0,
+                reasoning_tokens: usage.reasoning_tokens() as i64,
             }),
             total_tokens: usage.total_tokens,
         }
@@ -209,6 +210,12 @@ impl From<CompletionError> for crate::models::ErrorResponse {
                 "invalid_request_error".to_string(),
                 "model".to_string(),
             ),
+            CompletionError::ModelNotFound(msg) => {
+                ErrorResponse::with_param(msg, "model_not_found".to_string(), "model".to_string())
+            }
+            CompletionError::ModelNotAllowed(msg) => {
+                ErrorResponse::with_param(msg, "model_not_allowed".to_string(), "model".to_string())
+            }
             CompletionError::InvalidParams(msg) => {
                 ErrorResponse::new(msg, "invalid_request_error".to_string())
             }
@@ -220,6 +227,9 @@ impl From<CompletionError> for crate::models::ErrorResponse {
                 };
                 ErrorResponse::new(message, "rate_limit_exceeded".to_string())
             }
+            CompletionError::QuotaExceeded(msg) => {
+                ErrorResponse::new(msg, "quota_exceeded".to_string())
+            }
             CompletionError::ProviderError {
                 status_code,
                 message,
@@ -235,10 +245,30 @@ impl From<CompletionError> for crate::models::ErrorResponse {
             CompletionError::ServiceOverloaded(msg) => {
                 ErrorResponse::new(msg, "service_overloaded".to_string())
             }
+            CompletionError::DuplicateRequest(msg) => {
+                ErrorResponse::new(msg, "duplicate_request".to_string())
+            }
+            CompletionError::ModelWarming(msg) => {
+                ErrorResponse::new(msg, "model_warming".to_string())
+            }
             CompletionError::InternalError(msg) => ErrorResponse::new(
                 format!("Internal server error: {msg}"),
                 "internal_server_error".to_string(),
             ),
+            CompletionError::ProvidersFailed {
+                error,
+                provider_failures,
+            } => ErrorResponse::from(*error).with_details(crate::models::ErrorDetails {
+                provider_failures: provider_failures
+                    .into_iter()
+                    .map(|failure| crate::models::ProviderFailureDetail {
+                        attempt: failure.attempt,
+                        status_code: failure.status_code,
+                        category: failure.category,
+                    })
+                    .collect(),
+                detailed_error: None,
+            }),
         }
     }
 }
@@ -1188,4 +1218,42 @@ mod tests {
         assert_eq!(wire["return_hidden_states"], serde_json::json!(true));
         assert_eq!(wire["layers"], serde_json::json!([0, 5, 11]));
     }
+
+    #[test]
+    fn all_providers_failed_surfaces_structured_details() {
+        let error = CompletionError::ProvidersFailed {
+            error: Box::new(CompletionError::ServiceOverloaded(
+                "The service is temporarily overloaded.".to_string(),
+            )),
+            provider_failures: vec![
+                inference_providers::ProviderFailure {
+                    attempt: 1,
+                    status_code: Some(503),
+                    category: "http_5xx".to_string(),
+                },
+                inference_providers::ProviderFailure {
+                    attempt: 2,
+                    status_code: None,
+                    category: "timeout".to_string(),
+                },
+            ],
+        };
+
+        let body = serde_json::to_value(crate::models::ErrorResponse::from(error)).unwrap();
+        assert_eq!(body["error"]["type"], "service_overloaded");
+        assert_eq!(
+            body["error"]["details"]["provider_failures"],
+            serde_json::json!([
+                {"attempt": 1, "status_code": 503, "category": "http_5xx"},
+                {"attempt": 2, "category": "timeout"}
+            ])
+        );
+
+        // Errors from a single attempt carry no details
+        let body = serde_json::to_value(crate::models::ErrorResponse::from(
+            CompletionError::InvalidParams("bad".to_string()),
+        ))
+        .unwrap();
+        assert!(body["error"].get("details").is_none());
+    }
 }
@@ -17,10 +17,13 @@ use crate::{
     openapi::ApiDoc,
     routes::{
         api::{build_management_router, AppState},
-        attestation::{self, get_attestation_report, get_signature},
+        attestation::{
+            self, get_attestation_quote, get_attestation_report, get_signature,
+            verify_model_attestation, verify_signature,
+        },
         auth::{
             current_user, github_login, google_login, login_page, logout, oauth_callback,
-            StateStore,
+            oidc_login, StateStore,
         },
         billing::{get_billing_costs, BillingRouteState},
         completions::{
@@ -31,8 +34,11 @@ use crate::{
         feature_requests::{
             list_admin_feature_requests, submit_feature_request, FeatureRequestsRouteState,
         },
-        health::health_check,
-        models::{get_model_by_name, list_models, ModelsAppState},
+        health::{health_check, liveness, readiness, HealthState},
+        models::{
+            get_model_by_name, get_model_capabilities, get_model_pricing, list_models,
+            ModelsAppState,
+        },
         responses,
     },
 };
@@ -43,7 +49,7 @@ use axum::{
     extract::DefaultBodyLimit,
     middleware::{from_fn, from_fn_with_state, map_response, Next},
     response::Html,
-    routing::{get, post},
+    routing::{delete, get, post},
     Router,
 };
 use config::ApiConfig;
@@ -97,6 +103,7 @@ pub struct DomainServices {
     pub completion_service: Arc<services::CompletionServiceImpl>,
     pub models_service: Arc<services::models::ModelsServiceImpl>,
     pub mcp_manager: Arc<services::mcp::McpClientManager>,
+    pub mcp_service: Arc<dyn services::mcp::McpService>,
     pub inference_provider_pool: Arc<services::inference_provider_pool::InferenceProviderPool>,
     pub attestation_service: Arc<services::attestation::AttestationService>,
     pub organization_service:
@@ -110,6 +117,14 @@ pub struct DomainServices {
     pub web_search_provider: Arc<dyn services::responses::tools::WebSearchProviderTrait>,
     pub service_usage_service:
         Arc<dyn services::service_usage::ServiceUsageServiceTrait + Send + Sync>,
+    pub batch_service: Arc<dyn services::batches::BatchServiceTrait>,
+    /// Background worker for batches; started by the binary, driven
+    /// directly by tests.
+    pub batch_processor: Arc<services::batches::BatchProcessor>,
+    /// Flipped by the binary when shutdown begins; new requests then get 503.
+    pub shutdown_state: middleware::ShutdownState,
+    /// Per-API-key request limiter, shared by the routes and batch lines.
+    pub rate_limit_state: middleware::RateLimitState,
 }
 
 /// Initialize database connection and run migrations
@@ -276,10 +291,11 @@ pub fn create_oauth_manager(config: &ApiConfig) -> OAuthManager {
         .clone()
         .map(config::OAuthProviderConfig::from);
 
-    let manager = OAuthManager::new(github_config, google_config).unwrap_or_else(|_| {
-        tracing::error!("Failed to create OAuth manager");
-        std::process::exit(1);
-    });
+    let manager = OAuthManager::new(github_config, google_config, config.auth.oidc.clone())
+        .unwrap_or_else(|_| {
+            tracing::error!("Failed to create OAuth manager");
+            std::process::exit(1);
+        });
 
     if config.auth.github.is_some() {
         tracing::info!("GitHub OAuth configured");
@@ -287,6 +303,9 @@ pub fn create_oauth_manager(config: &ApiConfig) -> OAuthManager {
     if config.auth.google.is_some() {
         tracing::info!("Google OAuth configured");
     }
+    if config.auth.oidc.is_some() {
+        tracing::info!("OIDC configured");
+    }
 
     manager
 }
@@ -368,14 +387,20 @@ pub async fn init_domain_services_with_pool(
             config.ita.clone(),
         )
         .await
-        .unwrap(),
+        .unwrap()
+        .with_inference_id_namespace((&config.server).into()),
     );
 
     // Create models service
-    let models_service = Arc::new(services::models::ModelsServiceImpl::new(
-        inference_provider_pool.clone(),
-        models_repo.clone(),
-    ));
+    let models_service = Arc::new(
+        services::models::ModelsServiceImpl::new(
+            inference_provider_pool.clone(),
+            models_repo.clone(),
+        )
+        .with_organization_alias_repository(Arc::new(
+            database::repositories::OrganizationModelAliasRepository::new(database.pool().clone()),
+        )),
+    );
 
     // Prepare repositories for usage service (will be created after workspace service)
     let usage_repository = Arc::new(
@@ -390,6 +415,15 @@ pub async fn init_domain_services_with_pool(
 
     // Create MCP client manager
     let mcp_manager = Arc::new(services::mcp::McpClientManager::new());
+    let mcp_service = Arc::new(
+        services::mcp::McpServiceImpl::new(
+            Arc::new(database::repositories::McpConnectorRepository::new(
+                database.pool().clone(),
+            )),
+            mcp_manager.clone(),
+        )
+        .with_insecure_server_urls(config.mcp_connectors.allow_insecure_server_urls),
+    ) as Arc<dyn services::mcp::McpService>;
 
     // Create workspace service with API key management (needs organization_service)
     let workspace_repository = Arc::new(database::repositories::WorkspaceRepository::new(
@@ -401,36 +435,70 @@ pub async fn init_domain_services_with_pool(
     )) as Arc<dyn services::workspace::ApiKeyRepository>;
 
     let workspace_service = Arc::new(services::workspace::WorkspaceServiceImpl::new(
-        workspace_repository,
-        api_key_repository,
+        workspace_repository.clone(),
+        api_key_repository.clone(),
         organization_service.clone(),
     ))
         as Arc<dyn services::workspace::WorkspaceServiceTrait + Send + Sync>;
 
-    // Now create usage service with workspace_service
-    let usage_service = Arc::new(services::usage::UsageServiceImpl::new(
-        usage_repository as Arc<dyn services::usage::UsageRepository>,
-        models_repo.clone() as Arc<dyn services::usage::ModelRepository>,
-        limits_repository_for_usage as Arc<dyn services::usage::OrganizationLimitsRepository>,
-        workspace_service.clone(),
-        metrics_service.clone(),
-    )) as Arc<dyn services::usage::UsageServiceTrait + Send + Sync>;
+    // Now create usage service with workspace_service. Pricing is read from
+    // the database on every billed request, so admin price changes apply on
+    // all instances at once.
+    let usage_service = Arc::new(
+        services::usage::UsageServiceImpl::new(
+            usage_repository as Arc<dyn services::usage::UsageRepository>,
+            models_repo.clone() as Arc<dyn services::usage::ModelRepository>,
+            limits_repository_for_usage as Arc<dyn services::usage::OrganizationLimitsRepository>,
+            workspace_service.clone(),
+            metrics_service.clone(),
+        )
+        .with_currency_rates(services::usage::CurrencyRates::from_config(
+            &config.currency,
+        ))
+        .with_model_quota_repository(Arc::new(
+            database::repositories::ModelQuotaRepository::new(database.pool().clone()),
+        )),
+    ) as Arc<dyn services::usage::UsageServiceTrait + Send + Sync>;
 
     // Create organization limit repository for completion service rate limiting
-    let org_limit_repository = Arc::new(database::repositories::PgOrganizationRepository::new(
+    let org_repository = Arc::new(database::repositories::PgOrganizationRepository::new(
         database.pool().clone(),
-    ))
+    ));
+    let org_limit_repository = org_repository.clone()
         as Arc<dyn services::completions::ports::OrganizationConcurrentLimitRepository>;
+    let org_model_access_repository =
+        org_repository as Arc<dyn services::completions::ports::OrganizationModelAccessRepository>;
 
     // Create completion service with usage tracking (needs usage_service)
-    let completion_service = Arc::new(services::CompletionServiceImpl::new(
+    let mut completion_service = services::CompletionServiceImpl::new(
         inference_provider_pool.clone(),
         attestation_service.clone(),
         usage_service.clone(),
         metrics_service.clone(),
         models_repo.clone() as Arc<dyn services::models::ModelsRepository>,
         org_limit_repository,
-    ));
+        org_model_access_repository,
+    )
+    .with_stream_dedup_window(std::time::Duration::from_millis(
+        config.server.stream_dedup_window_ms,
+    ))
+    .with_end_user_rate_limit(config.server.end_user_requests_per_minute)
+    .with_json_schema_fallback(config.server.json_schema_fallback)
+    .with_inference_id_namespace((&config.server).into());
+    if config.audit_log.enabled {
+        tracing::info!(
+            store_bodies = config.audit_log.store_bodies,
+            "Completion audit log enabled"
+        );
+        completion_service =
+            completion_service.with_audit_logger(services::audit::AuditLogger::new(
+                Arc::new(database::repositories::PgCompletionAuditLogRepository::new(
+                    database.pool().clone(),
+                )),
+                config.audit_log.store_bodies,
+            ));
+    }
+    let completion_service = Arc::new(completion_service);
 
     let brave_search_provider =
         Arc::new(services::responses::tools::brave::BraveWebSearchProvider::new());
@@ -525,12 +593,43 @@ pub async fn init_domain_services_with_pool(
         config.staking_farm.clone(),
     ));
 
+    let batch_repository = Arc::new(database::PgBatchRepository::new(database.pool().clone()))
+        as Arc<dyn services::batches::BatchRepository>;
+    let batch_service = Arc::new(services::batches::BatchServiceImpl::new(
+        batch_repository.clone(),
+        files_service.clone(),
+    )) as Arc<dyn services::batches::BatchServiceTrait>;
+    // Batch lines share the per-key rate limiter with the synchronous routes.
+    let rate_limit_state = middleware::RateLimitState::default();
+    let batch_usage_state = middleware::UsageState {
+        usage_service: usage_service.clone(),
+        staking_farm_service: staking_farm_service.clone(),
+        usage_repository: Arc::new(database::repositories::OrganizationUsageRepository::new(
+            database.pool().clone(),
+        )),
+        api_key_repository: Arc::new(database::repositories::ApiKeyRepository::new(
+            database.pool().clone(),
+        )),
+    };
+    let batch_processor = Arc::new(services::batches::BatchProcessor::new(
+        batch_repository,
+        Arc::new(routes::batches::CompletionBatchExecutor::new(
+            completion_service.clone(),
+            workspace_repository,
+            api_key_repository,
+            batch_usage_state,
+            rate_limit_state.clone(),
+        )),
+        files_service.clone(),
+    ));
+
     DomainServices {
         conversation_service,
         response_service,
         completion_service,
         models_service,
         mcp_manager,
+        mcp_service,
         inference_provider_pool,
         attestation_service,
         organization_service,
@@ -542,6 +641,10 @@ pub async fn init_domain_services_with_pool(
         staking_farm_service,
         web_search_provider,
         service_usage_service,
+        batch_service,
+        batch_processor,
+        shutdown_state: middleware::ShutdownState::default(),
+        rate_limit_state,
     }
 }
 
@@ -902,11 +1005,15 @@ pub async fn init_inference_providers(
         Ok(models) if !models.is_empty() => {
             tracing::info!(count = models.len(), "Loading inference_url models");
             pool.load_inference_url_models(models, false).await;
+            pool.mark_discovery_initialized();
         }
         Ok(_) => {
             tracing::info!("No inference_url models found in database");
+            pool.mark_discovery_initialized();
         }
         Err(e) => {
+            // Until a refresh succeeds, unknown models are reported as warming
+            // (503) rather than not found.
             tracing::warn!(error = %e, "Failed to fetch inference_url models");
         }
     }
@@ -930,6 +1037,7 @@ pub async fn init_inference_providers(
     pool.clone()
         .start_refresh_task(models_source, refresh_interval)
         .await;
+    pool.clone().start_chat_pin_sweeper().await;
 
     // Chutes attested provider — hard-off by default (`ENABLE_CHUTES`). Each model
     // is served over a verified ML-KEM E2EE channel: every request attests the
@@ -1154,6 +1262,11 @@ pub fn build_app_with_config(
         organization_service: domain_services.organization_service.clone(),
         workspace_service: domain_services.workspace_service.clone(),
         mcp_manager: domain_services.mcp_manager.clone(),
+        auto_tool_service: Arc::new(services::auto_tools::AutoToolExecutionService::new(
+            domain_services.completion_service.clone(),
+            domain_services.mcp_service.clone(),
+            config.mcp_connectors.auto_tool_max_iterations,
+        )),
         completion_service: domain_services.completion_service.clone(),
         models_service: domain_services.models_service.clone(),
         auth_service: auth_components.auth_service.clone(),
@@ -1175,9 +1288,12 @@ pub fn build_app_with_config(
         analytics_service: analytics_service.clone(),
         staking_farm_service: domain_services.staking_farm_service.clone(),
         config: config.clone(),
+        page_size_limits: (&config.server).into(),
+        inference_id_namespace: (&config.server).into(),
         ohttp_gateway,
         ohttp_attestation,
         http_client: reqwest::Client::new(),
+        auth_state: auth_components.auth_state_middleware.clone(),
     };
 
     // Create usage state for middleware
@@ -1195,7 +1311,7 @@ pub fn build_app_with_config(
         api_key_repository,
     };
 
-    let rate_limit_state = middleware::RateLimitState::default();
+    let rate_limit_state = domain_services.rate_limit_state.clone();
 
     // Build individual route groups
     let auth_routes = build_auth_routes(
@@ -1204,11 +1320,16 @@ pub fn build_app_with_config(
         config.clone(),
     );
 
+    let body_limit_state = middleware::BodyLimitState {
+        max_bytes: config.server.max_inference_body_bytes,
+    };
+
     let completion_routes = build_completion_routes(
         app_state.clone(),
         &auth_components.auth_state_middleware,
         usage_state.clone(),
         rate_limit_state.clone(),
+        body_limit_state,
     );
 
     let gateway_routes = build_gateway_routes(
@@ -1226,7 +1347,15 @@ pub fn build_app_with_config(
         &auth_components.auth_state_middleware,
         usage_state.clone(),
         rate_limit_state.clone(),
+        body_limit_state,
     );
+    let batch_routes = build_batch_routes(
+        domain_services.batch_service.clone(),
+        &auth_components.auth_state_middleware,
+        usage_state.clone(),
+        rate_limit_state.clone(),
+    );
+
     let unsupported_openai_routes = build_unsupported_openai_routes(
         &auth_components.auth_state_middleware,
         rate_limit_state.clone(),
@@ -1243,6 +1372,7 @@ pub fn build_app_with_config(
     let conversation_routes = build_conversation_routes(
         domain_services.conversation_service,
         &auth_components.auth_state_middleware,
+        (&config.server).into(),
     );
 
     let management_routes = build_management_router(
@@ -1258,7 +1388,7 @@ pub fn build_app_with_config(
 
     let model_routes = build_model_routes(domain_services.models_service.clone());
 
-    let services_routes = build_services_routes(database.pool().clone());
+    let services_routes = build_services_routes(database.pool().clone(), (&config.server).into());
 
     let admin_routes = build_admin_routes(
         database.clone(),
@@ -1286,6 +1416,12 @@ pub fn build_app_with_config(
     let feature_request_routes = build_feature_request_routes(
         database.pool().clone(),
         &auth_components.auth_state_middleware,
+        (&config.server).into(),
+    );
+
+    let mcp_connector_routes = build_mcp_connector_routes(
+        domain_services.mcp_service.clone(),
+        &auth_components.auth_state_middleware,
     );
 
     let billing_routes = build_billing_routes(
@@ -1303,13 +1439,19 @@ pub fn build_app_with_config(
         let summary_service = Arc::new(services::reporting_usage::ReportingUsageService::new(
             summary_repository,
         ));
+        let guard_state = middleware::ReportingGuardState::from_config(&config.usage_reporting);
         build_reporting_usage_routes(
             domain_services.usage_service.clone(),
             domain_services.service_usage_service.clone(),
             summary_service,
-            middleware::ReportingGuardState::from_config(&config.usage_reporting),
+            guard_state.clone(),
             &auth_components.auth_state_middleware,
         )
+        .merge(build_usage_csv_routes(
+            app_state.clone(),
+            guard_state,
+            &auth_components.auth_state_middleware,
+        ))
     } else {
         tracing::info!("Programmatic usage reporting routes are disabled");
         Router::new()
@@ -1325,11 +1467,25 @@ pub fn build_app_with_config(
         .route("/health", get(health_check))
         .layer(cache_control_layer("public, max-age=5"));
 
+    // Orchestrator probes live at the root, uncached: /healthz only proves the
+    // process is serving, /readyz also checks the database and model pool.
+    let probe_routes = Router::new()
+        .route("/healthz", get(liveness))
+        .route("/readyz", get(readiness))
+        .with_state(HealthState {
+            database: database.clone(),
+            inference_provider_pool: domain_services.inference_provider_pool.clone(),
+        });
+
     // Create metrics state for HTTP metrics middleware
     let metrics_state = middleware::MetricsState {
         metrics_service: domain_services.metrics_service.clone(),
     };
 
+    let slow_request_state = middleware::SlowRequestState {
+        threshold: std::time::Duration::from_millis(config.server.slow_request_threshold_ms),
+    };
+
     // Create CORS layer
     let cors_config = config.cors.clone();
     let cors = CorsLayer::new()
@@ -1364,6 +1520,7 @@ pub fn build_app_with_config(
                 .nest("/auth", auth_routes)
                 .merge(completion_routes)
                 .merge(response_routes)
+                .merge(batch_routes)
                 .merge(unsupported_openai_routes)
                 .merge(conversation_routes)
                 .merge(management_routes)
@@ -1376,6 +1533,7 @@ pub fn build_app_with_config(
                 .merge(auth_vpc_routes)
                 .merge(files_routes)
                 .merge(feature_request_routes)
+                .merge(mcp_connector_routes)
                 .merge(billing_routes)
                 .merge(reporting_usage_routes)
                 .merge(gateway_routes)
@@ -1390,11 +1548,19 @@ pub fn build_app_with_config(
         .merge(openapi_routes)
         .merge(mcp_routes)
         .merge(ohttp_root_routes)
+        .merge(probe_routes)
         // Requests matching no route (or no method on a matched route) get a
         // stable generic JSON envelope instead of Axum's default empty-body
         // 404/405 (nearai/infra#192).
         .fallback(routes::unsupported::unknown_route)
         .method_not_allowed_fallback(routes::unsupported::method_not_allowed)
+        // Once shutdown begins, new requests are turned away with 503 before
+        // doing any work; requests already inside keep running. Inside cors
+        // and metrics so the 503 carries CORS headers and is counted.
+        .layer(from_fn_with_state(
+            domain_services.shutdown_state.clone(),
+            middleware::shutdown_middleware,
+        ))
         .layer(cors)
         // Add HTTP metrics middleware to track all requests
         .layer(from_fn_with_state(
@@ -1408,6 +1574,12 @@ pub fn build_app_with_config(
         // endpoints) sign the *request* body hash, not the HTTP response body,
         // so compression is safe for them as well.
         .layer(CompressionLayer::new())
+        // Inside request correlation so slow-request warnings carry the
+        // request_id span.
+        .layer(from_fn_with_state(
+            slow_request_state,
+            middleware::slow_request_middleware,
+        ))
         .layer(from_fn(middleware::request_correlation_middleware))
         // Outermost response pass: every client-facing 429 gets a
         // machine-readable Retry-After header (SDK backoff honors it).
@@ -1463,17 +1635,28 @@ pub fn build_auth_routes(
         config.clone(),
     );
 
+    let trusted_proxy_hops = config.server.trusted_proxy_hops;
     let near_auth_state: NearAuthState = (auth_components.near_auth_service.clone(), config);
 
-    // Create a sub-router for the NEAR route with its own state
+    // Create a sub-router for the NEAR routes with their own state
     let near_router = Router::new()
         .route("/near", post(routes::auth::near_login))
+        .route(
+            "/near/nonce",
+            get(routes::auth::near_nonce).layer(from_fn_with_state(
+                middleware::RateLimitState::new(middleware::rate_limit::DEFAULT_CLIENT_RATE_LIMIT)
+                    .with_trusted_proxy_hops(trusted_proxy_hops),
+                middleware::client_rate_limit_middleware,
+            )),
+        )
+        .route("/near/verify", post(routes::auth::near_verify))
         .with_state(near_auth_state);
 
     Router::new()
         .route("/login", get(login_page))
         .route("/github", get(github_login))
         .route("/google", get(google_login))
+        .route("/oidc", get(oidc_login))
         .route("/callback", get(oauth_callback))
         .route(
             "/user",
@@ -1493,6 +1676,20 @@ pub fn build_auth_routes(
                 crate::middleware::auth::refresh_middleware,
             )),
         )
+        .route(
+            "/sessions",
+            get(routes::auth::list_sessions).layer(from_fn_with_state(
+                auth_state_middleware.clone(),
+                auth_middleware,
+            )),
+        )
+        .route(
+            "/sessions/{session_id}",
+            delete(routes::auth::revoke_session).layer(from_fn_with_state(
+                auth_state_middleware.clone(),
+                auth_middleware,
+            )),
+        )
         .merge(near_router)
         .with_state(auth_state)
 }
@@ -1503,18 +1700,44 @@ pub fn build_completion_routes(
     auth_state_middleware: &AuthState,
     usage_state: middleware::UsageState,
     rate_limit_state: middleware::RateLimitState,
+    body_limit_state: middleware::BodyLimitState,
 ) -> Router {
     use crate::routes::files::MAX_FILE_SIZE;
 
-    // Text-based inference routes (chat/completions, image generation, audio transcription, rerank, score)
-    // Use default body limit (~2 MB) since they only accept JSON
-    let text_inference_routes = Router::new()
+    // JSON inference routes whose bodies grow with the prompt (large `messages`
+    // arrays, inline base64 images). Auth runs first so unauthenticated
+    // requests are never buffered; the configurable body limit then answers
+    // oversized requests with a JSON 413 before the body hash buffers them, and
+    // also raises the extractors' own limit (axum defaults to 2 MB).
+    let json_inference_routes = Router::new()
         .route("/chat/completions", post(chat_completions))
         .route("/completions", post(completions))
+        .route("/embeddings", post(embeddings))
+        .layer(DefaultBodyLimit::max(body_limit_state.max_bytes))
+        .with_state(app_state.clone())
+        .layer(from_fn_with_state(
+            usage_state.clone(),
+            middleware::usage_check_middleware,
+        ))
+        .layer(from_fn_with_state(
+            rate_limit_state.clone(),
+            middleware::api_key_rate_limit_middleware,
+        ))
+        .layer(from_fn(middleware::body_hash_middleware))
+        .layer(from_fn_with_state(
+            body_limit_state,
+            middleware::body_limit_middleware,
+        ))
+        .layer(from_fn_with_state(
+            auth_state_middleware.clone(),
+            middleware::auth::auth_middleware_with_workspace_context,
+        ));
+
+    // Other text-based inference routes (image generation, audio transcription, rerank, score)
+    let text_inference_routes = Router::new()
         .route("/images/generations", post(image_generations))
         .route("/audio/transcriptions", post(audio_transcriptions))
         .route("/rerank", post(rerank))
-        .route("/embeddings", post(embeddings))
         .route("/score", post(score))
         // Override the router-level audio limit (25 MB) for privacy/classify: this is a
         // text-only endpoint, so a 256 KB cap is more appropriate.
@@ -1576,6 +1799,7 @@ pub fn build_completion_routes(
         ));
 
     Router::new()
+        .merge(json_inference_routes)
         .merge(text_inference_routes)
         .merge(file_inference_routes)
         .merge(metadata_routes)
@@ -1588,14 +1812,18 @@ pub fn build_response_routes(
     auth_state_middleware: &AuthState,
     usage_state: middleware::UsageState,
     rate_limit_state: middleware::RateLimitState,
+    body_limit_state: middleware::BodyLimitState,
 ) -> Router {
     let route_state = responses::ResponseRouteState {
         response_service: response_service.clone(),
         attestation_service: attestation_service.clone(),
     };
 
+    // Same layering as the JSON inference routes: auth, then the body limit,
+    // then the body hash.
     let inference_routes = Router::new()
         .route("/responses", post(responses::create_response))
+        .layer(DefaultBodyLimit::max(body_limit_state.max_bytes))
         .with_state(route_state.clone())
         .layer(from_fn_with_state(
             usage_state,
@@ -1605,11 +1833,15 @@ pub fn build_response_routes(
             rate_limit_state.clone(),
             middleware::api_key_rate_limit_middleware,
         ))
+        .layer(from_fn(middleware::body_hash_middleware))
+        .layer(from_fn_with_state(
+            body_limit_state,
+            middleware::body_limit_middleware,
+        ))
         .layer(from_fn_with_state(
             auth_state_middleware.clone(),
             middleware::auth::auth_middleware_with_workspace_context,
-        ))
-        .layer(from_fn(middleware::body_hash_middleware));
+        ));
 
     let other_routes = Router::new()
         .route("/responses/{response_id}", get(responses::get_response))
@@ -1658,6 +1890,40 @@ pub fn build_unsupported_openai_routes(
         ))
 }
 
+/// Build batch routes. Creating a batch goes through the credit check so an
+/// organization without credits is refused up front rather than per line.
+pub fn build_batch_routes(
+    batch_service: Arc<dyn services::batches::BatchServiceTrait>,
+    auth_state_middleware: &AuthState,
+    usage_state: middleware::UsageState,
+    rate_limit_state: middleware::RateLimitState,
+) -> Router {
+    use crate::routes::batches::*;
+
+    let state = BatchRouteState { batch_service };
+
+    let create_routes = Router::new()
+        .route("/batches", post(create_batch))
+        .with_state(state.clone())
+        .layer(from_fn_with_state(
+            usage_state,
+            middleware::usage_check_middleware,
+        ));
+
+    Router::new()
+        .route("/batches/{batch_id}", get(retrieve_batch))
+        .with_state(state)
+        .merge(create_routes)
+        .layer(from_fn_with_state(
+            rate_limit_state,
+            middleware::api_key_rate_limit_middleware,
+        ))
+        .layer(from_fn_with_state(
+            auth_state_middleware.clone(),
+            middleware::auth::auth_middleware_with_workspace_context,
+        ))
+}
+
 pub fn build_mcp_routes(
     web_search_provider: Arc<dyn services::responses::tools::WebSearchProviderTrait>,
     service_usage_service: Arc<dyn services::service_usage::ServiceUsageServiceTrait + Send + Sync>,
@@ -1687,6 +1953,7 @@ pub fn build_mcp_routes(
 pub fn build_conversation_routes(
     conversation_service: Arc<services::ConversationService>,
     auth_state_middleware: &AuthState,
+    page_size_limits: routes::common::PageSizeLimits,
 ) -> Router {
     Router::new()
         .route("/conversations", post(conversations::create_conversation))
@@ -1718,6 +1985,10 @@ pub fn build_conversation_routes(
             "/conversations/{conversation_id}/clone",
             post(conversations::clone_conversation),
         )
+        .route(
+            "/conversations/{conversation_id}/fork",
+            post(conversations::fork_conversation),
+        )
         .route(
             "/conversations/{conversation_id}/items",
             get(conversations::list_conversation_items),
@@ -1726,10 +1997,14 @@ pub fn build_conversation_routes(
             "/conversations/{conversation_id}/items",
             post(conversations::create_conversation_items),
         )
-        .with_state(
-            conversation_service
-                as Arc<dyn services::conversations::ports::ConversationServiceTrait>,
+        .route(
+            "/conversations/{conversation_id}/items/search",
+            get(conversations::search_conversation_items),
         )
+        .with_state(conversations::ConversationRouteState {
+            conversation_service,
+            page_size_limits,
+        })
         .layer(from_fn_with_state(
             auth_state_middleware.clone(),
             auth_middleware_with_api_key,
@@ -1740,18 +2015,23 @@ pub fn build_conversation_routes(
 ///
 /// Route classification (nearai/infra#193) — see `routes/attestation.rs` for
 /// the full table:
-/// - `GET /v1/attestation/report` and `GET /v1/signature/{chat_id}` require an
+/// - `GET /v1/attestation/report`, `GET /v1/attestation/quote`,
+///   `POST /v1/attestation/verify-model`, `GET /v1/signature/{chat_id}` and
+///   `GET /v1/verify/{chat_id}` require an
 ///   API key (`auth_middleware_with_api_key`). The middleware only validates
 ///   the key (rejecting missing/invalid/expired/revoked keys with 401); like
-///   the signature route, report retrieval is non-billable — no usage or
-///   billing records are created.
+///   the signature route, report retrieval is non-billable — no usage or billing
+///   records are created.
 /// - `GET /v1/attestation/ita-token` is deliberately public; the rationale is
 ///   documented on `build_public_attestation_routes`.
 pub fn build_attestation_routes(app_state: AppState, auth_state_middleware: &AuthState) -> Router {
     let attestation_route_state = attestation::AttestationRouteState::from(app_state);
     let authenticated_routes = Router::new()
         .route("/attestation/report", get(get_attestation_report))
+        .route("/attestation/quote", get(get_attestation_quote))
+        .route("/attestation/verify-model", post(verify_model_attestation))
         .route("/signature/{chat_id}", get(get_signature))
+        .route("/verify/{chat_id}", get(verify_signature))
         .with_state(attestation_route_state.clone())
         .layer(from_fn_with_state(
             auth_state_middleware.clone(),
@@ -1781,11 +2061,23 @@ pub fn build_workspace_routes(app_state: AppState, auth_state_middleware: &AuthS
                 .put(update_workspace)
                 .delete(delete_workspace),
         )
+        .route(
+            "/workspaces/{workspace_id}/monthly-budget",
+            axum::routing::patch(update_workspace_monthly_budget),
+        )
+        .route(
+            "/workspaces/{workspace_id}/transfer",
+            post(transfer_workspace),
+        )
         // Workspace API key management
         .route(
             "/workspaces/{workspace_id}/api-keys",
             get(list_workspace_api_keys).post(create_workspace_api_key),
         )
+        .route(
+            "/workspaces/{workspace_id}/api-keys/revoke-all",
+            post(revoke_all_workspace_api_keys),
+        )
         .route(
             "/workspaces/{workspace_id}/api-keys/{key_id}",
             axum::routing::delete(revoke_workspace_api_key).patch(update_workspace_api_key),
@@ -1821,15 +2113,46 @@ pub fn build_files_routes(app_state: AppState, auth_state_middleware: &AuthState
         ))
 }
 
+/// Build workspace-scoped MCP connector routes (API key auth)
+pub fn build_mcp_connector_routes(
+    mcp_service: Arc<dyn services::mcp::McpService>,
+    auth_state_middleware: &AuthState,
+) -> Router {
+    use crate::routes::mcp_connectors::*;
+
+    let state = McpConnectorsRouteState { mcp_service };
+
+    Router::new()
+        .route(
+            "/mcp/connectors",
+            post(create_mcp_connector).get(list_mcp_connectors),
+        )
+        .route(
+            "/mcp/connectors/{connector_id}",
+            delete(delete_mcp_connector),
+        )
+        .route(
+            "/mcp/connectors/{connector_id}/tools/{tool_name}/invoke",
+            post(invoke_mcp_tool),
+        )
+        .with_state(state)
+        .layer(from_fn_with_state(
+            auth_state_middleware.clone(),
+            middleware::auth::auth_middleware_with_workspace_context,
+        ))
+}
+
 /// Build feature request routes for user submissions and admin aggregation.
 pub fn build_feature_request_routes(
     pool: database::DbPool,
     auth_state_middleware: &AuthState,
+    page_size_limits: routes::common::PageSizeLimits,
 ) -> Router {
     use crate::middleware::{admin_middleware, auth_middleware};
 
     let state = FeatureRequestsRouteState {
         repository: Arc::new(database::repositories::FeatureRequestRepository::new(pool)),
+        page_size_limits,
     };
 
     let user_routes = Router::new()
@@ -1915,6 +2238,34 @@ pub fn build_reporting_usage_routes(
         ))
 }
 
+/// Build the CSV usage history exports. They share the reporting routes'
+/// global rate and concurrency guard and are only mounted when usage
+/// reporting is enabled.
+pub fn build_usage_csv_routes(
+    app_state: AppState,
+    guard_state: middleware::ReportingGuardState,
+    auth_state_middleware: &AuthState,
+) -> Router {
+    Router::new()
+        .route(
+            "/organizations/{org_id}/usage/history.csv",
+            get(crate::routes::usage_csv::export_organization_usage_history_csv),
+        )
+        .route(
+            "/workspaces/{workspace_id}/api-keys/{key_id}/usage/history.csv",
+            get(crate::routes::usage_csv::export_api_key_usage_history_csv),
+        )
+        .with_state(app_state)
+        .layer(from_fn_with_state(
+            auth_state_middleware.clone(),
+            auth_middleware,
+        ))
+        .layer(from_fn_with_state(
+            guard_state,
+            middleware::reporting_global_guard_middleware,
+        ))
+}
+
 /// Build gateway routes for external model gateways to validate API keys.
 /// Reuses the same auth, rate limiting, and usage check middleware as completions.
 pub fn build_gateway_routes(
@@ -1965,6 +2316,11 @@ pub fn build_model_routes(models_service: Arc<dyn ModelsServiceTrait>) -> Router
         // Public endpoints - no auth required
         .route("/model/list", get(list_models))
         .route("/model/{model_name}", get(get_model_by_name))
+        .route(
+            "/models/{model_name}/capabilities",
+            get(get_model_capabilities),
+        )
+        .route("/models/{model_name}/pricing", get(get_model_pricing))
         .with_state(models_app_state)
         // Public, anonymous, identical-for-all-clients responses that change
         // only when an admin updates the model catalog. 30s fresh window plus
@@ -1976,12 +2332,18 @@ pub fn build_model_routes(models_service: Arc<dyn ModelsServiceTrait>) -> Router
 }
 
 /// Build public services routes (no auth) — GET /v1/services, GET /v1/services/{service_name}
-pub fn build_services_routes(pool: database::DbPool) -> Router {
+pub fn build_services_routes(
+    pool: database::DbPool,
+    page_size_limits: routes::common::PageSizeLimits,
+) -> Router {
     use crate::routes::services::{get_service_by_name, list_services, ServicesRouteState};
     use database::repositories::ServiceRepository;
 
     let service_repository = Arc::new(ServiceRepository::new(pool));
-    let state = ServicesRouteState { service_repository };
+    let state = ServicesRouteState {
+        service_repository,
+        page_size_limits,
+    };
 
     Router::new()
         .route("/services", get(list_services))
@@ -2063,14 +2425,16 @@ pub fn build_admin_routes(
         delete_admin_access_token, delete_model, deprecate_model, get_admin_organization_balance,
         get_billing_summary, get_infra_summary, get_model_consumption_timeseries,
         get_model_history, get_model_revenue, get_org_revenue,
-        get_organization as get_admin_organization, get_organization_concurrent_limit,
-        get_organization_limits_history, get_organization_metrics, get_organization_timeseries,
-        get_performance_timeseries, get_platform_metrics, get_platform_timeseries,
+        get_organization as get_admin_organization, get_organization_allowed_models,
+        get_organization_concurrent_limit, get_organization_limits_history,
+        get_organization_metrics, get_organization_seat_limit, get_organization_timeseries,
+        get_performance_timeseries, get_platform_metrics, get_platform_timeseries, get_pool_state,
         get_revenue_density, list_admin_access_tokens, list_invitation_email_deliveries,
         list_model_pricing_changes, list_models as admin_list_models, list_organization_members,
         list_organizations, list_users, preview_model_deprecation, preview_model_pricing_changes,
-        resend_invitation_email, update_organization_concurrent_limit, update_organization_limits,
-        update_service, AdminAppState,
+        refresh_discovery, resend_invitation_email, update_organization_allowed_models,
+        update_organization_concurrent_limit, update_organization_limits,
+        update_organization_seat_limit, update_service, AdminAppState,
     };
     use crate::routes::staking_farm::{
         get_admin_organization_staking_farm, sync_admin_organization_staking_farm,
@@ -2093,14 +2457,19 @@ pub fn build_admin_routes(
     // `model_aliases` tables. It also holds the `completion_service` so it
     // can invalidate the per-org concurrent-limit cache after a PATCH to
     // `/v1/admin/organizations/{org_id}/concurrent-limit`.
-    let admin_service = Arc::new(AdminServiceImpl::new(
-        admin_repository as Arc<dyn services::admin::AdminRepository>,
-        services.models_service as Arc<dyn services::models::ModelsServiceTrait>,
-        services.completion_service.clone()
-            as Arc<dyn services::completions::CompletionServiceTrait>,
-        services::email::sender_from_config(&config.invitation_email)
-            .expect("Failed to initialize admin email sender"),
-    )) as Arc<dyn services::admin::AdminService + Send + Sync>;
+    let admin_service = Arc::new(
+        AdminServiceImpl::new(
+            admin_repository as Arc<dyn services::admin::AdminRepository>,
+            services.models_service as Arc<dyn services::models::ModelsServiceTrait>,
+            services.completion_service.clone()
+                as Arc<dyn services::completions::CompletionServiceTrait>,
+            services::email::sender_from_config(&config.invitation_email)
+                .expect("Failed to initialize admin email sender"),
+        )
+        .with_currency_rates(services::usage::CurrencyRates::from_config(
+            &config.currency,
+        )),
+    ) as Arc<dyn services::admin::AdminService + Send + Sync>;
 
     let github_dispatcher =
         services::github_dispatch::dispatcher_from_config(&config.github_dispatch);
@@ -2117,6 +2486,7 @@ pub fn build_admin_routes(
         auth_service: auth_state_middleware.auth_service.clone(),
         usage_service: services.usage_service,
         staking_farm_service: services.staking_farm_service,
+        page_size_limits: (&config.server).into(),
         config,
         admin_access_token_repository,
         inference_provider_pool: services.inference_provider_pool,
@@ -2165,6 +2535,11 @@ pub fn build_admin_routes(
             "/admin/models/{model_name}/deprecation/confirm",
             axum::routing::post(confirm_model_deprecation),
         )
+        .route("/admin/pool/state", axum::routing::get(get_pool_state))
+        .route(
+            "/admin/discovery/refresh",
+            axum::routing::post(refresh_discovery),
+        )
         .route("/admin/services", axum::routing::post(create_service))
         .route("/admin/services/{id}", axum::routing::patch(update_service))
         .route(
@@ -2192,6 +2567,15 @@ pub fn build_admin_routes(
             axum::routing::patch(update_organization_concurrent_limit)
                 .get(get_organization_concurrent_limit),
         )
+        .route(
+            "/admin/organizations/{org_id}/seat-limit",
+            axum::routing::patch(update_organization_seat_limit).get(get_organization_seat_limit),
+        )
+        .route(
+            "/admin/organizations/{org_id}/allowed-models",
+            axum::routing::patch(update_organization_allowed_models)
+                .get(get_organization_allowed_models),
+        )
         .route(
             "/admin/organizations/{org_id}/metrics",
             axum::routing::get(get_organization_metrics),
@@ -2529,6 +2913,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_openapi_attestation_quote_requires_api_key() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let quote_get = &spec["paths"]["/v1/attestation/quote"]["get"];
+
+        assert!(
+            quote_get.is_object(),
+            "missing OpenAPI operation: GET /v1/attestation/quote"
+        );
+        assert_eq!(
+            quote_get["security"],
+            serde_json::json!([{ "api_key": [] }]),
+            "/v1/attestation/quote must require api_key security"
+        );
+    }
+
     #[test]
     fn test_openapi_signature_requires_api_key() {
         // nearai/infra#193: /v1/signature/{chat_id} stays API-key-protected.
@@ -2546,6 +2946,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_openapi_admin_batch_upsert_declares_dry_run_response() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let schema = &spec["paths"]["/v1/admin/models"]["patch"]["responses"]["200"]["content"]
+            ["application/json"]["schema"];
+        assert_eq!(
+            schema["$ref"], "#/components/schemas/BatchUpsertModelsResponse",
+            "PATCH /v1/admin/models must declare both the upsert and dry-run bodies"
+        );
+
+        let variants = spec["components"]["schemas"]["BatchUpsertModelsResponse"]["oneOf"]
+            .as_array()
+            .expect("BatchUpsertModelsResponse should be a oneOf");
+        assert!(variants
+            .iter()
+            .any(|v| v["$ref"] == "#/components/schemas/BatchUpsertDryRunResponse"));
+    }
+
     #[test]
     fn test_openapi_conversation_action_paths_use_v1_prefix() {
         let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
@@ -2582,7 +3000,22 @@ mod tests {
                 host: "127.0.0.1".to_string(),
                 port: 0, // Use port 0 for testing to get a random available port
                 pricing_change_apply_interval_secs: 0,
+                batch_processing_interval_secs: 0,
                 ohttp_enabled: false,
+                stream_keepalive_interval_ms: 0,
+                stream_coalesce_window_ms: 0,
+                stream_coalesce_max_bytes: config::DEFAULT_STREAM_COALESCE_MAX_BYTES,
+                stream_dedup_window_ms: 0,
+                end_user_requests_per_minute: 0,
+                max_inference_body_bytes: config::DEFAULT_MAX_INFERENCE_BODY_BYTES,
+                slow_request_threshold_ms: config::DEFAULT_SLOW_REQUEST_THRESHOLD_MS,
+                default_page_size: config::DEFAULT_PAGE_SIZE,
+                max_page_size: config::DEFAULT_MAX_PAGE_SIZE,
+                inference_id_namespace: config::DEFAULT_INFERENCE_ID_NAMESPACE,
+                inference_id_legacy_lookup: true,
+                shutdown_drain_secs: 0,
+                json_schema_fallback: config::JsonSchemaFallbackPolicy::Passthrough,
+                trusted_proxy_hops: config::DEFAULT_TRUSTED_PROXY_HOPS,
             },
             inference_api_key: Some("test-key".to_string()),
             internal_usage_token: None,
@@ -2599,6 +3032,7 @@ mod tests {
                 encoding_key: "mock_encoding_key".to_string(),
                 github: None,
                 google: None,
+                oidc: None,
                 near: config::NearConfig::default(),
                 admin_domains: vec![],
                 require_session_bound_access_tokens: false,
@@ -2614,6 +3048,8 @@ mod tests {
                 max_connections: 5,
                 tls_enabled: false,
                 tls_ca_cert_path: None,
+                tls_min_version: None,
+                tls_pinned_spki_sha256: Vec::new(),
                 refresh_interval: 30,
                 mock: false,
             },
@@ -2635,6 +3071,11 @@ mod tests {
             infra: config::InfraConfig::default(),
             staking_farm: config::StakingFarmConfig::default(),
             usage_reporting: config::UsageReportingConfig::default(),
+            usage_anomaly: config::UsageAnomalyConfig::default(),
+            audit_log: config::AuditLogConfig::default(),
+            provider_headers: config::ProviderHeadersConfig::default(),
+            mcp_connectors: config::McpConnectorsConfig::default(),
+            currency: config::CurrencyConfig::default(),
             ita: config::ItaAttestationConfig::default(),
         };
 
@@ -2677,6 +3118,8 @@ mod tests {
             max_connections: 5,
             tls_enabled: false,
             tls_ca_cert_path: None,
+            tls_min_version: None,
+            tls_pinned_spki_sha256: Vec::new(),
             refresh_interval: 30,
             mock: false,
         };
@@ -2690,7 +3133,22 @@ mod tests {
                 host: "127.0.0.1".to_string(),
                 port: 0,
                 pricing_change_apply_interval_secs: 0,
+                batch_processing_interval_secs: 0,
                 ohttp_enabled: false,
+                stream_keepalive_interval_ms: 0,
+                stream_coalesce_window_ms: 0,
+                stream_coalesce_max_bytes: config::DEFAULT_STREAM_COALESCE_MAX_BYTES,
+                stream_dedup_window_ms: 0,
+                end_user_requests_per_minute: 0,
+                max_inference_body_bytes: config::DEFAULT_MAX_INFERENCE_BODY_BYTES,
+                slow_request_threshold_ms: config::DEFAULT_SLOW_REQUEST_THRESHOLD_MS,
+                default_page_size: config::DEFAULT_PAGE_SIZE,
+                max_page_size: config::DEFAULT_MAX_PAGE_SIZE,
+                inference_id_namespace: config::DEFAULT_INFERENCE_ID_NAMESPACE,
+                inference_id_legacy_lookup: true,
+                shutdown_drain_secs: 0,
+                json_schema_fallback: config::JsonSchemaFallbackPolicy::Passthrough,
+                trusted_proxy_hops: config::DEFAULT_TRUSTED_PROXY_HOPS,
             },
             inference_api_key: Some("test-key".to_string()),
             internal_usage_token: None,
@@ -2707,6 +3165,7 @@ mod tests {
                 encoding_key: "mock_encoding_key".to_string(),
                 github: None,
                 google: None,
+                oidc: None,
                 near: config::NearConfig::default(),
                 admin_domains: vec![],
                 require_session_bound_access_tokens: false,
@@ -2722,6 +3181,8 @@ mod tests {
                 max_connections: 5,
                 tls_enabled: false,
                 tls_ca_cert_path: None,
+                tls_min_version: None,
+                tls_pinned_spki_sha256: Vec::new(),
                 refresh_interval: 30,
                 mock: false,
             },
@@ -2743,6 +3204,11 @@ mod tests {
             infra: config::InfraConfig::default(),
             staking_farm: config::StakingFarmConfig::default(),
             usage_reporting: config::UsageReportingConfig::default(),
+            usage_anomaly: config::UsageAnomalyConfig::default(),
+            audit_log: config::AuditLogConfig::default(),
+            provider_headers: config::ProviderHeadersConfig::default(),
+            mcp_connectors: config::McpConnectorsConfig::default(),
+            currency: config::CurrencyConfig::default(),
             ita: config::ItaAttestationConfig::default(),
         };
 
@@ -1,13 +1,21 @@
+use api::middleware::ShutdownState;
 use api::{build_app_with_config, init_auth_services, init_database, init_domain_services};
 use config::{ApiConfig, LoggingConfig};
-use database::repositories::AdminCompositeRepository;
+use database::pool_metrics::{PoolMetricsReporter, POOL_METRICS_INTERVAL};
+use database::repositories::{
+    AdminCompositeRepository, OrganizationUsageRepository, PgAdvisoryLock,
+};
 use database::{Database, ShutdownCoordinator, ShutdownStage};
+use inference_providers::spki_verifier::ClientIdentity;
+use opentelemetry::trace::TracerProvider;
 use opentelemetry::{global, KeyValue};
-use opentelemetry_otlp::{MetricExporter, WithExportConfig};
-use opentelemetry_sdk::{metrics::SdkMeterProvider, Resource};
+use opentelemetry_otlp::{MetricExporter, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{metrics::SdkMeterProvider, trace::SdkTracerProvider, Resource};
 use services::admin::ModelPricingScheduler;
+use services::batches::BatchProcessor;
 use services::inference_provider_pool::InferenceProviderPool;
 use services::metrics::{MetricsServiceTrait, OtlpMetricsService};
+use services::usage::anomaly::{UsageAnomalyDetector, USAGE_ANOMALY_DETECTOR_LOCK};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -15,9 +23,38 @@ use std::time::Duration;
 async fn main() {
     // Load configuration and initialize logging
     let config = load_configuration();
-    init_tracing(&config.logging);
+
+    // Get environment from env var (local, dev, staging, prod)
+    let environment = std::env::var("ENVIRONMENT").unwrap_or_else(|_| "local".to_string());
+
+    let resource = Resource::builder()
+        .with_attributes(vec![
+            KeyValue::new("service.name", "cloud-api"),
+            KeyValue::new("environment", environment.clone()),
+        ])
+        .build();
+
+    // Spans are exported over the same OTLP endpoint as metrics
+    let span_exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(&config.otlp.endpoint)
+        .build()
+        .expect("Failed to build OTLP span exporter");
+    let tracer_provider = SdkTracerProvider::builder()
+        .with_batch_exporter(span_exporter)
+        .with_resource(resource.clone())
+        .build();
+
+    init_tracing(&config.logging, &tracer_provider);
     tracing::debug!("Config: {:?}", config);
 
+    // A configured mTLS client certificate that cannot be loaded must stop
+    // startup: running without it only fails later, at every mTLS handshake.
+    if let Err(e) = ClientIdentity::from_env() {
+        tracing::error!(error = %e, "Failed to load vLLM client certificate");
+        std::process::exit(1);
+    }
+
     // Initialize core services
     let database = init_database(&config.database).await;
     if config.usage_reporting.enabled {
@@ -34,23 +71,13 @@ async fn main() {
         .build()
         .expect("Failed to build OTLP metrics exporter");
 
-    // Get environment from env var (local, dev, staging, prod)
-    let environment = std::env::var("ENVIRONMENT").unwrap_or_else(|_| "local".to_string());
-
-    let resource = Resource::builder()
-        .with_attributes(vec![
-            KeyValue::new("service.name", "cloud-api"),
-            KeyValue::new("environment", environment.clone()),
-        ])
-        .build();
-
     let meter_provider = SdkMeterProvider::builder()
         .with_periodic_exporter(exporter)
         .with_resource(resource)
         .build();
 
     tracing::info!(
-        "OpenTelemetry metrics initialized for environment: {}",
+        "OpenTelemetry metrics and traces initialized for environment: {}",
         environment
     );
 
@@ -64,10 +91,19 @@ async fn main() {
         database.clone(),
         &config,
         auth_components.organization_service.clone(),
-        metrics_service,
+        metrics_service.clone(),
     )
     .await;
 
+    // Export database connection-pool gauges (write pool, plus read pools
+    // when running against a Patroni cluster).
+    let pool_metrics_reporter =
+        Arc::new(PoolMetricsReporter::new(database.clone(), metrics_service));
+    pool_metrics_reporter
+        .clone()
+        .start(POOL_METRICS_INTERVAL)
+        .await;
+
     let config = Arc::new(config);
 
     // Build application router with config
@@ -89,15 +125,48 @@ async fn main() {
         .start(config.server.pricing_change_apply_interval_secs)
         .await;
 
+    // Start the batch worker. Batches are claimed atomically (FOR UPDATE SKIP
+    // LOCKED), so every instance can run it.
+    let batch_processor = domain_services.batch_processor.clone();
+    batch_processor
+        .clone()
+        .start(config.server.batch_processing_interval_secs)
+        .await;
+
+    // Start the usage anomaly detector (no-op unless USAGE_ANOMALY_INTERVAL_SECS
+    // is set). Only the instance holding its advisory lock runs detection, so
+    // anomalies are reported once rather than by every instance.
+    let usage_anomaly_detector = Arc::new(
+        UsageAnomalyDetector::new(
+            Arc::new(OrganizationUsageRepository::new(database.pool().clone())),
+            domain_services.metrics_service.clone(),
+            config.usage_anomaly.clone(),
+        )
+        .with_single_instance_lock(Arc::new(PgAdvisoryLock::new(
+            database.pool().clone(),
+            USAGE_ANOMALY_DETECTOR_LOCK,
+        ))),
+    );
+    usage_anomaly_detector.clone().start().await;
+
     // Start server with graceful shutdown handling
     start_server(
         app,
         config,
+        domain_services.shutdown_state,
         database,
         domain_services.inference_provider_pool,
         pricing_scheduler,
+        batch_processor,
+        usage_anomaly_detector,
+        pool_metrics_reporter,
     )
     .await;
+
+    // Flush spans still buffered by the batch exporter
+    if let Err(e) = tracer_provider.shutdown() {
+        tracing::warn!(error = %e, "Failed to flush OTLP spans on shutdown");
+    }
 }
 
 /// Load and validate configuration
@@ -112,12 +181,17 @@ fn load_configuration() -> ApiConfig {
 }
 
 /// Start the HTTP server with graceful shutdown on SIGTERM/SIGINT
+#[allow(clippy::too_many_arguments)]
 async fn start_server(
     app: axum::Router,
     config: Arc<ApiConfig>,
+    shutdown_state: ShutdownState,
     database: Arc<Database>,
     inference_provider_pool: Arc<InferenceProviderPool>,
     pricing_scheduler: Arc<ModelPricingScheduler>,
+    batch_processor: Arc<BatchProcessor>,
+    usage_anomaly_detector: Arc<UsageAnomalyDetector>,
+    pool_metrics_reporter: Arc<PoolMetricsReporter>,
 ) {
     let bind_address = format!("{}:{}", config.server.host, config.server.port);
     let listener = tokio::net::TcpListener::bind(&bind_address)
@@ -134,18 +208,39 @@ async fn start_server(
         }
     );
 
-    let server = axum::serve(listener, app).with_graceful_shutdown(shutdown_signal());
+    let drain = Duration::from_secs(config.server.shutdown_drain_secs);
+    // Peer addresses back the per-client rate limit when no trusted proxy
+    // forwarded the request
+    let server = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal(shutdown_state, drain));
 
     match server.await {
         Ok(_) => {
             tracing::info!("Server shutdown successfully, initiating coordinated cleanup");
-            perform_coordinated_shutdown(database, inference_provider_pool, pricing_scheduler)
-                .await;
+            perform_coordinated_shutdown(
+                database,
+                inference_provider_pool,
+                pricing_scheduler,
+                batch_processor,
+                usage_anomaly_detector,
+                pool_metrics_reporter,
+            )
+            .await;
         }
         Err(e) => {
             tracing::error!("Server error: {}", e);
-            perform_coordinated_shutdown(database, inference_provider_pool, pricing_scheduler)
-                .await;
+            perform_coordinated_shutdown(
+                database,
+                inference_provider_pool,
+                pricing_scheduler,
+                batch_processor,
+                usage_anomaly_detector,
+                pool_metrics_reporter,
+            )
+            .await;
             std::process::exit(1);
         }
     }
@@ -156,6 +251,9 @@ async fn perform_coordinated_shutdown(
     database: Arc<Database>,
     inference_provider_pool: Arc<InferenceProviderPool>,
     pricing_scheduler: Arc<ModelPricingScheduler>,
+    batch_processor: Arc<BatchProcessor>,
+    usage_anomaly_detector: Arc<UsageAnomalyDetector>,
+    pool_metrics_reporter: Arc<PoolMetricsReporter>,
 ) {
     let mut coordinator = ShutdownCoordinator::new(Duration::from_secs(30));
     coordinator.start();
@@ -175,6 +273,12 @@ async fn perform_coordinated_shutdown(
                 inference_provider_pool.shutdown().await;
                 tracing::info!("Step 1.2: Cancelling pricing change scheduler task");
                 pricing_scheduler.shutdown().await;
+                tracing::info!("Step 1.3: Cancelling batch processor task");
+                batch_processor.shutdown().await;
+                tracing::info!("Step 1.4: Cancelling usage anomaly detector task");
+                usage_anomaly_detector.shutdown().await;
+                tracing::info!("Step 1.5: Cancelling database pool metrics reporter");
+                pool_metrics_reporter.shutdown().await;
                 tracing::debug!("All background tasks cancelled");
             },
         )
@@ -215,7 +319,10 @@ async fn perform_coordinated_shutdown(
     tracing::info!("=== SHUTDOWN COMPLETE ===");
 }
 
-async fn shutdown_signal() {
+/// Resolves once the listener should close. On SIGTERM/SIGINT the shutdown
+/// flag is set first, so new requests get 503 while in-flight ones drain;
+/// the listener stays open for `drain` so load balancers can deregister us.
+async fn shutdown_signal(shutdown_state: ShutdownState, drain: Duration) {
     use tokio::signal;
 
     let sigterm = async {
@@ -230,62 +337,55 @@ async fn shutdown_signal() {
     };
 
     tokio::select! {
-        _ = sigterm => {
-            tracing::info!("SIGTERM signal received");
-            tracing::info!("=== SHUTDOWN PHASE 0: STOP ACCEPTING REQUESTS ===");
-            tracing::info!("Server will stop accepting new requests immediately");
-            tracing::info!("Draining in-flight requests...");
-        }
-        _ = sigint => {
-            tracing::info!("SIGINT signal received");
-            tracing::info!("=== SHUTDOWN PHASE 0: STOP ACCEPTING REQUESTS ===");
-            tracing::info!("Server will stop accepting new requests immediately");
-            tracing::info!("Draining in-flight requests...");
-        }
+        _ = sigterm => tracing::info!("SIGTERM signal received"),
+        _ = sigint => tracing::info!("SIGINT signal received"),
     }
+
+    tracing::info!("=== SHUTDOWN PHASE 0: STOP ACCEPTING REQUESTS ===");
+    shutdown_state.begin();
+    tracing::info!("New requests are now rejected with 503");
+    if !drain.is_zero() {
+        tracing::info!(
+            "Keeping listener open for {}s so load balancers can deregister",
+            drain.as_secs()
+        );
+        tokio::time::sleep(drain).await;
+    }
+    tracing::info!("Draining in-flight requests...");
 }
 
-/// Initialize tracing/logging based on configuration
-fn init_tracing(logging_config: &LoggingConfig) {
+/// Initialize tracing/logging based on configuration. Spans are also
+/// exported through `tracer_provider`.
+fn init_tracing(logging_config: &LoggingConfig, tracer_provider: &SdkTracerProvider) {
+    use tracing_subscriber::prelude::*;
+    use tracing_subscriber::{fmt, EnvFilter, Layer, Registry};
+
     // Build the filter string from the logging configuration
     let mut filter = logging_config.level.clone();
     for (module, level) in &logging_config.modules {
         filter.push_str(&format!(",{module}={level}"));
     }
 
-    // Initialize tracing based on the format specified in config
-    match logging_config.format.as_str() {
-        "json" => {
-            tracing_subscriber::fmt()
-                .json()
-                .with_env_filter(filter)
-                .with_current_span(false)
-                .with_span_list(false)
-                .init();
-        }
-        "compact" => {
-            tracing_subscriber::fmt()
-                .compact()
-                .with_env_filter(filter)
-                .with_target(false)
-                .with_thread_ids(false)
-                .with_thread_names(false)
-                .init();
-        }
-        "pretty" => {
-            tracing_subscriber::fmt()
-                .pretty()
-                .with_env_filter(filter)
-                .init();
-        }
-        _ => {
-            // Default to JSON format for containerized environments (Datadog friendly)
-            tracing_subscriber::fmt()
-                .json()
-                .with_env_filter(filter)
-                .with_current_span(false)
-                .with_span_list(false)
-                .init();
-        }
-    }
+    // Pick the log format specified in config
+    let fmt_layer: Box<dyn Layer<Registry> + Send + Sync> = match logging_config.format.as_str() {
+        "compact" => fmt::layer()
+            .compact()
+            .with_target(false)
+            .with_thread_ids(false)
+            .with_thread_names(false)
+            .boxed(),
+        "pretty" => fmt::layer().pretty().boxed(),
+        // Default to JSON format for containerized environments (Datadog friendly)
+        _ => fmt::layer()
+            .json()
+            .with_current_span(false)
+            .with_span_list(false)
+            .boxed(),
+    };
+
+    tracing_subscriber::registry()
+        .with(fmt_layer)
+        .with(tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer("cloud-api")))
+        .with(EnvFilter::new(filter))
+        .init();
 }
@@ -1,8 +1,8 @@
 use axum::{
     extract::{Request, State},
-    http::StatusCode,
+    http::{header, HeaderValue, StatusCode},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
 };
 use database::User as DbUser;
 use services::auth::{AuthError, AuthServiceTrait, OAuthManager, SessionToken};
@@ -58,208 +58,136 @@ pub struct AuthenticatedReportingToken {
     pub scope: ReportingTokenScope,
 }
 
+/// Rejection returned by the authentication middlewares. Every `401` carries
+/// `WWW-Authenticate: Bearer` (RFC 6750) alongside the JSON error body.
+#[derive(Debug)]
+pub struct AuthRejection(StatusCode, axum::Json<crate::models::ErrorResponse>);
+
+impl From<(StatusCode, axum::Json<crate::models::ErrorResponse>)> for AuthRejection {
+    fn from((status, body): (StatusCode, axum::Json<crate::models::ErrorResponse>)) -> Self {
+        Self(status, body)
+    }
+}
+
+impl IntoResponse for AuthRejection {
+    fn into_response(self) -> Response {
+        let Self(status, body) = self;
+        let mut response = (status, body).into_response();
+        if status == StatusCode::UNAUTHORIZED {
+            response
+                .headers_mut()
+                .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+        }
+        response
+    }
+}
+
+fn authentication_error(message: &str) -> AuthRejection {
+    AuthRejection(
+        StatusCode::UNAUTHORIZED,
+        axum::Json(crate::models::ErrorResponse::new(
+            message.to_string(),
+            "authentication_error".to_string(),
+        )),
+    )
+}
+
+/// Extract the bearer token from the `Authorization` header. A missing header,
+/// a scheme other than `Bearer`, and an empty or whitespace-containing token
+/// are all rejected with the same `authentication_error` 401.
+fn bearer_token(request: &Request) -> Result<&str, AuthRejection> {
+    let Some(auth_value) = request.headers().get(header::AUTHORIZATION) else {
+        return Err(authentication_error("Missing Authorization header"));
+    };
+    let Some(token) = auth_value
+        .to_str()
+        .ok()
+        .and_then(|value| value.split_once(' '))
+        .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("Bearer"))
+        .map(|(_, token)| token)
+    else {
+        debug!("Authorization header uses unsupported scheme");
+        return Err(authentication_error(
+            "Authorization header must use the Bearer scheme",
+        ));
+    };
+    if token.is_empty() || token.contains(char::is_whitespace) {
+        debug!("Authorization header carries a malformed bearer token");
+        return Err(authentication_error("Malformed bearer token"));
+    }
+    Ok(token)
+}
+
 pub async fn auth_middleware_with_api_key(
     State(state): State<AuthState>,
-    request: Request,
+    mut request: Request,
     next: Next,
-) -> Result<Response, (StatusCode, axum::Json<crate::models::ErrorResponse>)> {
-    let auth_header = request
-        .headers()
-        .get("authorization")
-        .and_then(|h| h.to_str().ok());
-
+) -> Result<Response, AuthRejection> {
     tracing::debug!(
-        authorization_present = auth_header.is_some(),
+        authorization_present = request.headers().contains_key(header::AUTHORIZATION),
         "Auth API KEY middleware"
     );
 
-    let auth_result = if let Some(auth_value) = auth_header {
-        debug!("Found Authorization header");
-        if let Some(token) = auth_value.strip_prefix("Bearer ") {
-            authenticate_api_key(&state, token).await
-        } else {
-            debug!("Authorization header uses unsupported scheme");
-            Err((
-                StatusCode::UNAUTHORIZED,
-                axum::Json(crate::models::ErrorResponse::new(
-                    "Invalid authorization header format".to_string(),
-                    "invalid_auth_header".to_string(),
-                )),
-            ))
-        }
-    } else {
-        Err((
-            StatusCode::UNAUTHORIZED,
-            axum::Json(crate::models::ErrorResponse::new(
-                "Missing authorization header".to_string(),
-                "missing_auth_header".to_string(),
-            )),
-        ))
-    };
+    let token = bearer_token(&request)?;
+    let api_key = authenticate_api_key(&state, token).await?;
 
-    match auth_result {
-        Ok(api_key) => {
-            // Clone request to add extension
-            debug!(api_key_id = %api_key.id.0, "Adding API key to request");
-            let mut request = request;
-            request.extensions_mut().insert(api_key);
-            Ok(next.run(request).await)
-        }
-        Err(error) => Err(error),
-    }
+    debug!(api_key_id = %api_key.id.0, "Adding API key to request");
+    request.extensions_mut().insert(api_key);
+    Ok(next.run(request).await)
 }
 
 /// API Key middleware with workspace/organization context resolution
 pub async fn auth_middleware_with_workspace_context(
     State(state): State<AuthState>,
-    request: Request,
+    mut request: Request,
     next: Next,
-) -> Result<Response, (StatusCode, axum::Json<crate::models::ErrorResponse>)> {
-    let auth_header = request
-        .headers()
-        .get("authorization")
-        .and_then(|h| h.to_str().ok());
-
+) -> Result<Response, AuthRejection> {
     tracing::debug!(
-        authorization_present = auth_header.is_some(),
+        authorization_present = request.headers().contains_key(header::AUTHORIZATION),
         "Auth API KEY with workspace middleware"
     );
 
-    let auth_result = if let Some(auth_value) = auth_header {
-        debug!("Found Authorization header");
-        if let Some(token) = auth_value.strip_prefix("Bearer ") {
-            authenticate_api_key_with_context(&state, token).await
-        } else {
-            debug!("Authorization header uses unsupported scheme");
-            Err((
-                StatusCode::UNAUTHORIZED,
-                axum::Json(crate::models::ErrorResponse::new(
-                    "Invalid authorization header format".to_string(),
-                    "invalid_auth_header".to_string(),
-                )),
-            ))
-        }
-    } else {
-        Err((
-            StatusCode::UNAUTHORIZED,
-            axum::Json(crate::models::ErrorResponse::new(
-                "Missing authorization header".to_string(),
-                "missing_auth_header".to_string(),
-            )),
-        ))
-    };
+    let token = bearer_token(&request)?;
+    let authenticated_api_key = authenticate_api_key_with_context(&state, token).await?;
 
-    match auth_result {
-        Ok(authenticated_api_key) => {
-            debug!("Adding authenticated API key with workspace context to request");
-            let mut request = request;
-            request.extensions_mut().insert(authenticated_api_key);
-            Ok(next.run(request).await)
-        }
-        Err(error) => Err(error),
-    }
+    debug!("Adding authenticated API key with workspace context to request");
+    request.extensions_mut().insert(authenticated_api_key);
+    Ok(next.run(request).await)
 }
 
 /// Authentication middleware that validates session tokens only
 pub async fn auth_middleware(
     State(state): State<AuthState>,
-    request: Request,
+    mut request: Request,
     next: Next,
-) -> Result<Response, (StatusCode, axum::Json<crate::models::ErrorResponse>)> {
-    // Try to extract authentication from various sources
-    let auth_header = request
-        .headers()
-        .get("authorization")
-        .and_then(|h| h.to_str().ok());
-
+) -> Result<Response, AuthRejection> {
     tracing::debug!(
-        authorization_present = auth_header.is_some(),
+        authorization_present = request.headers().contains_key(header::AUTHORIZATION),
         "Auth middleware (session access token only)"
     );
 
-    let auth_result = if let Some(auth_value) = auth_header {
-        debug!("Found Authorization header");
-        if let Some(token) = auth_value.strip_prefix("Bearer ") {
-            debug!("Extracted Bearer token");
-            authenticate_session_access(&state, token.to_string()).await
-        } else {
-            debug!("Authorization header uses unsupported scheme");
-            Err((
-                StatusCode::UNAUTHORIZED,
-                axum::Json(crate::models::ErrorResponse::new(
-                    "Authorization header does not start with 'Bearer '".to_string(),
-                    "unauthorized".to_string(),
-                )),
-            ))
-        }
-    } else {
-        Err((
-            StatusCode::UNAUTHORIZED,
-            axum::Json(crate::models::ErrorResponse::new(
-                "Missing authorization".to_string(),
-                "unauthorized".to_string(),
-            )),
-        ))
-    };
+    let token = bearer_token(&request)?.to_string();
+    let user = authenticate_session_access(&state, token).await?;
 
-    match auth_result {
-        Ok(user) => {
-            // Clone request to add extension
-            let mut request = request;
-            request.extensions_mut().insert(AuthenticatedUser(user));
-            Ok(next.run(request).await)
-        }
-        Err(status) => Err(status),
-    }
+    request.extensions_mut().insert(AuthenticatedUser(user));
+    Ok(next.run(request).await)
 }
 
 pub async fn auth_middleware_with_reporting_token(
     State(state): State<AuthState>,
-    request: Request,
+    mut request: Request,
     next: Next,
-) -> Result<Response, (StatusCode, axum::Json<crate::models::ErrorResponse>)> {
-    let auth_header = request
-        .headers()
-        .get("authorization")
-        .and_then(|h| h.to_str().ok());
-
+) -> Result<Response, AuthRejection> {
     tracing::debug!(
-        has_authorization = auth_header.is_some(),
+        has_authorization = request.headers().contains_key(header::AUTHORIZATION),
         "Auth reporting token middleware"
     );
 
-    let auth_result = if let Some(auth_value) = auth_header {
-        debug!("Found Authorization header");
-        if let Some(token) = auth_value.strip_prefix("Bearer ") {
-            authenticate_reporting_token(&state, token).await
-        } else {
-            debug!("Authorization header uses unsupported scheme");
-            Err((
-                StatusCode::UNAUTHORIZED,
-                axum::Json(crate::models::ErrorResponse::new(
-                    "Invalid authorization header format".to_string(),
-                    "invalid_auth_header".to_string(),
-                )),
-            ))
-        }
-    } else {
-        Err((
-            StatusCode::UNAUTHORIZED,
-            axum::Json(crate::models::ErrorResponse::new(
-                "Missing authorization header".to_string(),
-                "missing_auth_header".to_string(),
-            )),
-        ))
-    };
+    let token = bearer_token(&request)?;
+    let reporting_token = authenticate_reporting_token(&state, token).await?;
 
-    match auth_result {
-        Ok(reporting_token) => {
-            let mut request = request;
-            request.extensions_mut().insert(reporting_token);
-            Ok(next.run(request).await)
-        }
-        Err(error) => Err(error),
-    }
+    request.extensions_mut().insert(reporting_token);
+    Ok(next.run(request).await)
 }
 
 /// Admin authentication middleware - verifies user is authenticated AND has admin access
@@ -268,13 +196,7 @@ pub async fn admin_middleware(
     State(state): State<AuthState>,
     request: Request,
     next: Next,
-) -> Result<Response, (StatusCode, axum::Json<crate::models::ErrorResponse>)> {
-    // Try to extract authentication from various sources
-    let auth_header = request
-        .headers()
-        .get("authorization")
-        .and_then(|h| h.to_str().ok());
-
+) -> Result<Response, AuthRejection> {
     let user_agent = request
         .headers()
         .get("User-Agent")
@@ -282,95 +204,27 @@ pub async fn admin_middleware(
         .map(|s| s.to_string());
 
     tracing::debug!(
-        authorization_present = auth_header.is_some(),
+        authorization_present = request.headers().contains_key(header::AUTHORIZATION),
         "Admin auth middleware"
     );
 
-    let auth_result = if let Some(auth_value) = auth_header {
-        debug!("Found Authorization header");
-        if let Some(token) = auth_value.strip_prefix("Bearer ") {
-            debug!("Extracted Bearer token for admin auth");
-
-            // Check if this looks like an admin access token (starts with "adm_")
-            // Admin access tokens should ONLY be validated as admin tokens, no fallback
-            if token.starts_with("adm_") {
-                match authenticate_admin_access_token(&state, token, user_agent.as_deref()).await {
-                    Ok(admin_token) => {
-                        debug!(
-                            admin_access_token_id = %admin_token.id,
-                            authenticated = true,
-                            "Authenticated via admin access token"
-                        );
-
-                        // Check if this is an admin access token management endpoint
-                        let path = request.uri().path();
-                        let is_access_token_management = path.starts_with("/admin/access-tokens");
-                        // For access token management endpoints, only allow session-based authentication
-                        if is_access_token_management {
-                            debug!("Access token management endpoint detected. Forbidden.");
-                            return Err((
-                                StatusCode::FORBIDDEN,
-                                axum::Json(crate::models::ErrorResponse::new(
-                                    "Access token management endpoint detected".to_string(),
-                                    "forbidden".to_string(),
-                                )),
-                            ));
-                        }
-
-                        // Query the actual admin user from database
-                        match get_admin_user_by_id(&state, admin_token.created_by_user_id).await {
-                            Ok(admin_user) => {
-                                debug!(
-                                    admin_user_id = %admin_user.id,
-                                    admin_access_token_id = %admin_token.id,
-                                    authenticated = true,
-                                    "Retrieved admin user for access token"
-                                );
-                                Ok(admin_user)
-                            }
-                            Err(_) => {
-                                error!("Failed to get admin user for access token");
-                                Err((
-                                    StatusCode::INTERNAL_SERVER_ERROR,
-                                    axum::Json(crate::models::ErrorResponse::new(
-                                        "Failed to get admin user for access token".to_string(),
-                                        "internal_server_error".to_string(),
-                                    )),
-                                ))
-                            }
-                        }
-                    }
-                    Err(err) => {
-                        debug!("Admin access token validation failed: {:?}", err);
-                        // Don't fall back to session token for admin access tokens
-                        // If it looks like an admin token but validation fails, reject it
-                        Err(err)
-                    }
-                }
-            } else {
-                // Not an admin access token, try as session token
-                debug!("Token does not appear to be an admin access token, trying session token");
-                authenticate_session_access(&state, token.to_string()).await
-            }
-        } else {
-            debug!("Authorization header uses unsupported scheme");
-            Err((
-                StatusCode::UNAUTHORIZED,
-                axum::Json(crate::models::ErrorResponse::new(
-                    "Invalid authorization header format".to_string(),
-                    "unauthorized".to_string(),
-                )),
-            ))
-        }
-    } else {
-        Err((
-            StatusCode::UNAUTHORIZED,
+    let token = bearer_token(&request)?;
+    let auth_result = authenticate_admin_token(&state, token, user_agent.as_deref()).await;
+
+    // For access token management endpoints, only allow session-based authentication
+    if auth_result.is_ok()
+        && token.starts_with("adm_")
+        && request.uri().path().starts_with("/admin/access-tokens")
+    {
+        debug!("Access token management endpoint detected. Forbidden.");
+        return Err(AuthRejection(
+            StatusCode::FORBIDDEN,
             axum::Json(crate::models::ErrorResponse::new(
-                "Missing authorization".to_string(),
-                "unauthorized".to_string(),
+                "Access token management endpoint detected".to_string(),
+                "forbidden".to_string(),
             )),
-        ))
-    };
+        ));
+    }
 
     match auth_result {
         Ok(user) => {
@@ -379,7 +233,7 @@ pub async fn admin_middleware(
 
             if !is_admin {
                 tracing::warn!("User attempted admin action without admin privileges");
-                return Err((
+                return Err(AuthRejection(
                     StatusCode::FORBIDDEN,
                     axum::Json(crate::models::ErrorResponse::new(
                         "No admin privileges".to_string(),
@@ -398,12 +252,73 @@ pub async fn admin_middleware(
             request.extensions_mut().insert(AdminUser(user));
             Ok(next.run(request).await)
         }
-        Err(status) => Err(status),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Authenticate a bearer token presented for admin access. Tokens that look
+/// like admin access tokens (`adm_`) are only validated as such and resolve to
+/// the admin who created them; anything else is validated as a session.
+async fn authenticate_admin_token(
+    state: &AuthState,
+    token: &str,
+    user_agent: Option<&str>,
+) -> Result<DbUser, (StatusCode, axum::Json<crate::models::ErrorResponse>)> {
+    if !token.starts_with("adm_") {
+        debug!("Token does not appear to be an admin access token, trying session token");
+        return authenticate_session_access(state, token.to_string()).await;
     }
+
+    // Don't fall back to session token for admin access tokens
+    let admin_token = authenticate_admin_access_token(state, token, user_agent)
+        .await
+        .inspect_err(|err| debug!("Admin access token validation failed: {:?}", err))?;
+    debug!(
+        admin_access_token_id = %admin_token.id,
+        authenticated = true,
+        "Authenticated via admin access token"
+    );
+
+    // Query the actual admin user from database
+    match get_admin_user_by_id(state, admin_token.created_by_user_id).await {
+        Ok(admin_user) => {
+            debug!(
+                admin_user_id = %admin_user.id,
+                admin_access_token_id = %admin_token.id,
+                authenticated = true,
+                "Retrieved admin user for access token"
+            );
+            Ok(admin_user)
+        }
+        Err(_) => {
+            error!("Failed to get admin user for access token");
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                axum::Json(crate::models::ErrorResponse::new(
+                    "Failed to get admin user for access token".to_string(),
+                    "internal_server_error".to_string(),
+                )),
+            ))
+        }
+    }
+}
+
+/// Resolve a bearer token to the user `admin_middleware` would authenticate
+/// (a session, or the creator of an `adm_` access token), for handlers that
+/// grant admin-only extras outside the admin routes. Callers still decide
+/// admin access with [`check_admin_access`].
+pub async fn authenticate_admin_caller(
+    state: &AuthState,
+    token: &str,
+    user_agent: Option<&str>,
+) -> Option<DbUser> {
+    authenticate_admin_token(state, token, user_agent)
+        .await
+        .ok()
 }
 
 /// Check if a user has admin access based on their email domain
-fn check_admin_access(state: &AuthState, user: &DbUser) -> bool {
+pub fn check_admin_access(state: &AuthState, user: &DbUser) -> bool {
     if state.admin_domains.is_empty() {
         return false;
     }
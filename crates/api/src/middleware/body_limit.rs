@@ -0,0 +1,116 @@
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use tracing::warn;
+
+use crate::models::ErrorResponse;
+
+/// Maximum accepted request body size, in bytes, for the wrapped routes
+#[derive(Clone, Copy, Debug)]
+pub struct BodyLimitState {
+    pub max_bytes: usize,
+}
+
+/// Middleware that rejects oversized request bodies with a JSON 413
+///
+/// Must sit outside `body_hash_middleware`, which buffers the whole body
+/// unconditionally, and inside auth so unauthenticated requests are never
+/// buffered. A declared `Content-Length` over the limit is rejected
+/// without reading anything; otherwise the body is buffered up to the limit
+/// (covering chunked uploads) and handed on as a single chunk.
+pub async fn body_limit_middleware(
+    State(state): State<BodyLimitState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let declared_length = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    if declared_length.is_some_and(|length| length > state.max_bytes as u64) {
+        warn!(
+            max_bytes = state.max_bytes,
+            declared_length, "Rejected request with oversized Content-Length"
+        );
+        return payload_too_large(state.max_bytes);
+    }
+
+    let (parts, body) = request.into_parts();
+    let body_bytes = match axum::body::to_bytes(body, state.max_bytes).await {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            warn!(
+                max_bytes = state.max_bytes,
+                "Rejected request body exceeding size limit"
+            );
+            return payload_too_large(state.max_bytes);
+        }
+    };
+
+    next.run(Request::from_parts(parts, Body::from(body_bytes)))
+        .await
+}
+
+fn payload_too_large(max_bytes: usize) -> Response {
+    (
+        StatusCode::PAYLOAD_TOO_LARGE,
+        Json(ErrorResponse::new(
+            format!("Request body exceeds the maximum allowed size of {max_bytes} bytes"),
+            "payload_too_large".to_string(),
+        )),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{middleware, routing::post, Router};
+    use tower::ServiceExt;
+
+    fn app(max_bytes: usize) -> Router {
+        Router::new()
+            .route(
+                "/",
+                post(|body: String| async move { body.len().to_string() }),
+            )
+            .layer(middleware::from_fn_with_state(
+                BodyLimitState { max_bytes },
+                body_limit_middleware,
+            ))
+    }
+
+    #[tokio::test]
+    async fn test_body_within_limit_passes_through() {
+        let response = app(16)
+            .oneshot(Request::post("/").body(Body::from("hello")).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_returns_413() {
+        let response = app(4)
+            .oneshot(Request::post("/").body(Body::from("hello")).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_content_length_rejected_before_reading() {
+        let request = Request::post("/")
+            .header(header::CONTENT_LENGTH, "1000")
+            .body(Body::empty())
+            .unwrap();
+        let response = app(4).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
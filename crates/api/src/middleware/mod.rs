@@ -5,11 +5,14 @@
 
 pub mod auth;
 pub mod body_hash;
+pub mod body_limit;
 pub mod metrics;
 pub mod rate_limit;
 pub mod reporting_guard;
 pub mod request_correlation;
 pub mod retry_after;
+pub mod shutdown;
+pub mod slow_request;
 pub mod usage;
 
 // Re-export commonly used items
@@ -18,12 +21,15 @@ pub use auth::{
     AuthenticatedUser,
 };
 pub use body_hash::{body_hash_middleware, RequestBodyHash};
+pub use body_limit::{body_limit_middleware, BodyLimitState};
 pub use metrics::{http_metrics_middleware, MetricsState};
-pub use rate_limit::{api_key_rate_limit_middleware, RateLimitState};
+pub use rate_limit::{api_key_rate_limit_middleware, client_rate_limit_middleware, RateLimitState};
 pub use reporting_guard::{
     reporting_global_guard_middleware, reporting_token_guard_middleware, ReportingGuardState,
     ReportingRequestDeadline,
 };
 pub use request_correlation::{request_correlation_middleware, RequestCorrelation};
 pub use retry_after::retry_after_middleware;
+pub use shutdown::{shutdown_middleware, ShutdownState};
+pub use slow_request::{slow_request_middleware, RequestModel, SlowRequestState};
 pub use usage::{usage_check_middleware, UsageState};
@@ -1,11 +1,12 @@
 use axum::{
-    extract::{Request, State},
+    extract::{ConnectInfo, Request, State},
     http::{header::RETRY_AFTER, HeaderName, HeaderValue, StatusCode},
     middleware::Next,
     response::Response,
 };
 use moka::future::Cache;
 use std::{
+    net::SocketAddr,
     sync::{
         atomic::{AtomicU32, Ordering},
         Arc,
@@ -20,6 +21,12 @@ use crate::models::ErrorResponse;
 const DEFAULT_API_KEY_RATE_LIMIT: u32 = 1000; // requests per minute
 const RATE_LIMIT_WINDOW_SECS: u64 = 60;
 const RATE_LIMIT_CACHE_MAX_CAPACITY: u64 = 50_000;
+/// Requests per minute per client for unauthenticated endpoints that write
+/// state, such as NEAR nonce issuance
+pub const DEFAULT_CLIENT_RATE_LIMIT: u32 = 30;
+/// Bucket shared by requests whose client address cannot be determined: no
+/// usable `X-Forwarded-For` entry and no peer address (in-process tests)
+const UNKNOWN_CLIENT_KEY: &str = "unknown";
 
 #[derive(Debug)]
 struct Counter(AtomicU32);
@@ -38,6 +45,8 @@ impl Counter {
 pub struct RateLimitState {
     key_limits: Cache<String, Arc<Counter>>,
     rate_limit: u32,
+    /// See [`client_key`]
+    trusted_proxy_hops: usize,
 }
 
 impl Default for RateLimitState {
@@ -58,9 +67,17 @@ impl RateLimitState {
         Self {
             key_limits,
             rate_limit,
+            trusted_proxy_hops: config::DEFAULT_TRUSTED_PROXY_HOPS,
         }
     }
 
+    /// Number of proxies in front of the server that append to
+    /// `X-Forwarded-For`; see [`client_key`].
+    pub fn with_trusted_proxy_hops(mut self, hops: usize) -> Self {
+        self.trusted_proxy_hops = hops;
+        self
+    }
+
     async fn check_limit(&self, api_key_id: &str) -> (bool, u32, u32) {
         let counter = self
             .key_limits
@@ -132,6 +149,53 @@ pub async fn api_key_rate_limit_middleware(
     Ok(next.run(request).await)
 }
 
+/// Client key for unauthenticated requests. Each of the `trusted_proxy_hops`
+/// proxies appends the address it received the request from to
+/// `X-Forwarded-For`, so the entry that many places from the right was written
+/// by the outermost trusted proxy and cannot be forged by the client. With no
+/// trusted proxies, or a header too short to have passed through all of them,
+/// the peer address is used instead.
+fn client_key(request: &Request, trusted_proxy_hops: usize) -> String {
+    let forwarded_for = request
+        .headers()
+        .get("x-forwarded-for")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            let entries: Vec<&str> = value.split(',').map(str::trim).collect();
+            let index = entries.len().checked_sub(trusted_proxy_hops)?;
+            (trusted_proxy_hops > 0).then(|| entries[index])
+        })
+        .filter(|addr| !addr.is_empty());
+    if let Some(addr) = forwarded_for {
+        return addr.to_string();
+    }
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(peer)| peer.ip().to_string())
+        .unwrap_or_else(|| UNKNOWN_CLIENT_KEY.to_string())
+}
+
+/// Per-client limiter for unauthenticated endpoints, keyed by [`client_key`]
+pub async fn client_rate_limit_middleware(
+    State(state): State<RateLimitState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, RateLimitedResponse> {
+    let key = client_key(&request, state.trusted_proxy_hops);
+    let (allowed, count, limit) = state.check_limit(&key).await;
+    if !allowed {
+        warn!(
+            path = %request.uri().path(),
+            "Client rate limit exceeded: {}/{} requests/min",
+            count,
+            limit
+        );
+        return Err(rate_limited_response(count, limit));
+    }
+    Ok(next.run(request).await)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,6 +231,47 @@ mod tests {
         assert_eq!(count2, 1);
     }
 
+    fn request(forwarded_for: Option<&str>, peer: Option<&str>) -> Request {
+        let mut builder = Request::get("/");
+        if let Some(value) = forwarded_for {
+            builder = builder.header("x-forwarded-for", value);
+        }
+        let mut request = builder.body(axum::body::Body::empty()).unwrap();
+        if let Some(peer) = peer {
+            request
+                .extensions_mut()
+                .insert(ConnectInfo(peer.parse::<SocketAddr>().unwrap()));
+        }
+        request
+    }
+
+    #[test]
+    fn test_client_key_uses_forwarded_for_entry_of_outermost_trusted_proxy() {
+        let forwarded = Some("6.6.6.6, 1.1.1.1, 10.0.0.7");
+
+        assert_eq!(client_key(&request(forwarded, None), 1), "10.0.0.7");
+        assert_eq!(client_key(&request(forwarded, None), 2), "1.1.1.1");
+        assert_eq!(client_key(&request(Some("10.0.0.7"), None), 1), "10.0.0.7");
+    }
+
+    #[test]
+    fn test_client_key_falls_back_to_peer_address() {
+        let peer = Some("203.0.113.9:54321");
+
+        assert_eq!(client_key(&request(None, peer), 1), "203.0.113.9");
+        // Too few entries to have passed through every trusted proxy
+        assert_eq!(
+            client_key(&request(Some("6.6.6.6"), peer), 2),
+            "203.0.113.9"
+        );
+        // No trusted proxies: the header is client-controlled and ignored
+        assert_eq!(
+            client_key(&request(Some("6.6.6.6"), peer), 0),
+            "203.0.113.9"
+        );
+        assert_eq!(client_key(&request(None, None), 1), UNKNOWN_CLIENT_KEY);
+    }
+
     #[test]
     fn test_rate_limited_response_carries_retry_after() {
         use axum::response::IntoResponse;
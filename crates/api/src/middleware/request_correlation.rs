@@ -11,6 +11,9 @@ use uuid::Uuid;
 mod privacy_log_scanner;
 
 pub const REQUEST_ID_HEADER: &str = "x-request-id";
+/// W3C Trace Context header; its trace-id seeds the request ID when the
+/// client sends no usable `x-request-id`.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
 
 #[derive(Debug, Clone, Copy)]
 pub struct RequestCorrelation {
@@ -23,6 +26,13 @@ pub async fn request_correlation_middleware(mut request: Request<Body>, next: Ne
         .get(REQUEST_ID_HEADER)
         .and_then(|value| value.to_str().ok())
         .and_then(|value| Uuid::parse_str(value).ok())
+        .or_else(|| {
+            request
+                .headers()
+                .get(TRACEPARENT_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(trace_id_from_traceparent)
+        })
         .unwrap_or_else(Uuid::new_v4);
 
     request
@@ -55,11 +65,35 @@ pub async fn request_correlation_middleware(mut request: Request<Body>, next: Ne
     response
 }
 
+/// Extract the 128-bit trace-id of a `traceparent` header
+/// (`{version}-{trace-id}-{parent-id}-{flags}`) as a UUID, so an upstream
+/// trace and this request share one join key. Returns `None` for malformed
+/// headers and the all-zero trace-id, which the spec declares invalid.
+fn trace_id_from_traceparent(value: &str) -> Option<Uuid> {
+    let mut parts = value.trim().split('-');
+    let (version, trace_id, parent_id, flags) =
+        (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+    let is_hex = |part: &str, len: usize| {
+        part.len() == len && part.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+    };
+    if !is_hex(version, 2)
+        || version == "ff"
+        || !is_hex(trace_id, 32)
+        || !is_hex(parent_id, 16)
+        || !is_hex(flags, 2)
+        || (version == "00" && parts.next().is_some())
+    {
+        return None;
+    }
+    let trace_id = u128::from_str_radix(trace_id, 16).ok()?;
+    (trace_id != 0).then(|| Uuid::from_u128(trace_id))
+}
+
 fn prevent_request_id_caching(headers: &mut HeaderMap) {
     headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
 }
 
-fn log_safe_path(path: &str) -> String {
+pub(crate) fn log_safe_path(path: &str) -> String {
     path.split('/')
         .map(|segment| {
             if should_redact_path_segment(segment) {
@@ -131,6 +165,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn traceparent_trace_id_becomes_request_id() {
+        assert_eq!(
+            trace_id_from_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            Some(Uuid::parse_str("4bf92f35-77b3-4da6-a3ce-929d0e0e4736").unwrap())
+        );
+        for invalid in [
+            "",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+        ] {
+            assert_eq!(trace_id_from_traceparent(invalid), None, "{invalid}");
+        }
+    }
+
+    #[tokio::test]
+    async fn request_id_prefers_header_then_traceparent() {
+        let app = Router::new()
+            .route("/", post(|| async { "ok" }))
+            .layer(from_fn(request_correlation_middleware));
+        let traceparent = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let explicit = Uuid::new_v4();
+
+        for (request_id, expected) in [
+            (None, "4bf92f35-77b3-4da6-a3ce-929d0e0e4736".to_string()),
+            (Some(explicit.to_string()), explicit.to_string()),
+        ] {
+            let mut request = Request::post("/").header(TRACEPARENT_HEADER, traceparent);
+            if let Some(request_id) = request_id {
+                request = request.header(REQUEST_ID_HEADER, request_id);
+            }
+            let response = app
+                .clone()
+                .oneshot(request.body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(
+                response.headers().get(REQUEST_ID_HEADER).unwrap(),
+                expected.as_str()
+            );
+        }
+    }
+
     #[tokio::test]
     async fn tracing_logs_exclude_customer_content() {
         assert_production_logs_exclude_forbidden_expressions();
@@ -0,0 +1,135 @@
+//! Rejection of new requests once graceful shutdown has begun.
+//!
+//! The binary flips the shared flag when SIGTERM/SIGINT arrives; from then on
+//! every new request is answered with a JSON 503 and `Connection: close`, so
+//! clients and load balancers move to another instance. Requests already past
+//! this middleware are unaffected and run to completion.
+
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::models::ErrorResponse;
+
+/// Shared shutdown flag, cloned into the middleware and held by the binary
+#[derive(Clone, Debug, Default)]
+pub struct ShutdownState {
+    shutting_down: Arc<AtomicBool>,
+}
+
+impl ShutdownState {
+    /// Mark the server as shutting down; new requests are rejected from now on
+    pub fn begin(&self) {
+        self.shutting_down.store(true, Ordering::Release);
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::Acquire)
+    }
+}
+
+/// Middleware that answers 503 `server_shutting_down` once shutdown has begun
+pub async fn shutdown_middleware(
+    State(state): State<ShutdownState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if state.is_shutting_down() {
+        return shutting_down_response();
+    }
+    next.run(request).await
+}
+
+fn shutting_down_response() -> Response {
+    let mut response = (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ErrorResponse::new(
+            "Server is shutting down; retry the request".to_string(),
+            "server_shutting_down".to_string(),
+        )),
+    )
+        .into_response();
+    response
+        .headers_mut()
+        .insert(header::CONNECTION, HeaderValue::from_static("close"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware::from_fn_with_state, routing::get, Router};
+    use tokio::sync::{oneshot, Notify};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn new_requests_get_503_while_in_flight_request_completes() {
+        let state = ShutdownState::default();
+        let started = Arc::new(Notify::new());
+        let release = Arc::new(Notify::new());
+        let (handler_started, handler_release) = (started.clone(), release.clone());
+        let app = Router::new()
+            .route(
+                "/slow",
+                get(move || async move {
+                    handler_started.notify_one();
+                    handler_release.notified().await;
+                    "done"
+                }),
+            )
+            .route("/fast", get(|| async { "ok" }))
+            .layer(from_fn_with_state(state.clone(), shutdown_middleware));
+
+        let (tx, rx) = oneshot::channel();
+        let in_flight_app = app.clone();
+        tokio::spawn(async move {
+            let response = in_flight_app
+                .oneshot(Request::get("/slow").body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let _ = tx.send(response);
+        });
+        started.notified().await;
+
+        state.begin();
+
+        let rejected = app
+            .oneshot(Request::get("/fast").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(rejected.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(rejected.headers().get(header::CONNECTION).unwrap(), "close");
+        let body = axum::body::to_bytes(rejected.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["error"]["type"], "server_shutting_down");
+
+        release.notify_one();
+        let completed = rx.await.unwrap();
+        assert_eq!(completed.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn requests_pass_through_before_shutdown() {
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(from_fn_with_state(
+                ShutdownState::default(),
+                shutdown_middleware,
+            ));
+        let response = app
+            .oneshot(Request::get("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
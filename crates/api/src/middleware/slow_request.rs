@@ -0,0 +1,176 @@
+//! Slow-request logging for performance triage.
+//!
+//! Requests whose handler takes longer than the configured threshold to
+//! produce a response are logged at WARN with method, redacted path, status
+//! and elapsed milliseconds, plus the model for inference requests. Streaming
+//! responses are timed to the first byte: the body is still flowing when the
+//! handler returns. Request bodies are never read.
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use std::{
+    sync::{Arc, OnceLock},
+    time::{Duration, Instant},
+};
+
+use super::request_correlation::log_safe_path;
+
+/// Threshold above which a request is logged as slow (zero disables logging)
+#[derive(Clone, Copy, Debug)]
+pub struct SlowRequestState {
+    pub threshold: Duration,
+}
+
+/// Request extension that inference handlers fill with the requested model,
+/// so a slow-request log line can name it without touching the body.
+#[derive(Clone, Debug, Default)]
+pub struct RequestModel(Arc<OnceLock<String>>);
+
+impl RequestModel {
+    /// Record the model; only the first call takes effect.
+    pub fn set(&self, model: &str) {
+        let _ = self.0.set(model.to_string());
+    }
+
+    fn get(&self) -> Option<&str> {
+        self.0.get().map(String::as_str)
+    }
+}
+
+/// Middleware that logs requests slower than `SlowRequestState::threshold`
+pub async fn slow_request_middleware(
+    State(state): State<SlowRequestState>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response {
+    if state.threshold.is_zero() {
+        return next.run(request).await;
+    }
+
+    let start = Instant::now();
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let model = RequestModel::default();
+    request.extensions_mut().insert(model.clone());
+
+    let response = next.run(request).await;
+
+    let elapsed = start.elapsed();
+    if elapsed > state.threshold {
+        tracing::warn!(
+            method = %method,
+            path = %log_safe_path(&path),
+            status = response.status().as_u16(),
+            elapsed_ms = elapsed.as_millis() as u64,
+            threshold_ms = state.threshold.as_millis() as u64,
+            model = model.get().unwrap_or("-"),
+            "Slow request"
+        );
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{middleware::from_fn_with_state, routing::post, Extension, Router};
+    use std::{
+        io,
+        sync::{Arc, Mutex},
+    };
+    use tower::ServiceExt;
+    use tracing::Level;
+
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    struct CapturedLogsWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for CapturedLogsWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0
+                .lock()
+                .expect("captured logs mutex should not poison")
+                .extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl CapturedLogs {
+        fn contents(&self) -> String {
+            let logs = self
+                .0
+                .lock()
+                .expect("captured logs mutex should not poison");
+            String::from_utf8_lossy(&logs).into_owned()
+        }
+    }
+
+    /// Run one request through a handler that sleeps `handler_delay`, with the
+    /// middleware at `threshold`, and return the captured WARN logs.
+    async fn captured_warn_logs(threshold: Duration, handler_delay: Duration) -> String {
+        let logs = CapturedLogs::default();
+        let writer_logs = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(Level::WARN)
+            .with_ansi(false)
+            .with_writer(move || CapturedLogsWriter(Arc::clone(&writer_logs.0)))
+            .finish();
+        let app = Router::new()
+            .route(
+                "/v1/chat/completions",
+                post(
+                    move |Extension(model): Extension<RequestModel>| async move {
+                        model.set("test/slow-model");
+                        tokio::time::sleep(handler_delay).await;
+                        "ok"
+                    },
+                ),
+            )
+            .layer(from_fn_with_state(
+                SlowRequestState { threshold },
+                slow_request_middleware,
+            ));
+
+        let _subscriber_guard = tracing::subscriber::set_default(subscriber);
+        let response = app
+            .oneshot(
+                Request::post("/v1/chat/completions")
+                    .body(Body::from(r#"{"messages":"BODY_LOG_SENTINEL"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status().as_u16(), 200);
+        logs.contents()
+    }
+
+    #[tokio::test]
+    async fn test_logs_request_slower_than_threshold() {
+        let captured =
+            captured_warn_logs(Duration::from_millis(20), Duration::from_millis(60)).await;
+
+        assert!(captured.contains("Slow request"), "logs: {captured}");
+        assert!(captured.contains("method=POST"));
+        assert!(captured.contains("path=/v1/chat/completions"));
+        assert!(captured.contains("status=200"));
+        assert!(captured.contains("elapsed_ms="));
+        assert!(captured.contains("model=\"test/slow-model\""));
+        assert!(!captured.contains("BODY_LOG_SENTINEL"));
+    }
+
+    #[tokio::test]
+    async fn test_fast_request_not_logged() {
+        let captured = captured_warn_logs(Duration::from_secs(5), Duration::ZERO).await;
+
+        assert!(!captured.contains("Slow request"), "logs: {captured}");
+    }
+}
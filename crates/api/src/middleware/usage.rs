@@ -1,9 +1,10 @@
 use axum::{
     extract::{Request, State},
-    http::StatusCode,
+    http::{header::RETRY_AFTER, HeaderValue, StatusCode},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
 };
+use chrono::{DateTime, Datelike, TimeZone, Utc};
 use services::usage::{UsageCheckResult, UsageServiceTrait};
 use std::{future::Future, pin::Pin, sync::Arc};
 use tracing::{debug, warn};
@@ -46,6 +47,15 @@ impl StakingFarmPreflightSync for services::staking_farm::StakingFarmService {
     }
 }
 
+/// Error type when the organization has no credits left to spend.
+const INSUFFICIENT_QUOTA: &str = "insufficient_quota";
+/// Error type when a workspace budget or API key spend limit is reached.
+const SPEND_LIMIT_EXCEEDED: &str = "spend_limit_exceeded";
+
+const LIMIT_SCOPE_ORGANIZATION: &str = "organization";
+const LIMIT_SCOPE_WORKSPACE: &str = "workspace";
+const LIMIT_SCOPE_API_KEY: &str = "api_key";
+
 /// State for usage middleware
 #[derive(Clone)]
 pub struct UsageState {
@@ -103,13 +113,16 @@ pub async fn check_usage_for_api_key(
             );
             return Err((
                 StatusCode::PAYMENT_REQUIRED,
-                axum::Json(ErrorResponse::new(
+                axum::Json(ErrorResponse::over_limit(
                     format!(
                         "API key spend limit exceeded. Spent: {}, Limit: {}",
                         format_amount(api_key_spend),
                         format_amount(api_key_limit)
                     ),
-                    "api_key_limit_exceeded".to_string(),
+                    SPEND_LIMIT_EXCEEDED,
+                    "api_key_limit_exceeded",
+                    LIMIT_SCOPE_API_KEY,
+                    None,
                 )),
             ));
         }
@@ -123,6 +136,57 @@ pub async fn check_usage_for_api_key(
         );
     }
 
+    // Then the workspace's monthly budget, if one is set
+    if let Some(monthly_budget) = api_key.workspace.monthly_budget {
+        let workspace_id = api_key.workspace.id.0;
+        let now = Utc::now();
+        let workspace_spend = state
+            .usage_repository
+            .get_workspace_spend_since(workspace_id, current_month_start(now))
+            .await
+            .map_err(|_| {
+                tracing::error!("Failed to get workspace monthly spend");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    axum::Json(ErrorResponse::new(
+                        "Failed to check workspace budget".to_string(),
+                        "internal_server_error".to_string(),
+                    )),
+                )
+            })?;
+
+        if workspace_spend >= monthly_budget {
+            warn!(
+                "Workspace {} exceeded monthly budget. Spent: {}, Budget: {}",
+                workspace_id,
+                format_amount(workspace_spend),
+                format_amount(monthly_budget)
+            );
+            return Err((
+                StatusCode::PAYMENT_REQUIRED,
+                axum::Json(ErrorResponse::over_limit(
+                    format!(
+                        "Workspace monthly budget exceeded. Spent: {}, Budget: {}",
+                        format_amount(workspace_spend),
+                        format_amount(monthly_budget)
+                    ),
+                    SPEND_LIMIT_EXCEEDED,
+                    "workspace_budget_exceeded",
+                    LIMIT_SCOPE_WORKSPACE,
+                    Some(next_month_start(now)),
+                )),
+            ));
+        }
+
+        debug!(
+            "Workspace {} within monthly budget. Spent: {}, Budget: {}, Remaining: {}",
+            workspace_id,
+            format_amount(workspace_spend),
+            format_amount(monthly_budget),
+            format_amount(monthly_budget - workspace_spend)
+        );
+    }
+
     check_organization_usage_after_staking_preflight(
         state.staking_farm_service.as_ref(),
         state.usage_service.as_ref(),
@@ -131,7 +195,30 @@ pub async fn check_usage_for_api_key(
     .await
 }
 
-async fn check_organization_usage_after_staking_preflight(
+/// Start of the calendar month (UTC) containing `now`; workspace budgets
+/// reset at this boundary.
+fn current_month_start(now: DateTime<Utc>) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(now.year(), now.month(), 1, 0, 0, 0)
+        .single()
+        .expect("the first of the month at midnight UTC is always a valid instant")
+}
+
+/// Start of the calendar month (UTC) after the one containing `now`.
+fn next_month_start(now: DateTime<Utc>) -> DateTime<Utc> {
+    let (year, month) = if now.month() == 12 {
+        (now.year() + 1, 1)
+    } else {
+        (now.year(), now.month() + 1)
+    };
+    Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0)
+        .single()
+        .expect("the first of the month at midnight UTC is always a valid instant")
+}
+
+/// Organization credit check (after a best-effort staking-farm sync). Also
+/// used outside the middleware by work that runs without a request, such as
+/// batch lines.
+pub async fn check_organization_usage_after_staking_preflight(
     staking_farm_service: &(dyn StakingFarmPreflightSync + Send + Sync),
     usage_service: &(dyn UsageServiceTrait + Send + Sync),
     organization_id: uuid::Uuid,
@@ -179,13 +266,16 @@ async fn check_organization_usage_after_staking_preflight(
             );
             Err((
                 StatusCode::PAYMENT_REQUIRED,
-                axum::Json(ErrorResponse::new(
+                axum::Json(ErrorResponse::over_limit(
                     format!(
                         "Credit limit exceeded. Spent: {}, Limit: {}. Please purchase more credits.",
                         format_amount(spent),
                         format_amount(limit)
                     ),
-                    "insufficient_credits".to_string(),
+                    INSUFFICIENT_QUOTA,
+                    "insufficient_credits",
+                    LIMIT_SCOPE_ORGANIZATION,
+                    None,
                 )),
             ))
         }
@@ -193,9 +283,12 @@ async fn check_organization_usage_after_staking_preflight(
             warn!("Organization has no credits - denying request");
             Err((
                 StatusCode::PAYMENT_REQUIRED,
-                axum::Json(ErrorResponse::new(
+                axum::Json(ErrorResponse::over_limit(
                     "No credits available. Please purchase credits to use the API.".to_string(),
-                    "no_credits".to_string(),
+                    INSUFFICIENT_QUOTA,
+                    "no_credits",
+                    LIMIT_SCOPE_ORGANIZATION,
+                    None,
                 )),
             ))
         }
@@ -203,10 +296,13 @@ async fn check_organization_usage_after_staking_preflight(
             warn!("Organization has no spending limit configured - denying request");
             Err((
                 StatusCode::PAYMENT_REQUIRED,
-                axum::Json(ErrorResponse::new(
+                axum::Json(ErrorResponse::over_limit(
                     "No spending limit configured. Please contact support to set up credits."
                         .to_string(),
-                    "no_limit_configured".to_string(),
+                    INSUFFICIENT_QUOTA,
+                    "no_limit_configured",
+                    LIMIT_SCOPE_ORGANIZATION,
+                    None,
                 )),
             ))
         }
@@ -218,7 +314,7 @@ pub async fn usage_check_middleware(
     State(state): State<UsageState>,
     request: Request,
     next: Next,
-) -> Result<Response, (StatusCode, axum::Json<ErrorResponse>)> {
+) -> Result<Response, Response> {
     let api_key = request
         .extensions()
         .get::<AuthenticatedApiKey>()
@@ -230,12 +326,36 @@ pub async fn usage_check_middleware(
                     "unauthorized".to_string(),
                 )),
             )
+                .into_response()
         })?;
 
-    check_usage_for_api_key(&state, api_key).await?;
+    check_usage_for_api_key(&state, api_key)
+        .await
+        .map_err(|(status, body)| usage_denied_response(status, body, Utc::now()))?;
     Ok(next.run(request).await)
 }
 
+/// Response for a rejected usage check. Limits that reset per period also
+/// get a `Retry-After` header counting down to `reset_at`.
+fn usage_denied_response(
+    status: StatusCode,
+    body: axum::Json<ErrorResponse>,
+    now: DateTime<Utc>,
+) -> Response {
+    match body.error.limit.as_ref().and_then(|l| l.reset_at) {
+        Some(reset_at) => {
+            let retry_after = (reset_at - now).num_seconds().max(1) as u64;
+            (
+                status,
+                [(RETRY_AFTER, HeaderValue::from(retry_after))],
+                body,
+            )
+                .into_response()
+        }
+        None => (status, body).into_response(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -364,6 +484,15 @@ mod tests {
             unimplemented!()
         }
 
+        async fn authorize_api_key_usage_access(
+            &self,
+            _workspace_id: Uuid,
+            _api_key_id: Uuid,
+            _user_id: Uuid,
+        ) -> Result<Uuid, UsageError> {
+            unimplemented!()
+        }
+
         async fn get_api_key_usage_history_with_permissions(
             &self,
             _workspace_id: Uuid,
@@ -457,4 +586,76 @@ mod tests {
         assert_eq!(usage.calls.lock().unwrap().as_slice(), &[organization_id]);
         assert_eq!(events.lock().unwrap().as_slice(), &["staking", "usage"]);
     }
+
+    #[test]
+    fn current_month_start_truncates_to_first_of_month_utc() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 31, 23, 59, 59).unwrap();
+        assert_eq!(
+            current_month_start(now),
+            Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap()
+        );
+        let first = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(current_month_start(first), first);
+    }
+
+    #[test]
+    fn next_month_start_rolls_over_the_year() {
+        let now = Utc.with_ymd_and_hms(2026, 12, 15, 8, 0, 0).unwrap();
+        assert_eq!(
+            next_month_start(now),
+            Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn organization_over_limit_reports_scope_without_reset() {
+        let staking = MockStakingFarmPreflight::default();
+        let usage = MockUsageService {
+            result: UsageCheckResult::LimitExceeded {
+                spent: 2_000_000_000,
+                limit: 1_000_000_000,
+            },
+            calls: Mutex::new(Vec::new()),
+            events: Arc::new(Mutex::new(Vec::new())),
+        };
+
+        let (status, body) =
+            check_organization_usage_after_staking_preflight(&staking, &usage, Uuid::new_v4())
+                .await
+                .unwrap_err();
+
+        assert_eq!(status, StatusCode::PAYMENT_REQUIRED);
+        assert_eq!(body.error.r#type, "insufficient_quota");
+        assert_eq!(body.error.code.as_deref(), Some("insufficient_credits"));
+        assert_eq!(
+            body.error.limit.as_ref().map(|l| l.limit_scope.as_str()),
+            Some("organization")
+        );
+        assert_eq!(body.error.limit.as_ref().and_then(|l| l.reset_at), None);
+
+        let response = usage_denied_response(status, body, Utc::now());
+        assert!(response.headers().get(RETRY_AFTER).is_none());
+    }
+
+    #[test]
+    fn periodic_limit_response_carries_retry_after_until_reset() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 31, 23, 0, 0).unwrap();
+        let body = ErrorResponse::over_limit(
+            "Workspace monthly budget exceeded".to_string(),
+            SPEND_LIMIT_EXCEEDED,
+            "workspace_budget_exceeded",
+            LIMIT_SCOPE_WORKSPACE,
+            Some(next_month_start(now)),
+        );
+
+        let response = usage_denied_response(StatusCode::PAYMENT_REQUIRED, axum::Json(body), now);
+
+        assert_eq!(response.status(), StatusCode::PAYMENT_REQUIRED);
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        assert_eq!(retry_after, Some(3600));
+    }
 }
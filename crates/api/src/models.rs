@@ -38,6 +38,8 @@ pub struct Delta {
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct ChatCompletionRequest {
+    /// May be omitted when the workspace configures a default model.
+    #[serde(default)]
     pub model: String,
     pub messages: Vec<Message>,
     pub max_tokens: Option<i64>,
@@ -186,6 +188,10 @@ pub struct ChatCompletionResponse {
 pub struct ChatChoice {
     pub index: i64,
     pub message: Message,
+    /// Token log probabilities, present when the request set `logprobs: true`.
+    /// Forwarded from the provider unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<Value>,
     pub finish_reason: Option<String>, // "stop", "length", "content_filter"
 }
 
@@ -1237,6 +1243,44 @@ pub struct ErrorDetail {
     pub r#type: String,
     pub param: Option<String>,
     pub code: Option<String>,
+    /// Machine-readable context, present only for some error kinds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<Box<ErrorDetails>>,
+    /// Which spend limit rejected the request, present only on usage-limit errors
+    #[serde(flatten, default)]
+    #[schema(inline)]
+    pub limit: Option<Box<LimitDetail>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LimitDetail {
+    /// `organization`, `workspace` or `api_key`
+    pub limit_scope: String,
+    /// When the exceeded limit resets, for limits that apply per period
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reset_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct ErrorDetails {
+    /// One entry per failed provider attempt, when every provider failed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub provider_failures: Vec<ProviderFailureDetail>,
+    /// Unsanitized provider error, only for admin callers that pass
+    /// `debug_errors=true` on a completion request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detailed_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProviderFailureDetail {
+    /// 1-based attempt number, in the order providers were tried
+    pub attempt: u32,
+    /// Upstream HTTP status, when the provider answered with one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    /// Failure category, e.g. `http_5xx`, `http_429`, `timeout`
+    pub category: String,
 }
 
 // ============================================
@@ -1378,6 +1422,26 @@ impl ChatCompletionRequest {
         Ok(())
     }
 
+    /// Fill fields the client left unset from the workspace's completion
+    /// defaults. Client-supplied values always win; `max_tokens` is also left
+    /// alone when the client sent `max_completion_tokens` instead.
+    pub fn apply_workspace_defaults(
+        &mut self,
+        defaults: &services::workspace::WorkspaceDefaultParams,
+    ) {
+        if self.model.is_empty() {
+            if let Some(model) = &defaults.model {
+                self.model = model.clone();
+            }
+        }
+        if self.temperature.is_none() {
+            self.temperature = defaults.temperature;
+        }
+        if self.max_tokens.is_none() && !self.extra.contains_key("max_completion_tokens") {
+            self.max_tokens = defaults.max_tokens;
+        }
+    }
+
     pub fn validate(&self) -> Result<(), String> {
         if self.model.is_empty() {
             return Err("model is required".to_string());
@@ -1540,6 +1604,8 @@ impl ErrorResponse {
                 r#type: error_type,
                 param: None,
                 code: None,
+                details: None,
+                limit: None,
             },
         }
     }
@@ -1551,9 +1617,34 @@ impl ErrorResponse {
                 r#type: error_type,
                 param: Some(param),
                 code: None,
+                details: None,
+                limit: None,
             },
         }
     }
+
+    pub fn with_details(mut self, details: ErrorDetails) -> Self {
+        self.error.details = Some(Box::new(details));
+        self
+    }
+
+    /// Error for a request rejected by a spend limit. `code` carries the
+    /// specific reason within `error_type`.
+    pub fn over_limit(
+        message: String,
+        error_type: &str,
+        code: &str,
+        limit_scope: &str,
+        reset_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        let mut response = Self::new(message, error_type.to_string());
+        response.error.code = Some(code.to_string());
+        response.error.limit = Some(Box::new(LimitDetail {
+            limit_scope: limit_scope.to_string(),
+            reset_at,
+        }));
+        response
+    }
 }
 
 // ============================================
@@ -2054,6 +2145,12 @@ pub struct ResponseInputItemList {
     pub first_id: String,
     pub last_id: String,
     pub has_more: bool,
+    /// Assistant output messages of the response (only with `include=output`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Vec<services::responses::models::ResponseOutputItem>>,
+    /// Tool calls made while producing the response (only with `include=tool_calls`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<services::responses::models::ResponseOutputItem>>,
 }
 
 // ============================================
@@ -2067,6 +2164,14 @@ pub struct CreateConversationRequest {
     pub metadata: Option<serde_json::Value>,
 }
 
+/// Request to fork a conversation
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct ForkConversationRequest {
+    /// Last item to copy into the fork (inclusive). Omit to copy every item.
+    #[serde(default)]
+    pub up_to_item_id: Option<String>,
+}
+
 /// Request to update a conversation
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateConversationRequest {
@@ -2419,6 +2524,7 @@ impl CreateConversationRequest {
                     MAX_METADATA_SIZE_BYTES
                 ));
             }
+            services::common::extract_metadata(metadata)?;
         }
 
         Ok(())
@@ -2438,6 +2544,7 @@ impl UpdateConversationRequest {
                     MAX_METADATA_SIZE_BYTES
                 ));
             }
+            services::common::extract_metadata(metadata)?;
         }
 
         Ok(())
@@ -2548,6 +2655,45 @@ pub struct OrganizationResponse {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Standard list envelope fields: `{ object: "list", data, has_more,
+/// first_id, last_id }`, the shape of the file list. Flattened into list
+/// responses next to their `data` array and any endpoint-specific fields.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ListMetadata {
+    /// Always `list`
+    pub object: String,
+    /// Whether more items exist after this page
+    pub has_more: bool,
+    /// ID of the first item on this page; absent when the page is empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_id: Option<String>,
+    /// ID of the last item on this page; absent when the page is empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
+}
+
+impl ListMetadata {
+    pub fn for_page<T>(items: &[T], has_more: bool, id: impl Fn(&T) -> String) -> Self {
+        Self {
+            object: "list".to_string(),
+            has_more,
+            first_id: items.first().map(&id),
+            last_id: items.last().map(&id),
+        }
+    }
+
+    /// For offset pagination: more items exist when this page ends before `total`.
+    pub fn for_offset_page<T>(
+        items: &[T],
+        offset: i64,
+        total: i64,
+        id: impl Fn(&T) -> String,
+    ) -> Self {
+        let end = offset.saturating_add(items.len() as i64);
+        Self::for_page(items, end < total, id)
+    }
+}
+
 /// Paginated organizations list response
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ListOrganizationsResponse {
@@ -2555,6 +2701,12 @@ pub struct ListOrganizationsResponse {
     pub total: i64,
     pub limit: i64,
     pub offset: i64,
+    /// Opaque cursor for the next page (pass as `after`); absent on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// Opaque cursor for the previous page (pass as `before`); absent on the first page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_cursor: Option<String>,
 }
 
 /// Member role enum for API
@@ -2709,6 +2861,56 @@ pub struct OrganizationSettingsResponse {
     pub settings: OrganizationSettings,
 }
 
+/// Request to create or retarget an organization-private model alias
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetOrganizationModelAliasRequest {
+    /// Canonical name of the active model the alias resolves to
+    pub model: String,
+}
+
+/// An organization-private model alias
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationModelAliasResponse {
+    /// Alias name usable as `model` in this organization's requests
+    pub alias: String,
+    /// Canonical model name the alias resolves to
+    pub model: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Organization-private model aliases
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ListOrganizationModelAliasesResponse {
+    pub aliases: Vec<OrganizationModelAliasResponse>,
+}
+
+/// Request to set an organization's token quota for a model and period
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetOrganizationModelQuotaRequest {
+    /// Maximum tokens (input + output) the organization may use on the model
+    /// per period; must be positive
+    pub token_limit: i64,
+}
+
+/// An organization's token quota for one model
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationModelQuotaResponse {
+    /// Canonical model name
+    pub model: String,
+    /// `day` or `month`, in UTC
+    pub period: String,
+    pub token_limit: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// An organization's per-model token quotas
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ListOrganizationModelQuotasResponse {
+    pub quotas: Vec<OrganizationModelQuotaResponse>,
+}
+
 /// Result of a single invitation attempt
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct InvitationResult {
@@ -2746,10 +2948,21 @@ pub struct PublicOrganizationMemberResponse {
 /// List organization members response with pagination
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ListOrganizationMembersResponse {
+    #[serde(flatten)]
+    pub list: ListMetadata,
+    pub data: Vec<PublicOrganizationMemberResponse>,
+    /// Same items as `data`; kept for existing consumers
+    #[schema(deprecated)]
     pub members: Vec<PublicOrganizationMemberResponse>,
     pub total: i64,
     pub limit: i64,
     pub offset: i64,
+    /// Opaque cursor for the next page (pass as `after`); absent on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// Opaque cursor for the previous page (pass as `before`); absent on the first page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_cursor: Option<String>,
 }
 
 /// Admin organization member response (for owners/admins)
@@ -3401,6 +3614,66 @@ impl ModelArchitecture {
     }
 }
 
+/// Capability flags derived from a model's admin-managed catalog fields.
+///
+/// Not stored separately: `supported_features`, `input_modalities` and
+/// `max_output_length` are the source of truth, so the flags can never drift
+/// from what the catalog advertises to OpenRouter.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelCapabilities {
+    /// Accepts `tools` / `tool_choice` (feature `tools`).
+    pub supports_tools: bool,
+    /// Accepts image content parts (input modality `image`).
+    pub supports_vision: bool,
+    /// Accepts `response_format: json_schema` (feature `structured_outputs`).
+    pub supports_json_schema: bool,
+    /// Maximum tokens per response, when the catalog defines one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<i32>,
+}
+
+impl ModelCapabilities {
+    pub fn derive(
+        input_modalities: Option<&[String]>,
+        supported_features: &[String],
+        max_output_length: Option<i32>,
+    ) -> Self {
+        let has_feature = |f: &str| supported_features.iter().any(|s| s == f);
+        Self {
+            supports_tools: has_feature("tools"),
+            supports_vision: input_modalities
+                .is_some_and(|modalities| modalities.iter().any(|m| m == "image")),
+            supports_json_schema: has_feature("structured_outputs"),
+            max_output_tokens: max_output_length,
+        }
+    }
+}
+
+/// Response for `GET /v1/models/{model_name}/capabilities`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ModelCapabilitiesResponse {
+    /// Canonical model name
+    pub model: String,
+    #[serde(flatten)]
+    pub capabilities: ModelCapabilities,
+}
+
+/// Response for `GET /v1/models/{model_name}/pricing`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ModelPricingResponse {
+    /// Canonical model name (aliases are resolved)
+    pub model: String,
+    pub currency: String,
+    pub input_cost_per_token: DecimalPrice,
+    pub output_cost_per_token: DecimalPrice,
+    pub cost_per_image: DecimalPrice,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_read_cost_per_token: Option<DecimalPrice>,
+    /// True when no price is configured for the model (every rate is zero)
+    pub pricing_unavailable: bool,
+}
+
 /// Model metadata
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ModelMetadata {
@@ -3428,6 +3701,9 @@ pub struct ModelMetadata {
     /// Whether this model supports TEE attestation
     #[serde(rename = "attestationSupported")]
     pub attestation_supported: bool,
+    /// Capability flags derived from the fields below.
+    #[serde(default)]
+    pub capabilities: ModelCapabilities,
 
     /// Model architecture (input/output modalities)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3518,6 +3794,34 @@ pub struct UpdateModelApiRequest {
     /// Required to be set explicitly when activating a zero-price model.
     #[serde(rename = "allowFree", skip_serializing_if = "Option::is_none")]
     pub allow_free: Option<bool>,
+    /// `max_tokens` applied to completions that do not set one.
+    #[serde(rename = "defaultMaxTokens", skip_serializing_if = "Option::is_none")]
+    pub default_max_tokens: Option<i32>,
+    /// Upper bound on the `max_tokens` a client may request. Larger values
+    /// are clamped to it, or rejected when `rejectOverMaxTokens` is true.
+    #[serde(rename = "maxMaxTokens", skip_serializing_if = "Option::is_none")]
+    pub max_max_tokens: Option<i32>,
+    /// Reject (400) instead of clamp when a request exceeds `maxMaxTokens`.
+    #[serde(
+        rename = "rejectOverMaxTokens",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reject_over_max_tokens: Option<bool>,
+    /// Inference request timeout in seconds for this model's endpoints.
+    /// Unset uses the global completion timeout.
+    ///
+    /// Tri-state PATCH semantics:
+    /// - omitted → leave unchanged
+    /// - `null` → clear back to the global completion timeout
+    /// - a number → set
+    #[serde(
+        rename = "requestTimeoutSecs",
+        default,
+        deserialize_with = "deserialize_nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(value_type = Option<i32>)]
+    pub request_timeout_secs: Nullable<i32>,
     pub aliases: Option<Vec<String>>,
     #[serde(rename = "ownedBy")]
     pub owned_by: Option<String>,
@@ -3620,6 +3924,40 @@ pub struct UpdateModelApiRequest {
 /// Batch update request format - Array of model name to update data
 pub type BatchUpdateModelApiRequest = std::collections::HashMap<String, UpdateModelApiRequest>;
 
+/// One field a dry-run batch upsert would change on an existing model.
+/// Prices are raw nano-USD amounts (fixed scale 9).
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ModelFieldChangeResponse {
+    pub model: String,
+    /// Field name, snake_case (e.g. `input_cost_per_token`).
+    pub field: String,
+    #[schema(value_type = Object)]
+    pub old: serde_json::Value,
+    #[schema(value_type = Object)]
+    pub new: serde_json::Value,
+}
+
+/// Response to `PATCH /v1/admin/models?dry_run=true`: what the batch would
+/// do, computed against current records. Nothing is persisted.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchUpsertDryRunResponse {
+    /// Models that do not exist yet and would be created.
+    pub would_create: Vec<String>,
+    /// Per-field changes to existing models.
+    pub would_update: Vec<ModelFieldChangeResponse>,
+    /// Existing models the batch would leave unchanged.
+    pub would_noop: Vec<String>,
+}
+
+/// Response to `PATCH /v1/admin/models`: the upserted models, or the
+/// dry-run diff when `dry_run=true`.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum BatchUpsertModelsResponse {
+    Upserted(Vec<ModelWithPricing>),
+    DryRun(BatchUpsertDryRunResponse),
+}
+
 /// Delete model request - optional reason for deletion
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct DeleteModelRequest {
@@ -4038,9 +4376,15 @@ pub struct UpdateOrganizationLimitsRequest {
 pub struct SpendLimitRequest {
     /// Amount in nano-dollars (scale 9). For example, $1.00 = 1000000000 nano-dollars.
     pub amount: i64,
+    /// `USD` (the default) or a currency with a configured exchange rate.
+    #[serde(default = "default_spend_limit_currency")]
     pub currency: String,
 }
 
+fn default_spend_limit_currency() -> String {
+    "USD".to_string()
+}
+
 /// Spend limit for API responses
 ///
 /// The system uses a fixed scale of 9 (nano-dollars = 1 billionth of a dollar).
@@ -4146,6 +4490,101 @@ pub struct GetOrganizationConcurrentLimitResponse {
     pub effective_limit: u32,
 }
 
+// ============================================
+// Organization Seat Limit API Models (Admin)
+// ============================================
+
+/// Request to set an organization's seat limit (Admin only)
+///
+/// Adding a member or accepting an invitation beyond the limit fails with
+/// `seat_limit_exceeded`. Set to null to remove the limit.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateOrganizationSeatLimitRequest {
+    /// Maximum number of members, owner included. Null means unlimited.
+    #[serde(rename = "maxMembers")]
+    pub max_members: Option<u32>,
+}
+
+/// An organization's seat limit and the seats in use
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationSeatLimitResponse {
+    #[serde(rename = "organizationId")]
+    pub organization_id: String,
+    /// Maximum number of members, owner included. Null means unlimited.
+    #[serde(rename = "maxMembers")]
+    pub max_members: Option<u32>,
+    /// Current number of members
+    #[serde(rename = "memberCount")]
+    pub member_count: i64,
+}
+
+/// Request to replace an organization's model allowlist (Admin only)
+///
+/// Entries may be canonical model names or aliases; they are stored as
+/// canonical names. Null or an empty list allows every model.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateOrganizationAllowedModelsRequest {
+    #[serde(rename = "allowedModels")]
+    pub allowed_models: Option<Vec<String>>,
+}
+
+/// An organization's model allowlist
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationAllowedModelsResponse {
+    #[serde(rename = "organizationId")]
+    pub organization_id: String,
+    /// Canonical model names the organization may call. Null means all models.
+    #[serde(rename = "allowedModels")]
+    pub allowed_models: Option<Vec<String>>,
+}
+
+/// Result of an admin-triggered provider discovery refresh
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DiscoveryRefreshResponse {
+    /// Models with at least one registered provider after the refresh
+    pub models: usize,
+    /// Distinct provider instances registered across all models
+    pub providers: usize,
+    /// How long the refresh took, in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Snapshot of the inference provider pool's routing state (Admin only)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PoolStateResponse {
+    /// Registered models, sorted by name
+    pub models: Vec<PoolModelState>,
+}
+
+/// Routing state of one model in the provider pool
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PoolModelState {
+    pub model: String,
+    /// Next round-robin offset, absent until the model has been routed to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub round_robin_index: Option<usize>,
+    /// Number of signing public keys mapped to this model's providers
+    pub pubkey_mappings: usize,
+    pub providers: Vec<PoolProviderState>,
+}
+
+/// Routing state of one provider instance
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PoolProviderState {
+    /// Stable provider identifier
+    pub id: String,
+    /// Backend host, when known (no credentials, path or query)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Provider implementation (`vllm`, `external`, `chutes`)
+    pub source: String,
+    /// Trust tier (`near`, `attested_3p`, `non_attested`)
+    pub tier: String,
+    pub consecutive_failures: u32,
+    /// Demoted behind healthy providers after repeated failures
+    pub ejected: bool,
+}
+
 // ============================================
 // File Upload Models
 // ============================================
@@ -4190,6 +4629,84 @@ pub struct FileDeleteResponse {
     pub deleted: bool,
 }
 
+// ============================================
+// Batch Models
+// ============================================
+
+/// One request line of a batch (same shape as a line of a JSONL input file)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchRequestInput {
+    /// Caller-chosen ID, unique within the batch, used to match results
+    pub custom_id: String,
+    /// Must be "POST" if given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Must equal the batch endpoint if given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Request body for the endpoint, e.g. a chat completion request
+    pub body: serde_json::Value,
+}
+
+/// Create batch request. Exactly one of `input_file_id` (a JSONL file uploaded
+/// with purpose "batch") or `requests` (inline lines) must be given.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateBatchRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_file_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requests: Option<Vec<BatchRequestInput>>,
+    /// Only "/v1/chat/completions" is supported
+    pub endpoint: String,
+    /// Only "24h" is supported
+    pub completion_window: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// Per-status line counts of a batch
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchRequestCounts {
+    pub total: i32,
+    pub completed: i32,
+    pub failed: i32,
+}
+
+/// What the synchronous endpoint returned for a batch line
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchLineResponse {
+    pub status_code: u16,
+    pub body: serde_json::Value,
+}
+
+/// Result of one batch line; `response` is null while the line is pending
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchResultLine {
+    pub custom_id: String,
+    pub status: String, // "pending", "completed" or "failed"
+    pub response: Option<BatchLineResponse>,
+}
+
+/// Batch object
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BatchObject {
+    pub id: String,
+    pub object: String, // Always "batch"
+    pub endpoint: String,
+    pub input_file_id: Option<String>,
+    pub completion_window: String,
+    pub status: String, // "validating", "in_progress" or "completed"
+    pub output_file_id: Option<String>,
+    pub created_at: i64, // Unix timestamp
+    pub in_progress_at: Option<i64>,
+    pub completed_at: Option<i64>,
+    pub request_counts: BatchRequestCounts,
+    pub metadata: Option<serde_json::Value>,
+    /// Per-line results in submission order (retrieval only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<BatchResultLine>>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4481,6 +4998,41 @@ mod tests {
         assert_eq!(req.top_p, None, "top_p must not default to Some(1.0)");
     }
 
+    #[test]
+    fn test_apply_workspace_defaults_fills_only_unset_fields() {
+        let defaults = services::workspace::WorkspaceDefaultParams {
+            model: Some("default-model".to_string()),
+            temperature: Some(0.2),
+            max_tokens: Some(64),
+        };
+
+        let mut bare: ChatCompletionRequest = serde_json::from_value(serde_json::json!({
+            "messages": [{"role": "user", "content": "hi"}],
+        }))
+        .expect("request without model should deserialize");
+        assert!(bare.validate().is_err(), "model is still required");
+        bare.apply_workspace_defaults(&defaults);
+        assert_eq!(bare.model, "default-model");
+        assert_eq!(bare.temperature, Some(0.2));
+        assert_eq!(bare.max_tokens, Some(64));
+        assert!(bare.validate().is_ok());
+
+        let mut explicit: ChatCompletionRequest = serde_json::from_value(serde_json::json!({
+            "model": "client-model",
+            "messages": [{"role": "user", "content": "hi"}],
+            "temperature": 1.5,
+            "max_completion_tokens": 10,
+        }))
+        .unwrap();
+        explicit.apply_workspace_defaults(&defaults);
+        assert_eq!(explicit.model, "client-model");
+        assert_eq!(explicit.temperature, Some(1.5));
+        assert_eq!(
+            explicit.max_tokens, None,
+            "max_completion_tokens from the client counts as an explicit limit"
+        );
+    }
+
     #[test]
     fn test_chat_completion_top_logprobs_requires_logprobs_true() {
         for logprobs in [None, Some(false)] {
@@ -4747,6 +5299,54 @@ mod tests {
         assert!(request.validate().is_ok());
     }
 
+    #[test]
+    fn test_message_content_string_round_trip() {
+        let json = serde_json::json!({"role": "user", "content": "Hello"});
+        let message: Message = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(
+            message.content,
+            Some(MessageContent::Text("Hello".to_string()))
+        );
+        let round_tripped = serde_json::to_value(&message).unwrap();
+        assert_eq!(round_tripped["content"], json["content"]);
+    }
+
+    #[test]
+    fn test_message_content_parts_round_trip() {
+        let json = serde_json::json!({
+            "role": "user",
+            "content": [
+                {"type": "text", "text": "What's in this image?"},
+                {"type": "image_url", "image_url": {"url": "https://example.com/cat.png"}, "detail": "high"},
+                {"type": "image_url", "image_url": "data:image/png;base64,iVBORw0KGgo="}
+            ]
+        });
+        let message: Message = serde_json::from_value(json.clone()).unwrap();
+        let Some(MessageContent::Parts(parts)) = &message.content else {
+            panic!("expected content parts, got {:?}", message.content);
+        };
+        assert_eq!(parts.len(), 3);
+        assert!(matches!(
+            &parts[1],
+            MessageContentPart::ImageUrl {
+                image_url: MessageImageUrl::Object { url },
+                detail: Some(detail),
+                ..
+            } if url == "https://example.com/cat.png" && detail == "high"
+        ));
+        assert!(matches!(
+            &parts[2],
+            MessageContentPart::ImageUrl {
+                image_url: MessageImageUrl::String(_),
+                ..
+            }
+        ));
+
+        // Serializing back yields the original content array unchanged.
+        let round_tripped = serde_json::to_value(&message).unwrap();
+        assert_eq!(round_tripped["content"], json["content"]);
+    }
+
     // ── max_tokens / n pre-flight validation (nearai/cloud-api #786) ──
 
     /// Build a minimal valid chat request, overriding `max_tokens` and `n`.
@@ -31,6 +31,8 @@ use utoipa::{Modify, OpenApi};
         (name = "Organization Members", description = "Organization member and invitation management"),
         (name = "Workspaces", description = "Workspace and API key management"),
         (name = "Files", description = "File upload and management"),
+        (name = "Batches", description = "Asynchronous batch chat completions"),
+        (name = "MCP Connectors", description = "Workspace MCP tool server registration and invocation"),
         (name = "Users", description = "User profile and token management"),
         (name = "Invitations", description = "Token-based invitation handling"),
         (name = "Usage", description = "Usage tracking and billing information"),
@@ -58,6 +60,8 @@ use utoipa::{Modify, OpenApi};
         // Model endpoints (public model catalog)
         crate::routes::models::list_models,
         crate::routes::models::get_model_by_name,
+        crate::routes::models::get_model_capabilities,
+        crate::routes::models::get_model_pricing,
         // Conversation endpoints
         crate::routes::conversations::create_conversation,
         crate::routes::conversations::get_conversation,
@@ -68,7 +72,9 @@ use utoipa::{Modify, OpenApi};
         crate::routes::conversations::archive_conversation,
         crate::routes::conversations::unarchive_conversation,
         crate::routes::conversations::clone_conversation,
+        crate::routes::conversations::fork_conversation,
         crate::routes::conversations::list_conversation_items,
+        crate::routes::conversations::search_conversation_items,
         crate::routes::conversations::create_conversation_items,
         // Response endpoints
         crate::routes::responses::create_response,
@@ -96,10 +102,13 @@ use utoipa::{Modify, OpenApi};
         crate::routes::workspaces::list_organization_workspaces,
         crate::routes::workspaces::get_workspace,
         crate::routes::workspaces::update_workspace,
+        crate::routes::workspaces::update_workspace_monthly_budget,
+        crate::routes::workspaces::transfer_workspace,
         crate::routes::workspaces::delete_workspace,
         crate::routes::workspaces::create_workspace_api_key,
         crate::routes::workspaces::list_workspace_api_keys,
         crate::routes::workspaces::revoke_workspace_api_key,
+        crate::routes::workspaces::revoke_all_workspace_api_keys,
         crate::routes::workspaces::update_api_key_spend_limit,
         crate::routes::workspaces::update_workspace_api_key,
         // Files endpoints
@@ -108,6 +117,9 @@ use utoipa::{Modify, OpenApi};
         crate::routes::files::get_file,
         crate::routes::files::delete_file,
         crate::routes::files::get_file_content,
+        // Batches endpoints
+        crate::routes::batches::create_batch,
+        crate::routes::batches::retrieve_batch,
         // Users endpoints
         crate::routes::users::get_current_user,
         crate::routes::users::update_current_user_profile,
@@ -126,6 +138,8 @@ use utoipa::{Modify, OpenApi};
         crate::routes::usage::get_organization_usage_history,
         crate::routes::usage::get_organization_usage_by_model,
         crate::routes::usage::get_api_key_usage_history,
+        crate::routes::usage_csv::export_organization_usage_history_csv,
+        crate::routes::usage_csv::export_api_key_usage_history_csv,
         crate::routes::usage::get_user_organization_metrics,
         crate::routes::usage::get_user_organization_timeseries,
         // Staking farm endpoints
@@ -136,8 +150,21 @@ use utoipa::{Modify, OpenApi};
         crate::routes::reporting_tokens::create_reporting_token,
         crate::routes::reporting_tokens::list_reporting_tokens,
         crate::routes::reporting_tokens::revoke_reporting_token,
+        // Organization model alias endpoints
+        crate::routes::organization_model_aliases::list_organization_model_aliases,
+        crate::routes::organization_model_aliases::set_organization_model_alias,
+        crate::routes::organization_model_aliases::delete_organization_model_alias,
+        // Organization model quota endpoints
+        crate::routes::organization_model_quotas::list_organization_model_quotas,
+        crate::routes::organization_model_quotas::set_organization_model_quota,
+        crate::routes::organization_model_quotas::delete_organization_model_quota,
         crate::routes::reporting_usage::export::export_usage,
         crate::routes::reporting_usage::summary::summary_usage,
+        // MCP connector endpoints
+        crate::routes::mcp_connectors::create_mcp_connector,
+        crate::routes::mcp_connectors::list_mcp_connectors,
+        crate::routes::mcp_connectors::delete_mcp_connector,
+        crate::routes::mcp_connectors::invoke_mcp_tool,
         // Feature request endpoints
         crate::routes::feature_requests::submit_feature_request,
         crate::routes::feature_requests::list_admin_feature_requests,
@@ -164,6 +191,12 @@ use utoipa::{Modify, OpenApi};
         crate::routes::staking_farm::sync_admin_organization_staking_farm,
         crate::routes::admin::update_organization_concurrent_limit,
         crate::routes::admin::get_organization_concurrent_limit,
+        crate::routes::admin::update_organization_seat_limit,
+        crate::routes::admin::get_organization_seat_limit,
+        crate::routes::admin::update_organization_allowed_models,
+        crate::routes::admin::get_organization_allowed_models,
+        crate::routes::admin::refresh_discovery,
+        crate::routes::admin::get_pool_state,
         crate::routes::admin::get_organization_metrics,
         crate::routes::admin::get_platform_metrics,
         crate::routes::admin::get_organization_timeseries,
@@ -186,15 +219,23 @@ use utoipa::{Modify, OpenApi};
         crate::routes::admin::update_service,
         // Health check endpoint
         crate::routes::health::health_check,
+        crate::routes::health::liveness,
+        crate::routes::health::readiness,
         // Attestation endpoints
         crate::routes::attestation::signature::get_signature,
+        crate::routes::attestation::signature::verify_signature,
         crate::routes::attestation::report::get_attestation_report,
+        crate::routes::attestation::report::get_attestation_quote,
+        crate::routes::attestation::verify_model::verify_model_attestation,
         crate::routes::attestation::ita_token::get_ita_token,
     ),
     components(
         schemas(
             // Health check models
             crate::routes::health::HealthResponse,
+            crate::routes::health::ReadinessResponse,
+            crate::routes::health::ReadinessChecks,
+            crate::routes::health::DependencyStatus,
             // Core API models
             ChatCompletionRequest, ChatCompletionResponse, Message, CompletionUsage,
             CompletionRequest, CompletionPrompt, StopSequences, CompletionResponse,
@@ -216,6 +257,10 @@ use utoipa::{Modify, OpenApi};
             // Workspace models
             crate::routes::workspaces::CreateWorkspaceRequest,
             crate::routes::workspaces::UpdateWorkspaceRequest,
+            crate::routes::workspaces::WorkspaceDefaultParams,
+            crate::routes::workspaces::UpdateWorkspaceMonthlyBudgetRequest,
+            crate::routes::workspaces::TransferWorkspaceRequest,
+            crate::routes::workspaces::RevokeAllApiKeysResponse,
             crate::routes::workspaces::WorkspaceResponse,
             // Organization Members models
             AddOrganizationMemberRequest,
@@ -244,13 +289,18 @@ use utoipa::{Modify, OpenApi};
             AdminUserResponse,
             crate::routes::users::UpdateUserProfileRequest,
             // Conversation models
-            CreateConversationRequest, ConversationObject,
+            CreateConversationRequest, ForkConversationRequest, ConversationObject,
             UpdateConversationRequest, ConversationDeleteResult, ConversationItemList,
             // Response models
             CreateResponseRequest, ResponseObject,
             // Attestation models
             crate::routes::attestation::SignatureResponse,
+            crate::routes::attestation::SignatureVerificationResponse,
             crate::routes::attestation::AttestationResponse,
+            crate::routes::attestation::VerifyModelAttestationRequest,
+            crate::routes::attestation::VerifyModelAttestationResponse,
+            crate::routes::attestation::ProviderAttestationResult,
+            crate::routes::attestation::ModelAttestationSummary,
             crate::routes::attestation::ItaTokenItem,
             crate::routes::attestation::ItaModelTokenItem,
             crate::routes::attestation::ItaModelAliasResolved,
@@ -261,7 +311,8 @@ use utoipa::{Modify, OpenApi};
             crate::routes::attestation::QuoteResponse,
             // Model pricing models
             ModelListResponse, ModelWithPricing, AdminModelListResponse, AdminModelWithPricing,
-            DecimalPrice, DecimalPriceRequest, ModelMetadata,
+            BatchUpsertModelsResponse, BatchUpsertDryRunResponse, ModelFieldChangeResponse,
+            DecimalPrice, DecimalPriceRequest, ModelMetadata, ModelCapabilities, ModelCapabilitiesResponse, ModelPricingResponse,
             ServiceResponse, ServiceListResponse,
             AdminServiceResponse, AdminServiceListResponse, CreateServiceRequest, UpdateServiceRequest,
             UpdateModelApiRequest, ModelHistoryEntry, ModelHistoryResponse,
@@ -276,6 +327,12 @@ use utoipa::{Modify, OpenApi};
             // Organization concurrent limit models (Admin)
             UpdateOrganizationConcurrentLimitRequest, UpdateOrganizationConcurrentLimitResponse,
             GetOrganizationConcurrentLimitResponse,
+            // Organization seat limit models (Admin)
+            UpdateOrganizationSeatLimitRequest, OrganizationSeatLimitResponse,
+            // Organization model allowlist models (Admin)
+            UpdateOrganizationAllowedModelsRequest, OrganizationAllowedModelsResponse,
+            // Provider discovery models (Admin)
+            DiscoveryRefreshResponse, PoolStateResponse, PoolModelState, PoolProviderState,
             // Invitation email delivery models (Admin)
             AdminInvitationEmailDeliveryResponse, ListAdminInvitationEmailDeliveriesResponse,
             AdminInvitationEmailResendResultResponse,
@@ -307,6 +364,14 @@ use utoipa::{Modify, OpenApi};
             crate::routes::reporting_tokens::CreateReportingTokenResponse,
             crate::routes::reporting_tokens::ReportingTokenResponse,
             crate::routes::reporting_tokens::ListReportingTokensResponse,
+            // Organization model alias models
+            SetOrganizationModelAliasRequest,
+            OrganizationModelAliasResponse,
+            ListOrganizationModelAliasesResponse,
+            // Organization model quota models
+            SetOrganizationModelQuotaRequest,
+            OrganizationModelQuotaResponse,
+            ListOrganizationModelQuotasResponse,
             crate::routes::reporting_usage::ReportingUsageSource,
             crate::routes::reporting_usage::ReportingUsageRowSource,
             crate::routes::reporting_usage::ReportingUsageExportResponse,
@@ -321,6 +386,14 @@ use utoipa::{Modify, OpenApi};
             crate::routes::reporting_usage::ReportingModelSummary,
             crate::routes::reporting_usage::ReportingServiceSummary,
             crate::routes::reporting_usage::ReportingDaySummary,
+            // MCP connector models
+            crate::routes::mcp_connectors::McpConnectorAuthRequest,
+            crate::routes::mcp_connectors::CreateMcpConnectorRequest,
+            crate::routes::mcp_connectors::McpConnectorResponse,
+            crate::routes::mcp_connectors::McpConnectorListResponse,
+            crate::routes::mcp_connectors::McpConnectorDeleteResponse,
+            crate::routes::mcp_connectors::InvokeMcpToolRequest,
+            crate::routes::mcp_connectors::InvokeMcpToolResponse,
             // Feature request models
             crate::routes::feature_requests::FeatureRequestKind,
             crate::routes::feature_requests::SubmitFeatureRequest,
@@ -337,6 +410,11 @@ use utoipa::{Modify, OpenApi};
             crate::routes::billing::RequestCost,
             // File models
             FileUploadResponse, ExpiresAfter, FileListResponse, FileDeleteResponse,
+            // Standard list envelope
+            ListMetadata,
+            // Batch models
+            CreateBatchRequest, BatchRequestInput, BatchObject, BatchRequestCounts,
+            BatchResultLine, BatchLineResponse,
             // Platform Stats analytics models
             services::admin::PlatformMetrics,
             services::admin::PlatformProviderUsage,
@@ -7,19 +7,23 @@ use crate::models::{
     AdminAccessTokenResponse, AdminInvitationEmailResendResultResponse, AdminModelListResponse,
     AdminModelWithPricing, AdminOrganizationMemberResponse, AdminOrganizationResponse,
     AdminServiceResponse, AdminUserOrganizationDetails, AdminUserResponse,
-    BatchUpdateModelApiRequest, CreateAdminAccessTokenRequest, CreateServiceRequest, CreditType,
-    DecimalPrice, DecimalPriceRequest, DeleteAdminAccessTokenRequest, DeleteModelRequest,
-    DeprecateModelRequest, DeprecateModelResponse, ErrorResponse,
+    BatchUpdateModelApiRequest, BatchUpsertDryRunResponse, BatchUpsertModelsResponse,
+    CreateAdminAccessTokenRequest, CreateServiceRequest, CreditType, DecimalPrice,
+    DecimalPriceRequest, DeleteAdminAccessTokenRequest, DeleteModelRequest, DeprecateModelRequest,
+    DeprecateModelResponse, DiscoveryRefreshResponse, ErrorResponse,
     GetOrganizationConcurrentLimitResponse, ListAdminInvitationEmailDeliveriesResponse,
     ListAdminOrganizationMembersResponse, ListOrganizationsAdminResponse,
     ListPricingChangesResponse, ListUsersResponse, MemberRole, ModelArchitecture,
-    ModelDeprecationConfirmResponse, ModelDeprecationPreviewResponse, ModelDeprecationRequest,
-    ModelHistoryEntry, ModelHistoryResponse, ModelMetadata, ModelWithPricing,
-    OrgLimitsHistoryEntry, OrgLimitsHistoryResponse, OrganizationUsage, PricingChangeBatchRequest,
+    ModelCapabilities, ModelDeprecationConfirmResponse, ModelDeprecationPreviewResponse,
+    ModelDeprecationRequest, ModelFieldChangeResponse, ModelHistoryEntry, ModelHistoryResponse,
+    ModelMetadata, ModelWithPricing, OrgLimitsHistoryEntry, OrgLimitsHistoryResponse,
+    OrganizationAllowedModelsResponse, OrganizationSeatLimitResponse, OrganizationUsage,
+    PoolModelState, PoolProviderState, PoolStateResponse, PricingChangeBatchRequest,
     PricingChangeConfirmResponse, PricingChangeModelPreviewDto, PricingChangePreviewResponse,
     PricingFieldUpdates, PricingFields, ScheduledPricingChangeDto, SpendLimit,
-    UpdateOrganizationConcurrentLimitRequest, UpdateOrganizationConcurrentLimitResponse,
-    UpdateOrganizationLimitsRequest, UpdateOrganizationLimitsResponse, UpdateServiceRequest,
+    UpdateOrganizationAllowedModelsRequest, UpdateOrganizationConcurrentLimitRequest,
+    UpdateOrganizationConcurrentLimitResponse, UpdateOrganizationLimitsRequest,
+    UpdateOrganizationLimitsResponse, UpdateOrganizationSeatLimitRequest, UpdateServiceRequest,
 };
 use crate::routes::common::format_amount;
 use crate::routes::usage::{compute_organization_balance_response, OrganizationBalanceResponse};
@@ -164,6 +168,7 @@ pub struct AdminAppState {
     pub usage_service: Arc<dyn UsageServiceTrait + Send + Sync>,
     pub staking_farm_service: Arc<services::staking_farm::StakingFarmService>,
     pub config: Arc<ApiConfig>,
+    pub page_size_limits: crate::routes::common::PageSizeLimits,
     pub admin_access_token_repository: Arc<database::repositories::AdminAccessTokenRepository>,
     pub inference_provider_pool: Arc<services::inference_provider_pool::InferenceProviderPool>,
     pub github_dispatcher: Arc<dyn GitHubDispatcher>,
@@ -181,17 +186,29 @@ fn bad_request(
     )
 }
 
+#[derive(Debug, serde::Deserialize)]
+pub struct BatchUpsertModelsQueryParams {
+    /// Validate and diff against current records without persisting.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
 /// Batch upsert models metadata (Admin only)
 ///
 /// Upserts (inserts or updates) pricing and metadata for one or more models. Only authenticated admins can perform this operation.
 /// The body should be an array of objects where each key is a model name and the value is the model data.
+/// With `dry_run=true` the batch is validated and diffed against current records instead, and a
+/// `BatchUpsertDryRunResponse` is returned; nothing is persisted and no providers are (re)registered.
 #[utoipa::path(
     patch,
     path = "/v1/admin/models",
     tag = "Admin",
     request_body = BatchUpdateModelApiRequest,
+    params(
+        ("dry_run" = Option<bool>, Query, description = "Return the diff (BatchUpsertDryRunResponse) without persisting (default: false)")
+    ),
     responses(
-        (status = 200, description = "Models upserted successfully", body = Vec<ModelWithPricing>),
+        (status = 200, description = "Models upserted successfully, or the dry-run diff (BatchUpsertDryRunResponse) when dry_run=true", body = BatchUpsertModelsResponse),
         (status = 400, description = "Invalid request", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
@@ -203,9 +220,11 @@ fn bad_request(
 pub async fn batch_upsert_models(
     State(app_state): State<AdminAppState>,
     Extension(admin_user): Extension<AdminUser>, // Require admin auth
+    Query(params): Query<BatchUpsertModelsQueryParams>,
     ResponseJson(batch_request): ResponseJson<BatchUpdateModelApiRequest>,
-) -> Result<ResponseJson<Vec<ModelWithPricing>>, (StatusCode, ResponseJson<ErrorResponse>)> {
+) -> Result<ResponseJson<BatchUpsertModelsResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
     debug!(
+        dry_run = params.dry_run,
         "Batch upsert models request with {} model(s)",
         batch_request.len()
     );
@@ -279,6 +298,34 @@ pub async fn batch_upsert_models(
                 ));
             }
         }
+        for (field, value) in [
+            ("defaultMaxTokens", request.default_max_tokens),
+            ("maxMaxTokens", request.max_max_tokens),
+            ("requestTimeoutSecs", request.request_timeout_secs.flatten()),
+        ] {
+            if value.is_some_and(|v| v <= 0) {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    ResponseJson(ErrorResponse::new(
+                        format!("model '{model_name}': {field} must be positive"),
+                        "invalid_request".to_string(),
+                    )),
+                ));
+            }
+        }
+        if let (Some(default), Some(max)) = (request.default_max_tokens, request.max_max_tokens) {
+            if default > max {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    ResponseJson(ErrorResponse::new(
+                        format!(
+                            "model '{model_name}': defaultMaxTokens must not exceed maxMaxTokens"
+                        ),
+                        "invalid_request".to_string(),
+                    )),
+                ));
+            }
+        }
         if let Some(params) = &request.supported_sampling_parameters {
             for p in params {
                 if !VALID_SAMPLING_PARAMS.contains(&p.as_str()) {
@@ -376,7 +423,7 @@ pub async fn batch_upsert_models(
     // Convert API request to service request
     // Note: Default owned_by value is applied in the repository layer during INSERT,
     // not here, so we can distinguish between CREATE (apply default) and UPDATE (preserve old)
-    let models = batch_request
+    let models: services::admin::BatchUpdateModelAdminRequest = batch_request
         .iter()
         .map(|(model_name, request)| {
             (
@@ -398,6 +445,10 @@ pub async fn batch_upsert_models(
                     verifiable: request.verifiable,
                     is_active: request.is_active,
                     allow_free: request.allow_free,
+                    default_max_tokens: request.default_max_tokens,
+                    max_max_tokens: request.max_max_tokens,
+                    reject_over_max_tokens: request.reject_over_max_tokens,
+                    request_timeout_secs: request.request_timeout_secs,
                     aliases: request.aliases.clone(),
                     owned_by: request.owned_by.clone(),
                     provider_type: request.provider_type.clone(),
@@ -434,33 +485,40 @@ pub async fn batch_upsert_models(
         })
         .collect();
 
+    if params.dry_run {
+        let preview = app_state
+            .admin_service
+            .preview_batch_upsert_models(&models)
+            .await
+            .map_err(|e| {
+                error!("Failed to preview model upsert");
+                map_batch_upsert_error(e)
+            })?;
+        return Ok(ResponseJson(BatchUpsertModelsResponse::DryRun(
+            BatchUpsertDryRunResponse {
+                would_create: preview.would_create,
+                would_update: preview
+                    .would_update
+                    .into_iter()
+                    .map(|change| ModelFieldChangeResponse {
+                        model: change.model_name,
+                        field: change.field,
+                        old: change.old,
+                        new: change.new,
+                    })
+                    .collect(),
+                would_noop: preview.would_noop,
+            },
+        )));
+    }
+
     let updated_models = app_state
         .admin_service
         .batch_upsert_models(models)
         .await
         .map_err(|e| {
             error!("Failed to upsert models");
-            match e {
-                services::admin::AdminError::ModelNotFound(msg) => (
-                    StatusCode::NOT_FOUND,
-                    ResponseJson(ErrorResponse::new(msg, "model_not_found".to_string())),
-                ),
-                services::admin::AdminError::InvalidPricing(msg) => (
-                    StatusCode::BAD_REQUEST,
-                    ResponseJson(ErrorResponse::new(msg, "invalid_pricing".to_string())),
-                ),
-                services::admin::AdminError::Unauthorized(msg) => (
-                    StatusCode::UNAUTHORIZED,
-                    ResponseJson(ErrorResponse::new(msg, "unauthorized".to_string())),
-                ),
-                _ => (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    ResponseJson(ErrorResponse::new(
-                        format!("Failed to upsert models, error: {e:?}"),
-                        "internal_server_error".to_string(),
-                    )),
-                ),
-            }
+            map_batch_upsert_error(e)
         })?;
 
     // Update providers at runtime so changes take effect without server restart.
@@ -512,35 +570,40 @@ pub async fn batch_upsert_models(
     // without provider_config) must not re-register the model with its
     // long-context tier or declared capacities missing; the DB row is the
     // source of truth the periodic refresh would converge to anyway.
-    let inference_url_models: Vec<(String, String, Option<u32>)> = batch_request
-        .iter()
-        .filter_map(|(model_name, request)| {
-            let merged = updated_models.get(model_name)?;
-            let is_active = merged.is_active;
-            let is_external = merged.provider_type == "external";
-            // Only re-register when the PATCH touched something
-            // registration-relevant, mirroring the unregister trigger above
-            // (plus provider_config, which now carries routing tiers).
-            let touches_registration = request.provider_type.is_some()
-                || request.inference_url.is_some()
-                || request.provider_config.is_some()
-                || request.is_active.is_some()
-                || request.context_length.is_some();
-            if is_active && !is_external && touches_registration {
-                merged.inference_url.clone().map(|url| {
-                    services::inference_provider_pool::expand_inference_endpoints(
-                        model_name,
-                        &url,
-                        u32::try_from(merged.context_length).ok(),
-                        merged.provider_config.as_ref(),
-                    )
-                })
-            } else {
-                None
-            }
-        })
-        .flatten()
-        .collect();
+    let inference_url_models: Vec<services::inference_provider_pool::InferenceEndpoint> =
+        batch_request
+            .iter()
+            .filter_map(|(model_name, request)| {
+                let merged = updated_models.get(model_name)?;
+                let is_active = merged.is_active;
+                let is_external = merged.provider_type == "external";
+                // Only re-register when the PATCH touched something
+                // registration-relevant, mirroring the unregister trigger above
+                // (plus provider_config, which now carries routing tiers).
+                let touches_registration = request.provider_type.is_some()
+                    || request.inference_url.is_some()
+                    || request.provider_config.is_some()
+                    || request.is_active.is_some()
+                    || request.context_length.is_some()
+                    || request.request_timeout_secs.is_some();
+                if is_active && !is_external && touches_registration {
+                    merged.inference_url.clone().map(|url| {
+                        services::inference_provider_pool::expand_inference_endpoints(
+                            model_name,
+                            &url,
+                            u32::try_from(merged.context_length).ok(),
+                            merged.provider_config.as_ref(),
+                            merged
+                                .request_timeout_secs
+                                .and_then(|secs| u32::try_from(secs).ok()),
+                        )
+                    })
+                } else {
+                    None
+                }
+            })
+            .flatten()
+            .collect();
 
     if !inference_url_models.is_empty() {
         tracing::info!(
@@ -658,6 +721,11 @@ pub async fn batch_upsert_models(
                     updated_model.provider_config,
                 ),
                 attestation_supported: updated_model.attestation_supported,
+                capabilities: ModelCapabilities::derive(
+                    updated_model.input_modalities.as_deref(),
+                    &updated_model.supported_features,
+                    updated_model.max_output_length,
+                ),
                 architecture: ModelArchitecture::from_options(
                     updated_model.input_modalities,
                     updated_model.output_modalities,
@@ -679,7 +747,35 @@ pub async fn batch_upsert_models(
         })
         .collect();
 
-    Ok(ResponseJson(api_models))
+    Ok(ResponseJson(BatchUpsertModelsResponse::Upserted(
+        api_models,
+    )))
+}
+
+fn map_batch_upsert_error(
+    e: services::admin::AdminError,
+) -> (StatusCode, ResponseJson<ErrorResponse>) {
+    match e {
+        services::admin::AdminError::ModelNotFound(msg) => (
+            StatusCode::NOT_FOUND,
+            ResponseJson(ErrorResponse::new(msg, "model_not_found".to_string())),
+        ),
+        services::admin::AdminError::InvalidPricing(msg) => (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse::new(msg, "invalid_pricing".to_string())),
+        ),
+        services::admin::AdminError::Unauthorized(msg) => (
+            StatusCode::UNAUTHORIZED,
+            ResponseJson(ErrorResponse::new(msg, "unauthorized".to_string())),
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ResponseJson(ErrorResponse::new(
+                format!("Failed to upsert models, error: {e:?}"),
+                "internal_server_error".to_string(),
+            )),
+        ),
+    }
 }
 
 /// List all models (Admin only)
@@ -709,16 +805,20 @@ pub async fn list_models(
     Extension(_admin_user): Extension<AdminUser>,
     axum::extract::Query(params): axum::extract::Query<ListModelsQueryParams>,
 ) -> Result<ResponseJson<AdminModelListResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
 
     debug!(
         "List models request with limit={}, offset={}, include_inactive={}",
-        params.limit, params.offset, params.include_inactive
+        limit, params.offset, params.include_inactive
     );
 
     let (models, total) = app_state
         .admin_service
-        .list_models(params.include_inactive, params.limit, params.offset)
+        .list_models(params.include_inactive, limit, params.offset)
         .await
         .map_err(|e| {
             error!("Failed to list models");
@@ -764,6 +864,11 @@ pub async fn list_models(
                     model.provider_config,
                 ),
                 attestation_supported: model.attestation_supported,
+                capabilities: ModelCapabilities::derive(
+                    model.input_modalities.as_deref(),
+                    &model.supported_features,
+                    model.max_output_length,
+                ),
                 architecture: ModelArchitecture::from_options(
                     model.input_modalities,
                     model.output_modalities,
@@ -788,7 +893,7 @@ pub async fn list_models(
     let response = AdminModelListResponse {
         models: api_models,
         total,
-        limit: params.limit,
+        limit,
         offset: params.offset,
     };
 
@@ -827,16 +932,20 @@ pub async fn get_model_history(
     Extension(_admin_user): Extension<AdminUser>, // Require admin auth
     axum::extract::Query(params): axum::extract::Query<ModelHistoryQueryParams>,
 ) -> Result<ResponseJson<ModelHistoryResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
 
     debug!(
         "Get model history request for model: {}, limit={}, offset={}",
-        model_name, params.limit, params.offset
+        model_name, limit, params.offset
     );
 
     let (history, total) = app_state
         .admin_service
-        .get_model_history(&model_name, params.limit, params.offset)
+        .get_model_history(&model_name, limit, params.offset)
         .await
         .map_err(|e| {
             error!("Failed to get model history");
@@ -921,7 +1030,7 @@ pub async fn get_model_history(
         model_name,
         history: history_entries,
         total,
-        limit: params.limit,
+        limit,
         offset: params.offset,
     };
 
@@ -1089,7 +1198,11 @@ pub async fn get_organization_limits_history(
     Extension(_admin_user): Extension<AdminUser>, // Require admin auth
     axum::extract::Query(params): axum::extract::Query<OrgLimitsHistoryQueryParams>,
 ) -> Result<ResponseJson<OrgLimitsHistoryResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
 
     let organization_uuid = match uuid::Uuid::parse_str(&org_id) {
         Ok(id) => id,
@@ -1106,12 +1219,12 @@ pub async fn get_organization_limits_history(
 
     debug!(
         "Get limits history for organization_id={}, limit={}, offset={}",
-        org_id, params.limit, params.offset
+        org_id, limit, params.offset
     );
 
     let (history, total) = app_state
         .admin_service
-        .get_organization_limits_history(organization_uuid, params.limit, params.offset)
+        .get_organization_limits_history(organization_uuid, limit, params.offset)
         .await
         .map_err(|e| match e {
             services::admin::AdminError::OrganizationNotFound(msg) => {
@@ -1179,7 +1292,7 @@ pub async fn get_organization_limits_history(
     let response = OrgLimitsHistoryResponse {
         history: entries,
         total,
-        limit: params.limit,
+        limit,
         offset: params.offset,
     };
 
@@ -1454,6 +1567,11 @@ pub async fn deprecate_model(
             provider_type: m.provider_type,
             provider_config: crate::routes::common::redact_provider_config(m.provider_config),
             attestation_supported: m.attestation_supported,
+            capabilities: ModelCapabilities::derive(
+                m.input_modalities.as_deref(),
+                &m.supported_features,
+                m.max_output_length,
+            ),
             architecture: ModelArchitecture::from_options(m.input_modalities, m.output_modalities),
             inference_url: m.inference_url,
             hugging_face_id: m.hugging_face_id,
@@ -1968,11 +2086,15 @@ pub async fn list_users(
     Extension(_admin_user): Extension<AdminUser>, // Require admin auth
     axum::extract::Query(params): axum::extract::Query<ListUsersQueryParams>,
 ) -> Result<ResponseJson<ListUsersResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
 
     debug!(
         "List users request with limit={}, offset={}, include_organizations={}, has_search={}, is_active={:?}, has_search_by_name={}",
-        params.limit,
+        limit,
         params.offset,
         params.include_organizations,
         params
@@ -1993,7 +2115,7 @@ pub async fn list_users(
         let (users_with_orgs, total) = app_state
             .admin_service
             .list_users_with_organizations(
-                params.limit,
+                limit,
                 params.offset,
                 params.search.clone(),
                 params.is_active,
@@ -2063,7 +2185,7 @@ pub async fn list_users(
         let (users, total) = app_state
             .admin_service
             .list_users(
-                params.limit,
+                limit,
                 params.offset,
                 params.search.clone(),
                 params.is_active,
@@ -2109,7 +2231,7 @@ pub async fn list_users(
     let response = ListUsersResponse {
         users: user_responses,
         total,
-        limit: params.limit,
+        limit,
         offset: params.offset,
     };
 
@@ -2143,16 +2265,20 @@ pub async fn list_organizations(
     axum::extract::Query(params): axum::extract::Query<ListOrganizationsQueryParams>,
 ) -> Result<ResponseJson<ListOrganizationsAdminResponse>, (StatusCode, ResponseJson<ErrorResponse>)>
 {
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
 
     debug!(
         "List organizations request with limit={}, offset={}",
-        params.limit, params.offset
+        limit, params.offset
     );
 
     let (organizations, total) = app_state
         .admin_service
-        .list_organizations(params.limit, params.offset)
+        .list_organizations(limit, params.offset)
         .await
         .map_err(|e| {
             error!("Failed to list organizations: {:?}", e);
@@ -2179,7 +2305,7 @@ pub async fn list_organizations(
     let response = ListOrganizationsAdminResponse {
         organizations: org_responses,
         total,
-        limit: params.limit,
+        limit,
         offset: params.offset,
     };
 
@@ -2338,16 +2464,20 @@ pub async fn list_organization_members(
     ResponseJson<ListAdminOrganizationMembersResponse>,
     (StatusCode, ResponseJson<ErrorResponse>),
 > {
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
 
     debug!(
         "List organization members request: org_id={}, limit={}, offset={}",
-        org_id, params.limit, params.offset
+        org_id, limit, params.offset
     );
 
     let (members, total) = app_state
         .admin_service
-        .list_organization_members(org_id, params.limit, params.offset)
+        .list_organization_members(org_id, limit, params.offset)
         .await
         .map_err(|e| match e {
             services::admin::AdminError::OrganizationNotFound(_) => {
@@ -2408,7 +2538,7 @@ pub async fn list_organization_members(
     Ok(ResponseJson(ListAdminOrganizationMembersResponse {
         members: member_responses,
         total,
-        limit: params.limit,
+        limit,
         offset: params.offset,
     }))
 }
@@ -2448,11 +2578,15 @@ pub async fn list_invitation_email_deliveries(
     ResponseJson<ListAdminInvitationEmailDeliveriesResponse>,
     (StatusCode, ResponseJson<ErrorResponse>),
 > {
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
 
     debug!(
         "List invitation email deliveries request with limit={}, offset={}",
-        params.limit, params.offset
+        limit, params.offset
     );
 
     let filters = services::organization::InvitationEmailDeliveryFilters {
@@ -2472,7 +2606,7 @@ pub async fn list_invitation_email_deliveries(
 
     let (deliveries, total) = app_state
         .organization_service
-        .list_invitation_email_deliveries(filters, params.limit, params.offset)
+        .list_invitation_email_deliveries(filters, limit, params.offset)
         .await
         .map_err(|e| match e {
             services::organization::OrganizationError::InvalidParams(msg) => (
@@ -2497,7 +2631,7 @@ pub async fn list_invitation_email_deliveries(
             .map(services_invitation_email_delivery_to_api)
             .collect(),
         total,
-        limit: params.limit,
+        limit,
         offset: params.offset,
     };
 
@@ -2792,18 +2926,22 @@ pub async fn list_admin_access_tokens(
     Extension(admin_user): Extension<AdminUser>, // Require admin auth
     axum::extract::Query(params): axum::extract::Query<ListUsersQueryParams>,
 ) -> Result<ResponseJson<serde_json::Value>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
 
     debug!(
         admin_user_id = %admin_user.0.id,
-        limit = params.limit,
+        limit = limit,
         offset = params.offset,
         "List admin access tokens request"
     );
 
     match app_state
         .admin_access_token_repository
-        .list(params.limit, params.offset)
+        .list(limit, params.offset)
         .await
     {
         Ok(tokens) => {
@@ -2815,7 +2953,7 @@ pub async fn list_admin_access_tokens(
 
             let response = serde_json::json!({
                 "data": tokens,
-                "limit": params.limit,
+                "limit": limit,
                 "offset": params.offset,
                 "total": total
             });
@@ -2930,8 +3068,7 @@ pub async fn delete_admin_access_token(
 
 #[derive(Debug, serde::Deserialize)]
 pub struct ListUsersQueryParams {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     #[serde(default)]
@@ -2943,16 +3080,14 @@ pub struct ListUsersQueryParams {
 
 #[derive(Debug, serde::Deserialize)]
 pub struct ListOrganizationsQueryParams {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
 }
 
 #[derive(Debug, serde::Deserialize)]
 pub struct ListInvitationEmailDeliveriesQueryParams {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     pub organization_id: Option<Uuid>,
@@ -2965,8 +3100,7 @@ pub struct ListInvitationEmailDeliveriesQueryParams {
 
 #[derive(Debug, serde::Deserialize)]
 pub struct ListModelsQueryParams {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     #[serde(default)]
@@ -2975,16 +3109,14 @@ pub struct ListModelsQueryParams {
 
 #[derive(Debug, serde::Deserialize)]
 pub struct ModelHistoryQueryParams {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
 }
 
 #[derive(Debug, serde::Deserialize)]
 pub struct OrgLimitsHistoryQueryParams {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
 }
@@ -3273,8 +3405,7 @@ pub struct ModelRevenueQueryParams {
     pub start: Option<String>,
     /// End of time range (ISO 8601). Defaults to now.
     pub end: Option<String>,
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     /// Filter by verifiable (TEE) models only / non-verifiable only.
@@ -3322,11 +3453,16 @@ pub async fn get_model_revenue(
     ResponseJson<services::admin::ModelRevenueReport>,
     (StatusCode, ResponseJson<ErrorResponse>),
 > {
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
+
     debug!(
         "Get platform model revenue request, start: {:?}, end: {:?}, limit: {}, offset: {}",
-        params.start, params.end, params.limit, params.offset
+        params.start, params.end, limit, params.offset
     );
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
     let (start, end) = crate::routes::common::parse_metrics_range(
         params.start.as_deref(),
         params.end.as_deref(),
@@ -3353,7 +3489,7 @@ pub async fn get_model_revenue(
             provider_type: params.provider_type,
             model_search: params.model_search,
             sort,
-            limit: params.limit,
+            limit,
             offset: params.offset,
         })
         .await
@@ -3377,8 +3513,7 @@ pub struct OrgRevenueQueryParams {
     pub start: Option<String>,
     /// End of time range (ISO 8601). Defaults to now.
     pub end: Option<String>,
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     /// Filter to current paying (true) / non-paying (false) orgs.
@@ -3424,11 +3559,16 @@ pub async fn get_org_revenue(
     ResponseJson<services::admin::OrgRevenueReport>,
     (StatusCode, ResponseJson<ErrorResponse>),
 > {
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
+
     debug!(
         "Get platform org revenue request, start: {:?}, end: {:?}, limit: {}, offset: {}",
-        params.start, params.end, params.limit, params.offset
+        params.start, params.end, limit, params.offset
     );
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
     let (start, end) = crate::routes::common::parse_metrics_range(
         params.start.as_deref(),
         params.end.as_deref(),
@@ -3446,7 +3586,7 @@ pub async fn get_org_revenue(
             paying: params.paying,
             search: params.search,
             sort,
-            limit: params.limit,
+            limit,
             offset: params.offset,
         })
         .await
@@ -3995,6 +4135,406 @@ pub async fn get_organization_concurrent_limit(
     Ok(ResponseJson(response))
 }
 
+fn seat_limit_error_response(
+    error: services::admin::AdminError,
+    action: &str,
+) -> (StatusCode, ResponseJson<ErrorResponse>) {
+    match error {
+        services::admin::AdminError::OrganizationNotFound(msg) => (
+            StatusCode::NOT_FOUND,
+            ResponseJson(ErrorResponse::new(
+                msg,
+                "organization_not_found".to_string(),
+            )),
+        ),
+        services::admin::AdminError::InvalidLimits(msg) => (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse::new(msg, "invalid_limits".to_string())),
+        ),
+        _ => {
+            error!("Failed to {action} organization seat limit");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse::new(
+                    format!("Failed to {action} seat limit"),
+                    "internal_server_error".to_string(),
+                )),
+            )
+        }
+    }
+}
+
+fn seat_limit_response(
+    organization_id: uuid::Uuid,
+    seat_limit: services::admin::OrganizationSeatLimit,
+) -> OrganizationSeatLimitResponse {
+    OrganizationSeatLimitResponse {
+        organization_id: organization_id.to_string(),
+        max_members: seat_limit.max_members,
+        member_count: seat_limit.member_count,
+    }
+}
+
+/// Update organization seat limit (Admin only)
+///
+/// Caps how many members the organization may have, owner included. Adding a
+/// member or accepting an invitation beyond the cap fails with
+/// `seat_limit_exceeded`, and new invitations are refused while the
+/// organization is full. Lowering the cap below the current member count
+/// removes nobody. Set to null to remove the limit.
+#[utoipa::path(
+    patch,
+    path = "/v1/admin/organizations/{org_id}/seat-limit",
+    tag = "Admin",
+    params(
+        ("org_id" = Uuid, Path, description = "Organization ID")
+    ),
+    request_body = UpdateOrganizationSeatLimitRequest,
+    responses(
+        (status = 200, description = "Seat limit updated successfully", body = OrganizationSeatLimitResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Organization not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn update_organization_seat_limit(
+    State(app_state): State<AdminAppState>,
+    Path(org_id): Path<uuid::Uuid>,
+    Extension(_admin_user): Extension<AdminUser>,
+    ResponseJson(request): ResponseJson<UpdateOrganizationSeatLimitRequest>,
+) -> Result<ResponseJson<OrganizationSeatLimitResponse>, (StatusCode, ResponseJson<ErrorResponse>)>
+{
+    debug!(
+        "Update organization seat limit request for org_id: {}, max_members: {:?}",
+        org_id, request.max_members
+    );
+
+    let seat_limit = app_state
+        .admin_service
+        .update_organization_seat_limit(org_id, request.max_members)
+        .await
+        .map_err(|e| seat_limit_error_response(e, "update"))?;
+
+    Ok(ResponseJson(seat_limit_response(org_id, seat_limit)))
+}
+
+/// Get organization seat limit (Admin only)
+///
+/// Returns the organization's seat limit (null when unlimited) and its current
+/// member count.
+#[utoipa::path(
+    get,
+    path = "/v1/admin/organizations/{org_id}/seat-limit",
+    tag = "Admin",
+    params(
+        ("org_id" = Uuid, Path, description = "Organization ID")
+    ),
+    responses(
+        (status = 200, description = "Seat limit retrieved successfully", body = OrganizationSeatLimitResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Organization not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn get_organization_seat_limit(
+    State(app_state): State<AdminAppState>,
+    Path(org_id): Path<uuid::Uuid>,
+    Extension(_admin_user): Extension<AdminUser>,
+) -> Result<ResponseJson<OrganizationSeatLimitResponse>, (StatusCode, ResponseJson<ErrorResponse>)>
+{
+    debug!("Get organization seat limit request for org_id: {}", org_id);
+
+    let seat_limit = app_state
+        .admin_service
+        .get_organization_seat_limit(org_id)
+        .await
+        .map_err(|e| seat_limit_error_response(e, "get"))?;
+
+    Ok(ResponseJson(seat_limit_response(org_id, seat_limit)))
+}
+
+/// Update organization model allowlist (Admin only)
+///
+/// Restricts which models the organization may call. Entries may be model
+/// names or aliases and are stored as canonical names. Set to null or an
+/// empty list to allow all models. Changes take effect immediately.
+#[utoipa::path(
+    patch,
+    path = "/v1/admin/organizations/{org_id}/allowed-models",
+    tag = "Admin",
+    params(
+        ("org_id" = String, Path, description = "The organization's ID (as a UUID)")
+    ),
+    request_body = UpdateOrganizationAllowedModelsRequest,
+    responses(
+        (status = 200, description = "Allowlist updated successfully", body = OrganizationAllowedModelsResponse),
+        (status = 400, description = "Invalid request or unknown model", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Organization not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn update_organization_allowed_models(
+    State(app_state): State<AdminAppState>,
+    Path(org_id): Path<String>,
+    Extension(_admin_user): Extension<AdminUser>,
+    ResponseJson(request): ResponseJson<UpdateOrganizationAllowedModelsRequest>,
+) -> Result<
+    ResponseJson<OrganizationAllowedModelsResponse>,
+    (StatusCode, ResponseJson<ErrorResponse>),
+> {
+    debug!(
+        "Update organization allowed models request for org_id: {}",
+        org_id
+    );
+
+    let org_uuid = uuid::Uuid::parse_str(&org_id).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse::new(
+                "Invalid organization ID format".to_string(),
+                "invalid_id".to_string(),
+            )),
+        )
+    })?;
+
+    let allowed_models = app_state
+        .admin_service
+        .update_organization_allowed_models(org_uuid, request.allowed_models)
+        .await
+        .map_err(|e| {
+            error!("Failed to update organization allowed models");
+            match e {
+                services::admin::AdminError::OrganizationNotFound(msg) => (
+                    StatusCode::NOT_FOUND,
+                    ResponseJson(ErrorResponse::new(
+                        msg,
+                        "organization_not_found".to_string(),
+                    )),
+                ),
+                services::admin::AdminError::ModelNotFound(msg) => (
+                    StatusCode::BAD_REQUEST,
+                    ResponseJson(ErrorResponse::new(msg, "invalid_model".to_string())),
+                ),
+                _ => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    ResponseJson(ErrorResponse::new(
+                        "Failed to update allowed models".to_string(),
+                        "internal_server_error".to_string(),
+                    )),
+                ),
+            }
+        })?;
+
+    Ok(ResponseJson(OrganizationAllowedModelsResponse {
+        organization_id: org_id,
+        allowed_models,
+    }))
+}
+
+/// Get organization model allowlist (Admin only)
+///
+/// Returns the canonical model names the organization may call, or null if
+/// all models are allowed.
+#[utoipa::path(
+    get,
+    path = "/v1/admin/organizations/{org_id}/allowed-models",
+    tag = "Admin",
+    params(
+        ("org_id" = String, Path, description = "The organization's ID (as a UUID)")
+    ),
+    responses(
+        (status = 200, description = "Allowlist retrieved successfully", body = OrganizationAllowedModelsResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Organization not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn get_organization_allowed_models(
+    State(app_state): State<AdminAppState>,
+    Path(org_id): Path<String>,
+    Extension(_admin_user): Extension<AdminUser>,
+) -> Result<
+    ResponseJson<OrganizationAllowedModelsResponse>,
+    (StatusCode, ResponseJson<ErrorResponse>),
+> {
+    debug!(
+        "Get organization allowed models request for org_id: {}",
+        org_id
+    );
+
+    let org_uuid = uuid::Uuid::parse_str(&org_id).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse::new(
+                "Invalid organization ID format".to_string(),
+                "invalid_id".to_string(),
+            )),
+        )
+    })?;
+
+    let allowed_models = app_state
+        .admin_service
+        .get_organization_allowed_models(org_uuid)
+        .await
+        .map_err(|e| {
+            error!("Failed to get organization allowed models");
+            match e {
+                services::admin::AdminError::OrganizationNotFound(msg) => (
+                    StatusCode::NOT_FOUND,
+                    ResponseJson(ErrorResponse::new(
+                        msg,
+                        "organization_not_found".to_string(),
+                    )),
+                ),
+                _ => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    ResponseJson(ErrorResponse::new(
+                        "Failed to get allowed models".to_string(),
+                        "internal_server_error".to_string(),
+                    )),
+                ),
+            }
+        })?;
+
+    Ok(ResponseJson(OrganizationAllowedModelsResponse {
+        organization_id: org_id,
+        allowed_models,
+    }))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct DiscoveryRefreshQueryParams {
+    /// Drop cached provider attestation reports before refreshing.
+    #[serde(default)]
+    pub force_attestation: bool,
+}
+
+/// Force a provider discovery refresh (Admin only)
+///
+/// Reloads inference_url and external providers from the database immediately
+/// instead of waiting for the periodic refresh. Concurrent calls coalesce into
+/// a single refresh. Provider attestation reports cached within their TTL are
+/// reused unless `force_attestation=true`.
+#[utoipa::path(
+    post,
+    path = "/v1/admin/discovery/refresh",
+    tag = "Admin",
+    params(
+        ("force_attestation" = Option<bool>, Query, description = "Re-fetch provider attestation reports instead of reusing cached ones (default: false)")
+    ),
+    responses(
+        (status = 200, description = "Discovery refreshed", body = DiscoveryRefreshResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 503, description = "Discovery source not available", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn refresh_discovery(
+    State(app_state): State<AdminAppState>,
+    Extension(admin_user): Extension<AdminUser>,
+    Query(params): Query<DiscoveryRefreshQueryParams>,
+) -> Result<ResponseJson<DiscoveryRefreshResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    tracing::info!(
+        admin_user_id = %admin_user.0.id,
+        force_attestation = params.force_attestation,
+        "Admin-triggered provider discovery refresh"
+    );
+
+    let summary = app_state
+        .inference_provider_pool
+        .refresh_now(params.force_attestation)
+        .await
+        .ok_or_else(|| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                ResponseJson(ErrorResponse::new(
+                    "Provider discovery source is not configured".to_string(),
+                    "discovery_unavailable".to_string(),
+                )),
+            )
+        })?;
+
+    tracing::info!(
+        models = summary.models,
+        providers = summary.providers,
+        duration_ms = summary.duration_ms,
+        "Provider discovery refresh completed"
+    );
+
+    Ok(ResponseJson(DiscoveryRefreshResponse {
+        models: summary.models,
+        providers: summary.providers,
+        duration_ms: summary.duration_ms,
+    }))
+}
+
+/// Inspect provider pool routing state (Admin only)
+///
+/// Returns, per model, the registered providers with their health, the
+/// current round-robin index and the number of signing-key mappings. No
+/// URLs, credentials or keys are included.
+#[utoipa::path(
+    get,
+    path = "/v1/admin/pool/state",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Pool state retrieved", body = PoolStateResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn get_pool_state(
+    State(app_state): State<AdminAppState>,
+    Extension(_admin_user): Extension<AdminUser>,
+) -> ResponseJson<PoolStateResponse> {
+    let snapshot = app_state
+        .inference_provider_pool
+        .routing_state_snapshot()
+        .await;
+
+    ResponseJson(PoolStateResponse {
+        models: snapshot
+            .models
+            .into_iter()
+            .map(|model| PoolModelState {
+                model: model.model,
+                round_robin_index: model.round_robin_index,
+                pubkey_mappings: model.pubkey_mappings,
+                providers: model
+                    .providers
+                    .into_iter()
+                    .map(|provider| PoolProviderState {
+                        id: provider.id,
+                        host: provider.host,
+                        source: provider.source.to_string(),
+                        tier: provider.tier.to_string(),
+                        consecutive_failures: provider.consecutive_failures,
+                        ejected: provider.ejected,
+                    })
+                    .collect(),
+            })
+            .collect(),
+    })
+}
+
 #[cfg(test)]
 mod deprecation_date_tests {
     use super::{format_deprecation_date, parse_deprecation_date};
@@ -19,6 +19,7 @@ pub struct AppState {
     pub organization_service: Arc<dyn OrganizationServiceTrait + Send + Sync>,
     pub workspace_service: Arc<dyn WorkspaceServiceTrait + Send + Sync>,
     pub mcp_manager: Arc<McpClientManager>,
+    pub auto_tool_service: Arc<services::auto_tools::AutoToolExecutionService>,
     pub completion_service: Arc<dyn CompletionServiceTrait>,
     pub models_service: Arc<dyn ModelsServiceTrait>,
     pub auth_service: Arc<dyn AuthServiceTrait>,
@@ -35,12 +36,17 @@ pub struct AppState {
     pub analytics_service: Arc<services::admin::AnalyticsService>,
     pub staking_farm_service: Arc<services::staking_farm::StakingFarmService>,
     pub config: Arc<config::ApiConfig>,
+    pub page_size_limits: crate::routes::common::PageSizeLimits,
+    pub inference_id_namespace: services::completions::InferenceIdNamespace,
     /// OHTTP gateway for RFC 9458 decapsulation/encapsulation. `None` when OHTTP_ENABLED is unset.
     pub ohttp_gateway: Option<Arc<OhttpGateway>>,
     /// Pre-built attestation payload for the OHTTP key config; included in GET /v1/attestation/report.
     pub ohttp_attestation: Option<OhttpAttestation>,
     /// HTTP client used exclusively for OHTTP loopback requests to self.
     pub http_client: reqwest::Client,
+    /// Middleware auth state, for handlers that recognise admin callers
+    /// outside the admin routes.
+    pub auth_state: AuthState,
 }
 
 // Import route handlers
@@ -163,6 +169,24 @@ pub fn build_management_router(app_state: AppState, auth_state: AuthState) -> Ro
         .route(
             "/{id}/reporting-tokens/{token_id}",
             delete(crate::routes::reporting_tokens::revoke_reporting_token),
+        )
+        .route(
+            "/{id}/model-aliases",
+            get(crate::routes::organization_model_aliases::list_organization_model_aliases),
+        )
+        .route(
+            "/{id}/model-aliases/{alias}",
+            put(crate::routes::organization_model_aliases::set_organization_model_alias)
+                .delete(crate::routes::organization_model_aliases::delete_organization_model_alias),
+        )
+        .route(
+            "/{id}/model-quotas",
+            get(crate::routes::organization_model_quotas::list_organization_model_quotas),
+        )
+        .route(
+            "/{id}/model-quotas/{model}/{period}",
+            put(crate::routes::organization_model_quotas::set_organization_model_quota)
+                .delete(crate::routes::organization_model_quotas::delete_organization_model_quota),
         );
 
     // User routes (require access token authentication)
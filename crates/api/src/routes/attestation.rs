@@ -7,7 +7,10 @@
 //! | Route                           | Auth    | Rationale |
 //! |---------------------------------|---------|-----------|
 //! | `GET /v1/attestation/report`    | API key | Data-plane endpoint, documented as key-protected. Key validation only — retrieval is not billed and never creates usage records. |
+//! | `GET /v1/attestation/quote`     | API key | Same as the report, but requires a `nonce` and verifies the report embeds it. Non-billable. |
 //! | `GET /v1/signature/{chat_id}`   | API key | Returns per-completion signatures; completions are key-scoped, so lookups are too. |
+//! | `GET /v1/verify/{chat_id}`      | API key | Verifies the stored signature server-side; same scoping as the signature lookup. |
+//! | `POST /v1/attestation/verify-model` | API key | Fetches and checks a nonce-bound report from every provider of a model. Non-billable. |
 //! | `GET /v1/attestation/ita-token` | Public  | Deliberate exception — see `build_public_attestation_routes`. |
 
 use crate::{ohttp_gateway::OhttpAttestation, routes::api::AppState};
@@ -21,17 +24,23 @@ pub(crate) mod ita_token;
 mod ita_token_models;
 pub(crate) mod report;
 pub(crate) mod signature;
+pub(crate) mod verify_model;
 
 pub use ita_token::get_ita_token;
 pub use ita_token_models::{
     ItaModelAliasResolved, ItaModelTokenItem, ItaTokenItem, ItaTokenQuery, ItaTokenResponse,
 };
 pub use report::{
-    get_attestation_report, AttestationQuery, AttestationResponse, DstackCpuQuote, Evidence,
-    NvidiaPayload, QuoteResponse, VerifyRequest, VpcInfo,
+    get_attestation_quote, get_attestation_report, AttestationQuery, AttestationResponse,
+    DstackCpuQuote, Evidence, NvidiaPayload, QuoteResponse, VerifyRequest, VpcInfo,
 };
 pub use signature::{
-    get_signature, SignatureQuery, SignatureResponse, SignatureUnavailableResponse,
+    get_signature, verify_signature, SignatureQuery, SignatureResponse,
+    SignatureUnavailableResponse, SignatureVerificationResponse,
+};
+pub use verify_model::{
+    verify_model_attestation, ModelAttestationSummary, ProviderAttestationResult,
+    VerifyModelAttestationRequest, VerifyModelAttestationResponse,
 };
 
 #[derive(Clone)]
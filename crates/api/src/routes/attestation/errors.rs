@@ -28,6 +28,7 @@ pub(super) fn signature_error_response(
         AttestationError::ProviderError(_)
         | AttestationError::RepositoryError(_)
         | AttestationError::InternalError(_)
+        | AttestationError::NonceMismatch(_)
         | AttestationError::ItaUnavailable { .. }
         | AttestationError::ItaRateLimited { .. }
         | AttestationError::ItaTimeout
@@ -64,6 +65,12 @@ pub(super) fn attestation_report_error_response(
             "provider_error",
             None,
         ),
+        AttestationError::NonceMismatch(_) => error_response(
+            StatusCode::BAD_GATEWAY,
+            message,
+            "attestation_nonce_mismatch",
+            None,
+        ),
         AttestationError::SignatureNotFound(_) => {
             error_response(StatusCode::NOT_FOUND, message, "not_found_error", None)
         }
@@ -121,12 +128,14 @@ pub(super) fn ita_token_error_response(error: AttestationError) -> Response {
             }
             response
         }
-        AttestationError::ItaBadUpstream { .. } => error_tuple_into_response(error_response(
-            StatusCode::BAD_GATEWAY,
-            message,
-            "bad_gateway",
-            None,
-        )),
+        AttestationError::ItaBadUpstream { .. } | AttestationError::NonceMismatch(_) => {
+            error_tuple_into_response(error_response(
+                StatusCode::BAD_GATEWAY,
+                message,
+                "bad_gateway",
+                None,
+            ))
+        }
         AttestationError::ItaUnavailable { .. } => error_tuple_into_response(error_response(
             StatusCode::SERVICE_UNAVAILABLE,
             message,
@@ -154,24 +154,7 @@ pub async fn get_attestation_report(
 ) -> Result<axum::response::Response, (StatusCode, ResponseJson<ErrorResponse>)> {
     let alias_resolved =
         resolve_attestation_alias(params.model.as_deref(), &state.models_service, &headers).await?;
-    let provider_filter = match params
-        .provider
-        .as_deref()
-        .map(str::to_ascii_lowercase)
-        .as_deref()
-    {
-        None => None,
-        Some("near") => Some(ProviderTier::Near),
-        Some("chutes") => Some(ProviderTier::Attested3p),
-        Some(unknown) => {
-            return Err(error_response(
-                StatusCode::BAD_REQUEST,
-                format!("Unknown provider '{unknown}'. Accepted values: 'near', 'chutes'."),
-                "invalid_request_error",
-                Some("provider"),
-            ));
-        }
-    };
+    let provider_filter = parse_provider_filter(params.provider.as_deref())?;
 
     let report = state
         .attestation_service
@@ -186,6 +169,88 @@ pub async fn get_attestation_report(
         .await
         .map_err(attestation_report_error_response)?;
 
+    Ok(report_response(&state, report, alias_resolved))
+}
+
+/// Get a nonce-bound attestation quote
+///
+/// Like `/v1/attestation/report`, but the `nonce` parameter is required and the
+/// gateway verifies that the returned gateway quote and every model attestation
+/// embed it before responding. A report that does not carry the requested nonce
+/// (e.g. a replayed or stale cached report) is rejected with 502, so a 200
+/// response is always fresh for the caller's nonce.
+#[utoipa::path(
+    get,
+    path = "/v1/attestation/quote",
+    params(
+        AttestationQuery
+    ),
+    responses(
+        (status = 200, description = "Nonce-bound attestation report retrieved", body = AttestationResponse),
+        (status = 400, description = "Missing or invalid nonce", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 502, description = "Returned report does not embed the requested nonce", body = ErrorResponse),
+        (status = 503, description = "Service unavailable", body = ErrorResponse)
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Attestation"
+)]
+pub async fn get_attestation_quote(
+    Query(params): Query<AttestationQuery>,
+    State(state): State<AttestationRouteState>,
+    headers: HeaderMap,
+) -> Result<axum::response::Response, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let Some(nonce) = params.nonce else {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "nonce is required: pass a 32-byte hex-encoded nonce".to_string(),
+            "invalid_request_error",
+            Some("nonce"),
+        ));
+    };
+    let alias_resolved =
+        resolve_attestation_alias(params.model.as_deref(), &state.models_service, &headers).await?;
+    let provider_filter = parse_provider_filter(params.provider.as_deref())?;
+
+    let report = state
+        .attestation_service
+        .get_attestation_quote(
+            params.model,
+            params.signing_algo,
+            nonce,
+            params.signing_address,
+            params.include_tls_fingerprint.unwrap_or(false),
+            provider_filter,
+        )
+        .await
+        .map_err(attestation_report_error_response)?;
+
+    Ok(report_response(&state, report, alias_resolved))
+}
+
+fn parse_provider_filter(
+    provider: Option<&str>,
+) -> Result<Option<ProviderTier>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    match provider.map(str::to_ascii_lowercase).as_deref() {
+        None => Ok(None),
+        Some("near") => Ok(Some(ProviderTier::Near)),
+        Some("chutes") => Ok(Some(ProviderTier::Attested3p)),
+        Some(unknown) => Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Unknown provider '{unknown}'. Accepted values: 'near', 'chutes'."),
+            "invalid_request_error",
+            Some("provider"),
+        )),
+    }
+}
+
+fn report_response(
+    state: &AttestationRouteState,
+    report: services::attestation::models::AttestationReport,
+    alias_resolved: Option<(String, String)>,
+) -> axum::response::Response {
     let mut response: AttestationResponse = report.into();
     if let Some(ohttp) = &state.ohttp_attestation {
         response.ohttp_key_config = Some(ohttp.key_config.clone());
@@ -195,7 +260,7 @@ pub async fn get_attestation_report(
     if let Some((requested, canonical)) = alias_resolved {
         attach_alias_header(&mut http_response, &requested, &canonical);
     }
-    Ok(http_response)
+    http_response
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
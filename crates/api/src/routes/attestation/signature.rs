@@ -61,12 +61,14 @@ pub struct SignatureUnavailableResponse {
 /// Get completion signature
 ///
 /// Get cryptographic signature for a chat completion for verification.
+/// The completion may be identified by its chat id or by the `Inference-Id`
+/// UUID returned with it.
 /// Returns signature data on success, or an unavailable response if the stream was disconnected.
 #[utoipa::path(
     get,
     path = "/v1/signature/{chat_id}",
     params(
-        ("chat_id" = String, Path, description = "Chat completion ID"),
+        ("chat_id" = String, Path, description = "Chat completion ID, or its `Inference-Id` UUID"),
         SignatureQuery
     ),
     responses(
@@ -135,3 +137,80 @@ pub(super) fn validate_signing_algo(
         )),
     }
 }
+
+/// Response for signature verification endpoint
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SignatureVerificationResponse {
+    /// Whether the stored signature is valid for `message` and `signing_address`
+    pub verified: bool,
+    pub signing_algo: String,
+    /// Ethereum address (`ecdsa`) or hex-encoded public key (`ed25519`) the
+    /// signature was verified against
+    pub signing_address: String,
+    /// Request hash component of the signed message, if it could be parsed
+    pub request_hash: Option<String>,
+    /// Response hash component of the signed message, if it could be parsed
+    pub response_hash: Option<String>,
+    /// The exact message the signature covers
+    pub message: String,
+    /// Which key produced the signature: `"provider_tee"` or `"gateway"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_kind: Option<String>,
+    /// Reason verification failed; omitted when `verified` is true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<services::attestation::SignatureVerification> for SignatureVerificationResponse {
+    fn from(result: services::attestation::SignatureVerification) -> Self {
+        Self {
+            verified: result.verified,
+            signing_algo: result.signing_algo,
+            signing_address: result.signing_address,
+            request_hash: result.request_hash,
+            response_hash: result.response_hash,
+            message: result.message,
+            signature_kind: result.signature_kind.map(|kind| kind.as_str().to_string()),
+            error: result.error,
+        }
+    }
+}
+
+/// Verify completion signature
+///
+/// Verify the stored signature for a chat completion server-side and return the
+/// verified message components (request/response hashes, signed message and
+/// signer) rather than a bare boolean.
+#[utoipa::path(
+    get,
+    path = "/v1/verify/{chat_id}",
+    params(
+        ("chat_id" = String, Path, description = "Chat completion ID, or its `Inference-Id` UUID"),
+        SignatureQuery
+    ),
+    responses(
+        (status = 200, description = "Signature verification result", body = SignatureVerificationResponse),
+        (status = 404, description = "Signature not found", body = ErrorResponse),
+        (status = 400, description = "Invalid parameters", body = ErrorResponse)
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Attestation"
+)]
+pub async fn verify_signature(
+    Path(chat_id): Path<String>,
+    Query(params): Query<SignatureQuery>,
+    State(state): State<AttestationRouteState>,
+) -> Result<ResponseJson<SignatureVerificationResponse>, (StatusCode, ResponseJson<ErrorResponse>)>
+{
+    validate_signing_algo(params.signing_algo.as_deref())?;
+
+    let result = state
+        .attestation_service
+        .verify_chat_signature(chat_id.as_str(), params.signing_algo)
+        .await
+        .map_err(signature_error_response)?;
+
+    Ok(ResponseJson(result.into()))
+}
@@ -103,6 +103,14 @@ impl services::attestation::ports::AttestationServiceTrait for RecordingItaAttes
         Err(AttestationError::InternalError("unused".to_string()))
     }
 
+    async fn verify_model_attestation(
+        &self,
+        _model: String,
+        _signing_algo: Option<String>,
+    ) -> Result<services::attestation::models::ModelAttestationVerification, AttestationError> {
+        Err(AttestationError::InternalError("unused".to_string()))
+    }
+
     async fn get_ita_attestation_token(
         &self,
         query: ServiceItaTokenQuery,
@@ -164,10 +172,50 @@ impl ModelsServiceTrait for TestModelsService {
         Ok(model_with_name(canonical))
     }
 
+    async fn resolve_and_get_model_for_organization(
+        &self,
+        _organization_id: Uuid,
+        identifier: &str,
+    ) -> Result<services::models::ModelWithPricing, ModelsError> {
+        self.resolve_and_get_model(identifier).await
+    }
+
     async fn resolve_alias_cached(&self, _identifier: &str) -> Option<String> {
         None
     }
 
+    async fn resolve_alias_cached_for_organization(
+        &self,
+        _organization_id: Uuid,
+        _identifier: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    async fn list_organization_model_aliases(
+        &self,
+        _organization_id: Uuid,
+    ) -> Result<Vec<services::models::OrganizationModelAlias>, ModelsError> {
+        Ok(Vec::new())
+    }
+
+    async fn set_organization_model_alias(
+        &self,
+        _organization_id: Uuid,
+        _alias_name: &str,
+        _canonical_model_name: &str,
+    ) -> Result<services::models::OrganizationModelAlias, ModelsError> {
+        unimplemented!()
+    }
+
+    async fn delete_organization_model_alias(
+        &self,
+        _organization_id: Uuid,
+        _alias_name: &str,
+    ) -> Result<(), ModelsError> {
+        unimplemented!()
+    }
+
     async fn get_configured_model_names(&self) -> Result<Vec<String>, ModelsError> {
         Ok(Vec::new())
     }
@@ -255,6 +303,7 @@ fn model_with_name(model_name: &str) -> ModelWithPricing {
         is_ready: None,
         deprecation_date: None,
         openrouter_slug: None,
+        max_tokens_limits: Default::default(),
         created_at: chrono::Utc::now(),
     }
 }
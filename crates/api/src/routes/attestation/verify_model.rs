@@ -0,0 +1,148 @@
+use super::{
+    alias::{attach_alias_header, resolve_attestation_alias},
+    errors::attestation_report_error_response,
+    signature::validate_signing_algo,
+    AttestationRouteState,
+};
+use crate::{models::ErrorResponse, routes::completions::provider_tier_to_str};
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Json as ResponseJson},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Request body for verifying a model's attestation across all its providers
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct VerifyModelAttestationRequest {
+    /// Model name or alias
+    pub model: String,
+    /// Signing algorithm: `ecdsa` or `ed25519` (default `ed25519`)
+    pub signing_algo: Option<String>,
+}
+
+/// Verification outcome for one provider serving the model
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProviderAttestationResult {
+    /// Position of the provider in the model's provider list
+    pub provider_index: usize,
+    /// Serving tier: `near`, `chutes` or `non-attested`
+    pub provider: String,
+    /// Whether every check passed for this provider
+    pub passed: bool,
+    /// The provider returned an attestation report
+    pub report_fetched: bool,
+    /// The report was issued for this request's nonce
+    pub nonce_verified: bool,
+    /// The report's TDX quote verified and binds this request's nonce
+    pub quote_verified: bool,
+    /// Why verification failed; absent when it passed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure: Option<String>,
+    /// The provider's attestation report, when one was returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attestation: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Pass/fail counts across all providers
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ModelAttestationSummary {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+}
+
+/// Response for the multi-provider attestation verification endpoint
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct VerifyModelAttestationResponse {
+    /// Canonical model name
+    pub model: String,
+    pub signing_algo: String,
+    /// Gateway-generated nonce every provider report was requested with
+    pub nonce: String,
+    /// True only when every provider passed
+    pub verified: bool,
+    pub summary: ModelAttestationSummary,
+    pub providers: Vec<ProviderAttestationResult>,
+}
+
+impl From<services::attestation::models::ModelAttestationVerification>
+    for VerifyModelAttestationResponse
+{
+    fn from(verification: services::attestation::models::ModelAttestationVerification) -> Self {
+        let verified = verification.verified();
+        let providers: Vec<ProviderAttestationResult> = verification
+            .providers
+            .into_iter()
+            .map(|check| ProviderAttestationResult {
+                provider_index: check.provider_index,
+                provider: provider_tier_to_str(check.tier).to_string(),
+                passed: check.passed(),
+                report_fetched: check.report_fetched,
+                nonce_verified: check.nonce_verified,
+                quote_verified: check.quote_verified,
+                failure: check.failure,
+                attestation: check.attestation,
+            })
+            .collect();
+        let passed = providers.iter().filter(|p| p.passed).count();
+        Self {
+            model: verification.model,
+            signing_algo: verification.signing_algo,
+            nonce: verification.nonce,
+            verified,
+            summary: ModelAttestationSummary {
+                total: providers.len(),
+                passed,
+                failed: providers.len() - passed,
+            },
+            providers,
+        }
+    }
+}
+
+/// Verify a model's attestation across all providers
+///
+/// Fetches an attestation report from every provider serving the model, bound
+/// to a fresh gateway-generated nonce, and checks each one. Returns
+/// per-provider pass/fail and an overall summary; a failing provider does not
+/// fail the request. Requires an API key; non-billable.
+#[utoipa::path(
+    post,
+    path = "/v1/attestation/verify-model",
+    request_body = VerifyModelAttestationRequest,
+    responses(
+        (status = 200, description = "Per-provider verification results", body = VerifyModelAttestationResponse),
+        (status = 400, description = "Invalid signing algorithm", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 503, description = "Model not found or no provider serves it", body = ErrorResponse)
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Attestation"
+)]
+pub async fn verify_model_attestation(
+    State(state): State<AttestationRouteState>,
+    headers: HeaderMap,
+    Json(request): Json<VerifyModelAttestationRequest>,
+) -> Result<axum::response::Response, (StatusCode, ResponseJson<ErrorResponse>)> {
+    validate_signing_algo(request.signing_algo.as_deref())?;
+    let alias_resolved =
+        resolve_attestation_alias(Some(&request.model), &state.models_service, &headers).await?;
+
+    let verification = state
+        .attestation_service
+        .verify_model_attestation(request.model, request.signing_algo)
+        .await
+        .map_err(attestation_report_error_response)?;
+
+    let mut response =
+        ResponseJson(VerifyModelAttestationResponse::from(verification)).into_response();
+    if let Some((requested, canonical)) = alias_resolved {
+        attach_alias_header(&mut response, &requested, &canonical);
+    }
+    Ok(response)
+}
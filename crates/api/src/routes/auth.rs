@@ -1,6 +1,6 @@
 use crate::middleware::AuthenticatedUser;
 use axum::{
-    extract::{Query, Request, State},
+    extract::{Path, Query, Request, State},
     http::{HeaderMap, StatusCode},
     response::{Html, IntoResponse, Redirect, Response},
     Extension, Json,
@@ -113,6 +113,30 @@ pub struct AuthResponse {
     provider: String,
 }
 
+/// An active login session as shown in the user's device list
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SessionResponse {
+    pub id: uuid::Uuid,
+    /// Human-readable device, e.g. "Chrome on macOS"; falls back to the
+    /// stored User-Agent for sessions created before device labels existed
+    pub device: String,
+    pub created_at: chrono::DateTime<Utc>,
+    pub last_used_at: chrono::DateTime<Utc>,
+    pub expires_at: chrono::DateTime<Utc>,
+}
+
+impl From<services::auth::Session> for SessionResponse {
+    fn from(session: services::auth::Session) -> Self {
+        Self {
+            id: session.id.0,
+            device: session.device_label.unwrap_or(session.user_agent),
+            created_at: session.created_at,
+            last_used_at: session.last_used_at,
+            expires_at: session.expires_at,
+        }
+    }
+}
+
 // NEAR authentication types
 use base64::prelude::*;
 use near_api::signer::NEP413Payload;
@@ -226,6 +250,28 @@ impl<'de> serde::Deserialize<'de> for NearPayloadJson {
     }
 }
 
+/// Response body for `GET /v1/auth/near/nonce`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NearNonceResponse {
+    /// The nonce to sign (as array of 32 bytes)
+    pub nonce: Vec<u8>,
+    /// The NEP-413 message to sign alongside the nonce
+    pub message: String,
+    /// The NEP-413 recipient to sign alongside the nonce
+    pub recipient: String,
+    /// When the nonce stops being redeemable
+    pub expires_at: chrono::DateTime<Utc>,
+}
+
+/// Request body for `POST /v1/auth/near/verify`
+#[derive(Debug, Deserialize)]
+pub struct NearVerifyRequest {
+    /// The signed message from the wallet
+    pub signed_message: NearSignedMessageJson,
+    /// The nonce returned by `GET /v1/auth/near/nonce` (as array of 32 bytes)
+    pub nonce: Vec<u8>,
+}
+
 #[derive(Serialize)]
 pub struct NearAuthResponse {
     access_token: String,
@@ -330,6 +376,51 @@ pub async fn google_login(
     Ok(Redirect::to(&auth_url))
 }
 
+/// Initiate generic OIDC flow - redirects to the configured identity provider
+pub async fn oidc_login(
+    Query(params): Query<OAuthInitQuery>,
+    State((oauth, state_store, _auth_service, config)): State<AuthState>,
+) -> Result<Redirect, StatusCode> {
+    debug!(
+        "Initiating OIDC flow - frontend_callback: {}",
+        params.frontend_callback.is_some()
+    );
+
+    if let Some(ref callback_url) = params.frontend_callback {
+        if let Err(err_msg) = validate_frontend_callback(callback_url, &config.cors) {
+            error!(
+                "frontend_callback validation failed at OIDC login: {}",
+                err_msg
+            );
+            return Err(StatusCode::BAD_REQUEST);
+        }
+    }
+
+    let (auth_url, state, pkce_verifier) = oauth.oidc_auth_url().map_err(|_| {
+        error!("Failed to generate OIDC auth URL");
+        StatusCode::NOT_FOUND
+    })?;
+
+    // Store state and PKCE verifier in database for multi-instance support
+    state_store
+        .create(
+            state.clone(),
+            "oidc".to_string(),
+            Some(pkce_verifier),
+            params.frontend_callback.clone(),
+        )
+        .await
+        .map_err(|e| {
+            error!("Failed to store OAuth state: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let state_present = !state.is_empty();
+    let state_len = state.len();
+    debug!(state_present, state_len, "Redirecting to OIDC provider");
+    Ok(Redirect::to(&auth_url))
+}
+
 /// Handle OAuth callback - NEW frontend-centric flow
 ///
 /// Frontend calls this endpoint with the OAuth code to exchange for session token.
@@ -419,6 +510,25 @@ pub async fn oauth_callback(
                 .handle_google_callback(params.code, params.state, verifier)
                 .await
         }
+        "oidc" => {
+            let verifier = match oauth_state_row.pkce_verifier {
+                Some(v) => v,
+                None => {
+                    error!("Missing PKCE verifier for OIDC");
+                    return (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(serde_json::json!({
+                            "error": "internal_server_error",
+                            "error_description": "Missing PKCE verifier for OIDC"
+                        })),
+                    )
+                        .into_response();
+                }
+            };
+            oauth
+                .handle_oidc_callback(params.code, params.state, verifier)
+                .await
+        }
         _ => {
             error!("Unknown provider: {}", oauth_state_row.provider);
             return (
@@ -592,6 +702,81 @@ pub async fn logout(
     }
 }
 
+/// List the authenticated user's active sessions (one per logged-in device)
+pub async fn list_sessions(
+    State((_oauth, _state_store, auth_service, _config)): State<AuthState>,
+    Extension(user): Extension<AuthenticatedUser>,
+) -> Response {
+    match auth_service
+        .list_sessions(services::auth::UserId(user.0.id))
+        .await
+    {
+        Ok(sessions) => Json(
+            sessions
+                .into_iter()
+                .map(SessionResponse::from)
+                .collect::<Vec<_>>(),
+        )
+        .into_response(),
+        Err(e) => {
+            error!(error = %e, user_id = %user.0.id, "Failed to list sessions");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "internal_server_error",
+                    "error_description": "Failed to list sessions"
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Revoke one of the authenticated user's sessions
+///
+/// Like logout, this deletes the server-side session, so its refresh token
+/// and every access token bound to it stop working immediately. Sessions of
+/// other users are reported as not found.
+pub async fn revoke_session(
+    State((_oauth, _state_store, auth_service, _config)): State<AuthState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(session_id): Path<uuid::Uuid>,
+) -> Response {
+    debug!(
+        "Revoking session_id={} for user_id={}",
+        session_id, user.0.id
+    );
+
+    match auth_service
+        .revoke_session(
+            services::auth::UserId(user.0.id),
+            services::auth::SessionId(session_id),
+        )
+        .await
+    {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "not_found",
+                "error_description": "Session not found"
+            })),
+        )
+            .into_response(),
+        Err(e) => {
+            error!(error = %e, user_id = %user.0.id, "Failed to revoke session");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "internal_server_error",
+                    "error_description": "Failed to revoke session"
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
 /// NEAR wallet login endpoint
 pub async fn near_login(
     State((near_auth_service, config)): State<NearAuthState>,
@@ -635,33 +820,130 @@ pub async fn near_login(
         }
     };
 
-    // Extract user agent
-    let user_agent_header = match headers.get("User-Agent").and_then(|h| h.to_str().ok()) {
-        Some(ua) => ua,
-        None => {
-            tracing::warn!("Missing User-Agent header");
+    let Some(user_agent_header) = near_user_agent(&headers) else {
+        return missing_user_agent_response();
+    };
+
+    // Verify and authenticate
+    let result = near_auth_service
+        .verify_and_authenticate(
+            signed_message,
+            payload,
+            None, // ip_address
+            user_agent_header,
+            config.auth.encoding_key.clone(),
+        )
+        .await;
+
+    near_auth_result_response(result, &account_id_str)
+}
+
+/// Issue a nonce for NEAR challenge/response login
+///
+/// The wallet signs the returned nonce, message and recipient as a NEP-413
+/// payload and redeems it once at `POST /v1/auth/near/verify`.
+pub async fn near_nonce(State((near_auth_service, _config)): State<NearAuthState>) -> Response {
+    match near_auth_service.issue_nonce().await {
+        Ok(issued) => Json(NearNonceResponse {
+            nonce: issued.nonce.to_vec(),
+            message: issued.message,
+            recipient: issued.recipient,
+            expires_at: issued.expires_at,
+        })
+        .into_response(),
+        Err(e) => {
+            error!("Failed to issue NEAR nonce: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "internal_error",
+                    "error_description": "Failed to issue nonce"
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Exchange a wallet signature over an issued nonce for a session
+pub async fn near_verify(
+    State((near_auth_service, config)): State<NearAuthState>,
+    headers: HeaderMap,
+    Json(verify_request): Json<NearVerifyRequest>,
+) -> Response {
+    let account_id_str = verify_request.signed_message.account_id.clone();
+    debug!(
+        "NEAR nonce verification attempt for account: {}",
+        account_id_str
+    );
+
+    let signed_message = match verify_request.signed_message.try_into() {
+        Ok(msg) => msg,
+        Err(e) => {
+            tracing::warn!("Failed to parse signed message: {}", e);
             return (
                 StatusCode::BAD_REQUEST,
                 Json(serde_json::json!({
                     "error": "bad_request",
-                    "error_description": "Missing User-Agent header"
+                    "error_description": "Invalid signed message format"
                 })),
             )
                 .into_response();
         }
     };
 
-    // Verify and authenticate
+    let Ok(nonce) = <[u8; 32]>::try_from(verify_request.nonce.as_slice()) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "bad_request",
+                "error_description": "Nonce must be exactly 32 bytes"
+            })),
+        )
+            .into_response();
+    };
+
+    let Some(user_agent_header) = near_user_agent(&headers) else {
+        return missing_user_agent_response();
+    };
+
     let result = near_auth_service
-        .verify_and_authenticate(
+        .verify_issued_nonce_and_authenticate(
             signed_message,
-            payload,
+            nonce,
             None, // ip_address
-            user_agent_header.to_string(),
+            user_agent_header,
             config.auth.encoding_key.clone(),
         )
         .await;
 
+    near_auth_result_response(result, &account_id_str)
+}
+
+/// Extract the User-Agent the NEAR session is bound to
+fn near_user_agent(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("User-Agent")
+        .and_then(|h| h.to_str().ok())
+        .map(str::to_string)
+}
+
+fn missing_user_agent_response() -> Response {
+    tracing::warn!("Missing User-Agent header");
+    (
+        StatusCode::BAD_REQUEST,
+        Json(serde_json::json!({
+            "error": "bad_request",
+            "error_description": "Missing User-Agent header"
+        })),
+    )
+        .into_response()
+}
+
+fn near_auth_result_response(
+    result: anyhow::Result<(String, services::auth::Session, String)>,
+    account_id_str: &str,
+) -> Response {
     match result {
         Ok((access_token, refresh_session, refresh_token)) => {
             debug!("NEAR authentication successful: {}", account_id_str);
@@ -0,0 +1,418 @@
+use crate::{
+    middleware::{
+        auth::AuthenticatedApiKey, rate_limit::check_rate_limit_for_api_key,
+        usage::check_usage_for_api_key, RateLimitState, RequestBodyHash, UsageState,
+    },
+    models::{
+        BatchLineResponse, BatchObject, BatchRequestCounts, BatchResultLine, ChatCompletionRequest,
+        CreateBatchRequest, ErrorResponse,
+    },
+    routes::{common::map_domain_error_to_status, completions::convert_chat_request_to_service},
+};
+use async_trait::async_trait;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use services::{
+    batches::{
+        Batch, BatchError, BatchInput, BatchInputLine, BatchLineOutcome, BatchLineResult,
+        BatchRequestExecutor, BatchRequestLine, BatchServiceTrait,
+    },
+    completions::ports::{CompletionError, CompletionServiceTrait, RequestPriority},
+    id_prefixes::{PREFIX_BATCH, PREFIX_FILE},
+    workspace::{ApiKeyId, ApiKeyRepository, WorkspaceRepository},
+};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use tracing::{debug, error, warn};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct BatchRouteState {
+    pub batch_service: Arc<dyn BatchServiceTrait>,
+}
+
+type BatchRouteError = (StatusCode, Json<ErrorResponse>);
+
+fn invalid_request(message: String) -> BatchRouteError {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse::new(
+            message,
+            "invalid_request_error".to_string(),
+        )),
+    )
+}
+
+fn map_batch_error(e: BatchError) -> BatchRouteError {
+    match e {
+        BatchError::InvalidRequest(message) => invalid_request(message),
+        BatchError::NotFound => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(
+                "Batch not found".to_string(),
+                "not_found_error".to_string(),
+            )),
+        ),
+        BatchError::File(_) | BatchError::RepositoryError(_) => {
+            error!(error = %e, "Batch operation failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new(
+                    "Failed to process batch request".to_string(),
+                    "internal_server_error".to_string(),
+                )),
+            )
+        }
+    }
+}
+
+fn batch_to_response(batch: Batch, results: Option<Vec<BatchLineResult>>) -> BatchObject {
+    BatchObject {
+        id: format!("{PREFIX_BATCH}{}", batch.id),
+        object: "batch".to_string(),
+        endpoint: batch.endpoint,
+        input_file_id: batch.input_file_id.map(|id| format!("{PREFIX_FILE}{id}")),
+        completion_window: batch.completion_window,
+        status: batch.status.as_str().to_string(),
+        output_file_id: batch.output_file_id.map(|id| format!("{PREFIX_FILE}{id}")),
+        created_at: batch.created_at.timestamp(),
+        in_progress_at: batch.in_progress_at.map(|dt| dt.timestamp()),
+        completed_at: batch.completed_at.map(|dt| dt.timestamp()),
+        request_counts: BatchRequestCounts {
+            total: batch.request_counts.total,
+            completed: batch.request_counts.completed,
+            failed: batch.request_counts.failed,
+        },
+        metadata: batch.metadata,
+        results: results.map(|results| {
+            results
+                .into_iter()
+                .map(|result| BatchResultLine {
+                    custom_id: result.custom_id,
+                    status: result.status.as_str().to_string(),
+                    response: result.status_code.map(|status_code| BatchLineResponse {
+                        status_code,
+                        body: result.response_body.unwrap_or(serde_json::Value::Null),
+                    }),
+                })
+                .collect()
+        }),
+    }
+}
+
+/// Create batch
+///
+/// Queue a set of chat completion requests for asynchronous processing. The
+/// lines come either inline (`requests`) or from a JSONL file uploaded with
+/// purpose "batch" (`input_file_id`). Poll `GET /v1/batches/{batch_id}` for
+/// status and per-line results.
+#[utoipa::path(
+    post,
+    path = "/v1/batches",
+    tag = "Batches",
+    request_body = CreateBatchRequest,
+    responses(
+        (status = 200, description = "Batch created", body = BatchObject),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 402, description = "Insufficient credits", body = ErrorResponse),
+        (status = 500, description = "Server error", body = ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+pub async fn create_batch(
+    State(state): State<BatchRouteState>,
+    Extension(api_key): Extension<AuthenticatedApiKey>,
+    Json(request): Json<CreateBatchRequest>,
+) -> Result<Json<BatchObject>, BatchRouteError> {
+    debug!(
+        "Create batch request from workspace: {}",
+        api_key.workspace.id.0
+    );
+
+    let input = match (request.input_file_id, request.requests) {
+        (Some(file_id), None) => {
+            let id_str = file_id.strip_prefix(PREFIX_FILE).unwrap_or(&file_id);
+            let file_uuid = Uuid::parse_str(id_str)
+                .map_err(|_| invalid_request(format!("Invalid file ID format: {file_id}")))?;
+            BatchInput::File(file_uuid)
+        }
+        (None, Some(requests)) => BatchInput::Inline(
+            requests
+                .into_iter()
+                .map(|line| BatchInputLine {
+                    custom_id: line.custom_id,
+                    method: line.method,
+                    url: line.url,
+                    body: line.body,
+                })
+                .collect(),
+        ),
+        _ => {
+            return Err(invalid_request(
+                "Exactly one of input_file_id or requests must be provided".to_string(),
+            ))
+        }
+    };
+
+    let api_key_id = Uuid::parse_str(&api_key.api_key.id.0).map_err(|_| {
+        error!("Failed to parse API key ID");
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new(
+                "Internal error".to_string(),
+                "internal_server_error".to_string(),
+            )),
+        )
+    })?;
+
+    let batch = state
+        .batch_service
+        .create_batch(services::batches::CreateBatchRequest {
+            organization_id: api_key.organization.id.0,
+            workspace_id: api_key.workspace.id.0,
+            api_key_id,
+            created_by_user_id: api_key.api_key.created_by_user_id.0,
+            endpoint: request.endpoint,
+            completion_window: request.completion_window,
+            input,
+            metadata: request.metadata,
+        })
+        .await
+        .map_err(map_batch_error)?;
+
+    Ok(Json(batch_to_response(batch, None)))
+}
+
+/// Retrieve batch
+///
+/// Get a batch's status, request counts and per-line results.
+#[utoipa::path(
+    get,
+    path = "/v1/batches/{batch_id}",
+    tag = "Batches",
+    params(
+        ("batch_id" = String, Path, description = "The ID of the batch to retrieve")
+    ),
+    responses(
+        (status = 200, description = "Batch retrieved", body = BatchObject),
+        (status = 400, description = "Invalid batch ID", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Batch not found", body = ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+pub async fn retrieve_batch(
+    State(state): State<BatchRouteState>,
+    Extension(api_key): Extension<AuthenticatedApiKey>,
+    Path(batch_id): Path<String>,
+) -> Result<Json<BatchObject>, BatchRouteError> {
+    let id_str = batch_id.strip_prefix(PREFIX_BATCH).unwrap_or(&batch_id);
+    let batch_uuid = Uuid::parse_str(id_str)
+        .map_err(|_| invalid_request(format!("Invalid batch ID format: {batch_id}")))?;
+
+    let (batch, results) = state
+        .batch_service
+        .get_batch(batch_uuid, api_key.workspace.id.0)
+        .await
+        .map_err(map_batch_error)?;
+
+    Ok(Json(batch_to_response(batch, Some(results))))
+}
+
+/// Runs batch lines through the chat completion service, producing the
+/// status and body `POST /v1/chat/completions` would have returned.
+///
+/// Each line re-resolves the submitting API key and applies the same gates
+/// as the synchronous endpoint: key revocation and expiry, the per-key rate
+/// limit, the key's spend limit, the workspace's monthly budget and the
+/// organization's credits.
+pub struct CompletionBatchExecutor {
+    completion_service: Arc<dyn CompletionServiceTrait>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+    usage_state: UsageState,
+    rate_limit_state: RateLimitState,
+}
+
+impl CompletionBatchExecutor {
+    pub fn new(
+        completion_service: Arc<dyn CompletionServiceTrait>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        api_key_repository: Arc<dyn ApiKeyRepository>,
+        usage_state: UsageState,
+        rate_limit_state: RateLimitState,
+    ) -> Self {
+        Self {
+            completion_service,
+            workspace_repository,
+            api_key_repository,
+            usage_state,
+            rate_limit_state,
+        }
+    }
+
+    /// Resolve the batch's API key with its workspace and organization, as
+    /// the auth middleware does for a synchronous request. `Err` carries the
+    /// outcome to record for the line.
+    async fn resolve_api_key(
+        &self,
+        batch: &Batch,
+    ) -> Result<AuthenticatedApiKey, BatchLineOutcome> {
+        let api_key = match self
+            .api_key_repository
+            .get_by_id(ApiKeyId(batch.api_key_id.to_string()))
+            .await
+        {
+            Ok(api_key) => api_key,
+            Err(_) => {
+                error!(batch_id = %batch.id, "Failed to load API key for batch");
+                return Err(BatchLineOutcome::Deferred);
+            }
+        };
+        let api_key = match api_key {
+            Some(api_key)
+                if api_key.is_active
+                    && api_key
+                        .expires_at
+                        .is_none_or(|expires_at| expires_at > chrono::Utc::now()) =>
+            {
+                api_key
+            }
+            _ => {
+                return Err(line_error(
+                    StatusCode::UNAUTHORIZED,
+                    ErrorResponse::new(
+                        "Invalid or expired API key".to_string(),
+                        "invalid_api_key".to_string(),
+                    ),
+                ))
+            }
+        };
+
+        match self
+            .workspace_repository
+            .get_workspace_with_organization(api_key.workspace_id.clone())
+            .await
+        {
+            Ok(Some((workspace, organization))) => Ok(AuthenticatedApiKey {
+                api_key,
+                workspace,
+                organization,
+            }),
+            Ok(None) => Err(line_error(
+                StatusCode::UNAUTHORIZED,
+                ErrorResponse::new(
+                    "Workspace not found for API key".to_string(),
+                    "invalid_api_key".to_string(),
+                ),
+            )),
+            Err(_) => {
+                error!(batch_id = %batch.id, "Failed to resolve workspace for batch");
+                Err(BatchLineOutcome::Deferred)
+            }
+        }
+    }
+}
+
+fn line_error(status: StatusCode, body: ErrorResponse) -> BatchLineOutcome {
+    BatchLineOutcome::Done {
+        status_code: status.as_u16(),
+        body: serde_json::to_value(body).unwrap_or(serde_json::Value::Null),
+    }
+}
+
+#[async_trait]
+impl BatchRequestExecutor for CompletionBatchExecutor {
+    async fn execute(&self, batch: &Batch, line: &BatchRequestLine) -> BatchLineOutcome {
+        let api_key = match self.resolve_api_key(batch).await {
+            Ok(api_key) => api_key,
+            Err(outcome) => return outcome,
+        };
+
+        // The per-key rate limit is shared with interactive traffic; a line
+        // over it waits for a later pass instead of failing.
+        if check_rate_limit_for_api_key(&self.rate_limit_state, &api_key)
+            .await
+            .is_err()
+        {
+            return BatchLineOutcome::Deferred;
+        }
+
+        // Same spend, budget and credit gates as `usage_check_middleware`;
+        // a check that fails on a server error is retried later.
+        if let Err((status, Json(body))) =
+            check_usage_for_api_key(&self.usage_state, &api_key).await
+        {
+            if status.is_server_error() {
+                return BatchLineOutcome::Deferred;
+            }
+            return line_error(status, body);
+        }
+
+        let request: ChatCompletionRequest = match serde_json::from_value(line.body.clone()) {
+            Ok(request) => request,
+            Err(e) => {
+                return line_error(
+                    StatusCode::BAD_REQUEST,
+                    ErrorResponse::new(
+                        format!("Invalid request body: {e}"),
+                        "invalid_request_error".to_string(),
+                    ),
+                )
+            }
+        };
+        if let Err(error) = request.validate_request() {
+            return line_error(StatusCode::BAD_REQUEST, error);
+        }
+
+        let body_bytes = serde_json::to_vec(&line.body).unwrap_or_default();
+        let body_hash = RequestBodyHash {
+            hash: hex::encode(Sha256::digest(&body_bytes)),
+            body_bytes: body_bytes.into(),
+        };
+        let mut service_request = convert_chat_request_to_service(
+            &request,
+            batch.created_by_user_id,
+            batch.api_key_id.to_string(),
+            batch.organization_id,
+            batch.workspace_id,
+            body_hash,
+            Uuid::new_v4(),
+        );
+        // Batch lines yield concurrent slots to interactive traffic.
+        service_request.priority = RequestPriority::Low;
+
+        match self
+            .completion_service
+            .create_chat_completion(service_request)
+            .await
+        {
+            Ok(response_with_bytes) => BatchLineOutcome::Done {
+                status_code: StatusCode::OK.as_u16(),
+                body: serde_json::from_slice(&response_with_bytes.raw_bytes).unwrap_or_else(|_| {
+                    serde_json::to_value(&response_with_bytes.response)
+                        .unwrap_or(serde_json::Value::Null)
+                }),
+            },
+            Err(e)
+                if matches!(
+                    e.root(),
+                    CompletionError::RateLimitExceeded(_) | CompletionError::ServiceOverloaded(_)
+                ) =>
+            {
+                warn!(
+                    batch_id = %batch.id,
+                    line_index = line.line_index,
+                    "Batch line hit a concurrency limit; deferring"
+                );
+                BatchLineOutcome::Deferred
+            }
+            Err(e) => line_error(map_domain_error_to_status(&e), e.into()),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::models::ErrorResponse;
 use axum::{http::HeaderMap, http::StatusCode, response::Json as ResponseJson};
 use services::completions::CompletionError;
-use services::organization::OrganizationError;
+use services::organization::{ListCursor, OrganizationError, PageCursor};
 use uuid::Uuid;
 
 /// HTTP 429 "Too Many Requests" — used here for the "all backends exhausted;
@@ -18,18 +18,30 @@ pub fn status_overloaded() -> StatusCode {
     StatusCode::TOO_MANY_REQUESTS
 }
 
+/// `Retry-After` seconds sent with a 503 `model_warming` response: the pool
+/// has not completed provider discovery since startup, which normally resolves
+/// within a refresh or two.
+pub const MODEL_WARMING_RETRY_AFTER_SECS: u64 = 5;
+
 /// Map domain errors to HTTP status codes
 pub fn map_domain_error_to_status(error: &CompletionError) -> StatusCode {
     match error {
         CompletionError::InvalidModel(_) | CompletionError::InvalidParams(_) => {
             StatusCode::BAD_REQUEST
         }
-        CompletionError::RateLimitExceeded(_) => StatusCode::TOO_MANY_REQUESTS,
+        CompletionError::ModelNotFound(_) => StatusCode::NOT_FOUND,
+        CompletionError::ModelNotAllowed(_) => StatusCode::FORBIDDEN,
+        CompletionError::RateLimitExceeded(_) | CompletionError::QuotaExceeded(_) => {
+            StatusCode::TOO_MANY_REQUESTS
+        }
         CompletionError::ProviderError { status_code, .. } => {
             StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
         }
         CompletionError::ServiceOverloaded(_) => status_overloaded(),
+        CompletionError::DuplicateRequest(_) => StatusCode::CONFLICT,
+        CompletionError::ModelWarming(_) => StatusCode::SERVICE_UNAVAILABLE,
         CompletionError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        CompletionError::ProvidersFailed { error, .. } => map_domain_error_to_status(error),
     }
 }
 
@@ -96,16 +108,58 @@ pub fn inject_warning_field(body: &[u8], warning: &str) -> Option<Vec<u8>> {
     serde_json::to_vec(&value).ok()
 }
 
-/// Validate pagination parameters (limit/offset pattern)
+/// Insert a top-level `"debug"` field into a serialized JSON object. Like
+/// [`inject_warning_field`], returns `None` for non-object bodies and breaks
+/// response-hash verification; only admin debug requests use it.
+pub fn inject_debug_field(body: &[u8], debug: serde_json::Value) -> Option<Vec<u8>> {
+    let mut value: serde_json::Value = serde_json::from_slice(body).ok()?;
+    value.as_object_mut()?.insert("debug".to_string(), debug);
+    serde_json::to_vec(&value).ok()
+}
+
+/// Page-size bounds for `limit`/`offset` listings, taken from `ServerConfig`
+/// and carried in each route state that serves paginated listings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageSizeLimits {
+    /// Page size used when a listing request omits `limit`
+    pub default_page_size: i64,
+    /// Larger requested limits are clamped to this
+    pub max_page_size: i64,
+}
+
+impl Default for PageSizeLimits {
+    fn default() -> Self {
+        Self {
+            default_page_size: config::DEFAULT_PAGE_SIZE,
+            max_page_size: config::DEFAULT_MAX_PAGE_SIZE,
+        }
+    }
+}
+
+impl From<&config::ServerConfig> for PageSizeLimits {
+    fn from(server: &config::ServerConfig) -> Self {
+        Self {
+            default_page_size: server.default_page_size,
+            max_page_size: server.max_page_size,
+        }
+    }
+}
+
+/// Validate pagination parameters (limit/offset pattern) and return the
+/// effective limit.
 ///
 /// Ensures:
 /// - limit is positive (> 0)
-/// - limit does not exceed 1000
 /// - offset is non-negative (>= 0)
+///
+/// A missing limit means the default page size; a limit above the maximum
+/// page size is clamped to it.
 pub fn validate_limit_offset(
-    limit: i64,
+    page_size_limits: PageSizeLimits,
+    limit: Option<i64>,
     offset: i64,
-) -> Result<(), (StatusCode, ResponseJson<ErrorResponse>)> {
+) -> Result<i64, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let limit = limit.unwrap_or(page_size_limits.default_page_size);
     if limit <= 0 {
         return Err((
             StatusCode::BAD_REQUEST,
@@ -115,29 +169,125 @@ pub fn validate_limit_offset(
             )),
         ));
     }
-    if limit > 1000 {
+    if offset < 0 {
         return Err((
             StatusCode::BAD_REQUEST,
             ResponseJson(ErrorResponse::new(
-                "Limit cannot exceed 1000".to_string(),
+                "Offset must be non-negative".to_string(),
                 "invalid_parameter".to_string(),
             )),
         ));
     }
-    if offset < 0 {
-        return Err((
+    Ok(limit.min(page_size_limits.max_page_size))
+}
+
+const LIST_CURSOR_VERSION: u8 = 1;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ListCursorPayload {
+    v: u8,
+    key: chrono::DateTime<chrono::Utc>,
+    id: Uuid,
+}
+
+/// Encode a keyset position as an opaque, URL-safe cursor string.
+pub fn encode_list_cursor(cursor: ListCursor) -> String {
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+    let payload = ListCursorPayload {
+        v: LIST_CURSOR_VERSION,
+        key: cursor.sort_key,
+        id: cursor.id,
+    };
+    // Serializing a timestamp and a UUID cannot fail.
+    URL_SAFE_NO_PAD.encode(serde_json::to_vec(&payload).unwrap_or_default())
+}
+
+fn decode_list_cursor(value: &str) -> Option<ListCursor> {
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+    let bytes = URL_SAFE_NO_PAD.decode(value).ok()?;
+    let payload: ListCursorPayload = serde_json::from_slice(&bytes).ok()?;
+    (payload.v == LIST_CURSOR_VERSION).then_some(ListCursor {
+        sort_key: payload.key,
+        id: payload.id,
+    })
+}
+
+/// Resolve the `after`/`before` list query parameters into a page cursor.
+///
+/// At most one of them may be set, and not together with a non-zero
+/// `offset`: cursors replace offset pagination rather than combine with it.
+pub fn parse_page_cursor(
+    after: Option<&str>,
+    before: Option<&str>,
+    offset: i64,
+) -> Result<Option<PageCursor>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let invalid = |message: &str| {
+        (
             StatusCode::BAD_REQUEST,
             ResponseJson(ErrorResponse::new(
-                "Offset must be non-negative".to_string(),
+                message.to_string(),
                 "invalid_parameter".to_string(),
             )),
-        ));
+        )
+    };
+    let cursor = match (after, before) {
+        (None, None) => return Ok(None),
+        (Some(_), Some(_)) => return Err(invalid("Specify at most one of after or before")),
+        (Some(value), None) => PageCursor::After(
+            decode_list_cursor(value).ok_or_else(|| invalid("Invalid after cursor"))?,
+        ),
+        (None, Some(value)) => PageCursor::Before(
+            decode_list_cursor(value).ok_or_else(|| invalid("Invalid before cursor"))?,
+        ),
+    };
+    if offset != 0 {
+        return Err(invalid("offset cannot be combined with after or before"));
     }
-    Ok(())
+    Ok(Some(cursor))
 }
 
-pub fn default_limit() -> i64 {
-    100
+/// Trim a keyset page fetched with `limit + 1` rows and compute its cursors.
+///
+/// The extra row only signals that more rows exist past the page. Returns
+/// the page plus `next_cursor` (continue with `after`) and
+/// `previous_cursor` (continue with `before`), each `None` when there is
+/// nothing in that direction.
+pub fn keyset_page<T>(
+    mut rows: Vec<T>,
+    limit: i64,
+    offset: i64,
+    cursor: Option<PageCursor>,
+    position: impl Fn(&T) -> ListCursor,
+) -> (Vec<T>, Option<String>, Option<String>) {
+    let limit = usize::try_from(limit).unwrap_or(0);
+    let has_more = rows.len() > limit;
+    let (more_after, more_before) = match cursor {
+        Some(PageCursor::Before(_)) => {
+            if has_more {
+                rows.drain(..rows.len() - limit);
+            }
+            (true, has_more)
+        }
+        Some(PageCursor::After(_)) => {
+            rows.truncate(limit);
+            (has_more, true)
+        }
+        None => {
+            rows.truncate(limit);
+            (has_more, offset > 0)
+        }
+    };
+    let next_cursor = rows
+        .last()
+        .filter(|_| more_after)
+        .map(|row| encode_list_cursor(position(row)));
+    let previous_cursor = rows
+        .first()
+        .filter(|_| more_before)
+        .map(|row| encode_list_cursor(position(row)));
+    (rows, next_cursor, previous_cursor)
 }
 
 /// Map an analytics granularity string to the PostgreSQL `DATE_TRUNC` literal.
@@ -567,6 +717,13 @@ pub fn map_organization_error(
                 "conflict".to_string(),
             )),
         ),
+        OrganizationError::SeatLimitExceeded => (
+            StatusCode::CONFLICT,
+            ResponseJson(ErrorResponse::new(
+                error.to_string(),
+                "seat_limit_exceeded".to_string(),
+            )),
+        ),
         OrganizationError::InternalError(msg) => {
             tracing::error!("Organization internal error: {}", msg);
             (
@@ -584,6 +741,63 @@ pub fn map_organization_error(
 mod tests {
     use super::*;
 
+    fn list_cursor(seconds: i64) -> ListCursor {
+        ListCursor {
+            sort_key: chrono::DateTime::from_timestamp(seconds, 123_456_000).unwrap(),
+            id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_list_cursor_round_trips() {
+        let cursor = list_cursor(1_700_000_000);
+        let encoded = encode_list_cursor(cursor);
+
+        assert_eq!(
+            parse_page_cursor(Some(&encoded), None, 0).unwrap(),
+            Some(PageCursor::After(cursor))
+        );
+        assert_eq!(
+            parse_page_cursor(None, Some(&encoded), 0).unwrap(),
+            Some(PageCursor::Before(cursor))
+        );
+    }
+
+    #[test]
+    fn test_parse_page_cursor_rejects_invalid_combinations() {
+        let encoded = encode_list_cursor(list_cursor(1));
+
+        assert!(parse_page_cursor(Some("not-a-cursor"), None, 0).is_err());
+        assert!(parse_page_cursor(Some(&encoded), Some(&encoded), 0).is_err());
+        assert!(parse_page_cursor(Some(&encoded), None, 10).is_err());
+        assert_eq!(parse_page_cursor(None, None, 10).unwrap(), None);
+    }
+
+    #[test]
+    fn test_keyset_page_cursors() {
+        let rows: Vec<ListCursor> = (0..3).map(list_cursor).collect();
+
+        // First page with an extra row: more after, nothing before.
+        let (page, next, previous) = keyset_page(rows.clone(), 2, 0, None, |row| *row);
+        assert_eq!(page, rows[..2]);
+        assert_eq!(next, Some(encode_list_cursor(rows[1])));
+        assert_eq!(previous, None);
+
+        // Backwards page with an extra row drops the row farthest from the cursor.
+        let cursor = Some(PageCursor::Before(list_cursor(9)));
+        let (page, next, previous) = keyset_page(rows.clone(), 2, 0, cursor, |row| *row);
+        assert_eq!(page, rows[1..]);
+        assert_eq!(next, Some(encode_list_cursor(rows[2])));
+        assert_eq!(previous, Some(encode_list_cursor(rows[1])));
+
+        // Last forward page: no next cursor.
+        let cursor = Some(PageCursor::After(list_cursor(9)));
+        let (page, next, previous) = keyset_page(rows[..1].to_vec(), 2, 0, cursor, |row| *row);
+        assert_eq!(page.len(), 1);
+        assert_eq!(next, None);
+        assert_eq!(previous, Some(encode_list_cursor(rows[0])));
+    }
+
     #[test]
     fn test_parse_legacy_file_reference_valid_with_prefix() {
         let result =
@@ -653,28 +867,47 @@ mod tests {
 
     #[test]
     fn test_validate_limit_offset() {
+        let limits = PageSizeLimits::default();
+
         // Valid cases
-        assert!(validate_limit_offset(10, 0).is_ok());
-        assert!(validate_limit_offset(1000, 100).is_ok());
+        assert_eq!(validate_limit_offset(limits, Some(10), 0).unwrap(), 10);
+        assert_eq!(
+            validate_limit_offset(limits, Some(1000), 100).unwrap(),
+            1000
+        );
+
+        // A missing limit is the default page size
+        assert_eq!(
+            validate_limit_offset(limits, None, 0).unwrap(),
+            config::DEFAULT_PAGE_SIZE
+        );
+
+        // Over the maximum page size is clamped, not rejected
+        assert_eq!(
+            validate_limit_offset(limits, Some(1_000_000), 0).unwrap(),
+            config::DEFAULT_MAX_PAGE_SIZE
+        );
+
+        // The bounds come from the limits passed in
+        let small = PageSizeLimits {
+            default_page_size: 5,
+            max_page_size: 20,
+        };
+        assert_eq!(validate_limit_offset(small, None, 0).unwrap(), 5);
+        assert_eq!(validate_limit_offset(small, Some(50), 0).unwrap(), 20);
 
         // Invalid limit <= 0
-        let err = validate_limit_offset(0, 0).unwrap_err();
+        let err = validate_limit_offset(limits, Some(0), 0).unwrap_err();
         assert_eq!(err.0, StatusCode::BAD_REQUEST);
         assert_eq!(err.1 .0.error.message, "Limit must be positive");
         assert_eq!(err.1 .0.error.r#type, "invalid_parameter");
 
-        let err = validate_limit_offset(-1, 0).unwrap_err();
+        let err = validate_limit_offset(limits, Some(-1), 0).unwrap_err();
         assert_eq!(err.0, StatusCode::BAD_REQUEST);
         assert_eq!(err.1 .0.error.message, "Limit must be positive");
 
-        // Invalid limit > 1000
-        let err = validate_limit_offset(1001, 0).unwrap_err();
-        assert_eq!(err.0, StatusCode::BAD_REQUEST);
-        assert_eq!(err.1 .0.error.message, "Limit cannot exceed 1000");
-        assert_eq!(err.1 .0.error.r#type, "invalid_parameter");
-
         // Invalid offset < 0
-        let err = validate_limit_offset(10, -1).unwrap_err();
+        let err = validate_limit_offset(limits, Some(10), -1).unwrap_err();
         assert_eq!(err.0, StatusCode::BAD_REQUEST);
         assert_eq!(err.1 .0.error.message, "Offset must be non-negative");
         assert_eq!(err.1 .0.error.r#type, "invalid_parameter");
@@ -728,12 +961,27 @@ mod tests {
         assert_eq!(map_domain_error_to_status(&error), StatusCode::BAD_REQUEST);
     }
 
+    #[test]
+    fn test_map_domain_error_model_not_found() {
+        let error = CompletionError::ModelNotFound("no provider".to_string());
+        assert_eq!(map_domain_error_to_status(&error), StatusCode::NOT_FOUND);
+    }
+
     #[test]
     fn test_map_domain_error_service_overloaded() {
         let error = CompletionError::ServiceOverloaded("overloaded".to_string());
         assert_eq!(map_domain_error_to_status(&error).as_u16(), 429);
     }
 
+    #[test]
+    fn test_map_domain_error_model_warming() {
+        let error = CompletionError::ModelWarming("not ready".to_string());
+        assert_eq!(
+            map_domain_error_to_status(&error),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+    }
+
     #[test]
     fn test_validate_encryption_version_valid() {
         let mut headers = HeaderMap::new();
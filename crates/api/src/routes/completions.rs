@@ -1,11 +1,12 @@
 use crate::{
-    middleware::{auth::AuthenticatedApiKey, RequestBodyHash, RequestCorrelation},
+    middleware::{auth::AuthenticatedApiKey, RequestBodyHash, RequestCorrelation, RequestModel},
     models::*,
     routes::{
         api::AppState,
         common::{
-            alias_warning_message, inject_warning_field, map_domain_error_to_status,
-            no_aliasing_requested, HEADER_MODEL_ALIAS_RESOLVED, HEADER_NO_ALIASING,
+            alias_warning_message, inject_debug_field, inject_warning_field,
+            map_domain_error_to_status, no_aliasing_requested, HEADER_MODEL_ALIAS_RESOLVED,
+            HEADER_NO_ALIASING, MODEL_WARMING_RETRY_AFTER_SECS,
         },
         extractors::OpenAiJson,
         files::MAX_FILE_SIZE,
@@ -13,19 +14,26 @@ use crate::{
 };
 use axum::{
     body::{Body, Bytes},
-    extract::{Extension, Multipart, State},
+    extract::{Extension, Multipart, Query, State},
     http::{header, StatusCode},
     response::{IntoResponse, Json as ResponseJson, Response},
 };
 use futures::stream::StreamExt;
+use serde::Deserialize;
 use services::auto_redact::{self, AutoRedactError, RedactionMap, StreamUnredact};
 use services::common::encryption_headers as service_encryption_headers;
 use services::completions::{
-    hash_inference_id_to_uuid,
-    ports::{CompletionMessage, CompletionRequest as ServiceCompletionRequest},
+    capture_assembled_prompt,
+    ports::{
+        CompletionError as ServiceCompletionError, CompletionMessage,
+        CompletionRequest as ServiceCompletionRequest, RequestPriority,
+    },
+    InferenceIdNamespace,
 };
+use services::inference_provider_pool::capture_provider_error_detail;
 use sha2::{Digest, Sha256};
 use std::convert::Infallible;
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, Instrument};
@@ -90,9 +98,75 @@ fn insert_request_id_header(
     );
 }
 
+/// Copy allowlisted client headers into `extra` for the provider to forward.
+///
+/// Any `x_passthrough_headers` the client put in the JSON body is discarded
+/// first, so only headers that passed the operator allowlist reach the provider.
+fn insert_passthrough_headers(
+    config: &config::ProviderHeadersConfig,
+    headers: &header::HeaderMap,
+    extra: &mut std::collections::HashMap<String, serde_json::Value>,
+) {
+    extra.remove(services::common::PASSTHROUGH_HEADERS_KEY);
+    let forwarded: serde_json::Map<String, serde_json::Value> = headers
+        .iter()
+        .filter(|(name, _)| config.allows(name.as_str()))
+        .filter_map(|(name, value)| {
+            let value = value.to_str().ok()?;
+            Some((name.as_str().to_string(), value.to_string().into()))
+        })
+        .collect();
+    if !forwarded.is_empty() {
+        extra.insert(
+            services::common::PASSTHROUGH_HEADERS_KEY.to_string(),
+            serde_json::Value::Object(forwarded),
+        );
+    }
+}
+
+/// Request header capping how many distinct providers the gateway tries for
+/// this request before giving up (it can only lower the configured cap).
+const HEADER_MAX_FALLBACK_ATTEMPTS: &str = "x-max-fallback-attempts";
+
+/// Copy a valid `x-max-fallback-attempts` header into `extra` for the provider
+/// pool. Any value the client put in the JSON body under the internal key is
+/// discarded first; a header that is not a positive integer is rejected.
+fn insert_max_fallback_attempts(
+    headers: &header::HeaderMap,
+    extra: &mut std::collections::HashMap<String, serde_json::Value>,
+) -> Result<(), (StatusCode, ResponseJson<ErrorResponse>)> {
+    extra.remove(services::common::MAX_FALLBACK_ATTEMPTS_KEY);
+    let Some(value) = headers.get(HEADER_MAX_FALLBACK_ATTEMPTS) else {
+        return Ok(());
+    };
+    let attempts = value
+        .to_str()
+        .ok()
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .filter(|&n| n > 0)
+        .ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse::new(
+                    "Invalid X-Max-Fallback-Attempts: must be a positive integer".to_string(),
+                    "invalid_parameter".to_string(),
+                )),
+            )
+        })?;
+    extra.insert(
+        services::common::MAX_FALLBACK_ATTEMPTS_KEY.to_string(),
+        attempts.into(),
+    );
+    Ok(())
+}
+
 // Custom header for exposing the inference ID as a UUID
 const HEADER_INFERENCE_ID: &str = "Inference-Id";
 
+/// Carries the caller's own admin session (`Bearer <access token>`) for the
+/// admin debug flags; the API key alone never grants them.
+const HEADER_ADMIN_AUTHORIZATION: &str = "x-admin-authorization";
+
 // Custom header surfacing which trust tier served a completion.
 // Value is "near" for NEAR AI's own TEE fleet, "chutes" for the attested Chutes
 // fallback, or "non-attested" for external (non-TEE) providers.
@@ -100,7 +174,7 @@ const HEADER_SERVING_PROVIDER: &str = "x-serving-provider";
 
 /// Map a [`inference_providers::ProviderTier`] to the string value emitted in
 /// the `x-serving-provider` response header.
-fn provider_tier_to_str(tier: inference_providers::ProviderTier) -> &'static str {
+pub(crate) fn provider_tier_to_str(tier: inference_providers::ProviderTier) -> &'static str {
     match tier {
         inference_providers::ProviderTier::Near => "near",
         inference_providers::ProviderTier::Attested3p => "chutes",
@@ -129,12 +203,16 @@ fn e2ee_requested(encryption_headers: &crate::routes::common::EncryptionHeaders)
 async fn reject_if_aliased(
     models_service: &Arc<dyn services::models::ModelsServiceTrait>,
     headers: &header::HeaderMap,
+    organization_id: Uuid,
     requested_model: &str,
 ) -> Result<(), Response> {
     if !no_aliasing_requested(headers) {
         return Ok(());
     }
-    match models_service.resolve_and_get_model(requested_model).await {
+    match models_service
+        .resolve_and_get_model_for_organization(organization_id, requested_model)
+        .await
+    {
         Ok(m) if m.model_name != requested_model => Err((
             StatusCode::BAD_REQUEST,
             ResponseJson(ErrorResponse::new(
@@ -164,6 +242,26 @@ async fn reject_if_aliased(
     }
 }
 
+/// Enforce the organization's model allowlist for endpoints that call the
+/// provider pool directly rather than through a completion service method.
+async fn reject_if_model_not_allowed(
+    app_state: &AppState,
+    organization_id: Uuid,
+    model_name: &str,
+) -> Result<(), Response> {
+    app_state
+        .completion_service
+        .ensure_model_allowed(organization_id, model_name)
+        .await
+        .map_err(|domain_error| {
+            (
+                map_domain_error_to_status(&domain_error),
+                ResponseJson::<ErrorResponse>(domain_error.into()),
+            )
+                .into_response()
+        })
+}
+
 struct ImageUsageRecord<'a> {
     organization_id: Uuid,
     workspace_id: Uuid,
@@ -173,6 +271,7 @@ struct ImageUsageRecord<'a> {
     image_count: i32,
     provider_attribution: services::usage::ProviderAttribution,
     inference_type: services::usage::InferenceType,
+    inference_id_namespace: InferenceIdNamespace,
 }
 
 /// Build a `RecordUsageServiceRequest` for image operations (generation or editing).
@@ -190,12 +289,17 @@ fn build_image_usage_request(
         inference_type: record.inference_type,
         ttft_ms: None,
         avg_itl_ms: None,
-        inference_id: Some(hash_inference_id_to_uuid(record.provider_request_id)),
+        inference_id: Some(
+            record
+                .inference_id_namespace
+                .hash(record.provider_request_id),
+        ),
         provider_request_id: Some(record.provider_request_id.to_string()),
         stop_reason: Some(services::usage::StopReason::Completed),
         response_id: None,
         image_count: Some(record.image_count),
         provider_attribution: record.provider_attribution,
+        estimated: false,
     }
 }
 
@@ -308,9 +412,13 @@ fn completion_stream_error_category(e: &inference_providers::CompletionError) ->
         inference_providers::CompletionError::HttpError { .. } => "http_error",
         inference_providers::CompletionError::InvalidResponse(_) => "invalid_response",
         inference_providers::CompletionError::NoPubKeyProvider(_) => "stale_pubkey",
+        inference_providers::CompletionError::ModelWarming(_) => "model_warming",
         inference_providers::CompletionError::Unknown(_) => "unknown",
         inference_providers::CompletionError::ClientMediaError(_) => "client_media_error",
         inference_providers::CompletionError::Timeout { .. } => "timeout",
+        inference_providers::CompletionError::AllProvidersFailed { last_error, .. } => {
+            completion_stream_error_category(last_error)
+        }
     }
 }
 
@@ -335,7 +443,11 @@ fn completion_stream_error_openai_type(e: &inference_providers::CompletionError)
         | inference_providers::CompletionError::InvalidResponse(_)
         | inference_providers::CompletionError::Unknown(_)
         | inference_providers::CompletionError::NoPubKeyProvider(_)
+        | inference_providers::CompletionError::ModelWarming(_)
         | inference_providers::CompletionError::Timeout { .. } => "server_error",
+        inference_providers::CompletionError::AllProvidersFailed { last_error, .. } => {
+            completion_stream_error_openai_type(last_error)
+        }
     }
 }
 
@@ -517,7 +629,9 @@ fn rewritten_control_event_bytes(event: &inference_providers::SSEEvent) -> Optio
     let line = String::from_utf8_lossy(&event.raw_bytes);
     if line.trim_start().starts_with(':') {
         let mut bytes = event.raw_bytes.to_vec();
-        if bytes.ends_with(b"\n") {
+        if bytes.ends_with(b"\n\n") {
+            // Already a complete event (e.g. a gateway keep-alive comment).
+        } else if bytes.ends_with(b"\n") {
             bytes.push(b'\n');
         } else {
             bytes.extend_from_slice(b"\n\n");
@@ -555,12 +669,308 @@ fn build_final_usage_chunk_bytes(
 }
 
 // Helper function to extract inference ID from a parsed stream chunk
-fn extract_inference_id_from_chunk(chunk: &inference_providers::StreamChunk) -> Uuid {
+fn extract_inference_id_from_chunk(
+    chunk: &inference_providers::StreamChunk,
+    namespace: InferenceIdNamespace,
+) -> Uuid {
     let id = match chunk {
         inference_providers::StreamChunk::Chat(c) => &c.id,
         inference_providers::StreamChunk::Text(c) => &c.id,
     };
-    hash_inference_id_to_uuid(id)
+    namespace.hash(id)
+}
+
+/// SSE comment sent while a stream waits for its first upstream chunk.
+const STREAM_KEEPALIVE_COMMENT: &[u8] = b": keep-alive\n\n";
+
+type SSEEventResult = Result<inference_providers::SSEEvent, inference_providers::CompletionError>;
+
+/// Wraps a provider event stream and races a heartbeat timer against it:
+/// while the inner stream is pending and no chunk has arrived yet, each tick
+/// yields a `: keep-alive` comment control event so proxies and browsers see
+/// bytes before a slow first token. The first parsed chunk, `[DONE]`
+/// terminator, error, or end of stream permanently disables the timer, so a
+/// heartbeat can never follow `[DONE]`. Heartbeats are emitted as control
+/// events (not raw bytes) so they flow through the same forwarding and
+/// gateway-signature hashing path as upstream comments.
+struct KeepAliveStream<S> {
+    inner: S,
+    heartbeat: Option<tokio::time::Interval>,
+}
+
+impl<S> KeepAliveStream<S> {
+    fn new(inner: S, interval: Option<Duration>) -> Self {
+        let heartbeat = interval.map(|period| {
+            let mut heartbeat = tokio::time::interval(period);
+            heartbeat.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            heartbeat
+        });
+        Self { inner, heartbeat }
+    }
+}
+
+impl<S> futures::Stream for KeepAliveStream<S>
+where
+    S: futures::Stream<Item = SSEEventResult> + Unpin,
+{
+    type Item = SSEEventResult;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        match self.inner.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(event))) => {
+                if event.chunk.is_some() || event.is_done_marker() {
+                    self.heartbeat = None;
+                } else if let Some(heartbeat) = self.heartbeat.as_mut() {
+                    // Upstream control lines already keep the connection
+                    // warm; restart the heartbeat period from here.
+                    heartbeat.reset();
+                }
+                return Poll::Ready(Some(Ok(event)));
+            }
+            Poll::Ready(other) => {
+                self.heartbeat = None;
+                return Poll::Ready(other);
+            }
+            Poll::Pending => {}
+        }
+
+        let Some(heartbeat) = self.heartbeat.as_mut() else {
+            return Poll::Pending;
+        };
+        match heartbeat.poll_tick(cx) {
+            Poll::Ready(_) => Poll::Ready(Some(Ok(inference_providers::SSEEvent {
+                raw_bytes: Bytes::from_static(STREAM_KEEPALIVE_COMMENT),
+                chunk: None,
+                raw_passthrough: true,
+            }))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Settings for [`CoalescingStream`], taken from
+/// `server.stream_coalesce_window_ms` / `server.stream_coalesce_max_bytes`.
+#[derive(Debug, Clone, Copy)]
+struct StreamCoalescing {
+    /// Longest a content delta may be held waiting for more to merge with.
+    window: Duration,
+    /// Merged content at or above this many bytes is forwarded immediately.
+    max_bytes: usize,
+}
+
+/// A chat chunk carrying nothing but a plain `content` delta for one choice,
+/// which is all [`CoalescingStream`] ever merges. Anything else — role,
+/// tool-call or reasoning deltas, logprobs, `finish_reason`, usage — is a
+/// boundary that is forwarded as its own chunk.
+fn coalescable_content_chunk(
+    event: &inference_providers::SSEEvent,
+) -> Option<&inference_providers::models::ChatCompletionChunk> {
+    let Some(inference_providers::StreamChunk::Chat(chat)) = &event.chunk else {
+        return None;
+    };
+    let [choice] = chat.choices.as_slice() else {
+        return None;
+    };
+    let delta = choice.delta.as_ref()?;
+    let plain_content = delta.content.is_some()
+        && delta.role.is_none()
+        && delta.name.is_none()
+        && delta.tool_call_id.is_none()
+        && delta.tool_calls.is_none()
+        && delta.reasoning_content.is_none()
+        && delta.reasoning.is_none()
+        && delta.extra.is_empty();
+    (plain_content
+        && chat.usage.is_none()
+        && chat.prompt_token_ids.is_none()
+        && choice.finish_reason.is_none()
+        && choice.logprobs.is_none()
+        && choice.token_ids.is_none())
+    .then_some(chat)
+}
+
+fn coalesced_content_len(event: &inference_providers::SSEEvent) -> usize {
+    coalescable_content_chunk(event)
+        .and_then(|chat| chat.choices[0].delta.as_ref()?.content.as_ref())
+        .map_or(0, String::len)
+}
+
+/// Append `next`'s content to `pending` if both are content deltas for the
+/// same completion and choice. Returns false (leaving `pending` untouched)
+/// when they can't be merged.
+fn merge_content_delta(
+    pending: &mut inference_providers::SSEEvent,
+    next: &inference_providers::models::ChatCompletionChunk,
+) -> bool {
+    let Some(current) = coalescable_content_chunk(pending) else {
+        return false;
+    };
+    if current.id != next.id
+        || current.model != next.model
+        || current.modality != next.modality
+        || current.extra != next.extra
+        || current.choices[0].index != next.choices[0].index
+    {
+        return false;
+    }
+    let Some(inference_providers::StreamChunk::Chat(current)) = pending.chunk.as_mut() else {
+        return false;
+    };
+    let addition = next.choices[0]
+        .delta
+        .as_ref()
+        .and_then(|delta| delta.content.as_deref())
+        .unwrap_or_default();
+    if let Some(content) = current.choices[0]
+        .delta
+        .as_mut()
+        .and_then(|delta| delta.content.as_mut())
+    {
+        content.push_str(addition);
+    }
+    true
+}
+
+fn is_blank_separator(event: &inference_providers::SSEEvent) -> bool {
+    event.chunk.is_none() && event.raw_bytes.trim_ascii().is_empty()
+}
+
+/// Opt-in smoothing for providers that emit many tiny chunks: adjacent plain
+/// content deltas are merged into one chunk until `max_bytes` of content has
+/// accumulated or `window` has passed since the first was held, whichever
+/// comes first. Any other event (a role/tool/reasoning delta, the
+/// `finish_reason` or usage chunk, a control line, an error, end of stream)
+/// first flushes what is held, so merging never crosses those boundaries.
+///
+/// Held chunks are re-serialized by the forwarding path (`raw_passthrough`
+/// is cleared), so the blank separator lines that framed them upstream are
+/// dropped. Callers must not enable this when the client verifies the bytes
+/// against the provider's own signature.
+struct CoalescingStream<S> {
+    inner: S,
+    config: Option<StreamCoalescing>,
+    pending: Option<inference_providers::SSEEvent>,
+    deadline: Option<std::pin::Pin<Box<tokio::time::Sleep>>>,
+    /// Event that ended the held run; forwarded right after it.
+    held: Option<SSEEventResult>,
+    /// Swallow the upstream separator that followed a held chunk.
+    drop_separator: bool,
+    inner_done: bool,
+}
+
+impl<S> CoalescingStream<S> {
+    fn new(inner: S, config: Option<StreamCoalescing>) -> Self {
+        Self {
+            inner,
+            config,
+            pending: None,
+            deadline: None,
+            held: None,
+            drop_separator: false,
+            inner_done: false,
+        }
+    }
+
+    fn flush(&mut self) -> Option<SSEEventResult> {
+        self.deadline = None;
+        self.pending.take().map(Ok)
+    }
+}
+
+impl<S> futures::Stream for CoalescingStream<S>
+where
+    S: futures::Stream<Item = SSEEventResult> + Unpin,
+{
+    type Item = SSEEventResult;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        let Some(config) = self.config else {
+            return self.inner.poll_next_unpin(cx);
+        };
+        let this = &mut *self;
+
+        if this.pending.is_none() {
+            if let Some(held) = this.held.take() {
+                return Poll::Ready(Some(held));
+            }
+        }
+        if this
+            .pending
+            .as_ref()
+            .is_some_and(|pending| coalesced_content_len(pending) >= config.max_bytes)
+        {
+            return Poll::Ready(this.flush());
+        }
+
+        loop {
+            if this.inner_done {
+                return Poll::Ready(this.flush());
+            }
+            match this.inner.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(mut event))) => {
+                    if this.drop_separator && is_blank_separator(&event) {
+                        continue;
+                    }
+                    let Some(next) = coalescable_content_chunk(&event) else {
+                        this.drop_separator = false;
+                        if this.pending.is_some() {
+                            this.held = Some(Ok(event));
+                            return Poll::Ready(this.flush());
+                        }
+                        return Poll::Ready(Some(Ok(event)));
+                    };
+                    this.drop_separator = true;
+                    if let Some(pending) = this.pending.as_mut() {
+                        if merge_content_delta(pending, next) {
+                            if coalesced_content_len(pending) >= config.max_bytes {
+                                return Poll::Ready(this.flush());
+                            }
+                            continue;
+                        }
+                    }
+                    // Start a new run; a run that couldn't absorb this delta
+                    // (different choice or completion) goes out first.
+                    event.raw_passthrough = false;
+                    let previous = this.flush();
+                    this.pending = Some(event);
+                    this.deadline = Some(Box::pin(tokio::time::sleep(config.window)));
+                    if previous.is_some() {
+                        return Poll::Ready(previous);
+                    }
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    this.drop_separator = false;
+                    if this.pending.is_some() {
+                        this.held = Some(Err(e));
+                        return Poll::Ready(this.flush());
+                    }
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Ready(None) => {
+                    this.inner_done = true;
+                    return Poll::Ready(this.flush());
+                }
+                Poll::Pending => {
+                    if let Some(deadline) = this.deadline.as_mut() {
+                        if deadline.as_mut().poll(cx).is_ready() {
+                            return Poll::Ready(this.flush());
+                        }
+                    }
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
 }
 
 // Convert MessageContent to serde_json::Value, preserving multimodal parts (images, audio, etc.)
@@ -575,7 +985,7 @@ fn message_content_to_value(content: &Option<MessageContent>) -> serde_json::Val
 }
 
 // Convert HTTP ChatCompletionRequest to service CompletionRequest
-fn convert_chat_request_to_service(
+pub(crate) fn convert_chat_request_to_service(
     request: &ChatCompletionRequest,
     user_id: Uuid,
     api_key_id: String,
@@ -586,11 +996,14 @@ fn convert_chat_request_to_service(
 ) -> ServiceCompletionRequest {
     // `presence_penalty` / `frequency_penalty` are typed fields on
     // `ChatCompletionRequest`, so `#[serde(flatten)] extra` never captures them.
-    // The service layer hardcodes the typed `ChatCompletionParams` penalty slots
-    // to `None`, so forward them through `extra` (matching seed/logprobs and the
-    // text-completion path) — otherwise they are silently dropped before reaching
-    // the self-hosted backend (nearai/cloud-api #622).
+    // The service request has no typed slot for them, so forward them through
+    // `extra` (matching seed/logit_bias and the text-completion path); the
+    // service validates them and moves them into the typed
+    // `ChatCompletionParams` fields (nearai/cloud-api #622).
     let mut extra = request.extra.clone();
+    // A string `priority` is an admission hint for this gateway, not a
+    // sampling param; keep it out of the provider request.
+    let priority = RequestPriority::take_hint(&mut extra);
     if let Some(presence_penalty) = request.presence_penalty {
         extra.insert(
             "presence_penalty".to_string(),
@@ -642,6 +1055,7 @@ fn convert_chat_request_to_service(
         body_hash: body_hash.hash.clone(),
         response_id: None, // Direct chat completions API calls don't have a response_id
         skip_provider_chat_signature: false,
+        priority,
         extra,
     }
 }
@@ -660,15 +1074,7 @@ async fn maybe_redact(
     service_request: &mut ServiceCompletionRequest,
     pool: &services::inference_provider_pool::InferenceProviderPool,
 ) -> Result<(bool, RedactionMap, i64), AutoRedactError> {
-    let header_values: Vec<&str> = headers
-        .get_all(auto_redact::AUTO_REDACT_HEADER)
-        .iter()
-        .filter_map(|v| v.to_str().ok())
-        .collect();
-    let body_field = service_request
-        .extra
-        .get(auto_redact::AUTO_REDACT_BODY_FIELD);
-    let enabled = auto_redact::is_enabled(header_values.iter().copied(), body_field);
+    let enabled = auto_redact_requested(headers, &service_request.extra);
 
     // Always strip the body field so providers with strict JSON schemas
     // (e.g. Anthropic) don't 400 on unknown keys.
@@ -717,6 +1123,206 @@ fn auto_redact_error_response(err: AutoRedactError) -> Response {
     }
 }
 
+/// True when auto-redact was requested via header or body field.
+fn auto_redact_requested(
+    headers: &header::HeaderMap,
+    extra: &std::collections::HashMap<String, serde_json::Value>,
+) -> bool {
+    let header_values: Vec<&str> = headers
+        .get_all(auto_redact::AUTO_REDACT_HEADER)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .collect();
+    auto_redact::is_enabled(
+        header_values.iter().copied(),
+        extra.get(auto_redact::AUTO_REDACT_BODY_FIELD),
+    )
+}
+
+/// Serve a chat completion that opted into server-side MCP tool execution.
+///
+/// Non-streaming requests get the final turn's signed bytes as a regular
+/// `chat.completion`; earlier turns are billed and signed on their own.
+/// Streaming requests get one SSE `tool_call`/`tool_result` event per
+/// executed tool, then the final turn as a single `chat.completion.chunk`,
+/// a usage chunk totalling every turn when `include_usage` is set, and
+/// `[DONE]`. The first loop event is awaited before responding so request
+/// errors (unknown model, rate limits) keep their HTTP status and the
+/// `Inference-Id` header can name the turn that started the loop.
+async fn auto_tool_execution_response(
+    app_state: &AppState,
+    service_request: ServiceCompletionRequest,
+    stream: bool,
+    include_usage: bool,
+) -> Response {
+    let mut events = app_state.auto_tool_service.run(service_request);
+    let first = match events.next().await {
+        Some(Ok(event)) => event,
+        Some(Err(domain_error)) => {
+            let status_code = map_domain_error_to_status(&domain_error);
+            return (
+                status_code,
+                ResponseJson::<ErrorResponse>(domain_error.into()),
+            )
+                .into_response();
+        }
+        None => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse::new(
+                    "Tool execution ended without a response".to_string(),
+                    "internal_server_error".to_string(),
+                )),
+            )
+                .into_response();
+        }
+    };
+
+    if stream {
+        let first_completion_id = match &first {
+            services::auto_tools::ToolLoopEvent::ToolCall(call) => call.completion_id.clone(),
+            services::auto_tools::ToolLoopEvent::ToolResult(result) => result.completion_id.clone(),
+            services::auto_tools::ToolLoopEvent::Completed { response, .. } => {
+                response.response.id.clone()
+            }
+        };
+        let body = futures::stream::once(std::future::ready(Ok(first)))
+            .chain(events)
+            .map(move |event| {
+                Ok::<Bytes, Infallible>(Bytes::from(match event {
+                    Ok(event) => auto_tool_sse_frame(event, include_usage),
+                    Err(domain_error) => {
+                        let error: ErrorResponse = domain_error.into();
+                        format!(
+                            "data: {}\n\n",
+                            serde_json::to_string(&error).unwrap_or_default()
+                        )
+                    }
+                }))
+            });
+        return Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "text/event-stream")
+            .header(header::CACHE_CONTROL, "no-cache")
+            .header(header::CONNECTION, "keep-alive")
+            .header(
+                HEADER_INFERENCE_ID,
+                app_state
+                    .inference_id_namespace
+                    .hash(&first_completion_id)
+                    .to_string(),
+            )
+            .header("Access-Control-Expose-Headers", HEADER_INFERENCE_ID)
+            .body(Body::from_stream(body))
+            .unwrap();
+    }
+
+    // Tool events only matter to streaming clients; wait for the final turn.
+    let mut next = Some(Ok(first));
+    loop {
+        match next {
+            Some(Ok(services::auto_tools::ToolLoopEvent::Completed { response, .. })) => {
+                return Response::builder()
+                    .status(StatusCode::OK)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .header(
+                        HEADER_INFERENCE_ID,
+                        app_state
+                            .inference_id_namespace
+                            .hash(&response.response.id)
+                            .to_string(),
+                    )
+                    .header(
+                        HEADER_SERVING_PROVIDER,
+                        provider_tier_to_str(response.serving_tier),
+                    )
+                    .header(
+                        "Access-Control-Expose-Headers",
+                        format!("{HEADER_INFERENCE_ID}, {HEADER_SERVING_PROVIDER}"),
+                    )
+                    .body(Body::from(response.raw_bytes))
+                    .unwrap();
+            }
+            Some(Ok(_)) => next = events.next().await,
+            Some(Err(domain_error)) => {
+                let status_code = map_domain_error_to_status(&domain_error);
+                return (
+                    status_code,
+                    ResponseJson::<ErrorResponse>(domain_error.into()),
+                )
+                    .into_response();
+            }
+            None => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    ResponseJson(ErrorResponse::new(
+                        "Tool execution ended without a response".to_string(),
+                        "internal_server_error".to_string(),
+                    )),
+                )
+                    .into_response();
+            }
+        }
+    }
+}
+
+/// Encode one tool-loop event as an SSE frame. The final turn is emitted
+/// as a single `chat.completion.chunk` followed by `[DONE]`, so clients that
+/// ignore named events still read a standard completion stream. With
+/// `include_usage`, an OpenAI-style usage chunk (empty `choices`) carrying
+/// the total across all turns precedes `[DONE]`.
+fn auto_tool_sse_frame(event: services::auto_tools::ToolLoopEvent, include_usage: bool) -> String {
+    use services::auto_tools::ToolLoopEvent;
+    match event {
+        ToolLoopEvent::ToolCall(call) => format!(
+            "event: tool_call\ndata: {}\n\n",
+            serde_json::to_string(&call).unwrap_or_default()
+        ),
+        ToolLoopEvent::ToolResult(result) => format!(
+            "event: tool_result\ndata: {}\n\n",
+            serde_json::to_string(&result).unwrap_or_default()
+        ),
+        ToolLoopEvent::Completed {
+            response,
+            total_usage,
+        } => {
+            let response = response.response;
+            let choices: Vec<serde_json::Value> = response
+                .choices
+                .into_iter()
+                .map(|choice| {
+                    serde_json::json!({
+                        "index": choice.index,
+                        "delta": choice.message,
+                        "finish_reason": choice.finish_reason,
+                    })
+                })
+                .collect();
+            let chunk = serde_json::json!({
+                "id": response.id,
+                "object": "chat.completion.chunk",
+                "created": response.created,
+                "model": response.model,
+                "choices": choices,
+            });
+            let mut frame = format!("data: {chunk}\n\n");
+            if include_usage {
+                let usage_chunk = serde_json::json!({
+                    "id": response.id,
+                    "object": "chat.completion.chunk",
+                    "created": response.created,
+                    "model": response.model,
+                    "choices": [],
+                    "usage": total_usage,
+                });
+                frame.push_str(&format!("data: {usage_chunk}\n\n"));
+            }
+            frame.push_str("data: [DONE]\n\n");
+            frame
+        }
+    }
+}
+
 /// Upper bound on the spawned auto-redact billing task so a stuck billing DB
 /// or model lookup can't leak a background task indefinitely.
 const AUTO_REDACT_BILL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
@@ -809,6 +1415,7 @@ async fn bill_auto_redact_classify(
         response_id: None,
         image_count: None,
         provider_attribution: services::usage::ProviderAttribution::default(),
+        estimated: false,
     };
 
     if let Err(e) = app_state.usage_service.record_usage(usage_request).await {
@@ -1234,6 +1841,9 @@ fn convert_text_request_to_service(
     // are rejected upstream (see unsupported_completion_param) — they have no
     // equivalent under the translate-to-chat path — so they never reach here set.
     let mut extra = request.extra.clone();
+    // A string `priority` is an admission hint for this gateway, not a
+    // sampling param; keep it out of the provider request.
+    let priority = RequestPriority::take_hint(&mut extra);
     if let Some(presence_penalty) = request.presence_penalty {
         extra.insert(
             "presence_penalty".to_string(),
@@ -1271,6 +1881,7 @@ fn convert_text_request_to_service(
         body_hash: body_hash.hash.clone(),
         response_id: None, // Direct text completions API calls don't have a response_id
         skip_provider_chat_signature: false,
+        priority,
         extra,
     }
 }
@@ -1298,43 +1909,180 @@ fn unsupported_completion_param(request: &CompletionRequest) -> Option<&'static
     None
 }
 
+/// Query flags accepted by the completion endpoints
+#[derive(Debug, Default, Deserialize)]
+pub struct CompletionDebugQuery {
+    /// Include the unsanitized provider error as `error.details.detailed_error`.
+    /// Honored only when `X-Admin-Authorization` carries an admin session.
+    #[serde(default)]
+    pub debug_errors: bool,
+    /// Include the chat request as sent to the provider (after workspace
+    /// defaults, alias resolution and token limits) as a top-level `debug`
+    /// field. Non-streaming chat completions only; honored only when
+    /// `X-Admin-Authorization` carries an admin session, since it exposes the
+    /// full prompt.
+    #[serde(default)]
+    pub debug_prompt: bool,
+}
+
+/// Whether this request may see admin debug output: the caller asked for it
+/// and authenticated as an admin through `X-Admin-Authorization`, with either
+/// a session or an `adm_` access token, exactly as the admin routes accept.
+/// Who created the API key is irrelevant, since anyone holding the key can
+/// send requests with it.
+async fn admin_debug_allowed(
+    app_state: &AppState,
+    headers: &header::HeaderMap,
+    requested: bool,
+) -> bool {
+    if !requested {
+        return false;
+    }
+    let Some(token) = headers
+        .get(HEADER_ADMIN_AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+    else {
+        return false;
+    };
+    let user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok());
+    let Some(caller) = crate::middleware::auth::authenticate_admin_caller(
+        &app_state.auth_state,
+        token.trim(),
+        user_agent,
+    )
+    .await
+    else {
+        debug!("Admin debug output denied: invalid admin credentials");
+        return false;
+    };
+    // Read the email from the user record rather than the session, which
+    // may carry a stale or placeholder identity
+    match app_state
+        .user_service
+        .get_user(services::auth::UserId(caller.id))
+        .await
+    {
+        Ok(user) => crate::middleware::auth::check_admin_access(
+            &app_state.auth_state,
+            &database::User {
+                email: user.email,
+                ..caller
+            },
+        ),
+        Err(_) => false,
+    }
+}
+
+/// Await a completion call, capturing the assembled provider request when
+/// `capture` is set.
+async fn with_assembled_prompt<F: Future>(
+    capture: bool,
+    future: F,
+) -> (F::Output, Option<serde_json::Value>) {
+    if capture {
+        capture_assembled_prompt(future).await
+    } else {
+        (future.await, None)
+    }
+}
+
+/// Await a completion call, capturing raw provider errors when `capture` is set.
+async fn with_provider_error_detail<F: Future>(
+    capture: bool,
+    future: F,
+) -> (F::Output, Option<String>) {
+    if capture {
+        capture_provider_error_detail(future).await
+    } else {
+        (future.await, None)
+    }
+}
+
+fn completion_error_response(
+    domain_error: ServiceCompletionError,
+    detailed_error: Option<String>,
+) -> Response {
+    let status_code = map_domain_error_to_status(&domain_error);
+    let warming = matches!(domain_error, ServiceCompletionError::ModelWarming(_));
+    let mut body: ErrorResponse = domain_error.into();
+    if let Some(detailed_error) = detailed_error {
+        body.error
+            .details
+            .get_or_insert_with(Default::default)
+            .detailed_error = Some(detailed_error);
+    }
+    let mut response = (status_code, ResponseJson(body)).into_response();
+    if warming {
+        response.headers_mut().insert(
+            header::RETRY_AFTER,
+            header::HeaderValue::from(MODEL_WARMING_RETRY_AFTER_SECS),
+        );
+    }
+    response
+}
+
 /// Create chat completion
 ///
 /// Generate AI model responses for chat conversations. Supports both streaming and non-streaming modes.
 /// OpenAI-compatible endpoint.
+///
+/// The optional `X-Max-Fallback-Attempts` header caps how many of the model's
+/// providers are tried before the request fails.
 #[utoipa::path(
     post,
     path = "/v1/chat/completions",
     tag = "Chat",
     request_body = ChatCompletionRequest,
+    params(
+        ("debug_errors" = Option<bool>, Query, description = "Admin-only (requires an admin session in X-Admin-Authorization): include the unsanitized provider error in error.details.detailed_error"),
+        ("debug_prompt" = Option<bool>, Query, description = "Admin-only (requires an admin session in X-Admin-Authorization): include the request sent to the provider in a top-level debug field (non-streaming only)"),
+        ("X-Max-Fallback-Attempts" = Option<u32>, Header, description = "Most distinct providers to try before failing; can only lower the server's limit")
+    ),
     responses(
         (status = 200, description = "Completion generated successfully", body = ChatCompletionResponse),
         (status = 400, description = "Invalid request parameters", body = ErrorResponse),
         (status = 401, description = "Invalid or missing API key", body = ErrorResponse),
         (status = 402, description = "Insufficient credits", body = ErrorResponse),
+        (status = 404, description = "Model not served by any provider (error.type model_not_found)", body = ErrorResponse),
+        (status = 413, description = "Request body exceeds the configured size limit", body = ErrorResponse),
         (status = 500, description = "Server error", body = ErrorResponse),
-        (status = 429, description = "Rate limited or overloaded — retry with backoff. Check error.type: rate_limit_exceeded or service_overloaded.", body = ErrorResponse)
+        (status = 429, description = "Rate limited or overloaded — retry with backoff. Check error.type: rate_limit_exceeded or service_overloaded.", body = ErrorResponse),
+        (status = 503, description = "Model warming: provider discovery has not initialized yet; retry after Retry-After", body = ErrorResponse)
     ),
     security(
         ("api_key" = [])
     )
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn chat_completions(
     State(app_state): State<AppState>,
     Extension(api_key): Extension<AuthenticatedApiKey>,
     Extension(body_hash): Extension<RequestBodyHash>,
     Extension(correlation): Extension<RequestCorrelation>,
+    request_model: Option<Extension<RequestModel>>,
+    Query(debug): Query<CompletionDebugQuery>,
     headers: header::HeaderMap,
-    OpenAiJson(request): OpenAiJson<ChatCompletionRequest>,
+    OpenAiJson(mut request): OpenAiJson<ChatCompletionRequest>,
 ) -> axum::response::Response {
     debug!(
         "Chat completions request from api key: {:?}",
         api_key.api_key.id
     );
+    // Workspace defaults fill only what the client left unset, and must run
+    // before validation so a default model satisfies "model is required".
+    if let Some(defaults) = &api_key.workspace.default_params {
+        request.apply_workspace_defaults(defaults);
+    }
     debug!(
         "Request model: {}, stream: {:?}, org: {}, workspace: {}",
         request.model, request.stream, api_key.organization.id, api_key.workspace.id.0
     );
+    if let Some(Extension(request_model)) = &request_model {
+        request_model.set(&request.model);
+    }
     // Validate the request
     if let Err(error) = request.validate_request() {
         return (StatusCode::BAD_REQUEST, ResponseJson(error)).into_response();
@@ -1358,9 +2106,11 @@ pub async fn chat_completions(
         model = %request.model,
     );
 
-    chat_completions_inner(app_state, api_key, body_hash, headers, request, request_id)
-        .instrument(span)
-        .await
+    chat_completions_inner(
+        app_state, api_key, body_hash, headers, request, request_id, debug,
+    )
+    .instrument(span)
+    .await
 }
 
 // Inner async fn so .instrument(span) wraps all awaits in the handler.
@@ -1375,8 +2125,17 @@ async fn chat_completions_inner(
     headers: header::HeaderMap,
     request: ChatCompletionRequest,
     request_id: Uuid,
+    debug: CompletionDebugQuery,
 ) -> axum::response::Response {
     let request_hash = body_hash.hash.clone();
+    let admin_debug = admin_debug_allowed(
+        &app_state,
+        &headers,
+        debug.debug_errors || debug.debug_prompt,
+    )
+    .await;
+    let capture_error_detail = admin_debug && debug.debug_errors;
+    let capture_prompt = admin_debug && debug.debug_prompt;
 
     // Convert HTTP request to service parameters
     // Note: Names are not passed - high-cardinality data is tracked via database, not metrics
@@ -1398,16 +2157,54 @@ async fn chat_completions_inner(
 
     // Add validated headers to service_request.extra
     insert_encryption_headers(&encryption_headers, &mut service_request.extra);
+    insert_passthrough_headers(
+        &app_state.config.provider_headers,
+        &headers,
+        &mut service_request.extra,
+    );
+    if let Err(err) = insert_max_fallback_attempts(&headers, &mut service_request.extra) {
+        return err.into_response();
+    }
     let e2ee_active = e2ee_requested(&encryption_headers);
     let include_stream_usage_in_response = chat_stream_include_usage_requested(&request);
+    let auto_tool_execution = services::auto_tools::take_body_field(&mut service_request.extra);
 
     // Strict alias mode: refuse to serve through an alias before any
     // inference happens (issue #573).
-    if let Err(resp) = reject_if_aliased(&app_state.models_service, &headers, &request.model).await
+    if let Err(resp) = reject_if_aliased(
+        &app_state.models_service,
+        &headers,
+        api_key.organization.id.0,
+        &request.model,
+    )
+    .await
     {
         return resp;
     }
 
+    // Server-side MCP tool execution reads and rewrites the conversation
+    // between turns, which E2EE and auto-redact both rule out.
+    if auto_tool_execution {
+        if e2ee_active || auto_redact_requested(&headers, &service_request.extra) {
+            return (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse::new(
+                    "auto_tool_execution cannot be combined with end-to-end encryption or auto_redact"
+                        .to_string(),
+                    "invalid_request_error".to_string(),
+                )),
+            )
+                .into_response();
+        }
+        return auto_tool_execution_response(
+            &app_state,
+            service_request,
+            request.stream == Some(true),
+            include_stream_usage_in_response,
+        )
+        .await;
+    }
+
     // Pre-dispatch alias detection (issue #573): the canonical name when
     // the requested model is a registered alias, mirroring the resolution
     // the completion service is about to apply. Derived purely from the
@@ -1418,7 +2215,7 @@ async fn chat_completions_inner(
     // path); advisory only — strict mode above stays authoritative.
     let alias_canonical = app_state
         .models_service
-        .resolve_alias_cached(&request.model)
+        .resolve_alias_cached_for_organization(api_key.organization.id.0, &request.model)
         .await;
     let resolved_model_name = alias_canonical.as_deref().unwrap_or(&request.model);
     let model_attestation_supported = if request.stream == Some(true) {
@@ -1444,6 +2241,20 @@ async fn chat_completions_inner(
     let gateway_signature_enabled = usage_mode.gateway_signature_enabled;
     let strip_intermediate_usage = usage_mode.strip_intermediate_usage;
     service_request.skip_provider_chat_signature = gateway_signature_enabled;
+    // Keep-alive comments change the client-facing bytes, so skip them when
+    // those bytes are verified against the provider's own signature.
+    let stream_keepalive_interval = (app_state.config.server.stream_keepalive_interval_ms > 0
+        && (gateway_signature_enabled || model_attestation_supported != Some(true)))
+    .then(|| Duration::from_millis(app_state.config.server.stream_keepalive_interval_ms));
+    // Merging deltas rewrites the client-facing bytes too, and would splice
+    // ciphertext for E2EE streams.
+    let stream_coalescing = (app_state.config.server.stream_coalesce_window_ms > 0
+        && !e2ee_active
+        && (gateway_signature_enabled || model_attestation_supported != Some(true)))
+    .then(|| StreamCoalescing {
+        window: Duration::from_millis(app_state.config.server.stream_coalesce_window_ms),
+        max_bytes: app_state.config.server.stream_coalesce_max_bytes,
+    });
 
     // Auto-redact (opt-in via x-auto-redact header or auto_redact body field).
     // On success this may rewrite service_request.messages to substitute
@@ -1490,11 +2301,14 @@ async fn chat_completions_inner(
     // Check if streaming is requested
     if request.stream == Some(true) {
         // Call the streaming completion service
-        match app_state
-            .completion_service
-            .create_chat_completion_stream(service_request)
-            .await
-        {
+        let (result, detailed_error) = with_provider_error_detail(
+            capture_error_detail,
+            app_state
+                .completion_service
+                .create_chat_completion_stream(service_request),
+        )
+        .await;
+        match result {
             Ok(stream) => {
                 // Make stream peekable to extract chat_id for Inference-Id header
                 let mut peekable_stream = Box::pin(stream.peekable());
@@ -1514,8 +2328,24 @@ async fn chat_completions_inner(
                 // Raw chat_id string captured alongside the hashed UUID so we can
                 // look up the serving-provider tier from the pool's chat_id mapping.
                 let mut stream_chat_id: Option<String> = None;
+                // With keep-alives enabled, stop peeking after one interval
+                // so response headers go out and heartbeats can start; a slow
+                // first token then ships without an Inference-Id header.
+                let peek_deadline = stream_keepalive_interval
+                    .map(|interval| tokio::time::Instant::now() + interval);
                 let inference_id = loop {
-                    let is_control = match peekable_stream.as_mut().peek().await {
+                    let peeked = match peek_deadline {
+                        Some(deadline) => {
+                            match tokio::time::timeout_at(deadline, peekable_stream.as_mut().peek())
+                                .await
+                            {
+                                Ok(peeked) => peeked,
+                                Err(_) => break None,
+                            }
+                        }
+                        None => peekable_stream.as_mut().peek().await,
+                    };
+                    let is_control = match peeked {
                         Some(Ok(event)) => {
                             if let Some(chunk) = &event.chunk {
                                 // Capture the raw chat_id for the tier lookup below.
@@ -1523,7 +2353,10 @@ async fn chat_completions_inner(
                                     inference_providers::StreamChunk::Chat(c) => c.id.clone(),
                                     inference_providers::StreamChunk::Text(c) => c.id.clone(),
                                 });
-                                break Some(extract_inference_id_from_chunk(chunk));
+                                break Some(extract_inference_id_from_chunk(
+                                    chunk,
+                                    app_state.inference_id_namespace,
+                                ));
                             }
                             true
                         }
@@ -1598,9 +2431,16 @@ async fn chat_completions_inner(
                 let public_signature_chat_id_for_chain = public_signature_chat_id.clone();
                 let attestation_service_for_chain = app_state.attestation_service.clone();
 
-                // Re-attach any stashed leading control events, then convert
-                // to a raw bytes stream.
-                let event_stream = futures::stream::iter(leading_control).chain(peekable_stream);
+                // Re-attach any stashed leading control events, send
+                // keep-alives until the first chunk, optionally merge tiny
+                // content deltas, then convert to a raw bytes stream.
+                let event_stream = CoalescingStream::new(
+                    KeepAliveStream::new(
+                        futures::stream::iter(leading_control).chain(peekable_stream),
+                        stream_keepalive_interval,
+                    ),
+                    stream_coalescing,
+                );
 
                 let byte_stream = event_stream
                     .filter_map(move |result| {
@@ -2049,26 +2889,29 @@ async fn chat_completions_inner(
                     .body(Body::from_stream(byte_stream))
                     .unwrap()
             }
-            Err(domain_error) => {
-                let status_code = map_domain_error_to_status(&domain_error);
-                (
-                    status_code,
-                    ResponseJson::<ErrorResponse>(domain_error.into()),
-                )
-                    .into_response()
-            }
+            Err(domain_error) => completion_error_response(domain_error, detailed_error),
         }
     } else {
         // Call the non-streaming completion service
-        match app_state
-            .completion_service
-            .create_chat_completion(service_request)
-            .await
-        {
+        let ((result, assembled_prompt), detailed_error) = with_provider_error_detail(
+            capture_error_detail,
+            with_assembled_prompt(
+                capture_prompt,
+                app_state
+                    .completion_service
+                    .create_chat_completion(service_request),
+            ),
+        )
+        .await;
+        match result {
             Ok(mut response_with_bytes) => {
-                // Extract inference ID from response ID (reuse same hashing as usage tracking)
-                let inference_id =
-                    Some(hash_inference_id_to_uuid(&response_with_bytes.response.id));
+                // Use the inference ID usage was recorded under; a coalesced
+                // follower's differs from the hash of the shared response ID.
+                let inference_id = Some(response_with_bytes.inference_id.unwrap_or_else(|| {
+                    app_state
+                        .inference_id_namespace
+                        .hash(&response_with_bytes.response.id)
+                }));
 
                 // When auto-redact is enabled, we substitute placeholders back to
                 // originals and re-serialize. The provider's raw_bytes are over the
@@ -2117,6 +2960,17 @@ async fn chat_completions_inner(
                     _ => body_bytes,
                 };
 
+                // Admin prompt debugging: like the alias warning, this
+                // re-serializes the body and gives up raw-bytes verification.
+                let body_bytes = match assembled_prompt {
+                    Some(prompt) if !e2ee_active => inject_debug_field(
+                        &body_bytes,
+                        serde_json::json!({ "assembled_request": prompt }),
+                    )
+                    .unwrap_or(body_bytes),
+                    _ => body_bytes,
+                };
+
                 let mut response_builder = Response::builder()
                     .status(StatusCode::OK)
                     .header(header::CONTENT_TYPE, "application/json");
@@ -2162,14 +3016,7 @@ async fn chat_completions_inner(
 
                 response_builder.body(Body::from(body_bytes)).unwrap()
             }
-            Err(domain_error) => {
-                let status_code = map_domain_error_to_status(&domain_error);
-                (
-                    status_code,
-                    ResponseJson::<ErrorResponse>(domain_error.into()),
-                )
-                    .into_response()
-            }
+            Err(domain_error) => completion_error_response(domain_error, detailed_error),
         }
     }
 }
@@ -2182,22 +3029,31 @@ async fn chat_completions_inner(
     path = "/v1/completions",
     tag = "Chat",
     request_body = CompletionRequest,
+    params(
+        ("debug_errors" = Option<bool>, Query, description = "Admin-only (requires an admin session in X-Admin-Authorization): include the unsanitized provider error in error.details.detailed_error")
+    ),
     responses(
         (status = 200, description = "Completion generated successfully", body = CompletionResponse),
         (status = 400, description = "Invalid request parameters", body = ErrorResponse),
         (status = 401, description = "Invalid or missing API key", body = ErrorResponse),
+        (status = 404, description = "Model not served by any provider (error.type model_not_found)", body = ErrorResponse),
+        (status = 413, description = "Request body exceeds the configured size limit", body = ErrorResponse),
         (status = 500, description = "Server error", body = ErrorResponse),
-        (status = 429, description = "Rate limited or overloaded — retry with backoff. Check error.type: rate_limit_exceeded or service_overloaded.", body = ErrorResponse)
+        (status = 429, description = "Rate limited or overloaded — retry with backoff. Check error.type: rate_limit_exceeded or service_overloaded.", body = ErrorResponse),
+        (status = 503, description = "Model warming: provider discovery has not initialized yet; retry after Retry-After", body = ErrorResponse)
     ),
     security(
         ("api_key" = [])
     )
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn completions(
     State(app_state): State<AppState>,
     Extension(api_key): Extension<AuthenticatedApiKey>,
     Extension(body_hash): Extension<RequestBodyHash>,
     Extension(correlation): Extension<RequestCorrelation>,
+    request_model: Option<Extension<RequestModel>>,
+    Query(debug): Query<CompletionDebugQuery>,
     headers: header::HeaderMap,
     OpenAiJson(request): OpenAiJson<CompletionRequest>,
 ) -> axum::response::Response {
@@ -2209,6 +3065,9 @@ pub async fn completions(
         "Request model: {}, stream: {:?}, org: {}, workspace: {}",
         request.model, request.stream, api_key.organization.id, api_key.workspace.id.0
     );
+    if let Some(Extension(request_model)) = &request_model {
+        request_model.set(&request.model);
+    }
 
     // Validate the request
     if let Err(error) = request.validate_request() {
@@ -2227,9 +3086,17 @@ pub async fn completions(
         model = %request.model,
     );
 
-    completions_inner(app_state, api_key, body_hash, headers, request, request_id)
-        .instrument(span)
-        .await
+    completions_inner(
+        app_state,
+        api_key,
+        body_hash,
+        headers,
+        request,
+        request_id,
+        debug.debug_errors,
+    )
+    .instrument(span)
+    .await
 }
 
 // The legacy text-completions endpoint is implemented by translating the
@@ -2256,7 +3123,9 @@ async fn completions_inner(
     headers: header::HeaderMap,
     request: CompletionRequest,
     request_id: Uuid,
+    debug_errors: bool,
 ) -> axum::response::Response {
+    let capture_error_detail = admin_debug_allowed(&app_state, &headers, debug_errors).await;
     // Reject E2E encryption: validate for parity (an invalid version still 400s
     // the same way chat does), then refuse if any encryption header is present.
     let encryption_headers = match crate::routes::common::validate_encryption_headers(&headers) {
@@ -2337,13 +3206,19 @@ async fn completions_inner(
     // service resolves aliases for this endpoint exactly like chat, so it
     // gets the same contract: x-no-aliasing rejects, and aliased responses
     // carry the warning + x-model-alias-resolved header.
-    if let Err(resp) = reject_if_aliased(&app_state.models_service, &headers, &request.model).await
+    if let Err(resp) = reject_if_aliased(
+        &app_state.models_service,
+        &headers,
+        api_key.organization.id.0,
+        &request.model,
+    )
+    .await
     {
         return resp;
     }
     let alias_canonical = app_state
         .models_service
-        .resolve_alias_cached(&request.model)
+        .resolve_alias_cached_for_organization(api_key.organization.id.0, &request.model)
         .await;
 
     let service_request = convert_text_request_to_service(
@@ -2358,11 +3233,14 @@ async fn completions_inner(
     );
 
     if request.stream == Some(true) {
-        match app_state
-            .completion_service
-            .create_chat_completion_stream(service_request)
-            .await
-        {
+        let (result, detailed_error) = with_provider_error_detail(
+            capture_error_detail,
+            app_state
+                .completion_service
+                .create_chat_completion_stream(service_request),
+        )
+        .await;
+        match result {
             Ok(stream) => {
                 // Peek the first data chunk to surface the Inference-Id
                 // header, consuming any leading control events. This route
@@ -2382,7 +3260,10 @@ async fn completions_inner(
                                     inference_providers::StreamChunk::Chat(c) => c.id.clone(),
                                     inference_providers::StreamChunk::Text(c) => c.id.clone(),
                                 });
-                                break Some(extract_inference_id_from_chunk(chunk));
+                                break Some(extract_inference_id_from_chunk(
+                                    chunk,
+                                    app_state.inference_id_namespace,
+                                ));
                             }
                             true
                         }
@@ -2518,23 +3399,23 @@ async fn completions_inner(
                     .body(Body::from_stream(byte_stream))
                     .unwrap()
             }
-            Err(domain_error) => {
-                let status_code = map_domain_error_to_status(&domain_error);
-                (
-                    status_code,
-                    ResponseJson::<ErrorResponse>(domain_error.into()),
-                )
-                    .into_response()
-            }
+            Err(domain_error) => completion_error_response(domain_error, detailed_error),
         }
     } else {
-        match app_state
-            .completion_service
-            .create_chat_completion(service_request)
-            .await
-        {
+        let (result, detailed_error) = with_provider_error_detail(
+            capture_error_detail,
+            app_state
+                .completion_service
+                .create_chat_completion(service_request),
+        )
+        .await;
+        match result {
             Ok(response_with_bytes) => {
-                let inference_id = hash_inference_id_to_uuid(&response_with_bytes.response.id);
+                let inference_id = response_with_bytes.inference_id.unwrap_or_else(|| {
+                    app_state
+                        .inference_id_namespace
+                        .hash(&response_with_bytes.response.id)
+                });
                 let completion = chat_response_to_text_response(response_with_bytes.response);
 
                 let body_bytes = match serde_json::to_vec(&completion) {
@@ -2591,14 +3472,7 @@ async fn completions_inner(
 
                 response_builder.body(Body::from(body_bytes)).unwrap()
             }
-            Err(domain_error) => {
-                let status_code = map_domain_error_to_status(&domain_error);
-                (
-                    status_code,
-                    ResponseJson::<ErrorResponse>(domain_error.into()),
-                )
-                    .into_response()
-            }
+            Err(domain_error) => completion_error_response(domain_error, detailed_error),
         }
     }
 }
@@ -2906,6 +3780,7 @@ mod tests {
             is_ready: None,
             deprecation_date: None,
             openrouter_slug: None,
+            max_tokens_limits: Default::default(),
             created_at: chrono::Utc::now(),
         }
     }
@@ -3569,6 +4444,237 @@ mod tests {
         assert!(rewritten_control_event_bytes(&done).is_none());
     }
 
+    #[test]
+    fn rewritten_control_events_keep_terminated_keepalive_intact() {
+        let keepalive = inference_providers::SSEEvent {
+            raw_bytes: Bytes::from_static(STREAM_KEEPALIVE_COMMENT),
+            chunk: None,
+            raw_passthrough: true,
+        };
+        assert_eq!(
+            rewritten_control_event_bytes(&keepalive),
+            Some(Bytes::from_static(STREAM_KEEPALIVE_COMMENT))
+        );
+    }
+
+    fn chunk_event(id: &str) -> SSEEventResult {
+        Ok(inference_providers::SSEEvent {
+            raw_bytes: Bytes::from(format!("data: {{\"id\":\"{id}\"}}\n")),
+            chunk: Some(make_chat_chunk(id)),
+            raw_passthrough: true,
+        })
+    }
+
+    fn done_event() -> SSEEventResult {
+        Ok(inference_providers::SSEEvent {
+            raw_bytes: Bytes::from_static(b"data: [DONE]\n"),
+            chunk: None,
+            raw_passthrough: true,
+        })
+    }
+
+    fn is_keepalive(event: &SSEEventResult) -> bool {
+        matches!(event, Ok(e) if e.chunk.is_none() && e.raw_bytes == STREAM_KEEPALIVE_COMMENT)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn keepalive_stream_heartbeats_until_first_chunk_then_stops() {
+        let delayed = futures::stream::once(tokio::time::sleep(Duration::from_millis(250)))
+            .filter_map(|_| async { None });
+        // The tail sleeps after [DONE] so a live timer would get a chance to fire.
+        let tail = futures::stream::once(tokio::time::sleep(Duration::from_secs(1)))
+            .filter_map(|_| async { None });
+        let inner = Box::pin(
+            delayed
+                .chain(futures::stream::iter(vec![
+                    chunk_event("chatcmpl-1"),
+                    done_event(),
+                ]))
+                .chain(tail),
+        );
+
+        let events: Vec<_> = KeepAliveStream::new(inner, Some(Duration::from_millis(100)))
+            .collect()
+            .await;
+
+        let first_chunk = events
+            .iter()
+            .position(|e| matches!(e, Ok(ev) if ev.chunk.is_some()))
+            .expect("chunk must be forwarded");
+        assert!(
+            first_chunk >= 1,
+            "expected a heartbeat before the first chunk"
+        );
+        assert!(events[..first_chunk].iter().all(is_keepalive));
+        assert!(!events[first_chunk..].iter().any(is_keepalive));
+        assert!(matches!(events.last(), Some(Ok(ev)) if ev.is_done_marker()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn keepalive_stream_disabled_is_passthrough() {
+        let inner = Box::pin(
+            futures::stream::once(tokio::time::sleep(Duration::from_millis(250)))
+                .filter_map(|_| async { None })
+                .chain(futures::stream::iter(vec![
+                    chunk_event("chatcmpl-1"),
+                    done_event(),
+                ])),
+        );
+
+        let events: Vec<_> = KeepAliveStream::new(inner, None).collect().await;
+
+        assert_eq!(events.len(), 2);
+        assert!(!events.iter().any(is_keepalive));
+    }
+
+    fn content_event(content: &str) -> SSEEventResult {
+        let chunk = inference_providers::models::ChatCompletionChunk {
+            usage: None,
+            choices: vec![inference_providers::models::ChatChoice {
+                delta: Some(inference_providers::models::ChatDelta {
+                    content: Some(content.to_string()),
+                    ..Default::default()
+                }),
+                ..chat_stream_content_choice()
+            }],
+            ..chat_stream_chunk_with_usage(vec![])
+        };
+        Ok(inference_providers::SSEEvent {
+            raw_bytes: Bytes::from(format!(
+                "data: {}\n",
+                serde_json::to_string(&chunk).unwrap()
+            )),
+            chunk: Some(inference_providers::StreamChunk::Chat(chunk)),
+            raw_passthrough: true,
+        })
+    }
+
+    fn separator_event() -> SSEEventResult {
+        Ok(inference_providers::SSEEvent {
+            raw_bytes: Bytes::from_static(b"\n"),
+            chunk: None,
+            raw_passthrough: true,
+        })
+    }
+
+    fn chat_chunk_of(event: &SSEEventResult) -> &inference_providers::models::ChatCompletionChunk {
+        match event {
+            Ok(inference_providers::SSEEvent {
+                chunk: Some(inference_providers::StreamChunk::Chat(chat)),
+                ..
+            }) => chat,
+            other => panic!("expected a chat chunk, got {other:?}"),
+        }
+    }
+
+    fn delta_content(event: &SSEEventResult) -> Option<&str> {
+        chat_chunk_of(event)
+            .choices
+            .first()?
+            .delta
+            .as_ref()?
+            .content
+            .as_deref()
+    }
+
+    fn coalescing(window_ms: u64, max_bytes: usize) -> Option<StreamCoalescing> {
+        Some(StreamCoalescing {
+            window: Duration::from_millis(window_ms),
+            max_bytes,
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn coalescing_stream_merges_deltas_but_keeps_finish_and_usage_distinct() {
+        let inner = futures::stream::iter(vec![
+            content_event("Hel"),
+            separator_event(),
+            content_event("lo"),
+            separator_event(),
+            content_event(", world"),
+            separator_event(),
+            Ok(inference_providers::SSEEvent {
+                raw_bytes: Bytes::from_static(b"data: {}\n"),
+                chunk: Some(inference_providers::StreamChunk::Chat(
+                    inference_providers::models::ChatCompletionChunk {
+                        usage: None,
+                        ..chat_stream_chunk_with_usage(vec![chat_stream_finish_choice()])
+                    },
+                )),
+                raw_passthrough: true,
+            }),
+            separator_event(),
+            Ok(inference_providers::SSEEvent {
+                raw_bytes: Bytes::from_static(b"data: {}\n"),
+                chunk: Some(inference_providers::StreamChunk::Chat(
+                    chat_stream_chunk_with_usage(vec![]),
+                )),
+                raw_passthrough: true,
+            }),
+            separator_event(),
+            done_event(),
+        ]);
+
+        let events: Vec<_> = CoalescingStream::new(inner, coalescing(50, 1024))
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 6, "{events:?}");
+        assert_eq!(delta_content(&events[0]), Some("Hello, world"));
+        assert!(!events[0].as_ref().unwrap().raw_passthrough);
+        // The finish chunk still carries its own "hello" delta, unmerged.
+        let finish = chat_chunk_of(&events[1]);
+        assert!(finish.choices[0].finish_reason.is_some());
+        assert_eq!(delta_content(&events[1]), Some("hello"));
+        assert!(events[1].as_ref().unwrap().raw_passthrough);
+        assert!(matches!(&events[2], Ok(e) if e.raw_bytes == "\n"));
+        assert!(chat_chunk_of(&events[3]).usage.is_some());
+        assert!(matches!(&events[4], Ok(e) if e.raw_bytes == "\n"));
+        assert!(matches!(&events[5], Ok(e) if e.is_done_marker()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn coalescing_stream_flushes_at_window_and_byte_threshold() {
+        let pause = |ms| {
+            futures::stream::once(tokio::time::sleep(Duration::from_millis(ms)))
+                .filter_map(|_| async { None })
+        };
+        let inner = Box::pin(
+            futures::stream::iter(vec![content_event("a"), content_event("b")])
+                // Longer than the window: "ab" goes out on its own.
+                .chain(pause(100))
+                .chain(futures::stream::iter(vec![
+                    content_event("cd"),
+                    content_event("ef"),
+                    // Reaches max_bytes: flushed without waiting.
+                    content_event("g"),
+                    content_event("h"),
+                ])),
+        );
+
+        let events: Vec<_> = CoalescingStream::new(inner, coalescing(50, 4))
+            .collect()
+            .await;
+
+        let contents: Vec<_> = events.iter().map(delta_content).collect();
+        assert_eq!(contents, vec![Some("ab"), Some("cdef"), Some("gh")]);
+    }
+
+    #[tokio::test]
+    async fn coalescing_stream_disabled_is_passthrough() {
+        let inner = futures::stream::iter(vec![
+            content_event("a"),
+            separator_event(),
+            content_event("b"),
+        ]);
+
+        let events: Vec<_> = CoalescingStream::new(inner, None).collect().await;
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(delta_content(&events[0]), Some("a"));
+        assert!(events[0].as_ref().unwrap().raw_passthrough);
+    }
+
     #[test]
     fn default_chat_stream_strips_usage_from_terminal_choice_chunk() {
         let mut final_choice_chunk =
@@ -3581,12 +4687,48 @@ mod tests {
         assert!(final_choice_chunk.usage.is_none());
     }
 
+    #[test]
+    fn terminal_chunk_keeps_every_finish_reason_through_client_rewrites() {
+        use inference_providers::models::FinishReason;
+
+        for (reason, wire) in [
+            (FinishReason::Stop, "stop"),
+            (FinishReason::Length, "length"),
+            (FinishReason::ToolCalls, "tool_calls"),
+            (FinishReason::ContentFilter, "content_filter"),
+            (FinishReason::Other("abort".to_string()), "abort"),
+        ] {
+            for include_usage in [false, true] {
+                let mut chunk =
+                    chat_stream_chunk_with_usage(vec![inference_providers::models::ChatChoice {
+                        finish_reason: Some(reason.clone()),
+                        ..chat_stream_content_choice()
+                    }]);
+                let mut final_usage = None;
+                assert!(prepare_chat_stream_chunk_for_client_with_state(
+                    &mut chunk,
+                    include_usage,
+                    &mut final_usage
+                ));
+                let json = serde_json::to_value(&chunk).expect("chunk should serialize");
+                assert_eq!(json["choices"][0]["finish_reason"], wire);
+            }
+
+            // Legacy /v1/completions streams are reshaped from chat chunks.
+            let text = chat_chunk_to_text_chunk(make_chat_chunk_with_choice(
+                Some(empty_delta()),
+                Some(reason.clone()),
+            ));
+            assert_eq!(text.choices[0].finish_reason, Some(reason));
+        }
+    }
+
     #[test]
     fn test_extract_inference_id_from_chunk_valid() {
         let chunk = make_chat_chunk("chatcmpl-123abc");
-        let uuid1 = extract_inference_id_from_chunk(&chunk);
+        let uuid1 = extract_inference_id_from_chunk(&chunk, InferenceIdNamespace::default());
         // UUID should be deterministic - same input produces same UUID
-        let uuid2 = extract_inference_id_from_chunk(&chunk);
+        let uuid2 = extract_inference_id_from_chunk(&chunk, InferenceIdNamespace::default());
         assert_eq!(uuid1, uuid2);
     }
 
@@ -3594,8 +4736,8 @@ mod tests {
     fn test_extract_inference_id_from_chunk_deterministic() {
         let chunk1 = make_chat_chunk("chatcmpl-test123");
         let chunk2 = make_chat_chunk("chatcmpl-test123");
-        let uuid1 = extract_inference_id_from_chunk(&chunk1);
-        let uuid2 = extract_inference_id_from_chunk(&chunk2);
+        let uuid1 = extract_inference_id_from_chunk(&chunk1, InferenceIdNamespace::default());
+        let uuid2 = extract_inference_id_from_chunk(&chunk2, InferenceIdNamespace::default());
         assert_eq!(uuid1, uuid2);
     }
 
@@ -3603,15 +4745,15 @@ mod tests {
     fn test_extract_inference_id_from_chunk_different_ids() {
         let chunk1 = make_chat_chunk("chatcmpl-abc123");
         let chunk2 = make_chat_chunk("chatcmpl-xyz789");
-        let uuid1 = extract_inference_id_from_chunk(&chunk1);
-        let uuid2 = extract_inference_id_from_chunk(&chunk2);
+        let uuid1 = extract_inference_id_from_chunk(&chunk1, InferenceIdNamespace::default());
+        let uuid2 = extract_inference_id_from_chunk(&chunk2, InferenceIdNamespace::default());
         assert_ne!(uuid1, uuid2);
     }
 
     #[test]
     fn test_extract_inference_id_from_chunk_empty_id() {
         let chunk = make_chat_chunk("");
-        let result = extract_inference_id_from_chunk(&chunk);
+        let result = extract_inference_id_from_chunk(&chunk, InferenceIdNamespace::default());
         // Empty string should still produce a valid UUID
         assert!(
             !result.is_nil(),
@@ -3959,6 +5101,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn model_warming_error_response_is_503_with_retry_after() {
+        let response = completion_error_response(
+            ServiceCompletionError::ModelWarming("not ready".to_string()),
+            None,
+        );
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok()),
+            Some(MODEL_WARMING_RETRY_AFTER_SECS.to_string().as_str())
+        );
+
+        let response = completion_error_response(
+            ServiceCompletionError::ModelNotFound("not found".to_string()),
+            None,
+        );
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(response.headers().get(header::RETRY_AFTER).is_none());
+    }
+
     #[test]
     fn test_completion_stream_error_openai_type_http_status_mapping() {
         let rate_limited = inference_providers::CompletionError::HttpError {
@@ -4304,6 +5469,12 @@ pub async fn image_generations(
         }
     };
 
+    if let Err(resp) =
+        reject_if_model_not_allowed(&app_state, api_key.organization.id.0, &model.model_name).await
+    {
+        return resp;
+    }
+
     // Validate and enforce response_format for verifiable models
     // Verifiable models (attestation_supported = true) only support "b64_json" format
     // Default to "b64_json" if not specified to prevent downstream server from applying "url" default
@@ -4414,6 +5585,7 @@ pub async fn image_generations(
                 image_count,
                 provider_attribution,
                 inference_type: services::usage::InferenceType::ImageGeneration,
+                inference_id_namespace: app_state.inference_id_namespace,
             });
             record_usage_with_sync_fallback(usage_service, usage_request, "Image generation").await;
 
@@ -4621,10 +5793,12 @@ pub async fn audio_transcriptions(
             .into_response();
     }
 
-    // Resolve model to get UUID for usage tracking (handles aliases like chat_completions)
+    // Resolve model to get UUID for usage tracking (handles global and organization aliases
+    // like chat_completions)
+    let organization_id = api_key.organization.id.0;
     let model = match app_state
         .models_service
-        .resolve_and_get_model(&request.model)
+        .resolve_and_get_model_for_organization(organization_id, &request.model)
         .await
     {
         Ok(model) => model,
@@ -4651,8 +5825,8 @@ pub async fn audio_transcriptions(
         }
     };
     let model_id = model.id;
-    let model_name = request.model.clone();
-    let organization_id = api_key.organization.id.0;
+    // Providers are registered under the canonical name, never the alias
+    let model_name = model.model_name.clone();
 
     // Extract and validate encryption headers if present
     let encryption_headers = match crate::routes::common::validate_encryption_headers(&headers) {
@@ -4749,6 +5923,7 @@ pub async fn audio_transcriptions(
                 response_id: None,
                 image_count: None,
                 provider_attribution: services::usage::ProviderAttribution::default(),
+                estimated: false,
             };
 
             // Record usage synchronously - fail the request if usage recording fails
@@ -4790,6 +5965,12 @@ pub async fn audio_transcriptions(
         }
         Err(e) => {
             let (status_code, error_type, message) = match e {
+                services::completions::ports::CompletionError::ModelNotAllowed(msg) => {
+                    (StatusCode::FORBIDDEN, "model_not_allowed", msg)
+                }
+                services::completions::ports::CompletionError::QuotaExceeded(msg) => {
+                    (StatusCode::TOO_MANY_REQUESTS, "quota_exceeded", msg)
+                }
                 services::completions::ports::CompletionError::RateLimitExceeded(msg) => {
                     tracing::warn!("Concurrent request limit exceeded for audio transcription");
                     (StatusCode::TOO_MANY_REQUESTS, "rate_limit_error", msg)
@@ -5101,6 +6282,12 @@ pub async fn image_edits(
         }
     };
 
+    if let Err(resp) =
+        reject_if_model_not_allowed(&app_state, api_key.organization.id.0, &model.model_name).await
+    {
+        return resp;
+    }
+
     // Validate and enforce response_format for verifiable models
     // Verifiable models (attestation_supported = true) only support "b64_json" format
     // Default to "b64_json" if not specified to prevent downstream server from applying "url" default
@@ -5204,6 +6391,7 @@ pub async fn image_edits(
                 image_count,
                 provider_attribution,
                 inference_type: services::usage::InferenceType::ImageEdit,
+                inference_id_namespace: app_state.inference_id_namespace,
             });
             record_usage_with_sync_fallback(usage_service, usage_request, "Image edit").await;
 
@@ -5598,6 +6786,7 @@ pub async fn rerank(
                 response_id: None,
                 image_count: None,
                 provider_attribution: services::usage::ProviderAttribution::default(),
+                estimated: false,
             };
 
             // Record usage synchronously - this is billing-critical and must succeed
@@ -5618,6 +6807,12 @@ pub async fn rerank(
         }
         Err(e) => {
             let (status_code, error_type, message) = match e {
+                services::completions::ports::CompletionError::ModelNotAllowed(msg) => {
+                    (StatusCode::FORBIDDEN, "model_not_allowed", msg)
+                }
+                services::completions::ports::CompletionError::QuotaExceeded(msg) => {
+                    (StatusCode::TOO_MANY_REQUESTS, "quota_exceeded", msg)
+                }
                 services::completions::ports::CompletionError::RateLimitExceeded(msg) => {
                     tracing::warn!("Concurrent request limit exceeded for rerank");
                     (StatusCode::TOO_MANY_REQUESTS, "rate_limit_error", msg)
@@ -5748,6 +6943,7 @@ fn classify_provider_error(
         (status = 200, description = "Embeddings generated successfully", body = EmbeddingsResponseDoc),
         (status = 400, description = "Invalid request", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Request body exceeds the configured size limit", body = ErrorResponse),
         (status = 404, description = "Model not found", body = ErrorResponse),
         (status = 429, description = "Rate limited or overloaded — retry with backoff. Check error.type: rate_limit_exceeded or service_overloaded.", body = ErrorResponse),
         (status = 500, description = "Server error", body = ErrorResponse),
@@ -5942,6 +7138,7 @@ pub async fn embeddings(
                 response_id: None,
                 image_count: None,
                 provider_attribution: services::usage::ProviderAttribution::default(),
+                estimated: false,
             };
 
             if let Err(e) = app_state.usage_service.record_usage(usage_request).await {
@@ -5965,6 +7162,12 @@ pub async fn embeddings(
         }
         Err(e) => {
             let (status_code, error_type, message) = match e {
+                services::completions::ports::CompletionError::ModelNotAllowed(msg) => {
+                    (StatusCode::FORBIDDEN, "model_not_allowed", msg)
+                }
+                services::completions::ports::CompletionError::QuotaExceeded(msg) => {
+                    (StatusCode::TOO_MANY_REQUESTS, "quota_exceeded", msg)
+                }
                 services::completions::ports::CompletionError::RateLimitExceeded(msg) => {
                     tracing::warn!("Concurrent request limit exceeded for embeddings");
                     (StatusCode::TOO_MANY_REQUESTS, "rate_limit_error", msg)
@@ -6273,6 +7476,7 @@ pub async fn privacy_classify(
                 response_id: None,
                 image_count: None,
                 provider_attribution: services::usage::ProviderAttribution::default(),
+                estimated: false,
             };
 
             if let Err(e) = app_state.usage_service.record_usage(usage_request).await {
@@ -6295,6 +7499,12 @@ pub async fn privacy_classify(
         }
         Err(e) => {
             let (status_code, error_type, message) = match e {
+                services::completions::ports::CompletionError::ModelNotAllowed(msg) => {
+                    (StatusCode::FORBIDDEN, "model_not_allowed", msg)
+                }
+                services::completions::ports::CompletionError::QuotaExceeded(msg) => {
+                    (StatusCode::TOO_MANY_REQUESTS, "quota_exceeded", msg)
+                }
                 services::completions::ports::CompletionError::RateLimitExceeded(msg) => {
                     tracing::warn!("Concurrent request limit exceeded for privacy classify");
                     (StatusCode::TOO_MANY_REQUESTS, "rate_limit_error", msg)
@@ -6597,6 +7807,12 @@ pub async fn privacy_redact(
         Ok(b) => b,
         Err(e) => {
             let (status_code, error_type, message) = match e {
+                services::completions::ports::CompletionError::ModelNotAllowed(msg) => {
+                    (StatusCode::FORBIDDEN, "model_not_allowed", msg)
+                }
+                services::completions::ports::CompletionError::QuotaExceeded(msg) => {
+                    (StatusCode::TOO_MANY_REQUESTS, "quota_exceeded", msg)
+                }
                 services::completions::ports::CompletionError::RateLimitExceeded(msg) => {
                     tracing::warn!("Concurrent request limit exceeded for privacy redact");
                     (StatusCode::TOO_MANY_REQUESTS, "rate_limit_error", msg)
@@ -6797,6 +8013,7 @@ pub async fn privacy_redact(
         response_id: None,
         image_count: None,
         provider_attribution: services::usage::ProviderAttribution::default(),
+        estimated: false,
     };
 
     if let Err(e) = app_state.usage_service.record_usage(usage_request).await {
@@ -6986,7 +8203,7 @@ pub async fn score(
                 }
             };
 
-            let inference_id = hash_inference_id_to_uuid(&response.id);
+            let inference_id = app_state.inference_id_namespace.hash(&response.id);
 
             // Score requests don't have traditional token counts - use input token count as 1
             let usage_request = services::usage::ports::RecordUsageServiceRequest {
@@ -7006,6 +8223,7 @@ pub async fn score(
                 response_id: None,
                 image_count: None,
                 provider_attribution: services::usage::ProviderAttribution::default(),
+                estimated: false,
             };
 
             // Record usage with timeout to prevent blocking responses
@@ -7040,6 +8258,7 @@ pub async fn score(
                         response_id: None,
                         image_count: None,
                         provider_attribution: services::usage::ProviderAttribution::default(),
+                        estimated: false,
                     };
                     tokio::spawn(async move {
                         if let Err(e) = usage_service_clone.record_usage(usage_request_retry).await
@@ -7077,6 +8296,7 @@ pub async fn score(
                         response_id: None,
                         image_count: None,
                         provider_attribution: services::usage::ProviderAttribution::default(),
+                        estimated: false,
                     };
                     tokio::spawn(async move {
                         if let Err(e) = usage_service_clone.record_usage(usage_request_retry).await
@@ -7094,6 +8314,12 @@ pub async fn score(
         }
         Err(e) => {
             let (status_code, error_type, message) = match e {
+                services::completions::ports::CompletionError::ModelNotAllowed(msg) => {
+                    (StatusCode::FORBIDDEN, "model_not_allowed", msg)
+                }
+                services::completions::ports::CompletionError::QuotaExceeded(msg) => {
+                    (StatusCode::TOO_MANY_REQUESTS, "quota_exceeded", msg)
+                }
                 services::completions::ports::CompletionError::RateLimitExceeded(msg) => {
                     tracing::warn!("Concurrent request limit exceeded for score");
                     (StatusCode::TOO_MANY_REQUESTS, "rate_limit_error", msg)
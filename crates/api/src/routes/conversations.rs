@@ -1,6 +1,7 @@
 use crate::models::*;
+use crate::routes::common::PageSizeLimits;
 use axum::{
-    extract::{Extension, Json, Path, Query, State},
+    extract::{Extension, FromRef, Json, Path, Query, State},
     http::StatusCode,
     response::Json as ResponseJson,
 };
@@ -14,6 +15,14 @@ use std::sync::Arc;
 use tracing::debug;
 use uuid::Uuid;
 
+/// Router state for the conversation routes. Most handlers extract only the
+/// service; item listings also extract the page-size limits.
+#[derive(Clone, FromRef)]
+pub struct ConversationRouteState {
+    pub conversation_service: Arc<dyn services::conversations::ports::ConversationServiceTrait>,
+    pub page_size_limits: PageSizeLimits,
+}
+
 // Helper functions for ID conversion
 fn parse_conversation_id(id_str: &str) -> Result<ConversationId, ConversationError> {
     // Handle both prefixed (conv_*) and raw UUID formats
@@ -809,6 +818,94 @@ pub async fn clone_conversation(
     }
 }
 
+/// Fork a conversation
+///
+/// Creates a new conversation holding the source conversation's items up to and
+/// including `up_to_item_id` (every item when omitted). The fork records the
+/// source in `metadata.parent_conversation_id`.
+#[utoipa::path(
+    post,
+    path = "/v1/conversations/{conversation_id}/fork",
+    tag = "Conversations",
+    params(
+        ("conversation_id" = String, Path, description = "Conversation ID")
+    ),
+    request_body(content = Option<ForkConversationRequest>, description = "Optional fork point"),
+    responses(
+        (status = 201, description = "Conversation forked successfully", body = ConversationObject),
+        (status = 400, description = "Invalid conversation ID or fork item", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Conversation not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("api_key" = [])
+    )
+)]
+pub async fn fork_conversation(
+    Path(conversation_id): Path<String>,
+    State(service): State<Arc<dyn services::conversations::ports::ConversationServiceTrait>>,
+    Extension(api_key): Extension<services::workspace::ApiKey>,
+    request: Option<Json<ForkConversationRequest>>,
+) -> Result<(StatusCode, ResponseJson<ConversationObject>), (StatusCode, ResponseJson<ErrorResponse>)>
+{
+    debug!(
+        "Fork conversation {} for workspace {}",
+        conversation_id, api_key.workspace_id.0
+    );
+
+    let parsed_conversation_id = parse_conversation_id(&conversation_id).map_err(|error| {
+        (
+            map_conversation_error_to_status(&error),
+            ResponseJson(error.into()),
+        )
+    })?;
+    let up_to_item_id = request
+        .map(|Json(request)| request)
+        .unwrap_or_default()
+        .up_to_item_id;
+
+    let api_key_uuid = uuid::Uuid::parse_str(&api_key.id.0).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ResponseJson(ErrorResponse::new(
+                format!("Invalid API key ID format: {e}"),
+                "internal_server_error".to_string(),
+            )),
+        )
+    })?;
+
+    match service
+        .fork_conversation(
+            parsed_conversation_id,
+            api_key.workspace_id.clone(),
+            api_key_uuid,
+            up_to_item_id,
+        )
+        .await
+    {
+        Ok(Some(domain_conversation)) => {
+            let http_conversation = convert_domain_conversation_to_http(domain_conversation);
+            debug!(
+                "Forked conversation {} -> {} for workspace {}",
+                conversation_id, http_conversation.id, api_key.workspace_id.0
+            );
+            Ok((StatusCode::CREATED, ResponseJson(http_conversation)))
+        }
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            ResponseJson(ErrorResponse::new(
+                "Conversation not found".to_string(),
+                "not_found_error".to_string(),
+            )),
+        )),
+        Err(error) => Err((
+            map_conversation_error_to_status(&error),
+            ResponseJson(error.into()),
+        )),
+    }
+}
+
 /// List conversation messages
 ///
 /// Get all messages and responses in a conversation, sorted by creation time.
@@ -836,6 +933,7 @@ pub async fn list_conversation_items(
     Path(conversation_id): Path<String>,
     Query(params): Query<ListItemsQuery>,
     State(service): State<Arc<dyn services::conversations::ports::ConversationServiceTrait>>,
+    State(page_size_limits): State<PageSizeLimits>,
     Extension(api_key): Extension<services::workspace::ApiKey>,
 ) -> Result<ResponseJson<ConversationItemList>, (StatusCode, ResponseJson<ErrorResponse>)> {
     debug!(
@@ -844,7 +942,11 @@ pub async fn list_conversation_items(
     );
 
     // Validate limit parameter
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
 
     let parsed_conversation_id = match parse_conversation_id(&conversation_id) {
         Ok(id) => id,
@@ -857,7 +959,7 @@ pub async fn list_conversation_items(
     };
 
     // Request limit + 1 items to determine if there are more
-    let fetch_limit = params.limit + 1;
+    let fetch_limit = limit + 1;
 
     // Get items from conversation service
     match service
@@ -877,9 +979,9 @@ pub async fn list_conversation_items(
                 .collect();
 
             // Now check has_more and truncate AFTER filtering
-            let has_more = http_items.len() > params.limit as usize;
+            let has_more = http_items.len() > limit as usize;
             let http_items = if has_more {
-                http_items.into_iter().take(params.limit as usize).collect()
+                http_items.into_iter().take(limit as usize).collect()
             } else {
                 http_items
             };
@@ -902,6 +1004,89 @@ pub async fn list_conversation_items(
     }
 }
 
+/// Search conversation messages
+///
+/// Case-insensitive substring search over the text of the messages in a
+/// conversation. Matching items are returned in conversation order and are
+/// paginated with `limit` / `after` like the item listing.
+#[utoipa::path(
+    get,
+    path = "/v1/conversations/{conversation_id}/items/search",
+    tag = "Conversations",
+    params(
+        ("conversation_id" = String, Path, description = "Conversation ID"),
+        ("q" = String, Query, description = "Text to search for (case-insensitive substring)"),
+        ("limit" = Option<i64>, Query, description = "Maximum number of items to return"),
+        ("after" = Option<String>, Query, description = "Return items after this item ID")
+    ),
+    responses(
+        (status = 200, description = "Matching conversation items", body = ConversationItemList),
+        (status = 400, description = "Bad request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Conversation not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("api_key" = [])
+    )
+)]
+pub async fn search_conversation_items(
+    Path(conversation_id): Path<String>,
+    Query(params): Query<SearchItemsQuery>,
+    State(service): State<Arc<dyn services::conversations::ports::ConversationServiceTrait>>,
+    State(page_size_limits): State<PageSizeLimits>,
+    Extension(api_key): Extension<services::workspace::ApiKey>,
+) -> Result<ResponseJson<ConversationItemList>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    debug!(
+        "Search items in conversation {} for workspace {}",
+        conversation_id, api_key.workspace_id.0
+    );
+
+    let limit = crate::routes::common::validate_limit_offset(page_size_limits, params.limit, 0)?;
+
+    let parsed_conversation_id = parse_conversation_id(&conversation_id).map_err(|error| {
+        (
+            map_conversation_error_to_status(&error),
+            ResponseJson(error.into()),
+        )
+    })?;
+
+    // Request limit + 1 items to determine if there are more
+    let items = service
+        .search_conversation_items(
+            parsed_conversation_id,
+            api_key.workspace_id.clone(),
+            params.q,
+            params.after,
+            limit + 1,
+        )
+        .await
+        .map_err(|error| {
+            (
+                map_conversation_error_to_status(&error),
+                ResponseJson(error.into()),
+            )
+        })?;
+
+    let mut http_items: Vec<ConversationItem> = items
+        .into_iter()
+        .map(convert_output_item_to_conversation_item)
+        .collect();
+    let has_more = http_items.len() > limit as usize;
+    http_items.truncate(limit as usize);
+
+    let first_id = http_items.first().map(get_item_id).unwrap_or_default();
+    let last_id = http_items.last().map(get_item_id).unwrap_or_default();
+
+    Ok(ResponseJson(ConversationItemList {
+        object: "list".to_string(),
+        data: http_items,
+        first_id,
+        last_id,
+        has_more,
+    }))
+}
+
 /// Create items in a conversation (for backfilling)
 ///
 /// Adds items to a conversation, allowing API callers to backfill conversations.
@@ -1428,6 +1613,16 @@ fn convert_domain_conversation_to_http(
         metadata.remove("cloned_from_id");
     }
 
+    // Update parent_conversation_id in metadata based on database field
+    if let Some(parent_conversation_id) = domain_conversation.parent_conversation_id {
+        metadata.insert(
+            "parent_conversation_id".to_string(),
+            serde_json::json!(parent_conversation_id.to_string()),
+        );
+    } else {
+        metadata.remove("parent_conversation_id");
+    }
+
     // Expose root_response_id via metadata (instead of adding a top-level field).
     // This enables first-turn parallel responses to share the same structural parent.
     if let Some(root_response_id) = domain_conversation.root_response_id {
@@ -1486,11 +1681,18 @@ impl From<ConversationError> for ErrorResponse {
 // Query parameter structs
 #[derive(Debug, Deserialize)]
 pub struct ListItemsQuery {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     pub order: Option<String>, // "asc" or "desc"
     pub after: Option<String>,
     pub include: Option<Vec<String>>,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct SearchItemsQuery {
+    #[serde(default)]
+    pub q: String,
+    pub limit: Option<i64>,
+    pub after: Option<String>,
+}
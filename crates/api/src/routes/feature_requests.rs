@@ -25,6 +25,7 @@ const MAX_SOURCE_LENGTH: usize = 100;
 #[derive(Clone)]
 pub struct FeatureRequestsRouteState {
     pub repository: Arc<FeatureRequestRepository>,
+    pub page_size_limits: crate::routes::common::PageSizeLimits,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
@@ -116,8 +117,7 @@ pub struct AdminFeatureRequestListResponse {
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct AdminFeatureRequestListQuery {
     pub kind: Option<String>,
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
 }
@@ -223,7 +223,7 @@ pub async fn list_admin_feature_requests(
     Query(params): Query<AdminFeatureRequestListQuery>,
 ) -> Result<ResponseJson<AdminFeatureRequestListResponse>, (StatusCode, ResponseJson<ErrorResponse>)>
 {
-    validate_limit_offset(params.limit, params.offset)?;
+    let limit = validate_limit_offset(state.page_size_limits, params.limit, params.offset)?;
 
     let kind = match params
         .kind
@@ -249,13 +249,13 @@ pub async fn list_admin_feature_requests(
 
     let (requests, total) = state
         .repository
-        .list_admin(kind.as_deref(), params.limit, params.offset)
+        .list_admin(kind.as_deref(), limit, params.offset)
         .await
         .map_err(internal_error)?;
 
     Ok(ResponseJson(AdminFeatureRequestListResponse {
         requests: requests.into_iter().map(summary_to_response).collect(),
-        limit: params.limit,
+        limit,
         offset: params.offset,
         total,
     }))
@@ -1,7 +1,14 @@
-use axum::{http::StatusCode, response::Json as ResponseJson};
+use axum::{extract::State, http::StatusCode, response::Json as ResponseJson};
+use database::Database;
 use serde::{Deserialize, Serialize};
+use services::inference_provider_pool::InferenceProviderPool;
+use std::{sync::Arc, time::Duration};
 use utoipa::ToSchema;
 
+/// Upper bound on the readiness database probe, so a hung connection pool
+/// reports unhealthy instead of stalling the orchestrator's probe.
+const READINESS_DB_TIMEOUT: Duration = Duration::from_secs(2);
+
 /// Health check response
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct HealthResponse {
@@ -12,6 +19,58 @@ pub struct HealthResponse {
     pub version: Option<String>,
 }
 
+/// Dependencies consulted by the readiness probe
+#[derive(Clone)]
+pub struct HealthState {
+    pub database: Arc<Database>,
+    pub inference_provider_pool: Arc<InferenceProviderPool>,
+}
+
+/// Status of a single dependency
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DependencyStatus {
+    /// "ok" or "unhealthy"
+    pub status: String,
+    /// Why the dependency is unhealthy (omitted when ok)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl DependencyStatus {
+    fn ok() -> Self {
+        Self {
+            status: "ok".to_string(),
+            detail: None,
+        }
+    }
+
+    fn unhealthy(detail: &str) -> Self {
+        Self {
+            status: "unhealthy".to_string(),
+            detail: Some(detail.to_string()),
+        }
+    }
+
+    fn is_ok(&self) -> bool {
+        self.status == "ok"
+    }
+}
+
+/// Per-dependency readiness results
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReadinessChecks {
+    pub database: DependencyStatus,
+    pub inference_pool: DependencyStatus,
+}
+
+/// Readiness probe response
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReadinessResponse {
+    /// "ok" when every dependency is healthy, otherwise "unavailable"
+    pub status: String,
+    pub checks: ReadinessChecks,
+}
+
 /// Health check
 ///
 /// Check service health status. No authentication required.
@@ -33,6 +92,88 @@ pub async fn health_check() -> (StatusCode, ResponseJson<HealthResponse>) {
     )
 }
 
+/// Liveness probe
+///
+/// Returns 200 whenever the process is serving requests. Does not consult
+/// any dependency. No authentication required.
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    responses(
+        (status = 200, description = "Process is alive", body = HealthResponse),
+    ),
+    tag = "Health"
+)]
+pub async fn liveness() -> (StatusCode, ResponseJson<HealthResponse>) {
+    health_check().await
+}
+
+/// Readiness probe
+///
+/// Returns 200 when the database answers a trivial query and the inference
+/// pool has at least one discovered model; otherwise 503 with the failing
+/// dependency marked unhealthy. No authentication required.
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    responses(
+        (status = 200, description = "All dependencies are healthy", body = ReadinessResponse),
+        (status = 503, description = "At least one dependency is unhealthy", body = ReadinessResponse),
+    ),
+    tag = "Health"
+)]
+pub async fn readiness(
+    State(state): State<HealthState>,
+) -> (StatusCode, ResponseJson<ReadinessResponse>) {
+    let (database, inference_pool) = tokio::join!(
+        check_database(&state.database),
+        check_inference_pool(&state.inference_provider_pool)
+    );
+    let ready = database.is_ok() && inference_pool.is_ok();
+    let (status_code, status) = if ready {
+        (StatusCode::OK, "ok")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "unavailable")
+    };
+    (
+        status_code,
+        ResponseJson(ReadinessResponse {
+            status: status.to_string(),
+            checks: ReadinessChecks {
+                database,
+                inference_pool,
+            },
+        }),
+    )
+}
+
+async fn check_database(database: &Database) -> DependencyStatus {
+    let probe = async {
+        let client = database.pool().get().await?;
+        client.query_one("SELECT 1", &[]).await?;
+        Ok::<_, anyhow::Error>(())
+    };
+    match tokio::time::timeout(READINESS_DB_TIMEOUT, probe).await {
+        Ok(Ok(())) => DependencyStatus::ok(),
+        Ok(Err(e)) => {
+            tracing::warn!(error = %e, "Readiness probe: database query failed");
+            DependencyStatus::unhealthy("database query failed")
+        }
+        Err(_) => {
+            tracing::warn!("Readiness probe: database query timed out");
+            DependencyStatus::unhealthy("database query timed out")
+        }
+    }
+}
+
+async fn check_inference_pool(pool: &InferenceProviderPool) -> DependencyStatus {
+    if pool.has_registered_models().await {
+        DependencyStatus::ok()
+    } else {
+        DependencyStatus::unhealthy("no models discovered")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,4 +185,12 @@ mod tests {
         assert_eq!(status, StatusCode::OK);
         assert_eq!(response.status, "ok");
     }
+
+    #[tokio::test]
+    async fn test_inference_pool_without_models_is_unhealthy() {
+        let pool = InferenceProviderPool::new(None, config::ExternalProvidersConfig::default());
+        let status = check_inference_pool(&pool).await;
+        assert!(!status.is_ok());
+        assert_eq!(status.detail.as_deref(), Some("no models discovered"));
+    }
 }
@@ -0,0 +1,371 @@
+use crate::middleware::auth::AuthenticatedApiKey;
+use crate::models::ErrorResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::Json as ResponseJson,
+    Extension,
+};
+use serde::{Deserialize, Serialize};
+use services::mcp::{McpAuthConfig, McpBearerConfig, McpConnector, McpConnectorId, McpError};
+use std::sync::Arc;
+use tracing::{debug, error};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+const MAX_CONNECTOR_NAME_LENGTH: usize = 255;
+
+#[derive(Clone)]
+pub struct McpConnectorsRouteState {
+    pub mcp_service: Arc<dyn services::mcp::McpService>,
+}
+
+/// Authentication the gateway presents to the MCP server
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum McpConnectorAuthRequest {
+    None,
+    Bearer { token: String },
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateMcpConnectorRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub server_url: String,
+    /// Defaults to `{"type": "none"}`
+    pub auth: Option<McpConnectorAuthRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct McpConnectorResponse {
+    pub id: String,
+    pub object: String, // Always "mcp_connector"
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub server_url: String,
+    /// "none" or "bearer"; credentials are never returned
+    pub auth_type: String,
+    pub created_at: i64, // Unix timestamp
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct McpConnectorListResponse {
+    pub object: String, // Always "list"
+    pub data: Vec<McpConnectorResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct McpConnectorDeleteResponse {
+    pub id: String,
+    pub object: String, // Always "mcp_connector"
+    pub deleted: bool,
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct InvokeMcpToolRequest {
+    /// Tool arguments; must be a JSON object when present
+    pub arguments: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InvokeMcpToolResponse {
+    pub object: String, // Always "mcp_tool_result"
+    pub tool: String,
+    /// MCP content blocks returned by the tool
+    pub content: Vec<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub structured_content: Option<serde_json::Value>,
+    /// True when the tool itself reported a failure
+    pub is_error: bool,
+}
+
+impl From<McpConnector> for McpConnectorResponse {
+    fn from(connector: McpConnector) -> Self {
+        let auth_type = match connector.auth {
+            McpAuthConfig::None => "none",
+            McpAuthConfig::Bearer(_) => "bearer",
+            McpAuthConfig::ApiKey(_) => "api_key",
+            McpAuthConfig::Custom(_) => "custom",
+        };
+        Self {
+            id: connector.id.0.to_string(),
+            object: "mcp_connector".to_string(),
+            name: connector.name,
+            description: connector.description,
+            server_url: connector.server_url,
+            auth_type: auth_type.to_string(),
+            created_at: connector.created_at.timestamp(),
+        }
+    }
+}
+
+type ErrorTuple = (StatusCode, ResponseJson<ErrorResponse>);
+
+fn error_response(status: StatusCode, message: &str, error_type: &str) -> ErrorTuple {
+    (
+        status,
+        ResponseJson(ErrorResponse::new(
+            message.to_string(),
+            error_type.to_string(),
+        )),
+    )
+}
+
+fn map_mcp_error(err: McpError) -> ErrorTuple {
+    match err {
+        McpError::ConnectorNotFound => error_response(
+            StatusCode::NOT_FOUND,
+            "MCP connector not found",
+            "not_found_error",
+        ),
+        McpError::ConnectorAlreadyExists => error_response(
+            StatusCode::CONFLICT,
+            "An MCP connector with this name already exists in the workspace",
+            "conflict",
+        ),
+        McpError::InvalidConfiguration(message) => {
+            error_response(StatusCode::BAD_REQUEST, &message, "invalid_request_error")
+        }
+        McpError::ConnectionTimeout { .. } => error_response(
+            StatusCode::GATEWAY_TIMEOUT,
+            "MCP server did not respond in time",
+            "mcp_server_error",
+        ),
+        McpError::ToolNotFound { .. } => error_response(
+            StatusCode::BAD_REQUEST,
+            "Tool not found on this MCP connector",
+            "invalid_request_error",
+        ),
+        McpError::NetworkError(_)
+        | McpError::ProtocolError(_)
+        | McpError::AuthenticationFailed { .. } => {
+            debug!("MCP server request failed: {}", err);
+            error_response(
+                StatusCode::BAD_GATEWAY,
+                "MCP server request failed",
+                "mcp_server_error",
+            )
+        }
+        McpError::InternalError(_) => {
+            error!("MCP connector operation failed: {}", err);
+            error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Internal server error",
+                "server_error",
+            )
+        }
+    }
+}
+
+fn parse_connector_id(connector_id: &str) -> Result<McpConnectorId, ErrorTuple> {
+    Uuid::parse_str(connector_id)
+        .map(McpConnectorId)
+        .map_err(|_| {
+            error_response(
+                StatusCode::BAD_REQUEST,
+                "Invalid connector ID format",
+                "invalid_request_error",
+            )
+        })
+}
+
+/// Register an MCP connector
+///
+/// Registers a remote MCP server for the workspace of the calling API key.
+#[utoipa::path(
+    post,
+    path = "/v1/mcp/connectors",
+    tag = "MCP Connectors",
+    request_body = CreateMcpConnectorRequest,
+    responses(
+        (status = 201, description = "Connector registered", body = McpConnectorResponse),
+        (status = 400, description = "Invalid connector configuration", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "Connector name already in use", body = ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+pub async fn create_mcp_connector(
+    State(state): State<McpConnectorsRouteState>,
+    Extension(api_key): Extension<AuthenticatedApiKey>,
+    Json(request): Json<CreateMcpConnectorRequest>,
+) -> Result<(StatusCode, ResponseJson<McpConnectorResponse>), ErrorTuple> {
+    if request.name.len() > MAX_CONNECTOR_NAME_LENGTH {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "Connector name is too long",
+            "invalid_request_error",
+        ));
+    }
+    let auth = match request.auth.unwrap_or(McpConnectorAuthRequest::None) {
+        McpConnectorAuthRequest::None => McpAuthConfig::None,
+        McpConnectorAuthRequest::Bearer { token } => {
+            if token.is_empty() {
+                return Err(error_response(
+                    StatusCode::BAD_REQUEST,
+                    "Bearer token must not be empty",
+                    "invalid_request_error",
+                ));
+            }
+            McpAuthConfig::Bearer(McpBearerConfig {
+                token,
+                header_name: None,
+            })
+        }
+    };
+
+    let connector = state
+        .mcp_service
+        .create_connector(
+            api_key.organization.id.clone(),
+            api_key.workspace.id.clone(),
+            api_key.api_key.created_by_user_id.0,
+            request.name,
+            request.description,
+            request.server_url,
+            auth,
+        )
+        .await
+        .map_err(map_mcp_error)?;
+
+    debug!(
+        "Registered MCP connector {} in workspace {}",
+        connector.id, api_key.workspace.id.0
+    );
+    Ok((StatusCode::CREATED, ResponseJson(connector.into())))
+}
+
+/// List MCP connectors
+///
+/// Lists the MCP connectors registered in the workspace of the calling API key.
+#[utoipa::path(
+    get,
+    path = "/v1/mcp/connectors",
+    tag = "MCP Connectors",
+    responses(
+        (status = 200, description = "Connectors in the workspace", body = McpConnectorListResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+pub async fn list_mcp_connectors(
+    State(state): State<McpConnectorsRouteState>,
+    Extension(api_key): Extension<AuthenticatedApiKey>,
+) -> Result<ResponseJson<McpConnectorListResponse>, ErrorTuple> {
+    let connectors = state
+        .mcp_service
+        .list_connectors(api_key.workspace.id.clone())
+        .await
+        .map_err(map_mcp_error)?;
+
+    Ok(ResponseJson(McpConnectorListResponse {
+        object: "list".to_string(),
+        data: connectors.into_iter().map(Into::into).collect(),
+    }))
+}
+
+/// Delete an MCP connector
+#[utoipa::path(
+    delete,
+    path = "/v1/mcp/connectors/{connector_id}",
+    tag = "MCP Connectors",
+    params(("connector_id" = String, Path, description = "Connector ID")),
+    responses(
+        (status = 200, description = "Connector deleted", body = McpConnectorDeleteResponse),
+        (status = 400, description = "Invalid connector ID", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Connector not found", body = ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+pub async fn delete_mcp_connector(
+    State(state): State<McpConnectorsRouteState>,
+    Extension(api_key): Extension<AuthenticatedApiKey>,
+    Path(connector_id): Path<String>,
+) -> Result<ResponseJson<McpConnectorDeleteResponse>, ErrorTuple> {
+    let id = parse_connector_id(&connector_id)?;
+    let deleted = state
+        .mcp_service
+        .delete_connector(id.clone(), api_key.workspace.id.clone())
+        .await
+        .map_err(map_mcp_error)?;
+    if !deleted {
+        return Err(map_mcp_error(McpError::ConnectorNotFound));
+    }
+
+    Ok(ResponseJson(McpConnectorDeleteResponse {
+        id: id.0.to_string(),
+        object: "mcp_connector".to_string(),
+        deleted: true,
+    }))
+}
+
+/// Invoke an MCP tool
+///
+/// Calls a tool on a registered connector. A failure reported by the tool
+/// itself is returned with `is_error: true`; a tool the connector does not
+/// expose returns 400 and transport failures return 502.
+#[utoipa::path(
+    post,
+    path = "/v1/mcp/connectors/{connector_id}/tools/{tool_name}/invoke",
+    tag = "MCP Connectors",
+    params(
+        ("connector_id" = String, Path, description = "Connector ID"),
+        ("tool_name" = String, Path, description = "Name of the tool to call")
+    ),
+    request_body = InvokeMcpToolRequest,
+    responses(
+        (status = 200, description = "Tool result", body = InvokeMcpToolResponse),
+        (status = 400, description = "Invalid request or unknown tool", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Connector not found", body = ErrorResponse),
+        (status = 502, description = "MCP server request failed", body = ErrorResponse),
+        (status = 504, description = "MCP server timed out", body = ErrorResponse)
+    ),
+    security(("api_key" = []))
+)]
+pub async fn invoke_mcp_tool(
+    State(state): State<McpConnectorsRouteState>,
+    Extension(api_key): Extension<AuthenticatedApiKey>,
+    Path((connector_id, tool_name)): Path<(String, String)>,
+    request: Option<Json<InvokeMcpToolRequest>>,
+) -> Result<ResponseJson<InvokeMcpToolResponse>, ErrorTuple> {
+    let id = parse_connector_id(&connector_id)?;
+    let arguments = request.and_then(|Json(request)| request.arguments);
+    if arguments.as_ref().is_some_and(|args| !args.is_object()) {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "Tool arguments must be a JSON object",
+            "invalid_request_error",
+        ));
+    }
+
+    let result = state
+        .mcp_service
+        .invoke_connector_tool(
+            id.clone(),
+            api_key.workspace.id.clone(),
+            tool_name.clone(),
+            arguments,
+        )
+        .await
+        .map_err(map_mcp_error)?;
+
+    debug!("Invoked tool on MCP connector {}", id);
+    let content = result
+        .content
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| map_mcp_error(McpError::InternalError(e.to_string())))?;
+
+    Ok(ResponseJson(InvokeMcpToolResponse {
+        object: "mcp_tool_result".to_string(),
+        tool: tool_name,
+        content,
+        structured_content: result.structured_content,
+        is_error: result.is_error.unwrap_or(false),
+    }))
+}
@@ -3,6 +3,7 @@ pub mod api;
 pub mod attestation;
 pub mod auth;
 pub mod auth_vpc;
+pub mod batches;
 pub mod billing;
 pub mod common;
 pub mod completions;
@@ -12,10 +13,13 @@ pub mod feature_requests;
 pub mod files;
 pub mod gateway;
 pub mod health;
+pub mod mcp_connectors;
 pub mod mcp_server;
 pub mod models;
 pub mod ohttp;
 pub mod organization_members;
+pub mod organization_model_aliases;
+pub mod organization_model_quotas;
 pub mod organizations;
 pub mod reporting_tokens;
 pub mod reporting_usage;
@@ -24,5 +28,6 @@ pub mod services;
 pub mod staking_farm;
 pub mod unsupported;
 pub mod usage;
+pub mod usage_csv;
 pub mod users;
 pub mod workspaces;
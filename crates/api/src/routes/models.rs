@@ -1,6 +1,6 @@
 use crate::models::{
-    DecimalPrice, ErrorResponse, ModelArchitecture, ModelListResponse, ModelMetadata,
-    ModelWithPricing,
+    DecimalPrice, ErrorResponse, ModelArchitecture, ModelCapabilities, ModelCapabilitiesResponse,
+    ModelListResponse, ModelMetadata, ModelPricingResponse, ModelWithPricing,
 };
 use axum::{
     extract::{Path, Query, State},
@@ -144,6 +144,11 @@ pub async fn list_models(
                     model.provider_config,
                 ),
                 attestation_supported: model.attestation_supported,
+                capabilities: ModelCapabilities::derive(
+                    model.input_modalities.as_deref(),
+                    &model.supported_features,
+                    model.max_output_length,
+                ),
                 architecture: ModelArchitecture::from_options(
                     model.input_modalities,
                     model.output_modalities,
@@ -260,6 +265,11 @@ pub async fn get_model_by_name(
             provider_type: model.provider_type,
             provider_config: crate::routes::common::redact_provider_config(model.provider_config),
             attestation_supported: model.attestation_supported,
+            capabilities: ModelCapabilities::derive(
+                model.input_modalities.as_deref(),
+                &model.supported_features,
+                model.max_output_length,
+            ),
             architecture: ModelArchitecture::from_options(
                 model.input_modalities,
                 model.output_modalities,
@@ -283,6 +293,138 @@ pub async fn get_model_by_name(
     Ok(ResponseJson(api_model))
 }
 
+/// Get model capabilities
+///
+/// Get the capability flags (tools, vision, JSON schema, output limit) for a
+/// model. URL-encode model names containing slashes. Public endpoint.
+#[utoipa::path(
+    get,
+    path = "/v1/models/{model_name}/capabilities",
+    tag = "Models",
+    params(
+        ("model_name" = String, Path, description = "Model name or alias (URL-encode if it contains slashes)")
+    ),
+    responses(
+        (status = 200, description = "Model capability flags", body = ModelCapabilitiesResponse),
+        (status = 404, description = "Model not found", body = ErrorResponse),
+        (status = 500, description = "Server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_model_capabilities(
+    State(app_state): State<ModelsAppState>,
+    Path(model_name): Path<String>,
+) -> Result<ResponseJson<ModelCapabilitiesResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    debug!("Get model capabilities request for: {}", model_name);
+
+    let model = app_state
+        .models_service
+        .resolve_public_model(&model_name)
+        .await
+        .map_err(|e| match e {
+            services::models::ModelsError::NotFound(_) => {
+                warn!("Model not found: '{}' (URL-decoded query)", model_name);
+                (
+                    StatusCode::NOT_FOUND,
+                    ResponseJson(ErrorResponse::new(
+                        format!("Model '{model_name}' not found"),
+                        "model_not_found".to_string(),
+                    )),
+                )
+            }
+            other => {
+                error!(error = %other, "Failed to get capabilities for model '{}'", model_name);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    ResponseJson(ErrorResponse::new(
+                        "Failed to retrieve model".to_string(),
+                        "internal_server_error".to_string(),
+                    )),
+                )
+            }
+        })?;
+
+    Ok(ResponseJson(ModelCapabilitiesResponse {
+        capabilities: ModelCapabilities::derive(
+            model.input_modalities.as_deref(),
+            &model.supported_features,
+            model.max_output_length,
+        ),
+        model: model.model_name,
+    }))
+}
+
+/// Get model pricing
+///
+/// Get the current per-token (and per-image) prices for a model. Aliases resolve
+/// to the canonical model. URL-encode model names containing slashes. Public endpoint.
+#[utoipa::path(
+    get,
+    path = "/v1/models/{model_name}/pricing",
+    tag = "Models",
+    params(
+        ("model_name" = String, Path, description = "Model name or alias (URL-encode if it contains slashes)")
+    ),
+    responses(
+        (status = 200, description = "Current model pricing", body = ModelPricingResponse),
+        (status = 404, description = "Model not found", body = ErrorResponse),
+        (status = 500, description = "Server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_model_pricing(
+    State(app_state): State<ModelsAppState>,
+    Path(model_name): Path<String>,
+) -> Result<ResponseJson<ModelPricingResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    debug!("Get model pricing request for: {}", model_name);
+
+    let model = app_state
+        .models_service
+        .resolve_public_model(&model_name)
+        .await
+        .map_err(|e| match e {
+            services::models::ModelsError::NotFound(_) => {
+                warn!("Model not found: '{}' (URL-decoded query)", model_name);
+                (
+                    StatusCode::NOT_FOUND,
+                    ResponseJson(ErrorResponse::new(
+                        format!("Model '{model_name}' not found"),
+                        "model_not_found".to_string(),
+                    )),
+                )
+            }
+            other => {
+                error!(error = %other, "Failed to get pricing for model '{}'", model_name);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    ResponseJson(ErrorResponse::new(
+                        "Failed to retrieve model".to_string(),
+                        "internal_server_error".to_string(),
+                    )),
+                )
+            }
+        })?;
+
+    let price = |amount| DecimalPrice {
+        amount,
+        scale: 9,
+        currency: "USD".to_string(),
+    };
+    // Unpriced models carry the zero default on every rate.
+    let pricing_unavailable = model.input_cost_per_token == 0
+        && model.output_cost_per_token == 0
+        && model.cost_per_image == 0
+        && model.cache_read_cost_per_token.unwrap_or(0) == 0;
+
+    Ok(ResponseJson(ModelPricingResponse {
+        model: model.model_name,
+        currency: "USD".to_string(),
+        input_cost_per_token: price(model.input_cost_per_token),
+        output_cost_per_token: price(model.output_cost_per_token),
+        cost_per_image: price(model.cost_per_image),
+        cache_read_cost_per_token: model.cache_read_cost_per_token.map(price),
+        pricing_unavailable,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,6 +472,7 @@ mod tests {
             is_ready: Some(true),
             deprecation_date: None,
             openrouter_slug: None,
+            max_tokens_limits: Default::default(),
             created_at: chrono::Utc::now(),
         }
     }
@@ -372,10 +515,50 @@ mod tests {
             Ok(service_model("public/enriched-detail", Some(4_096)))
         }
 
+        async fn resolve_and_get_model_for_organization(
+            &self,
+            _organization_id: Uuid,
+            identifier: &str,
+        ) -> Result<services::models::ModelWithPricing, ModelsError> {
+            self.resolve_and_get_model(identifier).await
+        }
+
         async fn resolve_alias_cached(&self, _identifier: &str) -> Option<String> {
             None
         }
 
+        async fn resolve_alias_cached_for_organization(
+            &self,
+            _organization_id: Uuid,
+            _identifier: &str,
+        ) -> Option<String> {
+            None
+        }
+
+        async fn list_organization_model_aliases(
+            &self,
+            _organization_id: Uuid,
+        ) -> Result<Vec<services::models::OrganizationModelAlias>, ModelsError> {
+            Ok(Vec::new())
+        }
+
+        async fn set_organization_model_alias(
+            &self,
+            _organization_id: Uuid,
+            _alias_name: &str,
+            _canonical_model_name: &str,
+        ) -> Result<services::models::OrganizationModelAlias, ModelsError> {
+            unimplemented!()
+        }
+
+        async fn delete_organization_model_alias(
+            &self,
+            _organization_id: Uuid,
+            _alias_name: &str,
+        ) -> Result<(), ModelsError> {
+            unimplemented!()
+        }
+
         async fn get_configured_model_names(&self) -> Result<Vec<String>, ModelsError> {
             Ok(Vec::new())
         }
@@ -5,8 +5,11 @@ use crate::{
         services_member_to_api_member, services_member_with_user_to_api,
     },
     middleware::AuthenticatedUser,
-    models::{ErrorResponse, ListOrganizationMembersResponse, PublicOrganizationMemberResponse},
-    routes::api::AppState,
+    models::{
+        ErrorResponse, ListMetadata, ListOrganizationMembersResponse,
+        PublicOrganizationMemberResponse,
+    },
+    routes::{api::AppState, common::map_organization_error},
 };
 use axum::{
     extract::{Extension, Json, Path, Query, State},
@@ -34,7 +37,7 @@ use uuid::Uuid;
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden - not an admin or owner", body = ErrorResponse),
         (status = 404, description = "User not found", body = ErrorResponse),
-        (status = 409, description = "User is already a member", body = ErrorResponse),
+        (status = 409, description = "User is already a member, or the organization has reached its seat limit (`seat_limit_exceeded`)", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     security(
@@ -99,6 +102,7 @@ pub async fn add_organization_member(
                 "conflict".to_string(),
             )),
         )),
+        Err(e @ OrganizationError::SeatLimitExceeded) => Err(map_organization_error(e)),
         Err(e) => {
             error!("Failed to add organization member: {}", e);
             Err((
@@ -129,6 +133,7 @@ pub async fn add_organization_member(
         (status = 400, description = "Bad request - empty invitation list", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden - not an admin or owner", body = ErrorResponse),
+        (status = 409, description = "Organization has reached its seat limit (`seat_limit_exceeded`)", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     security(
@@ -206,6 +211,7 @@ pub async fn invite_organization_member_by_email(
             StatusCode::FORBIDDEN,
             Json(ErrorResponse::new(msg, "forbidden".to_string())),
         )),
+        Err(e @ OrganizationError::SeatLimitExceeded) => Err(map_organization_error(e)),
         Err(_) => {
             error!("Failed to invite organization members");
             Err((
@@ -366,10 +372,11 @@ pub async fn remove_organization_member(
 /// Query parameters for listing organization members
 #[derive(Debug, Deserialize)]
 pub struct ListMembersParams {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
+    pub after: Option<String>,
+    pub before: Option<String>,
 }
 
 /// Query parameters for listing organization invitations
@@ -528,6 +535,7 @@ pub async fn cancel_organization_invitation(
         Err(OrganizationError::UserNotFound)
         | Err(OrganizationError::AlreadyExists)
         | Err(OrganizationError::AlreadyMember)
+        | Err(OrganizationError::SeatLimitExceeded)
         | Err(OrganizationError::InternalError(_)) => {
             error!("Failed to cancel organization invitation");
             Err((
@@ -590,7 +598,9 @@ pub async fn cancel_invitation(
     params(
         ("org_id" = Uuid, Path, description = "Organization ID"),
         ("limit" = Option<i64>, Query, description = "Number of records to return (default: 100, max: 1000)"),
-        ("offset" = Option<i64>, Query, description = "Offset for pagination (default: 0)")
+        ("offset" = Option<i64>, Query, description = "Offset for pagination (default: 0)"),
+        ("after" = Option<String>, Query, description = "Cursor from `next_cursor`: return the page after it"),
+        ("before" = Option<String>, Query, description = "Cursor from `previous_cursor`: return the page before it")
     ),
     responses(
         (status = 200, description = "List of organization members with public user information", body = ListOrganizationMembersResponse),
@@ -609,12 +619,21 @@ pub async fn list_organization_members(
     Query(params): Query<ListMembersParams>,
 ) -> Result<Json<ListOrganizationMembersResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
     let user_id = user.0.id;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
+
     debug!(
         "Listing members for organization: {} for user: {} (limit: {}, offset: {})",
-        org_id, user_id, params.limit, params.offset
+        org_id, user_id, limit, params.offset
     );
-
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let cursor = crate::routes::common::parse_page_cursor(
+        params.after.as_deref(),
+        params.before.as_deref(),
+        params.offset,
+    )?;
 
     let organization_id = OrganizationId(org_id);
     let requester_id = authenticated_user_to_user_id(user);
@@ -658,12 +677,24 @@ pub async fn list_organization_members(
         .get_members_with_users_paginated(
             organization_id,
             requester_id,
-            params.limit,
+            // One extra row tells whether a next page exists.
+            limit + 1,
             params.offset,
+            cursor,
         )
         .await
     {
         Ok(members) => {
+            let (members, next_cursor, previous_cursor) = crate::routes::common::keyset_page(
+                members,
+                limit,
+                params.offset,
+                cursor,
+                |member| services::organization::ListCursor {
+                    sort_key: member.joined_at,
+                    id: member.user_id.0,
+                },
+            );
             let member_responses: Vec<PublicOrganizationMemberResponse> = members
                 .into_iter()
                 .map(services_member_with_user_to_api)
@@ -676,11 +707,17 @@ pub async fn list_organization_members(
                 total
             );
 
+            let list =
+                ListMetadata::for_page(&member_responses, next_cursor.is_some(), |m| m.id.clone());
             Ok(Json(ListOrganizationMembersResponse {
+                list,
+                data: member_responses.clone(),
                 members: member_responses,
                 total,
-                limit: params.limit,
+                limit,
                 offset: params.offset,
+                next_cursor,
+                previous_cursor,
             }))
         }
         Err(OrganizationError::Unauthorized(msg)) => {
@@ -0,0 +1,200 @@
+use crate::{
+    conversions::authenticated_user_to_user_id,
+    middleware::AuthenticatedUser,
+    models::{
+        ErrorResponse, ListOrganizationModelAliasesResponse, OrganizationModelAliasResponse,
+        SetOrganizationModelAliasRequest,
+    },
+    routes::{api::AppState, common::map_organization_error},
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use services::{
+    models::{ModelsError, OrganizationModelAlias},
+    organization::{MemberRole, OrganizationId},
+};
+use uuid::Uuid;
+
+type RouteError = (StatusCode, Json<ErrorResponse>);
+
+/// List organization model aliases
+///
+/// Private aliases resolve only for requests made with this organization's
+/// API keys, and take precedence over global aliases. Any member may list them.
+#[utoipa::path(
+    get,
+    path = "/v1/organizations/{org_id}/model-aliases",
+    tag = "Organizations",
+    params(
+        ("org_id" = Uuid, Path, description = "Organization ID")
+    ),
+    responses(
+        (status = 200, description = "Organization model aliases", body = ListOrganizationModelAliasesResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn list_organization_model_aliases(
+    State(app_state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path(org_id): Path<Uuid>,
+) -> Result<Json<ListOrganizationModelAliasesResponse>, RouteError> {
+    require_member_role(&app_state, user, org_id, false).await?;
+
+    let aliases = app_state
+        .models_service
+        .list_organization_model_aliases(org_id)
+        .await
+        .map_err(map_models_error)?;
+
+    Ok(Json(ListOrganizationModelAliasesResponse {
+        aliases: aliases.into_iter().map(alias_response).collect(),
+    }))
+}
+
+/// Set an organization model alias
+///
+/// Creates the alias or points it at a different model. The target must be
+/// the canonical name of an active model, and the alias may not shadow a
+/// canonical model name. Requires the owner or admin role.
+#[utoipa::path(
+    put,
+    path = "/v1/organizations/{org_id}/model-aliases/{alias}",
+    tag = "Organizations",
+    params(
+        ("org_id" = Uuid, Path, description = "Organization ID"),
+        ("alias" = String, Path, description = "Alias name (URL-encoded)")
+    ),
+    request_body = SetOrganizationModelAliasRequest,
+    responses(
+        (status = 200, description = "Alias saved", body = OrganizationModelAliasResponse),
+        (status = 400, description = "Invalid alias or unknown target model", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn set_organization_model_alias(
+    State(app_state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path((org_id, alias)): Path<(Uuid, String)>,
+    Json(request): Json<SetOrganizationModelAliasRequest>,
+) -> Result<Json<OrganizationModelAliasResponse>, RouteError> {
+    require_member_role(&app_state, user, org_id, true).await?;
+
+    let alias = app_state
+        .models_service
+        .set_organization_model_alias(org_id, &alias, request.model.trim())
+        .await
+        .map_err(map_models_error)?;
+
+    Ok(Json(alias_response(alias)))
+}
+
+/// Delete an organization model alias
+///
+/// Requires the owner or admin role.
+#[utoipa::path(
+    delete,
+    path = "/v1/organizations/{org_id}/model-aliases/{alias}",
+    tag = "Organizations",
+    params(
+        ("org_id" = Uuid, Path, description = "Organization ID"),
+        ("alias" = String, Path, description = "Alias name (URL-encoded)")
+    ),
+    responses(
+        (status = 204, description = "Alias deleted"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Alias not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn delete_organization_model_alias(
+    State(app_state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path((org_id, alias)): Path<(Uuid, String)>,
+) -> Result<StatusCode, RouteError> {
+    require_member_role(&app_state, user, org_id, true).await?;
+
+    app_state
+        .models_service
+        .delete_organization_model_alias(org_id, &alias)
+        .await
+        .map_err(map_models_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Require membership in `org_id`; `manage` additionally requires the owner
+/// or admin role.
+async fn require_member_role(
+    app_state: &AppState,
+    user: AuthenticatedUser,
+    org_id: Uuid,
+    manage: bool,
+) -> Result<(), RouteError> {
+    let user_id = authenticated_user_to_user_id(user);
+    let role = app_state
+        .organization_service
+        .get_user_role(OrganizationId(org_id), user_id)
+        .await
+        .map_err(map_organization_error)?;
+
+    match role {
+        Some(MemberRole::Owner | MemberRole::Admin) => Ok(()),
+        Some(MemberRole::Member) if !manage => Ok(()),
+        Some(MemberRole::Member) | None => Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "You are not authorized to manage model aliases for this organization.".to_string(),
+                "forbidden".to_string(),
+            )),
+        )),
+    }
+}
+
+fn alias_response(alias: OrganizationModelAlias) -> OrganizationModelAliasResponse {
+    OrganizationModelAliasResponse {
+        alias: alias.alias_name,
+        model: alias.canonical_model_name,
+        created_at: alias.created_at,
+        updated_at: alias.updated_at,
+    }
+}
+
+fn map_models_error(error: ModelsError) -> RouteError {
+    match error {
+        ModelsError::InvalidParams(message) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(message, "bad_request".to_string())),
+        ),
+        ModelsError::NotFound(message) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(message, "not_found".to_string())),
+        ),
+        ModelsError::InternalError(message) => {
+            tracing::error!(error = %message, "Organization model alias operation failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new(
+                    "Internal server error".to_string(),
+                    "internal_server_error".to_string(),
+                )),
+            )
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    conversions::authenticated_user_to_user_id,
+    middleware::AuthenticatedUser,
+    models::{
+        ErrorResponse, ListOrganizationModelQuotasResponse, OrganizationModelQuotaResponse,
+        SetOrganizationModelQuotaRequest,
+    },
+    routes::{api::AppState, common::map_organization_error},
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use services::{
+    organization::{MemberRole, OrganizationId},
+    usage::{ModelQuota, ModelQuotaPeriod, UsageError},
+};
+use uuid::Uuid;
+
+type RouteError = (StatusCode, Json<ErrorResponse>);
+
+/// List organization model quotas
+///
+/// Per-model token caps for this organization. Once a model's usage in the
+/// current UTC day or month reaches its quota, requests for that model are
+/// rejected with `quota_exceeded` until the period resets; other models are
+/// unaffected. Any member may list them.
+#[utoipa::path(
+    get,
+    path = "/v1/organizations/{org_id}/model-quotas",
+    tag = "Organizations",
+    params(
+        ("org_id" = Uuid, Path, description = "Organization ID")
+    ),
+    responses(
+        (status = 200, description = "Organization model quotas", body = ListOrganizationModelQuotasResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn list_organization_model_quotas(
+    State(app_state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path(org_id): Path<Uuid>,
+) -> Result<Json<ListOrganizationModelQuotasResponse>, RouteError> {
+    require_member_role(&app_state, user, org_id, false).await?;
+
+    let quotas = app_state
+        .usage_service
+        .list_model_quotas(org_id)
+        .await
+        .map_err(map_usage_error)?;
+
+    Ok(Json(ListOrganizationModelQuotasResponse {
+        quotas: quotas.into_iter().map(quota_response).collect(),
+    }))
+}
+
+/// Set an organization model quota
+///
+/// Creates or replaces the token quota for a model and period. The model
+/// must be the canonical name of an active model. Requires the owner or
+/// admin role.
+#[utoipa::path(
+    put,
+    path = "/v1/organizations/{org_id}/model-quotas/{model}/{period}",
+    tag = "Organizations",
+    params(
+        ("org_id" = Uuid, Path, description = "Organization ID"),
+        ("model" = String, Path, description = "Canonical model name (URL-encoded)"),
+        ("period" = String, Path, description = "`day` or `month`")
+    ),
+    request_body = SetOrganizationModelQuotaRequest,
+    responses(
+        (status = 200, description = "Quota saved", body = OrganizationModelQuotaResponse),
+        (status = 400, description = "Invalid period or token limit", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Model not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn set_organization_model_quota(
+    State(app_state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path((org_id, model, period)): Path<(Uuid, String, String)>,
+    Json(request): Json<SetOrganizationModelQuotaRequest>,
+) -> Result<Json<OrganizationModelQuotaResponse>, RouteError> {
+    let period = parse_period(&period)?;
+    require_member_role(&app_state, user, org_id, true).await?;
+
+    let quota = app_state
+        .usage_service
+        .set_model_quota(org_id, model.trim(), period, request.token_limit)
+        .await
+        .map_err(map_usage_error)?;
+
+    Ok(Json(quota_response(quota)))
+}
+
+/// Delete an organization model quota
+///
+/// Requires the owner or admin role.
+#[utoipa::path(
+    delete,
+    path = "/v1/organizations/{org_id}/model-quotas/{model}/{period}",
+    tag = "Organizations",
+    params(
+        ("org_id" = Uuid, Path, description = "Organization ID"),
+        ("model" = String, Path, description = "Canonical model name (URL-encoded)"),
+        ("period" = String, Path, description = "`day` or `month`")
+    ),
+    responses(
+        (status = 204, description = "Quota deleted"),
+        (status = 400, description = "Invalid period", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Quota not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn delete_organization_model_quota(
+    State(app_state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path((org_id, model, period)): Path<(Uuid, String, String)>,
+) -> Result<StatusCode, RouteError> {
+    let period = parse_period(&period)?;
+    require_member_role(&app_state, user, org_id, true).await?;
+
+    app_state
+        .usage_service
+        .delete_model_quota(org_id, &model, period)
+        .await
+        .map_err(map_usage_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn parse_period(period: &str) -> Result<ModelQuotaPeriod, RouteError> {
+    ModelQuotaPeriod::parse(period).ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "period must be 'day' or 'month'".to_string(),
+                "bad_request".to_string(),
+            )),
+        )
+    })
+}
+
+/// Require membership in `org_id`; `manage` additionally requires the owner
+/// or admin role.
+async fn require_member_role(
+    app_state: &AppState,
+    user: AuthenticatedUser,
+    org_id: Uuid,
+    manage: bool,
+) -> Result<(), RouteError> {
+    let user_id = authenticated_user_to_user_id(user);
+    let role = app_state
+        .organization_service
+        .get_user_role(OrganizationId(org_id), user_id)
+        .await
+        .map_err(map_organization_error)?;
+
+    match role {
+        Some(MemberRole::Owner | MemberRole::Admin) => Ok(()),
+        Some(MemberRole::Member) if !manage => Ok(()),
+        Some(MemberRole::Member) | None => Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "You are not authorized to manage model quotas for this organization.".to_string(),
+                "forbidden".to_string(),
+            )),
+        )),
+    }
+}
+
+fn quota_response(quota: ModelQuota) -> OrganizationModelQuotaResponse {
+    OrganizationModelQuotaResponse {
+        model: quota.model_name,
+        period: quota.period.as_str().to_string(),
+        token_limit: quota.token_limit,
+        created_at: quota.created_at,
+        updated_at: quota.updated_at,
+    }
+}
+
+fn map_usage_error(error: UsageError) -> RouteError {
+    match error {
+        UsageError::ValidationError(message) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(message, "bad_request".to_string())),
+        ),
+        UsageError::ModelNotFound(message) | UsageError::NotFound(message) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(message, "not_found".to_string())),
+        ),
+        error => {
+            tracing::error!(error = %error, "Organization model quota operation failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new(
+                    "Internal server error".to_string(),
+                    "internal_server_error".to_string(),
+                )),
+            )
+        }
+    }
+}
@@ -25,7 +25,9 @@ use utoipa::ToSchema;
         ("limit" = Option<i64>, Query, description = "Maximum number to return"),
         ("offset" = Option<i64>, Query, description = "Number to skip"),
         ("order_by" = Option<OrganizationOrderBy>, Query, description = "Sort by field"),
-        ("order_direction" = Option<OrganizationOrderDirection>, Query, description = "Sort direction")
+        ("order_direction" = Option<OrganizationOrderDirection>, Query, description = "Sort direction"),
+        ("after" = Option<String>, Query, description = "Cursor from `next_cursor`: return the page after it"),
+        ("before" = Option<String>, Query, description = "Cursor from `previous_cursor`: return the page before it")
     ),
     responses(
         (status = 200, description = "List of organizations", body = ListOrganizationsResponse),
@@ -43,7 +45,16 @@ pub async fn list_organizations(
 ) -> Result<Json<ListOrganizationsResponse>, (StatusCode, Json<ErrorResponse>)> {
     debug!("Listing organizations for user: {}", user.0.id);
 
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
+    let cursor = crate::routes::common::parse_page_cursor(
+        params.after.as_deref(),
+        params.before.as_deref(),
+        params.offset,
+    )?;
 
     let user_id = crate::conversions::authenticated_user_to_user_id(user);
 
@@ -70,14 +81,26 @@ pub async fn list_organizations(
         .organization_service
         .list_organizations_with_roles_for_user(
             user_id.clone(),
-            params.limit,
+            // One extra row tells whether a next page exists.
+            limit + 1,
             params.offset,
             params.order_by.map(From::from),
             params.order_direction.map(From::from),
+            cursor,
         )
         .await
     {
         Ok(organizations) => {
+            let (organizations, next_cursor, previous_cursor) = crate::routes::common::keyset_page(
+                organizations,
+                limit,
+                params.offset,
+                cursor,
+                |org_with_role| services::organization::ListCursor {
+                    sort_key: org_with_role.organization.created_at,
+                    id: org_with_role.organization.id.0,
+                },
+            );
             debug!("Found {} organizations for user", organizations.len());
             let org_responses: Vec<OrganizationResponse> = organizations
                 .into_iter()
@@ -92,8 +115,10 @@ pub async fn list_organizations(
             Ok(Json(ListOrganizationsResponse {
                 organizations: org_responses,
                 total,
-                limit: params.limit,
+                limit,
                 offset: params.offset,
+                next_cursor,
+                previous_cursor,
             }))
         }
         Err(_) => {
@@ -112,12 +137,13 @@ pub async fn list_organizations(
 /// Query parameters for listing
 #[derive(Debug, Deserialize)]
 pub struct ListOrganizationsParams {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     pub order_by: Option<OrganizationOrderBy>,
     pub order_direction: Option<OrganizationOrderDirection>,
+    pub after: Option<String>,
+    pub before: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -6,7 +6,7 @@ use crate::{
 };
 use axum::{
     body::Body,
-    extract::{Extension, Path, Query, State},
+    extract::{Extension, Path, Query, RawQuery, State},
     http::{header, HeaderMap, Response, StatusCode},
     response::{IntoResponse, Json as ResponseJson},
 };
@@ -43,7 +43,7 @@ fn not_implemented_error(message: impl Into<String>) -> NotImplementedErrorRespo
 
 #[cfg(test)]
 mod tests {
-    use super::not_implemented_error;
+    use super::{not_implemented_error, parse_input_items_include, InputItemsInclude};
     use crate::routes::common::{HEADER_SHOULD_RETRY, SHOULD_RETRY_FALSE};
     use axum::{http::StatusCode, response::IntoResponse};
 
@@ -60,6 +60,34 @@ mod tests {
             Some(SHOULD_RETRY_FALSE)
         );
     }
+
+    #[test]
+    fn input_items_include_accepts_comma_and_bracket_forms() {
+        assert_eq!(
+            parse_input_items_include(None).unwrap(),
+            InputItemsInclude::default()
+        );
+        assert_eq!(
+            parse_input_items_include(Some("limit=5&include=output,tool_calls")).unwrap(),
+            InputItemsInclude {
+                output: true,
+                tool_calls: true,
+            }
+        );
+        assert_eq!(
+            parse_input_items_include(Some("include%5B%5D=tool_calls")).unwrap(),
+            InputItemsInclude {
+                output: false,
+                tool_calls: true,
+            }
+        );
+    }
+
+    #[test]
+    fn input_items_include_rejects_unknown_values() {
+        let error = parse_input_items_include(Some("include=output,secrets")).unwrap_err();
+        assert!(error.contains("secrets"), "error: {error}");
+    }
 }
 
 // Helper function to convert service ResponseContentItem to API ResponseContentPart (input-only)
@@ -104,6 +132,7 @@ fn map_response_error_to_status(error: &ServiceResponseError) -> StatusCode {
         ServiceResponseError::StreamInterrupted => StatusCode::INTERNAL_SERVER_ERROR,
         ServiceResponseError::ConversationNotFound => StatusCode::NOT_FOUND,
         ServiceResponseError::PreviousResponseNotFound => StatusCode::NOT_FOUND,
+        ServiceResponseError::ResponseNotFound => StatusCode::NOT_FOUND,
         ServiceResponseError::McpConnectionFailed(_) => StatusCode::BAD_GATEWAY,
         ServiceResponseError::McpToolDiscoveryFailed(_) => StatusCode::BAD_GATEWAY,
         ServiceResponseError::McpToolExecutionFailed(_) => StatusCode::BAD_GATEWAY,
@@ -170,6 +199,10 @@ impl From<ServiceResponseError> for ErrorResponse {
                 "Previous response not found".to_string(),
                 "not_found_error".to_string(),
             ),
+            ServiceResponseError::ResponseNotFound => ErrorResponse::new(
+                "Response not found".to_string(),
+                "not_found_error".to_string(),
+            ),
             ServiceResponseError::McpConnectionFailed(msg) => ErrorResponse::new(
                 format!("MCP connection failed: {msg}"),
                 "mcp_error".to_string(),
@@ -242,6 +275,7 @@ pub struct ResponseRouteState {
         (status = 400, description = "Invalid request", body = ErrorResponse),
         (status = 401, description = "Invalid or missing API key", body = ErrorResponse),
         (status = 402, description = "Insufficient credits", body = ErrorResponse),
+        (status = 413, description = "Request body exceeds the configured size limit", body = ErrorResponse),
         (status = 500, description = "Server error", body = ErrorResponse)
     ),
     security(
@@ -558,6 +592,13 @@ pub async fn create_response(
                                 }
                             }
                         }
+                        "response.cancelled" => {
+                            status = ResponseStatus::Cancelled;
+                            if event.usage.is_some() {
+                                tracked_usage = event.usage.clone();
+                            }
+                            final_response = event.response;
+                        }
                         "response.failed" => {
                             status = ResponseStatus::Failed;
                             failed_error = event.error.clone();
@@ -753,9 +794,10 @@ pub async fn delete_response(
     Err(not_implemented_error("Delete response not yet implemented"))
 }
 
-/// Cancel a response (for background responses)
+/// Cancel a response
 ///
-/// Cancel an in-progress background response.
+/// Cancel an in-progress response. Its generation is aborted and a streaming
+/// client receives a final `response.cancelled` event.
 #[utoipa::path(
     post,
     path = "/v1/responses/{response_id}/cancel",
@@ -765,21 +807,33 @@ pub async fn delete_response(
     ),
     responses(
         (status = 200, description = "Response cancelled successfully", body = ResponseObject),
+        (status = 400, description = "Response is not in progress", body = ErrorResponse),
         (status = 401, description = "Invalid or missing API key", body = ErrorResponse),
-        (status = 404, description = "Response not found", body = ErrorResponse),
-        (status = 501, description = "Not implemented", body = ErrorResponse)
+        (status = 404, description = "Response not found", body = ErrorResponse)
     ),
     security(
         ("api_key" = [])
     )
 )]
 pub async fn cancel_response(
-    Path(_response_id): Path<String>,
-    State(_state): State<ResponseRouteState>,
-    Extension(_api_key): Extension<AuthenticatedApiKey>,
-) -> Result<ResponseJson<ResponseObject>, NotImplementedErrorResponse> {
-    // TODO: Implement cancel_response method in ResponseService
-    Err(not_implemented_error("Cancel response not yet implemented"))
+    Path(response_id): Path<String>,
+    State(state): State<ResponseRouteState>,
+    Extension(api_key): Extension<AuthenticatedApiKey>,
+) -> Result<ResponseJson<ResponseObject>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    debug!(
+        "Cancel response {} from workspace {}",
+        response_id, api_key.workspace.id.0
+    );
+
+    state
+        .response_service
+        .cancel_response(&response_id, api_key.workspace.id.0)
+        .await
+        .map(ResponseJson)
+        .map_err(|error| {
+            let status_code = map_response_error_to_status(&error);
+            (status_code, ResponseJson(error.into()))
+        })
 }
 
 /// List input items for a response
@@ -790,11 +844,12 @@ pub async fn cancel_response(
     path = "/v1/responses/{response_id}/input_items",
     tag = "Responses",
     params(
-        ("response_id" = String, Path, description = "Response ID")
+        ("response_id" = String, Path, description = "Response ID"),
+        ("include" = Option<String>, Query, description = "Comma-separated sub-resources to expand inline: output, tool_calls. Also accepted as repeated include[] parameters")
     ),
     responses(
         (status = 200, description = "List of input items", body = ResponseInputItemList),
-        (status = 400, description = "Invalid response ID", body = ErrorResponse),
+        (status = 400, description = "Invalid response ID or include value", body = ErrorResponse),
         (status = 401, description = "Invalid or missing API key", body = ErrorResponse),
         (status = 404, description = "Response not found", body = ErrorResponse),
         (status = 500, description = "Server error", body = ErrorResponse)
@@ -806,6 +861,7 @@ pub async fn cancel_response(
 pub async fn list_input_items(
     Path(response_id): Path<String>,
     Query(params): Query<ListInputItemsQuery>,
+    RawQuery(raw_query): RawQuery,
     State(state): State<ResponseRouteState>,
     Extension(auth): Extension<AuthenticatedApiKey>,
 ) -> Result<ResponseJson<ResponseInputItemList>, (StatusCode, ResponseJson<ErrorResponse>)> {
@@ -815,6 +871,16 @@ pub async fn list_input_items(
         response_id, auth.workspace.id.0
     );
 
+    let include = parse_input_items_include(raw_query.as_deref()).map_err(|message| {
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse::new(
+                message,
+                "invalid_request_error".to_string(),
+            )),
+        )
+    })?;
+
     // Parse response ID (format: "resp_{uuid}")
     let response_uuid = response_id
         .strip_prefix("resp_")
@@ -876,10 +942,33 @@ pub async fn list_input_items(
             )
         })?;
 
+    // Expansions come from the same item fetch, so they cost no extra queries
+    let mut output = include.output.then(Vec::new);
+    let mut tool_calls = include.tool_calls.then(Vec::new);
+
     // Filter to only user input items and convert to API format
     let mut input_items: Vec<crate::models::ResponseInputItem> = Vec::new();
 
     for item in items {
+        match &item {
+            ResponseOutputItem::Message { role, .. } if role == "assistant" => {
+                if let Some(output) = output.as_mut() {
+                    output.push(item);
+                }
+                continue;
+            }
+            ResponseOutputItem::ToolCall { .. }
+            | ResponseOutputItem::FunctionCall { .. }
+            | ResponseOutputItem::McpCall { .. }
+            | ResponseOutputItem::WebSearchCall { .. } => {
+                if let Some(tool_calls) = tool_calls.as_mut() {
+                    tool_calls.push(item);
+                }
+                continue;
+            }
+            _ => {}
+        }
+
         if let ResponseOutputItem::Message {
             role,
             content,
@@ -926,9 +1015,45 @@ pub async fn list_input_items(
         first_id,
         last_id,
         has_more,
+        output,
+        tool_calls,
     }))
 }
 
+/// Sub-resources `list_input_items` can expand inline via `include`
+#[derive(Debug, Default, PartialEq, Eq)]
+struct InputItemsInclude {
+    output: bool,
+    tool_calls: bool,
+}
+
+/// Parse `include` from the raw query string, accepting both comma-separated
+/// (`include=output,tool_calls`) and repeated (`include[]=output`) forms.
+fn parse_input_items_include(raw_query: Option<&str>) -> Result<InputItemsInclude, String> {
+    let mut include = InputItemsInclude::default();
+    let Some(raw_query) = raw_query else {
+        return Ok(include);
+    };
+
+    for (key, value) in url::form_urlencoded::parse(raw_query.as_bytes()) {
+        if key != "include" && key != "include[]" {
+            continue;
+        }
+        for name in value.split(',').map(str::trim).filter(|v| !v.is_empty()) {
+            match name {
+                "output" => include.output = true,
+                "tool_calls" => include.tool_calls = true,
+                _ => {
+                    return Err(format!(
+                        "Invalid include value '{name}'. Allowed values: output, tool_calls"
+                    ))
+                }
+            }
+        }
+    }
+    Ok(include)
+}
+
 // Query parameter structs
 #[derive(Debug, Deserialize)]
 pub struct GetResponseQuery {
@@ -943,7 +1068,6 @@ pub struct GetResponseQuery {
 #[derive(Debug, Deserialize)]
 pub struct ListInputItemsQuery {
     pub after: Option<String>,
-    pub include: Option<Vec<String>>,
     pub limit: Option<i64>,
     pub order: Option<String>, // "asc" or "desc"
 }
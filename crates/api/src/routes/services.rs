@@ -16,12 +16,12 @@ use tracing::error;
 #[derive(Clone)]
 pub struct ServicesRouteState {
     pub service_repository: Arc<ServiceRepository>,
+    pub page_size_limits: common::PageSizeLimits,
 }
 
 #[derive(Debug, serde::Deserialize, utoipa::IntoParams)]
 pub struct ListServicesQueryParams {
-    #[serde(default = "common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
 }
@@ -69,11 +69,11 @@ pub async fn list_services(
     State(state): State<ServicesRouteState>,
     Query(params): Query<ListServicesQueryParams>,
 ) -> Result<ResponseJson<ServiceListResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    validate_limit_offset(params.limit, params.offset)?;
+    let limit = validate_limit_offset(state.page_size_limits, params.limit, params.offset)?;
     let (services, total) = state
         .service_repository
         // Public endpoint only exposes active services; include_inactive is always false here.
-        .list(false, params.limit, params.offset)
+        .list(false, limit, params.offset)
         .await
         .map_err(|e| {
             error!("Failed to list services: {:?}", e);
@@ -91,7 +91,7 @@ pub async fn list_services(
         .collect::<Result<Vec<_>, _>>()?;
     Ok(ResponseJson(ServiceListResponse {
         services: services_api,
-        limit: params.limit,
+        limit,
         offset: params.offset,
         total,
     }))
@@ -15,15 +15,25 @@ pub fn openai_compat_routes() -> Router {
         .route("/images/variations", post(openai_endpoint_not_implemented))
         .route("/audio/translations", post(openai_endpoint_not_implemented))
         .route("/moderations", post(openai_endpoint_not_implemented))
-        .route("/batches", any(openai_endpoint_not_implemented))
-        .route("/batches/{*path}", any(openai_endpoint_not_implemented))
+        // POST /batches and GET /batches/{batch_id} are served by `routes::batches`.
+        .route("/batches", get(openai_endpoint_not_implemented))
+        .route(
+            "/batches/{batch_id}/cancel",
+            post(openai_endpoint_not_implemented),
+        )
         .route("/threads", any(openai_endpoint_not_implemented))
         .route("/threads/{*path}", any(openai_endpoint_not_implemented))
         .route("/assistants", any(openai_endpoint_not_implemented))
         .route("/assistants/{*path}", any(openai_endpoint_not_implemented))
         .route("/responses", get(openai_endpoint_not_implemented))
         .route("/models", post(openai_endpoint_not_implemented))
-        .route("/models/{*model_id}", any(openai_endpoint_not_implemented))
+        // Split rather than `/models/{*model_id}` so that concrete routes such
+        // as `/models/{model_name}/capabilities` can be registered alongside.
+        .route("/models/{model_id}", any(openai_endpoint_not_implemented))
+        .route(
+            "/models/{model_id}/{*path}",
+            any(openai_endpoint_not_implemented),
+        )
 }
 
 /// Global router fallback for requests that match no route.
@@ -99,8 +109,6 @@ mod tests {
             (Method::POST, "/v1/audio/translations"),
             (Method::POST, "/v1/moderations"),
             (Method::GET, "/v1/batches"),
-            (Method::POST, "/v1/batches"),
-            (Method::GET, "/v1/batches/batch_123"),
             (Method::POST, "/v1/batches/batch_123/cancel"),
             (Method::GET, "/v1/threads"),
             (Method::POST, "/v1/threads"),
@@ -113,6 +121,7 @@ mod tests {
             (Method::POST, "/v1/models"),
             (Method::GET, "/v1/models/openai/gpt-oss-120b"),
             (Method::DELETE, "/v1/models/openai/gpt-oss-120b"),
+            (Method::GET, "/v1/models/gpt-oss-120b"),
         ];
 
         for (method, path) in cases {
@@ -1,6 +1,6 @@
 use crate::{
     middleware::AuthenticatedUser,
-    models::ErrorResponse,
+    models::{ErrorResponse, ListMetadata},
     routes::{api::AppState, common::format_amount},
 };
 use axum::{
@@ -21,7 +21,7 @@ use uuid::Uuid;
 
 pub(crate) type UsageError = (StatusCode, ResponseJson<ErrorResponse>);
 
-async fn check_org_membership(
+pub(crate) async fn check_org_membership(
     app_state: &AppState,
     user: AuthenticatedUser,
     org_id: &str,
@@ -241,35 +241,45 @@ pub struct UsageHistoryEntryResponse {
 /// Usage history response
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UsageHistoryResponse {
+    #[serde(flatten)]
+    pub list: ListMetadata,
     pub data: Vec<UsageHistoryEntryResponse>,
     pub total: usize,
     pub limit: i64,
     pub offset: i64,
 }
 
+impl UsageHistoryResponse {
+    fn new(data: Vec<UsageHistoryEntryResponse>, total: usize, limit: i64, offset: i64) -> Self {
+        Self {
+            list: ListMetadata::for_offset_page(&data, offset, total as i64, |e| e.id.clone()),
+            data,
+            total,
+            limit,
+            offset,
+        }
+    }
+}
+
 /// Query parameters for usage history
 #[derive(Debug, Deserialize)]
 pub struct UsageHistoryQuery {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     pub start_date: Option<String>,
     pub end_date: Option<String>,
     pub start_time: Option<String>,
     pub end_time: Option<String>,
+    pub created_after: Option<String>,
+    pub created_before: Option<String>,
     pub workspace_id: Option<Uuid>,
     pub api_key_id: Option<Uuid>,
 }
 
 impl UsageHistoryQuery {
     const fn has_filters(&self) -> bool {
-        self.start_date.is_some()
-            || self.end_date.is_some()
-            || self.start_time.is_some()
-            || self.end_time.is_some()
-            || self.workspace_id.is_some()
-            || self.api_key_id.is_some()
+        self.has_time_filters() || self.workspace_id.is_some() || self.api_key_id.is_some()
     }
 
     const fn has_time_filters(&self) -> bool {
@@ -277,6 +287,8 @@ impl UsageHistoryQuery {
             || self.end_date.is_some()
             || self.start_time.is_some()
             || self.end_time.is_some()
+            || self.created_after.is_some()
+            || self.created_before.is_some()
     }
 }
 
@@ -300,6 +312,8 @@ pub struct ServiceUsageEntryResponse {
 /// Service usage history response
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ServiceUsageHistoryResponse {
+    #[serde(flatten)]
+    pub list: ListMetadata,
     pub data: Vec<ServiceUsageEntryResponse>,
     pub total: usize,
     pub limit: i64,
@@ -309,8 +323,7 @@ pub struct ServiceUsageHistoryResponse {
 /// Query parameters for service usage history
 #[derive(Debug, Deserialize)]
 pub struct ServiceUsageHistoryQuery {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     /// Filter by platform service name (e.g. \"web_search\").
@@ -372,11 +385,14 @@ pub async fn get_organization_balance(
         ("end_date" = Option<String>, Query, description = "Inclusive UTC end date in YYYY-MM-DD or RFC3339 format."),
         ("start_time" = Option<String>, Query, description = "Inclusive RFC3339 start timestamp. Takes precedence over start_date."),
         ("end_time" = Option<String>, Query, description = "Inclusive RFC3339 end timestamp. Takes precedence over end_date."),
+        ("created_after" = Option<String>, Query, description = "Only rows created at or after this RFC3339 timestamp. Cannot be combined with start_time or start_date."),
+        ("created_before" = Option<String>, Query, description = "Only rows created at or before this RFC3339 timestamp. Cannot be combined with end_time or end_date."),
         ("workspace_id" = Option<Uuid>, Query, description = "Filter by workspace ID."),
         ("api_key_id" = Option<Uuid>, Query, description = "Filter by API key ID.")
     ),
     responses(
         (status = 200, description = "Usage history", body = UsageHistoryResponse),
+        (status = 400, description = "Invalid query", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
@@ -391,26 +407,30 @@ pub async fn get_organization_usage_history(
     Path(org_id): Path<String>,
     Query(query): Query<UsageHistoryQuery>,
 ) -> Result<ResponseJson<UsageHistoryResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        query.limit,
+        query.offset,
+    )?;
+
     tracing::debug!(
         "Get usage history for org {} by user {}, limit: {}, offset: {}",
         org_id,
         user.0.id,
-        query.limit,
+        limit,
         query.offset
     );
 
-    // Validate pagination parameters
-    crate::routes::common::validate_limit_offset(query.limit, query.offset)?;
-
     let organization_id = check_org_membership(&app_state, user, &org_id).await?;
 
     if query.has_filters() {
-        return get_filtered_organization_usage_history(&app_state, organization_id, &query).await;
+        return get_filtered_organization_usage_history(&app_state, organization_id, &query, limit)
+            .await;
     }
 
     let (history, total) = app_state
         .usage_service
-        .get_usage_history(organization_id, Some(query.limit), Some(query.offset))
+        .get_usage_history(organization_id, Some(limit), Some(query.offset))
         .await
         .map_err(|_| {
             tracing::error!("Failed to get usage history");
@@ -446,20 +466,21 @@ pub async fn get_organization_usage_history(
         })
         .collect();
 
-    Ok(ResponseJson(UsageHistoryResponse {
+    Ok(ResponseJson(UsageHistoryResponse::new(
         data,
-        total: total as usize,
-        limit: query.limit,
-        offset: query.offset,
-    }))
+        total as usize,
+        limit,
+        query.offset,
+    )))
 }
 
 async fn get_filtered_organization_usage_history(
     app_state: &AppState,
     organization_id: Uuid,
     query: &UsageHistoryQuery,
+    limit: i64,
 ) -> Result<ResponseJson<UsageHistoryResponse>, UsageError> {
-    let report_query = usage_history_report_query(organization_id, query)?;
+    let report_query = usage_history_report_query(organization_id, query, limit)?;
     let (history, total) = app_state
         .usage_service
         .list_inference_usage_history(report_query)
@@ -473,18 +494,20 @@ async fn get_filtered_organization_usage_history(
     let total = usize::try_from(total)
         .map_err(|_| internal_usage_history_error("Invalid usage history total"))?;
 
-    Ok(ResponseJson(UsageHistoryResponse {
+    Ok(ResponseJson(UsageHistoryResponse::new(
         data,
         total,
-        limit: query.limit,
-        offset: query.offset,
-    }))
+        limit,
+        query.offset,
+    )))
 }
 
-fn usage_history_report_query(
+pub(crate) fn usage_history_report_query(
     organization_id: Uuid,
     query: &UsageHistoryQuery,
+    limit: i64,
 ) -> Result<InferenceUsageHistoryQuery, UsageError> {
+    validate_created_range(query)?;
     if !query.has_time_filters() {
         return Ok(InferenceUsageHistoryQuery {
             organization_id,
@@ -492,7 +515,7 @@ fn usage_history_report_query(
             end_time: None,
             workspace_id: query.workspace_id,
             api_key_id: query.api_key_id,
-            limit: query.limit,
+            limit,
             offset: query.offset,
         });
     }
@@ -507,7 +530,7 @@ fn usage_history_report_query(
         inference_type: None,
         service_name: None,
         limit: Some(
-            u16::try_from(query.limit)
+            u16::try_from(limit)
                 .map_err(|_| usage_history_query_bad_request("Limit cannot exceed 1000"))?,
         ),
         cursor: None,
@@ -521,12 +544,20 @@ fn usage_history_report_query(
         end_time: parsed.end_time,
         workspace_id: parsed.workspace_id,
         api_key_id: parsed.api_key_id,
-        limit: query.limit,
+        limit,
         offset: query.offset,
     })
 }
 
 fn usage_history_start_time(query: &UsageHistoryQuery) -> Result<Option<String>, UsageError> {
+    if let Some(created_after) = query.created_after.as_ref() {
+        if query.start_time.is_some() || query.start_date.is_some() {
+            return Err(usage_history_query_bad_request(
+                "created_after cannot be combined with start_time or start_date",
+            ));
+        }
+        return Ok(Some(created_after.clone()));
+    }
     match query.start_time.as_ref() {
         Some(value) => Ok(Some(value.clone())),
         None => normalize_usage_history_date(query.start_date.as_deref(), DateBoundary::Start),
@@ -534,12 +565,44 @@ fn usage_history_start_time(query: &UsageHistoryQuery) -> Result<Option<String>,
 }
 
 fn usage_history_end_time(query: &UsageHistoryQuery) -> Result<Option<String>, UsageError> {
+    if let Some(created_before) = query.created_before.as_ref() {
+        if query.end_time.is_some() || query.end_date.is_some() {
+            return Err(usage_history_query_bad_request(
+                "created_before cannot be combined with end_time or end_date",
+            ));
+        }
+        return Ok(Some(created_before.clone()));
+    }
     match query.end_time.as_ref() {
         Some(value) => Ok(Some(value.clone())),
         None => normalize_usage_history_date(query.end_date.as_deref(), DateBoundary::End),
     }
 }
 
+/// Rejects a `created_after`/`created_before` pair that cannot match any row, with an
+/// error naming the parameters the client actually sent.
+fn validate_created_range(query: &UsageHistoryQuery) -> Result<(), UsageError> {
+    let (Some(after), Some(before)) = (
+        query.created_after.as_deref(),
+        query.created_before.as_deref(),
+    ) else {
+        return Ok(());
+    };
+    let parse = |value: &str| {
+        DateTime::parse_from_rfc3339(value).map_err(|_| {
+            usage_history_query_bad_request(
+                "created_after and created_before must be RFC3339 timestamps",
+            )
+        })
+    };
+    if parse(after)? > parse(before)? {
+        return Err(usage_history_query_bad_request(
+            "created_after must be less than or equal to created_before",
+        ));
+    }
+    Ok(())
+}
+
 #[derive(Clone, Copy)]
 enum DateBoundary {
     Start,
@@ -601,6 +664,33 @@ fn usage_report_row_response(
     })
 }
 
+fn api_key_usage_history_error(e: services::usage::UsageError) -> UsageError {
+    tracing::error!("Failed to get usage history");
+    match e {
+        services::usage::UsageError::Unauthorized(_) => (
+            StatusCode::FORBIDDEN,
+            ResponseJson(ErrorResponse::new(
+                "Access denied to this workspace".to_string(),
+                "forbidden".to_string(),
+            )),
+        ),
+        services::usage::UsageError::NotFound(_) => (
+            StatusCode::NOT_FOUND,
+            ResponseJson(ErrorResponse::new(
+                "API key not found in this workspace".to_string(),
+                "not_found".to_string(),
+            )),
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ResponseJson(ErrorResponse::new(
+                "Failed to retrieve usage history".to_string(),
+                "internal_server_error".to_string(),
+            )),
+        ),
+    }
+}
+
 fn checked_usage_history_i32(value: i64, field: &str) -> Result<i32, UsageError> {
     i32::try_from(value)
         .map_err(|_| internal_usage_history_error(&format!("Invalid usage history {field}")))
@@ -668,25 +758,28 @@ pub async fn get_service_usage_history(
     Path(org_id): Path<String>,
     Query(query): Query<ServiceUsageHistoryQuery>,
 ) -> Result<ResponseJson<ServiceUsageHistoryResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        query.limit,
+        query.offset,
+    )?;
+
     tracing::debug!(
         "Get service usage history for org {} by user {}, service: {:?}, limit: {}, offset: {}",
         org_id,
         user.0.id,
         query.service_name,
-        query.limit,
+        limit,
         query.offset
     );
 
-    // Validate pagination parameters
-    crate::routes::common::validate_limit_offset(query.limit, query.offset)?;
-
     let organization_id = check_org_membership(&app_state, user, &org_id).await?;
 
     let service_name = query.service_name.as_deref();
 
     let (history, total) = app_state
         .service_usage_service
-        .get_usage_history(organization_id, service_name, query.limit, query.offset)
+        .get_usage_history(organization_id, service_name, limit, query.offset)
         .await
         .map_err(|_| {
             tracing::error!("Failed to get service usage history");
@@ -699,7 +792,7 @@ pub async fn get_service_usage_history(
             )
         })?;
 
-    let data = history
+    let data: Vec<ServiceUsageEntryResponse> = history
         .into_iter()
         .map(|entry| ServiceUsageEntryResponse {
             id: entry.id.to_string(),
@@ -716,9 +809,10 @@ pub async fn get_service_usage_history(
         .collect();
 
     Ok(ResponseJson(ServiceUsageHistoryResponse {
+        list: ListMetadata::for_offset_page(&data, query.offset, total, |e| e.id.clone()),
         data,
         total: total as usize,
-        limit: query.limit,
+        limit,
         offset: query.offset,
     }))
 }
@@ -734,10 +828,17 @@ pub async fn get_service_usage_history(
         ("workspace_id" = String, Path, description = "Workspace ID"),
         ("api_key_id" = String, Path, description = "API Key ID"),
         ("limit" = Option<i64>, Query, description = "Number of records to return (default: 100)"),
-        ("offset" = Option<i64>, Query, description = "Offset for pagination (default: 0)")
+        ("offset" = Option<i64>, Query, description = "Offset for pagination (default: 0)"),
+        ("start_date" = Option<String>, Query, description = "Inclusive UTC start date in YYYY-MM-DD or RFC3339 format."),
+        ("end_date" = Option<String>, Query, description = "Inclusive UTC end date in YYYY-MM-DD or RFC3339 format."),
+        ("start_time" = Option<String>, Query, description = "Inclusive RFC3339 start timestamp. Takes precedence over start_date."),
+        ("end_time" = Option<String>, Query, description = "Inclusive RFC3339 end timestamp. Takes precedence over end_date."),
+        ("created_after" = Option<String>, Query, description = "Only rows created at or after this RFC3339 timestamp. Cannot be combined with start_time or start_date."),
+        ("created_before" = Option<String>, Query, description = "Only rows created at or before this RFC3339 timestamp. Cannot be combined with end_time or end_date.")
     ),
     responses(
         (status = 200, description = "Usage history", body = UsageHistoryResponse),
+        (status = 400, description = "Invalid query", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden", body = ErrorResponse),
         (status = 404, description = "Not found", body = ErrorResponse),
@@ -753,18 +854,21 @@ pub async fn get_api_key_usage_history(
     Path((workspace_id, api_key_id)): Path<(String, String)>,
     Query(query): Query<UsageHistoryQuery>,
 ) -> Result<ResponseJson<UsageHistoryResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        query.limit,
+        query.offset,
+    )?;
+
     tracing::debug!(
         "Get usage history for API key {} in workspace {} by user {}, limit: {}, offset: {}",
         api_key_id,
         workspace_id,
         user.0.id,
-        query.limit,
+        limit,
         query.offset
     );
 
-    // Validate pagination parameters
-    crate::routes::common::validate_limit_offset(query.limit, query.offset)?;
-
     let workspace_uuid = Uuid::parse_str(&workspace_id).map_err(|_| {
         (
             StatusCode::BAD_REQUEST,
@@ -785,6 +889,21 @@ pub async fn get_api_key_usage_history(
         )
     })?;
 
+    if query.has_time_filters() {
+        let organization_id = app_state
+            .usage_service
+            .authorize_api_key_usage_access(workspace_uuid, api_key_uuid, user.0.id)
+            .await
+            .map_err(api_key_usage_history_error)?;
+        let query = UsageHistoryQuery {
+            workspace_id: Some(workspace_uuid),
+            api_key_id: Some(api_key_uuid),
+            ..query
+        };
+        return get_filtered_organization_usage_history(&app_state, organization_id, &query, limit)
+            .await;
+    }
+
     // Get usage history with permission checking handled by the service
     let (history, total) = app_state
         .usage_service
@@ -792,36 +911,11 @@ pub async fn get_api_key_usage_history(
             workspace_uuid,
             api_key_uuid,
             user.0.id,
-            Some(query.limit),
+            Some(limit),
             Some(query.offset),
         )
         .await
-        .map_err(|e| {
-            tracing::error!("Failed to get usage history");
-            match e {
-                services::usage::UsageError::Unauthorized(_) => (
-                    StatusCode::FORBIDDEN,
-                    ResponseJson(ErrorResponse::new(
-                        "Access denied to this workspace".to_string(),
-                        "forbidden".to_string(),
-                    )),
-                ),
-                services::usage::UsageError::NotFound(_) => (
-                    StatusCode::NOT_FOUND,
-                    ResponseJson(ErrorResponse::new(
-                        "API key not found in this workspace".to_string(),
-                        "not_found".to_string(),
-                    )),
-                ),
-                _ => (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    ResponseJson(ErrorResponse::new(
-                        "Failed to retrieve usage history".to_string(),
-                        "internal_server_error".to_string(),
-                    )),
-                ),
-            }
-        })?;
+        .map_err(api_key_usage_history_error)?;
 
     let data = history
         .into_iter()
@@ -846,12 +940,12 @@ pub async fn get_api_key_usage_history(
         })
         .collect();
 
-    Ok(ResponseJson(UsageHistoryResponse {
+    Ok(ResponseJson(UsageHistoryResponse::new(
         data,
-        total: total as usize,
-        limit: query.limit,
-        offset: query.offset,
-    }))
+        total as usize,
+        limit,
+        query.offset,
+    )))
 }
 
 // ============================================
@@ -1187,7 +1281,7 @@ pub struct TimeSeriesQuery {
     pub granularity: Option<String>,
 }
 
-const MAX_DATE_RANGE_DAYS: i64 = 366;
+pub(crate) const MAX_DATE_RANGE_DAYS: i64 = 366;
 
 fn validate_date_range(
     start: chrono::DateTime<Utc>,
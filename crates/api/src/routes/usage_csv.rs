@@ -0,0 +1,420 @@
+//! CSV exports of inference usage history.
+//!
+//! Rows are read page by page with the reporting keyset cursor and streamed
+//! to the client as they arrive, so large date ranges are never buffered in
+//! memory. The first page is fetched before the response starts so that
+//! query and database errors still surface as JSON error responses.
+//!
+//! An export covers at most [`MAX_DATE_RANGE_DAYS`] days (the most recent
+//! ones when no start is given) and stops after [`MAX_CSV_ROWS`] rows.
+
+use crate::{
+    middleware::AuthenticatedUser,
+    models::ErrorResponse,
+    routes::{
+        api::AppState,
+        usage::{
+            check_org_membership, usage_history_report_query, UsageError, UsageHistoryQuery,
+            MAX_DATE_RANGE_DAYS,
+        },
+    },
+};
+use axum::{
+    body::{Body, Bytes},
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json as ResponseJson, Response},
+    Extension,
+};
+use chrono::{Duration, Utc};
+use futures::stream::{self, StreamExt};
+use serde::Deserialize;
+use services::usage::{
+    InferenceUsageReportCursor, InferenceUsageReportQuery, InferenceUsageReportRow,
+    UsageServiceTrait,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Rows fetched per database round trip.
+const CSV_PAGE_SIZE: u16 = 1000;
+
+/// Rows written before an export stops.
+const MAX_CSV_ROWS: usize = 100_000;
+
+const CSV_HEADER: &str =
+    "timestamp,model,input_tokens,output_tokens,cost_usd,inference_type,inference_id\n";
+
+/// Query parameters for CSV usage exports
+#[derive(Debug, Default, Deserialize)]
+pub struct UsageCsvQuery {
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub created_after: Option<String>,
+    pub created_before: Option<String>,
+    pub workspace_id: Option<Uuid>,
+    pub api_key_id: Option<Uuid>,
+}
+
+impl UsageCsvQuery {
+    fn into_report_query(
+        self,
+        organization_id: Uuid,
+    ) -> Result<InferenceUsageReportQuery, UsageError> {
+        let history_query = UsageHistoryQuery {
+            limit: None,
+            offset: 0,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            created_after: self.created_after,
+            created_before: self.created_before,
+            workspace_id: self.workspace_id,
+            api_key_id: self.api_key_id,
+        };
+        let parsed =
+            usage_history_report_query(organization_id, &history_query, CSV_PAGE_SIZE.into())?;
+        // Explicit ranges are already capped by the report query; an export
+        // without time filters covers the most recent window instead of all time.
+        let end_time = parsed.end_time.unwrap_or_else(Utc::now);
+        let start_time = parsed
+            .start_time
+            .unwrap_or_else(|| end_time - Duration::days(MAX_DATE_RANGE_DAYS));
+        Ok(InferenceUsageReportQuery {
+            organization_id,
+            start_time: Some(start_time),
+            end_time: Some(end_time),
+            workspace_id: parsed.workspace_id,
+            api_key_id: parsed.api_key_id,
+            model: None,
+            inference_type: None,
+            limit: CSV_PAGE_SIZE,
+            cursor: None,
+            deadline: None,
+        })
+    }
+}
+
+/// Export organization usage history as CSV
+///
+/// Streams the organization's inference usage, newest first, as CSV with the columns
+/// timestamp, model, input_tokens, output_tokens, cost_usd, inference_type and inference_id.
+/// The range may span at most 366 days and defaults to the last 366; at most 100000 rows
+/// are exported. Only available when usage reporting is enabled.
+#[utoipa::path(
+    get,
+    path = "/v1/organizations/{org_id}/usage/history.csv",
+    tag = "Usage",
+    params(
+        ("org_id" = String, Path, description = "Organization ID"),
+        ("start_date" = Option<String>, Query, description = "Inclusive UTC start date in YYYY-MM-DD or RFC3339 format."),
+        ("end_date" = Option<String>, Query, description = "Inclusive UTC end date in YYYY-MM-DD or RFC3339 format."),
+        ("start_time" = Option<String>, Query, description = "Inclusive RFC3339 start timestamp. Takes precedence over start_date."),
+        ("end_time" = Option<String>, Query, description = "Inclusive RFC3339 end timestamp. Takes precedence over end_date."),
+        ("created_after" = Option<String>, Query, description = "Only rows created at or after this RFC3339 timestamp. Cannot be combined with start_time or start_date."),
+        ("created_before" = Option<String>, Query, description = "Only rows created at or before this RFC3339 timestamp. Cannot be combined with end_time or end_date."),
+        ("workspace_id" = Option<Uuid>, Query, description = "Filter by workspace ID."),
+        ("api_key_id" = Option<Uuid>, Query, description = "Filter by API key ID.")
+    ),
+    responses(
+        (status = 200, description = "Usage history CSV", content_type = "text/csv", body = String),
+        (status = 400, description = "Invalid query", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 429, description = "Too many export requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn export_organization_usage_history_csv(
+    State(app_state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(org_id): Path<String>,
+    Query(query): Query<UsageCsvQuery>,
+) -> Result<Response, UsageError> {
+    tracing::debug!(
+        "Export usage history CSV for org {} by user {}",
+        org_id,
+        user.0.id
+    );
+
+    let organization_id = check_org_membership(&app_state, user, &org_id).await?;
+    let report_query = query.into_report_query(organization_id)?;
+
+    usage_csv_response(app_state.usage_service, report_query).await
+}
+
+/// Export API key usage history as CSV
+///
+/// Streams the API key's inference usage, newest first, in the same CSV format and with
+/// the same range and row limits as the organization export.
+#[utoipa::path(
+    get,
+    path = "/v1/workspaces/{workspace_id}/api-keys/{api_key_id}/usage/history.csv",
+    tag = "Usage",
+    params(
+        ("workspace_id" = String, Path, description = "Workspace ID"),
+        ("api_key_id" = String, Path, description = "API Key ID"),
+        ("start_date" = Option<String>, Query, description = "Inclusive UTC start date in YYYY-MM-DD or RFC3339 format."),
+        ("end_date" = Option<String>, Query, description = "Inclusive UTC end date in YYYY-MM-DD or RFC3339 format."),
+        ("start_time" = Option<String>, Query, description = "Inclusive RFC3339 start timestamp. Takes precedence over start_date."),
+        ("end_time" = Option<String>, Query, description = "Inclusive RFC3339 end timestamp. Takes precedence over end_date.")
+    ),
+    responses(
+        (status = 200, description = "Usage history CSV", content_type = "text/csv", body = String),
+        (status = 400, description = "Invalid query", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 429, description = "Too many export requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = [])
+    )
+)]
+pub async fn export_api_key_usage_history_csv(
+    State(app_state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path((workspace_id, api_key_id)): Path<(String, String)>,
+    Query(query): Query<UsageCsvQuery>,
+) -> Result<Response, UsageError> {
+    tracing::debug!(
+        "Export usage history CSV for API key {} in workspace {} by user {}",
+        api_key_id,
+        workspace_id,
+        user.0.id
+    );
+
+    let workspace_uuid = parse_id(&workspace_id, "Invalid workspace ID")?;
+    let api_key_uuid = parse_id(&api_key_id, "Invalid API key ID")?;
+
+    let organization_id = app_state
+        .usage_service
+        .authorize_api_key_usage_access(workspace_uuid, api_key_uuid, user.0.id)
+        .await
+        .map_err(|e| match e {
+            services::usage::UsageError::Unauthorized(_) => (
+                StatusCode::FORBIDDEN,
+                ResponseJson(ErrorResponse::new(
+                    "Access denied to this workspace".to_string(),
+                    "forbidden".to_string(),
+                )),
+            ),
+            services::usage::UsageError::NotFound(_) => (
+                StatusCode::NOT_FOUND,
+                ResponseJson(ErrorResponse::new(
+                    "API key not found in this workspace".to_string(),
+                    "not_found".to_string(),
+                )),
+            ),
+            _ => export_error(),
+        })?;
+
+    let report_query = UsageCsvQuery {
+        workspace_id: Some(workspace_uuid),
+        api_key_id: Some(api_key_uuid),
+        ..query
+    }
+    .into_report_query(organization_id)?;
+
+    usage_csv_response(app_state.usage_service, report_query).await
+}
+
+fn parse_id(raw: &str, message: &str) -> Result<Uuid, UsageError> {
+    Uuid::parse_str(raw).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse::new(
+                message.to_string(),
+                "invalid_id".to_string(),
+            )),
+        )
+    })
+}
+
+fn export_error() -> (StatusCode, ResponseJson<ErrorResponse>) {
+    tracing::error!("Failed to export usage history");
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        ResponseJson(ErrorResponse::new(
+            "Failed to export usage history".to_string(),
+            "internal_server_error".to_string(),
+        )),
+    )
+}
+
+async fn usage_csv_response(
+    usage_service: Arc<dyn UsageServiceTrait + Send + Sync>,
+    query: InferenceUsageReportQuery,
+) -> Result<Response, UsageError> {
+    let rows = usage_service
+        .list_inference_usage_report(query.clone())
+        .await
+        .map_err(|_| export_error())?;
+
+    let mut first_chunk = String::from(CSV_HEADER);
+    push_csv_rows(&mut first_chunk, &rows);
+    let next = next_page_query(&query, &rows, rows.len());
+
+    // A failure after the response has started can only be reported by
+    // aborting the body; the client sees a truncated transfer.
+    let remaining_pages = stream::try_unfold((next, rows.len()), move |(next, written)| {
+        let usage_service = usage_service.clone();
+        async move {
+            let Some(query) = next else {
+                return Ok(None);
+            };
+            let rows = usage_service
+                .list_inference_usage_report(query.clone())
+                .await
+                .map_err(|_| {
+                    tracing::error!("Failed to read usage history page during CSV export");
+                    std::io::Error::other("usage history export failed")
+                })?;
+            let rows = &rows[..rows.len().min(MAX_CSV_ROWS - written)];
+            let written = written + rows.len();
+            let mut chunk = String::new();
+            push_csv_rows(&mut chunk, rows);
+            let next = next_page_query(&query, rows, written);
+            Ok::<_, std::io::Error>(Some((Bytes::from(chunk), (next, written))))
+        }
+    });
+    let body = stream::once(async move { Ok(Bytes::from(first_chunk)) }).chain(remaining_pages);
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"usage-history.csv\"",
+            ),
+        ],
+        Body::from_stream(body),
+    )
+        .into_response())
+}
+
+/// Query for the page after `rows`, or `None` when `rows` was the last page
+/// or `written` rows already reach [`MAX_CSV_ROWS`].
+fn next_page_query(
+    query: &InferenceUsageReportQuery,
+    rows: &[InferenceUsageReportRow],
+    written: usize,
+) -> Option<InferenceUsageReportQuery> {
+    if rows.len() < usize::from(query.limit) || written >= MAX_CSV_ROWS {
+        return None;
+    }
+    let last = rows.last()?;
+    Some(InferenceUsageReportQuery {
+        cursor: Some(InferenceUsageReportCursor {
+            created_at: last.created_at,
+            id: last.id,
+        }),
+        ..query.clone()
+    })
+}
+
+fn push_csv_rows(out: &mut String, rows: &[InferenceUsageReportRow]) {
+    for row in rows {
+        let inference_id = row
+            .inference_id
+            .map(|id| id.to_string())
+            .unwrap_or_default();
+        let fields = [
+            row.created_at.to_rfc3339(),
+            csv_field(&row.model),
+            row.input_tokens.to_string(),
+            row.output_tokens.to_string(),
+            nano_usd_to_decimal(row.total_cost_nano_usd),
+            csv_field(&row.inference_type),
+            inference_id,
+        ];
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+}
+
+/// Quote a field when it contains a delimiter, quote or line break (RFC 4180).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render nano-dollars as an exact decimal dollar amount, e.g. `0.000001500`.
+fn nano_usd_to_decimal(nano: i64) -> String {
+    let sign = if nano < 0 { "-" } else { "" };
+    let abs = nano.unsigned_abs();
+    format!("{sign}{}.{:09}", abs / 1_000_000_000, abs % 1_000_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn row(model: &str, created_secs: i64) -> InferenceUsageReportRow {
+        InferenceUsageReportRow {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::nil(),
+            workspace_id: Uuid::nil(),
+            api_key_id: Uuid::nil(),
+            created_at: Utc.timestamp_opt(created_secs, 0).unwrap(),
+            model: model.to_string(),
+            inference_type: "chat_completion".to_string(),
+            input_tokens: 12,
+            output_tokens: 34,
+            cache_read_tokens: 0,
+            total_tokens: 46,
+            input_cost_nano_usd: 0,
+            output_cost_nano_usd: 0,
+            cache_read_cost_nano_usd: None,
+            total_cost_nano_usd: 1_500,
+            response_id: None,
+            provider_request_id: None,
+            inference_id: None,
+            stop_reason: None,
+            image_count: None,
+        }
+    }
+
+    #[test]
+    fn csv_rows_escape_fields_and_format_cost() {
+        let mut out = String::new();
+        push_csv_rows(&mut out, &[row("org/model,\"v2\"", 0)]);
+        assert_eq!(
+            out,
+            "1970-01-01T00:00:00+00:00,\"org/model,\"\"v2\"\"\",12,34,0.000001500,chat_completion,\n"
+        );
+    }
+
+    #[test]
+    fn nano_usd_renders_exact_decimal() {
+        assert_eq!(nano_usd_to_decimal(0), "0.000000000");
+        assert_eq!(nano_usd_to_decimal(12_345_000_000), "12.345000000");
+        assert_eq!(nano_usd_to_decimal(-5), "-0.000000005");
+    }
+
+    #[test]
+    fn next_page_continues_from_last_row_only_when_page_is_full() {
+        let mut query = InferenceUsageReportQuery::for_organization(Uuid::nil());
+        query.limit = 2;
+        let rows = [row("a", 20), row("b", 10)];
+
+        let next = next_page_query(&query, &rows, 2).expect("full page has a successor");
+        let cursor = next.cursor.unwrap();
+        assert_eq!(cursor.id, rows[1].id);
+        assert_eq!(cursor.created_at, rows[1].created_at);
+
+        assert!(next_page_query(&query, &rows[..1], 1).is_none());
+        assert!(next_page_query(&query, &rows, MAX_CSV_ROWS).is_none());
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
     },
     middleware::AuthenticatedUser,
     models::ErrorResponse,
-    routes::api::AppState,
+    routes::{api::AppState, common::map_organization_error},
 };
 use axum::{
     extract::{Extension, Json, Path, State},
@@ -117,7 +117,7 @@ pub async fn get_current_user(
     // list_organizations_for_user + N × get_user_role).
     let organizations = match app_state
         .organization_service
-        .list_organizations_with_roles_for_user(user_id.clone(), 100, 0, None, None)
+        .list_organizations_with_roles_for_user(user_id.clone(), 100, 0, None, None, None)
         .await
     {
         Ok(orgs) => orgs
@@ -522,7 +522,7 @@ pub async fn list_user_invitations(
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden - invitation belongs to another user", body = ErrorResponse),
         (status = 404, description = "Invitation not found", body = ErrorResponse),
-        (status = 409, description = "User is already a member", body = ErrorResponse),
+        (status = 409, description = "User is already a member, or the organization has reached its seat limit (`seat_limit_exceeded`)", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     security(
@@ -576,6 +576,7 @@ pub async fn accept_invitation(
                 "conflict".to_string(),
             )),
         )),
+        Err(e @ OrganizationError::SeatLimitExceeded) => Err(map_organization_error(e)),
         Err(_) => {
             error!("Failed to accept invitation");
             Err((
@@ -733,7 +734,7 @@ pub async fn get_invitation_by_token(
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden - invitation belongs to another user", body = ErrorResponse),
         (status = 404, description = "Invitation not found", body = ErrorResponse),
-        (status = 409, description = "User is already a member", body = ErrorResponse),
+        (status = 409, description = "User is already a member, or the organization has reached its seat limit (`seat_limit_exceeded`)", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     security(
@@ -783,6 +784,7 @@ pub async fn accept_invitation_by_token(
                 "conflict".to_string(),
             )),
         )),
+        Err(e @ OrganizationError::SeatLimitExceeded) => Err(map_organization_error(e)),
         Err(_) => {
             error!("Failed to accept invitation by token");
             Err((
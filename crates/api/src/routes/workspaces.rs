@@ -2,8 +2,8 @@ use crate::{
     conversions::authenticated_user_to_user_id,
     middleware::{auth::AuthenticatedApiKey, AuthenticatedUser},
     models::{
-        ApiKeyResponse, CreateApiKeyRequest, ErrorResponse, ListApiKeysResponse,
-        UpdateApiKeyRequest, UpdateApiKeySpendLimitRequest,
+        ApiKeyResponse, CreateApiKeyRequest, DecimalPrice, DecimalPriceRequest, ErrorResponse,
+        ListApiKeysResponse, ListMetadata, UpdateApiKeyRequest, UpdateApiKeySpendLimitRequest,
     },
     routes::api::AppState,
 };
@@ -13,7 +13,7 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use services::organization::OrganizationId;
-use tracing::{debug, error};
+use tracing::{debug, error, info};
 use utoipa::ToSchema;
 use uuid::Uuid;
 // ============================================
@@ -48,12 +48,73 @@ impl CreateWorkspaceRequest {
     }
 }
 
+/// Completion defaults applied to chat completion requests made with this
+/// workspace's API keys. A default is only used when the client omits the field.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceDefaultParams {
+    /// Model used when the request does not name one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Sampling temperature (0-2)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Maximum tokens to generate (at least 1)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<i64>,
+}
+
+impl WorkspaceDefaultParams {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(model) = &self.model {
+            crate::routes::common::validate_non_empty_field(model, "default model")?;
+            crate::routes::common::validate_max_length(
+                model,
+                "default model",
+                crate::consts::MAX_NAME_LENGTH,
+            )?;
+        }
+        if let Some(temperature) = self.temperature {
+            if !(0.0..=2.0).contains(&temperature) {
+                return Err("default temperature must be between 0 and 2".to_string());
+            }
+        }
+        if let Some(max_tokens) = self.max_tokens {
+            if max_tokens < 1 {
+                return Err("default max_tokens must be at least 1".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<services::workspace::WorkspaceDefaultParams> for WorkspaceDefaultParams {
+    fn from(params: services::workspace::WorkspaceDefaultParams) -> Self {
+        Self {
+            model: params.model,
+            temperature: params.temperature,
+            max_tokens: params.max_tokens,
+        }
+    }
+}
+
+impl From<WorkspaceDefaultParams> for services::workspace::WorkspaceDefaultParams {
+    fn from(params: WorkspaceDefaultParams) -> Self {
+        Self {
+            model: params.model,
+            temperature: params.temperature,
+            max_tokens: params.max_tokens,
+        }
+    }
+}
+
 /// Request to update a workspace
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateWorkspaceRequest {
     pub name: Option<String>,
     pub description: Option<String>,
     pub settings: Option<serde_json::Value>,
+    /// Replaces the workspace's completion defaults. Send `{}` to clear them.
+    pub default_params: Option<WorkspaceDefaultParams>,
 }
 
 impl UpdateWorkspaceRequest {
@@ -86,6 +147,10 @@ impl UpdateWorkspaceRequest {
             }
         }
 
+        if let Some(default_params) = &self.default_params {
+            default_params.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -102,11 +167,58 @@ pub struct WorkspaceResponse {
     pub updated_at: chrono::DateTime<chrono::Utc>,
     pub is_active: bool,
     pub settings: Option<serde_json::Value>,
+    pub default_params: Option<WorkspaceDefaultParams>,
+    /// Monthly spend budget (nano-dollars, scale 9); omitted when unlimited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monthly_budget: Option<DecimalPrice>,
+}
+
+/// Request to set or clear a workspace's monthly budget
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateWorkspaceMonthlyBudgetRequest {
+    /// New budget in nano-dollars; null removes the budget
+    pub monthly_budget: Option<DecimalPriceRequest>,
+}
+
+impl UpdateWorkspaceMonthlyBudgetRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(budget) = &self.monthly_budget {
+            budget.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Request to move a workspace to another organization
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TransferWorkspaceRequest {
+    /// Organization that will own the workspace and be billed for its usage
+    pub organization_id: Uuid,
+}
+
+/// Result of revoking every API key in a workspace
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RevokeAllApiKeysResponse {
+    /// Number of keys that were active and are now revoked
+    pub revoked_count: u64,
+}
+
+fn monthly_budget_to_price(monthly_budget: Option<i64>) -> Option<DecimalPrice> {
+    monthly_budget.map(|amount| DecimalPrice {
+        amount,
+        scale: 9,
+        currency: "USD".to_string(),
+    })
 }
 
 /// Paginated workspaces list response
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ListWorkspacesResponse {
+    #[serde(flatten)]
+    pub list: ListMetadata,
+    pub data: Vec<WorkspaceResponse>,
+    /// Same items as `data`; kept for existing consumers
+    #[schema(deprecated)]
     pub workspaces: Vec<WorkspaceResponse>,
     pub total: i64,
     pub limit: i64,
@@ -116,8 +228,7 @@ pub struct ListWorkspacesResponse {
 /// Query parameters for listing
 #[derive(Debug, Deserialize)]
 pub struct ListParams {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     pub order_by: Option<WorkspaceOrderBy>,
@@ -156,8 +267,7 @@ impl From<WorkspaceOrderDirection> for services::workspace::WorkspaceOrderDirect
 
 #[derive(Debug, Deserialize)]
 pub struct ListApiKeysParams {
-    #[serde(default = "crate::routes::common::default_limit")]
-    pub limit: i64,
+    pub limit: Option<i64>,
     #[serde(default)]
     pub offset: i64,
     pub order_by: Option<ApiKeyOrderBy>,
@@ -266,6 +376,8 @@ pub async fn create_workspace(
                 updated_at: workspace.updated_at,
                 is_active: workspace.is_active,
                 settings: workspace.settings,
+                default_params: workspace.default_params.map(Into::into),
+                monthly_budget: monthly_budget_to_price(workspace.monthly_budget),
             };
             Ok((StatusCode::CREATED, Json(response)))
         }
@@ -343,7 +455,11 @@ pub async fn list_organization_workspaces(
     );
 
     // Validate pagination parameters
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
 
     let user_id = authenticated_user_to_user_id(user);
     let organization_id = OrganizationId(org_id);
@@ -379,7 +495,7 @@ pub async fn list_organization_workspaces(
         .list_workspaces_for_organization_paginated(
             organization_id,
             user_id,
-            params.limit,
+            limit,
             params.offset,
             params.order_by.map(From::from),
             params.order_direction.map(From::from),
@@ -399,13 +515,21 @@ pub async fn list_organization_workspaces(
                     updated_at: w.updated_at,
                     is_active: w.is_active,
                     settings: w.settings,
+                    default_params: w.default_params.map(Into::into),
+                    monthly_budget: monthly_budget_to_price(w.monthly_budget),
                 })
                 .collect();
 
+            let list =
+                ListMetadata::for_offset_page(&workspace_responses, params.offset, total, |w| {
+                    w.id.clone()
+                });
             Ok(Json(ListWorkspacesResponse {
+                list,
+                data: workspace_responses.clone(),
                 workspaces: workspace_responses,
                 total,
-                limit: params.limit,
+                limit,
                 offset: params.offset,
             }))
         }
@@ -474,6 +598,8 @@ pub async fn get_workspace(
                 updated_at: workspace.updated_at,
                 is_active: workspace.is_active,
                 settings: workspace.settings,
+                default_params: workspace.default_params.map(Into::into),
+                monthly_budget: monthly_budget_to_price(workspace.monthly_budget),
             };
             Ok(Json(response))
         }
@@ -554,6 +680,7 @@ pub async fn update_workspace(
             request.name,
             request.description,
             request.settings,
+            request.default_params.map(Into::into),
         )
         .await
     {
@@ -568,6 +695,8 @@ pub async fn update_workspace(
                 updated_at: updated.updated_at,
                 is_active: updated.is_active,
                 settings: updated.settings,
+                default_params: updated.default_params.map(Into::into),
+                monthly_budget: monthly_budget_to_price(updated.monthly_budget),
             };
             Ok(Json(response))
         }
@@ -602,6 +731,202 @@ pub async fn update_workspace(
     }
 }
 
+/// Update workspace monthly budget
+///
+/// Sets the monthly spending budget for a workspace. Once the workspace's spend for the
+/// current calendar month (UTC) reaches the budget, requests made with its API keys are
+/// rejected with 402, independently of the organization and per-key limits. Set
+/// monthly_budget to null to remove the budget.
+#[utoipa::path(
+    patch,
+    path = "/v1/workspaces/{workspace_id}/monthly-budget",
+    tag = "Workspaces",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID")
+    ),
+    request_body = UpdateWorkspaceMonthlyBudgetRequest,
+    responses(
+        (status = 200, description = "Monthly budget updated successfully", body = WorkspaceResponse),
+        (status = 400, description = "Bad request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Workspace not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = []),
+    )
+)]
+pub async fn update_workspace_monthly_budget(
+    State(app_state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(workspace_id): Path<Uuid>,
+    Json(request): Json<UpdateWorkspaceMonthlyBudgetRequest>,
+) -> Result<Json<WorkspaceResponse>, (StatusCode, Json<ErrorResponse>)> {
+    debug!(
+        "Updating monthly budget for workspace: {} by user: {}",
+        workspace_id, user.0.id
+    );
+
+    if let Err(msg) = request.validate() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(msg, "bad_request".to_string())),
+        ));
+    }
+
+    let user_id = authenticated_user_to_user_id(user);
+    let workspace_id_typed = services::workspace::WorkspaceId(workspace_id);
+
+    match app_state
+        .workspace_service
+        .update_workspace_monthly_budget(
+            workspace_id_typed,
+            user_id,
+            request.monthly_budget.map(|budget| budget.amount),
+        )
+        .await
+    {
+        Ok(updated) => {
+            let response = WorkspaceResponse {
+                id: updated.id.0.to_string(),
+                name: updated.name,
+                description: updated.description,
+                organization_id: updated.organization_id.0.to_string(),
+                created_by_user_id: updated.created_by_user_id.0.to_string(),
+                created_at: updated.created_at,
+                updated_at: updated.updated_at,
+                is_active: updated.is_active,
+                settings: updated.settings,
+                default_params: updated.default_params.map(Into::into),
+                monthly_budget: monthly_budget_to_price(updated.monthly_budget),
+            };
+            Ok(Json(response))
+        }
+        Err(services::workspace::WorkspaceError::NotFound) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(
+                "Workspace not found".to_string(),
+                "not_found".to_string(),
+            )),
+        )),
+        Err(services::workspace::WorkspaceError::Unauthorized(msg)) => Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(msg, "forbidden".to_string())),
+        )),
+        Err(_) => {
+            error!("Failed to update workspace monthly budget");
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new(
+                    "Failed to update monthly budget".to_string(),
+                    "internal_server_error".to_string(),
+                )),
+            ))
+        }
+    }
+}
+
+/// Transfer workspace
+///
+/// Moves a workspace and its API keys to another organization. The caller must be an
+/// owner or admin of both organizations. Usage recorded before the transfer stays
+/// attributed to the original organization; later requests are billed to the new one.
+#[utoipa::path(
+    post,
+    path = "/v1/workspaces/{workspace_id}/transfer",
+    tag = "Workspaces",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID")
+    ),
+    request_body = TransferWorkspaceRequest,
+    responses(
+        (status = 200, description = "Workspace transferred successfully", body = WorkspaceResponse),
+        (status = 400, description = "Bad request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Workspace not found", body = ErrorResponse),
+        (status = 409, description = "Workspace name already exists in target organization", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = []),
+    )
+)]
+pub async fn transfer_workspace(
+    State(app_state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(workspace_id): Path<Uuid>,
+    Json(request): Json<TransferWorkspaceRequest>,
+) -> Result<Json<WorkspaceResponse>, (StatusCode, Json<ErrorResponse>)> {
+    debug!(
+        "Transferring workspace: {} to organization: {} by user: {}",
+        workspace_id, request.organization_id, user.0.id
+    );
+
+    let user_id = authenticated_user_to_user_id(user);
+    let workspace_id_typed = services::workspace::WorkspaceId(workspace_id);
+
+    match app_state
+        .workspace_service
+        .transfer_workspace(
+            workspace_id_typed,
+            user_id,
+            OrganizationId(request.organization_id),
+        )
+        .await
+    {
+        Ok(updated) => {
+            let response = WorkspaceResponse {
+                id: updated.id.0.to_string(),
+                name: updated.name,
+                description: updated.description,
+                organization_id: updated.organization_id.0.to_string(),
+                created_by_user_id: updated.created_by_user_id.0.to_string(),
+                created_at: updated.created_at,
+                updated_at: updated.updated_at,
+                is_active: updated.is_active,
+                settings: updated.settings,
+                default_params: updated.default_params.map(Into::into),
+                monthly_budget: monthly_budget_to_price(updated.monthly_budget),
+            };
+            Ok(Json(response))
+        }
+        Err(services::workspace::WorkspaceError::NotFound) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(
+                "Workspace not found".to_string(),
+                "not_found".to_string(),
+            )),
+        )),
+        Err(services::workspace::WorkspaceError::Unauthorized(msg)) => Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(msg, "forbidden".to_string())),
+        )),
+        Err(services::workspace::WorkspaceError::InvalidParams(msg)) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(msg, "bad_request".to_string())),
+        )),
+        Err(services::workspace::WorkspaceError::AlreadyExists) => Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "Workspace name already exists in target organization".to_string(),
+                "conflict".to_string(),
+            )),
+        )),
+        Err(_) => {
+            error!("Failed to transfer workspace");
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new(
+                    "Failed to transfer workspace".to_string(),
+                    "internal_server_error".to_string(),
+                )),
+            ))
+        }
+    }
+}
+
 /// Delete workspace
 ///
 /// Deletes (deactivates) a workspace. Only the workspace creator or organization admin/owner can delete.
@@ -835,14 +1160,18 @@ pub async fn list_workspace_api_keys(
     Path(workspace_id): Path<Uuid>,
     Query(params): Query<ListApiKeysParams>,
 ) -> Result<Json<ListApiKeysResponse>, (StatusCode, Json<ErrorResponse>)> {
+    // Validate pagination parameters
+    let limit = crate::routes::common::validate_limit_offset(
+        app_state.page_size_limits,
+        params.limit,
+        params.offset,
+    )?;
+
     debug!(
         "Listing API keys for workspace: {} by user: {} (limit: {}, offset: {})",
-        workspace_id, user.0.id, params.limit, params.offset
+        workspace_id, user.0.id, limit, params.offset
     );
 
-    // Validate pagination parameters
-    crate::routes::common::validate_limit_offset(params.limit, params.offset)?;
-
     let user_id = authenticated_user_to_user_id(user);
     let workspace_id_typed = services::workspace::WorkspaceId(workspace_id);
 
@@ -888,7 +1217,7 @@ pub async fn list_workspace_api_keys(
         .list_api_keys_paginated(
             workspace_id_typed,
             user_id,
-            params.limit,
+            limit,
             params.offset,
             order_by,
             order_direction,
@@ -909,7 +1238,7 @@ pub async fn list_workspace_api_keys(
             Ok(Json(ListApiKeysResponse {
                 api_keys: api_key_responses,
                 total,
-                limit: params.limit,
+                limit,
                 offset: params.offset,
             }))
         }
@@ -1011,6 +1340,78 @@ pub async fn revoke_workspace_api_key(
     }
 }
 
+/// Revoke all API keys in a workspace
+///
+/// Revokes every API key in the workspace at once, e.g. after a credential leak. The
+/// caller must be an owner or admin of the workspace's organization. A key validated
+/// shortly before the call can keep authenticating until the API key cache entry
+/// expires (30 seconds).
+#[utoipa::path(
+    post,
+    path = "/v1/workspaces/{workspace_id}/api-keys/revoke-all",
+    tag = "Workspaces",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID")
+    ),
+    responses(
+        (status = 200, description = "API keys revoked", body = RevokeAllApiKeysResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Workspace not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    security(
+        ("session_token" = []),
+    )
+)]
+pub async fn revoke_all_workspace_api_keys(
+    State(app_state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(workspace_id): Path<Uuid>,
+) -> Result<Json<RevokeAllApiKeysResponse>, (StatusCode, Json<ErrorResponse>)> {
+    debug!(
+        "Revoking all API keys in workspace: {} by user: {}",
+        workspace_id, user.0.id
+    );
+
+    let user_id = authenticated_user_to_user_id(user);
+
+    match app_state
+        .workspace_service
+        .revoke_all_api_keys(services::workspace::WorkspaceId(workspace_id), user_id)
+        .await
+    {
+        Ok(revoked_count) => {
+            info!(
+                "Revoked {} API keys in workspace {}",
+                revoked_count, workspace_id
+            );
+            Ok(Json(RevokeAllApiKeysResponse { revoked_count }))
+        }
+        Err(services::workspace::WorkspaceError::NotFound) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(
+                "Workspace not found".to_string(),
+                "not_found".to_string(),
+            )),
+        )),
+        Err(services::workspace::WorkspaceError::Unauthorized(msg)) => Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(msg, "forbidden".to_string())),
+        )),
+        Err(_) => {
+            error!("Failed to revoke workspace API keys");
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new(
+                    "Failed to revoke API keys".to_string(),
+                    "internal_server_error".to_string(),
+                )),
+            ))
+        }
+    }
+}
+
 /// Revoke API key using workspace context from middleware
 ///
 /// This route uses the workspace context from authenticated API keys to validate
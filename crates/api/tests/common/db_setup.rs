@@ -94,6 +94,8 @@ async fn ensure_shared_db() {
                 max_connections: 2,
                 tls_enabled: false,
                 tls_ca_cert_path: None,
+                tls_min_version: None,
+                tls_pinned_spki_sha256: Vec::new(),
                 refresh_interval: 30,
                 mock: false,
             };
@@ -32,13 +32,6 @@ use services::usage::ModelPricing;
 use sha2::{Digest, Sha256};
 use std::sync::{Arc, Mutex};
 
-#[cfg(test)]
-use ed25519_dalek::{Signature as Ed25519Signature, VerifyingKey as Ed25519VerifyingKey};
-#[cfg(test)]
-use k256::ecdsa::{RecoveryId, Signature as EcdsaSignature, VerifyingKey};
-#[cfg(test)]
-use sha3::Keccak256;
-
 pub const MOCK_USER_ID: &str = "11111111-1111-1111-1111-111111111111";
 
 /// Shared pricing constants for e2e tests that use setup_qwen_model / setup_qwen_model_with_cache_pricing.
@@ -63,7 +56,22 @@ pub fn test_config() -> ApiConfig {
                 .unwrap_or(0),
             // Tests drive the pricing scheduler's run_once() directly.
             pricing_change_apply_interval_secs: 0,
+            batch_processing_interval_secs: 0,
             ohttp_enabled: false,
+            stream_keepalive_interval_ms: 0,
+            stream_coalesce_window_ms: 0,
+            stream_coalesce_max_bytes: config::DEFAULT_STREAM_COALESCE_MAX_BYTES,
+            stream_dedup_window_ms: 0,
+            end_user_requests_per_minute: 0,
+            max_inference_body_bytes: config::DEFAULT_MAX_INFERENCE_BODY_BYTES,
+            slow_request_threshold_ms: config::DEFAULT_SLOW_REQUEST_THRESHOLD_MS,
+            default_page_size: config::DEFAULT_PAGE_SIZE,
+            max_page_size: config::DEFAULT_MAX_PAGE_SIZE,
+            inference_id_namespace: config::DEFAULT_INFERENCE_ID_NAMESPACE,
+            inference_id_legacy_lookup: true,
+            shutdown_drain_secs: 0,
+            json_schema_fallback: config::JsonSchemaFallbackPolicy::Passthrough,
+            trusted_proxy_hops: config::DEFAULT_TRUSTED_PROXY_HOPS,
         },
         inference_api_key: std::env::var("INFERENCE_API_KEY")
             .or_else(|_| std::env::var("MODEL_DISCOVERY_API_KEY"))
@@ -84,6 +92,7 @@ pub fn test_config() -> ApiConfig {
             encoding_key: "mock_encoding_key".to_string(),
             github: None,
             google: None,
+            oidc: None,
             near: config::NearConfig::default(),
             admin_domains: vec!["test.com".to_string()],
             require_session_bound_access_tokens: false,
@@ -102,6 +111,8 @@ pub fn test_config() -> ApiConfig {
             max_connections: 4,
             tls_enabled: false,
             tls_ca_cert_path: None,
+            tls_min_version: None,
+            tls_pinned_spki_sha256: Vec::new(),
             refresh_interval: 30,
             mock: false,
         },
@@ -128,7 +139,15 @@ pub fn test_config() -> ApiConfig {
             enabled: true,
             ..config::UsageReportingConfig::default()
         },
+        usage_anomaly: config::UsageAnomalyConfig::default(),
         ita: config::ItaAttestationConfig::default(),
+        audit_log: config::AuditLogConfig::default(),
+        provider_headers: config::ProviderHeadersConfig::default(),
+        mcp_connectors: config::McpConnectorsConfig {
+            allow_insecure_server_urls: true,
+            ..Default::default()
+        },
+        currency: config::CurrencyConfig::default(),
     }
 }
 
@@ -432,6 +451,24 @@ where
     (server, database)
 }
 
+/// Like `setup_test_server_with_config`, but also returns the mock provider
+/// so the test can script responses under the mutated config.
+pub async fn setup_test_server_with_config_and_mock<F>(
+    mutate: F,
+) -> (
+    axum_test::TestServer,
+    Arc<inference_providers::mock::MockProvider>,
+)
+where
+    F: FnOnce(&mut config::ApiConfig),
+{
+    let mut infra = setup_test_infrastructure().await;
+    mutate(&mut infra.config);
+    let (server, _pool, mock, _router) =
+        build_test_server_components(infra.database.clone(), infra.config).await;
+    (server, mock)
+}
+
 pub async fn setup_test_server_with_database() -> (axum_test::TestServer, Arc<Database>) {
     let (server, _, _, database) = setup_test_server_with_pool().await;
     (server, database)
@@ -456,6 +493,43 @@ pub async fn setup_test_server_with_pool() -> (
     )
 }
 
+/// Like `setup_test_server_with_pool`, but also returns the batch processor.
+/// Test configs disable the interval worker, so tests drive batches through
+/// their lifecycle with `run_once`.
+pub async fn setup_test_server_with_batch_processor() -> (
+    axum_test::TestServer,
+    Arc<inference_providers::mock::MockProvider>,
+    Arc<services::batches::BatchProcessor>,
+) {
+    let infra = setup_test_infrastructure().await;
+    assert_mock_user_in_db(&infra.database).await;
+
+    let auth_components = init_auth_services(infra.database.clone(), &infra.config);
+    let (inference_provider_pool, mock_provider) =
+        api::init_inference_providers_with_mocks(&infra.config).await;
+    let domain_services = api::init_domain_services_with_pool(
+        infra.database.clone(),
+        &infra.config,
+        auth_components.organization_service.clone(),
+        inference_provider_pool,
+        Arc::new(services::metrics::MockMetricsService),
+    )
+    .await;
+    let batch_processor = domain_services.batch_processor.clone();
+
+    let app = build_app_with_config(
+        infra.database,
+        auth_components,
+        domain_services,
+        Arc::new(infra.config),
+    );
+    (
+        axum_test::TestServer::new(app),
+        mock_provider,
+        batch_processor,
+    )
+}
+
 /// Like `setup_test_server`, but also returns the underlying `axum::Router`,
 /// so a test can drive it in-process (`tower::ServiceExt::oneshot`) and poll
 /// the response body frame-by-frame. `axum_test` buffers whole response
@@ -553,6 +627,49 @@ pub async fn setup_test_server_with_mcp_factory(
     (server, inference_provider_pool, mock_provider)
 }
 
+/// Test server whose NEAR logins are checked by `verifier` instead of a
+/// NEAR RPC access-key lookup
+pub async fn setup_test_server_with_near_verifier(
+    verifier: Arc<dyn services::auth::NearSignatureVerifier>,
+) -> axum_test::TestServer {
+    let infra = setup_test_infrastructure().await;
+
+    assert_mock_user_in_db(&infra.database).await;
+
+    let mut auth_components = init_auth_services(infra.database.clone(), &infra.config);
+    let nonce_repository = Arc::new(database::PostgresNearNonceRepository::new(
+        infra.database.pool().clone(),
+    ));
+    auth_components.near_auth_service = Arc::new(
+        services::auth::NearAuthService::new(
+            auth_components.auth_service.clone(),
+            nonce_repository,
+            infra.config.auth.near.clone(),
+        )
+        .with_signature_verifier(verifier),
+    );
+
+    let (inference_provider_pool, _mock_provider) =
+        api::init_inference_providers_with_mocks(&infra.config).await;
+    let metrics_service = Arc::new(services::metrics::MockMetricsService);
+    let domain_services = api::init_domain_services_with_pool(
+        infra.database.clone(),
+        &infra.config,
+        auth_components.organization_service.clone(),
+        inference_provider_pool,
+        metrics_service,
+    )
+    .await;
+
+    let app = build_app_with_config(
+        infra.database.clone(),
+        auth_components,
+        domain_services,
+        Arc::new(infra.config),
+    );
+    axum_test::TestServer::new(app)
+}
+
 pub async fn setup_unique_test_session(database: &Arc<Database>) -> (String, String) {
     let user_id = uuid::Uuid::new_v4();
     let user_id_str = user_id.to_string();
@@ -1211,109 +1328,17 @@ pub fn verify_ecdsa_signature(
     signature_hex: &str,
     signing_address_hex: &str,
 ) -> bool {
-    // Remove 0x prefix if present
-    let sig_clean = signature_hex.strip_prefix("0x").unwrap_or(signature_hex);
-    let addr_clean = signing_address_hex
-        .strip_prefix("0x")
-        .unwrap_or(signing_address_hex);
-
-    // Decode signature (should be 65 bytes = 130 hex chars)
-    let signature_bytes = match hex::decode(sig_clean) {
-        Ok(bytes) => bytes,
-        Err(_) => return false,
-    };
-
-    if signature_bytes.len() != 65 {
-        eprintln!(
-            "Invalid signature length: expected 65 bytes, got {} bytes",
-            signature_bytes.len()
-        );
-        return false;
-    }
-
-    // Extract r, s, and Ethereum v (recovery ID)
-    let r_s: [u8; 64] = signature_bytes[..64]
-        .try_into()
-        .expect("Signature should be 64 bytes for r||s");
-    let ethereum_v = signature_bytes[64]; // Last byte: Ethereum v (27 or 28)
-
-    // Validate Ethereum v format
-    if ethereum_v != 27 && ethereum_v != 28 {
-        eprintln!("Invalid Ethereum v: expected 27 or 28, got {ethereum_v}");
-        return false;
-    }
-
-    // Parse the signature
-    let signature = match EcdsaSignature::from_bytes(&r_s.into()) {
-        Ok(sig) => sig,
+    match services::attestation::verify_ecdsa_signature(
+        signature_text,
+        signature_hex,
+        signing_address_hex,
+    ) {
+        Ok(()) => true,
         Err(e) => {
-            eprintln!("Failed to parse signature: {e}");
-            return false;
-        }
-    };
-
-    // Convert Ethereum v (27-28) to k256 RecoveryId (0-3)
-    // Ethereum v = 27 + recovery_bit, so recovery_bit = v - 27
-    let recovery_bit = ethereum_v - 27;
-    let recovery_id = match RecoveryId::try_from(recovery_bit) {
-        Ok(rid) => rid,
-        Err(e) => {
-            eprintln!("Invalid recovery ID: {e}");
-            return false;
+            eprintln!("ECDSA signature verification failed: {e}");
+            false
         }
-    };
-
-    // Hash the message with Ethereum signed message format (matching the signing process)
-    // Format: \x19Ethereum Signed Message:\n{length}{message}
-    let message_bytes = signature_text.as_bytes();
-    let prefix = format!("\x19Ethereum Signed Message:\n{}", message_bytes.len());
-    let prefix_bytes = prefix.as_bytes();
-
-    // Concatenate prefix + message
-    let mut prefixed_message = Vec::with_capacity(prefix_bytes.len() + message_bytes.len());
-    prefixed_message.extend_from_slice(prefix_bytes);
-    prefixed_message.extend_from_slice(message_bytes);
-
-    // Hash with Keccak256
-    let mut hasher = Keccak256::new();
-    hasher.update(&prefixed_message);
-    let message_hash = hasher.finalize();
-
-    // Recover the public key from the signature
-    let recovered_key =
-        match VerifyingKey::recover_from_prehash(&message_hash, &signature, recovery_id) {
-            Ok(key) => key,
-            Err(e) => {
-                eprintln!("Failed to recover public key: {e}");
-                return false;
-            }
-        };
-
-    // Convert recovered public key to Ethereum address
-    // Get uncompressed public key (65 bytes: 0x04 + 32 bytes x + 32 bytes y)
-    let encoded_point = recovered_key.to_encoded_point(false);
-    let point_bytes = encoded_point.as_bytes();
-
-    // Extract x and y coordinates (skip the 0x04 prefix, take 64 bytes)
-    let uncompressed_pubkey = &point_bytes[1..65];
-
-    // Hash with Keccak256
-    let addr_hash = Keccak256::digest(uncompressed_pubkey);
-
-    // Ethereum address is the last 20 bytes (bytes 12..32)
-    let recovered_address_bytes = &addr_hash[12..32];
-    let recovered_address_hex = hex::encode(recovered_address_bytes);
-
-    // Compare with the expected signing address (should be Ethereum address format)
-    let addresses_match = recovered_address_hex.eq_ignore_ascii_case(addr_clean);
-
-    if !addresses_match {
-        eprintln!(
-            "Address mismatch:\n  Expected: {addr_clean}\n  Recovered: {recovered_address_hex}"
-        );
     }
-
-    addresses_match
 }
 
 /// Verify ED25519 signature.
@@ -1323,68 +1348,12 @@ pub fn verify_ed25519_signature(
     signature_hex: &str,
     public_key_hex: &str,
 ) -> bool {
-    // Remove 0x prefix if present
-    let sig_clean = signature_hex.strip_prefix("0x").unwrap_or(signature_hex);
-    let pub_key_clean = public_key_hex.strip_prefix("0x").unwrap_or(public_key_hex);
-
-    // Decode signature (should be 64 bytes = 128 hex chars)
-    let signature_bytes = match hex::decode(sig_clean) {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            eprintln!("Failed to decode signature hex");
-            return false;
-        }
-    };
-
-    if signature_bytes.len() != 64 {
-        eprintln!(
-            "Invalid ED25519 signature length: expected 64 bytes, got {} bytes",
-            signature_bytes.len()
-        );
-        return false;
-    }
-
-    // Parse the signature
-    let signature = match Ed25519Signature::try_from(signature_bytes.as_slice()) {
-        Ok(sig) => sig,
-        Err(e) => {
-            eprintln!("Failed to parse ED25519 signature: {e}");
-            return false;
-        }
-    };
-
-    // Decode public key (should be 32 bytes = 64 hex chars)
-    let public_key_bytes = match hex::decode(pub_key_clean) {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            eprintln!("Failed to decode public key hex");
-            return false;
-        }
-    };
-
-    if public_key_bytes.len() != 32 {
-        eprintln!(
-            "Invalid ED25519 public key length: expected 32 bytes, got {} bytes",
-            public_key_bytes.len()
-        );
-        return false;
-    }
-
-    // Parse the public key
-    let public_key = match Ed25519VerifyingKey::try_from(public_key_bytes.as_slice()) {
-        Ok(key) => key,
-        Err(e) => {
-            eprintln!("Failed to parse ED25519 public key: {e}");
-            return false;
-        }
-    };
-
-    // Verify the signature
-    match public_key.verify_strict(signature_text.as_bytes(), &signature) {
-        Ok(_) => {
-            eprintln!("✅ ED25519 signature is cryptographically valid!");
-            true
-        }
+    match services::attestation::verify_ed25519_signature(
+        signature_text,
+        signature_hex,
+        public_key_hex,
+    ) {
+        Ok(()) => true,
         Err(e) => {
             eprintln!("ED25519 signature verification failed: {e}");
             false
@@ -0,0 +1,184 @@
+// E2E tests for `PATCH /v1/admin/models?dry_run=true`:
+// - a dry run reports creates, field-level updates and no-ops against
+//   current records without persisting anything,
+// - a real run of the same batch applies it,
+// - a dry run validates exactly like a real run.
+
+use crate::common::*;
+use api::models::{BatchUpsertDryRunResponse, ModelHistoryResponse};
+
+async fn patch_models(
+    server: &axum_test::TestServer,
+    dry_run: bool,
+    body: serde_json::Value,
+) -> axum_test::TestResponse {
+    let path = if dry_run {
+        "/v1/admin/models?dry_run=true"
+    } else {
+        "/v1/admin/models"
+    };
+    server
+        .patch(path)
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&body)
+        .await
+}
+
+async fn model_history(
+    server: &axum_test::TestServer,
+    model_name: &str,
+) -> axum_test::TestResponse {
+    server
+        .get(format!("/v1/admin/models/{model_name}/history").as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await
+}
+
+fn new_model_body(input_cost: i64) -> serde_json::Value {
+    serde_json::json!({
+        "inputCostPerToken":  { "amount": input_cost, "currency": "USD" },
+        "outputCostPerToken": { "amount": 2_000_000, "currency": "USD" },
+        "modelDisplayName":   "Dry Run Test Model",
+        "modelDescription":   "Synthetic model for dry-run tests",
+        "contextLength":      4096,
+        "maxOutputLength":    1024,
+        "verifiable":         false,
+        "isActive":           true,
+        "aliases":            []
+    })
+}
+
+#[tokio::test]
+async fn test_dry_run_reports_create_and_persists_nothing() {
+    let server = setup_test_server().await;
+    let model_name = format!("test-dry-run-create-{}", uuid::Uuid::new_v4());
+
+    let response = patch_models(
+        &server,
+        true,
+        serde_json::json!({ &model_name: new_model_body(1_000_000) }),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let preview: BatchUpsertDryRunResponse = response.json();
+    assert_eq!(preview.would_create, vec![model_name.clone()]);
+    assert!(preview.would_update.is_empty());
+    assert!(preview.would_noop.is_empty());
+
+    // Nothing was written, so the model has no history.
+    let response = model_history(&server, &model_name).await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+
+    // The real run creates it.
+    let response = patch_models(
+        &server,
+        false,
+        serde_json::json!({ &model_name: new_model_body(1_000_000) }),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let response = model_history(&server, &model_name).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_dry_run_diffs_updates_and_real_run_applies_them() {
+    let server = setup_test_server().await;
+    let changed = format!("test-dry-run-changed-{}", uuid::Uuid::new_v4());
+    let unchanged = format!("test-dry-run-unchanged-{}", uuid::Uuid::new_v4());
+    let alias = format!("dry-run-alias-{}", uuid::Uuid::new_v4());
+
+    let response = patch_models(
+        &server,
+        false,
+        serde_json::json!({
+            &changed: new_model_body(1_000_000),
+            &unchanged: new_model_body(1_000_000),
+        }),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let update = serde_json::json!({
+        &changed: {
+            "inputCostPerToken": { "amount": 3_000_000, "currency": "USD" },
+            "modelDisplayName":  "Renamed Dry Run Model",
+            // Same as stored: not a change.
+            "contextLength":     4096,
+            "aliases":           [alias]
+        },
+        &unchanged: {
+            "outputCostPerToken": { "amount": 2_000_000, "currency": "USD" },
+            "isActive":           true
+        }
+    });
+
+    let response = patch_models(&server, true, update.clone()).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let preview: BatchUpsertDryRunResponse = response.json();
+    assert!(preview.would_create.is_empty());
+    assert_eq!(preview.would_noop, vec![unchanged.clone()]);
+
+    let mut fields: Vec<_> = preview
+        .would_update
+        .iter()
+        .map(|c| {
+            assert_eq!(c.model, changed);
+            (c.field.as_str(), c.old.clone(), c.new.clone())
+        })
+        .collect();
+    fields.sort_by(|a, b| a.0.cmp(b.0));
+    assert_eq!(
+        fields,
+        vec![
+            ("aliases", serde_json::json!([]), serde_json::json!([alias])),
+            (
+                "input_cost_per_token",
+                serde_json::json!(1_000_000),
+                serde_json::json!(3_000_000)
+            ),
+            (
+                "model_display_name",
+                serde_json::json!("Dry Run Test Model"),
+                serde_json::json!("Renamed Dry Run Model")
+            ),
+        ]
+    );
+
+    // The dry run wrote no history and left the price alone.
+    let history: ModelHistoryResponse = model_history(&server, &changed).await.json();
+    assert_eq!(history.total, 1);
+    assert_eq!(history.history[0].input_cost_per_token.amount, 1_000_000);
+
+    // The real run applies the same batch.
+    let response = patch_models(&server, false, update).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let history: ModelHistoryResponse = model_history(&server, &changed).await.json();
+    assert_eq!(history.total, 2);
+    assert_eq!(history.history[0].input_cost_per_token.amount, 3_000_000);
+    assert_eq!(
+        history.history[0].model_display_name,
+        "Renamed Dry Run Model"
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run_validates_like_a_real_run() {
+    let server = setup_test_server().await;
+    let model_name = format!("test-dry-run-invalid-{}", uuid::Uuid::new_v4());
+
+    let mut body = new_model_body(1_000_000);
+    body["modelDisplayName"] = serde_json::json!("   ");
+    let response = patch_models(&server, true, serde_json::json!({ &model_name: body })).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+
+    let mut body = new_model_body(1_000_000);
+    body["quantization"] = serde_json::json!("int3");
+    let response = patch_models(&server, true, serde_json::json!({ &model_name: body })).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+
+    let response = model_history(&server, &model_name).await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+}
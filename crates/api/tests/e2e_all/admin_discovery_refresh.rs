@@ -0,0 +1,110 @@
+// E2E tests for the admin discovery refresh endpoint
+
+use crate::common::*;
+use api::models::DiscoveryRefreshResponse;
+use services::inference_provider_pool::ExternalModelsSource;
+use std::sync::Arc;
+
+/// Discovery source serving a fixed set of external models.
+struct FixedModelsSource {
+    external: Vec<(String, serde_json::Value)>,
+}
+
+#[async_trait::async_trait]
+impl ExternalModelsSource for FixedModelsSource {
+    async fn fetch_external_models(&self) -> Result<Vec<(String, serde_json::Value)>, String> {
+        Ok(self.external.clone())
+    }
+
+    async fn fetch_inference_url_models(
+        &self,
+    ) -> Result<Vec<services::inference_provider_pool::InferenceEndpoint>, String> {
+        Ok(Vec::new())
+    }
+}
+
+fn external_model(base_url: &str) -> serde_json::Value {
+    serde_json::json!({
+        "backend": "openai_compatible",
+        "base_url": base_url,
+        "api_key": "sk-discovery-refresh-test"
+    })
+}
+
+#[tokio::test]
+async fn test_admin_discovery_refresh_returns_discovered_counts() {
+    let (server, pool, _mock, _db) = setup_test_server_with_pool().await;
+
+    let discovered = vec![
+        (
+            "refresh-test/model-a".to_string(),
+            external_model("https://a.example.com/v1"),
+        ),
+        (
+            "refresh-test/model-b".to_string(),
+            external_model("https://b.example.com/v1"),
+        ),
+    ];
+    pool.clone()
+        .start_refresh_task(
+            Arc::new(FixedModelsSource {
+                external: discovered.clone(),
+            }),
+            0,
+        )
+        .await;
+
+    let response = server
+        .post("/v1/admin/discovery/refresh")
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let summary: DiscoveryRefreshResponse = response.json();
+    assert_eq!(summary.models, discovered.len());
+    assert_eq!(summary.providers, discovered.len());
+
+    let mut registered = pool.registered_model_names().await;
+    registered.sort();
+    assert_eq!(
+        registered,
+        vec![
+            "refresh-test/model-a".to_string(),
+            "refresh-test/model-b".to_string()
+        ]
+    );
+
+    // Forcing attestation re-fetch is accepted and refreshes the same set.
+    let response = server
+        .post("/v1/admin/discovery/refresh?force_attestation=true")
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let summary: DiscoveryRefreshResponse = response.json();
+    assert_eq!(summary.models, discovered.len());
+}
+
+#[tokio::test]
+async fn test_admin_discovery_refresh_without_source_is_unavailable() {
+    let server = setup_test_server().await;
+
+    let response = server
+        .post("/v1/admin/discovery/refresh")
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 503);
+}
+
+#[tokio::test]
+async fn test_admin_discovery_refresh_requires_admin() {
+    let server = setup_test_server().await;
+
+    let response = server
+        .post("/v1/admin/discovery/refresh")
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 401);
+}
@@ -0,0 +1,51 @@
+// E2E tests for the admin pool routing state endpoint
+
+use crate::common::*;
+use api::models::PoolStateResponse;
+use inference_providers::mock::MockProvider;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_admin_pool_state_lists_registered_providers() {
+    let (server, pool, _mock, _db) = setup_test_server_with_pool().await;
+
+    let model = format!("pool-state-test/{}", uuid::Uuid::new_v4());
+    pool.register_providers(vec![
+        (model.clone(), Arc::new(MockProvider::new())),
+        (model.clone(), Arc::new(MockProvider::new())),
+    ])
+    .await;
+
+    let response = server
+        .get("/v1/admin/pool/state")
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let state: PoolStateResponse = response.json();
+    let entry = state
+        .models
+        .iter()
+        .find(|m| m.model == model)
+        .expect("registered model must be listed");
+    assert_eq!(entry.providers.len(), 2);
+    assert!(entry.pubkey_mappings > 0);
+    assert!(entry.providers.iter().all(|p| !p.ejected));
+    assert!(entry.providers.iter().all(|p| p.consecutive_failures == 0));
+    assert!(
+        !response.text().contains("api_key"),
+        "pool state must not expose credentials"
+    );
+}
+
+#[tokio::test]
+async fn test_admin_pool_state_requires_admin() {
+    let server = setup_test_server().await;
+
+    let response = server
+        .get("/v1/admin/pool/state")
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 401);
+}
@@ -86,9 +86,44 @@ impl services::models::ModelsServiceTrait for NoopModelsService {
     ) -> Result<services::models::ModelWithPricing, services::models::ModelsError> {
         unimplemented!()
     }
+    async fn resolve_and_get_model_for_organization(
+        &self,
+        _organization_id: uuid::Uuid,
+        _identifier: &str,
+    ) -> Result<services::models::ModelWithPricing, services::models::ModelsError> {
+        unimplemented!()
+    }
     async fn resolve_alias_cached(&self, _identifier: &str) -> Option<String> {
         None
     }
+    async fn resolve_alias_cached_for_organization(
+        &self,
+        _organization_id: uuid::Uuid,
+        _identifier: &str,
+    ) -> Option<String> {
+        None
+    }
+    async fn list_organization_model_aliases(
+        &self,
+        _organization_id: uuid::Uuid,
+    ) -> Result<Vec<services::models::OrganizationModelAlias>, services::models::ModelsError> {
+        unimplemented!()
+    }
+    async fn set_organization_model_alias(
+        &self,
+        _organization_id: uuid::Uuid,
+        _alias_name: &str,
+        _canonical_model_name: &str,
+    ) -> Result<services::models::OrganizationModelAlias, services::models::ModelsError> {
+        unimplemented!()
+    }
+    async fn delete_organization_model_alias(
+        &self,
+        _organization_id: uuid::Uuid,
+        _alias_name: &str,
+    ) -> Result<(), services::models::ModelsError> {
+        unimplemented!()
+    }
     async fn get_configured_model_names(
         &self,
     ) -> Result<Vec<String>, services::models::ModelsError> {
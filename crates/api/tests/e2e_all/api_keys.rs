@@ -605,9 +605,17 @@ async fn test_api_key_spend_limit_enforcement() {
         .expect("Failed to parse error response");
 
     assert_eq!(
-        error.error.r#type, "api_key_limit_exceeded",
-        "Error type should be api_key_limit_exceeded"
+        error.error.r#type, "spend_limit_exceeded",
+        "Error type should be spend_limit_exceeded"
     );
+    assert_eq!(error.error.code.as_deref(), Some("api_key_limit_exceeded"));
+    assert_eq!(
+        error.error.limit.as_ref().map(|l| l.limit_scope.as_str()),
+        Some("api_key")
+    );
+    // API key spend limits are lifetime caps; they never reset.
+    assert_eq!(error.error.limit.as_ref().and_then(|l| l.reset_at), None);
+    assert!(response2.maybe_header("retry-after").is_none());
     assert!(
         error.error.message.contains("API key spend limit exceeded"),
         "Error message should mention API key limit"
@@ -1006,3 +1014,116 @@ async fn test_api_key_name_edge_cases() {
 
     println!("✓ API key names with edge cases handled correctly");
 }
+
+// ============================================
+// Bulk Revocation Tests
+// ============================================
+
+#[tokio::test]
+async fn test_revoke_all_api_keys_in_workspace() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await; // $10.00 USD
+
+    let workspaces = list_workspaces(&server, org.id.clone()).await;
+    let workspace = workspaces.first().unwrap();
+
+    let mut keys = Vec::new();
+    for i in 0..3 {
+        let key = create_api_key_in_workspace(&server, workspace.id.clone(), format!("Leaked {i}"))
+            .await
+            .key
+            .unwrap();
+        keys.push(key);
+    }
+
+    // A key in another workspace of the same organization must keep working.
+    let response = server
+        .post(format!("/v1/organizations/{}/workspaces", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .json(&serde_json::json!({ "name": format!("untouched-{}", uuid::Uuid::new_v4()) }))
+        .await;
+    assert_eq!(response.status_code(), 201, "{}", response.text());
+    let other_workspace: api::routes::workspaces::WorkspaceResponse = response.json();
+    let other_key = create_api_key_in_workspace(&server, other_workspace.id, "Other".into())
+        .await
+        .key
+        .unwrap();
+
+    for key in &keys {
+        let response = server
+            .get("/v1/files?limit=1")
+            .add_header("Authorization", format!("Bearer {key}"))
+            .await;
+        assert_eq!(
+            response.status_code(),
+            200,
+            "key should work before revocation"
+        );
+    }
+
+    let response = server
+        .post(format!("/v1/workspaces/{}/api-keys/revoke-all", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let revoked: api::routes::workspaces::RevokeAllApiKeysResponse = response.json();
+    assert_eq!(revoked.revoked_count, keys.len() as u64);
+
+    for key in &keys {
+        let response = server
+            .get("/v1/files?limit=1")
+            .add_header("Authorization", format!("Bearer {key}"))
+            .await;
+        assert_eq!(
+            response.status_code(),
+            401,
+            "revoked key must fail authentication"
+        );
+    }
+
+    let response = server
+        .get("/v1/files?limit=1")
+        .add_header("Authorization", format!("Bearer {other_key}"))
+        .await;
+    assert_eq!(response.status_code(), 200, "other workspace is unaffected");
+
+    let list_response = server
+        .get(format!("/v1/workspaces/{}/api-keys", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(list_response.status_code(), 200);
+    let list_data = list_response.json::<api::models::ListApiKeysResponse>();
+    assert!(list_data.api_keys.is_empty());
+
+    // Nothing left to revoke.
+    let response = server
+        .post(format!("/v1/workspaces/{}/api-keys/revoke-all", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let revoked: api::routes::workspaces::RevokeAllApiKeysResponse = response.json();
+    assert_eq!(revoked.revoked_count, 0);
+}
+
+#[tokio::test]
+async fn test_revoke_all_api_keys_requires_org_admin() {
+    let (server, database) = setup_test_server_with_database().await;
+    let org = create_org(&server).await;
+    let workspaces = list_workspaces(&server, org.id.clone()).await;
+    let workspace = workspaces.first().unwrap();
+    let key = create_api_key_in_workspace(&server, workspace.id.clone(), "Kept".into()).await;
+
+    let (outsider_session, _) = setup_unique_test_session(&database).await;
+    let response = server
+        .post(format!("/v1/workspaces/{}/api-keys/revoke-all", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {outsider_session}"))
+        .await;
+    assert_eq!(response.status_code(), 403, "{}", response.text());
+
+    let list_response = server
+        .get(format!("/v1/workspaces/{}/api-keys", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    let list_data = list_response.json::<api::models::ListApiKeysResponse>();
+    assert!(list_data.api_keys.iter().any(|k| k.id == key.id));
+}
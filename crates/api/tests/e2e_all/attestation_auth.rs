@@ -36,7 +36,7 @@ async fn test_attestation_report_missing_auth_returns_401() {
     );
     let body: serde_json::Value = response.json();
     assert_eq!(
-        body["error"]["type"], "missing_auth_header",
+        body["error"]["type"], "authentication_error",
         "unexpected 401 envelope: {body}"
     );
 }
@@ -60,7 +60,7 @@ async fn test_attestation_report_malformed_auth_returns_401() {
     );
     let body: serde_json::Value = response.json();
     assert_eq!(
-        body["error"]["type"], "invalid_auth_header",
+        body["error"]["type"], "authentication_error",
         "unexpected 401 envelope: {body}"
     );
 }
@@ -302,7 +302,7 @@ async fn test_signature_route_missing_auth_returns_401() {
     );
     let body: serde_json::Value = response.json();
     assert_eq!(
-        body["error"]["type"], "missing_auth_header",
+        body["error"]["type"], "authentication_error",
         "unexpected 401 envelope: {body}"
     );
 }
@@ -0,0 +1,90 @@
+// E2E tests for GET /v1/attestation/quote: the nonce is mandatory and the
+// returned report must embed it (gateway quote and model attestations).
+
+use crate::common::*;
+
+const NONCE: &str = "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef";
+
+fn quote_url(model: &str) -> String {
+    let encoded = url::form_urlencoded::byte_serialize(model.as_bytes()).collect::<String>();
+    format!("/v1/attestation/quote?model={encoded}")
+}
+
+#[tokio::test]
+async fn test_attestation_quote_returns_report_bound_to_nonce() {
+    let (server, _pool, _mock, _db) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .get(&format!("{}&nonce={NONCE}", quote_url(&model)))
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["gateway_attestation"]["request_nonce"], NONCE);
+    let report_data = body["gateway_attestation"]["report_data"]
+        .as_str()
+        .expect("gateway report_data");
+    assert!(
+        report_data.ends_with(NONCE),
+        "report_data must embed the nonce in bytes 32..64: {report_data}"
+    );
+    let model_attestations = body["model_attestations"]
+        .as_array()
+        .expect("model_attestations");
+    assert!(!model_attestations.is_empty());
+    for attestation in model_attestations {
+        assert_eq!(attestation["request_nonce"], NONCE);
+    }
+}
+
+#[tokio::test]
+async fn test_attestation_quote_requires_nonce() {
+    let server = setup_test_server().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .get(&quote_url(&model))
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["error"]["param"], "nonce", "unexpected body: {body}");
+}
+
+#[tokio::test]
+async fn test_attestation_quote_rejects_stale_model_nonce() {
+    let (server, _pool, mock, _db) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    mock.set_stale_attestation_nonce(true);
+
+    let response = server
+        .get(&format!("{}&nonce={NONCE}", quote_url(&model)))
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+
+    assert_eq!(response.status_code(), 502, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(
+        body["error"]["type"], "attestation_nonce_mismatch",
+        "unexpected body: {body}"
+    );
+
+    // The plain report endpoint does not enforce freshness and still succeeds.
+    let response = server
+        .get(&format!(
+            "/v1/attestation/report?model={}&nonce={NONCE}",
+            url::form_urlencoded::byte_serialize(model.as_bytes()).collect::<String>()
+        ))
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
@@ -0,0 +1,141 @@
+// E2E tests for POST /v1/attestation/verify-model: every provider serving a
+// model is asked for a nonce-bound report and checked individually.
+
+use crate::common::*;
+use inference_providers::mock::MockProvider;
+use inference_providers::ProviderTier;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_verify_model_reports_per_provider_results() {
+    let (server, pool, _mock, _db) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let verified = Arc::new(MockProvider::new().with_tier(ProviderTier::Attested3p));
+    let failing = Arc::new(MockProvider::new().with_tier(ProviderTier::Attested3p));
+    failing.set_fail_attestation(true);
+    pool.register_providers(vec![
+        (E2E_QWEN_MODEL_NAME.to_string(), verified),
+        (E2E_QWEN_MODEL_NAME.to_string(), failing),
+    ])
+    .await;
+
+    let response = server
+        .post("/v1/attestation/verify-model")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({ "model": model, "signing_algo": "ecdsa" }))
+        .await;
+
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: api::routes::attestation::VerifyModelAttestationResponse = response.json();
+    assert_eq!(body.model, E2E_QWEN_MODEL_NAME);
+    assert_eq!(body.signing_algo, "ecdsa");
+    assert!(!body.verified);
+    assert_eq!(body.summary.total, 2);
+    assert_eq!(body.summary.passed, 1);
+    assert_eq!(body.summary.failed, 1);
+
+    let passing = &body.providers[0];
+    assert_eq!(passing.provider, "chutes");
+    assert!(passing.passed && passing.report_fetched);
+    assert!(passing.nonce_verified && passing.quote_verified);
+    assert!(passing.failure.is_none());
+    let attestation = passing.attestation.as_ref().expect("attestation report");
+    assert_eq!(attestation["request_nonce"], body.nonce.as_str());
+
+    let failing = &body.providers[1];
+    assert_eq!(failing.provider, "chutes");
+    assert!(!failing.passed && !failing.report_fetched && !failing.quote_verified);
+    assert!(failing.failure.is_some());
+    assert!(failing.attestation.is_none());
+}
+
+/// A report is only trusted once its quote verifies: echoing the nonce is not
+/// enough, so a quote-less (forged) NEAR report and a non-attested provider
+/// both fail.
+#[tokio::test]
+async fn test_verify_model_rejects_reports_without_a_verified_quote() {
+    let (server, pool, _mock, _db) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    pool.register_providers(vec![
+        (
+            E2E_QWEN_MODEL_NAME.to_string(),
+            Arc::new(MockProvider::new().with_tier(ProviderTier::Near)),
+        ),
+        (
+            E2E_QWEN_MODEL_NAME.to_string(),
+            Arc::new(MockProvider::new().with_tier(ProviderTier::NonAttested)),
+        ),
+    ])
+    .await;
+
+    let response = server
+        .post("/v1/attestation/verify-model")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({ "model": model }))
+        .await;
+
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: api::routes::attestation::VerifyModelAttestationResponse = response.json();
+    assert!(!body.verified);
+    assert_eq!(body.summary.total, 2);
+    assert_eq!(body.summary.failed, 2);
+    assert_eq!(body.providers[0].provider, "near");
+    for provider in &body.providers {
+        assert!(provider.report_fetched && provider.nonce_verified);
+        assert!(!provider.quote_verified && !provider.passed);
+        assert_eq!(
+            provider.failure.as_deref(),
+            Some("attestation quote failed verification")
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_verify_model_flags_stale_nonce() {
+    let (server, _pool, mock, _db) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    mock.set_stale_attestation_nonce(true);
+
+    let response = server
+        .post("/v1/attestation/verify-model")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({ "model": model }))
+        .await;
+
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: api::routes::attestation::VerifyModelAttestationResponse = response.json();
+    assert!(!body.verified);
+    assert_eq!(body.summary.failed, body.summary.total);
+    for provider in &body.providers {
+        assert!(provider.report_fetched && !provider.nonce_verified);
+    }
+}
+
+#[tokio::test]
+async fn test_verify_model_rejects_invalid_signing_algo() {
+    let server = setup_test_server().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .post("/v1/attestation/verify-model")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({ "model": model, "signing_algo": "rsa" }))
+        .await;
+
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(
+        body["error"]["param"], "signing_algo",
+        "unexpected body: {body}"
+    );
+}
@@ -7,6 +7,7 @@
 //! - File size validation
 //! - Empty file validation
 //! - Missing/invalid model
+//! - Organization model aliases
 //! - Authentication requirements
 //! - Usage tracking and billing
 //! - Concurrent request limiting
@@ -90,6 +91,61 @@ async fn test_audio_transcription_basic() {
     assert!(!body.text.is_empty());
 }
 
+/// Test that an organization-private alias resolves for audio transcriptions
+#[tokio::test]
+async fn test_audio_transcription_resolves_org_alias() {
+    let server = setup_test_server().await;
+
+    let model_name = "Qwen/Qwen-Image-2512";
+    setup_whisper_model(&server, model_name).await;
+
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let other = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+    let other_key = get_api_key_for_org(&server, other.id.clone()).await;
+
+    let alias = format!("org-audio-alias-{}", uuid::Uuid::new_v4());
+    let response = server
+        .put(
+            format!(
+                "/v1/organizations/{}/model-aliases/{}",
+                org.id,
+                urlencoding::encode(&alias)
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({ "model": model_name }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let transcribe = |key: String| {
+        server
+            .post("/v1/audio/transcriptions")
+            .add_header("Authorization", format!("Bearer {key}"))
+            .multipart(
+                axum_test::multipart::MultipartForm::new()
+                    .add_part(
+                        "file",
+                        axum_test::multipart::Part::bytes(create_mock_audio_file(10))
+                            .file_name("test.mp3")
+                            .mime_type("audio/mpeg"),
+                    )
+                    .add_text("model", alias.clone()),
+            )
+    };
+
+    let response = transcribe(api_key).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: api::models::AudioTranscriptionResponse = response.json();
+    assert!(!body.text.is_empty());
+
+    // The alias is private to the organization that created it.
+    let response = transcribe(other_key).await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+}
+
 /// Test audio transcription with language parameter
 #[tokio::test]
 async fn test_audio_transcription_with_language() {
@@ -78,9 +78,9 @@ async fn auto_redact_empty_messages_returns_400() {
     );
 }
 
-/// Empty content with auto-redact enabled. No text fragments means the
-/// detector is invoked with an empty list (or short-circuits), no
-/// placeholders are minted, and the response is passed through unchanged.
+/// Whitespace-only content with auto-redact enabled (fully empty content is
+/// rejected before dispatch). Nothing to detect means no placeholders are
+/// minted, and the response is passed through unchanged.
 #[tokio::test]
 async fn auto_redact_empty_content_is_noop() {
     let (server, _pool, mock_provider, _db) = setup_test_server_with_pool().await;
@@ -102,14 +102,14 @@ async fn auto_redact_empty_content_is_noop() {
         .add_header("x-auto-redact", "on")
         .json(&serde_json::json!({
             "model": E2E_QWEN_MODEL_NAME,
-            "messages": [{"role": "user", "content": ""}],
+            "messages": [{"role": "user", "content": " "}],
         }))
         .await;
 
     assert_eq!(
         resp.status_code(),
         200,
-        "empty content with auto_redact on should succeed: {}",
+        "whitespace-only content with auto_redact on should succeed: {}",
         resp.text()
     );
 
@@ -398,7 +398,7 @@ async fn auto_redact_avoids_collision_with_dummy_shaped_input() {
 /// Large input (~512 KB of filler with PII at the edges) with auto-redact
 /// on. Confirms the redact path handles a substantial body without
 /// truncating or mishandling the PII at the boundaries. Stays well under
-/// the 25 MB route limit so the request itself doesn't 413.
+/// the 10 MB default route limit so the request itself doesn't 413.
 #[tokio::test]
 async fn auto_redact_handles_large_input_under_limit() {
     let (server, _pool, mock_provider, _db) = setup_test_server_with_pool().await;
@@ -433,7 +433,7 @@ async fn auto_redact_handles_large_input_under_limit() {
     assert_eq!(
         resp.status_code(),
         200,
-        "large (~512 KB) body under the 25 MB chat limit should succeed; got: {}",
+        "large (~512 KB) body under the 10 MB chat limit should succeed; got: {}",
         // Don't dump the entire body — it would be enormous on failure.
         resp.status_code()
     );
@@ -469,11 +469,10 @@ async fn auto_redact_handles_large_input_under_limit() {
     );
 }
 
-/// Body that exceeds the 25 MB chat completions limit must be rejected
-/// without leaking PII to the provider. The exact status is implementation-
-/// defined (axum's DefaultBodyLimit returns 413, but middleware ordering
-/// can produce 400 instead); accept either, and require that the provider
-/// is never called.
+/// Body that exceeds the chat completions body limit must be rejected
+/// without leaking PII to the provider. The status itself is covered by the
+/// body-limit tests; here require only a rejection and that the provider is
+/// never called.
 #[tokio::test]
 async fn auto_redact_rejects_oversize_body() {
     let (server, _pool, mock_provider, _db) = setup_test_server_with_pool().await;
@@ -486,7 +485,7 @@ async fn auto_redact_rejects_oversize_body() {
         .set_default_response(inference_providers::mock::ResponseTemplate::new("nope"))
         .await;
 
-    // 26 MB > 25 MB AUDIO_TRANSCRIPTION_MAX_BODY_SIZE limit on
+    // 26 MB > the 10 MB default MAX_INFERENCE_BODY_BYTES limit on
     // /v1/chat/completions.
     let big = "a".repeat(26 * 1024 * 1024);
     let body = serde_json::json!({
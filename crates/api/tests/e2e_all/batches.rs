@@ -0,0 +1,343 @@
+// E2E tests for the batch API (POST /v1/batches, GET /v1/batches/{id})
+//
+// The shared test database may hold batches from other runs, and `run_once`
+// claims any open batch. Tests that drive the processor poll until their own
+// batch reaches the expected state.
+
+use crate::common::*;
+use api::models::BatchObject;
+use serde_json::json;
+
+const MAX_PROCESSING_PASSES: usize = 50;
+
+fn chat_body(model: &str, content: &str) -> serde_json::Value {
+    json!({
+        "model": model,
+        "messages": [{"role": "user", "content": content}],
+        "max_tokens": 16
+    })
+}
+
+async fn get_batch(server: &axum_test::TestServer, api_key: &str, batch_id: &str) -> BatchObject {
+    let response = server
+        .get(&format!("/v1/batches/{batch_id}"))
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    response.json::<BatchObject>()
+}
+
+/// Run processing passes until the batch reaches `status`.
+async fn process_until_status(
+    server: &axum_test::TestServer,
+    processor: &services::batches::BatchProcessor,
+    api_key: &str,
+    batch_id: &str,
+    status: &str,
+) -> BatchObject {
+    for _ in 0..MAX_PROCESSING_PASSES {
+        processor.run_once().await.expect("batch processing pass");
+        let batch = get_batch(server, api_key, batch_id).await;
+        if batch.status == status {
+            return batch;
+        }
+    }
+    panic!("batch {batch_id} never reached status {status}");
+}
+
+#[tokio::test]
+async fn test_inline_batch_lifecycle_with_per_line_results() {
+    let (server, mock_provider, processor) = setup_test_server_with_batch_processor().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .post("/v1/batches")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "endpoint": "/v1/chat/completions",
+            "completion_window": "24h",
+            "metadata": {"job": "nightly"},
+            "requests": [
+                {"custom_id": "first", "method": "POST", "url": "/v1/chat/completions",
+                 "body": chat_body(&model, "Hello")},
+                {"custom_id": "second", "body": chat_body(&model, "Hi again")},
+                {"custom_id": "broken", "body": {"model": model}}
+            ]
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let created = response.json::<BatchObject>();
+    assert!(created.id.starts_with("batch_"));
+    assert_eq!(created.object, "batch");
+    assert_eq!(created.status, "validating");
+    assert_eq!(created.request_counts.total, 3);
+    assert_eq!(created.metadata, Some(json!({"job": "nightly"})));
+    assert!(created.output_file_id.is_none());
+
+    // Upstream rate limiting defers the batch: it is picked up but no line
+    // gets a result, and it is resumed on a later pass.
+    mock_provider
+        .set_error_override(Some(inference_providers::CompletionError::HttpError {
+            status_code: 429,
+            message: "Too many requests".to_string(),
+            is_external: false,
+        }))
+        .await;
+    let in_progress =
+        process_until_status(&server, &processor, &api_key, &created.id, "in_progress").await;
+    assert!(in_progress.in_progress_at.is_some());
+    assert_eq!(in_progress.request_counts.completed, 0);
+    assert_eq!(in_progress.request_counts.failed, 0);
+    assert!(in_progress
+        .results
+        .as_ref()
+        .unwrap()
+        .iter()
+        .all(|line| line.status == "pending" && line.response.is_none()));
+
+    mock_provider.set_error_override(None).await;
+    let completed =
+        process_until_status(&server, &processor, &api_key, &created.id, "completed").await;
+    assert!(completed.completed_at.is_some());
+    assert_eq!(completed.request_counts.total, 3);
+    assert_eq!(completed.request_counts.completed, 2);
+    assert_eq!(completed.request_counts.failed, 1);
+
+    let results = completed.results.expect("retrieval includes results");
+    let custom_ids: Vec<&str> = results.iter().map(|r| r.custom_id.as_str()).collect();
+    assert_eq!(custom_ids, ["first", "second", "broken"]);
+    for line in &results[..2] {
+        assert_eq!(line.status, "completed");
+        let response = line.response.as_ref().unwrap();
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.body["object"], "chat.completion");
+        assert!(response.body["choices"][0]["message"]["content"].is_string());
+    }
+    assert_eq!(results[2].status, "failed");
+    let broken = results[2].response.as_ref().unwrap();
+    assert_eq!(broken.status_code, 400);
+    assert!(broken.body["error"]["message"].is_string());
+
+    // The same results are written to a JSONL output file
+    let output_file_id = completed.output_file_id.expect("output file");
+    let response = server
+        .get(&format!("/v1/files/{output_file_id}/content"))
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 200);
+    let output_lines: Vec<serde_json::Value> = response
+        .text()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(output_lines.len(), 3);
+    assert_eq!(output_lines[0]["custom_id"], "first");
+    assert_eq!(output_lines[0]["response"]["status_code"], 200);
+    assert_eq!(output_lines[2]["custom_id"], "broken");
+    assert_eq!(output_lines[2]["response"]["status_code"], 400);
+}
+
+#[tokio::test]
+async fn test_batch_lines_fail_once_submitting_key_is_deleted() {
+    let (server, _mock_provider, processor) = setup_test_server_with_batch_processor().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    // A second key in the same workspace reads the batch after the first is gone
+    let reader_key = get_api_key_for_org(&server, org.id.clone()).await;
+    let workspace = list_workspaces(&server, org.id).await.remove(0);
+    let submit_key =
+        create_api_key_in_workspace(&server, workspace.id.clone(), "Batch submitter".to_string())
+            .await;
+
+    let response = server
+        .post("/v1/batches")
+        .add_header(
+            "Authorization",
+            format!("Bearer {}", submit_key.key.as_ref().unwrap()),
+        )
+        .json(&json!({
+            "endpoint": "/v1/chat/completions",
+            "completion_window": "24h",
+            "requests": [{"custom_id": "only", "body": chat_body(&model, "Hello")}]
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let created = response.json::<BatchObject>();
+
+    let response = server
+        .delete(&format!(
+            "/v1/workspaces/{}/api-keys/{}",
+            workspace.id, submit_key.id
+        ))
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(response.status_code(), 204);
+
+    let completed =
+        process_until_status(&server, &processor, &reader_key, &created.id, "completed").await;
+    assert_eq!(completed.request_counts.failed, 1);
+    let results = completed.results.expect("retrieval includes results");
+    let response = results[0].response.as_ref().unwrap();
+    assert_eq!(response.status_code, 401);
+    assert_eq!(response.body["error"]["type"], "invalid_api_key");
+}
+
+#[tokio::test]
+async fn test_create_batch_from_uploaded_file() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let jsonl = [
+        json!({"custom_id": "a", "method": "POST", "url": "/v1/chat/completions",
+               "body": chat_body("some-model", "one")}),
+        json!({"custom_id": "b", "method": "POST", "url": "/v1/chat/completions",
+               "body": chat_body("some-model", "two")}),
+    ]
+    .iter()
+    .map(|line| line.to_string())
+    .collect::<Vec<_>>()
+    .join("\n");
+    let upload = server
+        .post("/v1/files")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .multipart(
+            axum_test::multipart::MultipartForm::new()
+                .add_text("purpose", "batch")
+                .add_part(
+                    "file",
+                    axum_test::multipart::Part::bytes(jsonl.into_bytes())
+                        .file_name("batch_input.jsonl")
+                        .mime_type("application/octet-stream"),
+                ),
+        )
+        .await;
+    assert_eq!(upload.status_code(), 201, "{}", upload.text());
+    let file_id = upload.json::<api::models::FileUploadResponse>().id;
+
+    let response = server
+        .post("/v1/batches")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "input_file_id": file_id,
+            "endpoint": "/v1/chat/completions",
+            "completion_window": "24h"
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let created = response.json::<BatchObject>();
+    assert_eq!(created.input_file_id.as_deref(), Some(file_id.as_str()));
+    assert_eq!(created.request_counts.total, 2);
+
+    let fetched = get_batch(&server, &api_key, &created.id).await;
+    let custom_ids: Vec<String> = fetched
+        .results
+        .unwrap()
+        .into_iter()
+        .map(|line| line.custom_id)
+        .collect();
+    assert_eq!(custom_ids, ["a", "b"]);
+}
+
+#[tokio::test]
+async fn test_create_batch_rejects_invalid_input() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let line = json!({"custom_id": "a", "body": chat_body("some-model", "hi")});
+    let cases = [
+        // Neither input source
+        json!({"endpoint": "/v1/chat/completions", "completion_window": "24h"}),
+        // Both input sources
+        json!({"endpoint": "/v1/chat/completions", "completion_window": "24h",
+               "input_file_id": "file-00000000-0000-0000-0000-000000000000",
+               "requests": [line]}),
+        // Unsupported endpoint
+        json!({"endpoint": "/v1/embeddings", "completion_window": "24h", "requests": [line]}),
+        // Unsupported completion window
+        json!({"endpoint": "/v1/chat/completions", "completion_window": "1h", "requests": [line]}),
+        // Duplicate custom_id
+        json!({"endpoint": "/v1/chat/completions", "completion_window": "24h",
+               "requests": [line, line]}),
+        // Unknown input file
+        json!({"endpoint": "/v1/chat/completions", "completion_window": "24h",
+               "input_file_id": "file-00000000-0000-0000-0000-000000000000"}),
+    ];
+    for body in cases {
+        let response = server
+            .post("/v1/batches")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .json(&body)
+            .await;
+        assert_eq!(response.status_code(), 400, "body: {body}");
+        let err = response.json::<api::models::ErrorResponse>();
+        assert_eq!(err.error.r#type, "invalid_request_error");
+    }
+
+    // Input files must be uploaded with purpose "batch"
+    let line_jsonl = json!({"custom_id": "a", "method": "POST", "url": "/v1/chat/completions",
+                            "body": chat_body("some-model", "hi")})
+    .to_string();
+    let upload = server
+        .post("/v1/files")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .multipart(
+            axum_test::multipart::MultipartForm::new()
+                .add_text("purpose", "user_data")
+                .add_part(
+                    "file",
+                    axum_test::multipart::Part::bytes(line_jsonl.into_bytes())
+                        .file_name("not_batch.jsonl")
+                        .mime_type("application/octet-stream"),
+                ),
+        )
+        .await;
+    assert_eq!(upload.status_code(), 201, "{}", upload.text());
+    let file_id = upload.json::<api::models::FileUploadResponse>().id;
+    let response = server
+        .post("/v1/batches")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "input_file_id": file_id,
+            "endpoint": "/v1/chat/completions",
+            "completion_window": "24h"
+        }))
+        .await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+
+    let response = server
+        .get("/v1/batches/batch_00000000-0000-0000-0000-000000000000")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 404);
+}
+
+#[tokio::test]
+async fn test_batch_not_visible_to_other_workspace() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let other_org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let other_api_key = get_api_key_for_org(&server, other_org.id).await;
+
+    let response = server
+        .post("/v1/batches")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "endpoint": "/v1/chat/completions",
+            "completion_window": "24h",
+            "requests": [{"custom_id": "a", "body": chat_body("some-model", "hi")}]
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let created = response.json::<BatchObject>();
+
+    let response = server
+        .get(&format!("/v1/batches/{}", created.id))
+        .add_header("Authorization", format!("Bearer {other_api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 404);
+}
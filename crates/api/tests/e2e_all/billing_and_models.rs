@@ -85,3 +85,86 @@ async fn test_billing_costs_happy_path() {
         "Missing inference ID should have zero cost"
     );
 }
+
+/// Admin pricing updates take effect on the next billed request. Billing
+/// reads pricing from the database, so this holds on every instance.
+#[tokio::test]
+async fn test_pricing_update_applies_to_next_completion() {
+    let (server, pool, mock_provider, _db) = setup_test_server_with_pool().await;
+    // A model private to this test, so repricing it cannot disturb others.
+    let model = format!("pricing-reload-test/{}", uuid::Uuid::new_v4());
+    pool.register_providers(vec![(
+        model.clone(),
+        mock_provider as std::sync::Arc<dyn inference_providers::InferenceProvider + Send + Sync>,
+    )])
+    .await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    async fn set_pricing(server: &axum_test::TestServer, model: &str, input: i64, output: i64) {
+        let mut batch = api::models::BatchUpdateModelApiRequest::new();
+        batch.insert(
+            model.to_string(),
+            serde_json::from_value(serde_json::json!({
+                "inputCostPerToken": {"amount": input, "currency": "USD"},
+                "outputCostPerToken": {"amount": output, "currency": "USD"},
+                "modelDisplayName": "Pricing reload test",
+                "modelDescription": "Pricing reload test model",
+                "contextLength": 128000,
+                "maxOutputLength": 1024,
+                "verifiable": true,
+                "isActive": true
+            }))
+            .unwrap(),
+        );
+        admin_batch_upsert_models(server, batch, get_session_id()).await;
+    }
+
+    async fn complete_and_get_cost(
+        server: &axum_test::TestServer,
+        api_key: &str,
+        org_id: &str,
+        model: &str,
+    ) -> api::routes::usage::UsageHistoryEntryResponse {
+        let response = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .json(&serde_json::json!({
+                "model": model,
+                "messages": [{"role": "user", "content": "Test"}],
+                "max_tokens": 10,
+                "stream": false
+            }))
+            .await;
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+
+        // Wait for async usage recording to complete
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+        let history = server
+            .get(&format!(
+                "/v1/organizations/{org_id}/usage/history?limit=1&offset=0"
+            ))
+            .add_header("Authorization", format!("Bearer {}", get_session_id()))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .await;
+        assert_eq!(history.status_code(), 200, "{}", history.text());
+        let mut history: api::routes::usage::UsageHistoryResponse = history.json();
+        history.data.remove(0)
+    }
+
+    set_pricing(&server, &model, 1_000, 2_000).await;
+    let first = complete_and_get_cost(&server, &api_key, &org.id, &model).await;
+    assert_eq!(
+        first.total_cost,
+        first.input_tokens as i64 * 1_000 + first.output_tokens as i64 * 2_000
+    );
+
+    set_pricing(&server, &model, 3_000, 5_000).await;
+    let second = complete_and_get_cost(&server, &api_key, &org.id, &model).await;
+    assert_ne!(first.id, second.id);
+    assert_eq!(
+        second.total_cost,
+        second.input_tokens as i64 * 3_000 + second.output_tokens as i64 * 5_000,
+        "the second completion must bill at the updated rate"
+    );
+}
@@ -0,0 +1,145 @@
+// E2E tests for `POST /v1/chat/completions?debug_prompt=true`:
+// - an admin caller gets the request as sent to the provider in `debug`,
+//   reflecting workspace defaults and org alias resolution,
+// - without the flag, or for a non-admin caller, no `debug` field is returned.
+
+use crate::common::*;
+use inference_providers::mock::{RequestMatcher, ResponseTemplate};
+use serde_json::json;
+
+/// Give the org's first workspace `temperature`/`max_tokens` defaults, point an
+/// org alias at the Qwen model, and return an API key for that workspace.
+async fn setup_defaults_and_alias(
+    server: &axum_test::TestServer,
+    session: &str,
+    org_id: &str,
+    alias: &str,
+) -> String {
+    let workspace = list_workspaces_with_session(server, org_id.to_string(), session)
+        .await
+        .remove(0);
+    let response = server
+        .put(format!("/v1/workspaces/{}", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {session}"))
+        .json(&json!({ "default_params": { "temperature": 0.3, "max_tokens": 128 } }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let response = server
+        .put(format!("/v1/organizations/{org_id}/model-aliases/{alias}").as_str())
+        .add_header("Authorization", format!("Bearer {session}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&json!({ "model": E2E_QWEN_MODEL_NAME }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    create_api_key_in_workspace_with_session(
+        server,
+        workspace.id,
+        "debug-prompt".to_string(),
+        session,
+    )
+    .await
+    .key
+    .unwrap()
+}
+
+/// Send a chat completion, authenticating the caller as `admin_session`
+/// through `X-Admin-Authorization`
+async fn chat(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    query: &str,
+    model: &str,
+    admin_session: &str,
+) -> serde_json::Value {
+    let response = server
+        .post(&format!("/v1/chat/completions{query}"))
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("X-Admin-Authorization", format!("Bearer {admin_session}"))
+        .json(&json!({
+            "model": model,
+            "messages": [{ "role": "user", "content": "Debug me" }],
+            "stream": false
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    response.json()
+}
+
+async fn setup_non_admin_session(database: &std::sync::Arc<database::Database>) -> String {
+    let user_id = uuid::Uuid::new_v4();
+    let client = database.pool().get().await.unwrap();
+    client
+        .execute(
+            "INSERT INTO users (id, email, username, auth_provider, provider_user_id, created_at, updated_at)
+             VALUES ($1, $2, $3, 'mock', $4, NOW(), NOW())",
+            &[
+                &user_id,
+                &format!("user-{user_id}@example.org"),
+                &format!("user-{user_id}"),
+                &format!("mock_user-{user_id}"),
+            ],
+        )
+        .await
+        .unwrap();
+    format!("rt_{user_id}")
+}
+
+#[tokio::test]
+async fn test_admin_debug_prompt_shows_assembled_request() {
+    let (server, _pool, mock, _db) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    mock.when(RequestMatcher::Any)
+        .respond_with(ResponseTemplate::new("ok"))
+        .await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let alias = format!("debug-alias-{}", uuid::Uuid::new_v4());
+    let api_key = setup_defaults_and_alias(&server, &get_session_id(), &org.id, &alias).await;
+
+    // Without the flag the response is untouched.
+    let body = chat(&server, &api_key, "", &alias, &get_session_id()).await;
+    assert!(body.get("debug").is_none(), "{body}");
+
+    let body = chat(
+        &server,
+        &api_key,
+        "?debug_prompt=true",
+        &alias,
+        &get_session_id(),
+    )
+    .await;
+    let assembled = &body["debug"]["assembled_request"];
+    // The alias was resolved to the canonical model before the provider call.
+    assert_eq!(assembled["model"], E2E_QWEN_MODEL_NAME, "{body}");
+    // Workspace defaults filled what the client left unset.
+    let temperature = assembled["temperature"].as_f64().unwrap();
+    assert!((temperature - 0.3).abs() < 1e-6, "{temperature}");
+    assert_eq!(assembled["max_tokens"], 128);
+    assert_eq!(
+        assembled["messages"],
+        json!([{ "role": "user", "content": "Debug me" }])
+    );
+    // Internal bookkeeping is not part of the prompt.
+    assert!(assembled.get("user").is_none());
+    assert!(assembled.get("x_request_id").is_none());
+    // The completion itself is unchanged.
+    assert_eq!(body["choices"][0]["message"]["content"], "ok");
+}
+
+#[tokio::test]
+async fn test_non_admin_never_sees_debug_prompt() {
+    let (server, _pool, mock, db) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    mock.when(RequestMatcher::Any)
+        .respond_with(ResponseTemplate::new("ok"))
+        .await;
+    let session = setup_non_admin_session(&db).await;
+    let org = setup_org_with_credits_and_session(&server, 10_000_000_000i64, &session).await;
+    let alias = format!("debug-alias-{}", uuid::Uuid::new_v4());
+    let api_key = setup_defaults_and_alias(&server, &session, &org.id, &alias).await;
+
+    let body = chat(&server, &api_key, "?debug_prompt=true", &alias, &session).await;
+    assert!(body.get("debug").is_none(), "{body}");
+    assert!(!body.to_string().contains("Debug me"), "{body}");
+}
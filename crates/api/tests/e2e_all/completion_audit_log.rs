@@ -0,0 +1,145 @@
+// E2E tests for the completion audit log (AUDIT_LOG_ENABLED /
+// AUDIT_LOG_STORE_BODIES): one row per chat completion, keyed by the request
+// body hash, with PII-redacted bodies when body storage is on.
+
+use crate::common::*;
+use std::sync::Arc;
+
+const EMAIL: &str = "jane.doe@example.com";
+const CARD: &str = "4111 1111 1111 1111";
+
+struct AuditRow {
+    model: String,
+    request_hash: String,
+    response_hash: String,
+    request_body: Option<String>,
+    response_body: Option<String>,
+}
+
+/// Audit rows are written in the background; poll until `expected` rows for
+/// the organization have landed.
+async fn wait_for_audit_rows(
+    database: &Arc<database::Database>,
+    organization_id: uuid::Uuid,
+    expected: usize,
+) -> Vec<AuditRow> {
+    let client = database
+        .pool()
+        .get()
+        .await
+        .expect("Failed to get database connection");
+    for _ in 0..50 {
+        let rows = client
+            .query(
+                "SELECT model, request_hash, response_hash, request_body, response_body
+                 FROM completion_audit_log WHERE organization_id = $1 ORDER BY created_at",
+                &[&organization_id],
+            )
+            .await
+            .expect("Failed to query audit log");
+        if rows.len() >= expected {
+            return rows
+                .into_iter()
+                .map(|row| AuditRow {
+                    model: row.get("model"),
+                    request_hash: row.get("request_hash"),
+                    response_hash: row.get("response_hash"),
+                    request_body: row.get("request_body"),
+                    response_body: row.get("response_body"),
+                })
+                .collect();
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    panic!("expected {expected} audit rows for organization {organization_id}");
+}
+
+fn request_body(stream: bool) -> serde_json::Value {
+    serde_json::json!({
+        "model": E2E_QWEN_MODEL_NAME,
+        "messages": [{
+            "role": "user",
+            "content": format!("My email is {EMAIL} and my card is {CARD}.")
+        }],
+        "max_tokens": 20,
+        "stream": stream
+    })
+}
+
+#[tokio::test]
+async fn test_audit_entries_are_written_with_redacted_bodies() {
+    let (server, database) = setup_test_server_with_config_and_database(|c| {
+        c.audit_log.enabled = true;
+        c.audit_log.store_bodies = true;
+    })
+    .await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let mut expected_hashes = Vec::new();
+    for stream in [false, true] {
+        let body = request_body(stream);
+        expected_hashes.push(compute_sha256(&serde_json::to_string(&body).unwrap()));
+        let response = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .json(&body)
+            .await;
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+    }
+
+    let org_id = uuid::Uuid::parse_str(&org.id).unwrap();
+    let rows = wait_for_audit_rows(&database, org_id, 2).await;
+    assert_eq!(rows.len(), 2);
+
+    for (row, expected_hash) in rows.iter().zip(&expected_hashes) {
+        assert_eq!(row.model, E2E_QWEN_MODEL_NAME);
+        assert_eq!(
+            &row.request_hash, expected_hash,
+            "request hash is body_hash"
+        );
+        assert_eq!(row.response_hash.len(), 64);
+
+        let stored_request = row.request_body.as_deref().expect("request body stored");
+        assert!(
+            stored_request.contains("[REDACTED_EMAIL]"),
+            "{stored_request}"
+        );
+        assert!(
+            stored_request.contains("[REDACTED_CARD]"),
+            "{stored_request}"
+        );
+        assert!(!stored_request.contains(EMAIL));
+        assert!(!stored_request.contains(CARD));
+
+        let stored_response = row.response_body.as_deref().expect("response body stored");
+        assert!(!stored_response.is_empty());
+        assert!(!stored_response.contains(EMAIL));
+    }
+}
+
+#[tokio::test]
+async fn test_audit_entries_hash_only_without_body_storage() {
+    let (server, database) = setup_test_server_with_config_and_database(|c| {
+        c.audit_log.enabled = true;
+    })
+    .await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&request_body(false))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let org_id = uuid::Uuid::parse_str(&org.id).unwrap();
+    let rows = wait_for_audit_rows(&database, org_id, 1).await;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].request_hash.len(), 64);
+    assert!(rows[0].request_body.is_none());
+    assert!(rows[0].response_body.is_none());
+}
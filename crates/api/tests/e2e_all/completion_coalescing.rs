@@ -0,0 +1,174 @@
+// E2E tests for coalescing identical in-flight non-streaming chat completions
+
+use crate::common::*;
+use inference_providers::mock::ResponseTemplate;
+use std::time::Duration;
+
+fn chat_body(temperature: f32) -> serde_json::Value {
+    serde_json::json!({
+        "model": E2E_QWEN_MODEL_NAME,
+        "messages": [{"role": "user", "content": "Deterministic question"}],
+        "stream": false,
+        "max_tokens": 10,
+        "temperature": temperature
+    })
+}
+
+async fn post_pair(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    body: &serde_json::Value,
+) -> (axum_test::TestResponse, axum_test::TestResponse) {
+    let request = || {
+        server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .json(body)
+    };
+    tokio::join!(request(), request())
+}
+
+#[tokio::test]
+async fn test_identical_deterministic_requests_share_one_provider_call() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+    // Keep the first call in flight long enough for the second to join it.
+    mock_provider
+        .set_default_response(
+            ResponseTemplate::new("Coalesced reply")
+                .with_response_delay(Duration::from_millis(300)),
+        )
+        .await;
+
+    let calls_before = mock_provider.chat_completion_request_count();
+    let (first, second) = post_pair(&server, &api_key, &chat_body(0.0)).await;
+    assert_eq!(first.status_code(), 200, "{}", first.text());
+    assert_eq!(second.status_code(), 200, "{}", second.text());
+    assert_eq!(
+        mock_provider.chat_completion_request_count() - calls_before,
+        1,
+        "identical concurrent deterministic requests must share one upstream call"
+    );
+
+    let inference_id = |response: &axum_test::TestResponse| {
+        response
+            .headers()
+            .get("Inference-Id")
+            .expect("Missing Inference-Id header")
+            .to_str()
+            .unwrap()
+            .to_string()
+    };
+    let first_inference_id = inference_id(&first);
+    let second_inference_id = inference_id(&second);
+    assert_ne!(
+        first_inference_id, second_inference_id,
+        "each coalesced request reports the inference id it was billed under"
+    );
+
+    let first: serde_json::Value = first.json();
+    let second: serde_json::Value = second.json();
+    assert_eq!(first["id"], second["id"]);
+    assert_eq!(
+        first["choices"][0]["message"]["content"],
+        second["choices"][0]["message"]["content"]
+    );
+
+    // Both requests are billed.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let history = server
+        .get(&format!(
+            "/v1/organizations/{}/usage/history?limit=10&offset=0",
+            org.id
+        ))
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(history.status_code(), 200, "{}", history.text());
+    let history: api::routes::usage::UsageHistoryResponse = history.json();
+    assert_eq!(history.data.len(), 2, "each coalesced request is billed");
+    let mut billed: Vec<String> = history
+        .data
+        .iter()
+        .filter_map(|entry| entry.inference_id.clone())
+        .collect();
+    let mut returned = vec![first_inference_id, second_inference_id];
+    billed.sort();
+    returned.sort();
+    assert_eq!(billed, returned);
+}
+
+#[tokio::test]
+async fn test_non_deterministic_requests_are_not_coalesced() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    mock_provider
+        .set_default_response(
+            ResponseTemplate::new("Sampled reply").with_response_delay(Duration::from_millis(300)),
+        )
+        .await;
+
+    let calls_before = mock_provider.chat_completion_request_count();
+    let (first, second) = post_pair(&server, &api_key, &chat_body(0.7)).await;
+    assert_eq!(first.status_code(), 200, "{}", first.text());
+    assert_eq!(second.status_code(), 200, "{}", second.text());
+    assert_eq!(
+        mock_provider.chat_completion_request_count() - calls_before,
+        2
+    );
+}
+
+/// The coalescing key includes the workspace: workspace default params are
+/// applied after the body is hashed, so two workspaces must not share a call.
+#[tokio::test]
+async fn test_identical_requests_from_different_workspaces_are_not_coalesced() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let first_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let response = server
+        .post(format!("/v1/organizations/{}/workspaces", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .json(&api::routes::workspaces::CreateWorkspaceRequest {
+            name: format!("coalescing-{}", uuid::Uuid::new_v4()),
+            description: None,
+        })
+        .await;
+    assert_eq!(response.status_code(), 201, "{}", response.text());
+    let other_workspace = response.json::<api::routes::workspaces::WorkspaceResponse>();
+    let second_key =
+        create_api_key_in_workspace(&server, other_workspace.id, "Coalescing".to_string())
+            .await
+            .key
+            .unwrap();
+
+    mock_provider
+        .set_default_response(
+            ResponseTemplate::new("Per-workspace reply")
+                .with_response_delay(Duration::from_millis(300)),
+        )
+        .await;
+
+    let calls_before = mock_provider.chat_completion_request_count();
+    let request = |api_key: &str| {
+        server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .json(&chat_body(0.0))
+    };
+    let (first, second) = tokio::join!(request(&first_key), request(&second_key));
+    assert_eq!(first.status_code(), 200, "{}", first.text());
+    assert_eq!(second.status_code(), 200, "{}", second.text());
+    assert_eq!(
+        mock_provider.chat_completion_request_count() - calls_before,
+        2,
+        "requests from different workspaces must not share an upstream call"
+    );
+}
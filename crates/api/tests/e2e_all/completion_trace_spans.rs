@@ -0,0 +1,98 @@
+// E2E test for the completion service trace spans: one chat completion
+// exports `chat_completion` with `resolve_model` and `provider_request`
+// children, and the pool's `select_provider` / `upstream_request` spans
+// nested under `provider_request`.
+
+use crate::common::*;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+use tracing_subscriber::layer::SubscriberExt;
+
+fn span<'a>(spans: &'a [SpanData], name: &str) -> &'a SpanData {
+    spans
+        .iter()
+        .find(|span| span.name == name)
+        .unwrap_or_else(|| panic!("missing span {name}"))
+}
+
+fn attribute(span: &SpanData, key: &str) -> String {
+    span.attributes
+        .iter()
+        .find(|attribute| attribute.key.as_str() == key)
+        .unwrap_or_else(|| panic!("span {} has no {key} attribute", span.name))
+        .value
+        .as_str()
+        .into_owned()
+}
+
+fn assert_child_of(child: &SpanData, parent: &SpanData) {
+    assert_eq!(
+        child.parent_span_id,
+        parent.span_context.span_id(),
+        "{} should be a child of {}",
+        child.name,
+        parent.name
+    );
+}
+
+#[tokio::test]
+async fn test_chat_completion_exports_span_hierarchy() {
+    let exporter = InMemorySpanExporter::default();
+    let tracer_provider = SdkTracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer("e2e")));
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let server = setup_test_server().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "max_tokens": 10
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    // Background work spawned under the completion span (signature storage,
+    // usage recording) keeps it open briefly after the response.
+    let mut spans = Vec::new();
+    for _ in 0..50 {
+        tracer_provider.force_flush().unwrap();
+        spans = exporter.get_finished_spans().unwrap();
+        if spans.iter().any(|span| span.name == "chat_completion") {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+
+    let completion = span(&spans, "chat_completion");
+    let resolve_model = span(&spans, "resolve_model");
+    let provider_request = span(&spans, "provider_request");
+    let select_provider = span(&spans, "select_provider");
+    let upstream_request = span(&spans, "upstream_request");
+
+    assert_child_of(resolve_model, completion);
+    assert_child_of(provider_request, completion);
+    assert_child_of(select_provider, provider_request);
+    assert_child_of(upstream_request, provider_request);
+
+    assert_eq!(attribute(completion, "model"), model);
+    for span in [
+        resolve_model,
+        provider_request,
+        select_provider,
+        upstream_request,
+    ] {
+        assert_eq!(attribute(span, "model"), model, "{}", span.name);
+        assert_eq!(attribute(span, "outcome"), "ok", "{}", span.name);
+    }
+}
@@ -295,3 +295,44 @@ async fn test_update_concurrent_limit_multiple_times() {
     assert_eq!(body["concurrentLimit"], 50);
     assert_eq!(body["effectiveLimit"], 50);
 }
+
+/// The string `priority` hint is consumed by admission control: any string
+/// (including unknown ones, which count as `normal`) is accepted and none is
+/// forwarded. An integer `priority` is vLLM's and reaches the provider.
+#[tokio::test]
+async fn test_priority_hint_accepted_and_not_forwarded() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    for priority in [
+        serde_json::json!("high"),
+        serde_json::json!("low"),
+        serde_json::json!("urgent"),
+        serde_json::json!(10),
+    ] {
+        let response = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .json(&serde_json::json!({
+                "model": E2E_QWEN_MODEL_NAME,
+                "messages": [{"role": "user", "content": "Hello"}],
+                "max_tokens": 10,
+                "priority": priority
+            }))
+            .await;
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+
+        let params = mock_provider.last_chat_params().await.unwrap();
+        if priority.is_string() {
+            assert!(
+                !params.extra.contains_key("priority"),
+                "priority {priority} leaked to the provider"
+            );
+        } else {
+            assert_eq!(params.extra.get("priority"), Some(&priority));
+        }
+    }
+}
@@ -0,0 +1,64 @@
+// E2E tests for the context-window guard: prompt tokens + max_tokens must fit
+// in the model's context_length (128000 for the e2e Qwen model).
+
+use crate::common::*;
+
+const CONTEXT_LENGTH: i64 = 128_000;
+
+fn words(n: usize) -> String {
+    vec!["word"; n].join(" ")
+}
+
+#[tokio::test]
+async fn test_over_limit_prompt_is_rejected_before_dispatch() {
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    for stream in [false, true] {
+        let response = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .json(&serde_json::json!({
+                "model": E2E_QWEN_MODEL_NAME,
+                "messages": [{ "role": "user", "content": words(50) }],
+                "max_tokens": CONTEXT_LENGTH - 10,
+                "stream": stream
+            }))
+            .await;
+
+        assert_eq!(response.status_code(), 400, "{}", response.text());
+        let body: serde_json::Value = response.json();
+        let message = body["error"]["message"].as_str().unwrap_or_default();
+        assert!(
+            message.contains("context window of 128000 tokens"),
+            "stream={stream}: {message}"
+        );
+        assert!(
+            message.contains("50 prompt tokens"),
+            "stream={stream}: {message}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_under_limit_prompt_passes_guard() {
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{ "role": "user", "content": words(5) }],
+            "max_tokens": CONTEXT_LENGTH - 10,
+            "stream": false
+        }))
+        .await;
+
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
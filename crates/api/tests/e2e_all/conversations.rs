@@ -460,6 +460,98 @@ async fn test_streaming_responses_api() {
     }));
 }
 
+/// Streamed responses emit typed events in lifecycle order: created, then
+/// text deltas, then the done events, with `response.completed` last and
+/// response event sequence numbers strictly increasing.
+#[tokio::test]
+async fn test_streaming_responses_api_event_order() {
+    let server = setup_test_server().await;
+    let model_id = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let conversation = create_conversation(&server, api_key.clone()).await;
+
+    let response = server
+        .post("/v1/responses")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "conversation": { "id": conversation.id },
+            "input": "Hello, how are you?",
+            "max_output_tokens": 50,
+            "stream": true,
+            "model": model_id
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200);
+
+    let events: Vec<serde_json::Value> = response
+        .text()
+        .split("\n\n")
+        .filter_map(|chunk| {
+            chunk
+                .lines()
+                .find_map(|line| line.strip_prefix("data: "))
+                .and_then(|data| serde_json::from_str(data).ok())
+        })
+        .collect();
+    let types: Vec<&str> = events
+        .iter()
+        .filter_map(|e| e.get("type").and_then(|t| t.as_str()))
+        .collect();
+    let position = |event_type: &str| {
+        types
+            .iter()
+            .position(|t| *t == event_type)
+            .unwrap_or_else(|| panic!("missing {event_type} event in {types:?}"))
+    };
+
+    assert_eq!(types.first(), Some(&"response.created"));
+    assert_eq!(types.last(), Some(&"response.completed"));
+    let first_delta = position("response.output_text.delta");
+    let last_delta = types
+        .iter()
+        .rposition(|t| *t == "response.output_text.delta")
+        .expect("delta present");
+    assert!(position("response.created") < first_delta);
+    assert!(last_delta < position("response.output_text.done"));
+    assert!(position("response.output_text.done") < position("response.completed"));
+
+    // Background events (e.g. conversation.title.updated) are unnumbered.
+    let lifecycle: Vec<&serde_json::Value> = events
+        .iter()
+        .filter(|e| {
+            e.get("type")
+                .and_then(|t| t.as_str())
+                .is_some_and(|t| t.starts_with("response."))
+        })
+        .collect();
+    let sequence: Vec<u64> = lifecycle
+        .iter()
+        .filter_map(|e| e.get("sequence_number").and_then(|n| n.as_u64()))
+        .collect();
+    assert_eq!(
+        sequence.len(),
+        lifecycle.len(),
+        "every response event is numbered"
+    );
+    assert!(
+        sequence.windows(2).all(|w| w[0] < w[1]),
+        "sequence numbers must be strictly increasing: {sequence:?}"
+    );
+
+    // The deltas reassemble into the final text.
+    let streamed: String = events
+        .iter()
+        .filter(|e| e.get("type").and_then(|t| t.as_str()) == Some("response.output_text.delta"))
+        .filter_map(|e| e.get("delta").and_then(|d| d.as_str()))
+        .collect();
+    let done_text = events[position("response.output_text.done")]
+        .get("text")
+        .and_then(|t| t.as_str())
+        .expect("output_text.done carries the full text");
+    assert_eq!(streamed, done_text);
+}
+
 // ============================================
 // Usage Limit Enforcement Tests
 // ============================================
@@ -2637,6 +2729,145 @@ async fn test_clone_conversation_with_responses_and_items() {
     println!("✅ Clone is independent - modifying clone doesn't affect original");
 }
 
+#[tokio::test]
+async fn test_fork_conversation_at_item() {
+    let server = setup_test_server().await;
+    let model_id = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await; // $10.00 USD
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let conv_create_response = server
+        .post("/v1/conversations")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "metadata": { "title": "Branching Conversation" }
+        }))
+        .await;
+    assert_eq!(conv_create_response.status_code(), 201);
+    let original_conv = conv_create_response.json::<api::models::ConversationObject>();
+
+    for message in ["First turn", "Second turn"] {
+        create_response(
+            &server,
+            original_conv.id.clone(),
+            model_id.clone(),
+            message.to_string(),
+            50,
+            api_key.clone(),
+        )
+        .await;
+    }
+
+    let original_items =
+        list_conversation_items(&server, original_conv.id.clone(), api_key.clone()).await;
+    assert!(
+        original_items.data.len() >= 4,
+        "Should have at least 4 items (2 user messages + 2 assistant responses)"
+    );
+
+    // Fork after the first turn's assistant reply (second item).
+    let fork_point = original_items.data[1].id().to_string();
+    let fork_response = server
+        .post(format!("/v1/conversations/{}/fork", original_conv.id).as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({ "up_to_item_id": fork_point }))
+        .await;
+    assert_eq!(fork_response.status_code(), 201);
+    let forked_conv = fork_response.json::<api::models::ConversationObject>();
+
+    assert_ne!(forked_conv.id, original_conv.id);
+    assert_eq!(
+        forked_conv
+            .metadata
+            .get("parent_conversation_id")
+            .and_then(|v| v.as_str()),
+        Some(original_conv.id.as_str())
+    );
+    assert_eq!(
+        forked_conv.metadata.get("title").and_then(|v| v.as_str()),
+        Some("Branching Conversation")
+    );
+
+    let forked_items =
+        list_conversation_items(&server, forked_conv.id.clone(), api_key.clone()).await;
+    assert_eq!(
+        forked_items.data.len(),
+        2,
+        "Fork should hold only the items up to the fork point"
+    );
+    for (orig_item, forked_item) in original_items.data.iter().zip(forked_items.data.iter()) {
+        assert_ne!(orig_item.id(), forked_item.id(), "Item IDs should be new");
+        match (orig_item, forked_item) {
+            (
+                api::models::ConversationItem::Message {
+                    content: orig_content,
+                    role: orig_role,
+                    ..
+                },
+                api::models::ConversationItem::Message {
+                    content: forked_content,
+                    role: forked_role,
+                    ..
+                },
+            ) => {
+                assert_eq!(orig_role, forked_role);
+                assert_eq!(
+                    serde_json::to_value(orig_content).unwrap(),
+                    serde_json::to_value(forked_content).unwrap()
+                );
+            }
+            other => panic!("Expected message items, got {other:?}"),
+        }
+    }
+
+    // The parent conversation is untouched.
+    let original_after =
+        list_conversation_items(&server, original_conv.id.clone(), api_key.clone()).await;
+    assert_eq!(original_after.data.len(), original_items.data.len());
+
+    // Forking without a body copies every item.
+    let full_fork_response = server
+        .post(format!("/v1/conversations/{}/fork", original_conv.id).as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(full_fork_response.status_code(), 201);
+    let full_fork = full_fork_response.json::<api::models::ConversationObject>();
+    let full_fork_items =
+        list_conversation_items(&server, full_fork.id.clone(), api_key.clone()).await;
+    assert_eq!(full_fork_items.data.len(), original_items.data.len());
+}
+
+#[tokio::test]
+async fn test_fork_conversation_rejects_foreign_item_and_conversation() {
+    let server = setup_test_server().await;
+    let (api_key, _) = create_org_and_api_key(&server).await;
+    let (other_api_key, _) = create_org_and_api_key(&server).await;
+
+    let conversation = server
+        .post("/v1/conversations")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({}))
+        .await
+        .json::<api::models::ConversationObject>();
+
+    // An item ID that is not part of the conversation is a bad fork point.
+    let response = server
+        .post(format!("/v1/conversations/{}/fork", conversation.id).as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "up_to_item_id": format!("msg_{}", uuid::Uuid::new_v4().simple())
+        }))
+        .await;
+    assert_eq!(response.status_code(), 400);
+
+    // Another workspace cannot fork the conversation.
+    let response = server
+        .post(format!("/v1/conversations/{}/fork", conversation.id).as_str())
+        .add_header("Authorization", format!("Bearer {other_api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 404);
+}
+
 #[tokio::test]
 async fn test_clone_pinned_and_archived_conversation() {
     let server = setup_test_server().await;
@@ -2926,7 +3157,7 @@ async fn test_combined_conversation_operations() {
         .json(&serde_json::json!({
             "metadata": {
                 "title": "Test Conversation",
-                "tags": ["important", "work"]
+                "tags": "important,work"
             }
         }))
         .await;
@@ -2956,7 +3187,7 @@ async fn test_combined_conversation_operations() {
         .json(&serde_json::json!({
             "metadata": {
                 "title": "Renamed Conversation",
-                "tags": ["important", "work", "updated"]
+                "tags": "important,work,updated"
             }
         }))
         .await;
@@ -3904,3 +4135,122 @@ async fn test_responses_api_with_json_schema() {
         panic!("Expected Message output item");
     }
 }
+
+#[tokio::test]
+async fn test_search_conversation_items() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let conversation = create_conversation(&server, api_key.clone()).await;
+
+    let response = server
+        .post(format!("/v1/conversations/{}/items", conversation.id).as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "items": [
+                {"type": "message", "role": "user", "content": [{"type": "input_text", "text": "Where is the Eiffel Tower?"}]},
+                {"type": "message", "role": "assistant", "content": [{"type": "output_text", "text": "The eiffel tower is in Paris.", "annotations": []}]},
+                {"type": "message", "role": "user", "content": [{"type": "input_text", "text": "Thanks! 100% helpful"}]}
+            ]
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let created = response.json::<api::models::ConversationItemList>();
+
+    // Case-insensitive substring match, returned in conversation order.
+    let response = server
+        .get(
+            format!(
+                "/v1/conversations/{}/items/search?q=EIFFEL",
+                conversation.id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let results = response.json::<api::models::ConversationItemList>();
+    assert_eq!(results.data.len(), 2);
+    assert_eq!(results.first_id, created.first_id);
+    assert!(!results.has_more);
+
+    // Pagination with limit + after cursor.
+    let response = server
+        .get(
+            format!(
+                "/v1/conversations/{}/items/search?q=eiffel&limit=1",
+                conversation.id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    let page1 = response.json::<api::models::ConversationItemList>();
+    assert_eq!(page1.data.len(), 1);
+    assert!(page1.has_more);
+    let response = server
+        .get(
+            format!(
+                "/v1/conversations/{}/items/search?q=eiffel&limit=1&after={}",
+                conversation.id, page1.last_id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    let page2 = response.json::<api::models::ConversationItemList>();
+    assert_eq!(page2.data.len(), 1);
+    assert!(!page2.has_more);
+    assert_ne!(page2.first_id, page1.first_id);
+
+    // LIKE metacharacters are matched literally.
+    let response = server
+        .get(
+            format!(
+                "/v1/conversations/{}/items/search?q=100%25",
+                conversation.id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(
+        response
+            .json::<api::models::ConversationItemList>()
+            .data
+            .len(),
+        1
+    );
+    let response = server
+        .get(format!("/v1/conversations/{}/items/search?q=%25", conversation.id).as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(
+        response
+            .json::<api::models::ConversationItemList>()
+            .data
+            .len(),
+        1
+    );
+
+    // No match is an empty list; a missing query is a 400.
+    let response = server
+        .get(
+            format!(
+                "/v1/conversations/{}/items/search?q=louvre",
+                conversation.id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert!(response
+        .json::<api::models::ConversationItemList>()
+        .data
+        .is_empty());
+    let response = server
+        .get(format!("/v1/conversations/{}/items/search", conversation.id).as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 400);
+}
@@ -451,3 +451,139 @@ async fn test_missing_type_field_returns_error() {
 
     assert!(response.status_code() == 400 || response.status_code() == 422);
 }
+
+/// Test a spend limit without a currency defaults to USD
+#[tokio::test]
+async fn test_missing_currency_defaults_to_usd() {
+    let server = setup_test_server().await;
+    let org = create_org(&server).await;
+
+    let response = update_limits_raw(
+        &server,
+        &org.id,
+        serde_json::json!({
+            "type": "grant",
+            "spendLimit": { "amount": 10_000_000_000i64 },
+            "changedBy": "admin@test.com",
+            "changeReason": "Test default currency"
+        }),
+    )
+    .await;
+
+    assert_eq!(response.status_code(), 200);
+    let updated = response.json::<api::models::UpdateOrganizationLimitsResponse>();
+    assert_eq!(updated.spend_limit.currency, "USD");
+    assert_eq!(updated.spend_limit.amount, 10_000_000_000);
+}
+
+/// Test a currency without a configured rate is rejected instead of being read as USD
+#[tokio::test]
+async fn test_unknown_currency_is_rejected() {
+    let server = setup_test_server().await;
+    let org = create_org(&server).await;
+
+    let response = update_limits_raw(
+        &server,
+        &org.id,
+        serde_json::json!({
+            "type": "payment",
+            "spendLimit": { "amount": 10_000_000_000i64, "currency": "XYZ" },
+            "changedBy": "admin@test.com",
+            "changeReason": "Test unknown currency"
+        }),
+    )
+    .await;
+
+    assert_eq!(response.status_code(), 400);
+    let error = response.json::<api::models::ErrorResponse>();
+    assert_eq!(error.error.r#type, "invalid_limits");
+    assert_eq!(
+        error.error.message,
+        "Unsupported currency 'XYZ'; supported currencies: USD, USDT"
+    );
+}
+
+/// Test a configured currency is stored as submitted and converted to USD for the spend limit
+#[tokio::test]
+async fn test_configured_currency_is_converted_to_usd() {
+    let server = setup_test_server_with_config(|config| {
+        config.currency = config::CurrencyConfig::parse("EUR=1.08").unwrap();
+    })
+    .await;
+    let org = create_org(&server).await;
+
+    let eur = add_credits_with_type(
+        &server,
+        &org.id,
+        "payment",
+        Some("stripe"),
+        10_000_000_000,
+        "eur",
+        &get_session_id(),
+    )
+    .await;
+    assert_eq!(eur.spend_limit.currency, "EUR");
+    assert_eq!(eur.spend_limit.amount, 10_000_000_000);
+    add_credits_with_type(
+        &server,
+        &org.id,
+        "grant",
+        Some("nearai"),
+        1_000_000_000,
+        "USD",
+        &get_session_id(),
+    )
+    .await;
+
+    let response = server
+        .get(format!("/v1/organizations/{}/usage/balance", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200);
+    let balance = response.json::<api::routes::usage::OrganizationBalanceResponse>();
+
+    // €10 at 1.08 plus $1
+    assert_eq!(balance.spend_limit, Some(11_800_000_000));
+    assert!(balance.credit_limits.iter().any(|limit| {
+        limit.credit_type == "payment" && limit.currency == "EUR" && limit.amount == 10_000_000_000
+    }));
+}
+
+/// Test a stored credit row in a currency without a configured rate is
+/// counted at face value instead of failing the balance and usage checks
+#[tokio::test]
+async fn test_legacy_currency_row_is_counted_as_usd() {
+    let (server, database) = setup_test_server_with_database().await;
+    let org = create_org(&server).await;
+    add_credits_with_type(
+        &server,
+        &org.id,
+        "payment",
+        Some("stripe"),
+        10_000_000_000,
+        "USD",
+        &get_session_id(),
+    )
+    .await;
+
+    let org_id = uuid::Uuid::parse_str(&org.id).unwrap();
+    let client = database.pool().get().await.expect("db connection");
+    client
+        .execute(
+            "UPDATE organization_limits_history SET currency = 'XYZ' \
+             WHERE organization_id = $1",
+            &[&org_id],
+        )
+        .await
+        .expect("rewrite currency");
+
+    let response = server
+        .get(format!("/v1/organizations/{}/usage/balance", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let balance = response.json::<api::routes::usage::OrganizationBalanceResponse>();
+    assert_eq!(balance.spend_limit, Some(10_000_000_000));
+}
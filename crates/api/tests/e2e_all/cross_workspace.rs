@@ -113,6 +113,23 @@ async fn test_cross_workspace_conversation_operations_denied() {
         "foreign and unknown conversation-items 404 bodies must be identical"
     );
 
+    // Search items: foreign and unknown IDs return identical 404s.
+    let foreign_search = server
+        .get(format!("/v1/conversations/{}/items/search?q=hello", conv_a.id).as_str())
+        .add_header("Authorization", format!("Bearer {key_b}"))
+        .await;
+    let unknown_search = server
+        .get(format!("/v1/conversations/{unknown_conv}/items/search?q=hello").as_str())
+        .add_header("Authorization", format!("Bearer {key_b}"))
+        .await;
+    assert_eq!(foreign_search.status_code(), 404);
+    assert_eq!(unknown_search.status_code(), 404);
+    assert_eq!(
+        foreign_search.text(),
+        unknown_search.text(),
+        "foreign and unknown conversation-search 404 bodies must be identical"
+    );
+
     // POST items (backfill) into a foreign conversation: 404, nothing created.
     let foreign_create_items = server
         .post(format!("/v1/conversations/{}/items", conv_a.id).as_str())
@@ -413,10 +430,11 @@ async fn test_cross_workspace_responses_api_denied() {
     assert_eq!(own_prev.status_code(), 200);
 }
 
-/// Response DELETE/cancel are not implemented: they must be authenticated,
-/// return 501 for everyone, and cause no state change anywhere.
+/// Response DELETE is not implemented: it must be authenticated, return 501
+/// for everyone, and cause no state change anywhere. Cancel is scoped to the
+/// owning workspace: a foreign cancel is an indistinguishable 404.
 #[tokio::test]
-async fn test_response_delete_and_cancel_unsupported_but_safe() {
+async fn test_response_delete_unsupported_and_cancel_workspace_scoped() {
     let server = setup_test_server().await;
     let model_id = setup_qwen_model(&server).await;
 
@@ -453,12 +471,20 @@ async fn test_response_delete_and_cancel_unsupported_but_safe() {
         .await;
     assert_eq!(foreign_delete.status_code(), 501);
 
-    // Foreign cancel: 501 as well.
+    // Foreign cancel: 404, as if the response did not exist.
     let foreign_cancel = server
         .post(format!("/v1/responses/{}/cancel", response_a.id).as_str())
         .add_header("Authorization", format!("Bearer {key_b}"))
         .await;
-    assert_eq!(foreign_cancel.status_code(), 501);
+    assert_eq!(foreign_cancel.status_code(), 404);
+
+    // The response itself was not cancelled: it is still finished, so the
+    // owner's own cancel is rejected as not in progress.
+    let owner_cancel = server
+        .post(format!("/v1/responses/{}/cancel", response_a.id).as_str())
+        .add_header("Authorization", format!("Bearer {key_a}"))
+        .await;
+    assert_eq!(owner_cancel.status_code(), 400);
 
     // No state change: the owner's conversation items are intact.
     assert_eq!(count_items(&server, &conv_a.id, &key_a).await, items_before);
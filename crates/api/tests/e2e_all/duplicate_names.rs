@@ -354,6 +354,7 @@ async fn test_update_workspace_name_to_duplicate_returns_409() {
         name: Some(ws2_name.clone()),
         description: None,
         settings: None,
+        default_params: None,
     };
 
     let update_response = server
@@ -398,6 +399,7 @@ async fn test_update_workspace_name_to_unique_succeeds() {
         name: Some(new_name.clone()),
         description: None,
         settings: None,
+        default_params: None,
     };
 
     let update_response = server
@@ -444,6 +446,7 @@ async fn test_update_workspace_name_to_same_name_succeeds() {
         name: Some(workspace_name.clone()),
         description: None,
         settings: None,
+        default_params: None,
     };
 
     let update_response = server
@@ -6,7 +6,7 @@ use crate::common::*;
 use api::models::{BatchUpdateModelApiRequest, ErrorResponse};
 
 /// Register an embedding model in the database for testing
-async fn setup_embedding_model(server: &axum_test::TestServer) -> String {
+pub async fn setup_embedding_model(server: &axum_test::TestServer) -> String {
     let mut batch = BatchUpdateModelApiRequest::new();
     batch.insert(
         "Qwen/Qwen3-Embedding-0.6B".to_string(),
@@ -0,0 +1,73 @@
+// E2E tests for the client `user` field: forwarding and per-end-user rate limiting.
+
+use crate::common::*;
+
+async fn post_chat(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    user: Option<serde_json::Value>,
+) -> axum_test::TestResponse {
+    let mut body = serde_json::json!({
+        "model": E2E_QWEN_MODEL_NAME,
+        "messages": [{"role": "user", "content": "Hello"}],
+        "stream": false,
+        "max_tokens": 10
+    });
+    if let Some(user) = user {
+        body["user"] = user;
+    }
+    server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&body)
+        .await
+}
+
+#[tokio::test]
+async fn test_client_user_is_forwarded_upstream() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let response = post_chat(&server, &api_key, Some(serde_json::json!("end-user-42"))).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let params = mock_provider.last_chat_params().await.unwrap();
+    assert_eq!(params.user.as_deref(), Some("end-user-42"));
+    assert!(!params.extra.contains_key("user"));
+
+    let response = post_chat(&server, &api_key, Some(serde_json::json!(42))).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+    let response = post_chat(&server, &api_key, Some(serde_json::json!("u".repeat(257)))).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_end_user_rate_limit_applies_per_end_user() {
+    let (server, _mock_provider) = setup_test_server_with_config_and_mock(|config| {
+        config.server.end_user_requests_per_minute = 2;
+    })
+    .await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+    let abuser = Some(serde_json::json!("abusive-end-user"));
+
+    for _ in 0..2 {
+        let response = post_chat(&server, &api_key, abuser.clone()).await;
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+    }
+    let response = post_chat(&server, &api_key, abuser.clone()).await;
+    assert_eq!(response.status_code(), 429, "{}", response.text());
+    assert!(
+        !response.text().contains("abusive-end-user"),
+        "the error must not echo the end-user identifier"
+    );
+
+    // Other end users of the same key, and requests without `user`, are unaffected.
+    let response = post_chat(&server, &api_key, Some(serde_json::json!("other-end-user"))).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let response = post_chat(&server, &api_key, None).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
@@ -12,8 +12,8 @@ async fn test_users_refresh_tokens_missing_authorization_message() {
 
     assert_eq!(response.status_code(), 401);
     let err = response.json::<api::models::ErrorResponse>();
-    assert_eq!(err.error.r#type, "unauthorized");
-    assert_eq!(err.error.message, "Missing authorization");
+    assert_eq!(err.error.r#type, "authentication_error");
+    assert_eq!(err.error.message, "Missing Authorization header");
 }
 
 #[tokio::test]
@@ -24,8 +24,8 @@ async fn test_users_me_missing_authorization_message() {
 
     assert_eq!(response.status_code(), 401);
     let err = response.json::<api::models::ErrorResponse>();
-    assert_eq!(err.error.r#type, "unauthorized");
-    assert_eq!(err.error.message, "Missing authorization");
+    assert_eq!(err.error.r#type, "authentication_error");
+    assert_eq!(err.error.message, "Missing Authorization header");
 }
 
 #[tokio::test]
@@ -39,10 +39,10 @@ async fn test_users_me_wrong_bearer_prefix_message() {
 
     assert_eq!(response.status_code(), 401);
     let err = response.json::<api::models::ErrorResponse>();
-    assert_eq!(err.error.r#type, "unauthorized");
+    assert_eq!(err.error.r#type, "authentication_error");
     assert_eq!(
         err.error.message,
-        "Authorization header does not start with 'Bearer '"
+        "Authorization header must use the Bearer scheme"
     );
 }
 
@@ -58,8 +58,8 @@ async fn test_list_organizations_missing_authorization_message() {
 
     assert_eq!(response.status_code(), 401);
     let err = response.json::<api::models::ErrorResponse>();
-    assert_eq!(err.error.r#type, "unauthorized");
-    assert_eq!(err.error.message, "Missing authorization");
+    assert_eq!(err.error.r#type, "authentication_error");
+    assert_eq!(err.error.message, "Missing Authorization header");
 }
 
 #[tokio::test]
@@ -124,8 +124,8 @@ async fn test_org_members_list_missing_authorization_message() {
 
     assert_eq!(response.status_code(), 401);
     let err = response.json::<api::models::ErrorResponse>();
-    assert_eq!(err.error.r#type, "unauthorized");
-    assert_eq!(err.error.message, "Missing authorization");
+    assert_eq!(err.error.r#type, "authentication_error");
+    assert_eq!(err.error.message, "Missing Authorization header");
 }
 
 #[tokio::test]
@@ -276,8 +276,8 @@ async fn test_create_workspace_missing_authorization_message() {
 
     assert_eq!(response.status_code(), 401);
     let err = response.json::<api::models::ErrorResponse>();
-    assert_eq!(err.error.r#type, "unauthorized");
-    assert_eq!(err.error.message, "Missing authorization");
+    assert_eq!(err.error.r#type, "authentication_error");
+    assert_eq!(err.error.message, "Missing Authorization header");
 }
 
 #[tokio::test]
@@ -293,8 +293,8 @@ async fn test_list_workspace_api_keys_missing_authorization_message() {
 
     assert_eq!(response.status_code(), 401);
     let err = response.json::<api::models::ErrorResponse>();
-    assert_eq!(err.error.r#type, "unauthorized");
-    assert_eq!(err.error.message, "Missing authorization");
+    assert_eq!(err.error.r#type, "authentication_error");
+    assert_eq!(err.error.message, "Missing Authorization header");
 }
 
 #[tokio::test]
@@ -928,10 +928,19 @@ async fn test_no_credits_denies_request() {
     let error = serde_json::from_str::<api::models::ErrorResponse>(&response.text())
         .expect("Failed to parse error response");
     println!("Error: {error:?}");
+    assert_eq!(error.error.r#type, "insufficient_quota");
     assert!(
-        error.error.r#type == "no_credits" || error.error.r#type == "no_limit_configured",
-        "Expected error type 'no_credits' or 'no_limit_configured'"
+        matches!(
+            error.error.code.as_deref(),
+            Some("no_credits" | "no_limit_configured")
+        ),
+        "Expected code 'no_credits' or 'no_limit_configured'"
     );
+    assert_eq!(
+        error.error.limit.as_ref().map(|l| l.limit_scope.as_str()),
+        Some("organization")
+    );
+    assert_eq!(error.error.limit.as_ref().and_then(|l| l.reset_at), None);
 }
 
 #[tokio::test]
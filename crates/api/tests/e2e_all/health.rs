@@ -1,4 +1,4 @@
-//! E2E test for the public health endpoint.
+//! E2E tests for the public health, liveness and readiness endpoints.
 //!
 //! Ported from infra-tests `check_health` (`tests/cloud_api_helpers.py`), which
 //! probes `GET /v1/health` on the live deployment. Here we assert the same
@@ -26,3 +26,46 @@ async fn test_health_endpoint_public_and_ok() {
         "health endpoint should report status=ok"
     );
 }
+
+#[tokio::test]
+async fn test_liveness_probe_ok() {
+    let server = setup_test_server().await;
+
+    let response = server.get("/healthz").await;
+
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    assert_eq!(response.json::<serde_json::Value>()["status"], "ok");
+}
+
+#[tokio::test]
+async fn test_readiness_probe_tracks_model_pool() {
+    let (server, pool, mock, _database) = setup_test_server_with_pool().await;
+
+    let ready = server.get("/readyz").await;
+    assert_eq!(ready.status_code(), 200, "{}", ready.text());
+    let body = ready.json::<serde_json::Value>();
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["checks"]["database"]["status"], "ok");
+    assert_eq!(body["checks"]["inference_pool"]["status"], "ok");
+
+    for model in pool.registered_model_names().await {
+        pool.unregister_provider(&model).await;
+    }
+
+    let unready = server.get("/readyz").await;
+    assert_eq!(unready.status_code(), 503, "{}", unready.text());
+    let body = unready.json::<serde_json::Value>();
+    assert_eq!(body["status"], "unavailable");
+    assert_eq!(body["checks"]["database"]["status"], "ok");
+    assert_eq!(body["checks"]["inference_pool"]["status"], "unhealthy");
+    assert_eq!(
+        body["checks"]["inference_pool"]["detail"],
+        "no models discovered"
+    );
+
+    pool.register_provider(E2E_QWEN_MODEL_NAME.to_string(), mock.clone())
+        .await;
+
+    let ready_again = server.get("/readyz").await;
+    assert_eq!(ready_again.status_code(), 200, "{}", ready_again.text());
+}
@@ -0,0 +1,112 @@
+// E2E tests for the configurable inference_id namespace and the legacy
+// NAMESPACE_DNS lookup kept during the transition.
+
+use crate::common::*;
+
+/// Run a non-streaming chat completion and return its provider id and
+/// `Inference-Id` header.
+async fn chat_completion(server: &axum_test::TestServer, api_key: &str) -> (String, uuid::Uuid) {
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "max_tokens": 10,
+            "stream": false
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let inference_id = response
+        .headers()
+        .get("Inference-Id")
+        .expect("Missing Inference-Id header")
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    let body: serde_json::Value = response.json();
+    (body["id"].as_str().unwrap().to_string(), inference_id)
+}
+
+#[tokio::test]
+async fn test_inference_id_hashes_under_configured_namespace() {
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let (chat_id, inference_id) = chat_completion(&server, &api_key).await;
+
+    assert_eq!(
+        inference_id,
+        uuid::Uuid::new_v5(&config::DEFAULT_INFERENCE_ID_NAMESPACE, chat_id.as_bytes())
+    );
+    assert_ne!(
+        inference_id,
+        uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_DNS, chat_id.as_bytes())
+    );
+
+    // The header value is the id stored on the usage row.
+    tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
+    let billing = server
+        .post("/v1/billing/costs")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({ "requestIds": [inference_id] }))
+        .await;
+    assert_eq!(billing.status_code(), 200);
+    let body: serde_json::Value = billing.json();
+    assert!(body["requests"][0]["costNanoUsd"].as_i64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn test_signature_lookup_resolves_usage_under_both_namespaces() {
+    let (server, _pool, _mock, database) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let client = database.pool().get().await.expect("db connection");
+
+    for namespace in [
+        config::DEFAULT_INFERENCE_ID_NAMESPACE,
+        uuid::Uuid::NAMESPACE_DNS,
+    ] {
+        let (chat_id, _) = chat_completion(&server, &api_key).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
+
+        // Rewrite the usage row as one recorded before provider request ids
+        // were stored, hashed under `namespace`, for a disconnected stream.
+        let legacy_id = uuid::Uuid::new_v5(&namespace, chat_id.as_bytes());
+        client
+            .execute(
+                "DELETE FROM chat_signatures WHERE chat_id = $1",
+                &[&chat_id],
+            )
+            .await
+            .expect("delete signature");
+        let updated = client
+            .execute(
+                "UPDATE organization_usage_log
+                 SET provider_request_id = NULL, inference_id = $2,
+                     stop_reason = 'client_disconnect'
+                 WHERE provider_request_id = $1",
+                &[&chat_id, &legacy_id],
+            )
+            .await
+            .expect("rewrite usage row");
+        assert_eq!(updated, 1);
+
+        let signature = server
+            .get(&format!("/v1/signature/{chat_id}?signing_algo=ecdsa"))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await;
+        assert_eq!(
+            signature.status_code(),
+            200,
+            "namespace {namespace}: {}",
+            signature.text()
+        );
+        let body: serde_json::Value = signature.json();
+        assert_eq!(body["error_code"], "STREAM_DISCONNECTED", "{body}");
+    }
+}
@@ -0,0 +1,146 @@
+// E2E tests for the standard list envelope
+// (`{ object: "list", data, has_more, first_id, last_id }`) on organization
+// members, workspaces and usage listings.
+
+use crate::common::*;
+use serde_json::{json, Value};
+
+/// Assert the envelope fields and return the page's `data` array.
+fn assert_list_envelope(body: &Value, expected_len: usize, has_more: bool) -> Vec<Value> {
+    assert_eq!(body["object"], "list", "{body}");
+    assert_eq!(body["has_more"], has_more, "{body}");
+    let data = body["data"]
+        .as_array()
+        .expect("data should be an array")
+        .clone();
+    assert_eq!(data.len(), expected_len, "{body}");
+    assert_eq!(body["first_id"], data[0]["id"], "{body}");
+    assert_eq!(body["last_id"], data[expected_len - 1]["id"], "{body}");
+    data
+}
+
+async fn get_json(server: &axum_test::TestServer, path: &str) -> Value {
+    let response = server
+        .get(path)
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    response.json()
+}
+
+#[tokio::test]
+async fn test_members_list_uses_standard_envelope() {
+    let (server, database) = setup_test_server_with_database().await;
+    let org = create_org(&server).await; // owner = member #1
+    let org_uuid = uuid::Uuid::parse_str(&org.id).expect("org id should be a uuid");
+
+    let second_user_id = uuid::Uuid::new_v4();
+    {
+        let client = database
+            .pool()
+            .get()
+            .await
+            .expect("Failed to get database connection");
+        client
+            .execute(
+                "INSERT INTO users (id, email, username, display_name, avatar_url, auth_provider, provider_user_id, is_active, created_at, updated_at)
+                 VALUES ($1, $2, $3, NULL, NULL, 'mock', $4, true, NOW(), NOW())",
+                &[
+                    &second_user_id,
+                    &format!("envelope-{second_user_id}@test.com"),
+                    &format!("envelope-{second_user_id}"),
+                    &format!("mock_envelope-{second_user_id}"),
+                ],
+            )
+            .await
+            .expect("Failed to insert second user");
+        client
+            .execute(
+                "INSERT INTO organization_members (organization_id, user_id, role) VALUES ($1, $2, 'member')",
+                &[&org_uuid, &second_user_id],
+            )
+            .await
+            .expect("Failed to insert second member");
+    }
+
+    let path = format!("/v1/organizations/{}/members", org.id);
+    let page0 = get_json(&server, &format!("{path}?limit=1&offset=0")).await;
+    let data = assert_list_envelope(&page0, 1, true);
+    // The legacy array carries the same items.
+    assert_eq!(page0["members"], json!(data));
+    assert_eq!(page0["total"], 2);
+
+    let page1 = get_json(&server, &format!("{path}?limit=1&offset=1")).await;
+    assert_list_envelope(&page1, 1, false);
+
+    let all = get_json(&server, &path).await;
+    assert_list_envelope(&all, 2, false);
+}
+
+#[tokio::test]
+async fn test_workspaces_list_uses_standard_envelope() {
+    let server = setup_test_server().await;
+    let org = create_org(&server).await;
+
+    for _ in 0..2 {
+        let response = server
+            .post(format!("/v1/organizations/{}/workspaces", org.id).as_str())
+            .add_header("Authorization", format!("Bearer {}", get_session_id()))
+            .json(&json!({ "name": format!("envelope-{}", uuid::Uuid::new_v4()) }))
+            .await;
+        assert_eq!(response.status_code(), 201, "{}", response.text());
+    }
+
+    let path = format!("/v1/organizations/{}/workspaces", org.id);
+    let all = get_json(&server, &path).await;
+    let total = all["total"].as_u64().expect("total") as usize;
+    assert!(total >= 2, "{all}");
+    let data = assert_list_envelope(&all, total, false);
+    assert_eq!(all["workspaces"], json!(data));
+
+    let first = get_json(&server, &format!("{path}?limit=1&offset=0")).await;
+    assert_list_envelope(&first, 1, true);
+
+    let last = get_json(&server, &format!("{path}?limit=1&offset={}", total - 1)).await;
+    assert_list_envelope(&last, 1, false);
+}
+
+#[tokio::test]
+async fn test_usage_history_uses_standard_envelope() {
+    let server = setup_test_server().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    for _ in 0..2 {
+        let response = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .json(&json!({
+                "model": model,
+                "messages": [{"role": "user", "content": "Hello"}],
+                "stream": false
+            }))
+            .await;
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+    }
+
+    // Usage is recorded asynchronously.
+    let path = format!("/v1/organizations/{}/usage/history", org.id);
+    let mut all = Value::Null;
+    for _ in 0..20 {
+        all = get_json(&server, &path).await;
+        if all["total"] == 2 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+    }
+    assert_list_envelope(&all, 2, false);
+
+    let first = get_json(&server, &format!("{path}?limit=1&offset=0")).await;
+    assert_list_envelope(&first, 1, true);
+
+    let last = get_json(&server, &format!("{path}?limit=1&offset=1")).await;
+    assert_list_envelope(&last, 1, false);
+}
@@ -0,0 +1,137 @@
+// E2E tests for `logprobs` / `top_logprobs` round-tripping from the provider to the client
+
+use crate::common::*;
+
+fn chat_body(stream: bool) -> serde_json::Value {
+    serde_json::json!({
+        "model": E2E_QWEN_MODEL_NAME,
+        "messages": [{"role": "user", "content": "Hello"}],
+        "stream": stream,
+        "max_tokens": 10,
+        "logprobs": true,
+        "top_logprobs": 2
+    })
+}
+
+fn assert_token_logprobs(entry: &serde_json::Value) {
+    assert!(entry["token"].is_string(), "token missing: {entry}");
+    assert!(entry["logprob"].is_f64(), "logprob missing: {entry}");
+    assert!(entry["bytes"].is_array(), "bytes missing: {entry}");
+    let top = entry["top_logprobs"]
+        .as_array()
+        .unwrap_or_else(|| panic!("top_logprobs missing: {entry}"));
+    assert_eq!(
+        top.len(),
+        2,
+        "top_logprobs should honor the request: {entry}"
+    );
+    assert_eq!(top[0]["token"], entry["token"]);
+}
+
+#[tokio::test]
+async fn test_logprobs_round_trip_non_stream() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&chat_body(false))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let params = mock_provider.last_chat_params().await.unwrap();
+    assert_eq!(params.extra.get("logprobs"), Some(&serde_json::json!(true)));
+    assert_eq!(
+        params.extra.get("top_logprobs"),
+        Some(&serde_json::json!(2))
+    );
+
+    let body: serde_json::Value = response.json();
+    let content = body["choices"][0]["logprobs"]["content"]
+        .as_array()
+        .unwrap_or_else(|| panic!("logprobs.content missing: {body}"));
+    assert!(!content.is_empty());
+    content.iter().for_each(assert_token_logprobs);
+
+    let text: String = content
+        .iter()
+        .map(|entry| entry["token"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        body["choices"][0]["message"]["content"].as_str(),
+        Some(text.as_str())
+    );
+}
+
+#[tokio::test]
+async fn test_logprobs_round_trip_stream() {
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&chat_body(true))
+        .await;
+    assert_eq!(response.status_code(), 200);
+    let body = response.text();
+
+    let mut content_chunks = 0;
+    for line in body.lines() {
+        let Some(data) = line.strip_prefix("data: ") else {
+            continue;
+        };
+        let Ok(chunk) = serde_json::from_str::<serde_json::Value>(data) else {
+            continue;
+        };
+        let Some(delta_content) = chunk
+            .pointer("/choices/0/delta/content")
+            .and_then(|v| v.as_str())
+        else {
+            continue;
+        };
+        let entries = chunk
+            .pointer("/choices/0/logprobs/content")
+            .and_then(|v| v.as_array())
+            .unwrap_or_else(|| panic!("streamed chunk missing logprobs: {chunk}"));
+        assert_eq!(entries.len(), 1);
+        assert_token_logprobs(&entries[0]);
+        assert_eq!(entries[0]["token"], delta_content);
+        content_chunks += 1;
+    }
+    assert!(content_chunks > 0, "no content chunks streamed: {body}");
+}
+
+#[tokio::test]
+async fn test_logprobs_absent_when_not_requested() {
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let mut request = chat_body(false);
+    request.as_object_mut().unwrap().remove("logprobs");
+    request.as_object_mut().unwrap().remove("top_logprobs");
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&request)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let body: serde_json::Value = response.json();
+    assert!(
+        body["choices"][0]
+            .get("logprobs")
+            .is_none_or(|v| v.is_null()),
+        "logprobs should be omitted: {body}"
+    );
+}
@@ -7,10 +7,13 @@ mod common;
 
 mod admin_activation_pricing_gate;
 mod admin_analytics;
+mod admin_batch_upsert_dry_run;
 mod admin_deprecate_model;
+mod admin_discovery_refresh;
 mod admin_invitation_email_deliveries;
 mod admin_list_models;
 mod admin_organization_members;
+mod admin_pool_state;
 mod admin_pricing_changes;
 mod admin_provider_attribution_model_revenue;
 mod admin_provider_attribution_platform;
@@ -19,24 +22,34 @@ mod admin_schema_compatibility;
 mod admin_services;
 mod api_keys;
 mod attestation_auth;
+mod attestation_quote;
+mod attestation_verify_model;
 mod audio_image;
 mod audio_transcriptions;
+mod auth_errors;
 mod auth_tokens;
 mod auto_redact;
 mod auto_redact_adversarial;
 mod backend_output_limits;
+mod batches;
 mod billing_and_models;
+mod chat_debug_prompt;
 mod chat_encryption;
 mod check_api_key;
 mod chutes_catalog;
 mod client_disconnect;
+mod completion_audit_log;
+mod completion_coalescing;
+mod completion_trace_spans;
 mod concurrent_limit;
+mod context_window;
 mod conversations;
 mod credit_types;
 mod cross_workspace;
 mod deser_error_envelope;
 mod duplicate_names;
 mod embeddings;
+mod end_user;
 mod error_msg;
 mod external_providers;
 mod feature_requests;
@@ -45,37 +58,71 @@ mod function_tools;
 mod general;
 mod glm52_tier_routing;
 mod health;
+mod inference_id_namespace;
 mod invitations;
 mod ita_attestation;
+mod list_envelope;
+mod logprobs;
+mod max_fallback_attempts;
 mod mcp;
+mod mcp_auto_tools;
+mod mcp_connectors;
 mod mcp_server;
 mod message_metadata;
+mod metadata_limits;
 mod model_alias_transparency;
+mod model_capabilities;
 mod model_history_test;
+mod model_max_tokens_limits;
+mod model_pricing;
+mod model_request_timeout;
 mod multiturn_tools;
 mod near_auth;
 mod oauth_frontend_callback;
+mod oidc_login;
 mod openrouter_params;
+mod org_allowed_models;
+mod org_model_aliases;
+mod org_model_quotas;
+mod org_seat_limit;
 mod org_system_prompt;
+mod organization_cursor_pagination;
 mod pagination_validation;
 mod patroni_failover;
 mod privacy_classify;
 mod privacy_redact;
 mod provider_errors;
+mod provider_header_passthrough;
 mod reasoning;
+mod refresh_token_rotation;
 mod reporting_usage;
 mod repositories;
+mod request_body_limit;
 mod request_id_contract;
 mod rerank;
+mod response_cancellation;
+mod response_input_items_include;
 mod response_signature_verification;
 mod score;
+mod seed_fingerprint;
 mod serving_provider;
+mod session_devices;
 mod session_logout;
 mod signature_verification;
+mod stream_dedup;
+mod stream_keepalive;
+mod tool_choice;
+mod usage_anomaly;
 mod usage_chat_completions;
+mod usage_fallback;
+mod usage_history_created_range;
+mod usage_history_csv;
 mod usage_provider_attribution;
 mod usage_recording;
 mod usage_responses;
 mod vpc_login;
 mod web_context_search;
 mod web_search_citations;
+mod workspace_default_params;
+mod workspace_monthly_budget;
+mod workspace_transfer;
@@ -0,0 +1,100 @@
+// E2E tests for the per-request X-Max-Fallback-Attempts cap on provider fallback.
+
+use crate::common::*;
+use inference_providers::mock::MockProvider;
+use inference_providers::CompletionError;
+use std::sync::Arc;
+
+/// Route the Qwen model to `count` providers that all reject the request with
+/// a fall-through "model not found", so each provider is tried at most once.
+async fn register_failing_providers(
+    pool: &services::inference_provider_pool::InferenceProviderPool,
+    count: usize,
+) -> Vec<Arc<MockProvider>> {
+    let mut providers = Vec::new();
+    for _ in 0..count {
+        let provider = Arc::new(MockProvider::new());
+        provider
+            .set_error_override(Some(CompletionError::HttpError {
+                status_code: 404,
+                message: "model not found".to_string(),
+                is_external: false,
+            }))
+            .await;
+        providers.push(provider);
+    }
+    pool.register_providers(
+        providers
+            .iter()
+            .map(|p| {
+                let provider: Arc<dyn inference_providers::InferenceProvider + Send + Sync> =
+                    p.clone();
+                (E2E_QWEN_MODEL_NAME.to_string(), provider)
+            })
+            .collect(),
+    )
+    .await;
+    providers
+}
+
+fn chat_body() -> serde_json::Value {
+    serde_json::json!({
+        "model": E2E_QWEN_MODEL_NAME,
+        "messages": [{"role": "user", "content": "Hello"}],
+        "stream": false
+    })
+}
+
+#[tokio::test]
+async fn test_max_fallback_attempts_header_caps_providers_tried() {
+    let (server, pool, _mock, _db) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    let providers = register_failing_providers(&pool, 5).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .add_header("X-Max-Fallback-Attempts", "2")
+        .json(&chat_body())
+        .await;
+    assert!(
+        !response.status_code().is_success(),
+        "every provider fails: {}",
+        response.text()
+    );
+
+    let counts: Vec<usize> = providers
+        .iter()
+        .map(|p| p.chat_completion_request_count())
+        .collect();
+    assert_eq!(counts.iter().sum::<usize>(), 2, "{counts:?}");
+    assert!(counts.iter().all(|&n| n <= 1), "{counts:?}");
+}
+
+#[tokio::test]
+async fn test_invalid_max_fallback_attempts_header_is_rejected() {
+    let (server, pool, _mock, _db) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    let providers = register_failing_providers(&pool, 2).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    for value in ["0", "-1", "two"] {
+        let response = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .add_header("X-Max-Fallback-Attempts", value)
+            .json(&chat_body())
+            .await;
+        assert_eq!(response.status_code(), 400, "{value}: {}", response.text());
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["error"]["type"], "invalid_parameter");
+    }
+    assert!(providers
+        .iter()
+        .all(|p| p.chat_completion_request_count() == 0));
+}
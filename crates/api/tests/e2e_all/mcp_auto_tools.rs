@@ -0,0 +1,161 @@
+// E2E tests for server-side MCP tool execution in chat completions
+
+use crate::common::*;
+use crate::mcp_connectors::{register_connector, start_mock_mcp_server};
+use inference_providers::mock::{RequestMatcher, ResponseTemplate, ToolCall};
+use serde_json::{json, Value};
+
+const USER_PROMPT: &str = "What's the weather in Paris?";
+const FINAL_ANSWER: &str = "It is sunny in Paris.";
+
+/// Register the mock MCP server in the org's workspace and script the model
+/// to call its `echo` tool once (only when the tool is offered), then answer.
+async fn setup_tool_calling_model(
+    server: &axum_test::TestServer,
+    mock: &inference_providers::mock::MockProvider,
+    api_key: &str,
+) {
+    let server_url = start_mock_mcp_server().await;
+    let response = register_connector(
+        server,
+        api_key,
+        json!({"name": "weather-tools", "server_url": server_url}),
+    )
+    .await;
+    assert_eq!(response.status_code(), 201, "{}", response.text());
+
+    mock.set_default_response(ResponseTemplate::new(FINAL_ANSWER))
+        .await;
+    mock.when(RequestMatcher::PromptWithTools {
+        prompt: USER_PROMPT.to_string(),
+        tool_names: vec!["echo".to_string()],
+    })
+    .respond_with(
+        ResponseTemplate::new("")
+            .with_tool_calls(vec![ToolCall::new("echo", r#"{"text":"sunny in Paris"}"#)]),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_auto_tool_execution_runs_mcp_tool_and_returns_final_answer() {
+    let (server, _, mock, _) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    setup_tool_calling_model(&server, &mock, &api_key).await;
+
+    let request_body = json!({
+        "model": model,
+        "messages": [{"role": "user", "content": USER_PROMPT}],
+        "stream": false,
+        "auto_tool_execution": true
+    });
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&request_body)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let response_text = response.text();
+    let body: Value = response.json();
+    assert_eq!(body["choices"][0]["message"]["content"], FINAL_ANSWER);
+    assert!(body["choices"][0]["message"]["tool_calls"].is_null());
+
+    // The final turn is returned byte-for-byte, so its signature verifies
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    let chat_id = body["id"].as_str().expect("completion id");
+    let signature = server
+        .get(format!("/v1/signature/{chat_id}?model={model}&signing_algo=ecdsa").as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(signature.status_code(), 200, "{}", signature.text());
+    let signature: Value = signature.json();
+    let signed_text = signature["text"].as_str().expect("signature text");
+    assert_eq!(
+        signed_text.split(':').nth(1),
+        Some(compute_sha256(&response_text).as_str())
+    );
+    // ...over the body the final turn sent, which carries the tool turns the
+    // client's body never had
+    assert_ne!(
+        signed_text.split(':').next(),
+        Some(compute_sha256(&request_body.to_string()).as_str())
+    );
+
+    // The second model call saw the tool result, and the opt-in field was
+    // never forwarded upstream
+    let params = mock.last_chat_params().await.expect("model was called");
+    let tool_message = params
+        .messages
+        .iter()
+        .find(|m| m.tool_call_id.is_some())
+        .expect("tool result message forwarded to the model");
+    assert_eq!(tool_message.content, Some(json!("sunny in Paris")));
+    assert!(!params.extra.contains_key("auto_tool_execution"));
+}
+
+#[tokio::test]
+async fn test_auto_tool_execution_streams_tool_events() {
+    let (server, _, mock, _) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    setup_tool_calling_model(&server, &mock, &api_key).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "model": model,
+            "messages": [{"role": "user", "content": USER_PROMPT}],
+            "stream": true,
+            "stream_options": {"include_usage": true},
+            "auto_tool_execution": true
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    assert!(response.headers().get("Inference-Id").is_some());
+    let text = response.text();
+
+    let call_at = text.find("event: tool_call").expect("tool_call event");
+    let result_at = text.find("event: tool_result").expect("tool_result event");
+    let answer_at = text.find(FINAL_ANSWER).expect("final answer chunk");
+    assert!(call_at < result_at && result_at < answer_at, "{text}");
+    assert!(text.contains("sunny in Paris"));
+    assert!(text.trim_end().ends_with("data: [DONE]"), "{text}");
+
+    // include_usage adds a trailing usage chunk with no choices
+    let usage_chunk: Value = text
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .filter_map(|data| serde_json::from_str::<Value>(data).ok())
+        .find(|chunk| chunk["choices"].as_array().is_some_and(|c| c.is_empty()))
+        .expect("usage chunk");
+    assert!(usage_chunk["usage"]["total_tokens"].as_u64().unwrap_or(0) > 0);
+}
+
+#[tokio::test]
+async fn test_tool_calls_are_returned_without_opt_in() {
+    let (server, _, mock, _) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    setup_tool_calling_model(&server, &mock, &api_key).await;
+
+    // Without the opt-in, MCP tools are not offered and nothing is executed
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "model": model,
+            "messages": [{"role": "user", "content": USER_PROMPT}],
+            "stream": false
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: Value = response.json();
+    assert_eq!(body["choices"][0]["message"]["content"], FINAL_ANSWER);
+    let params = mock.last_chat_params().await.expect("model was called");
+    assert!(params.tools.is_none());
+}
@@ -0,0 +1,217 @@
+// E2E tests for workspace-scoped MCP connector registration and tool invocation
+
+use crate::common::*;
+use api::routes::mcp_connectors::{
+    InvokeMcpToolResponse, McpConnectorListResponse, McpConnectorResponse,
+};
+use axum::{http::StatusCode, response::IntoResponse, routing::post, Json, Router};
+use serde_json::{json, Value};
+
+/// Minimal streamable-HTTP MCP server exposing an `echo` tool and a `fail`
+/// tool that reports a tool-level error.
+async fn mock_mcp_handler(Json(request): Json<Value>) -> axum::response::Response {
+    let Some(id) = request.get("id").cloned() else {
+        // Notifications (e.g. notifications/initialized) carry no id
+        return StatusCode::ACCEPTED.into_response();
+    };
+    let result = match request["method"].as_str().unwrap_or_default() {
+        "initialize" => json!({
+            "protocolVersion": "2024-11-05",
+            "capabilities": {"tools": {}},
+            "serverInfo": {"name": "mock-mcp", "version": "1.0.0"}
+        }),
+        "tools/list" => json!({
+            "tools": [
+                {"name": "echo", "inputSchema": {"type": "object"}},
+                {"name": "fail", "inputSchema": {"type": "object"}}
+            ]
+        }),
+        "tools/call" => match request["params"]["name"].as_str() {
+            Some("echo") => json!({
+                "content": [{
+                    "type": "text",
+                    "text": request["params"]["arguments"]["text"].as_str().unwrap_or_default()
+                }],
+                "isError": false
+            }),
+            _ => json!({
+                "content": [{"type": "text", "text": "tool exploded"}],
+                "isError": true
+            }),
+        },
+        _ => {
+            return Json(json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": {"code": -32601, "message": "Method not found"}
+            }))
+            .into_response()
+        }
+    };
+    Json(json!({"jsonrpc": "2.0", "id": id, "result": result})).into_response()
+}
+
+pub async fn start_mock_mcp_server() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind mock MCP server");
+    let addr = listener.local_addr().unwrap();
+    let app = Router::new().route("/mcp", post(mock_mcp_handler));
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    format!("http://{addr}/mcp")
+}
+
+pub async fn register_connector(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    body: Value,
+) -> axum_test::TestResponse {
+    server
+        .post("/v1/mcp/connectors")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&body)
+        .await
+}
+
+async fn invoke_tool(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    connector_id: &str,
+    tool: &str,
+    arguments: Value,
+) -> axum_test::TestResponse {
+    server
+        .post(format!("/v1/mcp/connectors/{connector_id}/tools/{tool}/invoke").as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({ "arguments": arguments }))
+        .await
+}
+
+#[tokio::test]
+async fn test_mcp_connector_register_list_invoke_delete() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let server_url = start_mock_mcp_server().await;
+
+    let response = register_connector(
+        &server,
+        &api_key,
+        json!({
+            "name": "mock-tools",
+            "description": "Mock MCP server",
+            "server_url": server_url,
+            "auth": {"type": "bearer", "token": "mcp-secret"}
+        }),
+    )
+    .await;
+    assert_eq!(response.status_code(), 201, "{}", response.text());
+    assert!(!response.text().contains("mcp-secret"));
+    let connector: McpConnectorResponse = response.json();
+    assert_eq!(connector.object, "mcp_connector");
+    assert_eq!(connector.name, "mock-tools");
+    assert_eq!(connector.auth_type, "bearer");
+
+    let duplicate = register_connector(
+        &server,
+        &api_key,
+        json!({"name": "mock-tools", "server_url": server_url}),
+    )
+    .await;
+    assert_eq!(duplicate.status_code(), 409, "{}", duplicate.text());
+
+    let response = server
+        .get("/v1/mcp/connectors")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let list: McpConnectorListResponse = response.json();
+    assert_eq!(list.data.len(), 1);
+    assert_eq!(list.data[0].id, connector.id);
+
+    let response = invoke_tool(
+        &server,
+        &api_key,
+        &connector.id,
+        "echo",
+        json!({"text": "hello"}),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let result: InvokeMcpToolResponse = response.json();
+    assert_eq!(result.tool, "echo");
+    assert!(!result.is_error);
+    assert_eq!(result.content[0]["text"], "hello");
+
+    let response = invoke_tool(&server, &api_key, &connector.id, "fail", json!({})).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let result: InvokeMcpToolResponse = response.json();
+    assert!(result.is_error);
+    assert_eq!(result.content[0]["text"], "tool exploded");
+
+    let response = invoke_tool(&server, &api_key, &connector.id, "missing", json!({})).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+    assert!(response.text().contains("invalid_request_error"));
+
+    let response = server
+        .delete(format!("/v1/mcp/connectors/{}", connector.id).as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let response = server
+        .get("/v1/mcp/connectors")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    let list: McpConnectorListResponse = response.json();
+    assert!(list.data.is_empty());
+
+    let response = invoke_tool(&server, &api_key, &connector.id, "echo", json!({})).await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_mcp_connectors_are_isolated_between_workspaces() {
+    let server = setup_test_server().await;
+    let org_a = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let org_b = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let key_a = get_api_key_for_org(&server, org_a.id).await;
+    let key_b = get_api_key_for_org(&server, org_b.id).await;
+    let server_url = start_mock_mcp_server().await;
+
+    let response = register_connector(
+        &server,
+        &key_a,
+        json!({"name": "private-tools", "server_url": server_url}),
+    )
+    .await;
+    assert_eq!(response.status_code(), 201, "{}", response.text());
+    let connector: McpConnectorResponse = response.json();
+
+    let response = server
+        .get("/v1/mcp/connectors")
+        .add_header("Authorization", format!("Bearer {key_b}"))
+        .await;
+    let list: McpConnectorListResponse = response.json();
+    assert!(list.data.is_empty());
+
+    let response = invoke_tool(&server, &key_b, &connector.id, "echo", json!({})).await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+
+    let response = server
+        .delete(format!("/v1/mcp/connectors/{}", connector.id).as_str())
+        .add_header("Authorization", format!("Bearer {key_b}"))
+        .await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+
+    // The same name is free in another workspace
+    let response = register_connector(
+        &server,
+        &key_b,
+        json!({"name": "private-tools", "server_url": server_url}),
+    )
+    .await;
+    assert_eq!(response.status_code(), 201, "{}", response.text());
+}
@@ -0,0 +1,92 @@
+// E2E tests for the OpenAI `metadata` limits (16 keys, 64-char keys,
+// 512-char string values) on chat completions and conversations.
+
+use crate::common::*;
+use serde_json::json;
+
+fn metadata_with_keys(count: usize) -> serde_json::Value {
+    serde_json::Value::Object(
+        (0..count)
+            .map(|i| (format!("key_{i}"), json!("value")))
+            .collect(),
+    )
+}
+
+async fn chat_with_metadata(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    metadata: serde_json::Value,
+) -> axum_test::TestResponse {
+    server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "max_tokens": 10,
+            "metadata": metadata
+        }))
+        .await
+}
+
+#[tokio::test]
+async fn test_chat_completion_metadata_limits() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = chat_with_metadata(&server, &api_key, metadata_with_keys(16)).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let params = mock_provider.last_chat_params().await.unwrap();
+    assert_eq!(params.extra["metadata"], metadata_with_keys(16));
+
+    for metadata in [
+        metadata_with_keys(17),
+        json!({ "key": "x".repeat(513) }),
+        json!({ "key": 1 }),
+    ] {
+        let response = chat_with_metadata(&server, &api_key, metadata).await;
+        assert_eq!(response.status_code(), 400, "{}", response.text());
+        let error: api::models::ErrorResponse = response.json();
+        assert!(
+            error.error.message.contains("metadata"),
+            "{}",
+            error.error.message
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_conversation_metadata_limits() {
+    let server = setup_test_server().await;
+    let (api_key, _) = create_org_and_api_key(&server).await;
+
+    let response = server
+        .post("/v1/conversations")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({ "metadata": { "title": "x".repeat(512) } }))
+        .await;
+    assert_eq!(response.status_code(), 201, "{}", response.text());
+    let conversation: api::models::ConversationObject = response.json();
+
+    let response = server
+        .post("/v1/conversations")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({ "metadata": metadata_with_keys(17) }))
+        .await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+
+    let response = server
+        .post(format!("/v1/conversations/{}", conversation.id).as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({ "metadata": { "title": "x".repeat(513) } }))
+        .await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+    let error: api::models::ErrorResponse = response.json();
+    assert_eq!(
+        error.error.message,
+        "metadata values can be at most 512 characters long"
+    );
+}
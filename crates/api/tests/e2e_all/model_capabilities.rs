@@ -0,0 +1,114 @@
+// E2E tests for GET /v1/models/{model_name}/capabilities
+
+use crate::common::*;
+use api::models::{BatchUpdateModelApiRequest, ModelCapabilitiesResponse};
+
+#[tokio::test]
+async fn test_model_capabilities_reflect_catalog_fields() {
+    let server = setup_test_server().await;
+
+    let model_name = format!("test-org/capabilities-{}", uuid::Uuid::new_v4());
+    let mut batch = BatchUpdateModelApiRequest::new();
+    batch.insert(
+        model_name.clone(),
+        serde_json::from_value(serde_json::json!({
+            "inputCostPerToken": { "amount": 1000000, "currency": "USD" },
+            "outputCostPerToken": { "amount": 2000000, "currency": "USD" },
+            "modelDisplayName": "Capabilities Test Model",
+            "modelDescription": "Model with tools, vision and structured outputs",
+            "contextLength": 8192,
+            "maxOutputLength": 2048,
+            "verifiable": false,
+            "isActive": true,
+            "inputModalities": ["text", "image"],
+            "outputModalities": ["text"],
+            "supportedFeatures": ["tools", "structured_outputs"]
+        }))
+        .unwrap(),
+    );
+    let updated = admin_batch_upsert_models(&server, batch, get_session_id()).await;
+    assert_eq!(updated.len(), 1);
+    assert!(updated[0].metadata.capabilities.supports_tools);
+
+    let encoded = urlencoding::encode(&model_name);
+    let response = server
+        .get(&format!("/v1/models/{encoded}/capabilities"))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let body: ModelCapabilitiesResponse = response.json();
+    assert_eq!(body.model, model_name);
+    assert!(body.capabilities.supports_tools);
+    assert!(body.capabilities.supports_vision);
+    assert!(body.capabilities.supports_json_schema);
+    assert_eq!(body.capabilities.max_output_tokens, Some(2048));
+
+    // The same flags are embedded in the public model detail response.
+    let detail = server.get(&format!("/v1/model/{encoded}")).await;
+    assert_eq!(detail.status_code(), 200);
+    let raw: serde_json::Value = detail.json();
+    assert_eq!(
+        raw["metadata"]["capabilities"],
+        serde_json::json!({
+            "supportsTools": true,
+            "supportsVision": true,
+            "supportsJsonSchema": true,
+            "maxOutputTokens": 2048
+        }),
+        "capabilities follow the metadata's camelCase naming"
+    );
+    let detail: api::models::ModelWithPricing = detail.json();
+    assert_eq!(detail.metadata.capabilities, body.capabilities);
+}
+
+#[tokio::test]
+async fn test_model_capabilities_text_only_model() {
+    let server = setup_test_server().await;
+
+    let model_name = format!("capabilities-text-{}", uuid::Uuid::new_v4());
+    let mut batch = BatchUpdateModelApiRequest::new();
+    batch.insert(
+        model_name.clone(),
+        serde_json::from_value(serde_json::json!({
+            "inputCostPerToken": { "amount": 1000000, "currency": "USD" },
+            "outputCostPerToken": { "amount": 2000000, "currency": "USD" },
+            "modelDisplayName": "Text Only Model",
+            "modelDescription": "No optional capabilities",
+            "contextLength": 4096,
+            "verifiable": false,
+            "isActive": true,
+            "inputModalities": ["text"],
+            "outputModalities": ["text"],
+            "supportedFeatures": ["json_mode"]
+        }))
+        .unwrap(),
+    );
+    admin_batch_upsert_models(&server, batch, get_session_id()).await;
+
+    let response = server
+        .get(&format!("/v1/models/{model_name}/capabilities"))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let body: ModelCapabilitiesResponse = response.json();
+    assert!(!body.capabilities.supports_tools);
+    assert!(!body.capabilities.supports_vision);
+    assert!(!body.capabilities.supports_json_schema);
+    assert_eq!(body.capabilities.max_output_tokens, None);
+}
+
+#[tokio::test]
+async fn test_model_capabilities_unknown_model_returns_404() {
+    let server = setup_test_server().await;
+
+    let response = server
+        .get(&format!(
+            "/v1/models/nonexistent-model-{}/capabilities",
+            uuid::Uuid::new_v4()
+        ))
+        .await;
+    assert_eq!(response.status_code(), 404);
+
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["error"]["type"], "model_not_found");
+}
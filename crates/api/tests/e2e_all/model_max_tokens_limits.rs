@@ -0,0 +1,136 @@
+// E2E tests for per-model default / maximum `max_tokens` enforcement.
+
+use crate::common::*;
+use api::models::BatchUpdateModelApiRequest;
+
+/// Register a uniquely named model served by the mock provider, with the
+/// given `max_tokens` policy, so the limits never leak into other tests.
+async fn setup_limited_model(
+    server: &axum_test::TestServer,
+    pool: &services::inference_provider_pool::InferenceProviderPool,
+    mock: &std::sync::Arc<inference_providers::mock::MockProvider>,
+    reject_over_max: bool,
+) -> String {
+    let model_name = format!("test-max-tokens/Limited-{}", uuid::Uuid::new_v4());
+    let mut batch = BatchUpdateModelApiRequest::new();
+    batch.insert(
+        model_name.clone(),
+        serde_json::from_value(serde_json::json!({
+            "inputCostPerToken":  { "amount": 1_000_000, "currency": "USD" },
+            "outputCostPerToken": { "amount": 2_000_000, "currency": "USD" },
+            "modelDisplayName": "Max Tokens Limits Test Model",
+            "modelDescription": "Synthetic model with a max_tokens policy",
+            "contextLength": 128000,
+            "verifiable": true,
+            "isActive": true,
+            "defaultMaxTokens": 256,
+            "maxMaxTokens": 1024,
+            "rejectOverMaxTokens": reject_over_max,
+        }))
+        .unwrap(),
+    );
+    admin_batch_upsert_models(server, batch, get_session_id()).await;
+    pool.register_provider(model_name.clone(), mock.clone())
+        .await;
+    model_name
+}
+
+async fn chat(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    model: &str,
+    budget: Option<(&str, i64)>,
+) -> axum_test::TestResponse {
+    let mut body = serde_json::json!({
+        "model": model,
+        "messages": [{ "role": "user", "content": "hello" }],
+    });
+    if let Some((field, value)) = budget {
+        body[field] = serde_json::json!(value);
+    }
+    server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&body)
+        .await
+}
+
+#[tokio::test]
+async fn test_max_tokens_default_applied_and_over_limit_clamped() {
+    let (server, pool, mock, _db) = setup_test_server_with_pool().await;
+    let model = setup_limited_model(&server, &pool, &mock, false).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    // Omitted: the model default is applied.
+    let response = chat(&server, &api_key, &model, None).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let params = mock.last_chat_params().await.expect("provider called");
+    assert_eq!(params.max_tokens, Some(256));
+
+    // Within the cap: forwarded unchanged.
+    let response = chat(&server, &api_key, &model, Some(("max_tokens", 800))).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let params = mock.last_chat_params().await.expect("provider called");
+    assert_eq!(params.max_tokens, Some(800));
+
+    // Over the cap: clamped, on whichever field the client used.
+    let response = chat(
+        &server,
+        &api_key,
+        &model,
+        Some(("max_completion_tokens", 100_000)),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let params = mock.last_chat_params().await.expect("provider called");
+    assert_eq!(params.extra["max_completion_tokens"], 1024);
+    assert_eq!(params.max_tokens, None);
+}
+
+#[tokio::test]
+async fn test_max_tokens_over_limit_rejected_when_configured() {
+    let (server, pool, mock, _db) = setup_test_server_with_pool().await;
+    let model = setup_limited_model(&server, &pool, &mock, true).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = chat(&server, &api_key, &model, Some(("max_tokens", 4096))).await;
+    assert_eq!(response.status_code(), 400);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["error"]["type"], "invalid_request_error");
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap_or_default()
+        .contains("1024"));
+
+    let response = chat(&server, &api_key, &model, Some(("max_tokens", 1024))).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_admin_rejects_default_above_max() {
+    let server = setup_test_server().await;
+    let mut batch = BatchUpdateModelApiRequest::new();
+    batch.insert(
+        format!("test-max-tokens/Invalid-{}", uuid::Uuid::new_v4()),
+        serde_json::from_value(serde_json::json!({
+            "inputCostPerToken":  { "amount": 1_000_000, "currency": "USD" },
+            "outputCostPerToken": { "amount": 2_000_000, "currency": "USD" },
+            "modelDisplayName": "Invalid Max Tokens Model",
+            "modelDescription": "defaultMaxTokens above maxMaxTokens",
+            "contextLength": 4096,
+            "defaultMaxTokens": 2048,
+            "maxMaxTokens": 1024,
+        }))
+        .unwrap(),
+    );
+    let response = server
+        .patch("/v1/admin/models")
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&batch)
+        .await;
+    assert_eq!(response.status_code(), 400);
+}
@@ -0,0 +1,95 @@
+// E2E tests for GET /v1/models/{model_name}/pricing
+
+use crate::common::*;
+use api::models::{BatchUpdateModelApiRequest, ModelPricingResponse};
+
+async fn upsert_priced_model(
+    server: &axum_test::TestServer,
+    model_name: &str,
+    alias: &str,
+    input_cost: i64,
+    output_cost: i64,
+) {
+    let mut batch = BatchUpdateModelApiRequest::new();
+    batch.insert(
+        model_name.to_string(),
+        serde_json::from_value(serde_json::json!({
+            "inputCostPerToken": { "amount": input_cost, "currency": "USD" },
+            "outputCostPerToken": { "amount": output_cost, "currency": "USD" },
+            "modelDisplayName": "Pricing Test Model",
+            "modelDescription": "Model for the public pricing endpoint",
+            "contextLength": 8192,
+            "verifiable": false,
+            "isActive": true,
+            // Zero-priced models can only be activated when explicitly free.
+            "allowFree": input_cost == 0 && output_cost == 0,
+            "aliases": [alias]
+        }))
+        .unwrap(),
+    );
+    admin_batch_upsert_models(server, batch, get_session_id()).await;
+}
+
+#[tokio::test]
+async fn test_model_pricing_for_priced_model_and_alias() {
+    let server = setup_test_server().await;
+
+    let model_name = format!("test-org/pricing-{}", uuid::Uuid::new_v4());
+    let alias = format!("pricing-alias-{}", uuid::Uuid::new_v4());
+    upsert_priced_model(&server, &model_name, &alias, 1_000_000, 3_000_000).await;
+
+    let encoded = urlencoding::encode(&model_name);
+    let response = server.get(&format!("/v1/models/{encoded}/pricing")).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let body: ModelPricingResponse = response.json();
+    assert_eq!(body.model, model_name);
+    assert_eq!(body.currency, "USD");
+    assert_eq!(body.input_cost_per_token.amount, 1_000_000);
+    assert_eq!(body.input_cost_per_token.scale, 9);
+    assert_eq!(body.output_cost_per_token.amount, 3_000_000);
+    assert!(!body.pricing_unavailable);
+
+    // An alias reports the canonical model's pricing.
+    let response = server.get(&format!("/v1/models/{alias}/pricing")).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let by_alias: ModelPricingResponse = response.json();
+    assert_eq!(by_alias.model, model_name);
+    assert_eq!(by_alias.input_cost_per_token, body.input_cost_per_token);
+    assert_eq!(by_alias.output_cost_per_token, body.output_cost_per_token);
+}
+
+#[tokio::test]
+async fn test_model_pricing_flags_unpriced_model() {
+    let server = setup_test_server().await;
+
+    let model_name = format!("pricing-unpriced-{}", uuid::Uuid::new_v4());
+    let alias = format!("pricing-unpriced-alias-{}", uuid::Uuid::new_v4());
+    upsert_priced_model(&server, &model_name, &alias, 0, 0).await;
+
+    let response = server
+        .get(&format!("/v1/models/{model_name}/pricing"))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let body: ModelPricingResponse = response.json();
+    assert!(body.pricing_unavailable);
+    assert_eq!(body.input_cost_per_token.amount, 0);
+    assert_eq!(body.output_cost_per_token.amount, 0);
+}
+
+#[tokio::test]
+async fn test_model_pricing_unknown_model_returns_404() {
+    let server = setup_test_server().await;
+
+    let response = server
+        .get(&format!(
+            "/v1/models/nonexistent-model-{}/pricing",
+            uuid::Uuid::new_v4()
+        ))
+        .await;
+    assert_eq!(response.status_code(), 404);
+
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["error"]["type"], "model_not_found");
+}
@@ -0,0 +1,140 @@
+// E2E tests for the per-model `requestTimeoutSecs` admin field:
+// - non-positive values are rejected,
+// - the value is persisted (a later dry run diffs against it),
+// - an explicit `null` clears it, while omitting it leaves it unchanged.
+
+use crate::common::*;
+use api::models::BatchUpsertDryRunResponse;
+
+async fn patch_models(
+    server: &axum_test::TestServer,
+    path: &str,
+    body: serde_json::Value,
+) -> axum_test::TestResponse {
+    server
+        .patch(path)
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&body)
+        .await
+}
+
+fn model_body(request_timeout_secs: i64) -> serde_json::Value {
+    serde_json::json!({
+        "inputCostPerToken":  { "amount": 1_000_000, "currency": "USD" },
+        "outputCostPerToken": { "amount": 2_000_000, "currency": "USD" },
+        "modelDisplayName":   "Request Timeout Test Model",
+        "modelDescription":   "Synthetic model for request timeout tests",
+        "contextLength":      4096,
+        "verifiable":         false,
+        "isActive":           true,
+        "requestTimeoutSecs": request_timeout_secs
+    })
+}
+
+#[tokio::test]
+async fn test_request_timeout_must_be_positive() {
+    let server = setup_test_server().await;
+    let model_name = format!("test-request-timeout-invalid-{}", uuid::Uuid::new_v4());
+
+    for value in [0, -30] {
+        let response = patch_models(
+            &server,
+            "/v1/admin/models",
+            serde_json::json!({ &model_name: model_body(value) }),
+        )
+        .await;
+        assert_eq!(response.status_code(), 400, "{}", response.text());
+        assert!(response.text().contains("requestTimeoutSecs"));
+    }
+}
+
+#[tokio::test]
+async fn test_request_timeout_is_persisted() {
+    let server = setup_test_server().await;
+    let model_name = format!("test-request-timeout-{}", uuid::Uuid::new_v4());
+
+    let response = patch_models(
+        &server,
+        "/v1/admin/models",
+        serde_json::json!({ &model_name: model_body(900) }),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let response = patch_models(
+        &server,
+        "/v1/admin/models?dry_run=true",
+        serde_json::json!({ &model_name: { "requestTimeoutSecs": 120 } }),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let preview: BatchUpsertDryRunResponse = response.json();
+    assert_eq!(preview.would_update.len(), 1);
+    let change = &preview.would_update[0];
+    assert_eq!(change.field, "request_timeout_secs");
+    assert_eq!(change.old, serde_json::json!(900));
+    assert_eq!(change.new, serde_json::json!(120));
+
+    // Re-sending the stored value is a no-op.
+    let response = patch_models(
+        &server,
+        "/v1/admin/models?dry_run=true",
+        serde_json::json!({ &model_name: { "requestTimeoutSecs": 900 } }),
+    )
+    .await;
+    let preview: BatchUpsertDryRunResponse = response.json();
+    assert_eq!(preview.would_noop, vec![model_name]);
+}
+
+#[tokio::test]
+async fn test_request_timeout_can_be_cleared() {
+    let server = setup_test_server().await;
+    let model_name = format!("test-request-timeout-clear-{}", uuid::Uuid::new_v4());
+
+    let response = patch_models(
+        &server,
+        "/v1/admin/models",
+        serde_json::json!({ &model_name: model_body(900) }),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    // Omitting the field leaves the stored timeout alone.
+    let response = patch_models(
+        &server,
+        "/v1/admin/models",
+        serde_json::json!({ &model_name: { "modelDisplayName": "Renamed" } }),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let response = patch_models(
+        &server,
+        "/v1/admin/models?dry_run=true",
+        serde_json::json!({ &model_name: { "requestTimeoutSecs": 900 } }),
+    )
+    .await;
+    let preview: BatchUpsertDryRunResponse = response.json();
+    assert_eq!(preview.would_noop, vec![model_name.clone()]);
+
+    // An explicit null clears it back to the global default.
+    let response = patch_models(
+        &server,
+        "/v1/admin/models",
+        serde_json::json!({ &model_name: { "requestTimeoutSecs": null } }),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let response = patch_models(
+        &server,
+        "/v1/admin/models?dry_run=true",
+        serde_json::json!({ &model_name: { "requestTimeoutSecs": 900 } }),
+    )
+    .await;
+    let preview: BatchUpsertDryRunResponse = response.json();
+    assert_eq!(preview.would_update.len(), 1);
+    let change = &preview.would_update[0];
+    assert_eq!(change.field, "request_timeout_secs");
+    assert_eq!(change.old, serde_json::Value::Null);
+    assert_eq!(change.new, serde_json::json!(900));
+}
@@ -1,5 +1,13 @@
 use crate::common::*;
 use axum::http::StatusCode;
+use near_api::signer::NEP413Payload;
+use near_api::types::{crypto::secret_key::ED25519SecretKey, SecretKey};
+use near_api::PublicKey;
+use rand::RngExt;
+use services::auth::near::{NearAuthError, SignedMessage};
+use services::auth::NearSignatureVerifier;
+use std::collections::HashMap;
+use std::sync::Arc;
 
 // ============================================
 // Happy Path Tests
@@ -277,25 +285,247 @@ async fn test_near_login_zero_timestamp_nonce() {
 }
 
 // ============================================
-// Note: Signature Verification Limitation
+// Challenge/Response Flow (server-issued nonces)
 // ============================================
 //
-// Tests that require valid NEAR wallet signatures (replay attack prevention,
-// user creation/linking) are not included due to the following limitations:
-//
-// 1. The `payload.verify()` method from near-api crate performs RPC calls to
-//    the NEAR blockchain to verify that the public key actually belongs to
-//    the account_id. This RPC verification cannot be easily mocked or bypassed
-//    in integration tests.
-//
-// 2. To properly test these scenarios, we would need either:
-//    - A real NEAR testnet account with valid private keys
-//    - Dependency injection of a trait-based verifier (architectural change)
-//    - Access to mock NEAR RPC responses
-//
-// 3. The current test suite validates all input validation and business logic
-//    constraints (message format, recipient validation, nonce timestamp validation,
-//    nonce replay detection in database), which cover the security-critical paths.
-//
-// Full integration tests for user creation and replay protection can be added
-// in the future if the architecture is modified to support signature verification mocking.
+// Key ownership is normally proven by a NEAR RPC access-key lookup, which is
+// unavailable here. These tests inject a verifier that knows each test
+// account's full access key instead.
+
+const TEST_ACCOUNT: &str = "alice.near";
+
+/// Stands in for the NEAR RPC lookup: each account owns exactly the key
+/// registered for it
+struct KnownKeysVerifier {
+    keys: HashMap<String, PublicKey>,
+}
+
+#[async_trait::async_trait]
+impl NearSignatureVerifier for KnownKeysVerifier {
+    async fn verify(
+        &self,
+        signed_message: &SignedMessage,
+        payload: &NEP413Payload,
+    ) -> Result<bool, NearAuthError> {
+        let Some(key) = self.keys.get(signed_message.account_id.as_str()) else {
+            return Ok(false);
+        };
+        let hash = payload
+            .compute_hash()
+            .map_err(|e| NearAuthError::SignatureVerificationFailed(e.to_string()))?;
+        Ok(*key == signed_message.public_key
+            && signed_message
+                .signature
+                .verify(hash, signed_message.public_key))
+    }
+}
+
+/// Test server on which `TEST_ACCOUNT`'s full access key is `key`
+async fn setup_near_server(key: &SecretKey) -> axum_test::TestServer {
+    let keys = HashMap::from([(TEST_ACCOUNT.to_string(), key.public_key())]);
+    setup_test_server_with_near_verifier(Arc::new(KnownKeysVerifier { keys })).await
+}
+
+fn random_near_key() -> SecretKey {
+    let mut seed = [0u8; 32];
+    rand::rng().fill(&mut seed);
+    SecretKey::ED25519(ED25519SecretKey::from_secret_key(seed))
+}
+
+async fn issue_near_nonce(server: &axum_test::TestServer) -> serde_json::Value {
+    let response = server.get("/v1/auth/near/nonce").await;
+    assert_eq!(
+        response.status_code(),
+        StatusCode::OK,
+        "{}",
+        response.text()
+    );
+    response.json::<serde_json::Value>()
+}
+
+/// Build a `/v1/auth/near/verify` body: `signer` signs the issued nonce while
+/// the request claims `claimed` as `account_id`'s key.
+fn near_verify_body(
+    issued: &serde_json::Value,
+    account_id: &str,
+    signer: &SecretKey,
+    claimed: &SecretKey,
+) -> serde_json::Value {
+    use base64::prelude::*;
+
+    let nonce: Vec<u8> = serde_json::from_value(issued["nonce"].clone()).unwrap();
+    let payload = NEP413Payload {
+        message: issued["message"].as_str().unwrap().to_string(),
+        nonce: nonce.clone().try_into().unwrap(),
+        recipient: issued["recipient"].as_str().unwrap().to_string(),
+        callback_url: None,
+    };
+    let signature = match signer.sign(payload.compute_hash().unwrap()) {
+        near_api::types::Signature::ED25519(signature) => signature.to_bytes(),
+        _ => unreachable!("test keys are ed25519"),
+    };
+
+    serde_json::json!({
+        "signed_message": {
+            "accountId": account_id,
+            "publicKey": claimed.public_key().to_string(),
+            "signature": BASE64_STANDARD.encode(signature),
+        },
+        "nonce": nonce,
+    })
+}
+
+async fn near_verify(
+    server: &axum_test::TestServer,
+    body: &serde_json::Value,
+) -> axum_test::TestResponse {
+    server
+        .post("/v1/auth/near/verify")
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(body)
+        .await
+}
+
+/// A valid signature over an issued nonce mints a working session token
+#[tokio::test]
+async fn test_near_verify_valid_signature_issues_token() {
+    let key = random_near_key();
+    let server = setup_near_server(&key).await;
+    let issued = issue_near_nonce(&server).await;
+    assert_eq!(issued["nonce"].as_array().map(Vec::len), Some(32));
+
+    let response = near_verify(
+        &server,
+        &near_verify_body(&issued, TEST_ACCOUNT, &key, &key),
+    )
+    .await;
+
+    assert_eq!(
+        response.status_code(),
+        StatusCode::OK,
+        "{}",
+        response.text()
+    );
+    let body = response.json::<serde_json::Value>();
+    let access_token = body["access_token"].as_str().expect("access_token");
+    assert!(!access_token.is_empty());
+    assert!(!body["refresh_token"]
+        .as_str()
+        .unwrap_or_default()
+        .is_empty());
+    assert_eq!(body["user"]["provider"], "near");
+
+    let me = server
+        .get("/v1/auth/user")
+        .add_header("Authorization", format!("Bearer {access_token}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(me.status_code(), StatusCode::OK, "{}", me.text());
+}
+
+/// A signature from a key other than the claimed account's is rejected
+#[tokio::test]
+async fn test_near_verify_wrong_key_rejected() {
+    let account_key = random_near_key();
+    let attacker_key = random_near_key();
+    let server = setup_near_server(&account_key).await;
+    let issued = issue_near_nonce(&server).await;
+
+    let response = near_verify(
+        &server,
+        &near_verify_body(&issued, TEST_ACCOUNT, &attacker_key, &account_key),
+    )
+    .await;
+
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        response.json::<serde_json::Value>()["error"],
+        "invalid_auth"
+    );
+
+    // The failed attempt must not burn the nonce for the real key holder.
+    let retry = near_verify(
+        &server,
+        &near_verify_body(&issued, TEST_ACCOUNT, &account_key, &account_key),
+    )
+    .await;
+    assert_eq!(retry.status_code(), StatusCode::OK, "{}", retry.text());
+}
+
+/// An implicit account ID matching the signing key is not proof of
+/// ownership on its own: the key must still be a full access key on chain
+#[tokio::test]
+async fn test_near_verify_implicit_account_requires_access_key() {
+    let key = random_near_key();
+    let server = setup_near_server(&random_near_key()).await;
+    let issued = issue_near_nonce(&server).await;
+    let implicit_account = hex::encode(key.public_key().key_data());
+
+    let response = near_verify(
+        &server,
+        &near_verify_body(&issued, &implicit_account, &key, &key),
+    )
+    .await;
+
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+}
+
+/// An issued nonce can be redeemed only once
+#[tokio::test]
+async fn test_near_verify_nonce_replay_rejected() {
+    let key = random_near_key();
+    let server = setup_near_server(&key).await;
+    let issued = issue_near_nonce(&server).await;
+    let body = near_verify_body(&issued, TEST_ACCOUNT, &key, &key);
+
+    let first = near_verify(&server, &body).await;
+    assert_eq!(first.status_code(), StatusCode::OK, "{}", first.text());
+
+    let replay = near_verify(&server, &body).await;
+    assert_eq!(replay.status_code(), StatusCode::UNAUTHORIZED);
+}
+
+/// A correctly signed nonce the server never issued is rejected
+#[tokio::test]
+async fn test_near_verify_unissued_nonce_rejected() {
+    let key = random_near_key();
+    let server = setup_near_server(&key).await;
+    let mut issued = issue_near_nonce(&server).await;
+    issued["nonce"] = serde_json::json!(create_near_test_nonce(0));
+
+    let response = near_verify(
+        &server,
+        &near_verify_body(&issued, TEST_ACCOUNT, &key, &key),
+    )
+    .await;
+
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+}
+
+/// Unauthenticated nonce issuance is rate limited per client
+#[tokio::test]
+async fn test_near_nonce_issuance_rate_limited() {
+    let server = setup_test_server().await;
+    let client = "203.0.113.7";
+
+    for _ in 0..api::middleware::rate_limit::DEFAULT_CLIENT_RATE_LIMIT {
+        let response = server
+            .get("/v1/auth/near/nonce")
+            .add_header("X-Forwarded-For", client)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+
+    let limited = server
+        .get("/v1/auth/near/nonce")
+        .add_header("X-Forwarded-For", client)
+        .await;
+    assert_eq!(limited.status_code(), StatusCode::TOO_MANY_REQUESTS);
+
+    // Other clients keep their own budget
+    let other = server
+        .get("/v1/auth/near/nonce")
+        .add_header("X-Forwarded-For", "203.0.113.8")
+        .await;
+    assert_eq!(other.status_code(), StatusCode::OK);
+}
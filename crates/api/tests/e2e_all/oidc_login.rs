@@ -0,0 +1,216 @@
+// E2E tests for the generic OIDC login flow against a mock identity provider.
+//
+// Runs against the real `AuthService` (`auth.mock = false`) so the callback
+// performs a real user upsert in the test database.
+
+use crate::common::*;
+use std::sync::Arc;
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TEST_UA: &str = "Mozilla/5.0 (X11; Linux x86_64) TestBrowser/1.0";
+
+fn oidc_config(idp: &MockServer) -> config::GenericOidcConfig {
+    config::GenericOidcConfig {
+        client_id: "cloud-api-client".to_string(),
+        client_secret: "cloud-api-secret".to_string(),
+        redirect_url: "http://localhost:3000/v1/auth/callback".to_string(),
+        issuer: idp.uri(),
+        authorization_url: format!("{}/authorize", idp.uri()),
+        token_url: format!("{}/token", idp.uri()),
+        userinfo_url: format!("{}/userinfo", idp.uri()),
+        scopes: vec!["openid".to_string(), "email".to_string()],
+    }
+}
+
+async fn mount_idp(idp: &MockServer, code: &str, access_token: &str, claims: serde_json::Value) {
+    idp.reset().await;
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(body_string_contains(format!("code={code}")))
+        .and(body_string_contains("code_verifier="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": access_token,
+            "token_type": "Bearer",
+            "expires_in": 3600
+        })))
+        .expect(1)
+        .mount(idp)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/userinfo"))
+        .and(header(
+            "Authorization",
+            format!("Bearer {access_token}").as_str(),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(claims))
+        .expect(1)
+        .mount(idp)
+        .await;
+}
+
+/// Start the login flow and return the `state` the server handed the IdP.
+async fn start_login(server: &axum_test::TestServer, idp: &MockServer) -> String {
+    let response = server.get("/v1/auth/oidc").await;
+    assert_eq!(response.status_code(), 303, "{}", response.text());
+    let location = response
+        .headers()
+        .get("location")
+        .and_then(|v| v.to_str().ok())
+        .expect("login should redirect to the IdP")
+        .to_string();
+    assert!(location.starts_with(&format!("{}/authorize", idp.uri())));
+
+    let url = url::Url::parse(&location).unwrap();
+    let query: std::collections::HashMap<_, _> = url.query_pairs().into_owned().collect();
+    assert_eq!(
+        query.get("client_id").map(String::as_str),
+        Some("cloud-api-client")
+    );
+    assert_eq!(query.get("response_type").map(String::as_str), Some("code"));
+    assert_eq!(query.get("scope").map(String::as_str), Some("openid email"));
+    assert!(query.contains_key("code_challenge"), "PKCE is required");
+    query.get("state").expect("state param").clone()
+}
+
+async fn complete_login(
+    server: &axum_test::TestServer,
+    code: &str,
+    state: &str,
+) -> serde_json::Value {
+    let response = server
+        .get(&format!("/v1/auth/callback?code={code}&state={state}"))
+        .add_header("User-Agent", TEST_UA)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    response.json()
+}
+
+async fn find_oidc_user(
+    database: &Arc<database::Database>,
+    subject: &str,
+) -> Option<(uuid::Uuid, String, Option<String>)> {
+    let client = database.pool().get().await.unwrap();
+    client
+        .query_opt(
+            "SELECT id, email, display_name FROM users
+             WHERE auth_provider = 'oidc' AND provider_user_id = $1",
+            &[&subject],
+        )
+        .await
+        .unwrap()
+        .map(|row| (row.get(0), row.get(1), row.get(2)))
+}
+
+#[tokio::test]
+async fn test_oidc_code_exchange_upserts_user() {
+    let idp = MockServer::start().await;
+    let config = oidc_config(&idp);
+    let (server, database) = setup_test_server_with_config_and_database(|c| {
+        c.auth.mock = false;
+        c.auth.oidc = Some(config);
+    })
+    .await;
+
+    let subject = format!("oidc-{}", uuid::Uuid::new_v4());
+    let email = format!("{subject}@idp.example.com");
+
+    // First login creates the user.
+    mount_idp(
+        &idp,
+        "code-1",
+        "access-1",
+        serde_json::json!({
+            "sub": subject,
+            "iss": idp.uri(),
+            "email": email,
+            "email_verified": true,
+            "name": "First Name"
+        }),
+    )
+    .await;
+    let state = start_login(&server, &idp).await;
+    let body = complete_login(&server, "code-1", &state).await;
+    assert_eq!(body["user"]["provider"], "oidc");
+    assert_eq!(body["user"]["email"], email.as_str());
+    assert!(body["access_token"].as_str().is_some_and(|t| !t.is_empty()));
+
+    let (user_id, stored_email, display_name) = find_oidc_user(&database, &subject)
+        .await
+        .expect("OIDC user should be created");
+    assert_eq!(stored_email, email);
+    assert_eq!(display_name.as_deref(), Some("First Name"));
+
+    // Second login with updated claims maps to the same user.
+    mount_idp(
+        &idp,
+        "code-2",
+        "access-2",
+        serde_json::json!({
+            "sub": subject,
+            "email": email,
+            "email_verified": true,
+            "name": "Second Name"
+        }),
+    )
+    .await;
+    let state = start_login(&server, &idp).await;
+    complete_login(&server, "code-2", &state).await;
+
+    let (same_user_id, _, display_name) = find_oidc_user(&database, &subject).await.unwrap();
+    assert_eq!(same_user_id, user_id);
+    assert_eq!(display_name.as_deref(), Some("Second Name"));
+
+    // Keep the shared mock user first in created_at-ordered admin listings.
+    database
+        .pool()
+        .get()
+        .await
+        .unwrap()
+        .execute(
+            "UPDATE users SET created_at = NOW() - INTERVAL '30 days' WHERE id = $1",
+            &[&user_id],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_oidc_callback_rejects_issuer_mismatch() {
+    let idp = MockServer::start().await;
+    let config = oidc_config(&idp);
+    let (server, database) = setup_test_server_with_config_and_database(|c| {
+        c.auth.mock = false;
+        c.auth.oidc = Some(config);
+    })
+    .await;
+
+    let subject = format!("oidc-{}", uuid::Uuid::new_v4());
+    mount_idp(
+        &idp,
+        "code-x",
+        "access-x",
+        serde_json::json!({
+            "sub": subject,
+            "iss": "https://other-issuer.example.com",
+            "email": format!("{subject}@idp.example.com"),
+            "email_verified": true
+        }),
+    )
+    .await;
+    let state = start_login(&server, &idp).await;
+
+    let response = server
+        .get(&format!("/v1/auth/callback?code=code-x&state={state}"))
+        .add_header("User-Agent", TEST_UA)
+        .await;
+    assert_eq!(response.status_code(), 401, "{}", response.text());
+    assert!(find_oidc_user(&database, &subject).await.is_none());
+}
+
+#[tokio::test]
+async fn test_oidc_login_not_configured_is_404() {
+    let server = setup_test_server().await;
+    let response = server.get("/v1/auth/oidc").await;
+    assert_eq!(response.status_code(), 404);
+}
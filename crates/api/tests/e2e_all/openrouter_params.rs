@@ -219,7 +219,7 @@ async fn test_sampling_extras_accepted_and_forwarded() {
 
 // ── seed ────────────────────────────────────────────────────────────────────
 
-/// `seed` must never error and must be forwarded (it rides in `extra`).
+/// `seed` must never error and must be forwarded as the typed param.
 #[tokio::test]
 async fn test_seed_accepted_and_forwarded() {
     let (server, mock, model, api_key) = setup().await;
@@ -244,11 +244,7 @@ async fn test_seed_accepted_and_forwarded() {
         response.text()
     );
     let params = mock.last_chat_params().await.expect("provider was called");
-    assert_eq!(
-        params.extra.get("seed").and_then(|v| v.as_i64()),
-        Some(12345),
-        "seed not forwarded in `extra`"
-    );
+    assert_eq!(params.seed, Some(12345), "seed not forwarded");
 }
 
 // ── logprobs / top_logprobs ─────────────────────────────────────────────────
@@ -479,11 +475,11 @@ async fn test_json_object_response_format_accepted_and_forwarded() {
 
 /// `frequency_penalty` and `presence_penalty` are typed fields on
 /// `ChatCompletionRequest`, so they don't fall through `#[serde(flatten)] extra`
-/// on their own. They must still reach the self-hosted backend — the service
-/// layer hardcodes the typed `ChatCompletionParams` penalty slots to `None`, so
-/// the route forwards them via the `extra` passthrough map. Regression guard for
-/// #622, where both penalties were silently dropped (output byte-identical at
-/// penalty 0 vs 2.0).
+/// on their own. They must still reach the self-hosted backend — the route
+/// forwards them via `extra` and the service validates them into the typed
+/// `ChatCompletionParams` slots, together with `logit_bias`. Regression guard
+/// for #622, where both penalties were silently dropped (output byte-identical
+/// at penalty 0 vs 2.0).
 #[tokio::test]
 async fn test_penalties_accepted_and_forwarded() {
     let (server, mock, model, api_key) = setup().await;
@@ -496,6 +492,7 @@ async fn test_penalties_accepted_and_forwarded() {
             "messages": [{"role": "user", "content": "Repeat banana ten times."}],
             "frequency_penalty": 1.5,
             "presence_penalty": 0.75,
+            "logit_bias": {"50256": -100, "1234": 2.5},
             "max_tokens": 20,
             "temperature": 0,
             "stream": false,
@@ -510,19 +507,66 @@ async fn test_penalties_accepted_and_forwarded() {
     );
     let params = mock.last_chat_params().await.expect("provider was called");
     assert_eq!(
-        params
-            .extra
-            .get("frequency_penalty")
-            .and_then(|v| v.as_f64()),
+        params.frequency_penalty,
         Some(1.5),
-        "frequency_penalty not forwarded in `extra` (#622)"
+        "frequency_penalty not forwarded (#622)"
     );
     assert_eq!(
-        params
-            .extra
-            .get("presence_penalty")
-            .and_then(|v| v.as_f64()),
+        params.presence_penalty,
         Some(0.75),
-        "presence_penalty not forwarded in `extra` (#622)"
+        "presence_penalty not forwarded (#622)"
+    );
+    let logit_bias = params.logit_bias.expect("logit_bias not forwarded");
+    assert_eq!(
+        logit_bias.get("50256").and_then(|v| v.as_f64()),
+        Some(-100.0)
+    );
+    assert_eq!(logit_bias.get("1234").and_then(|v| v.as_f64()), Some(2.5));
+    for key in ["frequency_penalty", "presence_penalty", "logit_bias"] {
+        assert!(
+            !params.extra.contains_key(key),
+            "{key} should move out of `extra` into its typed field"
+        );
+    }
+}
+
+/// Out-of-range penalties and malformed `logit_bias` maps are rejected with a
+/// 400 before any provider is called.
+#[tokio::test]
+async fn test_out_of_range_penalties_rejected_before_dispatch() {
+    let (server, mock, model, api_key) = setup().await;
+
+    let cases = [
+        ("presence_penalty", serde_json::json!(2.5)),
+        ("frequency_penalty", serde_json::json!(-3)),
+        ("logit_bias", serde_json::json!({"50256": 150})),
+        ("logit_bias", serde_json::json!({"not-a-token": 1})),
+        ("logit_bias", serde_json::json!([1, 2])),
+    ];
+    for (field, value) in cases {
+        let mut body = serde_json::json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "max_tokens": 5,
+            "stream": false,
+        });
+        body[field] = value.clone();
+
+        let response = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .json(&body)
+            .await;
+
+        assert_eq!(
+            response.status_code(),
+            400,
+            "{field}={value} should be rejected, got: {}",
+            response.text()
+        );
+    }
+    assert!(
+        mock.last_chat_params().await.is_none(),
+        "invalid sampling params must not reach the provider"
     );
 }
@@ -0,0 +1,125 @@
+// E2E tests for the per-organization model allowlist
+
+use crate::common::*;
+use serde_json::json;
+
+async fn set_allowed_models(
+    server: &axum_test::TestServer,
+    org_id: &str,
+    allowed_models: serde_json::Value,
+) -> axum_test::TestResponse {
+    server
+        .patch(format!("/v1/admin/organizations/{org_id}/allowed-models").as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&json!({ "allowedModels": allowed_models }))
+        .await
+}
+
+async fn chat(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    model: &str,
+) -> axum_test::TestResponse {
+    server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "stream": false
+        }))
+        .await
+}
+
+#[tokio::test]
+async fn test_allowlist_rejects_models_not_on_it() {
+    let server = setup_test_server().await;
+    let qwen = setup_qwen_model(&server).await;
+    let glm = setup_glm_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let response = set_allowed_models(&server, &org.id, json!([qwen])).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["allowedModels"], json!([qwen]));
+
+    let response = chat(&server, &api_key, &qwen).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let response = chat(&server, &api_key, &glm).await;
+    assert_eq!(response.status_code(), 403, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["error"]["type"], "model_not_allowed");
+    assert_eq!(body["error"]["param"], "model");
+
+    let response = server
+        .get(format!("/v1/admin/organizations/{}/allowed-models", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["allowedModels"], json!([qwen]));
+}
+
+#[tokio::test]
+async fn test_absent_or_empty_allowlist_allows_all_models() {
+    let server = setup_test_server().await;
+    let qwen = setup_qwen_model(&server).await;
+    let glm = setup_glm_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    for model in [&qwen, &glm] {
+        let response = chat(&server, &api_key, model).await;
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+    }
+
+    // Restrict, then clear with an empty list; the change applies immediately
+    let response = set_allowed_models(&server, &org.id, json!([qwen])).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let response = chat(&server, &api_key, &glm).await;
+    assert_eq!(response.status_code(), 403, "{}", response.text());
+
+    let response = set_allowed_models(&server, &org.id, json!([])).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert!(body["allowedModels"].is_null());
+
+    let response = chat(&server, &api_key, &glm).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_allowlist_rejects_unknown_models() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+
+    let response = set_allowed_models(&server, &org.id, json!(["no-such/model"])).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["error"]["type"], "invalid_model");
+}
+
+#[tokio::test]
+async fn test_allowlist_applies_to_embeddings() {
+    let server = setup_test_server().await;
+    let qwen = setup_qwen_model(&server).await;
+    let embedding_model = crate::embeddings::setup_embedding_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let response = set_allowed_models(&server, &org.id, json!([qwen])).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let response = server
+        .post("/v1/embeddings")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({"model": embedding_model, "input": "Hello world"}))
+        .await;
+    assert_eq!(response.status_code(), 403, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["error"]["type"], "model_not_allowed");
+}
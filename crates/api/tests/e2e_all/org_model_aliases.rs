@@ -0,0 +1,287 @@
+// E2E tests for organization-private model aliases:
+// - an org alias resolves only for that org's API keys,
+// - org aliases take precedence over global aliases of the same name,
+// - alias management requires owner/admin and validates its inputs.
+
+use crate::common::*;
+use api::models::{
+    BatchUpdateModelApiRequest, ListOrganizationModelAliasesResponse,
+    OrganizationModelAliasResponse,
+};
+
+async fn set_alias(
+    server: &axum_test::TestServer,
+    session: &str,
+    org_id: &str,
+    alias: &str,
+    model: &str,
+) -> axum_test::TestResponse {
+    server
+        .put(
+            format!(
+                "/v1/organizations/{org_id}/model-aliases/{}",
+                urlencoding::encode(alias)
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {session}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({ "model": model }))
+        .await
+}
+
+async fn chat(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    model: &str,
+) -> axum_test::TestResponse {
+    server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "model": model,
+            "messages": [{ "role": "user", "content": "Hello" }],
+            "stream": false,
+            "max_tokens": 16
+        }))
+        .await
+}
+
+/// Register a synthetic model carrying `global_alias` as a global alias.
+async fn setup_global_alias(server: &axum_test::TestServer, global_alias: &str) -> String {
+    let model_name = format!("test-org-alias/Global-{}", uuid::Uuid::new_v4());
+    let mut batch = BatchUpdateModelApiRequest::new();
+    batch.insert(
+        model_name.clone(),
+        serde_json::from_value(serde_json::json!({
+            "inputCostPerToken":  { "amount": 1_000_000, "currency": "USD" },
+            "outputCostPerToken": { "amount": 2_000_000, "currency": "USD" },
+            "modelDisplayName":   "Global Alias Test Model",
+            "modelDescription":   "Synthetic model owning a global alias",
+            "contextLength":      4096,
+            "maxOutputLength":    1024,
+            "verifiable":         false,
+            "isActive":           true,
+            "aliases":            [global_alias]
+        }))
+        .unwrap(),
+    );
+    admin_batch_upsert_models(server, batch, get_session_id()).await;
+    model_name
+}
+
+/// Assert the request resolved to `model`, which has no serving provider.
+fn assert_resolved_to_unserved(response: &axum_test::TestResponse, model: &str) {
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+    assert!(
+        response.text().contains(&format!(
+            "Model '{model}' not found in any configured provider"
+        )),
+        "{}",
+        response.text()
+    );
+}
+
+#[tokio::test]
+async fn test_org_alias_resolves_only_within_its_org() {
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let owner = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let other = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let owner_key = get_api_key_for_org(&server, owner.id.clone()).await;
+    let other_key = get_api_key_for_org(&server, other.id.clone()).await;
+
+    let alias = format!("org-alias-{}", uuid::Uuid::new_v4());
+    let response = set_alias(
+        &server,
+        &get_session_id(),
+        &owner.id,
+        &alias,
+        E2E_QWEN_MODEL_NAME,
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let saved: OrganizationModelAliasResponse = response.json();
+    assert_eq!(saved.alias, alias);
+    assert_eq!(saved.model, E2E_QWEN_MODEL_NAME);
+
+    let response = chat(&server, &owner_key, &alias).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["model"], E2E_QWEN_MODEL_NAME);
+
+    // Another organization does not see the private alias.
+    let response = chat(&server, &other_key, &alias).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+    assert!(
+        response.text().contains("not a valid model name or alias"),
+        "{}",
+        response.text()
+    );
+
+    // x-no-aliasing treats org aliases like any other alias.
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {owner_key}"))
+        .add_header("x-no-aliasing", "true")
+        .json(&serde_json::json!({
+            "model": alias,
+            "messages": [{ "role": "user", "content": "Hello" }],
+            "max_tokens": 16
+        }))
+        .await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_org_alias_overrides_global_alias_for_that_org_only() {
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let alias = format!("shared-alias-{}", uuid::Uuid::new_v4());
+    let global_target = setup_global_alias(&server, &alias).await;
+
+    let owner = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let other = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let owner_key = get_api_key_for_org(&server, owner.id.clone()).await;
+    let other_key = get_api_key_for_org(&server, other.id.clone()).await;
+
+    let response = set_alias(
+        &server,
+        &get_session_id(),
+        &owner.id,
+        &alias,
+        E2E_QWEN_MODEL_NAME,
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let response = chat(&server, &owner_key, &alias).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["model"], E2E_QWEN_MODEL_NAME);
+
+    // The other org still gets the global resolution. The synthetic global
+    // target has no provider, so dispatch fails naming the resolved model.
+    let response = chat(&server, &other_key, &alias).await;
+    assert_resolved_to_unserved(&response, &global_target);
+
+    // Deleting the org alias falls back to the global alias.
+    let response = server
+        .delete(
+            format!(
+                "/v1/organizations/{}/model-aliases/{}",
+                owner.id,
+                urlencoding::encode(&alias)
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 204, "{}", response.text());
+
+    let response = chat(&server, &owner_key, &alias).await;
+    assert_resolved_to_unserved(&response, &global_target);
+}
+
+#[tokio::test]
+async fn test_org_alias_management_validation_and_permissions() {
+    let (server, database) = setup_test_server_with_database().await;
+    setup_qwen_model(&server).await;
+    let org = create_org(&server).await;
+    let alias = format!("managed-alias-{}", uuid::Uuid::new_v4());
+
+    // Unknown target model
+    let response = set_alias(
+        &server,
+        &get_session_id(),
+        &org.id,
+        &alias,
+        "no-such/model-for-alias",
+    )
+    .await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+
+    // An alias may not shadow a canonical model name
+    let response = set_alias(
+        &server,
+        &get_session_id(),
+        &org.id,
+        E2E_QWEN_MODEL_NAME,
+        E2E_QWEN_MODEL_NAME,
+    )
+    .await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+
+    let response = set_alias(
+        &server,
+        &get_session_id(),
+        &org.id,
+        &alias,
+        E2E_QWEN_MODEL_NAME,
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    // Plain members can list but not modify.
+    let (member_session, _) = setup_unique_test_session(&database).await;
+    let member_id = uuid::Uuid::parse_str(
+        member_session
+            .strip_prefix("rt_")
+            .unwrap_or(&member_session),
+    )
+    .unwrap();
+    let org_uuid = uuid::Uuid::parse_str(&org.id).unwrap();
+    {
+        let client = database
+            .pool()
+            .get()
+            .await
+            .expect("Failed to get database connection");
+        client
+            .execute(
+                "INSERT INTO organization_members (organization_id, user_id, role) VALUES ($1, $2, 'member') ON CONFLICT DO NOTHING",
+                &[&org_uuid, &member_id],
+            )
+            .await
+            .expect("Failed to add member");
+    }
+
+    let response = server
+        .get(format!("/v1/organizations/{}/model-aliases", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {member_session}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let listed: ListOrganizationModelAliasesResponse = response.json();
+    assert_eq!(listed.aliases.len(), 1);
+    assert_eq!(listed.aliases[0].alias, alias);
+    assert_eq!(listed.aliases[0].model, E2E_QWEN_MODEL_NAME);
+
+    let response = set_alias(
+        &server,
+        &member_session,
+        &org.id,
+        &alias,
+        E2E_QWEN_MODEL_NAME,
+    )
+    .await;
+    assert_eq!(response.status_code(), 403, "{}", response.text());
+
+    // Outsiders cannot even list.
+    let (outsider_session, _) = setup_unique_test_session(&database).await;
+    let response = server
+        .get(format!("/v1/organizations/{}/model-aliases", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {outsider_session}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 403, "{}", response.text());
+
+    // Deleting an unknown alias is a 404.
+    let response = server
+        .delete(format!("/v1/organizations/{}/model-aliases/not-an-alias", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+}
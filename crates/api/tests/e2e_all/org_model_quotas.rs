@@ -0,0 +1,179 @@
+// E2E tests for per-organization model token quotas:
+// - a quota blocks only its model once the period's usage reaches it, on
+//   chat and non-chat endpoints alike,
+// - quota management validates the model, period and limit.
+
+use crate::common::*;
+use api::models::{ListOrganizationModelQuotasResponse, OrganizationModelQuotaResponse};
+use serde_json::json;
+
+async fn set_quota(
+    server: &axum_test::TestServer,
+    org_id: &str,
+    model: &str,
+    period: &str,
+    token_limit: i64,
+) -> axum_test::TestResponse {
+    server
+        .put(
+            format!(
+                "/v1/organizations/{org_id}/model-quotas/{}/{period}",
+                urlencoding::encode(model)
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&json!({ "token_limit": token_limit }))
+        .await
+}
+
+async fn delete_quota(
+    server: &axum_test::TestServer,
+    org_id: &str,
+    model: &str,
+    period: &str,
+) -> axum_test::TestResponse {
+    server
+        .delete(
+            format!(
+                "/v1/organizations/{org_id}/model-quotas/{}/{period}",
+                urlencoding::encode(model)
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await
+}
+
+async fn chat(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    model: &str,
+) -> axum_test::TestResponse {
+    server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "stream": false
+        }))
+        .await
+}
+
+#[tokio::test]
+async fn test_daily_model_quota_blocks_only_that_model() {
+    let server = setup_test_server().await;
+    let qwen = setup_qwen_model(&server).await;
+    let glm = setup_glm_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    // Caches the organization's (empty) quota list; setting a quota below
+    // must invalidate it.
+    let response = chat(&server, &api_key, &glm).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let response = set_quota(&server, &org.id, &qwen, "day", 5).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let quota: OrganizationModelQuotaResponse = response.json();
+    assert_eq!(quota.model, qwen);
+    assert_eq!(quota.period, "day");
+    assert_eq!(quota.token_limit, 5);
+
+    // Nothing used yet today, so the first request goes through.
+    let response = chat(&server, &api_key, &qwen).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    // Usage is recorded asynchronously; wait until it counts against the quota.
+    let mut blocked = None;
+    for _ in 0..20 {
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        let response = chat(&server, &api_key, &qwen).await;
+        if response.status_code() == 429 {
+            blocked = Some(response);
+            break;
+        }
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+    }
+    let response = blocked.expect("daily quota was never enforced");
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["error"]["type"], "quota_exceeded");
+
+    // Other models are unaffected.
+    let response = chat(&server, &api_key, &glm).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    // Removing the quota lifts the block immediately.
+    let response = delete_quota(&server, &org.id, &qwen, "day").await;
+    assert_eq!(response.status_code(), 204, "{}", response.text());
+    let response = chat(&server, &api_key, &qwen).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_model_quota_management_validates_inputs() {
+    let server = setup_test_server().await;
+    let qwen = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+
+    let response = set_quota(&server, &org.id, "no-such/model", "day", 100).await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+
+    let response = set_quota(&server, &org.id, &qwen, "week", 100).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+
+    let response = set_quota(&server, &org.id, &qwen, "day", 0).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+
+    let response = set_quota(&server, &org.id, &qwen, "month", 100).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    // Setting the same model and period again replaces the limit.
+    let response = set_quota(&server, &org.id, &qwen, "month", 200).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let response = server
+        .get(format!("/v1/organizations/{}/model-quotas", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let list: ListOrganizationModelQuotasResponse = response.json();
+    assert_eq!(list.quotas.len(), 1);
+    assert_eq!(list.quotas[0].model, qwen);
+    assert_eq!(list.quotas[0].period, "month");
+    assert_eq!(list.quotas[0].token_limit, 200);
+
+    let response = delete_quota(&server, &org.id, &qwen, "day").await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_model_quota_applies_to_embeddings() {
+    let server = setup_test_server().await;
+    let model = crate::embeddings::setup_embedding_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let response = set_quota(&server, &org.id, &model, "day", 1).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let embed = || {
+        server
+            .post("/v1/embeddings")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .json(&json!({ "model": model, "input": "Hello world" }))
+    };
+
+    // Embeddings usage is recorded before the response returns, so the
+    // second request already sees the first one's tokens.
+    let response = embed().await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let response = embed().await;
+    assert_eq!(response.status_code(), 429, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["error"]["type"], "quota_exceeded");
+}
@@ -0,0 +1,179 @@
+// E2E tests for organization seat limits (`max_members`):
+// - adding a member or accepting an invitation beyond the limit fails with
+//   `seat_limit_exceeded`, and invitations are refused while the org is full,
+// - removing a member frees a seat.
+
+use crate::common::*;
+use serde_json::json;
+
+async fn set_seat_limit(
+    server: &axum_test::TestServer,
+    org_id: &str,
+    max_members: serde_json::Value,
+) -> axum_test::TestResponse {
+    server
+        .patch(format!("/v1/admin/organizations/{org_id}/seat-limit").as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&json!({ "maxMembers": max_members }))
+        .await
+}
+
+async fn add_member(
+    server: &axum_test::TestServer,
+    org_id: &str,
+    user_id: &str,
+) -> axum_test::TestResponse {
+    server
+        .post(format!("/v1/organizations/{org_id}/members").as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&json!({ "user_id": user_id, "role": "member" }))
+        .await
+}
+
+async fn invite(
+    server: &axum_test::TestServer,
+    org_id: &str,
+    email: &str,
+) -> axum_test::TestResponse {
+    server
+        .post(format!("/v1/organizations/{org_id}/members/invite-by-email").as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&json!({ "invitations": [{ "email": email, "role": "member" }] }))
+        .await
+}
+
+/// Pending invitation for the mock auth email, which every test session
+/// authenticates as.
+async fn insert_invitation(
+    database: &std::sync::Arc<database::Database>,
+    org_id: &str,
+) -> uuid::Uuid {
+    let invitation_id = uuid::Uuid::new_v4();
+    let client = database.pool().get().await.unwrap();
+    client
+        .execute(
+            "INSERT INTO organization_invitations
+                 (id, organization_id, email, role, invited_by_user_id, status, token, created_at, expires_at)
+             VALUES ($1, $2, 'admin@test.com', 'member', $3, 'pending', $4, NOW(), NOW() + INTERVAL '7 days')",
+            &[
+                &invitation_id,
+                &uuid::Uuid::parse_str(org_id).unwrap(),
+                &uuid::Uuid::parse_str(MOCK_USER_ID).unwrap(),
+                &format!("test-token-{invitation_id}"),
+            ],
+        )
+        .await
+        .unwrap();
+    invitation_id
+}
+
+async fn accept(
+    server: &axum_test::TestServer,
+    session: &str,
+    invitation_id: uuid::Uuid,
+) -> axum_test::TestResponse {
+    server
+        .post(format!("/v1/users/me/invitations/{invitation_id}/accept").as_str())
+        .add_header("Authorization", format!("Bearer {session}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await
+}
+
+fn user_id_of(session: &str) -> &str {
+    session.strip_prefix("rt_").unwrap()
+}
+
+fn assert_seat_limit_exceeded(response: &axum_test::TestResponse) {
+    assert_eq!(response.status_code(), 409, "{}", response.text());
+    let error: api::models::ErrorResponse = response.json();
+    assert_eq!(error.error.r#type, "seat_limit_exceeded");
+}
+
+#[tokio::test]
+async fn test_seat_limit_blocks_adds_and_invites_until_a_seat_frees() {
+    let (server, database) = setup_test_server_with_database().await;
+    let org = create_org(&server).await;
+    let (first_session, _) = setup_unique_test_session(&database).await;
+    let (second_session, second_email) = setup_unique_test_session(&database).await;
+
+    // The owner plus one more.
+    let response = set_seat_limit(&server, &org.id, json!(2)).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let seat_limit: api::models::OrganizationSeatLimitResponse = response.json();
+    assert_eq!(seat_limit.max_members, Some(2));
+    assert_eq!(seat_limit.member_count, 1);
+
+    let response = add_member(&server, &org.id, user_id_of(&first_session)).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    // Full: the next add and any new invitation are refused.
+    assert_seat_limit_exceeded(&add_member(&server, &org.id, user_id_of(&second_session)).await);
+    assert_seat_limit_exceeded(&invite(&server, &org.id, &second_email).await);
+
+    // Removing a member frees the seat.
+    let response = server
+        .delete(
+            format!(
+                "/v1/organizations/{}/members/{}",
+                org.id,
+                user_id_of(&first_session)
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert!(response.status_code().is_success(), "{}", response.text());
+
+    let response = invite(&server, &org.id, &second_email).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let response = add_member(&server, &org.id, user_id_of(&second_session)).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let seat_limit: api::models::OrganizationSeatLimitResponse = server
+        .get(format!("/v1/admin/organizations/{}/seat-limit", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await
+        .json();
+    assert_eq!(seat_limit.member_count, 2);
+
+    // Removing the limit lets the org grow again.
+    let response = set_seat_limit(&server, &org.id, serde_json::Value::Null).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let response = add_member(&server, &org.id, user_id_of(&first_session)).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_accepting_an_invitation_beyond_the_seat_limit_is_rejected() {
+    let (server, database) = setup_test_server_with_database().await;
+    let org = create_org(&server).await;
+    let (first_session, _) = setup_unique_test_session(&database).await;
+    let (second_session, _) = setup_unique_test_session(&database).await;
+
+    // The invitation goes out while a seat is free, but the seat is taken
+    // before it is accepted.
+    let response = set_seat_limit(&server, &org.id, json!(2)).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let invitation_id = insert_invitation(&database, &org.id).await;
+    let response = add_member(&server, &org.id, user_id_of(&first_session)).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    assert_seat_limit_exceeded(&accept(&server, &second_session, invitation_id).await);
+}
+
+#[tokio::test]
+async fn test_seat_limit_must_be_positive() {
+    let server = setup_test_server().await;
+    let org = create_org(&server).await;
+
+    let response = set_seat_limit(&server, &org.id, json!(0)).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+
+    let response = set_seat_limit(&server, &uuid::Uuid::new_v4().to_string(), json!(5)).await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+}
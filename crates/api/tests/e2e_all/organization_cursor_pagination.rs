@@ -0,0 +1,192 @@
+//! Cursor pagination for organization and member listings: rows inserted
+//! between pages must not cause duplicates or skips.
+
+use crate::common::*;
+use std::collections::HashSet;
+
+async fn get_page(
+    server: &axum_test::TestServer,
+    session_id: &str,
+    url: &str,
+) -> serde_json::Value {
+    let response = server
+        .get(url)
+        .add_header("Authorization", format!("Bearer {session_id}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200, "{url}: {}", response.text());
+    response.json::<serde_json::Value>()
+}
+
+fn ids(
+    page: &serde_json::Value,
+    list: &str,
+    id_of: impl Fn(&serde_json::Value) -> String,
+) -> Vec<String> {
+    page[list].as_array().unwrap().iter().map(id_of).collect()
+}
+
+async fn insert_member(
+    database: &std::sync::Arc<database::Database>,
+    org_id: uuid::Uuid,
+) -> String {
+    let user_id = uuid::Uuid::new_v4();
+    let client = database.pool().get().await.expect("db connection");
+    client
+        .execute(
+            "INSERT INTO users (id, email, username, display_name, avatar_url, auth_provider, provider_user_id, is_active, created_at, updated_at)
+             VALUES ($1, $2, $3, NULL, NULL, 'mock', $4, true, NOW(), NOW())",
+            &[
+                &user_id,
+                &format!("cursor-{user_id}@test.com"),
+                &format!("cursor-{user_id}"),
+                &format!("mock_cursor-{user_id}"),
+            ],
+        )
+        .await
+        .expect("insert user");
+    client
+        .execute(
+            "INSERT INTO organization_members (organization_id, user_id, role) VALUES ($1, $2, 'member')",
+            &[&org_id, &user_id],
+        )
+        .await
+        .expect("insert member");
+    user_id.to_string()
+}
+
+#[tokio::test]
+async fn test_organization_cursor_pages_stable_across_inserts() {
+    let (server, database) = setup_test_server_with_database().await;
+    let (session_id, _email) = setup_unique_test_session(&database).await;
+    let mut original = HashSet::new();
+    for _ in 0..3 {
+        original.insert(create_org_with_session(&server, &session_id).await.id);
+    }
+    let org_id = |org: &serde_json::Value| org["id"].as_str().unwrap().to_string();
+
+    let first = get_page(
+        &server,
+        &session_id,
+        "/v1/organizations?limit=2&order_direction=desc",
+    )
+    .await;
+    let mut seen = ids(&first, "organizations", org_id);
+    assert_eq!(seen.len(), 2);
+    assert!(first.get("previous_cursor").is_none());
+    let mut next_cursor = first["next_cursor"].as_str().map(str::to_string);
+    assert!(next_cursor.is_some());
+
+    // Newer organizations sort ahead of the cursor; with offsets they would
+    // push already-seen rows onto the next page.
+    for _ in 0..2 {
+        create_org_with_session(&server, &session_id).await;
+    }
+
+    let mut last_page = first;
+    while let Some(cursor) = next_cursor {
+        last_page = get_page(
+            &server,
+            &session_id,
+            &format!("/v1/organizations?limit=2&order_direction=desc&after={cursor}"),
+        )
+        .await;
+        seen.extend(ids(&last_page, "organizations", org_id));
+        next_cursor = last_page["next_cursor"].as_str().map(str::to_string);
+    }
+
+    let unique: HashSet<String> = seen.iter().cloned().collect();
+    assert_eq!(
+        unique.len(),
+        seen.len(),
+        "duplicate rows across pages: {seen:?}"
+    );
+    assert_eq!(
+        unique, original,
+        "cursor pages must cover exactly the original rows"
+    );
+
+    // Paging back from the last page returns the first page's rows again.
+    let previous = last_page["previous_cursor"]
+        .as_str()
+        .expect("previous_cursor");
+    let back = get_page(
+        &server,
+        &session_id,
+        &format!("/v1/organizations?limit=2&order_direction=desc&before={previous}"),
+    )
+    .await;
+    assert_eq!(ids(&back, "organizations", org_id), seen[..2]);
+}
+
+#[tokio::test]
+async fn test_member_cursor_pages_stable_across_inserts() {
+    let (server, database) = setup_test_server_with_database().await;
+    let (session_id, _email) = setup_unique_test_session(&database).await;
+    let org = create_org_with_session(&server, &session_id).await;
+    let org_uuid = uuid::Uuid::parse_str(&org.id).unwrap();
+    let url = format!("/v1/organizations/{}/members?limit=2", org.id);
+    let member_id = |member: &serde_json::Value| member["user"]["id"].as_str().unwrap().to_string();
+
+    for _ in 0..4 {
+        insert_member(&database, org_uuid).await;
+    }
+    let original: HashSet<String> = ids(
+        &get_page(
+            &server,
+            &session_id,
+            &format!("/v1/organizations/{}/members", org.id),
+        )
+        .await,
+        "members",
+        member_id,
+    )
+    .into_iter()
+    .collect();
+    assert_eq!(original.len(), 5, "owner plus four inserted members");
+
+    let first = get_page(&server, &session_id, &url).await;
+    let mut seen = ids(&first, "members", member_id);
+    let mut next_cursor = first["next_cursor"].as_str().map(str::to_string);
+
+    // Members are listed newest first, so new joiners land before the cursor.
+    for _ in 0..2 {
+        insert_member(&database, org_uuid).await;
+    }
+
+    while let Some(cursor) = next_cursor {
+        let page = get_page(&server, &session_id, &format!("{url}&after={cursor}")).await;
+        seen.extend(ids(&page, "members", member_id));
+        next_cursor = page["next_cursor"].as_str().map(str::to_string);
+    }
+
+    let unique: HashSet<String> = seen.iter().cloned().collect();
+    assert_eq!(
+        unique.len(),
+        seen.len(),
+        "duplicate rows across pages: {seen:?}"
+    );
+    assert_eq!(
+        unique, original,
+        "cursor pages must cover exactly the original rows"
+    );
+}
+
+#[tokio::test]
+async fn test_cursor_params_validated() {
+    let (server, database) = setup_test_server_with_database().await;
+    let (session_id, _email) = setup_unique_test_session(&database).await;
+
+    for query in [
+        "after=garbage",
+        "after=a&before=b",
+        "offset=1&after=garbage",
+    ] {
+        let response = server
+            .get(&format!("/v1/organizations?{query}"))
+            .add_header("Authorization", format!("Bearer {session_id}"))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .await;
+        assert_eq!(response.status_code(), 400, "{query}: {}", response.text());
+    }
+}
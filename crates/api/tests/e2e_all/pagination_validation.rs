@@ -16,16 +16,31 @@ async fn test_list_workspaces_invalid_limit() {
     assert_eq!(err.error.message, "Limit must be positive");
     assert_eq!(err.error.r#type, "invalid_parameter");
 
-    // Test limit > 1000
+    // Test limit > 1000 is clamped to the maximum page size
     let response = server
         .get(format!("/v1/organizations/{}/workspaces?limit=1001", org.id).as_str())
         .add_header("Authorization", format!("Bearer {}", get_session_id()))
         .await;
 
-    assert_eq!(response.status_code(), 400);
-    let err = response.json::<api::models::ErrorResponse>();
-    assert_eq!(err.error.message, "Limit cannot exceed 1000");
-    assert_eq!(err.error.r#type, "invalid_parameter");
+    assert_eq!(response.status_code(), 200);
+    let body = response.json::<serde_json::Value>();
+    assert_eq!(body["limit"], config::DEFAULT_MAX_PAGE_SIZE);
+}
+
+#[tokio::test]
+async fn test_list_workspaces_default_limit() {
+    let server = setup_test_server().await;
+    let org = create_org(&server).await;
+
+    let response = server
+        .get(format!("/v1/organizations/{}/workspaces", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+
+    assert_eq!(response.status_code(), 200);
+    let body = response.json::<serde_json::Value>();
+    assert_eq!(body["limit"], config::DEFAULT_PAGE_SIZE);
+    assert_eq!(body["offset"], 0);
 }
 
 #[tokio::test]
@@ -63,16 +78,32 @@ async fn test_list_api_keys_invalid_limit() {
     assert_eq!(err.error.message, "Limit must be positive");
     assert_eq!(err.error.r#type, "invalid_parameter");
 
-    // Test limit > 1000
+    // Test limit > 1000 is clamped to the maximum page size
     let response = server
         .get(format!("/v1/workspaces/{}/api-keys?limit=1001", workspace.id).as_str())
         .add_header("Authorization", format!("Bearer {}", get_session_id()))
         .await;
 
-    assert_eq!(response.status_code(), 400);
-    let err = response.json::<api::models::ErrorResponse>();
-    assert_eq!(err.error.message, "Limit cannot exceed 1000");
-    assert_eq!(err.error.r#type, "invalid_parameter");
+    assert_eq!(response.status_code(), 200);
+    let body = response.json::<api::models::ListApiKeysResponse>();
+    assert_eq!(body.limit, config::DEFAULT_MAX_PAGE_SIZE);
+}
+
+#[tokio::test]
+async fn test_list_api_keys_default_limit() {
+    let server = setup_test_server().await;
+    let org = create_org(&server).await;
+    let workspaces = list_workspaces(&server, org.id.clone()).await;
+    let workspace = workspaces.first().unwrap();
+
+    let response = server
+        .get(format!("/v1/workspaces/{}/api-keys", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+
+    assert_eq!(response.status_code(), 200);
+    let body = response.json::<api::models::ListApiKeysResponse>();
+    assert_eq!(body.limit, config::DEFAULT_PAGE_SIZE);
 }
 
 #[tokio::test]
@@ -36,6 +36,8 @@ fn test_db_config() -> DatabaseConfig {
         max_read_connections: 2,
         tls_enabled: false,
         tls_ca_cert_path: None,
+        tls_min_version: None,
+        tls_pinned_spki_sha256: Vec::new(),
     }
 }
 
@@ -371,10 +371,12 @@ async fn test_provider_error_500_becomes_502() {
     );
 }
 
-/// Test that a model configured in DB but not in provider pool returns 400
+/// Test that a model configured in DB but not in provider pool returns 404
+/// once discovery has initialized (before that it is 503 `model_warming`)
 #[tokio::test]
-async fn test_model_not_found_in_provider_returns_400() {
-    let (server, _pool, _mock_provider, _db) = setup_test_server_with_pool().await;
+async fn test_model_not_found_in_provider_returns_404() {
+    let (server, pool, _mock_provider, _db) = setup_test_server_with_pool().await;
+    assert!(pool.is_discovery_initialized());
 
     // Register a model in the database that is NOT in the provider pool
     let mut batch = BatchUpdateModelApiRequest::new();
@@ -405,13 +407,15 @@ async fn test_model_not_found_in_provider_returns_400() {
 
     assert_eq!(
         response.status_code(),
-        400,
-        "Expected 400 for model not in provider pool, got {}",
+        404,
+        "Expected 404 for model not in provider pool, got {}",
         response.status_code()
     );
+    assert!(response.headers().get(RETRY_AFTER).is_none());
 
     let err = response.json::<api::models::ErrorResponse>();
-    assert_eq!(err.error.r#type, "invalid_request_error");
+    assert_eq!(err.error.r#type, "model_not_found");
+    assert_eq!(err.error.param.as_deref(), Some("model"));
     assert!(
         err.error.message.contains("nonexistent/FakeModel-1B"),
         "Error message should mention the model name. Got: {}",
@@ -534,3 +538,163 @@ async fn test_vllm_400_stays_400() {
     let err = response.json::<api::models::ErrorResponse>();
     assert_eq!(err.error.r#type, "invalid_request_error");
 }
+
+// ============================================
+// Admin error detail passthrough
+// ============================================
+
+const RAW_PROVIDER_ERROR: &str =
+    "error sending request for url (http://10.1.2.3:8000/v1/chat/completions): boom";
+
+/// Insert a user outside the test admin domain and return its session id
+async fn setup_non_admin_session(database: &std::sync::Arc<database::Database>) -> String {
+    let user_id = uuid::Uuid::new_v4();
+    let client = database.pool().get().await.unwrap();
+    client
+        .execute(
+            "INSERT INTO users (id, email, username, auth_provider, provider_user_id, created_at, updated_at)
+             VALUES ($1, $2, $3, 'mock', $4, NOW(), NOW())",
+            &[
+                &user_id,
+                &format!("user-{user_id}@example.org"),
+                &format!("user-{user_id}"),
+                &format!("mock_user-{user_id}"),
+            ],
+        )
+        .await
+        .unwrap();
+    format!("rt_{user_id}")
+}
+
+/// Send a chat completion the mock provider fails, optionally authenticating
+/// the caller as `admin_session` through `X-Admin-Authorization`
+async fn failing_chat_completion(
+    server: &axum_test::TestServer,
+    api_key: &str,
+    query: &str,
+    admin_session: Option<&str>,
+) -> serde_json::Value {
+    let mut request = server
+        .post(&format!("/v1/chat/completions{query}"))
+        .add_header("Authorization", format!("Bearer {api_key}"));
+    if let Some(session) = admin_session {
+        request = request.add_header("X-Admin-Authorization", format!("Bearer {session}"));
+    }
+    let response = request
+        .json(&chat_request("Qwen/Qwen3-30B-A3B-Instruct-2507", false))
+        .await;
+    assert_eq!(response.status_code(), 400);
+    response.json()
+}
+
+#[tokio::test]
+async fn test_non_admin_never_sees_raw_provider_error() {
+    let (server, _pool, mock_provider, db) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    let session = setup_non_admin_session(&db).await;
+    let org = setup_org_with_credits_and_session(&server, 10_000_000_000i64, &session).await;
+    let api_key = get_api_key_for_org_with_session(&server, org.id, &session).await;
+    mock_provider
+        .set_error_override(Some(inference_providers::CompletionError::HttpError {
+            status_code: 400,
+            message: RAW_PROVIDER_ERROR.to_string(),
+            is_external: false,
+        }))
+        .await;
+
+    for (query, admin_session) in [
+        ("", None),
+        ("?debug_errors=true", None),
+        ("?debug_errors=true", Some(session.as_str())),
+    ] {
+        let body = failing_chat_completion(&server, &api_key, query, admin_session).await;
+        let text = body.to_string();
+        assert!(!text.contains("10.1.2.3"), "leaked IP: {text}");
+        assert!(!text.contains("http://"), "leaked URL: {text}");
+        assert!(body["error"]["details"]["detailed_error"].is_null());
+    }
+}
+
+#[tokio::test]
+async fn test_admin_with_debug_flag_sees_raw_provider_error() {
+    let (server, _pool, mock_provider, _db) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    mock_provider
+        .set_error_override(Some(inference_providers::CompletionError::HttpError {
+            status_code: 400,
+            message: RAW_PROVIDER_ERROR.to_string(),
+            is_external: false,
+        }))
+        .await;
+
+    let admin_session = get_session_id();
+
+    // Without the flag the admin gets the same sanitized error as everyone
+    let body = failing_chat_completion(&server, &api_key, "", Some(&admin_session)).await;
+    assert!(!body.to_string().contains("10.1.2.3"), "body: {body}");
+    assert!(body["error"]["details"]["detailed_error"].is_null());
+
+    // An admin-created key alone does not make the caller an admin
+    let body = failing_chat_completion(&server, &api_key, "?debug_errors=true", None).await;
+    assert!(body["error"]["details"]["detailed_error"].is_null());
+
+    let body = failing_chat_completion(
+        &server,
+        &api_key,
+        "?debug_errors=true",
+        Some(&admin_session),
+    )
+    .await;
+    let message = body["error"]["message"].as_str().unwrap();
+    assert!(
+        !message.contains("10.1.2.3"),
+        "message stays sanitized: {message}"
+    );
+    let detailed = body["error"]["details"]["detailed_error"]
+        .as_str()
+        .expect("admin with debug flag should get detailed_error");
+    assert!(
+        detailed.contains("http://10.1.2.3:8000/v1/chat/completions"),
+        "detailed: {detailed}"
+    );
+}
+
+#[tokio::test]
+async fn test_admin_access_token_unlocks_debug_errors() {
+    let (server, _pool, mock_provider, _db) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    mock_provider
+        .set_error_override(Some(inference_providers::CompletionError::HttpError {
+            status_code: 400,
+            message: RAW_PROVIDER_ERROR.to_string(),
+            is_external: false,
+        }))
+        .await;
+
+    let access_token = get_access_token_from_refresh_token(&server, get_session_id()).await;
+    let response = server
+        .post("/v1/admin/access-tokens")
+        .add_header("Authorization", format!("Bearer {access_token}"))
+        .json(&serde_json::json!({
+            "expires_in_hours": 1,
+            "name": "debug errors",
+            "reason": "Testing admin debug output"
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let admin_token = response
+        .json::<api::models::AdminAccessTokenResponse>()
+        .access_token;
+    assert!(admin_token.starts_with("adm_"));
+
+    let body =
+        failing_chat_completion(&server, &api_key, "?debug_errors=true", Some(&admin_token)).await;
+    assert!(
+        body["error"]["details"]["detailed_error"].is_string(),
+        "admin access token should unlock detailed_error: {body}"
+    );
+}
@@ -0,0 +1,74 @@
+// E2E tests for forwarding allowlisted client headers to the inference provider
+
+use crate::common::*;
+
+const PASSTHROUGH_KEY: &str = "x_passthrough_headers";
+
+async fn send_chat(server: &axum_test::TestServer, api_key: &str, body: serde_json::Value) {
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .add_header("X-Routing-Hint", "pool-a")
+        .add_header("X-Not-Allowed", "drop-me")
+        .add_header("X-Request-Id", "client-supplied")
+        .json(&body)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
+
+fn chat_body() -> serde_json::Value {
+    serde_json::json!({
+        "model": E2E_QWEN_MODEL_NAME,
+        "messages": [{"role": "user", "content": "Hello"}],
+        "stream": false,
+        "max_tokens": 10
+    })
+}
+
+#[tokio::test]
+async fn test_allowlisted_headers_reach_provider() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|c| {
+        c.provider_headers =
+            config::ProviderHeadersConfig::parse("X-Routing-Hint,X-Tenant-Tier").unwrap();
+    })
+    .await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    send_chat(&server, &api_key, chat_body()).await;
+
+    let params = mock_provider.last_chat_params().await.unwrap();
+    let forwarded = params
+        .extra
+        .get(PASSTHROUGH_KEY)
+        .and_then(|v| v.as_object())
+        .expect("allowlisted headers should be forwarded");
+    assert_eq!(
+        forwarded.len(),
+        1,
+        "only allowlisted headers: {forwarded:?}"
+    );
+    assert_eq!(forwarded["x-routing-hint"], "pool-a");
+}
+
+#[tokio::test]
+async fn test_headers_not_forwarded_without_allowlist() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    // A client cannot smuggle headers through the body either.
+    let mut body = chat_body();
+    body[PASSTHROUGH_KEY] = serde_json::json!({"x-routing-hint": "pool-b"});
+    send_chat(&server, &api_key, body).await;
+
+    let params = mock_provider.last_chat_params().await.unwrap();
+    assert!(
+        !params.extra.contains_key(PASSTHROUGH_KEY),
+        "no headers should be forwarded: {:?}",
+        params.extra
+    );
+}
@@ -10,6 +10,10 @@
 //!      the response (non-streaming and streaming), and
 //!   2. the `chat_template_kwargs` toggle is forwarded to the provider intact
 //!      (it rides in the `extra` passthrough map).
+//!
+//! Reasoning tokens the provider reports in
+//! `usage.completion_tokens_details.reasoning_tokens` are forwarded too, and
+//! are part of the billed output tokens.
 
 use crate::common::*;
 use inference_providers::mock::{RequestMatcher, ResponseTemplate};
@@ -71,6 +75,14 @@ async fn test_reasoning_content_surfaced_non_streaming() {
         Some("Let me think step by step about the question."),
         "reasoning_content not surfaced in non-streaming response: {body}"
     );
+
+    // The mock counts one token per reasoning word, inside completion_tokens.
+    let usage = &body["usage"];
+    assert_eq!(
+        usage["completion_tokens_details"]["reasoning_tokens"], 9,
+        "reasoning tokens not forwarded: {body}"
+    );
+    assert!(usage["completion_tokens"].as_i64().unwrap() >= 9);
 }
 
 /// Same, but for streaming: reasoning deltas appear in the SSE stream and
@@ -95,6 +107,7 @@ async fn test_reasoning_content_surfaced_streaming() {
             "messages": [{"role": "user", "content": "What is the answer?"}],
             "max_tokens": 50,
             "stream": true,
+            "stream_options": {"include_usage": true},
         }))
         .await;
 
@@ -103,6 +116,7 @@ async fn test_reasoning_content_surfaced_streaming() {
 
     // Reassemble reasoning from the streamed deltas.
     let mut reasoning = String::new();
+    let mut last_usage = None;
     for line in body.lines() {
         let Some(data) = line.strip_prefix("data: ") else {
             continue;
@@ -113,6 +127,9 @@ async fn test_reasoning_content_surfaced_streaming() {
         let Ok(chunk) = serde_json::from_str::<serde_json::Value>(data) else {
             continue;
         };
+        if chunk["usage"].is_object() {
+            last_usage = Some(chunk["usage"].clone());
+        }
         if let Some(delta) = chunk.pointer("/choices/0/delta") {
             if let Some(rc) = delta.get("reasoning_content").and_then(|v| v.as_str()) {
                 reasoning.push_str(rc);
@@ -125,6 +142,58 @@ async fn test_reasoning_content_surfaced_streaming() {
         reasoning.contains("Thinking about it carefully"),
         "reasoning not reassembled from streamed deltas (got {reasoning:?}): {body}"
     );
+    let usage = last_usage.expect("final usage chunk");
+    assert_eq!(
+        usage["completion_tokens_details"]["reasoning_tokens"], 4,
+        "reasoning tokens not forwarded in streamed usage: {usage}"
+    );
+}
+
+/// Reasoning tokens are billed as output: the recorded output token count
+/// matches the completion_tokens returned to the client, which include them.
+#[tokio::test]
+async fn test_reasoning_tokens_billed_as_output() {
+    let (server, _pool, mock, _db) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    mock.when(RequestMatcher::Any)
+        .respond_with(ResponseTemplate::new("Done.").with_reasoning("One two three four five."))
+        .await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "Count."}],
+            "max_tokens": 50,
+            "stream": false,
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body = response.json::<serde_json::Value>();
+    let completion_tokens = body["usage"]["completion_tokens"].as_i64().unwrap();
+    let reasoning_tokens = body["usage"]["completion_tokens_details"]["reasoning_tokens"]
+        .as_i64()
+        .unwrap();
+    assert_eq!(reasoning_tokens, 5);
+    assert!(completion_tokens > reasoning_tokens);
+
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    let history = server
+        .get(&format!(
+            "/v1/organizations/{}/usage/history?limit=10&offset=0",
+            org.id
+        ))
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(history.status_code(), 200, "{}", history.text());
+    let history: api::routes::usage::UsageHistoryResponse = history.json();
+    assert_eq!(history.data.len(), 1);
+    assert_eq!(history.data[0].output_tokens as i64, completion_tokens);
 }
 
 /// The `chat_template_kwargs` reasoning toggle must be forwarded to the
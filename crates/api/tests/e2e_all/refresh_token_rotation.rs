@@ -0,0 +1,156 @@
+//! End-to-end tests for refresh-token rotation and reuse detection.
+//!
+//! Run against the real `AuthService` (`auth.mock = false`) and the
+//! PostgreSQL-backed session repository. A session row is a token family:
+//! every refresh rotates its token, and replaying a rotated-out token
+//! revokes the whole family.
+
+use crate::common::*;
+use database::repositories::SessionRepository;
+use std::sync::Arc;
+
+const TEST_UA: &str = "Mozilla/5.0 (X11; Linux x86_64) TestBrowser/1.0";
+
+async fn real_auth_server() -> (axum_test::TestServer, Arc<database::Database>) {
+    setup_test_server_with_config_and_database(|c| c.auth.mock = false).await
+}
+
+/// Insert a unique user directly in the database and return its id.
+///
+/// `created_at` is backdated so admin listings keep the shared mock user on
+/// page one.
+async fn create_real_user(database: &Arc<database::Database>) -> uuid::Uuid {
+    let user_id = uuid::Uuid::new_v4();
+    let client = database
+        .pool()
+        .get()
+        .await
+        .expect("Failed to get database connection");
+    client.execute(
+        "INSERT INTO users (id, email, username, display_name, avatar_url, auth_provider, provider_user_id, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, NOW() - INTERVAL '30 days', NOW())",
+        &[
+            &user_id,
+            &format!("rotation-test-{user_id}@test.com"),
+            &format!("rotation-test-{user_id}"),
+            &Some("Rotation Test User".to_string()),
+            &None::<String>,
+            &"mock",
+            &format!("rotation-test-{user_id}"),
+        ],
+    ).await.expect("Failed to create test user");
+    user_id
+}
+
+async fn cleanup_user(database: &Arc<database::Database>, user_id: uuid::Uuid) {
+    let client = database
+        .pool()
+        .get()
+        .await
+        .expect("Failed to get database connection");
+    client
+        .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+        .await
+        .expect("Failed to clean up test user");
+}
+
+async fn refresh(server: &axum_test::TestServer, refresh_token: &str) -> axum_test::TestResponse {
+    server
+        .post("/v1/users/me/access-tokens")
+        .add_header("Authorization", format!("Bearer {refresh_token}"))
+        .add_header("User-Agent", TEST_UA)
+        .await
+}
+
+async fn me_status(server: &axum_test::TestServer, access_token: &str) -> u16 {
+    server
+        .get("/v1/users/me")
+        .add_header("Authorization", format!("Bearer {access_token}"))
+        .await
+        .status_code()
+        .as_u16()
+}
+
+#[tokio::test]
+async fn test_refresh_rotates_token_and_bumps_generation() {
+    let (server, database) = real_auth_server().await;
+    let user_id = create_real_user(&database).await;
+    let repo = SessionRepository::new(database.pool().clone());
+    let (session, rt0) = repo
+        .create(user_id, None, TEST_UA.to_string(), 24)
+        .await
+        .expect("Failed to create session");
+    assert_eq!(session.generation, 0);
+
+    let response = refresh(&server, &rt0).await;
+    assert_eq!(response.status_code(), 200);
+    let first = response.json::<api::models::AccessAndRefreshTokenResponse>();
+    assert_ne!(first.refresh_token, rt0, "refresh must issue a new token");
+
+    let response = refresh(&server, &first.refresh_token).await;
+    assert_eq!(response.status_code(), 200);
+    let second = response.json::<api::models::AccessAndRefreshTokenResponse>();
+    assert_ne!(second.refresh_token, first.refresh_token);
+    assert_eq!(me_status(&server, &second.access_token).await, 200);
+
+    // Rotation happens in place: same session, generation advanced per refresh.
+    let stored = repo
+        .get_by_id(session.id)
+        .await
+        .expect("Failed to query session")
+        .expect("session must still exist after rotation");
+    assert_eq!(stored.generation, 2);
+
+    cleanup_user(&database, user_id).await;
+}
+
+#[tokio::test]
+async fn test_replayed_refresh_token_revokes_session_family() {
+    let (server, database) = real_auth_server().await;
+    let user_id = create_real_user(&database).await;
+    let repo = SessionRepository::new(database.pool().clone());
+    let (session, rt0) = repo
+        .create(user_id, None, TEST_UA.to_string(), 24)
+        .await
+        .expect("Failed to create session");
+    let (other_session, other_rt) = repo
+        .create(user_id, None, TEST_UA.to_string(), 24)
+        .await
+        .expect("Failed to create session");
+
+    let response = refresh(&server, &rt0).await;
+    assert_eq!(response.status_code(), 200);
+    let current = response.json::<api::models::AccessAndRefreshTokenResponse>();
+    assert_eq!(me_status(&server, &current.access_token).await, 200);
+
+    // Presenting the rotated-out token again is treated as compromise.
+    let response = refresh(&server, &rt0).await;
+    assert_eq!(
+        response.status_code(),
+        401,
+        "replayed refresh token must be rejected"
+    );
+
+    // The whole family is gone: the latest refresh token and the access
+    // token bound to the session no longer work.
+    assert_eq!(
+        refresh(&server, &current.refresh_token).await.status_code(),
+        401
+    );
+    assert_eq!(me_status(&server, &current.access_token).await, 401);
+    assert!(repo
+        .get_by_id(session.id)
+        .await
+        .expect("Failed to query session")
+        .is_none());
+
+    // Other sessions of the same user are unaffected.
+    assert!(repo
+        .get_by_id(other_session.id)
+        .await
+        .expect("Failed to query session")
+        .is_some());
+    assert_eq!(refresh(&server, &other_rt).await.status_code(), 200);
+
+    cleanup_user(&database, user_id).await;
+}
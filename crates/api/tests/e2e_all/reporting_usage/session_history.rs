@@ -109,6 +109,8 @@ fn session_usage_history_openapi_lists_supported_filters() {
         "end_date",
         "start_time",
         "end_time",
+        "created_after",
+        "created_before",
         "workspace_id",
         "api_key_id",
     ] {
@@ -0,0 +1,142 @@
+// E2E tests for the configurable body-size limit on JSON inference routes.
+
+use crate::common::*;
+
+const LIMIT: usize = 64 * 1024;
+
+async fn setup() -> (axum_test::TestServer, String) {
+    let server = setup_test_server_with_config(|config| {
+        config.server.max_inference_body_bytes = LIMIT;
+    })
+    .await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    (server, api_key)
+}
+
+fn assert_payload_too_large(response: &axum_test::TestResponse) {
+    assert_eq!(response.status_code(), 413);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["error"]["type"], "payload_too_large");
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap_or_default()
+        .contains(&LIMIT.to_string()));
+}
+
+#[tokio::test]
+async fn test_oversized_inference_bodies_return_structured_413() {
+    let (server, api_key) = setup().await;
+    let oversized = "a".repeat(LIMIT + 1);
+
+    for (path, body) in [
+        (
+            "/v1/chat/completions",
+            serde_json::json!({
+                "model": E2E_QWEN_MODEL_NAME,
+                "messages": [{ "role": "user", "content": oversized }],
+            }),
+        ),
+        (
+            "/v1/completions",
+            serde_json::json!({ "model": E2E_QWEN_MODEL_NAME, "prompt": oversized }),
+        ),
+        (
+            "/v1/embeddings",
+            serde_json::json!({ "model": E2E_QWEN_MODEL_NAME, "input": oversized }),
+        ),
+        (
+            "/v1/responses",
+            serde_json::json!({ "model": E2E_QWEN_MODEL_NAME, "input": oversized }),
+        ),
+    ] {
+        let response = server
+            .post(path)
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .json(&body)
+            .await;
+        assert_payload_too_large(&response);
+    }
+}
+
+#[tokio::test]
+async fn test_body_under_limit_is_accepted() {
+    let (server, api_key) = setup().await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{ "role": "user", "content": "a".repeat(LIMIT / 2) }],
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200);
+}
+
+#[tokio::test]
+async fn test_unauthenticated_oversized_body_is_rejected_by_auth() {
+    let (server, _) = setup().await;
+
+    for path in ["/v1/chat/completions", "/v1/responses"] {
+        let response = server
+            .post(path)
+            .json(&serde_json::json!({
+                "model": E2E_QWEN_MODEL_NAME,
+                "input": "a".repeat(LIMIT + 1),
+            }))
+            .await;
+        assert_eq!(response.status_code(), 401, "{path}");
+    }
+}
+
+#[tokio::test]
+async fn test_body_over_axum_default_is_accepted_under_configured_limit() {
+    // Above axum's 2 MB extractor default, below the 10 MB configured default.
+    // The bulk rides in an extra field so the prompt stays within the model's
+    // context window.
+    let padding = "a".repeat(3 * 1024 * 1024);
+    assert!(padding.len() < config::DEFAULT_MAX_INFERENCE_BODY_BYTES);
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let embedding_model = crate::embeddings::setup_embedding_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    for (path, body) in [
+        (
+            "/v1/chat/completions",
+            serde_json::json!({
+                "model": E2E_QWEN_MODEL_NAME,
+                "messages": [{ "role": "user", "content": "hi" }],
+                "padding": padding,
+            }),
+        ),
+        (
+            "/v1/completions",
+            serde_json::json!({
+                "model": E2E_QWEN_MODEL_NAME,
+                "prompt": "hi",
+                "padding": padding,
+            }),
+        ),
+        (
+            "/v1/embeddings",
+            serde_json::json!({
+                "model": embedding_model,
+                "input": "hi",
+                "padding": padding,
+            }),
+        ),
+    ] {
+        let response = server
+            .post(path)
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .json(&body)
+            .await;
+        assert_eq!(response.status_code(), 200, "{path}: {}", response.text());
+    }
+}
@@ -242,3 +242,41 @@ async fn test_request_id_contract_for_cors_and_streaming_surfaces() {
     );
     println!("request_id_contract tenant spoof rejection: public tenant headers ignored");
 }
+
+#[tokio::test]
+async fn test_traceparent_seeds_request_id_propagated_upstream() {
+    // Given
+    let (server, mock_provider) = setup_request_id_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let model = setup_qwen_model(&server).await;
+    let trace_id = Uuid::new_v4();
+    let traceparent = format!("00-{}-00f067aa0ba902b7-01", trace_id.simple());
+
+    // When
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("traceparent", traceparent)
+        .json(&serde_json::json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "sentinel-traceparent"}]
+        }))
+        .await;
+
+    // Then
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(assert_uuid_response_id("traceparent", &response), trace_id);
+    let params = mock_provider
+        .last_chat_params()
+        .await
+        .expect("request should reach mock provider");
+    assert_eq!(
+        params
+            .extra
+            .get("x_request_id")
+            .and_then(serde_json::Value::as_str),
+        Some(trace_id.to_string().as_str()),
+        "the trace-id should propagate upstream as the request ID"
+    );
+}
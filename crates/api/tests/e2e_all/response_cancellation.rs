@@ -0,0 +1,252 @@
+// E2E tests for cancelling in-flight responses
+
+use crate::common::*;
+use std::time::{Duration, Instant};
+
+const SLOW_GENERATION: Duration = Duration::from_secs(30);
+
+async fn create_conversation(server: &axum_test::TestServer, api_key: &str) -> uuid::Uuid {
+    let response = server
+        .post("/v1/conversations")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({}))
+        .await;
+    assert_eq!(response.status_code(), 201);
+    let conversation = response.json::<api::models::ConversationObject>();
+    uuid::Uuid::parse_str(
+        conversation
+            .id
+            .strip_prefix("conv_")
+            .unwrap_or(&conversation.id),
+    )
+    .expect("conversation id")
+}
+
+async fn response_status(
+    database: &database::Database,
+    conversation_id: uuid::Uuid,
+) -> Option<(uuid::Uuid, String)> {
+    let client = database.pool().get().await.expect("db connection");
+    client
+        .query_opt(
+            "SELECT id, status FROM responses WHERE conversation_id = $1 ORDER BY created_at DESC LIMIT 1",
+            &[&conversation_id],
+        )
+        .await
+        .expect("query responses")
+        .map(|row| (row.get("id"), row.get("status")))
+}
+
+#[tokio::test]
+async fn test_cancel_aborts_streaming_response() {
+    let (server, _pool, mock, database) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let conversation_id = create_conversation(&server, &api_key).await;
+
+    mock.set_default_response(
+        inference_providers::mock::ResponseTemplate::new("never delivered")
+            .with_first_chunk_delay(SLOW_GENERATION),
+    )
+    .await;
+
+    let started = Instant::now();
+    let stream_request = server
+        .post("/v1/responses")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "conversation": { "id": format!("conv_{}", conversation_id.simple()) },
+            "input": "Write a very long story",
+            "stream": true,
+            "model": model
+        }));
+    let cancel = async {
+        let response_id = loop {
+            if let Some((id, status)) = response_status(&database, conversation_id).await {
+                if status == "in_progress" {
+                    break id;
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        };
+        server
+            .post(&format!(
+                "/v1/responses/resp_{}/cancel",
+                response_id.simple()
+            ))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await
+    };
+    let (stream_response, cancel_response) = tokio::join!(stream_request, cancel);
+
+    assert_eq!(
+        cancel_response.status_code(),
+        200,
+        "{}",
+        cancel_response.text()
+    );
+    let cancelled: serde_json::Value = cancel_response.json();
+    assert_eq!(cancelled["status"], "cancelled");
+
+    assert!(
+        started.elapsed() < SLOW_GENERATION / 3,
+        "stream must terminate promptly after cancel, took {:?}",
+        started.elapsed()
+    );
+    assert_eq!(stream_response.status_code(), 200);
+    let text = stream_response.text();
+    let last_event = text
+        .lines()
+        .rev()
+        .find_map(|line| line.strip_prefix("event: "))
+        .expect("stream carries events");
+    assert_eq!(last_event, "response.cancelled");
+    assert!(!text.contains("response.completed"));
+
+    let (_, status) = response_status(&database, conversation_id)
+        .await
+        .expect("response row");
+    assert_eq!(status, "cancelled");
+}
+
+#[tokio::test]
+async fn test_cancel_finished_response_is_rejected() {
+    let (server, _pool, _mock, database) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let conversation_id = create_conversation(&server, &api_key).await;
+
+    let response = server
+        .post("/v1/responses")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "conversation": { "id": format!("conv_{}", conversation_id.simple()) },
+            "input": "hello",
+            "stream": false,
+            "model": model
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200);
+    let response_id = response.json::<api::models::ResponseObject>().id;
+
+    let cancel = server
+        .post(&format!("/v1/responses/{response_id}/cancel"))
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(cancel.status_code(), 400, "{}", cancel.text());
+
+    let (_, status) = response_status(&database, conversation_id)
+        .await
+        .expect("response row");
+    assert_eq!(status, "completed");
+}
+
+#[tokio::test]
+async fn test_late_completion_does_not_overwrite_cancel() {
+    use services::responses::models::{ResponseId, ResponseStatus};
+    use services::responses::ports::ResponseRepositoryTrait;
+    use services::workspace::WorkspaceId;
+
+    let (server, _pool, _mock, database) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let conversation_id = create_conversation(&server, &api_key).await;
+
+    let response = server
+        .post("/v1/responses")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "conversation": { "id": format!("conv_{}", conversation_id.simple()) },
+            "input": "hello",
+            "stream": false,
+            "model": model
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200);
+    let (response_uuid, _) = response_status(&database, conversation_id)
+        .await
+        .expect("response row");
+    let client = database.pool().get().await.expect("db connection");
+    let workspace_id: uuid::Uuid = client
+        .query_one(
+            "SELECT workspace_id FROM responses WHERE id = $1",
+            &[&response_uuid],
+        )
+        .await
+        .expect("workspace id")
+        .get(0);
+    let (id, workspace_id) = (ResponseId(response_uuid), WorkspaceId(workspace_id));
+    let repository = database::PgResponseRepository::new(database.pool().clone());
+    let reopen = || async {
+        client
+            .execute(
+                "UPDATE responses SET status = 'in_progress' WHERE id = $1",
+                &[&response_uuid],
+            )
+            .await
+            .expect("reopen response");
+    };
+
+    // A completion that lands after the cancel returns the cancelled row
+    reopen().await;
+    repository
+        .cancel(id.clone(), workspace_id.clone())
+        .await
+        .unwrap();
+    let late = repository
+        .update(
+            id.clone(),
+            workspace_id.clone(),
+            None,
+            ResponseStatus::Completed,
+            None,
+        )
+        .await
+        .unwrap()
+        .expect("response still exists");
+    assert_eq!(late.status, ResponseStatus::Cancelled);
+
+    // Racing the two, whichever wins, both callers see the row that is stored
+    for _ in 0..20 {
+        reopen().await;
+        let (cancelled, completed) = tokio::join!(
+            repository.cancel(id.clone(), workspace_id.clone()),
+            repository.update(
+                id.clone(),
+                workspace_id.clone(),
+                None,
+                ResponseStatus::Completed,
+                None,
+            ),
+        );
+        let (_, stored) = response_status(&database, conversation_id)
+            .await
+            .expect("response row");
+        let expected = match stored.as_str() {
+            "cancelled" => ResponseStatus::Cancelled,
+            "completed" => ResponseStatus::Completed,
+            other => panic!("unexpected status {other}"),
+        };
+        assert_eq!(cancelled.unwrap().unwrap().status, expected);
+        assert_eq!(completed.unwrap().unwrap().status, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_cancel_unknown_response_is_not_found() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let cancel = server
+        .post(&format!(
+            "/v1/responses/resp_{}/cancel",
+            uuid::Uuid::new_v4().simple()
+        ))
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(cancel.status_code(), 404);
+}
@@ -0,0 +1,104 @@
+// E2E tests for `include` expansion on the Responses input item listing
+
+use crate::common::*;
+use serde_json::json;
+
+/// Create a conversation-backed response and return (api_key, response_id)
+async fn create_response(server: &axum_test::TestServer) -> (String, String) {
+    let org = setup_org_with_credits(server, 10000000000i64).await; // $10.00 USD
+    let api_key = get_api_key_for_org(server, org.id).await;
+    let model = setup_qwen_model(server).await;
+
+    let conversation = server
+        .post("/v1/conversations")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({ "name": "Include Expansion" }))
+        .await;
+    assert_eq!(conversation.status_code(), 201);
+    let conversation: api::models::ConversationObject = conversation.json();
+
+    let response = server
+        .post("/v1/responses")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "model": model,
+            "conversation": { "id": conversation.id },
+            "input": [{ "role": "user", "content": "Hello, world!" }],
+            "stream": false,
+            "max_output_tokens": 10
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200);
+    let response: api::models::ResponseObject = response.json();
+    (api_key, response.id)
+}
+
+#[tokio::test]
+async fn test_input_items_lean_by_default() {
+    let (server, _, _mock, _db) = setup_test_server_with_pool().await;
+    let (api_key, response_id) = create_response(&server).await;
+
+    let listing = server
+        .get(format!("/v1/responses/{response_id}/input_items").as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(listing.status_code(), 200);
+    let body: serde_json::Value = listing.json();
+
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    assert!(body.get("output").is_none(), "body: {body}");
+    assert!(body.get("tool_calls").is_none(), "body: {body}");
+}
+
+#[tokio::test]
+async fn test_input_items_include_expands_requested_fields() {
+    let (server, _, _mock, _db) = setup_test_server_with_pool().await;
+    let (api_key, response_id) = create_response(&server).await;
+
+    let listing = server
+        .get(format!("/v1/responses/{response_id}/input_items?include=output").as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(listing.status_code(), 200);
+    let body: serde_json::Value = listing.json();
+
+    let output = body["output"]
+        .as_array()
+        .expect("output should be expanded");
+    assert!(!output.is_empty(), "body: {body}");
+    assert!(output
+        .iter()
+        .all(|item| item["type"] == "message" && item["role"] == "assistant"));
+    assert!(body.get("tool_calls").is_none(), "body: {body}");
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+
+    let listing = server
+        .get(
+            format!(
+                "/v1/responses/{response_id}/input_items?include[]=output&include[]=tool_calls"
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(listing.status_code(), 200);
+    let body: serde_json::Value = listing.json();
+
+    assert!(body["output"].is_array(), "body: {body}");
+    assert_eq!(body["tool_calls"], json!([]));
+}
+
+#[tokio::test]
+async fn test_input_items_unknown_include_rejected() {
+    let (server, _, _mock, _db) = setup_test_server_with_pool().await;
+    let (api_key, response_id) = create_response(&server).await;
+
+    let listing = server
+        .get(format!("/v1/responses/{response_id}/input_items?include=output,everything").as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(listing.status_code(), 400);
+    let error: api::models::ErrorResponse = listing.json();
+    assert_eq!(error.error.r#type, "invalid_request_error");
+    assert!(error.error.message.contains("everything"));
+}
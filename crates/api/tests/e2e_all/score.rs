@@ -317,13 +317,7 @@ async fn test_score_missing_api_key() {
     );
 
     let body = response.json::<serde_json::Value>();
-    // When no Authorization header is provided, we get "missing_auth_header" error type
-    assert!(
-        body["error"]["type"].as_str() == Some("unauthorized")
-            || body["error"]["type"].as_str() == Some("missing_auth_header"),
-        "Error type should be 'unauthorized' or 'missing_auth_header', got: {:?}",
-        body["error"]["type"]
-    );
+    assert_eq!(body["error"]["type"], "authentication_error");
 }
 
 /// Test authentication: invalid API key
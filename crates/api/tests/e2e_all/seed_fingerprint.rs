@@ -0,0 +1,79 @@
+// E2E tests for `seed` forwarding and `system_fingerprint` pass-through
+
+use crate::common::*;
+use inference_providers::mock::ResponseTemplate;
+
+const FINGERPRINT: &str = "fp_seed_test";
+
+fn chat_body(stream: bool) -> serde_json::Value {
+    serde_json::json!({
+        "model": E2E_QWEN_MODEL_NAME,
+        "messages": [{"role": "user", "content": "Hello"}],
+        "stream": stream,
+        "max_tokens": 10,
+        "seed": 42
+    })
+}
+
+#[tokio::test]
+async fn test_seed_forwarded_and_fingerprint_returned_non_stream() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    mock_provider
+        .set_default_response(
+            ResponseTemplate::new("Seeded reply").with_system_fingerprint(FINGERPRINT),
+        )
+        .await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&chat_body(false))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let params = mock_provider.last_chat_params().await.unwrap();
+    assert_eq!(params.seed, Some(42));
+    assert!(!params.extra.contains_key("seed"));
+
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["system_fingerprint"], FINGERPRINT, "{body}");
+}
+
+#[tokio::test]
+async fn test_seed_forwarded_and_fingerprint_returned_stream() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    mock_provider
+        .set_default_response(
+            ResponseTemplate::new("Seeded reply").with_system_fingerprint(FINGERPRINT),
+        )
+        .await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&chat_body(true))
+        .await;
+    assert_eq!(response.status_code(), 200);
+    let body = response.text();
+
+    let params = mock_provider.last_chat_params().await.unwrap();
+    assert_eq!(params.seed, Some(42));
+
+    let chunks: Vec<serde_json::Value> = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .filter_map(|data| serde_json::from_str(data).ok())
+        .collect();
+    assert!(!chunks.is_empty(), "no chunks streamed: {body}");
+    for chunk in &chunks {
+        assert_eq!(chunk["system_fingerprint"], FINGERPRINT, "{chunk}");
+    }
+}
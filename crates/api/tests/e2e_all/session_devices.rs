@@ -0,0 +1,210 @@
+//! End-to-end tests for listing and revoking a user's active sessions via
+//! `GET /v1/auth/sessions` and `DELETE /v1/auth/sessions/{id}`.
+//!
+//! Run against the real `AuthService` (`auth.mock = false`) and the
+//! PostgreSQL-backed session repository.
+
+use crate::common::*;
+use api::routes::auth::SessionResponse;
+use database::repositories::SessionRepository;
+use std::sync::Arc;
+
+const LAPTOP_UA: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36";
+const PHONE_UA: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Mobile/15E148 Safari/604.1";
+
+async fn real_auth_server() -> (axum_test::TestServer, Arc<database::Database>) {
+    setup_test_server_with_config_and_database(|c| c.auth.mock = false).await
+}
+
+/// Insert a unique user directly in the database and return its id.
+///
+/// `created_at` is backdated so admin listings keep the shared mock user on
+/// page one.
+async fn create_real_user(database: &Arc<database::Database>) -> uuid::Uuid {
+    let user_id = uuid::Uuid::new_v4();
+    let client = database
+        .pool()
+        .get()
+        .await
+        .expect("Failed to get database connection");
+    client.execute(
+        "INSERT INTO users (id, email, username, display_name, avatar_url, auth_provider, provider_user_id, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, NOW() - INTERVAL '30 days', NOW())",
+        &[
+            &user_id,
+            &format!("devices-test-{user_id}@test.com"),
+            &format!("devices-test-{user_id}"),
+            &Some("Devices Test User".to_string()),
+            &None::<String>,
+            &"mock",
+            &format!("devices-test-{user_id}"),
+        ],
+    ).await.expect("Failed to create test user");
+    user_id
+}
+
+async fn cleanup_user(database: &Arc<database::Database>, user_id: uuid::Uuid) {
+    let client = database
+        .pool()
+        .get()
+        .await
+        .expect("Failed to get database connection");
+    client
+        .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+        .await
+        .expect("Failed to clean up test user");
+}
+
+/// Log a user in on a device: create the session and exchange its refresh
+/// token for an access token. Returns (session_id, tokens).
+async fn login(
+    server: &axum_test::TestServer,
+    database: &Arc<database::Database>,
+    user_id: uuid::Uuid,
+    user_agent: &str,
+) -> (uuid::Uuid, api::models::AccessAndRefreshTokenResponse) {
+    let repo = SessionRepository::new(database.pool().clone());
+    let (session, refresh_token) = repo
+        .create(user_id, None, user_agent.to_string(), 24)
+        .await
+        .expect("Failed to create session");
+    let response = server
+        .post("/v1/users/me/access-tokens")
+        .add_header("Authorization", format!("Bearer {refresh_token}"))
+        .add_header("User-Agent", user_agent)
+        .await;
+    assert_eq!(response.status_code(), 200, "token mint should succeed");
+    (
+        session.id,
+        response.json::<api::models::AccessAndRefreshTokenResponse>(),
+    )
+}
+
+async fn list_sessions(server: &axum_test::TestServer, access_token: &str) -> Vec<SessionResponse> {
+    let response = server
+        .get("/v1/auth/sessions")
+        .add_header("Authorization", format!("Bearer {access_token}"))
+        .await;
+    assert_eq!(response.status_code(), 200);
+    response.json::<Vec<SessionResponse>>()
+}
+
+async fn me_status(server: &axum_test::TestServer, access_token: &str) -> u16 {
+    server
+        .get("/v1/users/me")
+        .add_header("Authorization", format!("Bearer {access_token}"))
+        .await
+        .status_code()
+        .as_u16()
+}
+
+#[tokio::test]
+async fn test_list_and_revoke_sessions() {
+    let (server, database) = real_auth_server().await;
+    let user_id = create_real_user(&database).await;
+    let (laptop_id, laptop) = login(&server, &database, user_id, LAPTOP_UA).await;
+    let (phone_id, phone) = login(&server, &database, user_id, PHONE_UA).await;
+
+    let sessions = list_sessions(&server, &laptop.access_token).await;
+    assert_eq!(sessions.len(), 2);
+    let laptop_entry = sessions.iter().find(|s| s.id == laptop_id).unwrap();
+    let phone_entry = sessions.iter().find(|s| s.id == phone_id).unwrap();
+    assert_eq!(laptop_entry.device, "Chrome on macOS");
+    assert_eq!(phone_entry.device, "Safari on iOS");
+    assert!(phone_entry.last_used_at >= phone_entry.created_at);
+
+    // Revoke the phone from the laptop.
+    let response = server
+        .delete(&format!("/v1/auth/sessions/{phone_id}"))
+        .add_header("Authorization", format!("Bearer {}", laptop.access_token))
+        .await;
+    assert_eq!(response.status_code(), 204);
+
+    // The revoked session no longer authenticates, by access or refresh token.
+    assert_eq!(me_status(&server, &phone.access_token).await, 401);
+    let response = server
+        .post("/v1/users/me/access-tokens")
+        .add_header("Authorization", format!("Bearer {}", phone.refresh_token))
+        .add_header("User-Agent", PHONE_UA)
+        .await;
+    assert_eq!(response.status_code(), 401);
+
+    // The laptop is unaffected and only sees itself now.
+    assert_eq!(me_status(&server, &laptop.access_token).await, 200);
+    let sessions = list_sessions(&server, &laptop.access_token).await;
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].id, laptop_id);
+
+    // Revoking again reports not found.
+    let response = server
+        .delete(&format!("/v1/auth/sessions/{phone_id}"))
+        .add_header("Authorization", format!("Bearer {}", laptop.access_token))
+        .await;
+    assert_eq!(response.status_code(), 404);
+
+    cleanup_user(&database, user_id).await;
+}
+
+#[tokio::test]
+async fn test_cannot_revoke_another_users_session() {
+    let (server, database) = real_auth_server().await;
+    let alice = create_real_user(&database).await;
+    let bob = create_real_user(&database).await;
+    let (_, alice_tokens) = login(&server, &database, alice, LAPTOP_UA).await;
+    let (bob_session, bob_tokens) = login(&server, &database, bob, PHONE_UA).await;
+
+    let response = server
+        .delete(&format!("/v1/auth/sessions/{bob_session}"))
+        .add_header(
+            "Authorization",
+            format!("Bearer {}", alice_tokens.access_token),
+        )
+        .await;
+    assert_eq!(response.status_code(), 404);
+    assert_eq!(me_status(&server, &bob_tokens.access_token).await, 200);
+
+    cleanup_user(&database, alice).await;
+    cleanup_user(&database, bob).await;
+}
+
+#[tokio::test]
+async fn test_authentication_updates_last_used_at() {
+    let (server, database) = real_auth_server().await;
+    let user_id = create_real_user(&database).await;
+    let (session_id, tokens) = login(&server, &database, user_id, LAPTOP_UA).await;
+
+    let client = database
+        .pool()
+        .get()
+        .await
+        .expect("Failed to get database connection");
+    client
+        .execute(
+            "UPDATE refresh_tokens SET last_used_at = NOW() - INTERVAL '1 hour' WHERE id = $1",
+            &[&session_id],
+        )
+        .await
+        .expect("Failed to backdate last_used_at");
+    let before = chrono::Utc::now() - chrono::Duration::minutes(1);
+
+    // Minting the access token already recorded a use on this instance, so it
+    // skips the write until its throttle interval has passed.
+    assert_eq!(me_status(&server, &tokens.access_token).await, 200);
+    let sessions = list_sessions(&server, &tokens.access_token).await;
+    assert!(
+        sessions[0].last_used_at < before,
+        "repeated authentication within the interval should not write"
+    );
+
+    // Another instance has not seen the session yet and records the use.
+    let (other_server, _) = real_auth_server().await;
+    assert_eq!(me_status(&other_server, &tokens.access_token).await, 200);
+
+    let sessions = list_sessions(&server, &tokens.access_token).await;
+    assert!(
+        sessions[0].last_used_at > before,
+        "authenticating should refresh last_used_at"
+    );
+
+    cleanup_user(&database, user_id).await;
+}
@@ -486,3 +486,279 @@ async fn test_streaming_chat_default_stream_signature_stored_before_done_emitted
         }
     }
 }
+
+// ============================================
+// Server-side Verification Endpoint Tests
+// ============================================
+
+async fn create_completion(server: &axum_test::TestServer, api_key: &str, model: &str) -> String {
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "stream": true,
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let text = response.text();
+    let chat_id = text
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .filter_map(|data| serde_json::from_str::<serde_json::Value>(data).ok())
+        .find_map(|chunk| chunk["id"].as_str().map(str::to_string))
+        .expect("stream should carry a chat id");
+    // Signatures are stored asynchronously after the stream completes.
+    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+    chat_id
+}
+
+#[tokio::test]
+async fn test_verify_signature_returns_verified_components() {
+    let server = setup_test_server().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let chat_id = create_completion(&server, &api_key, &model).await;
+
+    for algo in ["ecdsa", "ed25519"] {
+        let signature: serde_json::Value = server
+            .get(&format!("/v1/signature/{chat_id}?signing_algo={algo}"))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await
+            .json();
+
+        let response = server
+            .get(&format!("/v1/verify/{chat_id}?signing_algo={algo}"))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await;
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+        let body: serde_json::Value = response.json();
+        println!("{algo} verification: {body}");
+
+        assert_eq!(body["signing_algo"], algo);
+        assert_eq!(body["message"], signature["text"]);
+        assert_eq!(body["signing_address"], signature["signing_address"]);
+        let text = signature["text"].as_str().unwrap();
+        let mut parts = text.rsplitn(3, ':');
+        assert_eq!(body["response_hash"], parts.next().unwrap());
+        assert_eq!(body["request_hash"], parts.next().unwrap());
+        assert_eq!(body["verified"], true, "{algo} signature should verify");
+        assert!(body.get("error").is_none(), "unexpected error: {body}");
+    }
+}
+
+#[tokio::test]
+async fn test_signature_lookup_by_chat_id_and_inference_id() {
+    let server = setup_test_server().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "stream": false,
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let inference_id = response
+        .headers()
+        .get("Inference-Id")
+        .expect("completion should carry an Inference-Id header")
+        .to_str()
+        .unwrap()
+        .to_string();
+    let chat_id = response.json::<serde_json::Value>()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    // Usage and signatures are recorded asynchronously.
+    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+
+    for algo in ["ecdsa", "ed25519"] {
+        let by_chat_id = server
+            .get(&format!("/v1/signature/{chat_id}?signing_algo={algo}"))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await;
+        assert_eq!(by_chat_id.status_code(), 200, "{}", by_chat_id.text());
+        let by_inference_id = server
+            .get(&format!("/v1/signature/{inference_id}?signing_algo={algo}"))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await;
+        assert_eq!(
+            by_inference_id.status_code(),
+            200,
+            "{}",
+            by_inference_id.text()
+        );
+        let by_chat_id: serde_json::Value = by_chat_id.json();
+        assert_eq!(by_chat_id["signing_algo"], algo);
+        assert_eq!(by_inference_id.json::<serde_json::Value>(), by_chat_id);
+
+        let verified = server
+            .get(&format!("/v1/verify/{inference_id}?signing_algo={algo}"))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await;
+        assert_eq!(verified.status_code(), 200, "{}", verified.text());
+        assert_eq!(
+            verified.json::<serde_json::Value>()["message"],
+            by_chat_id["text"]
+        );
+    }
+
+    // A UUID that was never issued is still a 404.
+    let response = server
+        .get(&format!("/v1/signature/{}", uuid::Uuid::new_v4()))
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_verify_signature_unknown_chat_is_404() {
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .get("/v1/verify/chatcmpl-does-not-exist?signing_algo=ecdsa")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_verify_signature_rejects_unknown_algo() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .get("/v1/verify/chatcmpl-x?signing_algo=rsa")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+}
+
+// ============================================
+// Provider Signature Fixture Tests
+// ============================================
+
+/// With the mock in attestation fixture mode, a non-streaming completion stores
+/// the provider's own signatures. Both must verify against the fixture keys,
+/// and the attestation report must advertise the same signing identity.
+#[tokio::test]
+async fn test_provider_fixture_signatures_verify_for_both_algorithms() {
+    let (server, _pool, mock, _db) = setup_test_server_with_pool().await;
+    mock.set_attestation_fixture(true);
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "stream": false,
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: serde_json::Value = response.json();
+    let chat_id = body["id"].as_str().expect("completion id").to_string();
+    // The provider signature is fetched and stored asynchronously.
+    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+
+    for algo in ["ecdsa", "ed25519"] {
+        let (expected_address, expected_public_key) =
+            inference_providers::mock::fixture_signing_identity(algo).unwrap();
+
+        let signature: serde_json::Value = server
+            .get(&format!("/v1/signature/{chat_id}?signing_algo={algo}"))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await
+            .json();
+        let text = signature["text"].as_str().expect("signature text");
+        let signature_hex = signature["signature"].as_str().expect("signature");
+        assert_eq!(signature["signing_address"], expected_address);
+        assert_eq!(text.split(':').count(), 2, "text is request:response hash");
+
+        let is_valid = match algo {
+            "ecdsa" => verify_ecdsa_signature(text, signature_hex, &expected_address),
+            _ => verify_ed25519_signature(text, signature_hex, &expected_public_key),
+        };
+        assert!(is_valid, "{algo} provider signature should verify");
+
+        let verification: serde_json::Value = server
+            .get(&format!("/v1/verify/{chat_id}?signing_algo={algo}"))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await
+            .json();
+        assert_eq!(verification["verified"], true, "{verification}");
+        assert_eq!(verification["signature_kind"], "provider_tee");
+
+        let report: serde_json::Value = server
+            .get(&format!(
+                "/v1/attestation/report?model={}&signing_algo={algo}",
+                url::form_urlencoded::byte_serialize(model.as_bytes()).collect::<String>()
+            ))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await
+            .json();
+        let attestation = &report["model_attestations"][0];
+        assert_eq!(attestation["signing_address"], expected_address);
+        assert_eq!(attestation["signing_public_key"], expected_public_key);
+    }
+}
+
+/// A tampered signed text must fail verification against the fixture keys.
+#[tokio::test]
+async fn test_provider_fixture_signature_rejects_tampered_text() {
+    let (server, _pool, mock, _db) = setup_test_server_with_pool().await;
+    mock.set_attestation_fixture(true);
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "Hello"}],
+            "stream": false,
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let chat_id = response.json::<serde_json::Value>()["id"]
+        .as_str()
+        .expect("completion id")
+        .to_string();
+    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+
+    for algo in ["ecdsa", "ed25519"] {
+        let (address, public_key) =
+            inference_providers::mock::fixture_signing_identity(algo).unwrap();
+        let signature: serde_json::Value = server
+            .get(&format!("/v1/signature/{chat_id}?signing_algo={algo}"))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await
+            .json();
+        let tampered = format!("{}0", signature["text"].as_str().unwrap());
+        let signature_hex = signature["signature"].as_str().unwrap();
+        let is_valid = match algo {
+            "ecdsa" => verify_ecdsa_signature(&tampered, signature_hex, &address),
+            _ => verify_ed25519_signature(&tampered, signature_hex, &public_key),
+        };
+        assert!(!is_valid, "{algo} must reject a tampered text");
+    }
+}
@@ -0,0 +1,75 @@
+// E2E tests for the opt-in guard against duplicate streaming starts.
+
+use crate::common::*;
+use inference_providers::mock::ResponseTemplate;
+use std::time::Duration;
+
+fn stream_body() -> serde_json::Value {
+    serde_json::json!({
+        "model": E2E_QWEN_MODEL_NAME,
+        "messages": [{"role": "user", "content": "Stream me something"}],
+        "stream": true,
+        "max_tokens": 10
+    })
+}
+
+async fn post_stream(server: &axum_test::TestServer, api_key: &str) -> axum_test::TestResponse {
+    server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&stream_body())
+        .await
+}
+
+#[tokio::test]
+async fn test_identical_streaming_start_within_window_is_rejected() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|config| {
+        config.server.stream_dedup_window_ms = 5_000;
+    })
+    .await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+    // Keep the first start waiting on its first chunk while the retry arrives.
+    mock_provider
+        .set_default_response(
+            ResponseTemplate::new("Slow first token")
+                .with_first_chunk_delay(Duration::from_millis(400)),
+        )
+        .await;
+
+    let (first, second) = tokio::join!(post_stream(&server, &api_key), async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        post_stream(&server, &api_key).await
+    });
+    assert_eq!(first.status_code(), 200, "{}", first.text());
+    assert_eq!(second.status_code(), 409, "{}", second.text());
+    let error: serde_json::Value = second.json();
+    assert_eq!(error["error"]["type"], "duplicate_request");
+
+    // Once the first stream is established, the same request goes through.
+    let retry = post_stream(&server, &api_key).await;
+    assert_eq!(retry.status_code(), 200, "{}", retry.text());
+}
+
+#[tokio::test]
+async fn test_identical_streaming_starts_allowed_when_guard_disabled() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+    mock_provider
+        .set_default_response(
+            ResponseTemplate::new("Slow first token")
+                .with_first_chunk_delay(Duration::from_millis(400)),
+        )
+        .await;
+
+    let (first, second) = tokio::join!(post_stream(&server, &api_key), async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        post_stream(&server, &api_key).await
+    });
+    assert_eq!(first.status_code(), 200, "{}", first.text());
+    assert_eq!(second.status_code(), 200, "{}", second.text());
+}
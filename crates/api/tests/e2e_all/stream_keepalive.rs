@@ -0,0 +1,124 @@
+// E2E tests for SSE keep-alive comments sent before a slow first chunk.
+
+use crate::common::*;
+use std::time::Duration;
+
+const KEEPALIVE: &str = ": keep-alive\n\n";
+
+#[tokio::test]
+async fn test_streaming_keepalive_precedes_delayed_first_chunk() {
+    let (server, mock) = setup_test_server_with_config_and_mock(|config| {
+        config.server.stream_keepalive_interval_ms = 100;
+    })
+    .await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+    let model_name = "Qwen/Qwen3-30B-A3B-Instruct-2507";
+
+    mock.set_default_response(
+        inference_providers::mock::ResponseTemplate::new("Slow but steady answer")
+            .with_first_chunk_delay(Duration::from_millis(450)),
+    )
+    .await;
+
+    let request_body = serde_json::json!({
+        "model": model_name,
+        "messages": [{ "role": "user", "content": "hello" }],
+        "stream": true
+    });
+    let request_json = serde_json::to_string(&request_body).expect("Failed to serialize request");
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&request_body)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let text = response.text();
+    let first_data = text
+        .find("data: ")
+        .expect("stream should carry data frames");
+    assert!(
+        text[..first_data].contains(KEEPALIVE),
+        "expected a keep-alive before the first data frame: {text:?}"
+    );
+    let done = text
+        .find("data: [DONE]")
+        .expect("stream should end with [DONE]");
+    assert!(
+        !text[first_data..].contains(KEEPALIVE),
+        "keep-alives must stop once chunks flow: {text:?}"
+    );
+    assert_eq!(text[done..].trim_end(), "data: [DONE]");
+
+    // Heartbeats are plain SSE comments: every data frame still parses.
+    let mut chat_id = None::<String>;
+    for line in text.lines() {
+        let Some(data) = line.strip_prefix("data: ") else {
+            continue;
+        };
+        if data.trim() == "[DONE]" {
+            break;
+        }
+        let chunk: serde_json::Value =
+            serde_json::from_str(data).expect("stream data should be JSON");
+        if chat_id.is_none() {
+            chat_id = chunk["id"].as_str().map(str::to_string);
+        }
+    }
+    let chat_id = chat_id.expect("Should have extracted chat_id from stream");
+
+    // The gateway signature covers the exact bytes the client received,
+    // heartbeats included.
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+    let signature_response = server
+        .get(format!("/v1/signature/{chat_id}?model={model_name}&signing_algo=ecdsa").as_str())
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .await;
+    assert_eq!(
+        signature_response.status_code(),
+        200,
+        "{}",
+        signature_response.text()
+    );
+    let signature_json = signature_response.json::<serde_json::Value>();
+    let signature_text = signature_json["text"]
+        .as_str()
+        .expect("Signature response should have 'text' field");
+    assert_eq!(
+        signature_text,
+        format!(
+            "{}:{}",
+            compute_sha256(&request_json),
+            compute_sha256(&text)
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_streaming_without_delay_sends_no_keepalive() {
+    let (server, _mock) = setup_test_server_with_config_and_mock(|config| {
+        config.server.stream_keepalive_interval_ms = 5_000;
+    })
+    .await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10000000000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "model": "Qwen/Qwen3-30B-A3B-Instruct-2507",
+            "messages": [{ "role": "user", "content": "hello" }],
+            "stream": true
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    assert!(
+        response.headers().get("inference-id").is_some(),
+        "a prompt first chunk should still yield the Inference-Id header"
+    );
+    assert!(!response.text().contains(KEEPALIVE));
+}
@@ -0,0 +1,83 @@
+// E2E tests for chat `tool_choice`: every OpenAI mode reaches the provider,
+// and a forced function missing from `tools` is rejected up front.
+
+use crate::common::*;
+use serde_json::json;
+
+fn chat_body(tool_choice: serde_json::Value) -> serde_json::Value {
+    json!({
+        "model": E2E_QWEN_MODEL_NAME,
+        "messages": [{"role": "user", "content": "What's the weather in Paris?"}],
+        "max_tokens": 10,
+        "tools": [{
+            "type": "function",
+            "function": {
+                "name": "get_weather",
+                "description": "Get the weather for a city",
+                "parameters": {
+                    "type": "object",
+                    "properties": {"city": {"type": "string"}}
+                }
+            }
+        }],
+        "tool_choice": tool_choice
+    })
+}
+
+#[tokio::test]
+async fn test_tool_choice_modes_are_forwarded() {
+    let (server, mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    for tool_choice in [
+        json!("auto"),
+        json!("none"),
+        json!("required"),
+        json!({"type": "function", "function": {"name": "get_weather"}}),
+    ] {
+        let response = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .json(&chat_body(tool_choice.clone()))
+            .await;
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+
+        let params = mock_provider.last_chat_params().await.unwrap();
+        assert_eq!(
+            serde_json::to_value(&params.tool_choice).unwrap(),
+            tool_choice
+        );
+        // `none` strips the tool definitions so nothing can be called.
+        assert_eq!(params.tools.is_none(), tool_choice == "none");
+    }
+}
+
+#[tokio::test]
+async fn test_tool_choice_rejects_undeclared_function() {
+    let (server, _mock_provider) = setup_test_server_with_config_and_mock(|_| {}).await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id).await;
+
+    for tool_choice in [
+        json!({"type": "function", "function": {"name": "get_time"}}),
+        json!("sometimes"),
+    ] {
+        let response = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .json(&chat_body(tool_choice))
+            .await;
+        assert_eq!(response.status_code(), 400, "{}", response.text());
+        let error: api::models::ErrorResponse = response.json();
+        assert!(
+            error.error.message.contains("tool_choice"),
+            "{}",
+            error.error.message
+        );
+    }
+}
@@ -0,0 +1,129 @@
+// E2E tests for the hourly spend aggregation behind usage anomaly detection,
+// and for the advisory lock that keeps detection on a single instance.
+
+use crate::common::*;
+use chrono::{DurationRound, TimeDelta, Utc};
+use database::repositories::{OrganizationUsageRepository, PgAdvisoryLock};
+use services::usage::anomaly::UsageAnomalyDetector;
+use services::usage::ports::SingleInstanceLock;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_hourly_spend_aggregates_current_hour_usage() {
+    let (server, database) = setup_test_server_with_database().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    for _ in 0..2 {
+        let response = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .json(&serde_json::json!({
+                "model": E2E_QWEN_MODEL_NAME,
+                "messages": [{"role": "user", "content": "Spend some credits"}],
+                "stream": false
+            }))
+            .await;
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+    }
+
+    let now = Utc::now();
+    let hour = now.duration_trunc(TimeDelta::hours(1)).unwrap();
+    let repository = Arc::new(OrganizationUsageRepository::new(database.pool().clone()));
+    let rows = services::usage::UsageRepository::get_hourly_spend_since(
+        repository.as_ref(),
+        hour - TimeDelta::hours(1),
+    )
+    .await
+    .expect("hourly spend query should succeed");
+    let org_id: uuid::Uuid = org.id.parse().unwrap();
+    let org_rows: Vec<_> = rows
+        .iter()
+        .filter(|row| row.organization_id == org_id)
+        .collect();
+    // Both requests land in the current hour unless the test straddles an hour boundary.
+    assert!(!org_rows.is_empty() && org_rows.len() <= 2, "{org_rows:?}");
+    assert!(org_rows
+        .iter()
+        .all(|row| row.hour <= hour
+            && row.hour.duration_trunc(TimeDelta::hours(1)).unwrap() == row.hour));
+    assert!(org_rows.iter().map(|row| row.total_cost).sum::<i64>() > 0);
+
+    // A brand-new organization has no baseline, so its first hour is not flagged.
+    let detector = UsageAnomalyDetector::new(
+        repository,
+        Arc::new(services::metrics::MockMetricsService),
+        config::UsageAnomalyConfig::default(),
+    );
+    let anomalies = detector.run_once(now).await.unwrap();
+    assert!(anomalies.iter().all(|a| a.organization_id != org_id));
+}
+
+#[tokio::test]
+async fn test_advisory_lock_is_held_by_one_instance_at_a_time() {
+    let (_server, database) = setup_test_server_with_database().await;
+    let name = format!("test_lock_{}", uuid::Uuid::new_v4());
+    let first = PgAdvisoryLock::new(database.pool().clone(), name.clone());
+    let second = PgAdvisoryLock::new(database.pool().clone(), name);
+
+    let guard = first
+        .try_acquire()
+        .await
+        .unwrap()
+        .expect("a free lock should be acquired");
+    assert!(guard.is_held().await);
+    assert!(
+        second.try_acquire().await.unwrap().is_none(),
+        "a held lock must not be handed to another instance"
+    );
+
+    // Dropping the guard closes its session, which releases the lock.
+    drop(guard);
+    let mut reacquired = None;
+    for _ in 0..50 {
+        reacquired = second.try_acquire().await.unwrap();
+        if reacquired.is_some() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert!(reacquired.is_some(), "a released lock should be acquirable");
+}
+
+#[tokio::test]
+async fn test_advisory_lock_guard_notices_a_terminated_session() {
+    let (_server, database) = setup_test_server_with_database().await;
+    let name = format!("test_lock_{}", uuid::Uuid::new_v4());
+    let lock = PgAdvisoryLock::new(database.pool().clone(), name.clone());
+    let guard = lock
+        .try_acquire()
+        .await
+        .unwrap()
+        .expect("a free lock should be acquired");
+    assert!(guard.is_held().await);
+
+    // Kill the holding backend from another session, as a failover would.
+    let client = database
+        .pool()
+        .get()
+        .await
+        .expect("Failed to get database connection");
+    let terminated: i64 = client
+        .query_one(
+            "SELECT COUNT(pg_terminate_backend(pid)) FROM pg_locks
+             WHERE locktype = 'advisory' AND objsubid = 1
+               AND ((classid::bigint << 32) | objid::bigint) = hashtextextended($1::text, 0)",
+            &[&name],
+        )
+        .await
+        .unwrap()
+        .get(0);
+    assert_eq!(terminated, 1);
+
+    assert!(
+        !guard.is_held().await,
+        "a lost lock must not report as held"
+    );
+}
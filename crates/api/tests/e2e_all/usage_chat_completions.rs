@@ -534,6 +534,72 @@ async fn test_chat_completions_stream_include_usage_true_emits_final_usage_only(
     );
 }
 
+/// Billing must not depend on what the client asked to see: with `include_usage`
+/// false the usage chunk is stripped from the client stream but still recorded, and
+/// with `include_usage` true the client-visible usage matches the recorded row.
+#[tokio::test]
+async fn test_chat_completions_stream_records_usage_regardless_of_include_usage() {
+    ensure_usage_chat_completions_env();
+    let server = setup_test_server().await;
+
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    for include_usage in [false, true] {
+        let stream_resp = server
+            .post("/v1/chat/completions")
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .json(&json!({
+                "model": E2E_QWEN_MODEL_NAME,
+                "messages": [{ "role": "user", "content": "hello" }],
+                "stream": true,
+                "stream_options": { "include_usage": include_usage }
+            }))
+            .await;
+        assert_eq!(stream_resp.status_code(), 200, "{}", stream_resp.text());
+
+        let client_usage = stream_resp
+            .text()
+            .lines()
+            .filter_map(|line| line.strip_prefix("data: "))
+            .filter(|data| data.trim() != "[DONE]")
+            .filter_map(|data| serde_json::from_str::<serde_json::Value>(data).ok())
+            .filter_map(|chunk| chunk.get("usage").filter(|u| u.is_object()).cloned())
+            .next_back();
+        assert_eq!(
+            client_usage.is_some(),
+            include_usage,
+            "client should see usage only when include_usage is true"
+        );
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+        let history_resp = server
+            .get(&format!(
+                "/v1/organizations/{}/usage/history?limit=1&offset=0",
+                org.id
+            ))
+            .add_header("Authorization", format!("Bearer {}", get_session_id()))
+            .add_header("User-Agent", MOCK_USER_AGENT)
+            .await;
+        assert_eq!(history_resp.status_code(), 200);
+        let history: api::routes::usage::UsageHistoryResponse = history_resp.json();
+        assert_eq!(
+            history.total,
+            if include_usage { 2 } else { 1 },
+            "every stream should be billed (include_usage={include_usage})"
+        );
+        let entry = &history.data[0];
+        assert!(entry.input_tokens > 0 && entry.output_tokens > 0);
+
+        if let Some(usage) = client_usage {
+            assert_eq!(usage["prompt_tokens"], json!(entry.input_tokens));
+            assert_eq!(usage["completion_tokens"], json!(entry.output_tokens));
+        }
+    }
+}
+
 #[tokio::test]
 async fn test_chat_completions_stream_error_does_not_emit_final_usage() {
     ensure_usage_chat_completions_env();
@@ -761,3 +827,92 @@ async fn test_chat_completions_stream_with_cache_records_cache_in_history() {
         "usage history should record cache_read_tokens from stream completion"
     );
 }
+
+/// A backend that finishes its stream (finish_reason + `[DONE]`) without ever sending a usage
+/// chunk must still be billed: the gateway records an estimated usage row.
+#[tokio::test]
+async fn test_chat_completions_stream_without_provider_usage_records_estimated_usage() {
+    ensure_usage_chat_completions_env();
+    let (server, _pool, mock, database) = setup_test_server_with_pool().await;
+
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    mock.set_default_response(
+        inference_providers::mock::ResponseTemplate::new("one two three four five").without_usage(),
+    )
+    .await;
+
+    let stream_resp = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{ "role": "user", "content": "count to five please" }],
+            "stream": true
+        }))
+        .await;
+    assert_eq!(stream_resp.status_code(), 200, "{}", stream_resp.text());
+    assert!(stream_resp.text().contains("[DONE]"));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    let org_id = uuid::Uuid::parse_str(&org.id).expect("org id");
+    let client = database.pool().get().await.expect("db connection");
+    let row = client
+        .query_one(
+            "SELECT input_tokens, output_tokens, total_cost, estimated \
+             FROM organization_usage_log WHERE organization_id = $1",
+            &[&org_id],
+        )
+        .await
+        .expect("an estimated usage row should be recorded");
+
+    let input_tokens: i32 = row.get("input_tokens");
+    let output_tokens: i32 = row.get("output_tokens");
+    let total_cost: i64 = row.get("total_cost");
+    let estimated: bool = row.get("estimated");
+    assert!(
+        estimated,
+        "usage without a provider usage chunk must be flagged"
+    );
+    assert_eq!(output_tokens, 5, "one output token per streamed delta");
+    assert!(input_tokens > 0, "prompt tokens should be counted");
+    assert!(total_cost > 0, "estimated usage must still be billed");
+}
+
+/// Usage reported by the provider is recorded as-is and not flagged as estimated.
+#[tokio::test]
+async fn test_chat_completions_stream_with_provider_usage_is_not_estimated() {
+    ensure_usage_chat_completions_env();
+    let (server, _pool, _mock, database) = setup_test_server_with_pool().await;
+
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let stream_resp = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{ "role": "user", "content": "hello" }],
+            "stream": true
+        }))
+        .await;
+    assert_eq!(stream_resp.status_code(), 200, "{}", stream_resp.text());
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    let org_id = uuid::Uuid::parse_str(&org.id).expect("org id");
+    let client = database.pool().get().await.expect("db connection");
+    let row = client
+        .query_one(
+            "SELECT estimated FROM organization_usage_log WHERE organization_id = $1",
+            &[&org_id],
+        )
+        .await
+        .expect("a usage row should be recorded");
+    assert!(!row.get::<_, bool>("estimated"));
+}
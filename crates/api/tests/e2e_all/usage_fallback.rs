@@ -0,0 +1,232 @@
+// E2E tests that a request served after provider fallback is billed exactly once,
+// and that the unique (organization_id, inference_id) index (V0045) keeps a
+// repeated usage write for the same inference from billing it again.
+
+use crate::common::*;
+use api::routes::usage::UsageHistoryResponse;
+use database::{models::RecordUsageRequest, repositories::OrganizationUsageRepository};
+use inference_providers::mock::{MockProvider, RequestMatcher, ResponseTemplate};
+use inference_providers::{CompletionError, ProviderTier};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Route the Qwen model to a failing NEAR provider ahead of a serving attested
+/// third party, so every request fails over once before it is served.
+async fn register_failing_then_serving(
+    pool: &services::inference_provider_pool::InferenceProviderPool,
+) -> (Arc<MockProvider>, Arc<MockProvider>) {
+    let failing = Arc::new(MockProvider::new().with_tier(ProviderTier::Near));
+    failing
+        .set_error_override(Some(CompletionError::HttpError {
+            status_code: 502,
+            message: "bad gateway".to_string(),
+            is_external: false,
+        }))
+        .await;
+    let serving = Arc::new(MockProvider::new().with_tier(ProviderTier::Attested3p));
+    serving
+        .when(RequestMatcher::Any)
+        .respond_with(ResponseTemplate::new("Served after fallback"))
+        .await;
+    pool.register_providers(vec![
+        (E2E_QWEN_MODEL_NAME.to_string(), failing.clone()),
+        (E2E_QWEN_MODEL_NAME.to_string(), serving.clone()),
+    ])
+    .await;
+    (failing, serving)
+}
+
+async fn usage_history(server: &axum_test::TestServer, org_id: &str) -> UsageHistoryResponse {
+    // Usage is recorded asynchronously once the request finishes.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let response = server
+        .get(&format!(
+            "/v1/organizations/{org_id}/usage/history?limit=10&offset=0"
+        ))
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    response.json()
+}
+
+#[tokio::test]
+async fn test_fallback_chat_completion_records_usage_once() {
+    let (server, pool, _mock, _db) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    let (failing, serving) = register_failing_then_serving(&pool).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{"role": "user", "content": "Fail over please"}],
+            "stream": false
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    assert_eq!(failing.chat_completion_request_count(), 1);
+    assert_eq!(serving.chat_completion_request_count(), 1);
+    let body: serde_json::Value = response.json();
+
+    let history = usage_history(&server, &org.id).await;
+    assert_eq!(
+        history.data.len(),
+        1,
+        "a failed-over request is billed once"
+    );
+    let entry = &history.data[0];
+    assert_eq!(
+        entry.input_tokens as i64,
+        body["usage"]["prompt_tokens"].as_i64().unwrap()
+    );
+    assert_eq!(
+        entry.output_tokens as i64,
+        body["usage"]["completion_tokens"].as_i64().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_fallback_streaming_chat_completion_records_usage_once() {
+    let (server, pool, _mock, _db) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    let (failing, serving) = register_failing_then_serving(&pool).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{"role": "user", "content": "Fail over please"}],
+            "stream": true,
+            "stream_options": {"include_usage": true}
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    assert!(failing.last_chat_params().await.is_some());
+    assert!(serving.last_chat_params().await.is_some());
+    let usage = response
+        .text()
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .filter_map(|data| serde_json::from_str::<serde_json::Value>(data).ok())
+        .find_map(|chunk| chunk.get("usage").filter(|u| !u.is_null()).cloned())
+        .expect("stream should report usage");
+
+    let history = usage_history(&server, &org.id).await;
+    assert_eq!(history.data.len(), 1, "a failed-over stream is billed once");
+    let entry = &history.data[0];
+    assert_eq!(
+        entry.input_tokens as i64,
+        usage["prompt_tokens"].as_i64().unwrap()
+    );
+    assert_eq!(
+        entry.output_tokens as i64,
+        usage["completion_tokens"].as_i64().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_repeated_usage_write_for_an_inference_is_not_billed_twice() {
+    let (server, pool, _mock, database) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    register_failing_then_serving(&pool).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let api_key = get_api_key_for_org(&server, org.id.clone()).await;
+
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{"role": "user", "content": "Bill me once"}],
+            "stream": false
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    assert_eq!(usage_history(&server, &org.id).await.data.len(), 1);
+
+    let organization_id = uuid::Uuid::parse_str(&org.id).unwrap();
+    let client = database.pool().get().await.unwrap();
+    let row = client
+        .query_one(
+            "SELECT * FROM organization_usage_log WHERE organization_id = $1",
+            &[&organization_id],
+        )
+        .await
+        .unwrap();
+    let recorded_id: uuid::Uuid = row.get("id");
+    let inference_id: Option<uuid::Uuid> = row.get("inference_id");
+    assert!(inference_id.is_some(), "chat usage carries an inference id");
+
+    // Replaying the write through the repository returns the existing row.
+    let repository = OrganizationUsageRepository::new(database.pool().clone());
+    let replayed = repository
+        .record_usage(RecordUsageRequest {
+            organization_id,
+            workspace_id: row.get("workspace_id"),
+            api_key_id: row.get("api_key_id"),
+            model_id: row.get("model_id"),
+            model_name: row.get("model_name"),
+            input_tokens: row.get("input_tokens"),
+            output_tokens: row.get("output_tokens"),
+            input_cost: row.get("input_cost"),
+            output_cost: row.get("output_cost"),
+            total_cost: row.get("total_cost"),
+            inference_type: row.get("inference_type"),
+            ttft_ms: None,
+            avg_itl_ms: None,
+            inference_id,
+            provider_request_id: None,
+            stop_reason: None,
+            response_id: None,
+            image_count: None,
+            cache_read_tokens: 0,
+            served_provider_tier: None,
+            served_provider_type: None,
+            served_via_fallback: false,
+            estimated: false,
+        })
+        .await
+        .expect("a replayed usage write should succeed");
+    assert_eq!(replayed.id, recorded_id);
+
+    // A raw duplicate insert is rejected by the index itself.
+    let duplicate = client
+        .execute(
+            "INSERT INTO organization_usage_log (
+                id, organization_id, workspace_id, api_key_id,
+                model_id, model_name, input_tokens, output_tokens, cache_read_tokens, total_tokens,
+                input_cost, output_cost, total_cost, inference_type, created_at, inference_id
+             )
+             SELECT $1, organization_id, workspace_id, api_key_id,
+                    model_id, model_name, input_tokens, output_tokens, cache_read_tokens, total_tokens,
+                    input_cost, output_cost, total_cost, inference_type, NOW(), inference_id
+             FROM organization_usage_log WHERE id = $2",
+            &[&uuid::Uuid::new_v4(), &recorded_id],
+        )
+        .await
+        .expect_err("a second row for the same inference must violate the unique index");
+    assert_eq!(
+        duplicate.code(),
+        Some(&tokio_postgres::error::SqlState::UNIQUE_VIOLATION)
+    );
+
+    let balance = client
+        .query_one(
+            "SELECT total_requests FROM organization_balance WHERE organization_id = $1",
+            &[&organization_id],
+        )
+        .await
+        .unwrap();
+    assert_eq!(balance.get::<_, i64>("total_requests"), 1);
+    assert_eq!(usage_history(&server, &org.id).await.data.len(), 1);
+}
@@ -0,0 +1,129 @@
+// E2E tests for `created_after`/`created_before` filtering of usage history
+
+use crate::common::*;
+
+async fn chat(server: &axum_test::TestServer, api_key: &str) {
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{"role": "user", "content": "Hi"}],
+            "stream": false,
+            "max_tokens": 10
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
+
+fn history_ids(response: &axum_test::TestResponse) -> Vec<String> {
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: api::routes::usage::UsageHistoryResponse = response.json();
+    assert_eq!(body.total, body.data.len());
+    body.data.into_iter().map(|entry| entry.id).collect()
+}
+
+#[tokio::test]
+async fn test_usage_history_created_range_returns_only_in_range_rows() {
+    let (server, database) = setup_test_server_with_database().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let workspace = list_workspaces(&server, org.id.clone()).await.remove(0);
+    let api_key = create_api_key_in_workspace(&server, workspace.id.clone(), "Range".into()).await;
+    let secret = api_key.key.clone().unwrap();
+
+    for _ in 0..3 {
+        chat(&server, &secret).await;
+    }
+
+    // Wait for usage to be recorded
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    // Spread the recorded rows one day apart so the range can select the middle one.
+    let client = database.pool().get().await.unwrap();
+    let api_key_id = uuid::Uuid::parse_str(&api_key.id).unwrap();
+    let rows = client
+        .query(
+            "SELECT id FROM organization_usage_log WHERE api_key_id = $1 ORDER BY created_at",
+            &[&api_key_id],
+        )
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 3);
+    let seeded: Vec<uuid::Uuid> = rows.iter().map(|row| row.get(0)).collect();
+    for (day, id) in seeded.iter().enumerate() {
+        let created_at = chrono::DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z").unwrap()
+            + chrono::Duration::days(day as i64);
+        client
+            .execute(
+                "UPDATE organization_usage_log SET created_at = $1 WHERE id = $2",
+                &[&created_at.with_timezone(&chrono::Utc), id],
+            )
+            .await
+            .unwrap();
+    }
+
+    let range = "created_after=2026-01-01T12:00:00Z&created_before=2026-01-02T12:00:00Z";
+    let expected = vec![seeded[1].to_string()];
+
+    let response = server
+        .get(
+            format!(
+                "/v1/organizations/{}/usage/history?workspace_id={}&{range}",
+                org.id, workspace.id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(history_ids(&response), expected);
+
+    let response = server
+        .get(
+            format!(
+                "/v1/workspaces/{}/api-keys/{}/usage/history?{range}",
+                workspace.id, api_key.id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(history_ids(&response), expected);
+
+    // Pagination applies within the range, newest first.
+    let response = server
+        .get(
+            format!(
+                "/v1/workspaces/{}/api-keys/{}/usage/history?created_after=2026-01-01T00:00:00Z&limit=1&offset=1",
+                workspace.id, api_key.id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let body: api::routes::usage::UsageHistoryResponse = response.json();
+    assert_eq!(body.total, 3);
+    assert_eq!(body.data.len(), 1);
+    assert_eq!(body.data[0].id, seeded[1].to_string());
+}
+
+#[tokio::test]
+async fn test_usage_history_created_range_validation() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+
+    for query in [
+        "created_after=2026-01-02T00:00:00Z&created_before=2026-01-01T00:00:00Z",
+        "created_after=yesterday",
+        "created_after=2026-01-01T00:00:00Z&start_time=2026-01-01T00:00:00Z",
+        "created_before=2026-01-01T00:00:00Z&end_date=2026-01-01",
+    ] {
+        let response = server
+            .get(format!("/v1/organizations/{}/usage/history?{query}", org.id).as_str())
+            .add_header("Authorization", format!("Bearer {}", get_session_id()))
+            .await;
+        assert_eq!(response.status_code(), 400, "{query}: {}", response.text());
+    }
+}
@@ -0,0 +1,136 @@
+// E2E tests for CSV exports of usage history
+
+use crate::common::*;
+
+const CSV_HEADER: &str =
+    "timestamp,model,input_tokens,output_tokens,cost_usd,inference_type,inference_id";
+
+async fn chat(server: &axum_test::TestServer, api_key: &str) {
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{"role": "user", "content": "Hi"}],
+            "stream": false,
+            "max_tokens": 10
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+}
+
+fn assert_usage_csv(response: &axum_test::TestResponse, expected_rows: usize) {
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let content_type = response.header("content-type");
+    assert!(
+        content_type.to_str().unwrap().starts_with("text/csv"),
+        "unexpected content type: {content_type:?}"
+    );
+
+    let body = response.text();
+    let mut lines = body.lines();
+    assert_eq!(lines.next(), Some(CSV_HEADER));
+    let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+    assert_eq!(rows.len(), expected_rows, "{body}");
+    for row in rows {
+        assert_eq!(row.len(), 7, "{row:?}");
+        assert!(chrono::DateTime::parse_from_rfc3339(row[0]).is_ok());
+        assert_eq!(row[1], E2E_QWEN_MODEL_NAME);
+        assert!(row[2].parse::<i64>().unwrap() > 0);
+        assert!(row[3].parse::<i64>().unwrap() > 0);
+        assert!(row[4].contains('.'), "cost should be a decimal: {}", row[4]);
+        assert_eq!(row[5], "chat_completion");
+    }
+}
+
+#[tokio::test]
+async fn test_usage_history_csv_exports() {
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let workspace = list_workspaces(&server, org.id.clone()).await.remove(0);
+    let exported_key =
+        create_api_key_in_workspace(&server, workspace.id.clone(), "Exported".into()).await;
+    let other_key = create_api_key_in_workspace(&server, workspace.id.clone(), "Other".into())
+        .await
+        .key
+        .unwrap();
+
+    let exported_secret = exported_key.key.clone().unwrap();
+    chat(&server, &exported_secret).await;
+    chat(&server, &exported_secret).await;
+    chat(&server, &other_key).await;
+
+    // Wait for usage to be recorded
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    let response = server
+        .get(
+            format!(
+                "/v1/workspaces/{}/api-keys/{}/usage/history.csv",
+                workspace.id, exported_key.id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_usage_csv(&response, 2);
+
+    let response = server
+        .get(format!("/v1/organizations/{}/usage/history.csv", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_usage_csv(&response, 3);
+}
+
+#[tokio::test]
+async fn test_usage_history_csv_rejects_invalid_range() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+
+    let response = server
+        .get(
+            format!(
+                "/v1/organizations/{}/usage/history.csv?start_date=not-a-date",
+                org.id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_usage_history_csv_rejects_range_over_limit() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+
+    let response = server
+        .get(
+            format!(
+                "/v1/organizations/{}/usage/history.csv?start_date=2020-01-01&end_date=2022-01-01",
+                org.id
+            )
+            .as_str(),
+        )
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_usage_history_csv_is_hidden_when_reporting_is_disabled() {
+    let server = setup_test_server_with_config(|config| {
+        config.usage_reporting.enabled = false;
+    })
+    .await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+
+    let response = server
+        .get(format!("/v1/organizations/{}/usage/history.csv", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(response.status_code(), 404, "{}", response.text());
+}
@@ -98,6 +98,7 @@ fn attributed_usage_request(
         served_provider_tier: Some(ServedProviderTier::Attested3p),
         served_provider_type: Some(ServedProviderType::Chutes),
         served_via_fallback: true,
+        estimated: false,
     }
 }
 
@@ -0,0 +1,122 @@
+//! Per-workspace completion defaults (`default_params`) e2e tests.
+//!
+//! A workspace can default `model`, `temperature` and `max_tokens`; the chat
+//! completions route fills only the fields the client omitted, so the mocked
+//! provider must see the merged values while explicit client values win.
+
+use crate::common::*;
+use api::routes::workspaces::{WorkspaceDefaultParams, WorkspaceResponse};
+use inference_providers::mock::{RequestMatcher, ResponseTemplate};
+use serde_json::json;
+
+/// Set `default_params` on the org's first workspace and return an API key for it.
+async fn setup_workspace_with_defaults(
+    server: &axum_test::TestServer,
+    org_id: String,
+    default_params: serde_json::Value,
+) -> (WorkspaceResponse, String) {
+    let workspace = list_workspaces(server, org_id).await.remove(0);
+    let response = server
+        .put(format!("/v1/workspaces/{}", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .json(&json!({ "default_params": default_params }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let updated = response.json::<WorkspaceResponse>();
+
+    let api_key = create_api_key_in_workspace(server, updated.id.clone(), "defaults".to_string())
+        .await
+        .key
+        .unwrap();
+    (updated, api_key)
+}
+
+#[tokio::test]
+async fn test_update_workspace_default_params_roundtrip() {
+    let server = setup_test_server().await;
+    let org = create_org(&server).await;
+
+    let (workspace, _) = setup_workspace_with_defaults(
+        &server,
+        org.id.clone(),
+        json!({ "temperature": 0.3, "max_tokens": 128 }),
+    )
+    .await;
+    assert_eq!(
+        workspace.default_params,
+        Some(WorkspaceDefaultParams {
+            model: None,
+            temperature: Some(0.3),
+            max_tokens: Some(128),
+        })
+    );
+
+    // An update that omits default_params leaves them untouched.
+    let response = server
+        .put(format!("/v1/workspaces/{}", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .json(&json!({ "description": "still has defaults" }))
+        .await;
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(
+        response.json::<WorkspaceResponse>().default_params,
+        workspace.default_params
+    );
+
+    // Out-of-range defaults are rejected up front.
+    let response = server
+        .put(format!("/v1/workspaces/{}", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .json(&json!({ "default_params": { "temperature": 3.0 } }))
+        .await;
+    assert_eq!(response.status_code(), 400);
+}
+
+#[tokio::test]
+async fn test_bare_request_receives_workspace_defaults() {
+    let (server, _pool, mock, _db) = setup_test_server_with_pool().await;
+    let model = setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    mock.when(RequestMatcher::Any)
+        .respond_with(ResponseTemplate::new("ok"))
+        .await;
+
+    let (_, api_key) = setup_workspace_with_defaults(
+        &server,
+        org.id.clone(),
+        json!({ "model": model, "temperature": 0.25, "max_tokens": 42 }),
+    )
+    .await;
+
+    // No model, temperature or max_tokens: all three come from the workspace.
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "messages": [{"role": "user", "content": "hi"}],
+            "stream": false,
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let params = mock.last_chat_params().await.expect("provider was called");
+    assert_eq!(params.model, model);
+    assert_eq!(params.temperature, Some(0.25));
+    assert_eq!(params.max_tokens, Some(42));
+
+    // Client-supplied values win over the workspace defaults.
+    let response = server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "hi"}],
+            "temperature": 1.1,
+            "max_tokens": 7,
+            "stream": false,
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let params = mock.last_chat_params().await.expect("provider was called");
+    assert_eq!(params.temperature, Some(1.1));
+    assert_eq!(params.max_tokens, Some(7));
+}
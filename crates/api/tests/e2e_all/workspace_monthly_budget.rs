@@ -0,0 +1,161 @@
+// E2E tests for per-workspace monthly budgets
+
+use crate::common::*;
+use api::routes::workspaces::WorkspaceResponse;
+
+async fn set_monthly_budget(
+    server: &axum_test::TestServer,
+    workspace_id: &str,
+    budget: serde_json::Value,
+) -> axum_test::TestResponse {
+    server
+        .patch(format!("/v1/workspaces/{workspace_id}/monthly-budget").as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .json(&serde_json::json!({ "monthly_budget": budget }))
+        .await
+}
+
+async fn chat(server: &axum_test::TestServer, api_key: &str) -> axum_test::TestResponse {
+    server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{"role": "user", "content": "Hi"}],
+            "stream": false,
+            "max_tokens": 10
+        }))
+        .await
+}
+
+#[tokio::test]
+async fn test_workspace_monthly_budget_set_and_clear() {
+    let server = setup_test_server().await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let workspace = list_workspaces(&server, org.id.clone()).await.remove(0);
+    assert_eq!(workspace.monthly_budget, None);
+
+    let response = set_monthly_budget(
+        &server,
+        &workspace.id,
+        serde_json::json!({"amount": 5_000_000_000i64, "currency": "USD"}),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let updated: WorkspaceResponse = response.json();
+    let budget = updated.monthly_budget.expect("budget should be set");
+    assert_eq!(budget.amount, 5_000_000_000i64);
+    assert_eq!(budget.scale, 9);
+
+    let listed = list_workspaces(&server, org.id.clone()).await;
+    let listed = listed.iter().find(|w| w.id == workspace.id).unwrap();
+    assert_eq!(
+        listed.monthly_budget.as_ref().map(|b| b.amount),
+        Some(5_000_000_000i64)
+    );
+
+    let response = set_monthly_budget(
+        &server,
+        &workspace.id,
+        serde_json::json!({"amount": -1, "currency": "USD"}),
+    )
+    .await;
+    assert_eq!(response.status_code(), 400);
+
+    let response = set_monthly_budget(&server, &workspace.id, serde_json::Value::Null).await;
+    assert_eq!(response.status_code(), 200);
+    let cleared: WorkspaceResponse = response.json();
+    assert_eq!(cleared.monthly_budget, None);
+}
+
+#[tokio::test]
+async fn test_workspace_monthly_budget_blocks_only_that_workspace() {
+    let server = setup_test_server().await;
+    setup_qwen_model(&server).await;
+    let org = setup_org_with_credits(&server, 10_000_000_000i64).await;
+
+    let limited = list_workspaces(&server, org.id.clone()).await.remove(0);
+    let limited_key = create_api_key_in_workspace(&server, limited.id.clone(), "Limited".into())
+        .await
+        .key
+        .unwrap();
+
+    let response = server
+        .post(format!("/v1/organizations/{}/workspaces", org.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .json(&serde_json::json!({"name": "unlimited-workspace"}))
+        .await;
+    assert_eq!(response.status_code(), 201, "{}", response.text());
+    let unlimited: WorkspaceResponse = response.json();
+    let unlimited_key =
+        create_api_key_in_workspace(&server, unlimited.id.clone(), "Unlimited".into())
+            .await
+            .key
+            .unwrap();
+
+    // 1 nano-dollar: the first request is admitted, and its recorded cost
+    // exhausts the budget.
+    let response = set_monthly_budget(
+        &server,
+        &limited.id,
+        serde_json::json!({"amount": 1, "currency": "USD"}),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let first = chat(&server, &limited_key).await;
+    assert_eq!(first.status_code(), 200, "{}", first.text());
+
+    // Wait for usage to be recorded
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    let blocked = chat(&server, &limited_key).await;
+    assert_eq!(blocked.status_code(), 402, "{}", blocked.text());
+    let retry_after: i64 = blocked
+        .headers()
+        .get("retry-after")
+        .expect("monthly budget rejection carries Retry-After")
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    let raw: serde_json::Value = blocked.json();
+    assert_eq!(raw["error"]["limit_scope"], "workspace", "{raw}");
+    assert!(raw["error"]["reset_at"].is_string(), "{raw}");
+    let error: api::models::ErrorResponse = blocked.json();
+    assert_eq!(error.error.r#type, "spend_limit_exceeded");
+    assert_eq!(
+        error.error.code.as_deref(),
+        Some("workspace_budget_exceeded")
+    );
+    assert_eq!(
+        error.error.limit.as_ref().map(|l| l.limit_scope.as_str()),
+        Some("workspace")
+    );
+    let reset_at = error
+        .error
+        .limit
+        .as_ref()
+        .and_then(|l| l.reset_at)
+        .expect("monthly budget reports reset_at");
+    let now = chrono::Utc::now();
+    assert!(reset_at > now && reset_at - now <= chrono::Duration::days(31));
+    assert_eq!(chrono::Datelike::day(&reset_at), 1);
+    assert!(retry_after > 0 && retry_after <= (reset_at - now).num_seconds() + 1);
+
+    // Other workspaces in the organization are unaffected.
+    let other = chat(&server, &unlimited_key).await;
+    assert_eq!(other.status_code(), 200, "{}", other.text());
+
+    // Raising the budget lets the workspace through again.
+    let response = set_monthly_budget(
+        &server,
+        &limited.id,
+        serde_json::json!({"amount": 1_000_000_000i64, "currency": "USD"}),
+    )
+    .await;
+    assert_eq!(response.status_code(), 200);
+    let resumed = chat(&server, &limited_key).await;
+    assert_eq!(resumed.status_code(), 200, "{}", resumed.text());
+}
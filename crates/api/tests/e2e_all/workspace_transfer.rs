@@ -0,0 +1,216 @@
+// E2E tests for moving a workspace between organizations
+
+use crate::common::*;
+use api::routes::workspaces::WorkspaceResponse;
+
+async fn create_workspace(
+    server: &axum_test::TestServer,
+    org_id: &str,
+    session_id: &str,
+) -> WorkspaceResponse {
+    let response = server
+        .post(format!("/v1/organizations/{org_id}/workspaces").as_str())
+        .add_header("Authorization", format!("Bearer {session_id}"))
+        .json(&serde_json::json!({ "name": format!("transfer-{}", uuid::Uuid::new_v4()) }))
+        .await;
+    assert_eq!(response.status_code(), 201, "{}", response.text());
+    response.json()
+}
+
+async fn transfer(
+    server: &axum_test::TestServer,
+    workspace_id: &str,
+    organization_id: &str,
+    session_id: &str,
+) -> axum_test::TestResponse {
+    server
+        .post(format!("/v1/workspaces/{workspace_id}/transfer").as_str())
+        .add_header("Authorization", format!("Bearer {session_id}"))
+        .json(&serde_json::json!({ "organization_id": organization_id }))
+        .await
+}
+
+async fn chat(server: &axum_test::TestServer, api_key: &str) -> axum_test::TestResponse {
+    server
+        .post("/v1/chat/completions")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .add_header("User-Agent", MOCK_USER_AGENT)
+        .json(&serde_json::json!({
+            "model": E2E_QWEN_MODEL_NAME,
+            "messages": [{"role": "user", "content": "Hi"}],
+            "stream": false,
+            "max_tokens": 10
+        }))
+        .await
+}
+
+#[tokio::test]
+async fn test_transfer_workspace_moves_it_with_its_api_keys() {
+    let server = setup_test_server().await;
+    let source = create_org(&server).await;
+    let target = create_org(&server).await;
+    let workspace = create_workspace(&server, &source.id, &get_session_id()).await;
+    let api_key = create_api_key_in_workspace(&server, workspace.id.clone(), "Moved".into()).await;
+
+    let response = transfer(&server, &workspace.id, &target.id, &get_session_id()).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let moved: WorkspaceResponse = response.json();
+    assert_eq!(moved.id, workspace.id);
+    assert_eq!(moved.organization_id, target.id);
+    assert_eq!(moved.name, workspace.name);
+
+    let source_workspaces = list_workspaces(&server, source.id.clone()).await;
+    assert!(source_workspaces.iter().all(|w| w.id != workspace.id));
+    let target_workspaces = list_workspaces(&server, target.id.clone()).await;
+    assert!(target_workspaces.iter().any(|w| w.id == workspace.id));
+
+    // The API key stays with the workspace.
+    let response = server
+        .get(format!("/v1/workspaces/{}/api-keys", workspace.id).as_str())
+        .add_header("Authorization", format!("Bearer {}", get_session_id()))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let keys: api::models::ListApiKeysResponse = response.json();
+    assert!(keys.api_keys.iter().any(|k| k.id == api_key.id));
+
+    // Transferring into the organization that already owns it is rejected.
+    let response = transfer(&server, &workspace.id, &target.id, &get_session_id()).await;
+    assert_eq!(response.status_code(), 400, "{}", response.text());
+}
+
+#[tokio::test]
+async fn test_transfer_workspace_requires_admin_on_target_org() {
+    let (server, database) = setup_test_server_with_database().await;
+    let source = create_org(&server).await;
+    let workspace = create_workspace(&server, &source.id, &get_session_id()).await;
+
+    let (other_session, _) = setup_unique_test_session(&database).await;
+    let foreign = create_org_with_session(&server, &other_session).await;
+
+    let response = transfer(&server, &workspace.id, &foreign.id, &get_session_id()).await;
+    assert_eq!(response.status_code(), 403, "{}", response.text());
+    let error: api::models::ErrorResponse = response.json();
+    assert_eq!(error.error.r#type, "forbidden");
+
+    // Nor can the target org's owner pull in a workspace from an org they don't manage.
+    let response = transfer(&server, &workspace.id, &foreign.id, &other_session).await;
+    assert_eq!(response.status_code(), 403, "{}", response.text());
+
+    let source_workspaces = list_workspaces(&server, source.id.clone()).await;
+    assert!(source_workspaces.iter().any(|w| w.id == workspace.id));
+}
+
+#[tokio::test]
+async fn test_transfer_workspace_bills_new_usage_to_new_org() {
+    let (server, _pool, _mock, database) = setup_test_server_with_pool().await;
+    setup_qwen_model(&server).await;
+    let source = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let target = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let workspace = create_workspace(&server, &source.id, &get_session_id()).await;
+    let api_key = create_api_key_in_workspace(&server, workspace.id.clone(), "Billing".into())
+        .await
+        .key
+        .unwrap();
+
+    let before = chat(&server, &api_key).await;
+    assert_eq!(before.status_code(), 200, "{}", before.text());
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    let response = transfer(&server, &workspace.id, &target.id, &get_session_id()).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let after = chat(&server, &api_key).await;
+    assert_eq!(after.status_code(), 200, "{}", after.text());
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    let workspace_id = uuid::Uuid::parse_str(&workspace.id).expect("workspace id");
+    let client = database.pool().get().await.expect("db connection");
+    let rows = client
+        .query(
+            "SELECT organization_id FROM organization_usage_log \
+             WHERE workspace_id = $1 ORDER BY created_at ASC",
+            &[&workspace_id],
+        )
+        .await
+        .expect("usage rows");
+    let orgs: Vec<String> = rows
+        .iter()
+        .map(|row| row.get::<_, uuid::Uuid>("organization_id").to_string())
+        .collect();
+    assert_eq!(
+        orgs,
+        vec![source.id.clone(), target.id.clone()],
+        "usage before the transfer stays with the old org; later usage bills the new one"
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_workspace_moves_pending_batch_billing_to_new_org() {
+    let (server, _mock, processor) = setup_test_server_with_batch_processor().await;
+    let model = setup_qwen_model(&server).await;
+    let source = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let target = setup_org_with_credits(&server, 10_000_000_000i64).await;
+    let workspace = create_workspace(&server, &source.id, &get_session_id()).await;
+    let api_key = create_api_key_in_workspace(&server, workspace.id.clone(), "Batch".into())
+        .await
+        .key
+        .unwrap();
+
+    let response = server
+        .post("/v1/batches")
+        .add_header("Authorization", format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "endpoint": "/v1/chat/completions",
+            "completion_window": "24h",
+            "requests": [{"custom_id": "only", "body": {
+                "model": model,
+                "messages": [{"role": "user", "content": "Hi"}],
+                "max_tokens": 10
+            }}]
+        }))
+        .await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+    let batch: api::models::BatchObject = response.json();
+
+    let response = transfer(&server, &workspace.id, &target.id, &get_session_id()).await;
+    assert_eq!(response.status_code(), 200, "{}", response.text());
+
+    let mut status = String::new();
+    for _ in 0..50 {
+        processor.run_once().await.expect("batch processing pass");
+        let response = server
+            .get(&format!("/v1/batches/{}", batch.id))
+            .add_header("Authorization", format!("Bearer {api_key}"))
+            .await;
+        assert_eq!(response.status_code(), 200, "{}", response.text());
+        status = response.json::<api::models::BatchObject>().status;
+        if status == "completed" {
+            break;
+        }
+    }
+    assert_eq!(status, "completed");
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    let usage_count = |org_id: String| {
+        let server = &server;
+        async move {
+            let response = server
+                .get(&format!(
+                    "/v1/organizations/{org_id}/usage/history?limit=10&offset=0"
+                ))
+                .add_header("Authorization", format!("Bearer {}", get_session_id()))
+                .await;
+            assert_eq!(response.status_code(), 200, "{}", response.text());
+            response
+                .json::<api::routes::usage::UsageHistoryResponse>()
+                .data
+                .len()
+        }
+    };
+    assert_eq!(
+        usage_count(source.id.clone()).await,
+        0,
+        "a batch still pending at transfer time must not bill the old org"
+    );
+    assert_eq!(usage_count(target.id.clone()).await, 1);
+}
@@ -21,6 +21,8 @@ pub use types::*;
 pub enum ConfigError {
     #[error("Failed to load configuration from environment: {0}")]
     EnvError(String),
+    #[error("Invalid configuration:\n  - {}", .0.join("\n  - "))]
+    Invalid(Vec<String>),
 }
 
 /// Main configuration loading interface
@@ -33,6 +35,309 @@ impl ApiConfig {
         // Try to load .env file if it exists (don't error if it doesn't)
         let _ = dotenvy::dotenv();
 
-        ApiConfig::from_env().map_err(ConfigError::EnvError)
+        let config = ApiConfig::from_env().map_err(ConfigError::EnvError)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check cross-field invariants that individual `from_env` parsers cannot
+    /// see, so a bad combination fails at startup instead of surfacing later
+    /// as a confusing runtime error. Every problem is reported at once.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+
+        if self.server.host.trim().is_empty() {
+            problems.push("SERVER_HOST must not be empty".to_string());
+        }
+
+        if self.server.default_page_size < 1 {
+            problems.push("DEFAULT_PAGE_SIZE must be at least 1".to_string());
+        }
+        if self.server.max_page_size < self.server.default_page_size {
+            problems.push("MAX_PAGE_SIZE must not be smaller than DEFAULT_PAGE_SIZE".to_string());
+        }
+        if self.server.inference_id_namespace.is_nil() {
+            problems.push("INFERENCE_ID_NAMESPACE must not be the nil UUID".to_string());
+        }
+        if self.server.inference_id_namespace == EXTERNAL_USAGE_ID_NAMESPACE {
+            problems.push(
+                "INFERENCE_ID_NAMESPACE must not be the namespace reserved for external usage ids"
+                    .to_string(),
+            );
+        }
+
+        if !self.auth.mock && self.auth.encoding_key.trim().is_empty() {
+            problems.push(
+                "AUTH_ENCODING_KEY must be set when AUTH_MOCK is false; sessions cannot be issued without it"
+                    .to_string(),
+            );
+        }
+
+        if !self.database.mock {
+            if self.database.max_connections == 0 {
+                problems.push("DATABASE_MAX_CONNECTIONS must be at least 1".to_string());
+            }
+            if self.database.tls_ca_cert_path.is_some() && !self.database.tls_enabled {
+                problems.push(
+                    "DATABASE_TLS_CA_CERT_PATH is set but DATABASE_TLS_ENABLED is false"
+                        .to_string(),
+                );
+            }
+            if !self.database.tls_pinned_spki_sha256.is_empty() && !self.database.tls_enabled {
+                problems.push(
+                    "DATABASE_TLS_PINNED_SPKI_SHA256 is set but DATABASE_TLS_ENABLED is false"
+                        .to_string(),
+                );
+            }
+            if self
+                .database
+                .tls_pinned_spki_sha256
+                .iter()
+                .any(|pin| pin.len() != 64 || !pin.bytes().all(|b| b.is_ascii_hexdigit()))
+            {
+                problems.push(
+                    "DATABASE_TLS_PINNED_SPKI_SHA256 entries must be 64-character hex SHA-256 digests"
+                        .to_string(),
+                );
+            }
+        }
+
+        if !self.s3.mock && self.s3.bucket.trim().is_empty() {
+            problems.push("AWS_S3_BUCKET must not be empty when S3_MOCK is false".to_string());
+        }
+
+        let providers = &self.external_providers;
+        if providers.enable_chutes {
+            if providers.chutes_api_key.is_none() {
+                problems.push(
+                    "CHUTES_API_KEY (or CHUTES_API_KEY_FILE) must be set when ENABLE_CHUTES=true"
+                        .to_string(),
+                );
+            }
+            if providers.chutes_models.is_empty() {
+                problems.push(
+                    "CHUTES_MODELS must list at least one model when ENABLE_CHUTES=true"
+                        .to_string(),
+                );
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(problems))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_config() -> ApiConfig {
+        ApiConfig {
+            server: ServerConfig {
+                host: "0.0.0.0".to_string(),
+                port: 3000,
+                pricing_change_apply_interval_secs: 60,
+                batch_processing_interval_secs: 10,
+                ohttp_enabled: false,
+                stream_keepalive_interval_ms: 0,
+                stream_coalesce_window_ms: 0,
+                stream_coalesce_max_bytes: DEFAULT_STREAM_COALESCE_MAX_BYTES,
+                stream_dedup_window_ms: 0,
+                end_user_requests_per_minute: 0,
+                max_inference_body_bytes: DEFAULT_MAX_INFERENCE_BODY_BYTES,
+                slow_request_threshold_ms: DEFAULT_SLOW_REQUEST_THRESHOLD_MS,
+                default_page_size: DEFAULT_PAGE_SIZE,
+                max_page_size: DEFAULT_MAX_PAGE_SIZE,
+                inference_id_namespace: DEFAULT_INFERENCE_ID_NAMESPACE,
+                inference_id_legacy_lookup: true,
+                shutdown_drain_secs: 0,
+                json_schema_fallback: JsonSchemaFallbackPolicy::Passthrough,
+                trusted_proxy_hops: DEFAULT_TRUSTED_PROXY_HOPS,
+            },
+            inference_api_key: None,
+            internal_usage_token: None,
+            logging: LoggingConfig::default(),
+            dstack_client: DstackClientConfig {
+                url: "http://localhost:8000".to_string(),
+            },
+            auth: AuthConfig {
+                encoding_key: "encoding-key".to_string(),
+                ..AuthConfig::default()
+            },
+            database: DatabaseConfig {
+                primary_app_id: "postgres".to_string(),
+                gateway_subdomain: "example.com".to_string(),
+                host: None,
+                port: 5432,
+                database: "cloud_api".to_string(),
+                username: "postgres".to_string(),
+                password: "postgres".to_string(),
+                max_connections: 16,
+                tls_enabled: true,
+                tls_ca_cert_path: None,
+                tls_min_version: None,
+                tls_pinned_spki_sha256: Vec::new(),
+                refresh_interval: 30,
+                mock: false,
+            },
+            s3: S3Config {
+                mock: false,
+                bucket: "bucket".to_string(),
+                region: "us-east-1".to_string(),
+                encryption_key: "key".to_string(),
+            },
+            invitation_email: InvitationEmailConfig::default(),
+            otlp: OtlpConfig::default(),
+            cors: CorsConfig::default(),
+            external_providers: ExternalProvidersConfig::default(),
+            github_dispatch: GitHubDispatchConfig::default(),
+            infra: InfraConfig::default(),
+            staking_farm: StakingFarmConfig::default(),
+            usage_reporting: UsageReportingConfig::default(),
+            usage_anomaly: UsageAnomalyConfig::default(),
+            ita: ItaAttestationConfig::default(),
+            audit_log: AuditLogConfig::default(),
+            provider_headers: ProviderHeadersConfig::default(),
+            mcp_connectors: McpConnectorsConfig::default(),
+            currency: CurrencyConfig::default(),
+        }
+    }
+
+    fn problems(config: &ApiConfig) -> Vec<String> {
+        match config.validate() {
+            Err(ConfigError::Invalid(problems)) => problems,
+            other => panic!("expected invalid configuration, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn valid_config_passes() {
+        assert!(valid_config().validate().is_ok());
+    }
+
+    #[test]
+    fn missing_encoding_key_is_rejected_unless_auth_is_mocked() {
+        let mut config = valid_config();
+        config.auth.encoding_key = "  ".to_string();
+        assert_eq!(
+            problems(&config),
+            vec!["AUTH_ENCODING_KEY must be set when AUTH_MOCK is false; sessions cannot be issued without it"]
+        );
+
+        config.auth.mock = true;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn database_invariants_are_checked() {
+        let mut config = valid_config();
+        config.database.max_connections = 0;
+        config.database.tls_enabled = false;
+        config.database.tls_ca_cert_path = Some("/etc/ssl/ca.pem".to_string());
+        assert_eq!(
+            problems(&config),
+            vec![
+                "DATABASE_MAX_CONNECTIONS must be at least 1",
+                "DATABASE_TLS_CA_CERT_PATH is set but DATABASE_TLS_ENABLED is false",
+            ]
+        );
+    }
+
+    #[test]
+    fn database_tls_pins_are_checked() {
+        let mut config = valid_config();
+        config.database.tls_pinned_spki_sha256 = vec!["ab".repeat(32)];
+        assert!(config.validate().is_ok());
+
+        config.database.tls_enabled = false;
+        config
+            .database
+            .tls_pinned_spki_sha256
+            .push("not-a-digest".to_string());
+        assert_eq!(
+            problems(&config),
+            vec![
+                "DATABASE_TLS_PINNED_SPKI_SHA256 is set but DATABASE_TLS_ENABLED is false",
+                "DATABASE_TLS_PINNED_SPKI_SHA256 entries must be 64-character hex SHA-256 digests",
+            ]
+        );
+    }
+
+    #[test]
+    fn page_size_bounds_are_checked() {
+        let mut config = valid_config();
+        config.server.default_page_size = 0;
+        assert_eq!(
+            problems(&config),
+            vec!["DEFAULT_PAGE_SIZE must be at least 1"]
+        );
+
+        config.server.default_page_size = 200;
+        config.server.max_page_size = 100;
+        assert_eq!(
+            problems(&config),
+            vec!["MAX_PAGE_SIZE must not be smaller than DEFAULT_PAGE_SIZE"]
+        );
+    }
+
+    #[test]
+    fn nil_inference_id_namespace_is_rejected() {
+        let mut config = valid_config();
+        config.server.inference_id_namespace = uuid::Uuid::nil();
+        assert_eq!(
+            problems(&config),
+            vec!["INFERENCE_ID_NAMESPACE must not be the nil UUID"]
+        );
+    }
+
+    #[test]
+    fn reserved_inference_id_namespace_is_rejected() {
+        let mut config = valid_config();
+        config.server.inference_id_namespace = EXTERNAL_USAGE_ID_NAMESPACE;
+        assert_eq!(
+            problems(&config),
+            vec![
+                "INFERENCE_ID_NAMESPACE must not be the namespace reserved for external usage ids"
+            ]
+        );
+    }
+
+    #[test]
+    fn chutes_requires_key_and_models_when_enabled() {
+        let mut config = valid_config();
+        config.external_providers.enable_chutes = true;
+        assert_eq!(
+            problems(&config),
+            vec![
+                "CHUTES_API_KEY (or CHUTES_API_KEY_FILE) must be set when ENABLE_CHUTES=true",
+                "CHUTES_MODELS must list at least one model when ENABLE_CHUTES=true",
+            ]
+        );
+    }
+
+    #[test]
+    fn every_problem_is_reported_at_once() {
+        let mut config = valid_config();
+        config.server.host = String::new();
+        config.auth.encoding_key = String::new();
+        config.s3.bucket = String::new();
+        let err = config.validate().unwrap_err();
+        let message = err.to_string();
+        assert!(message.starts_with("Invalid configuration:"), "{message}");
+        assert!(
+            message.contains("SERVER_HOST must not be empty"),
+            "{message}"
+        );
+        assert!(
+            message.contains("AUTH_ENCODING_KEY must be set"),
+            "{message}"
+        );
+        assert!(
+            message.contains("AWS_S3_BUCKET must not be empty when S3_MOCK is false"),
+            "{message}"
+        );
     }
 }
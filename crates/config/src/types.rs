@@ -26,7 +26,12 @@ pub struct ApiConfig {
     pub infra: InfraConfig,
     pub staking_farm: StakingFarmConfig,
     pub usage_reporting: UsageReportingConfig,
+    pub usage_anomaly: UsageAnomalyConfig,
     pub ita: ItaAttestationConfig,
+    pub audit_log: AuditLogConfig,
+    pub provider_headers: ProviderHeadersConfig,
+    pub mcp_connectors: McpConnectorsConfig,
+    pub currency: CurrencyConfig,
 }
 
 impl ApiConfig {
@@ -59,10 +64,242 @@ impl ApiConfig {
             infra: InfraConfig::from_env(),
             ita: ItaAttestationConfig::from_env()?,
             usage_reporting: UsageReportingConfig::from_env()?,
+            usage_anomaly: UsageAnomalyConfig::from_env()?,
+            audit_log: AuditLogConfig::from_env()?,
+            provider_headers: ProviderHeadersConfig::from_env()?,
+            mcp_connectors: McpConnectorsConfig::from_env()?,
+            currency: CurrencyConfig::from_env()?,
         })
     }
 }
 
+/// Compliance audit trail for chat completions.
+///
+/// When enabled, every chat completion writes one row to the audit store with
+/// the API key, model, and SHA-256 hashes of the request and response bodies.
+/// Storing the bodies themselves (PII-redacted) is a separate opt-in because
+/// it persists customer content; both are off by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditLogConfig {
+    pub enabled: bool,
+    pub store_bodies: bool,
+}
+
+impl AuditLogConfig {
+    pub fn from_env() -> Result<Self, String> {
+        let config = Self {
+            enabled: parse_bool_env("AUDIT_LOG_ENABLED", false)?,
+            store_bodies: parse_bool_env("AUDIT_LOG_STORE_BODIES", false)?,
+        };
+        if config.store_bodies && !config.enabled {
+            return Err("AUDIT_LOG_STORE_BODIES requires AUDIT_LOG_ENABLED=true".to_string());
+        }
+        Ok(config)
+    }
+}
+
+/// Workspace MCP connectors registered through `/v1/mcp/connectors`.
+///
+/// Connector URLs must be public HTTPS endpoints by default. Allowing plain
+/// HTTP and private addresses exists for local development and tests only.
+/// `auto_tool_max_iterations` caps the model calls one chat completion may
+/// make when it opts into server-side tool execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpConnectorsConfig {
+    pub allow_insecure_server_urls: bool,
+    pub auto_tool_max_iterations: u32,
+}
+
+pub const DEFAULT_MCP_AUTO_TOOL_MAX_ITERATIONS: u32 = 5;
+
+impl Default for McpConnectorsConfig {
+    fn default() -> Self {
+        Self {
+            allow_insecure_server_urls: false,
+            auto_tool_max_iterations: DEFAULT_MCP_AUTO_TOOL_MAX_ITERATIONS,
+        }
+    }
+}
+
+impl McpConnectorsConfig {
+    pub fn from_env() -> Result<Self, String> {
+        let auto_tool_max_iterations = parse_u32_env(
+            "MCP_AUTO_TOOL_MAX_ITERATIONS",
+            DEFAULT_MCP_AUTO_TOOL_MAX_ITERATIONS,
+        )?;
+        if auto_tool_max_iterations == 0 {
+            return Err("MCP_AUTO_TOOL_MAX_ITERATIONS must be at least 1".to_string());
+        }
+        Ok(Self {
+            allow_insecure_server_urls: parse_bool_env(
+                "MCP_CONNECTORS_ALLOW_INSECURE_URLS",
+                false,
+            )?,
+            auto_tool_max_iterations,
+        })
+    }
+}
+
+/// Client request headers forwarded to self-hosted inference backends.
+///
+/// Some vLLM deployments act on custom headers (e.g. routing hints). Only
+/// `X-`-prefixed names listed here are forwarded; every other client header
+/// is dropped. Headers the gateway sets itself (auth, tracing, E2EE) can never
+/// be allowlisted, so a client cannot override them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProviderHeadersConfig {
+    /// Lowercased header names, e.g. `x-routing-hint`.
+    pub passthrough_allowlist: Vec<String>,
+}
+
+/// Headers the gateway sets on upstream requests itself.
+const RESERVED_PROVIDER_HEADERS: &[&str] = &[
+    "x-request-id",
+    "x-org-id",
+    "x-workspace-id",
+    "x-request-hash",
+    "x-signing-algo",
+    "x-client-pub-key",
+    "x-model-pub-key",
+    "x-encryption-version",
+    "x-encrypt-all-fields",
+];
+
+impl ProviderHeadersConfig {
+    pub fn from_env() -> Result<Self, String> {
+        match env::var("PROVIDER_HEADER_PASSTHROUGH") {
+            Ok(raw) => Self::parse(&raw),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Parse a comma-separated list of header names.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let mut passthrough_allowlist = Vec::new();
+        for entry in raw.split(',') {
+            let name = entry.trim().to_ascii_lowercase();
+            if name.is_empty() {
+                continue;
+            }
+            if !name.starts_with("x-") || name.len() == 2 {
+                return Err(format!(
+                    "PROVIDER_HEADER_PASSTHROUGH: '{name}' must be an X- prefixed header"
+                ));
+            }
+            if !name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            {
+                return Err(format!(
+                    "PROVIDER_HEADER_PASSTHROUGH: '{name}' is not a valid header name"
+                ));
+            }
+            if RESERVED_PROVIDER_HEADERS.contains(&name.as_str()) {
+                return Err(format!(
+                    "PROVIDER_HEADER_PASSTHROUGH: '{name}' is set by the gateway and cannot be forwarded"
+                ));
+            }
+            if !passthrough_allowlist.contains(&name) {
+                passthrough_allowlist.push(name);
+            }
+        }
+        Ok(Self {
+            passthrough_allowlist,
+        })
+    }
+
+    pub fn allows(&self, name: &str) -> bool {
+        self.passthrough_allowlist
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Static exchange rates for organization spend limits.
+///
+/// Limits are stored in the currency the admin submitted and converted to USD
+/// nano-dollars whenever they are compared with spend. Only USD and the
+/// currencies listed here are accepted; anything else is rejected at write
+/// time rather than being read as dollars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyConfig {
+    /// Uppercase currency code -> USD value of one unit in millionths
+    /// (`EUR=1.08` is stored as `1_080_000`).
+    pub usd_rates_micros: HashMap<String, i64>,
+}
+
+impl Default for CurrencyConfig {
+    /// Hot-pay credits are issued in USDT, which is pegged 1:1 to USD.
+    fn default() -> Self {
+        Self {
+            usd_rates_micros: HashMap::from([("USDT".to_string(), 1_000_000)]),
+        }
+    }
+}
+
+impl CurrencyConfig {
+    pub fn from_env() -> Result<Self, String> {
+        match env::var("SPEND_LIMIT_CURRENCY_RATES") {
+            Ok(raw) => Self::parse(&raw),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Parse a comma-separated list of `CODE=rate` pairs, where `rate` is the
+    /// USD value of one unit with at most six decimal places.
+    ///
+    /// Entries are merged over the defaults, so configuring other currencies
+    /// never drops the USDT rate that existing hot-pay credits rely on.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let mut usd_rates_micros = Self::default().usd_rates_micros;
+        for entry in raw.split(',') {
+            let entry = entry.trim();
+            if entry.is_empty() {
+                continue;
+            }
+            let (code, rate) = entry.split_once('=').ok_or_else(|| {
+                format!("SPEND_LIMIT_CURRENCY_RATES: '{entry}' must be CODE=rate")
+            })?;
+            let code = code.trim().to_ascii_uppercase();
+            if code.len() < 3 || code.len() > 16 || !code.bytes().all(|b| b.is_ascii_alphanumeric())
+            {
+                return Err(format!(
+                    "SPEND_LIMIT_CURRENCY_RATES: '{code}' is not a valid currency code"
+                ));
+            }
+            if code == "USD" {
+                return Err(
+                    "SPEND_LIMIT_CURRENCY_RATES: USD is the base currency and cannot be given a rate"
+                        .to_string(),
+                );
+            }
+            let micros = parse_rate_micros(rate.trim()).ok_or_else(|| {
+                format!(
+                    "SPEND_LIMIT_CURRENCY_RATES: rate for {code} must be a positive decimal with at most 6 decimal places"
+                )
+            })?;
+            usd_rates_micros.insert(code, micros);
+        }
+        Ok(Self { usd_rates_micros })
+    }
+}
+
+/// Parse a positive decimal such as `1.08` into millionths.
+fn parse_rate_micros(raw: &str) -> Option<i64> {
+    let (whole, fraction) = raw.split_once('.').unwrap_or((raw, ""));
+    if whole.is_empty()
+        || fraction.len() > 6
+        || !whole.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let whole: i64 = whole.parse().ok()?;
+    let fraction: i64 = format!("{fraction:0<6}").parse().ok()?;
+    let micros = whole.checked_mul(1_000_000)?.checked_add(fraction)?;
+    (micros > 0).then_some(micros)
+}
+
 /// Operational limits for the programmatic usage-reporting API.
 ///
 /// Reporting is disabled by default because its production indexes are built
@@ -144,6 +381,74 @@ impl UsageReportingConfig {
     }
 }
 
+/// Background detection of unusual organization spend.
+///
+/// Every `interval_secs` the detector compares each organization's spend in
+/// the current hour against the mean and standard deviation of its hourly
+/// spend over the preceding `lookback_hours`, and flags hours more than
+/// `stddev_threshold` deviations above the mean. Disabled by default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageAnomalyConfig {
+    /// Seconds between detection passes; 0 disables the detector.
+    pub interval_secs: u64,
+    pub lookback_hours: u32,
+    pub stddev_threshold: f64,
+    /// Organizations with spend in fewer baseline hours than this are
+    /// skipped, so a brand-new account's first hour is not an anomaly.
+    pub min_baseline_hours: u32,
+    /// Optional endpoint that receives a JSON POST per detected anomaly.
+    pub webhook_url: Option<String>,
+}
+
+impl Default for UsageAnomalyConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 0,
+            lookback_hours: 168,
+            stddev_threshold: 3.0,
+            min_baseline_hours: 24,
+            webhook_url: None,
+        }
+    }
+}
+
+impl UsageAnomalyConfig {
+    pub fn from_env() -> Result<Self, String> {
+        let defaults = Self::default();
+        let stddev_threshold = match env::var("USAGE_ANOMALY_STDDEV_THRESHOLD") {
+            Ok(raw) => raw
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite() && *value > 0.0)
+                .ok_or("USAGE_ANOMALY_STDDEV_THRESHOLD must be a positive number")?,
+            Err(_) => defaults.stddev_threshold,
+        };
+        let config = Self {
+            interval_secs: parse_u64_env("USAGE_ANOMALY_INTERVAL_SECS", defaults.interval_secs)?,
+            lookback_hours: parse_u32_env("USAGE_ANOMALY_LOOKBACK_HOURS", defaults.lookback_hours)?,
+            stddev_threshold,
+            min_baseline_hours: parse_u32_env(
+                "USAGE_ANOMALY_MIN_BASELINE_HOURS",
+                defaults.min_baseline_hours,
+            )?,
+            webhook_url: env::var("USAGE_ANOMALY_WEBHOOK_URL")
+                .ok()
+                .filter(|s| !s.is_empty()),
+        };
+        if config.lookback_hours == 0 {
+            return Err("USAGE_ANOMALY_LOOKBACK_HOURS must be greater than zero".to_string());
+        }
+        if config.min_baseline_hours > config.lookback_hours {
+            return Err(
+                "USAGE_ANOMALY_MIN_BASELINE_HOURS must not exceed USAGE_ANOMALY_LOOKBACK_HOURS"
+                    .to_string(),
+            );
+        }
+        Ok(config)
+    }
+}
+
 /// Global House of Stake farm configuration used to convert reward units into
 /// NEAR AI Cloud credits. The feature is disabled until contract/product IDs are
 /// supplied by the deployment environment.
@@ -355,6 +660,23 @@ fn is_owner_name(s: &str) -> bool {
     )
 }
 
+/// Lowest TLS protocol version accepted for database connections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseTlsVersion {
+    Tls12,
+    Tls13,
+}
+
+impl DatabaseTlsVersion {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "1.2" | "tls1.2" | "tlsv1.2" => Some(Self::Tls12),
+            "1.3" | "tls1.3" | "tlsv1.3" => Some(Self::Tls13),
+            _ => None,
+        }
+    }
+}
+
 /// Database configuration
 #[derive(Debug, Clone)]
 pub struct DatabaseConfig {
@@ -372,6 +694,13 @@ pub struct DatabaseConfig {
     /// Path to a custom CA certificate file (optional)
     /// If provided, this certificate will be added to the trust store
     pub tls_ca_cert_path: Option<String>,
+    /// Minimum TLS version for database connections (`1.2` or `1.3`).
+    /// `None` keeps the TLS library's default.
+    pub tls_min_version: Option<DatabaseTlsVersion>,
+    /// SHA-256 fingerprints (hex) of the SubjectPublicKeyInfo the database
+    /// server certificate must present. Empty disables pinning; several values
+    /// allow a key rotation without downtime.
+    pub tls_pinned_spki_sha256: Vec<String>,
     /// Interval in seconds for refreshing cluster state
     pub refresh_interval: u64,
     /// Use mock database for testing (bypasses Patroni discovery and real database)
@@ -415,6 +744,22 @@ impl DatabaseConfig {
                 .parse()
                 .map_err(|_| "DATABASE_TLS_ENABLED must be true or false")?,
             tls_ca_cert_path: env::var("DATABASE_TLS_CA_CERT_PATH").ok(),
+            tls_min_version: match env::var("DATABASE_TLS_MIN_VERSION") {
+                Ok(value) => Some(
+                    DatabaseTlsVersion::parse(&value)
+                        .ok_or("DATABASE_TLS_MIN_VERSION must be 1.2 or 1.3")?,
+                ),
+                Err(_) => None,
+            },
+            tls_pinned_spki_sha256: env::var("DATABASE_TLS_PINNED_SPKI_SHA256")
+                .map(|value| {
+                    value
+                        .split(',')
+                        .map(|pin| pin.trim().replace(':', "").to_ascii_lowercase())
+                        .filter(|pin| !pin.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
             refresh_interval: env::var("DATABASE_REFRESH_INTERVAL")
                 .unwrap_or_else(|_| "30".to_string())
                 .parse()
@@ -425,6 +770,69 @@ impl DatabaseConfig {
     }
 }
 
+/// Default cap on JSON inference request bodies (10 MB).
+pub const DEFAULT_MAX_INFERENCE_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+/// Default flush threshold for streamed content-delta coalescing (1 KB).
+pub const DEFAULT_STREAM_COALESCE_MAX_BYTES: usize = 1024;
+
+/// Default slow-request logging threshold (10 seconds).
+pub const DEFAULT_SLOW_REQUEST_THRESHOLD_MS: u64 = 10_000;
+
+/// Default number of `X-Forwarded-For` appending proxies (one load balancer).
+pub const DEFAULT_TRUSTED_PROXY_HOPS: usize = 1;
+
+/// Default `limit` for listing endpoints when the request omits it.
+pub const DEFAULT_PAGE_SIZE: i64 = 100;
+
+/// Default upper bound on `limit` for listing endpoints; larger values are clamped.
+pub const DEFAULT_MAX_PAGE_SIZE: i64 = 1000;
+
+/// Default UUID v5 namespace for hashing provider response ids into
+/// `inference_id`s: `uuid5(NAMESPACE_DNS, "inference-id.cloud-api.near.ai")`,
+/// embedded as a constant so the value never drifts.
+pub const DEFAULT_INFERENCE_ID_NAMESPACE: uuid::Uuid =
+    uuid::Uuid::from_u128(0x52928c6e_4609_5628_a8f0_8ee775572c36);
+
+/// Dedicated UUID v5 namespace for `inference_id`s derived from external `id`s
+/// submitted via `POST /v1/internal/usage`. The internal inference pipeline hashes
+/// provider request ids under its configured namespace (legacy rows under
+/// `Uuid::NAMESPACE_DNS`); using a different
+/// namespace UUID here makes the two `inference_id` spaces mathematically
+/// disjoint regardless of input — no shape of provider id (even one that
+/// happens to mimic a previous string-prefix scheme) can ever collide with
+/// an externally-submitted record's hash. `INFERENCE_ID_NAMESPACE` must
+/// therefore never be set to it.
+///
+/// Generated once via `uuid5(NAMESPACE_DNS, "external-usage.cloud-api.near.ai")`
+/// and embedded as a constant so the value never drifts.
+pub const EXTERNAL_USAGE_ID_NAMESPACE: uuid::Uuid =
+    uuid::Uuid::from_u128(0x1966acaf_9f95_5dab_b8f0_03a51c091314);
+
+/// What the completion service does with `response_format: json_schema` for a
+/// model whose catalog lacks the `structured_outputs` feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonSchemaFallbackPolicy {
+    /// Forward the request unchanged and let the provider decide.
+    #[default]
+    Passthrough,
+    /// Reject the request with `invalid_request_error`.
+    Reject,
+    /// Rewrite the format to `json_object`, dropping the schema.
+    Downgrade,
+}
+
+impl JsonSchemaFallbackPolicy {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "passthrough" => Some(Self::Passthrough),
+            "reject" => Some(Self::Reject),
+            "downgrade" => Some(Self::Downgrade),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ServerConfig {
     pub host: String,
@@ -432,8 +840,62 @@ pub struct ServerConfig {
     /// Interval in seconds between scheduled-pricing-change apply passes.
     /// Set to 0 to disable the background scheduler. Default: 60.
     pub pricing_change_apply_interval_secs: u64,
+    /// Interval in seconds between batch processing passes (POST /v1/batches).
+    /// Set to 0 to disable the background worker. Default: 10.
+    pub batch_processing_interval_secs: u64,
     /// Enable the OHTTP gateway (RFC 9458).  Set OHTTP_ENABLED=true to enable.
     pub ohttp_enabled: bool,
+    /// Interval in milliseconds between SSE `: keep-alive` comments sent while a
+    /// streaming completion waits for its first chunk. Enabling it commits the
+    /// response headers before the provider answers, so those streams carry no
+    /// `Inference-Id` / `X-Serving-Provider` headers. Set to 0 to disable. Default: 0.
+    pub stream_keepalive_interval_ms: u64,
+    /// Longest a streamed chat content delta may be held (milliseconds) so
+    /// adjacent tiny deltas from bursty providers can be merged into one
+    /// chunk. Set to 0 to disable. Default: 0.
+    pub stream_coalesce_window_ms: u64,
+    /// Merged content reaching this many bytes is forwarded without waiting
+    /// for the rest of the window. Default: 1024.
+    pub stream_coalesce_max_bytes: usize,
+    /// Window (milliseconds) in which a streaming start identical to one from
+    /// the same API key that is still connecting is rejected as a duplicate.
+    /// Set to 0 to disable. Default: 0.
+    pub stream_dedup_window_ms: u64,
+    /// Chat completions allowed per minute for each end user, identified by
+    /// the client's OpenAI `user` field within an API key. Requests without
+    /// `user` are not limited. Set to 0 to disable. Default: 0.
+    pub end_user_requests_per_minute: u32,
+    /// Maximum request body size in bytes for JSON inference routes
+    /// (chat/completions, completions, embeddings, responses). Larger bodies
+    /// are rejected with 413 before being buffered. Default: 10 MB.
+    pub max_inference_body_bytes: usize,
+    /// Requests taking longer than this (milliseconds, measured to the first
+    /// response byte) are logged at WARN. Set to 0 to disable. Default: 10000.
+    pub slow_request_threshold_ms: u64,
+    /// `limit` applied to listing endpoints when the request omits it. Default: 100.
+    pub default_page_size: i64,
+    /// Largest `limit` honored by listing endpoints; larger requests are
+    /// clamped to it. Default: 1000.
+    pub max_page_size: i64,
+    /// UUID v5 namespace `inference_id`s (the `Inference-Id` header and usage
+    /// rows) are hashed under. Default: [`DEFAULT_INFERENCE_ID_NAMESPACE`].
+    pub inference_id_namespace: uuid::Uuid,
+    /// While migrating off the legacy `NAMESPACE_DNS` hashing, also match
+    /// usage rows recorded under it when resolving a provider id. Default: true.
+    pub inference_id_legacy_lookup: bool,
+    /// Seconds to keep the listener open after SIGTERM/SIGINT while answering
+    /// new requests with 503, so load balancers can deregister the instance
+    /// before it stops accepting connections. In-flight requests keep running.
+    /// Default: 0 (stop accepting right away).
+    pub shutdown_drain_secs: u64,
+    /// Handling of `json_schema` response formats on models without the
+    /// `structured_outputs` feature. Default: passthrough.
+    pub json_schema_fallback: JsonSchemaFallbackPolicy,
+    /// Proxies in front of the server that each append to `X-Forwarded-For`.
+    /// The client address for per-client rate limits is the entry this many
+    /// places from the right; 0 ignores the header and uses the peer
+    /// address. Default: 1 (a single load balancer).
+    pub trusted_proxy_hops: usize,
 }
 
 impl ServerConfig {
@@ -449,9 +911,71 @@ impl ServerConfig {
                 .unwrap_or_else(|_| "60".to_string())
                 .parse()
                 .map_err(|_| "PRICING_CHANGE_APPLY_INTERVAL_SECS must be a non-negative integer")?,
+            batch_processing_interval_secs: env::var("BATCH_PROCESSING_INTERVAL_SECS")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .map_err(|_| "BATCH_PROCESSING_INTERVAL_SECS must be a non-negative integer")?,
             ohttp_enabled: env::var("OHTTP_ENABLED")
                 .map(|v| v == "true" || v == "1")
                 .unwrap_or(false),
+            stream_keepalive_interval_ms: env::var("STREAM_KEEPALIVE_INTERVAL_MS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .map_err(|_| "STREAM_KEEPALIVE_INTERVAL_MS must be a non-negative integer")?,
+            stream_coalesce_window_ms: env::var("STREAM_COALESCE_WINDOW_MS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .map_err(|_| "STREAM_COALESCE_WINDOW_MS must be a non-negative integer")?,
+            stream_coalesce_max_bytes: env::var("STREAM_COALESCE_MAX_BYTES")
+                .unwrap_or_else(|_| DEFAULT_STREAM_COALESCE_MAX_BYTES.to_string())
+                .parse()
+                .map_err(|_| "STREAM_COALESCE_MAX_BYTES must be a non-negative integer")?,
+            stream_dedup_window_ms: env::var("STREAM_DEDUP_WINDOW_MS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .map_err(|_| "STREAM_DEDUP_WINDOW_MS must be a non-negative integer")?,
+            end_user_requests_per_minute: env::var("END_USER_REQUESTS_PER_MINUTE")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .map_err(|_| "END_USER_REQUESTS_PER_MINUTE must be a non-negative integer")?,
+            max_inference_body_bytes: env::var("MAX_INFERENCE_BODY_BYTES")
+                .unwrap_or_else(|_| DEFAULT_MAX_INFERENCE_BODY_BYTES.to_string())
+                .parse()
+                .map_err(|_| "MAX_INFERENCE_BODY_BYTES must be a non-negative integer")?,
+            slow_request_threshold_ms: env::var("SLOW_REQUEST_THRESHOLD_MS")
+                .unwrap_or_else(|_| DEFAULT_SLOW_REQUEST_THRESHOLD_MS.to_string())
+                .parse()
+                .map_err(|_| "SLOW_REQUEST_THRESHOLD_MS must be a non-negative integer")?,
+            default_page_size: env::var("DEFAULT_PAGE_SIZE")
+                .unwrap_or_else(|_| DEFAULT_PAGE_SIZE.to_string())
+                .parse()
+                .map_err(|_| "DEFAULT_PAGE_SIZE must be an integer")?,
+            max_page_size: env::var("MAX_PAGE_SIZE")
+                .unwrap_or_else(|_| DEFAULT_MAX_PAGE_SIZE.to_string())
+                .parse()
+                .map_err(|_| "MAX_PAGE_SIZE must be an integer")?,
+            inference_id_namespace: match env::var("INFERENCE_ID_NAMESPACE") {
+                Ok(raw) => uuid::Uuid::parse_str(raw.trim())
+                    .map_err(|_| "INFERENCE_ID_NAMESPACE must be a UUID")?,
+                Err(_) => DEFAULT_INFERENCE_ID_NAMESPACE,
+            },
+            inference_id_legacy_lookup: env::var("INFERENCE_ID_LEGACY_LOOKUP")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(true),
+            shutdown_drain_secs: env::var("SHUTDOWN_DRAIN_SECS")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .map_err(|_| "SHUTDOWN_DRAIN_SECS must be a non-negative integer")?,
+            json_schema_fallback: match env::var("JSON_SCHEMA_FALLBACK_POLICY") {
+                Ok(raw) => JsonSchemaFallbackPolicy::parse(&raw).ok_or(
+                    "JSON_SCHEMA_FALLBACK_POLICY must be passthrough, reject or downgrade",
+                )?,
+                Err(_) => JsonSchemaFallbackPolicy::default(),
+            },
+            trusted_proxy_hops: env::var("TRUSTED_PROXY_HOPS")
+                .unwrap_or_else(|_| DEFAULT_TRUSTED_PROXY_HOPS.to_string())
+                .parse()
+                .map_err(|_| "TRUSTED_PROXY_HOPS must be a non-negative integer")?,
         })
     }
 }
@@ -532,6 +1056,8 @@ pub struct AuthConfig {
     pub encoding_key: String,
     pub github: Option<GitHubOAuthConfig>,
     pub google: Option<GoogleOAuthConfig>,
+    /// Generic OIDC provider (e.g. an enterprise IdP or GitHub Enterprise).
+    pub oidc: Option<GenericOidcConfig>,
     pub near: NearConfig,
     /// Email domains that are granted platform admin access
     /// Users with emails from these domains will have admin privileges
@@ -581,6 +1107,8 @@ impl AuthConfig {
             None
         };
 
+        let oidc = GenericOidcConfig::from_env()?;
+
         let admin_domains = env::var("AUTH_ADMIN_DOMAINS")
             .ok()
             .map(|domains| {
@@ -603,6 +1131,7 @@ impl AuthConfig {
                 .expect("AUTH_ENCODING_KEY environment variable is required"),
             github,
             google,
+            oidc,
             near,
             admin_domains,
             require_session_bound_access_tokens: parse_bool_env(
@@ -643,6 +1172,55 @@ pub struct GoogleOAuthConfig {
     pub redirect_url: String,
 }
 
+/// Generic OpenID Connect provider using the authorization-code flow (with
+/// PKCE) against explicitly configured endpoints. Users are identified by the
+/// userinfo `sub` claim.
+#[derive(Debug, Clone)]
+pub struct GenericOidcConfig {
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_url: String,
+    /// Expected `iss`; userinfo responses naming a different issuer are rejected.
+    pub issuer: String,
+    pub authorization_url: String,
+    pub token_url: String,
+    pub userinfo_url: String,
+    pub scopes: Vec<String>,
+}
+
+const OIDC_DEFAULT_SCOPES: &str = "openid email profile";
+
+impl GenericOidcConfig {
+    /// Load from `OIDC_*` environment variables. Returns `None` unless the
+    /// client id is set; once it is, every endpoint is required.
+    pub fn from_env() -> Result<Option<Self>, String> {
+        let Ok(client_id) = env::var("OIDC_CLIENT_ID") else {
+            return Ok(None);
+        };
+        let required = |name: &str| {
+            env::var(name)
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .ok_or_else(|| format!("{name} is required when OIDC_CLIENT_ID is set"))
+        };
+        Ok(Some(Self {
+            client_id,
+            client_secret: required("OIDC_CLIENT_SECRET")?,
+            redirect_url: required("OIDC_REDIRECT_URL")?,
+            issuer: required("OIDC_ISSUER")?,
+            authorization_url: required("OIDC_AUTHORIZATION_URL")?,
+            token_url: required("OIDC_TOKEN_URL")?,
+            userinfo_url: required("OIDC_USERINFO_URL")?,
+            scopes: env::var("OIDC_SCOPES")
+                .unwrap_or_else(|_| OIDC_DEFAULT_SCOPES.to_string())
+                .split([' ', ','])
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect(),
+        }))
+    }
+}
+
 /// NEAR wallet authentication configuration
 #[derive(Debug, Clone)]
 pub struct NearConfig {
@@ -881,6 +1459,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn provider_headers_parse_normalizes_and_dedups() {
+        let config =
+            ProviderHeadersConfig::parse(" X-Routing-Hint, x-tenant-tier,,x-routing-hint ")
+                .unwrap();
+        assert_eq!(
+            config.passthrough_allowlist,
+            vec!["x-routing-hint".to_string(), "x-tenant-tier".to_string()]
+        );
+        assert!(config.allows("X-ROUTING-HINT"));
+        assert!(!config.allows("x-other"));
+    }
+
+    #[test]
+    fn provider_headers_parse_rejects_unprefixed_and_reserved_names() {
+        assert!(ProviderHeadersConfig::parse("Authorization").is_err());
+        assert!(ProviderHeadersConfig::parse("routing-hint").is_err());
+        assert!(ProviderHeadersConfig::parse("x-").is_err());
+        assert!(ProviderHeadersConfig::parse("x-bad header").is_err());
+        assert!(ProviderHeadersConfig::parse("x-request-id").is_err());
+        assert!(ProviderHeadersConfig::parse("X-Client-Pub-Key").is_err());
+        assert_eq!(
+            ProviderHeadersConfig::parse("").unwrap(),
+            ProviderHeadersConfig::default()
+        );
+    }
+
+    #[test]
+    fn currency_rates_parse_to_micros() {
+        let config = CurrencyConfig::parse(" eur=1.08, GBP=1.271234 ,,USDT=1").unwrap();
+        assert_eq!(config.usd_rates_micros.len(), 3);
+        assert_eq!(config.usd_rates_micros["EUR"], 1_080_000);
+        assert_eq!(config.usd_rates_micros["GBP"], 1_271_234);
+        assert_eq!(config.usd_rates_micros["USDT"], 1_000_000);
+    }
+
+    #[test]
+    fn currency_rates_parse_rejects_bad_entries() {
+        assert!(CurrencyConfig::parse("EUR").is_err());
+        assert!(CurrencyConfig::parse("EUR=").is_err());
+        assert!(CurrencyConfig::parse("EUR=0").is_err());
+        assert!(CurrencyConfig::parse("EUR=-1").is_err());
+        assert!(CurrencyConfig::parse("EUR=1.0000001").is_err());
+        assert!(CurrencyConfig::parse("EU=1").is_err());
+        assert!(CurrencyConfig::parse("USD=1").is_err());
+        assert_eq!(
+            CurrencyConfig::parse("").unwrap(),
+            CurrencyConfig::default()
+        );
+    }
+
+    #[test]
+    fn currency_rates_parse_keeps_usdt_default() {
+        let config = CurrencyConfig::parse("EUR=1.08").unwrap();
+        assert_eq!(config.usd_rates_micros.len(), 2);
+        assert_eq!(config.usd_rates_micros["USDT"], 1_000_000);
+
+        let config = CurrencyConfig::parse("USDT=0.999").unwrap();
+        assert_eq!(config.usd_rates_micros["USDT"], 999_000);
+    }
+
     #[test]
     fn test_is_admin_email() {
         let config = AuthConfig {
@@ -888,6 +1527,7 @@ mod tests {
             encoding_key: "mock_encoding_key".to_string(),
             github: None,
             google: None,
+            oidc: None,
             near: NearConfig::default(),
             admin_domains: vec!["near.ai".to_string(), "near.org".to_string()],
             require_session_bound_access_tokens: false,
@@ -912,6 +1552,7 @@ mod tests {
             encoding_key: "mock_encoding_key".to_string(),
             github: None,
             google: None,
+            oidc: None,
             near: NearConfig::default(),
             admin_domains: vec![],
             require_session_bound_access_tokens: false,
@@ -1419,6 +2060,27 @@ pub struct ExternalProvidersConfig {
     /// Intel PCCS URL for DCAP collateral (shared with the NEAR attestation
     /// verifier), from `PCCS_URL`. One source of truth instead of ad-hoc env reads.
     pub pccs_url: Option<String>,
+    /// Extra attempts against the SAME provider on a transient failure
+    /// (502/503/504, dropped connection) before falling back to the next
+    /// provider, from `PROVIDER_SAME_PROVIDER_RETRIES` (default 2; 0 disables).
+    pub same_provider_retries: u32,
+    /// Max distinct providers a single request is attempted on before giving
+    /// up, even if more remain, from `PROVIDER_MAX_FALLBACK_ATTEMPTS` (default
+    /// 0 = unlimited). A request's `x-max-fallback-attempts` header can only
+    /// lower it.
+    pub max_fallback_attempts: usize,
+    /// Max providers whose attestation reports are fetched at once during
+    /// discovery, from `PROVIDER_DISCOVERY_CONCURRENCY` (default 20; 0 also
+    /// means the default).
+    pub provider_discovery_concurrency: usize,
+    /// How long a provider's attestation report is reused by discovery and
+    /// nonce-less attestation lookups, from `PROVIDER_ATTESTATION_CACHE_TTL_SECS`
+    /// (default 300; 0 disables the cache).
+    pub provider_attestation_cache_ttl_secs: u64,
+    /// How long a completion's chat_id stays pinned to the provider that
+    /// served it (for signature fetches), from `PROVIDER_CHAT_PIN_TTL_SECS`
+    /// (default 3600; 0 keeps pins until shutdown).
+    pub chat_pin_ttl_secs: u64,
 }
 
 impl ExternalProvidersConfig {
@@ -1548,6 +2210,26 @@ impl ExternalProvidersConfig {
             .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
             .unwrap_or(false);
         let pccs_url = env::var("PCCS_URL").ok().filter(|s| !s.is_empty());
+        let same_provider_retries = env::var("PROVIDER_SAME_PROVIDER_RETRIES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(2);
+        let max_fallback_attempts = env::var("PROVIDER_MAX_FALLBACK_ATTEMPTS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0);
+        let provider_discovery_concurrency = env::var("PROVIDER_DISCOVERY_CONCURRENCY")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(20);
+        let provider_attestation_cache_ttl_secs = env::var("PROVIDER_ATTESTATION_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(300);
+        let chat_pin_ttl_secs = env::var("PROVIDER_CHAT_PIN_TTL_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(3600);
 
         Self {
             openai_api_key,
@@ -1560,6 +2242,11 @@ impl ExternalProvidersConfig {
             chutes_models,
             chutes_enable_streaming,
             pccs_url,
+            same_provider_retries,
+            max_fallback_attempts,
+            provider_discovery_concurrency,
+            provider_attestation_cache_ttl_secs,
+            chat_pin_ttl_secs,
         }
     }
 
@@ -1,5 +1,5 @@
 use crate::patroni_discovery::{ClusterMember, PatroniDiscovery};
-use crate::pool::{create_pool_with_native_tls, DbPool};
+use crate::pool::{create_pool_with_native_tls, DbPool, NativeTlsOptions, PoolStatus};
 use anyhow::{anyhow, Result};
 use deadpool::managed::QueueMode;
 use deadpool_postgres::{Config, Object as PooledConnection, Pool, Runtime};
@@ -72,6 +72,8 @@ pub struct DatabaseConfig {
     pub max_read_connections: u32,
     pub tls_enabled: bool,
     pub tls_ca_cert_path: Option<String>,
+    pub tls_min_version: Option<config::DatabaseTlsVersion>,
+    pub tls_pinned_spki_sha256: Vec<String>,
 }
 
 impl ClusterManager {
@@ -260,7 +262,14 @@ impl ClusterManager {
 
         if self.database_config.tls_enabled {
             // Use native TLS and accept self-signed certificates for Patroni
-            create_pool_with_native_tls(cfg, true)
+            create_pool_with_native_tls(
+                cfg,
+                &NativeTlsOptions {
+                    accept_invalid_certs: true,
+                    min_version: self.database_config.tls_min_version,
+                    pinned_spki_sha256: self.database_config.tls_pinned_spki_sha256.clone(),
+                },
+            )
         } else {
             use tokio_postgres::NoTls;
             cfg.create_pool(Some(Runtime::Tokio1), NoTls)
@@ -438,6 +447,25 @@ impl ClusterManager {
         }
     }
 
+    /// Connection counts for the write pool and, summed across replicas, the
+    /// read pools. Pools that are not set up yet are omitted.
+    pub async fn pool_status(&self) -> Vec<PoolStatus> {
+        let mut statuses: Vec<PoolStatus> = self
+            .write_pool
+            .status()
+            .map(|status| PoolStatus::aggregate("write", [status]))
+            .into_iter()
+            .collect();
+        let read_pools = self.read_pools.read().await;
+        if !read_pools.is_empty() {
+            statuses.push(PoolStatus::aggregate(
+                "read",
+                read_pools.values().map(|pool| pool.status()),
+            ));
+        }
+        statuses
+    }
+
     /// Get the shared write-pool handle. Clones of this handle stay pointed at
     /// the current leader across failovers.
     pub fn write_pool(&self) -> DbPool {
@@ -497,6 +525,8 @@ mod tests {
             max_read_connections: 2,
             tls_enabled: false,
             tls_ca_cert_path: None,
+            tls_min_version: None,
+            tls_pinned_spki_sha256: Vec::new(),
         }
     }
 
@@ -5,19 +5,20 @@ pub mod mock;
 pub mod models;
 pub mod patroni_discovery;
 pub mod pool;
+pub mod pool_metrics;
 pub mod repositories;
 pub mod shutdown_coordinator;
 mod usage_reporting_indexes;
 
 pub use constants::*;
 pub use models::*;
-pub use pool::DbPool;
+pub use pool::{DbPool, PoolStatus};
 pub use repositories::{
     ApiKeyRepository, McpConnectorRepository, OAuthStateRepository,
-    OrganizationReportingTokenRepository, PgAttestationRepository, PgConversationRepository,
-    PgOrganizationInvitationRepository, PgOrganizationRepository, PgResponseItemsRepository,
-    PgResponseRepository, PostgresNearNonceRepository, PostgresReportingUsageSummaryRepository,
-    SessionRepository, UserRepository,
+    OrganizationReportingTokenRepository, PgAttestationRepository, PgBatchRepository,
+    PgConversationRepository, PgOrganizationInvitationRepository, PgOrganizationRepository,
+    PgResponseItemsRepository, PgResponseRepository, PostgresNearNonceRepository,
+    PostgresReportingUsageSummaryRepository, SessionRepository, UserRepository,
 };
 pub use shutdown_coordinator::{ShutdownCoordinator, ShutdownStage, ShutdownStageResult};
 pub use usage_reporting_indexes::ensure_usage_reporting_indexes;
@@ -30,7 +31,7 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tracing::{debug, info};
 // Re-export mock function
-use crate::pool::create_pool_with_native_tls;
+use crate::pool::{create_pool_with_native_tls, NativeTlsOptions};
 pub use mock::create_mock_database;
 
 /// Database service combining all repositories
@@ -121,6 +122,8 @@ impl Database {
             max_read_connections: config.max_connections as u32,
             tls_enabled: config.tls_enabled,
             tls_ca_cert_path: config.tls_ca_cert_path.clone(),
+            tls_min_version: config.tls_min_version,
+            tls_pinned_spki_sha256: config.tls_pinned_spki_sha256.clone(),
         };
 
         let cluster_manager = Arc::new(ClusterManager::new(
@@ -160,6 +163,20 @@ impl Database {
         &self.pool
     }
 
+    /// Connection counts for each pool role. Without a cluster manager only
+    /// the single write pool is reported.
+    pub async fn pool_status(&self) -> Vec<PoolStatus> {
+        match &self.cluster_manager {
+            Some(cluster_manager) => cluster_manager.pool_status().await,
+            None => self
+                .pool
+                .status()
+                .map(|status| PoolStatus::aggregate("write", [status]))
+                .into_iter()
+                .collect(),
+        }
+    }
+
     /// Get a reference to the cluster manager (if using Patroni)
     pub fn cluster_manager(&self) -> Option<&Arc<ClusterManager>> {
         self.cluster_manager.as_ref()
@@ -232,7 +249,14 @@ impl Database {
         pg_config.password = Some(config.password.clone());
 
         let pool = if config.tls_enabled {
-            create_pool_with_native_tls(pg_config, true)?
+            create_pool_with_native_tls(
+                pg_config,
+                &NativeTlsOptions {
+                    accept_invalid_certs: true,
+                    min_version: config.tls_min_version,
+                    pinned_spki_sha256: config.tls_pinned_spki_sha256.clone(),
+                },
+            )?
         } else {
             pg_config.create_pool(Some(Runtime::Tokio1), NoTls)?
         };
@@ -0,0 +1,4 @@
+-- Per-workspace completion defaults (model, temperature, max_tokens) merged
+-- into chat completion requests where the client left the field unset.
+-- Client-supplied values always win. NULL means no defaults.
+ALTER TABLE workspaces ADD COLUMN default_params JSONB;
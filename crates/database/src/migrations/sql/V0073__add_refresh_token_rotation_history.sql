@@ -0,0 +1,15 @@
+-- Refresh-token reuse detection. Each refresh_tokens row is a token family:
+-- rotation swaps the hash in place and bumps `generation`, and the retired
+-- hash is recorded here. Presenting a retired hash again means the token
+-- leaked, so the whole family (the refresh_tokens row) is revoked, which
+-- cascades to its history.
+ALTER TABLE refresh_tokens ADD COLUMN generation INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE refresh_token_rotations (
+    token_hash VARCHAR(64) PRIMARY KEY,
+    family_id UUID NOT NULL REFERENCES refresh_tokens(id) ON DELETE CASCADE,
+    generation INTEGER NOT NULL,
+    rotated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_refresh_token_rotations_family ON refresh_token_rotations(family_id);
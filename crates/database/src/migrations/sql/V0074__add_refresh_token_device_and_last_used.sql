@@ -0,0 +1,8 @@
+-- Device listing for active sessions: a human-readable device label derived
+-- from the User-Agent at login, and the last time the session authenticated.
+-- Existing sessions have no label and report their creation time as last use.
+ALTER TABLE refresh_tokens ADD COLUMN device_label VARCHAR(128);
+ALTER TABLE refresh_tokens ADD COLUMN last_used_at TIMESTAMPTZ;
+UPDATE refresh_tokens SET last_used_at = created_at;
+ALTER TABLE refresh_tokens ALTER COLUMN last_used_at SET NOT NULL;
+ALTER TABLE refresh_tokens ALTER COLUMN last_used_at SET DEFAULT NOW();
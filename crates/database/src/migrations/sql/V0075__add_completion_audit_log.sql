@@ -0,0 +1,23 @@
+-- Compliance audit trail for chat completions (AUDIT_LOG_ENABLED).
+-- Hashes are hex SHA-256. Bodies are only written with
+-- AUDIT_LOG_STORE_BODIES and are PII-redacted before insert.
+-- No foreign keys: audit rows must outlive the keys and workspaces they
+-- reference.
+CREATE TABLE completion_audit_log (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    request_id UUID NOT NULL,
+    organization_id UUID NOT NULL,
+    workspace_id UUID NOT NULL,
+    api_key_id UUID NOT NULL,
+    model TEXT NOT NULL,
+    request_hash VARCHAR(64) NOT NULL,
+    response_hash VARCHAR(64) NOT NULL,
+    request_body TEXT,
+    response_body TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_completion_audit_log_org_created
+    ON completion_audit_log(organization_id, created_at DESC);
+CREATE INDEX idx_completion_audit_log_api_key_created
+    ON completion_audit_log(api_key_id, created_at DESC);
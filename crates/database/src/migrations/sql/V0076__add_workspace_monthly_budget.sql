@@ -0,0 +1,6 @@
+-- Optional per-workspace monthly budget in nano-dollars (scale 9). Requests
+-- made with the workspace's API keys are rejected with 402 once the
+-- workspace's spend for the current calendar month (UTC) reaches it.
+-- Enforced in addition to the organization and per-key limits. NULL means
+-- no workspace budget.
+ALTER TABLE workspaces ADD COLUMN monthly_budget BIGINT;
@@ -0,0 +1,13 @@
+-- MCP connectors registered through the API belong to a workspace (the
+-- workspace of the API key that registered them). Legacy rows have no
+-- workspace and are not visible through the workspace-scoped endpoints.
+ALTER TABLE mcp_connectors
+    ADD COLUMN workspace_id UUID REFERENCES workspaces(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_mcp_connectors_workspace ON mcp_connectors(workspace_id);
+
+-- Connector names are unique per workspace rather than per organization.
+ALTER TABLE mcp_connectors DROP CONSTRAINT IF EXISTS mcp_connectors_organization_id_name_key;
+CREATE UNIQUE INDEX idx_mcp_connectors_workspace_name
+    ON mcp_connectors(workspace_id, name)
+    WHERE workspace_id IS NOT NULL;
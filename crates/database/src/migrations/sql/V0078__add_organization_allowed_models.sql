@@ -0,0 +1,4 @@
+-- Optional per-organization allowlist of canonical model names. When set,
+-- chat completions for any other model are rejected with model_not_allowed
+-- before dispatch. NULL (or an empty list) allows every model.
+ALTER TABLE organizations ADD COLUMN allowed_models TEXT[];
@@ -0,0 +1,8 @@
+-- Per-model output-token caps applied by the completion service.
+-- default_max_tokens is used when the client omits max_tokens /
+-- max_completion_tokens; max_max_tokens caps what a client may request.
+-- Requests above the cap are clamped unless reject_over_max_tokens is set,
+-- in which case they fail with 400. NULL leaves the respective limit off.
+ALTER TABLE models ADD COLUMN default_max_tokens INTEGER CHECK (default_max_tokens > 0);
+ALTER TABLE models ADD COLUMN max_max_tokens INTEGER CHECK (max_max_tokens > 0);
+ALTER TABLE models ADD COLUMN reject_over_max_tokens BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,15 @@
+-- Server-issued NEAR authentication nonces (challenge/response login).
+-- A nonce is redeemable only while it is listed here and unexpired; replay
+-- protection still goes through near_used_nonces.
+CREATE TABLE near_issued_nonces (
+    nonce_hex VARCHAR(64) PRIMARY KEY
+        CHECK (char_length(nonce_hex) = 64 AND nonce_hex ~ '^[0-9a-f]{64}$'),
+    issued_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+-- Index for cleanup of expired nonces
+CREATE INDEX idx_near_issued_nonces_expires_at ON near_issued_nonces(expires_at);
+
+COMMENT ON TABLE near_issued_nonces IS 'Nonces issued by GET /v1/auth/near/nonce, redeemable once before expires_at';
+COMMENT ON COLUMN near_issued_nonces.nonce_hex IS '64-character hex encoding of the 32-byte NEP-413 nonce';
@@ -0,0 +1,52 @@
+-- Asynchronous batch jobs (POST /v1/batches). A batch owns N request lines
+-- that a background worker runs through the chat completion service; the
+-- batch row carries status and counters, each line its own result.
+CREATE TABLE batches (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    api_key_id UUID NOT NULL REFERENCES api_keys(id) ON DELETE CASCADE,
+    created_by_user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    endpoint VARCHAR(100) NOT NULL,
+    completion_window VARCHAR(20) NOT NULL,
+    -- Set when the lines were read from an uploaded file (purpose 'batch').
+    input_file_id UUID REFERENCES files(id) ON DELETE SET NULL,
+    -- JSONL of per-line results, written when the batch completes.
+    output_file_id UUID REFERENCES files(id) ON DELETE SET NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'validating'
+        CHECK (status IN ('validating', 'in_progress', 'completed')),
+    metadata JSONB,
+    total_requests INT NOT NULL,
+    completed_requests INT NOT NULL DEFAULT 0,
+    failed_requests INT NOT NULL DEFAULT 0,
+    -- Worker lease: refreshed after every processed line; a NULL or stale
+    -- value on an in_progress batch lets another instance pick it up.
+    claimed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    in_progress_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_batches_workspace_id ON batches(workspace_id);
+
+CREATE INDEX idx_batches_open
+    ON batches(updated_at)
+    WHERE status IN ('validating', 'in_progress');
+
+CREATE TABLE batch_requests (
+    batch_id UUID NOT NULL REFERENCES batches(id) ON DELETE CASCADE,
+    line_index INT NOT NULL,
+    custom_id VARCHAR(256) NOT NULL,
+    body JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'completed', 'failed')),
+    response_status_code INT,
+    response_body JSONB,
+    completed_at TIMESTAMPTZ,
+    PRIMARY KEY (batch_id, line_index),
+    UNIQUE (batch_id, custom_id)
+);
+
+COMMENT ON TABLE batches IS 'Asynchronous chat completion batches created via POST /v1/batches';
+COMMENT ON TABLE batch_requests IS 'Request lines of a batch with their per-line result';
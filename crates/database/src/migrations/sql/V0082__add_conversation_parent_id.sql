@@ -0,0 +1,6 @@
+-- Track the conversation a fork was branched from (POST /v1/conversations/{id}/fork)
+ALTER TABLE conversations ADD COLUMN parent_conversation_id UUID REFERENCES conversations(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_conversations_parent ON conversations(parent_conversation_id) WHERE parent_conversation_id IS NOT NULL;
+
+COMMENT ON COLUMN conversations.parent_conversation_id IS 'ID of the conversation this was forked from. NULL means not a fork.';
@@ -0,0 +1,5 @@
+-- Flag usage rows whose token counts were estimated by the gateway because the
+-- provider's stream ended without a usage chunk
+ALTER TABLE organization_usage_log ADD COLUMN estimated BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN organization_usage_log.estimated IS 'TRUE when input/output tokens were estimated from the streamed deltas instead of reported by the provider.';
@@ -0,0 +1,15 @@
+-- Organization-scoped model aliases. Resolved before global aliases for
+-- requests that carry the organization's context, and invisible to every
+-- other organization.
+CREATE TABLE organization_model_aliases (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    alias_name VARCHAR(500) NOT NULL,
+    canonical_model_id UUID NOT NULL REFERENCES models(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (organization_id, alias_name)
+);
+
+CREATE INDEX idx_organization_model_aliases_canonical
+    ON organization_model_aliases(canonical_model_id);
@@ -0,0 +1,5 @@
+-- Per-model inference request timeout, in seconds. Slow models (long
+-- reasoning, very large contexts) can need more than the global completion
+-- timeout, and small fast models can fail over sooner with less. NULL falls
+-- back to the global completion timeout (VLLM_PROVIDER_COMPLETION_TIMEOUT).
+ALTER TABLE models ADD COLUMN request_timeout_secs INTEGER CHECK (request_timeout_secs > 0);
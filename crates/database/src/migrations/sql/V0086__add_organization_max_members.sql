@@ -0,0 +1,4 @@
+-- Seat limit: the most members (owner included) an organization may have.
+-- Enforced when members are added or invitations are accepted. NULL means
+-- unlimited.
+ALTER TABLE organizations ADD COLUMN max_members INTEGER CHECK (max_members > 0);
@@ -0,0 +1,29 @@
+-- Per-organization token quotas for individual models. A request for a model
+-- is rejected once the organization's usage of that model in the current
+-- UTC period (day or month) reaches token_limit; other models are unaffected.
+CREATE TABLE model_quotas (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    model_id UUID NOT NULL REFERENCES models(id) ON DELETE CASCADE,
+    period VARCHAR(16) NOT NULL CHECK (period IN ('day', 'month')),
+    token_limit BIGINT NOT NULL CHECK (token_limit > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (organization_id, model_id, period)
+);
+
+CREATE INDEX idx_model_quotas_model ON model_quotas(model_id);
+
+-- Quota checks sum an organization's usage of one model since the period start.
+--
+-- NOTE: organization_usage_log has 50M+ rows in production, and a plain CREATE
+-- INDEX blocks usage writes for the whole build. CONCURRENTLY cannot run inside
+-- a transaction (which Refinery uses), so build the index manually first:
+--
+--   CREATE INDEX CONCURRENTLY IF NOT EXISTS idx_org_usage_org_model_created
+--       ON organization_usage_log (organization_id, model_id, created_at);
+--
+-- then deploy this migration; the statement below is a no-op thanks to
+-- IF NOT EXISTS.
+CREATE INDEX IF NOT EXISTS idx_org_usage_org_model_created
+    ON organization_usage_log (organization_id, model_id, created_at);
@@ -141,6 +141,9 @@ pub struct Workspace {
     pub updated_at: DateTime<Utc>,
     pub is_active: bool,
     pub settings: Option<serde_json::Value>,
+    pub default_params: Option<serde_json::Value>,
+    /// Monthly spend budget in nano-dollars; `None` means unlimited
+    pub monthly_budget: Option<i64>,
 }
 
 /// API Key for authentication - now workspace-owned
@@ -173,6 +176,11 @@ pub struct Session {
     pub expires_at: DateTime<Utc>,
     pub ip_address: Option<String>,
     pub user_agent: String,
+    /// Number of times the refresh token has been rotated within this session
+    pub generation: i32,
+    /// Human-readable device derived from the User-Agent at login
+    pub device_label: Option<String>,
+    pub last_used_at: DateTime<Utc>,
 }
 
 /// Admin access token for tracking and managing admin access tokens
@@ -240,6 +248,7 @@ pub struct UpdateWorkspaceRequest {
     pub name: Option<String>,
     pub description: Option<String>,
     pub settings: Option<serde_json::Value>,
+    pub default_params: Option<serde_json::Value>,
 }
 
 impl OrganizationRole {
@@ -296,6 +305,7 @@ pub enum McpConnectionStatus {
 pub struct McpConnector {
     pub id: Uuid,
     pub organization_id: Uuid,
+    pub workspace_id: Option<Uuid>,
     pub name: String,
     pub description: Option<String>,
     pub mcp_server_url: String,
@@ -321,6 +331,7 @@ pub struct McpBearerConfig {
 /// Create MCP Connector Request
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateMcpConnectorRequest {
+    pub workspace_id: Option<Uuid>,
     pub name: String,
     pub description: Option<String>,
     pub mcp_server_url: String,
@@ -408,6 +419,7 @@ pub struct Conversation {
     pub archived_at: Option<DateTime<Utc>>, // Timestamp when archived, NULL if not archived
     pub deleted_at: Option<DateTime<Utc>>, // Timestamp when soft-deleted, NULL if not deleted
     pub cloned_from_id: Option<Uuid>,     // ID of conversation this was cloned from
+    pub parent_conversation_id: Option<Uuid>, // ID of conversation this was forked from
     pub metadata: serde_json::Value, // JSONB storing conversation metadata (includes title/name)
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -490,6 +502,14 @@ pub struct Model {
     /// If true, this model may be activated even when both cost fields are 0.
     /// Intended for intentionally-free models (e.g. community previews).
     pub allow_free: bool,
+    /// `max_tokens` applied when the client sends none. NULL = no default.
+    pub default_max_tokens: Option<i32>,
+    /// Upper bound on client-requested `max_tokens`. NULL = uncapped.
+    pub max_max_tokens: Option<i32>,
+    /// If true, requests above `max_max_tokens` are rejected instead of clamped.
+    pub reject_over_max_tokens: bool,
+    /// Inference request timeout in seconds. NULL = global completion timeout.
+    pub request_timeout_secs: Option<i32>,
     pub owned_by: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -514,6 +534,12 @@ pub struct UpdateModelPricingRequest {
     pub is_active: Option<bool>,
     /// If true, allows activation even with zero pricing.
     pub allow_free: Option<bool>,
+    pub default_max_tokens: Option<i32>,
+    pub max_max_tokens: Option<i32>,
+    pub reject_over_max_tokens: Option<bool>,
+    /// Tri-state: `None` = leave unchanged, `Some(None)` = clear back to the
+    /// global completion timeout, `Some(Some(v))` = set to `v`.
+    pub request_timeout_secs: Option<Option<i32>>,
     pub aliases: Option<Vec<String>>,
     pub owned_by: Option<String>,
     // Provider configuration
@@ -763,6 +789,8 @@ pub struct RecordUsageRequest {
     pub served_provider_tier: Option<ServedProviderTier>,
     pub served_provider_type: Option<ServedProviderType>,
     pub served_via_fallback: bool,
+    /// Token counts were estimated because the provider never reported usage
+    pub estimated: bool,
 }
 
 // ============================================
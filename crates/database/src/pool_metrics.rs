@@ -0,0 +1,144 @@
+use crate::{Database, PoolStatus};
+use services::metrics::{consts, MetricsServiceTrait};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+
+/// How often pool gauges are sampled. Short enough to catch bursts of
+/// connection pressure, long enough to be negligible overhead.
+pub const POOL_METRICS_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Background task that periodically exports connection-pool gauges
+/// (`in_use`, `available`, `waiters`) tagged by pool role.
+pub struct PoolMetricsReporter {
+    database: Arc<Database>,
+    metrics_service: Arc<dyn MetricsServiceTrait>,
+    task_handle: tokio::sync::Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl PoolMetricsReporter {
+    pub fn new(database: Arc<Database>, metrics_service: Arc<dyn MetricsServiceTrait>) -> Self {
+        Self {
+            database,
+            metrics_service,
+            task_handle: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Start the periodic export task; the first sample is taken immediately.
+    pub async fn start(self: Arc<Self>, interval: Duration) {
+        let handle = tokio::spawn({
+            let reporter = self.clone();
+            async move {
+                let mut interval = tokio::time::interval(interval);
+                loop {
+                    interval.tick().await;
+                    reporter.record_once().await;
+                }
+            }
+        });
+
+        let mut task_handle = self.task_handle.lock().await;
+        if let Some(previous) = task_handle.replace(handle) {
+            previous.abort();
+        }
+        info!("Database pool metrics reporter started with interval: {interval:?}");
+    }
+
+    /// Sample every pool once and record its gauges.
+    pub async fn record_once(&self) {
+        for status in self.database.pool_status().await {
+            record_pool_status(self.metrics_service.as_ref(), &status);
+        }
+    }
+
+    /// Cancel the background task.
+    pub async fn shutdown(&self) {
+        if let Some(handle) = self.task_handle.lock().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+fn record_pool_status(metrics_service: &dyn MetricsServiceTrait, status: &PoolStatus) {
+    let environment = consts::get_environment();
+    let pool_tag = format!("{}:{}", consts::TAG_POOL, status.pool);
+    let environment_tag = format!("{}:{}", consts::TAG_ENVIRONMENT, environment);
+    let tags = [pool_tag.as_str(), environment_tag.as_str()];
+
+    metrics_service.record_gauge(
+        consts::METRIC_DB_POOL_CONNECTIONS_IN_USE,
+        status.in_use as f64,
+        &tags,
+    );
+    metrics_service.record_gauge(
+        consts::METRIC_DB_POOL_CONNECTIONS_AVAILABLE,
+        status.available as f64,
+        &tags,
+    );
+    metrics_service.record_gauge(consts::METRIC_DB_POOL_WAITERS, status.waiting as f64, &tags);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DbPool;
+    use services::metrics::capturing::{CapturingMetricsService, MetricValue};
+
+    fn lazy_pool() -> deadpool_postgres::Pool {
+        // No connection is acquired, so the host never has to resolve.
+        let mut cfg = deadpool_postgres::Config::new();
+        cfg.host = Some("pool-metrics-test-host.invalid".to_string());
+        cfg.dbname = Some("test".to_string());
+        cfg.user = Some("test".to_string());
+        cfg.create_pool(
+            Some(deadpool_postgres::Runtime::Tokio1),
+            tokio_postgres::NoTls,
+        )
+        .expect("lazy pool must build without connecting")
+    }
+
+    #[tokio::test]
+    async fn record_once_exports_write_pool_gauges() {
+        let database = Arc::new(Database::new(DbPool::new(lazy_pool())));
+        let metrics_service = Arc::new(CapturingMetricsService::new());
+        let reporter = PoolMetricsReporter::new(database, metrics_service.clone());
+
+        reporter.record_once().await;
+
+        let metrics = metrics_service.get_metrics();
+        for name in [
+            consts::METRIC_DB_POOL_CONNECTIONS_IN_USE,
+            consts::METRIC_DB_POOL_CONNECTIONS_AVAILABLE,
+            consts::METRIC_DB_POOL_WAITERS,
+        ] {
+            let metric = metrics
+                .iter()
+                .find(|m| m.name == name)
+                .unwrap_or_else(|| panic!("{name} gauge missing"));
+            assert!(matches!(metric.value, MetricValue::Gauge(v) if v == 0.0));
+            assert!(metric.tags.contains(&"pool:write".to_string()));
+        }
+    }
+
+    #[test]
+    fn aggregate_sums_read_pools() {
+        let replica = |size, available, waiting| deadpool::Status {
+            max_size: 10,
+            size,
+            available,
+            waiting,
+        };
+        let status = PoolStatus::aggregate("read", [replica(4, 1, 0), replica(10, 0, 3)]);
+        assert_eq!(
+            status,
+            PoolStatus {
+                pool: "read",
+                max_size: 20,
+                in_use: 13,
+                available: 1,
+                waiting: 3,
+            }
+        );
+    }
+}
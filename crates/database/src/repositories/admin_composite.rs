@@ -10,11 +10,11 @@ use services::admin::{
     AdminModelInfo, AdminOrganizationInfo, AdminOrganizationMemberInfo, AdminRepository,
     DeprecateModelOutcome, ModelDeprecationDeliveryRecord, ModelDeprecationEmailStatus,
     ModelDeprecationModel, ModelDeprecationRecipient, ModelHistoryEntry, ModelPricing,
-    ModelPricingSnapshot, OrganizationLimits, OrganizationLimitsHistoryEntry,
-    OrganizationLimitsUpdate, PlatformServiceInfo, PricingChangeDeliveryRecord,
-    PricingChangeOpenConflictError, PricingChangeRecipientRow, ScheduledPricingChange,
-    ScheduledPricingChangeInsert, ScheduledPricingChangeStatus, UpdateModelAdminRequest, UserInfo,
-    UserOrganizationInfo,
+    ModelPricingSnapshot, ModelUpsertBaseline, OrganizationLimits, OrganizationLimitsHistoryEntry,
+    OrganizationLimitsUpdate, OrganizationSeatLimit, PlatformServiceInfo,
+    PricingChangeDeliveryRecord, PricingChangeOpenConflictError, PricingChangeRecipientRow,
+    ScheduledPricingChange, ScheduledPricingChangeInsert, ScheduledPricingChangeStatus,
+    UpdateModelAdminRequest, UserInfo, UserOrganizationInfo,
 };
 use services::service_usage::ports::ServiceUnit;
 use std::sync::Arc;
@@ -93,6 +93,40 @@ fn row_to_admin_org_info(row: &tokio_postgres::Row) -> AdminOrganizationInfo {
     }
 }
 
+/// Map a stored model row to the service-layer `ModelPricing` view.
+fn model_to_pricing(model: crate::models::Model) -> ModelPricing {
+    ModelPricing {
+        model_display_name: model.model_display_name,
+        model_description: model.model_description,
+        model_icon: model.model_icon,
+        input_cost_per_token: model.input_cost_per_token,
+        output_cost_per_token: model.output_cost_per_token,
+        cost_per_image: model.cost_per_image,
+        cache_read_cost_per_token: model.cache_read_cost_per_token,
+        context_length: model.context_length,
+        verifiable: model.verifiable,
+        is_active: model.is_active,
+        aliases: model.aliases,
+        owned_by: model.owned_by,
+        provider_type: model.provider_type,
+        provider_config: model.provider_config,
+        attestation_supported: model.attestation_supported,
+        input_modalities: model.input_modalities,
+        output_modalities: model.output_modalities,
+        inference_url: model.inference_url,
+        request_timeout_secs: model.request_timeout_secs,
+        hugging_face_id: model.hugging_face_id,
+        quantization: model.quantization,
+        max_output_length: model.max_output_length,
+        supported_sampling_parameters: model.supported_sampling_parameters,
+        supported_features: model.supported_features,
+        datacenters: model.datacenters,
+        is_ready: model.is_ready,
+        deprecation_date: model.deprecation_date,
+        openrouter_slug: model.openrouter_slug,
+    }
+}
+
 fn service_to_info(s: &crate::models::Service) -> Result<PlatformServiceInfo, anyhow::Error> {
     let unit = ServiceUnit::try_from(s.unit.as_str()).map_err(|e| anyhow::anyhow!("{}", e))?;
     Ok(PlatformServiceInfo {
@@ -128,6 +162,10 @@ impl AdminRepository for AdminCompositeRepository {
             verifiable: request.verifiable,
             is_active: request.is_active,
             allow_free: request.allow_free,
+            default_max_tokens: request.default_max_tokens,
+            max_max_tokens: request.max_max_tokens,
+            reject_over_max_tokens: request.reject_over_max_tokens,
+            request_timeout_secs: request.request_timeout_secs,
             aliases: request.aliases.clone(),
             owned_by: request.owned_by,
             provider_type: request.provider_type,
@@ -162,35 +200,24 @@ impl AdminRepository for AdminCompositeRepository {
                 .await?;
         }
 
-        Ok(ModelPricing {
-            model_display_name: model.model_display_name,
-            model_description: model.model_description,
-            model_icon: model.model_icon,
-            input_cost_per_token: model.input_cost_per_token,
-            output_cost_per_token: model.output_cost_per_token,
-            cost_per_image: model.cost_per_image,
-            cache_read_cost_per_token: model.cache_read_cost_per_token,
-            context_length: model.context_length,
-            verifiable: model.verifiable,
-            is_active: model.is_active,
-            aliases: model.aliases,
-            owned_by: model.owned_by,
-            provider_type: model.provider_type,
-            provider_config: model.provider_config,
-            attestation_supported: model.attestation_supported,
-            input_modalities: model.input_modalities,
-            output_modalities: model.output_modalities,
-            inference_url: model.inference_url,
-            hugging_face_id: model.hugging_face_id,
-            quantization: model.quantization,
-            max_output_length: model.max_output_length,
-            supported_sampling_parameters: model.supported_sampling_parameters,
-            supported_features: model.supported_features,
-            datacenters: model.datacenters,
-            is_ready: model.is_ready,
-            deprecation_date: model.deprecation_date,
-            openrouter_slug: model.openrouter_slug,
-        })
+        Ok(model_to_pricing(model))
+    }
+
+    async fn get_model_upsert_baseline(
+        &self,
+        model_name: &str,
+    ) -> Result<Option<ModelUpsertBaseline>> {
+        let Some(mut model) = self.model_repo.get_by_internal_name(model_name).await? else {
+            return Ok(None);
+        };
+        model.aliases = self.alias_repo.get_active_alias_names(&model.id).await?;
+        Ok(Some(ModelUpsertBaseline {
+            allow_free: model.allow_free,
+            default_max_tokens: model.default_max_tokens,
+            max_max_tokens: model.max_max_tokens,
+            reject_over_max_tokens: model.reject_over_max_tokens,
+            pricing: model_to_pricing(model),
+        }))
     }
 
     async fn get_model_costs(
@@ -546,6 +573,7 @@ impl AdminRepository for AdminCompositeRepository {
                 .flatten()
                 .and_then(|v| serde_json::from_value(v).ok()),
             inference_url: row.try_get("inference_url").ok().flatten(),
+            request_timeout_secs: row.try_get("request_timeout_secs").ok().flatten(),
             hugging_face_id: row.try_get("hugging_face_id").ok().flatten(),
             quantization: row.try_get("quantization").ok().flatten(),
             max_output_length: row.try_get("max_output_length").ok().flatten(),
@@ -566,7 +594,7 @@ impl AdminRepository for AdminCompositeRepository {
                 m.cache_read_cost_per_token, m.context_length, m.verifiable,
                 m.is_active, m.owned_by, m.provider_type, m.provider_config,
                 m.attestation_supported, m.input_modalities, m.output_modalities,
-                m.inference_url,
+                m.inference_url, m.request_timeout_secs,
                 m.hugging_face_id, m.quantization, m.max_output_length,
                 m.supported_sampling_parameters, m.supported_features, m.datacenters,
                 m.is_ready, m.deprecation_date, m.openrouter_slug,
@@ -1461,6 +1489,99 @@ impl AdminRepository for AdminCompositeRepository {
         }
     }
 
+    async fn update_organization_allowed_models(
+        &self,
+        organization_id: Uuid,
+        allowed_models: Option<Vec<String>>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+
+        let rows_updated = client
+            .execute(
+                "UPDATE organizations SET allowed_models = $1, updated_at = NOW() WHERE id = $2 AND is_active = true",
+                &[&allowed_models, &organization_id],
+            )
+            .await?;
+
+        if rows_updated == 0 {
+            anyhow::bail!("Organization not found or inactive: {}", organization_id);
+        }
+
+        Ok(())
+    }
+
+    async fn get_organization_allowed_models(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Option<Vec<String>>> {
+        let client = self.pool.get().await?;
+
+        let row = client
+            .query_opt(
+                "SELECT allowed_models FROM organizations WHERE id = $1 AND is_active = true",
+                &[&organization_id],
+            )
+            .await?;
+
+        match row {
+            Some(r) => Ok(r.get("allowed_models")),
+            None => anyhow::bail!("Organization not found or inactive: {}", organization_id),
+        }
+    }
+
+    async fn update_organization_max_members(
+        &self,
+        organization_id: Uuid,
+        max_members: Option<u32>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+
+        let db_max: Option<i32> = max_members.map(i32::try_from).transpose()?;
+
+        let rows_updated = client
+            .execute(
+                "UPDATE organizations SET max_members = $1, updated_at = NOW() WHERE id = $2 AND is_active = true",
+                &[&db_max, &organization_id],
+            )
+            .await?;
+
+        if rows_updated == 0 {
+            anyhow::bail!("Organization not found or inactive: {}", organization_id);
+        }
+
+        Ok(())
+    }
+
+    async fn get_organization_seat_limit(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<OrganizationSeatLimit> {
+        let client = self.pool.get().await?;
+
+        let row = client
+            .query_opt(
+                r#"
+                SELECT o.max_members,
+                       (SELECT COUNT(*) FROM organization_members m
+                        WHERE m.organization_id = o.id) AS member_count
+                FROM organizations o
+                WHERE o.id = $1 AND o.is_active = true
+                "#,
+                &[&organization_id],
+            )
+            .await?;
+
+        match row {
+            Some(r) => Ok(OrganizationSeatLimit {
+                max_members: r
+                    .get::<_, Option<i32>>("max_members")
+                    .and_then(|v| u32::try_from(v).ok()),
+                member_count: r.get("member_count"),
+            }),
+            None => anyhow::bail!("Organization not found or inactive: {}", organization_id),
+        }
+    }
+
     async fn list_all_organizations(
         &self,
         limit: i64,
@@ -0,0 +1,86 @@
+use crate::pool::DbPool;
+use anyhow::Context;
+use services::usage::ports::{SingleInstanceGuard, SingleInstanceLock};
+use std::time::Duration;
+use tracing::warn;
+
+/// Upper bound on the `pg_locks` round trip used to confirm the lock is still
+/// held; a half-open connection would otherwise hang the check forever.
+const LOCK_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// [`SingleInstanceLock`] backed by a session-level PostgreSQL advisory lock.
+///
+/// The holding connection is detached from the pool so the lock cannot leak
+/// to another checkout: dropping the guard closes the session, which releases
+/// the lock, and a lost connection releases it as well. Because a half-open
+/// connection still looks open locally, the guard confirms through the
+/// holding session itself that the lock is still granted.
+pub struct PgAdvisoryLock {
+    pool: DbPool,
+    name: String,
+}
+
+impl PgAdvisoryLock {
+    pub fn new(pool: DbPool, name: impl Into<String>) -> Self {
+        Self {
+            pool,
+            name: name.into(),
+        }
+    }
+}
+
+struct PgAdvisoryLockGuard {
+    client: deadpool_postgres::ClientWrapper,
+}
+
+#[async_trait::async_trait]
+impl SingleInstanceGuard for PgAdvisoryLockGuard {
+    async fn is_held(&self) -> bool {
+        if self.client.is_closed() {
+            return false;
+        }
+        let check = self.client.query_one(
+            "SELECT EXISTS (
+                SELECT 1 FROM pg_locks
+                WHERE locktype = 'advisory' AND pid = pg_backend_pid() AND granted
+            )",
+            &[],
+        );
+        match tokio::time::timeout(LOCK_CHECK_TIMEOUT, check).await {
+            Ok(Ok(row)) => row.get(0),
+            Ok(Err(e)) => {
+                warn!(error = %e, "Failed to verify advisory lock; treating it as lost");
+                false
+            }
+            Err(_) => {
+                warn!("Timed out verifying advisory lock; treating it as lost");
+                false
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SingleInstanceLock for PgAdvisoryLock {
+    async fn try_acquire(&self) -> anyhow::Result<Option<Box<dyn SingleInstanceGuard>>> {
+        let client = self
+            .pool
+            .get()
+            .await
+            .context("Failed to get database connection")?;
+        let acquired: bool = client
+            .query_one(
+                "SELECT pg_try_advisory_lock(hashtextextended($1::text, 0))",
+                &[&self.name],
+            )
+            .await
+            .context("Failed to try advisory lock")?
+            .get(0);
+        if !acquired {
+            return Ok(None);
+        }
+        Ok(Some(Box::new(PgAdvisoryLockGuard {
+            client: deadpool_postgres::Object::take(client),
+        })))
+    }
+}
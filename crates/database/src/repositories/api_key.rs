@@ -363,6 +363,30 @@ impl ApiKeyRepository {
         Ok(rows_affected > 0)
     }
 
+    /// Soft delete every API key in a workspace that is not already revoked.
+    /// A single UPDATE, so either all of them are revoked or none are.
+    pub async fn revoke_all_by_workspace(
+        &self,
+        workspace_id: Uuid,
+    ) -> Result<u64, RepositoryError> {
+        retry_db!("revoke_all_workspace_api_keys", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .execute(
+                    "UPDATE api_keys SET deleted_at = NOW() WHERE workspace_id = $1 AND deleted_at IS NULL",
+                    &[&workspace_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })
+    }
+
     /// Delete expired API keys
     pub async fn cleanup_expired(&self) -> Result<i64> {
         let rows_affected = retry_db!("delete_expried_api_keys", {
@@ -415,6 +439,8 @@ impl ApiKeyRepository {
                 updated_at: row.get("updated_at"),
                 is_active: row.get("is_active"),
                 settings: row.get("settings"),
+                default_params: row.get("default_params"),
+                monthly_budget: row.get("monthly_budget"),
             })),
             None => Ok(None),
         }
@@ -736,6 +762,13 @@ impl services::workspace::ports::ApiKeyRepository for ApiKeyRepository {
         self.revoke(uuid).await
     }
 
+    async fn revoke_all_by_workspace(
+        &self,
+        workspace_id: services::workspace::WorkspaceId,
+    ) -> Result<u64, RepositoryError> {
+        self.revoke_all_by_workspace(workspace_id.0).await
+    }
+
     async fn get_all_active_key_hashes(&self) -> Result<Vec<String>, RepositoryError> {
         self.get_all_active_key_hashes().await
     }
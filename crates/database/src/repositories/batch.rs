@@ -0,0 +1,425 @@
+use crate::pool::DbPool;
+use crate::repositories::utils::map_db_error;
+use crate::retry_db;
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use services::batches::{
+    Batch, BatchLineResult, BatchLineStatus, BatchRepository, BatchRequestCounts, BatchRequestLine,
+    BatchStatus, CreateBatchParams,
+};
+use services::common::RepositoryError;
+use tokio_postgres::Row;
+use tracing::debug;
+use uuid::Uuid;
+
+pub struct PgBatchRepository {
+    pool: DbPool,
+}
+
+impl PgBatchRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Batch>, RepositoryError> {
+        let row = retry_db!("get_batch_by_id", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt("SELECT * FROM batches WHERE id = $1", &[&id])
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        row.map(|row| row_to_batch(&row).map_err(RepositoryError::DataConversionError))
+            .transpose()
+    }
+}
+
+fn row_to_batch(row: &Row) -> Result<Batch> {
+    let status: String = row.get("status");
+    Ok(Batch {
+        id: row.get("id"),
+        organization_id: row.get("organization_id"),
+        workspace_id: row.get("workspace_id"),
+        api_key_id: row.get("api_key_id"),
+        created_by_user_id: row.get("created_by_user_id"),
+        endpoint: row.get("endpoint"),
+        completion_window: row.get("completion_window"),
+        input_file_id: row.get("input_file_id"),
+        output_file_id: row.get("output_file_id"),
+        status: BatchStatus::parse(&status)
+            .ok_or_else(|| anyhow!("Unknown batch status: {status}"))?,
+        metadata: row.get("metadata"),
+        request_counts: BatchRequestCounts {
+            total: row.get("total_requests"),
+            completed: row.get("completed_requests"),
+            failed: row.get("failed_requests"),
+        },
+        created_at: row.get("created_at"),
+        in_progress_at: row.get("in_progress_at"),
+        completed_at: row.get("completed_at"),
+    })
+}
+
+fn row_to_line_result(row: &Row) -> Result<BatchLineResult> {
+    let status: String = row.get("status");
+    let status_code: Option<i32> = row.get("response_status_code");
+    Ok(BatchLineResult {
+        line_index: row.get("line_index"),
+        custom_id: row.get("custom_id"),
+        status: BatchLineStatus::parse(&status)
+            .ok_or_else(|| anyhow!("Unknown batch line status: {status}"))?,
+        status_code: status_code.map(|code| code as u16),
+        response_body: row.get("response_body"),
+    })
+}
+
+#[async_trait]
+impl BatchRepository for PgBatchRepository {
+    async fn create(&self, params: CreateBatchParams) -> Result<Batch, RepositoryError> {
+        let id = Uuid::new_v4();
+        let total_requests = params.lines.len() as i32;
+        let line_indexes: Vec<i32> = params.lines.iter().map(|l| l.line_index).collect();
+        let custom_ids: Vec<&str> = params.lines.iter().map(|l| l.custom_id.as_str()).collect();
+        let bodies: Vec<&serde_json::Value> = params.lines.iter().map(|l| &l.body).collect();
+
+        let row = match retry_db!("create_batch", {
+            let mut client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            let transaction = client
+                .transaction()
+                .await
+                .context("Failed to start transaction")
+                .map_err(RepositoryError::DatabaseError)?;
+
+            let row = transaction
+                .query_one(
+                    r#"
+                    INSERT INTO batches (
+                        id, organization_id, workspace_id, api_key_id, created_by_user_id,
+                        endpoint, completion_window, input_file_id, metadata, total_requests
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                    RETURNING *
+                    "#,
+                    &[
+                        &id,
+                        &params.organization_id,
+                        &params.workspace_id,
+                        &params.api_key_id,
+                        &params.created_by_user_id,
+                        &params.endpoint,
+                        &params.completion_window,
+                        &params.input_file_id,
+                        &params.metadata,
+                        &total_requests,
+                    ],
+                )
+                .await
+                .map_err(map_db_error)?;
+
+            transaction
+                .execute(
+                    r#"
+                    INSERT INTO batch_requests (batch_id, line_index, custom_id, body)
+                    SELECT $1, line_index, custom_id, body
+                    FROM UNNEST($2::INT[], $3::TEXT[], $4::JSONB[])
+                        AS lines(line_index, custom_id, body)
+                    "#,
+                    &[&id, &line_indexes, &custom_ids, &bodies],
+                )
+                .await
+                .map_err(map_db_error)?;
+
+            transaction
+                .commit()
+                .await
+                .context("Failed to commit transaction")
+                .map_err(RepositoryError::DatabaseError)?;
+
+            Ok::<_, RepositoryError>(row)
+        }) {
+            Ok(row) => row,
+            Err(RepositoryError::AlreadyExists) => {
+                // Committed but the connection dropped before the response;
+                // the retry hit the primary key. Return the stored batch.
+                debug!(
+                    "Batch {} already exists, fetching existing record (idempotent retry)",
+                    id
+                );
+                return self.get_by_id(id).await?.ok_or_else(|| {
+                    RepositoryError::DatabaseError(anyhow!(
+                        "Batch {id} was reported as existing but not found"
+                    ))
+                });
+            }
+            Err(e) => return Err(e),
+        };
+
+        row_to_batch(&row).map_err(RepositoryError::DataConversionError)
+    }
+
+    async fn get_by_id_and_workspace(
+        &self,
+        id: Uuid,
+        workspace_id: Uuid,
+    ) -> Result<Option<Batch>, RepositoryError> {
+        let row = retry_db!("get_batch_by_id_and_workspace", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt(
+                    "SELECT * FROM batches WHERE id = $1 AND workspace_id = $2",
+                    &[&id, &workspace_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        row.map(|row| row_to_batch(&row).map_err(RepositoryError::DataConversionError))
+            .transpose()
+    }
+
+    async fn list_line_results(
+        &self,
+        batch_id: Uuid,
+    ) -> Result<Vec<BatchLineResult>, RepositoryError> {
+        let rows = retry_db!("list_batch_line_results", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query(
+                    r#"
+                    SELECT line_index, custom_id, status, response_status_code, response_body
+                    FROM batch_requests
+                    WHERE batch_id = $1
+                    ORDER BY line_index
+                    "#,
+                    &[&batch_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        rows.iter()
+            .map(|row| row_to_line_result(row).map_err(RepositoryError::DataConversionError))
+            .collect()
+    }
+
+    async fn claim_batches(
+        &self,
+        limit: i64,
+        stale_after: chrono::Duration,
+    ) -> Result<Vec<Batch>, RepositoryError> {
+        let stale_before = chrono::Utc::now() - stale_after;
+        let rows = retry_db!("claim_batches", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query(
+                    r#"
+                    UPDATE batches
+                    SET status = 'in_progress',
+                        in_progress_at = COALESCE(in_progress_at, NOW()),
+                        claimed_at = NOW(),
+                        updated_at = NOW()
+                    WHERE id IN (
+                        -- Oldest-touched first: a batch released after a
+                        -- deferral goes to the back of the queue.
+                        SELECT id FROM batches
+                        WHERE status = 'validating'
+                           OR (status = 'in_progress'
+                               AND (claimed_at IS NULL OR claimed_at < $2))
+                        ORDER BY updated_at
+                        FOR UPDATE SKIP LOCKED
+                        LIMIT $1
+                    )
+                    RETURNING *
+                    "#,
+                    &[&limit, &stale_before],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        rows.iter()
+            .map(|row| row_to_batch(row).map_err(RepositoryError::DataConversionError))
+            .collect()
+    }
+
+    async fn list_pending_lines(
+        &self,
+        batch_id: Uuid,
+    ) -> Result<Vec<BatchRequestLine>, RepositoryError> {
+        let rows = retry_db!("list_pending_batch_lines", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query(
+                    r#"
+                    SELECT line_index, custom_id, body
+                    FROM batch_requests
+                    WHERE batch_id = $1 AND status = 'pending'
+                    ORDER BY line_index
+                    "#,
+                    &[&batch_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(rows
+            .iter()
+            .map(|row| BatchRequestLine {
+                line_index: row.get("line_index"),
+                custom_id: row.get("custom_id"),
+                body: row.get("body"),
+            })
+            .collect())
+    }
+
+    async fn record_line_result(
+        &self,
+        batch_id: Uuid,
+        line_index: i32,
+        succeeded: bool,
+        status_code: u16,
+        response_body: serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        let status = if succeeded {
+            BatchLineStatus::Completed
+        } else {
+            BatchLineStatus::Failed
+        };
+        let status_code = i32::from(status_code);
+        retry_db!("record_batch_line_result", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            // The counters only move when the line was still pending, so a
+            // retried call cannot count the same line twice.
+            client
+                .execute(
+                    r#"
+                    WITH line AS (
+                        UPDATE batch_requests
+                        SET status = $3,
+                            response_status_code = $4,
+                            response_body = $5,
+                            completed_at = NOW()
+                        WHERE batch_id = $1 AND line_index = $2 AND status = 'pending'
+                        RETURNING batch_id
+                    )
+                    UPDATE batches
+                    SET completed_requests = completed_requests + CASE WHEN $6 THEN 1 ELSE 0 END,
+                        failed_requests = failed_requests + CASE WHEN $6 THEN 0 ELSE 1 END,
+                        claimed_at = NOW(),
+                        updated_at = NOW()
+                    WHERE id IN (SELECT batch_id FROM line)
+                    "#,
+                    &[
+                        &batch_id,
+                        &line_index,
+                        &status.as_str(),
+                        &status_code,
+                        &response_body,
+                        &succeeded,
+                    ],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+        Ok(())
+    }
+
+    async fn release_claim(&self, batch_id: Uuid) -> Result<(), RepositoryError> {
+        retry_db!("release_batch_claim", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .execute(
+                    r#"
+                    UPDATE batches
+                    SET claimed_at = NULL, updated_at = NOW()
+                    WHERE id = $1 AND status = 'in_progress'
+                    "#,
+                    &[&batch_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+        Ok(())
+    }
+
+    async fn mark_completed(
+        &self,
+        batch_id: Uuid,
+        output_file_id: Uuid,
+    ) -> Result<(), RepositoryError> {
+        retry_db!("mark_batch_completed", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .execute(
+                    r#"
+                    UPDATE batches
+                    SET status = 'completed',
+                        output_file_id = $2,
+                        completed_at = NOW(),
+                        claimed_at = NULL,
+                        updated_at = NOW()
+                    WHERE id = $1 AND status = 'in_progress'
+                    "#,
+                    &[&batch_id, &output_file_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+        Ok(())
+    }
+}
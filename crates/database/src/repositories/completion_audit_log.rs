@@ -0,0 +1,58 @@
+use crate::pool::DbPool;
+use crate::repositories::utils::map_db_error;
+use crate::retry_db;
+use anyhow::Context;
+use async_trait::async_trait;
+use services::audit::{AuditEntry, AuditSink};
+use services::common::RepositoryError;
+
+/// PostgreSQL-backed [`AuditSink`] writing to `completion_audit_log`.
+pub struct PgCompletionAuditLogRepository {
+    pool: DbPool,
+}
+
+impl PgCompletionAuditLogRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditSink for PgCompletionAuditLogRepository {
+    async fn record(&self, entry: AuditEntry) -> anyhow::Result<()> {
+        retry_db!("record_completion_audit_entry", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .execute(
+                    r#"
+                    INSERT INTO completion_audit_log (
+                        request_id, organization_id, workspace_id, api_key_id, model,
+                        request_hash, response_hash, request_body, response_body, created_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                    "#,
+                    &[
+                        &entry.request_id,
+                        &entry.organization_id,
+                        &entry.workspace_id,
+                        &entry.api_key_id,
+                        &entry.model,
+                        &entry.request_hash,
+                        &entry.response_hash,
+                        &entry.request_body,
+                        &entry.response_body,
+                        &entry.created_at,
+                    ],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::pool::DbPool;
+use crate::repositories::response_item::PgResponseItemsRepository;
 use crate::repositories::utils::map_db_error;
 use crate::retry_db;
 use anyhow::{Context, Result};
@@ -20,12 +21,181 @@ impl PgConversationRepository {
         Self { pool }
     }
 
+    /// Copy the responses and response items of conversation `id` into
+    /// `new_conv_id` inside `transaction`, re-keying every row and remapping
+    /// response links to the new IDs.
+    ///
+    /// With a `cutoff` (`(created_at, id)` of a response item), only items at or
+    /// before that position, and responses created no later than it, are copied.
+    async fn copy_conversation_contents(
+        transaction: &tokio_postgres::Transaction<'_>,
+        id: ConversationId,
+        workspace_id: &WorkspaceId,
+        new_conv_id: Uuid,
+        api_key_id: Uuid,
+        cutoff: Option<(chrono::DateTime<Utc>, Uuid)>,
+        now: chrono::DateTime<Utc>,
+    ) -> Result<()> {
+        let (cutoff_created_at, cutoff_id) = cutoff.unzip();
+
+        // Get the responses to copy from the source conversation
+        let original_responses = transaction
+            .query(
+                r#"
+            SELECT id FROM responses
+            WHERE conversation_id = $1 AND workspace_id = $2
+              AND ($3::timestamptz IS NULL OR created_at <= $3)
+            ORDER BY created_at ASC
+            "#,
+                &[&id.0, &workspace_id.0, &cutoff_created_at],
+            )
+            .await
+            .context("Failed to get original responses")?;
+
+        // Clone each response and build ID mapping
+        let mut id_map = std::collections::HashMap::new();
+
+        for orig_row in &original_responses {
+            let old_response_id: Uuid = orig_row.try_get("id")?;
+            let new_response_id = Uuid::new_v4();
+            id_map.insert(old_response_id, new_response_id);
+
+            // Clone the response with new ID and new conversation_id
+            transaction
+                .execute(
+                    r#"
+                INSERT INTO responses (id, workspace_id, api_key_id, model, status, instructions, conversation_id, previous_response_id, next_response_ids, usage, metadata, created_at, updated_at)
+                SELECT 
+                    $1,
+                    workspace_id,
+                    $2,
+                    model,
+                    status,
+                    instructions,
+                    $3,
+                    previous_response_id,
+                    next_response_ids,
+                    usage,
+                    metadata,
+                    $4,
+                    $5
+                FROM responses
+                WHERE id = $6
+                "#,
+                    &[&new_response_id, &api_key_id, &new_conv_id, &now, &now, &old_response_id],
+                )
+                .await
+                .context("Failed to clone response")?;
+        }
+
+        // Update previous_response_id and next_response_ids in cloned responses to point to new IDs
+        for (old_id, new_id) in &id_map {
+            // Get the original response to check its relationships
+            let original_resp = transaction
+                .query_opt(
+                    "SELECT previous_response_id, next_response_ids FROM responses WHERE id = $1",
+                    &[old_id],
+                )
+                .await
+                .context("Failed to get original response")?;
+
+            if let Some(orig_row) = original_resp {
+                let old_prev: Option<Uuid> = orig_row.try_get("previous_response_id")?;
+                let old_next: Option<serde_json::Value> = orig_row.try_get("next_response_ids")?;
+
+                // Map previous_response_id to new ID
+                let new_prev = old_prev.and_then(|old_prev_id| id_map.get(&old_prev_id).copied());
+
+                // Map next_response_ids array to new IDs
+                let new_next = if let Some(next_json) = old_next {
+                    if let Some(next_array) = next_json.as_array() {
+                        let mapped_next: Vec<String> = next_array
+                            .iter()
+                            .filter_map(|v| v.as_str())
+                            .filter_map(|s| Uuid::parse_str(s).ok())
+                            .filter_map(|old_next_id| id_map.get(&old_next_id))
+                            .map(|new_next_id| new_next_id.to_string())
+                            .collect();
+                        Some(serde_json::json!(mapped_next))
+                    } else {
+                        Some(next_json)
+                    }
+                } else {
+                    None
+                };
+
+                // Update the cloned response with mapped IDs
+                transaction
+                    .execute(
+                        r#"
+                    UPDATE responses
+                    SET previous_response_id = $2, next_response_ids = $3
+                    WHERE id = $1
+                    "#,
+                        &[new_id, &new_prev, &new_next],
+                    )
+                    .await
+                    .context("Failed to update cloned response relationships")?;
+            }
+        }
+
+        // Clone response_items up to the cutoff, mapping old response_ids to new ones
+        // Preserve original created_at timestamps to maintain order
+        let original_items = transaction
+            .query(
+                r#"
+            SELECT id, response_id, item, created_at FROM response_items
+            WHERE conversation_id = $1
+              AND ($2::timestamptz IS NULL OR (created_at, id) <= ($2, $3::uuid))
+            ORDER BY created_at ASC, id ASC
+            "#,
+                &[&id.0, &cutoff_created_at, &cutoff_id],
+            )
+            .await
+            .context("Failed to get original response items")?;
+
+        for item_row in &original_items {
+            let old_response_id: Uuid = item_row.try_get("response_id")?;
+            let mut item_json: serde_json::Value = item_row.try_get("item")?;
+            let original_created_at: chrono::DateTime<Utc> = item_row.try_get("created_at")?;
+
+            // Map old response_id to new response_id
+            let new_response_id = id_map
+                .get(&old_response_id)
+                .copied()
+                .unwrap_or(old_response_id);
+            let new_item_id = Uuid::new_v4();
+
+            // Update the "id" field inside the item JSON to use the new item ID
+            // The item JSON has a structure like: { "id": "msg_...", "type": "message", ... }
+            if let Some(obj) = item_json.as_object_mut() {
+                // Generate a new message ID in the format "msg_<uuid without hyphens>"
+                let new_msg_id = format!("msg_{}", new_item_id.as_simple());
+                obj.insert("id".to_string(), serde_json::Value::String(new_msg_id));
+            }
+
+            transaction
+                .execute(
+                    r#"
+                INSERT INTO response_items (id, response_id, api_key_id, conversation_id, item, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#,
+                    &[&new_item_id, &new_response_id, &api_key_id, &new_conv_id, &item_json, &original_created_at, &now],
+                )
+                .await
+                .context("Failed to clone response item")?;
+        }
+
+        Ok(())
+    }
+
     // Helper method to convert database row to Conversation model
     fn row_to_conversation(&self, row: tokio_postgres::Row) -> Result<Conversation> {
         let id: Uuid = row.try_get("id")?;
         let workspace_id: Uuid = row.try_get("workspace_id")?;
         let api_key_id: Uuid = row.try_get("api_key_id")?;
         let cloned_from_id: Option<Uuid> = row.try_get("cloned_from_id")?;
+        let parent_conversation_id: Option<Uuid> = row.try_get("parent_conversation_id")?;
 
         Ok(Conversation {
             id: id.into(),
@@ -35,6 +205,7 @@ impl PgConversationRepository {
             archived_at: row.try_get("archived_at")?,
             deleted_at: row.try_get("deleted_at")?,
             cloned_from_id: cloned_from_id.map(|id| id.into()),
+            parent_conversation_id: parent_conversation_id.map(|id| id.into()),
             root_response_id: None,
             metadata: row.try_get("metadata")?,
             created_at: row.try_get("created_at")?,
@@ -303,160 +474,134 @@ impl ConversationRepository for PgConversationRepository {
             return Ok(None);
         }
 
-        // Step 2: Get all responses from the original conversation
-        let original_responses = transaction
-            .query(
-                "SELECT id FROM responses WHERE conversation_id = $1 AND workspace_id = $2 ORDER BY created_at ASC",
-                &[&id.0, &workspace_id.0],
-            )
+        Self::copy_conversation_contents(
+            &transaction,
+            id,
+            &workspace_id,
+            new_conv_id,
+            api_key_id,
+            None,
+            now,
+        )
+        .await?;
+
+        // Commit the transaction
+        transaction
+            .commit()
             .await
-            .context("Failed to get original responses")?;
+            .context("Failed to commit clone transaction")?;
 
-        // Step 3: Clone each response and build ID mapping
-        let mut id_map = std::collections::HashMap::new();
+        debug!(
+            "Cloned conversation: {} -> {} for workspace: {} (including all responses and items)",
+            id, new_conv_id, workspace_id.0
+        );
 
-        for orig_row in &original_responses {
-            let old_response_id: Uuid = orig_row.try_get("id")?;
-            let new_response_id = Uuid::new_v4();
-            id_map.insert(old_response_id, new_response_id);
+        // Return the cloned conversation
+        let cloned_conv = self
+            .get_by_id(ConversationId(new_conv_id), workspace_id)
+            .await?;
+        Ok(cloned_conv)
+    }
 
-            // Clone the response with new ID and new conversation_id
-            transaction
-                .execute(
-                    r#"
-                INSERT INTO responses (id, workspace_id, api_key_id, model, status, instructions, conversation_id, previous_response_id, next_response_ids, usage, metadata, created_at, updated_at)
-                SELECT 
-                    $1,
-                    workspace_id,
-                    $2,
-                    model,
-                    status,
-                    instructions,
-                    $3,
-                    previous_response_id,
-                    next_response_ids,
-                    usage,
-                    metadata,
-                    $4,
-                    $5
-                FROM responses
-                WHERE id = $6
-                "#,
-                    &[&new_response_id, &api_key_id, &new_conv_id, &now, &now, &old_response_id],
-                )
-                .await
-                .context("Failed to clone response")?;
-        }
+    /// Fork a conversation: copy its items up to and including `up_to_item_id`
+    /// (all items when `None`) into a new conversation linked to the source via
+    /// `parent_conversation_id`. Excludes soft-deleted conversations.
+    async fn fork_conversation(
+        &self,
+        id: ConversationId,
+        workspace_id: WorkspaceId,
+        api_key_id: uuid::Uuid,
+        up_to_item_id: Option<String>,
+    ) -> Result<Option<Conversation>> {
+        let new_conv_id = Uuid::new_v4();
+        let now = Utc::now();
 
-        // Step 4: Update previous_response_id and next_response_ids in cloned responses to point to new IDs
-        for (old_id, new_id) in &id_map {
-            // Get the original response to check its relationships
-            let original_resp = transaction
-                .query_opt(
-                    "SELECT previous_response_id, next_response_ids FROM responses WHERE id = $1",
-                    &[old_id],
-                )
+        let mut client = retry_db!("get_db_client_for_fork", {
+            self.pool
+                .get()
                 .await
-                .context("Failed to get original response")?;
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)
+        })?;
 
-            if let Some(orig_row) = original_resp {
-                let old_prev: Option<Uuid> = orig_row.try_get("previous_response_id")?;
-                let old_next: Option<serde_json::Value> = orig_row.try_get("next_response_ids")?;
+        let transaction = client
+            .transaction()
+            .await
+            .context("Failed to start transaction")?;
 
-                // Map previous_response_id to new ID
-                let new_prev = old_prev.and_then(|old_prev_id| id_map.get(&old_prev_id).copied());
+        let conv_row = transaction
+            .query_opt(
+                r#"
+            INSERT INTO conversations (id, workspace_id, api_key_id, pinned_at, archived_at, deleted_at, parent_conversation_id, metadata, created_at, updated_at)
+            SELECT $1, workspace_id, $2, NULL, NULL, NULL, id, metadata, $3, $4
+            FROM conversations
+            WHERE id = $5 AND workspace_id = $6 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+                &[&new_conv_id, &api_key_id, &now, &now, &id.0, &workspace_id.0],
+            )
+            .await
+            .context("Failed to fork conversation")?;
 
-                // Map next_response_ids array to new IDs
-                let new_next = if let Some(next_json) = old_next {
-                    if let Some(next_array) = next_json.as_array() {
-                        let mapped_next: Vec<String> = next_array
-                            .iter()
-                            .filter_map(|v| v.as_str())
-                            .filter_map(|s| Uuid::parse_str(s).ok())
-                            .filter_map(|old_next_id| id_map.get(&old_next_id))
-                            .map(|new_next_id| new_next_id.to_string())
-                            .collect();
-                        Some(serde_json::json!(mapped_next))
-                    } else {
-                        Some(next_json)
-                    }
-                } else {
-                    None
-                };
+        if conv_row.is_none() {
+            transaction.rollback().await.ok();
+            return Ok(None);
+        }
 
-                // Update the cloned response with mapped IDs
-                transaction
-                    .execute(
+        // The fork point must be an item of this conversation in this workspace;
+        // unknown and foreign items are rejected the same way.
+        let cutoff = match up_to_item_id {
+            Some(item_id) => {
+                let item_uuid = PgResponseItemsRepository::extract_uuid_from_item_id(&item_id);
+                let item_row = transaction
+                    .query_opt(
                         r#"
-                    UPDATE responses
-                    SET previous_response_id = $2, next_response_ids = $3
-                    WHERE id = $1
+                    SELECT ri.created_at, ri.id
+                    FROM response_items ri
+                    JOIN responses r ON ri.response_id = r.id
+                    WHERE ri.id = $1
+                      AND ri.conversation_id = $2
+                      AND r.workspace_id = $3
                     "#,
-                        &[new_id, &new_prev, &new_next],
+                        &[&item_uuid, &id.0, &workspace_id.0],
                     )
                     .await
-                    .context("Failed to update cloned response relationships")?;
-            }
-        }
+                    .context("Failed to resolve fork item")?;
 
-        // Step 5: Clone all response_items, mapping old response_ids to new ones
-        // Preserve original created_at timestamps to maintain order
-        let original_items = transaction
-            .query(
-                "SELECT id, response_id, item, created_at FROM response_items WHERE conversation_id = $1 ORDER BY created_at ASC",
-                &[&id.0],
-            )
-            .await
-            .context("Failed to get original response items")?;
-
-        for item_row in &original_items {
-            let old_response_id: Uuid = item_row.try_get("response_id")?;
-            let mut item_json: serde_json::Value = item_row.try_get("item")?;
-            let original_created_at: chrono::DateTime<Utc> = item_row.try_get("created_at")?;
-
-            // Map old response_id to new response_id
-            let new_response_id = id_map
-                .get(&old_response_id)
-                .copied()
-                .unwrap_or(old_response_id);
-            let new_item_id = Uuid::new_v4();
-
-            // Update the "id" field inside the item JSON to use the new item ID
-            // The item JSON has a structure like: { "id": "msg_...", "type": "message", ... }
-            if let Some(obj) = item_json.as_object_mut() {
-                // Generate a new message ID in the format "msg_<uuid without hyphens>"
-                let new_msg_id = format!("msg_{}", new_item_id.as_simple());
-                obj.insert("id".to_string(), serde_json::Value::String(new_msg_id));
+                let Some(item_row) = item_row else {
+                    transaction.rollback().await.ok();
+                    return Err(anyhow::Error::new(RepositoryError::NotFound(
+                        "conversation item".to_string(),
+                    )));
+                };
+                Some((item_row.try_get("created_at")?, item_row.try_get("id")?))
             }
+            None => None,
+        };
+
+        Self::copy_conversation_contents(
+            &transaction,
+            id,
+            &workspace_id,
+            new_conv_id,
+            api_key_id,
+            cutoff,
+            now,
+        )
+        .await?;
 
-            transaction
-                .execute(
-                    r#"
-                INSERT INTO response_items (id, response_id, api_key_id, conversation_id, item, created_at, updated_at)
-                VALUES ($1, $2, $3, $4, $5, $6, $7)
-                "#,
-                    &[&new_item_id, &new_response_id, &api_key_id, &new_conv_id, &item_json, &original_created_at, &now],
-                )
-                .await
-                .context("Failed to clone response item")?;
-        }
-
-        // Commit the transaction
         transaction
             .commit()
             .await
-            .context("Failed to commit clone transaction")?;
+            .context("Failed to commit fork transaction")?;
 
         debug!(
-            "Cloned conversation: {} -> {} for workspace: {} (including all responses and items)",
+            "Forked conversation: {} -> {} for workspace: {}",
             id, new_conv_id, workspace_id.0
         );
 
-        // Return the cloned conversation
-        let cloned_conv = self
-            .get_by_id(ConversationId(new_conv_id), workspace_id)
-            .await?;
-        Ok(cloned_conv)
+        self.get_by_id(ConversationId(new_conv_id), workspace_id)
+            .await
     }
 
     /// Soft delete a conversation (sets deleted_at timestamp)
@@ -6,6 +6,7 @@ use crate::pool::DbPool;
 use crate::repositories::utils::map_db_error;
 use crate::retry_db;
 use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
 use chrono::Utc;
 use services::common::RepositoryError;
 use tracing::{debug, error, warn};
@@ -50,12 +51,12 @@ impl McpConnectorRepository {
                 .query_one(
                     r#"
                 INSERT INTO mcp_connectors (
-                    id, organization_id, name, description,
+                    id, organization_id, workspace_id, name, description,
                     mcp_server_url, auth_type, auth_config,
                     is_active, created_by, created_at, updated_at,
                     connection_status, capabilities, metadata
                 )
-                VALUES ($1, $2, $3, $4, $5, $6, $7, true, $8, $9, $10, 'pending', $11, $12)
+                VALUES ($1, $2, $13, $3, $4, $5, $6, $7, true, $8, $9, $10, 'pending', $11, $12)
                 RETURNING *
                 "#,
                     &[
@@ -71,6 +72,7 @@ impl McpConnectorRepository {
                         &now,
                         &None::<serde_json::Value>,
                         &None::<serde_json::Value>,
+                        &request.workspace_id,
                     ],
                 )
                 .await
@@ -134,6 +136,63 @@ impl McpConnectorRepository {
             .collect()
     }
 
+    /// Get an MCP connector by ID within a workspace
+    pub async fn get_by_id_and_workspace(
+        &self,
+        id: Uuid,
+        workspace_id: Uuid,
+    ) -> Result<Option<McpConnector>> {
+        let row = retry_db!("get_mcp_connector_by_id_and_workspace", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt(
+                    "SELECT * FROM mcp_connectors WHERE id = $1 AND workspace_id = $2",
+                    &[&id, &workspace_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        match row {
+            Some(row) => Ok(Some(self.row_to_connector(row)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get all MCP connectors for a workspace
+    pub async fn list_by_workspace(&self, workspace_id: Uuid) -> Result<Vec<McpConnector>> {
+        let rows = retry_db!("list_mcp_connectors_by_workspace", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query(
+                    r#"
+                SELECT * FROM mcp_connectors
+                WHERE workspace_id = $1
+                ORDER BY created_at DESC
+                "#,
+                    &[&workspace_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        rows.into_iter()
+            .map(|row| self.row_to_connector(row))
+            .collect()
+    }
+
     /// Get active MCP connectors for an organization
     pub async fn list_active_by_organization(
         &self,
@@ -266,6 +325,31 @@ impl McpConnectorRepository {
         Ok(())
     }
 
+    /// Delete an MCP connector within a workspace. Returns false if no such connector exists.
+    pub async fn delete_in_workspace(&self, id: Uuid, workspace_id: Uuid) -> Result<bool> {
+        let result = retry_db!("delete_mcp_connector_in_workspace", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .execute(
+                    "DELETE FROM mcp_connectors WHERE id = $1 AND workspace_id = $2",
+                    &[&id, &workspace_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        if result > 0 {
+            debug!("Deleted MCP connector: {}", id);
+        }
+        Ok(result > 0)
+    }
+
     /// Update connection status for an MCP connector
     pub async fn update_connection_status(
         &self,
@@ -467,6 +551,7 @@ impl McpConnectorRepository {
         Ok(McpConnector {
             id: row.get("id"),
             organization_id: row.get("organization_id"),
+            workspace_id: row.get("workspace_id"),
             name: row.get("name"),
             description: row.get("description"),
             mcp_server_url: row.get("mcp_server_url"),
@@ -500,3 +585,105 @@ impl McpConnectorRepository {
         })
     }
 }
+
+fn db_connector_to_service(connector: McpConnector) -> Result<services::mcp::McpConnector> {
+    let Some(workspace_id) = connector.workspace_id else {
+        bail!("MCP connector {} has no workspace", connector.id);
+    };
+    let auth = match connector.auth_type {
+        McpAuthType::None => services::mcp::McpAuthConfig::None,
+        McpAuthType::Bearer => {
+            let config: McpBearerConfig = serde_json::from_value(
+                connector
+                    .auth_config
+                    .context("Bearer MCP connector is missing its auth config")?,
+            )
+            .context("Invalid bearer auth config")?;
+            services::mcp::McpAuthConfig::Bearer(services::mcp::McpBearerConfig {
+                token: config.token,
+                header_name: None,
+            })
+        }
+    };
+
+    Ok(services::mcp::McpConnector {
+        id: services::mcp::McpConnectorId(connector.id),
+        organization_id: services::organization::OrganizationId(connector.organization_id),
+        workspace_id: services::workspace::WorkspaceId(workspace_id),
+        name: connector.name,
+        description: connector.description,
+        server_url: connector.mcp_server_url,
+        auth,
+        is_active: connector.is_active,
+        settings: connector.metadata.unwrap_or_default(),
+        created_at: connector.created_at,
+        updated_at: connector.updated_at,
+    })
+}
+
+#[async_trait]
+impl services::mcp::McpConnectorRepository for McpConnectorRepository {
+    async fn create(
+        &self,
+        organization_id: services::organization::OrganizationId,
+        workspace_id: services::workspace::WorkspaceId,
+        created_by: Uuid,
+        name: String,
+        description: Option<String>,
+        server_url: String,
+        auth: services::mcp::McpAuthConfig,
+    ) -> Result<services::mcp::McpConnector> {
+        let (auth_type, bearer_token) = match auth {
+            services::mcp::McpAuthConfig::None => (McpAuthType::None, None),
+            services::mcp::McpAuthConfig::Bearer(bearer) => {
+                (McpAuthType::Bearer, Some(bearer.token))
+            }
+            _ => bail!("Unsupported MCP connector auth type"),
+        };
+        let connector = self
+            .create(
+                organization_id.0,
+                created_by,
+                CreateMcpConnectorRequest {
+                    workspace_id: Some(workspace_id.0),
+                    name,
+                    description,
+                    mcp_server_url: server_url,
+                    auth_type,
+                    bearer_token,
+                },
+            )
+            .await?;
+        db_connector_to_service(connector)
+    }
+
+    async fn get_by_id(
+        &self,
+        id: services::mcp::McpConnectorId,
+        workspace_id: services::workspace::WorkspaceId,
+    ) -> Result<Option<services::mcp::McpConnector>> {
+        self.get_by_id_and_workspace(id.0, workspace_id.0)
+            .await?
+            .map(db_connector_to_service)
+            .transpose()
+    }
+
+    async fn delete(
+        &self,
+        id: services::mcp::McpConnectorId,
+        workspace_id: services::workspace::WorkspaceId,
+    ) -> Result<bool> {
+        self.delete_in_workspace(id.0, workspace_id.0).await
+    }
+
+    async fn list_by_workspace(
+        &self,
+        workspace_id: services::workspace::WorkspaceId,
+    ) -> Result<Vec<services::mcp::McpConnector>> {
+        self.list_by_workspace(workspace_id.0)
+            .await?
+            .into_iter()
+            .map(db_connector_to_service)
+            .collect()
+    }
+}
@@ -1,14 +1,18 @@
 pub mod admin_access_token;
 pub mod admin_composite;
+pub mod advisory_lock;
 pub mod analytics;
 pub mod api_key;
 pub mod attestation;
+pub mod batch;
+pub mod completion_audit_log;
 pub mod conversation;
 pub mod feature_request;
 pub mod file;
 pub mod mcp_connector;
 pub mod model;
 pub mod model_alias;
+pub mod model_quota;
 pub mod model_repository_impl;
 pub mod near_nonce;
 pub mod oauth_state;
@@ -16,6 +20,7 @@ pub mod organization;
 pub mod organization_invitation;
 pub mod organization_limits;
 pub mod organization_limits_repository_impl;
+pub mod organization_model_alias;
 pub mod organization_reporting_token;
 mod organization_reporting_token_row;
 pub mod organization_service_usage;
@@ -39,9 +44,12 @@ pub mod workspace;
 
 pub use admin_access_token::AdminAccessTokenRepository;
 pub use admin_composite::AdminCompositeRepository;
+pub use advisory_lock::PgAdvisoryLock;
 pub use analytics::PgAnalyticsRepository;
 pub use api_key::ApiKeyRepository;
 pub use attestation::PgAttestationRepository;
+pub use batch::PgBatchRepository;
+pub use completion_audit_log::PgCompletionAuditLogRepository;
 pub use conversation::PgConversationRepository;
 pub use feature_request::{
     FeatureRequestRepository, FeatureRequestSummary, FeatureRequestTarget,
@@ -51,11 +59,13 @@ pub use file::FileRepository;
 pub use mcp_connector::McpConnectorRepository;
 pub use model::ModelRepository;
 pub use model_alias::ModelAliasRepository;
+pub use model_quota::ModelQuotaRepository;
 pub use near_nonce::PostgresNearNonceRepository;
 pub use oauth_state::{OAuthStateRepository, OAuthStateRow};
 pub use organization::PgOrganizationRepository;
 pub use organization_invitation::PgOrganizationInvitationRepository;
 pub use organization_limits::OrganizationLimitsRepository;
+pub use organization_model_alias::OrganizationModelAliasRepository;
 pub use organization_reporting_token::OrganizationReportingTokenRepository;
 pub use organization_service_usage::{
     OrganizationServiceUsageRepository, RecordServiceUsageRequest,
@@ -84,6 +84,7 @@ impl ModelRepository {
                         m.hugging_face_id, m.quantization, m.max_output_length,
                         m.supported_sampling_parameters, m.supported_features, m.datacenters,
                         m.is_ready, m.deprecation_date, m.openrouter_slug, m.allow_free,
+                        m.default_max_tokens, m.max_max_tokens, m.reject_over_max_tokens, m.request_timeout_secs,
                         COALESCE(array_agg(a.alias_name) FILTER (WHERE a.alias_name IS NOT NULL), '{}') AS aliases
                     FROM models m
                     LEFT JOIN model_aliases a ON a.canonical_model_id = m.id AND a.is_active = true
@@ -167,6 +168,7 @@ impl ModelRepository {
                             m.hugging_face_id, m.quantization, m.max_output_length,
                             m.supported_sampling_parameters, m.supported_features, m.datacenters,
                             m.is_ready, m.deprecation_date, m.openrouter_slug, m.allow_free,
+                            m.default_max_tokens, m.max_max_tokens, m.reject_over_max_tokens, m.request_timeout_secs,
                             COALESCE(array_agg(a.alias_name) FILTER (WHERE a.alias_name IS NOT NULL), '{}') AS aliases
                         FROM models m
                         LEFT JOIN model_aliases a ON a.canonical_model_id = m.id AND a.is_active = true
@@ -191,6 +193,7 @@ impl ModelRepository {
                             m.hugging_face_id, m.quantization, m.max_output_length,
                             m.supported_sampling_parameters, m.supported_features, m.datacenters,
                             m.is_ready, m.deprecation_date, m.openrouter_slug, m.allow_free,
+                            m.default_max_tokens, m.max_max_tokens, m.reject_over_max_tokens, m.request_timeout_secs,
                             COALESCE(array_agg(a.alias_name) FILTER (WHERE a.alias_name IS NOT NULL), '{}') AS aliases
                         FROM models m
                         LEFT JOIN model_aliases a ON a.canonical_model_id = m.id AND a.is_active = true
@@ -232,7 +235,7 @@ impl ModelRepository {
                         input_cost_per_token, output_cost_per_token, cost_per_image, cache_read_cost_per_token,
                         context_length, verifiable, is_active, owned_by, created_at, updated_at,
                         provider_type, provider_config, attestation_supported,
-                        input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free
+                        input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free, default_max_tokens, max_max_tokens, reject_over_max_tokens, request_timeout_secs
                     FROM models
                     WHERE model_name = $1
                     "#,
@@ -267,7 +270,7 @@ impl ModelRepository {
                         input_cost_per_token, output_cost_per_token, cost_per_image, cache_read_cost_per_token,
                         context_length, verifiable, is_active, owned_by, created_at, updated_at,
                         provider_type, provider_config, attestation_supported,
-                        input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free
+                        input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free, default_max_tokens, max_max_tokens, reject_over_max_tokens, request_timeout_secs
                     FROM models
                     WHERE id = $1
                     "#,
@@ -330,6 +333,10 @@ impl ModelRepository {
                         m.deprecation_date,
                         m.openrouter_slug,
                         m.allow_free,
+                        m.default_max_tokens,
+                        m.max_max_tokens,
+                        m.reject_over_max_tokens,
+                        m.request_timeout_secs,
                         COALESCE(
                             array_agg(ma.alias_name)
                             FILTER (WHERE ma.alias_name IS NOT NULL),
@@ -414,6 +421,8 @@ impl ModelRepository {
         let openrouter_slug_value: Option<String> =
             update_request.openrouter_slug.clone().flatten();
         let openrouter_slug_clear: bool = matches!(update_request.openrouter_slug, Some(None));
+        let request_timeout_value: Option<i32> = update_request.request_timeout_secs.flatten();
+        let request_timeout_clear: bool = matches!(update_request.request_timeout_secs, Some(None));
         // Tri-state as well: explicit `null` disables cache pricing (NULL column).
         let cache_read_value: Option<i64> = update_request.cache_read_cost_per_token.flatten();
         let cache_read_clear: bool = matches!(update_request.cache_read_cost_per_token, Some(None));
@@ -459,13 +468,17 @@ impl ModelRepository {
                             deprecation_date = CASE WHEN $28 THEN NULL ELSE COALESCE($26, deprecation_date) END,
                             openrouter_slug = CASE WHEN $30 THEN NULL ELSE COALESCE($29, openrouter_slug) END,
                             allow_free = COALESCE($31, allow_free),
+                            default_max_tokens = COALESCE($33, default_max_tokens),
+                            max_max_tokens = COALESCE($34, max_max_tokens),
+                            reject_over_max_tokens = COALESCE($35, reject_over_max_tokens),
+                            request_timeout_secs = CASE WHEN $37 THEN NULL ELSE COALESCE($36, request_timeout_secs) END,
                             updated_at = NOW()
                         WHERE model_name = $1
                         RETURNING id, model_name, model_display_name, model_description, model_icon,
                                   input_cost_per_token, output_cost_per_token, cost_per_image, cache_read_cost_per_token,
                                   context_length, verifiable, is_active, owned_by, created_at, updated_at,
                                   provider_type, provider_config, attestation_supported,
-                                  input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free
+                                  input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free, default_max_tokens, max_max_tokens, reject_over_max_tokens, request_timeout_secs
                         "#,
                         &[
                             &model_name,
@@ -500,6 +513,11 @@ impl ModelRepository {
                             &openrouter_slug_clear,
                             &update_request.allow_free,
                             &cache_read_clear,
+                            &update_request.default_max_tokens,
+                            &update_request.max_max_tokens,
+                            &update_request.reject_over_max_tokens,
+                            &request_timeout_value,
+                            &request_timeout_clear,
                         ],
                     )
                     .await
@@ -533,7 +551,8 @@ impl ModelRepository {
                             context_length, verifiable, is_active, owned_by,
                             provider_type, provider_config, attestation_supported,
                             input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters,
-                            is_ready, deprecation_date, openrouter_slug, allow_free
+                            is_ready, deprecation_date, openrouter_slug, allow_free,
+                            default_max_tokens, max_max_tokens, reject_over_max_tokens, request_timeout_secs
                         ) VALUES (
                             $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11,
                             COALESCE($12, $13),
@@ -548,7 +567,8 @@ impl ModelRepository {
                             COALESCE($23, ARRAY[]::TEXT[]),
                             COALESCE($24, ARRAY[]::TEXT[]),
                             $25, $26, $27, $30,
-                            COALESCE($32, false)
+                            COALESCE($32, false),
+                            $33, $34, COALESCE($35, false), $36
                         )
                         ON CONFLICT (model_name) DO UPDATE SET
                             input_cost_per_token = EXCLUDED.input_cost_per_token,
@@ -581,12 +601,16 @@ impl ModelRepository {
                             deprecation_date = CASE WHEN $29 THEN NULL ELSE COALESCE($27, models.deprecation_date) END,
                             openrouter_slug = CASE WHEN $31 THEN NULL ELSE COALESCE($30, models.openrouter_slug) END,
                             allow_free = COALESCE($32, models.allow_free),
+                            default_max_tokens = COALESCE($33, models.default_max_tokens),
+                            max_max_tokens = COALESCE($34, models.max_max_tokens),
+                            reject_over_max_tokens = COALESCE($35, models.reject_over_max_tokens),
+                            request_timeout_secs = CASE WHEN $37 THEN NULL ELSE COALESCE($36, models.request_timeout_secs) END,
                             updated_at = NOW()
                         RETURNING id, model_name, model_display_name, model_description, model_icon,
                                   input_cost_per_token, output_cost_per_token, cost_per_image, cache_read_cost_per_token,
                                   context_length, verifiable, is_active, owned_by, created_at, updated_at,
                                   provider_type, provider_config, attestation_supported,
-                                  input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free
+                                  input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free, default_max_tokens, max_max_tokens, reject_over_max_tokens, request_timeout_secs
                         "#,
                         &[
                             &model_name,
@@ -624,6 +648,11 @@ impl ModelRepository {
                             &openrouter_slug_value,
                             &openrouter_slug_clear,
                             &update_request.allow_free,
+                            &update_request.default_max_tokens,
+                            &update_request.max_max_tokens,
+                            &update_request.reject_over_max_tokens,
+                            &request_timeout_value,
+                            &request_timeout_clear,
                         ],
                     )
                     .await
@@ -687,6 +716,7 @@ impl ModelRepository {
         let is_ready_value: Option<bool> = req.is_ready.flatten();
         let deprecation_date_value: Option<DateTime<Utc>> = req.deprecation_date.flatten();
         let openrouter_slug_value: Option<String> = req.openrouter_slug.clone().flatten();
+        let request_timeout_value: Option<i32> = req.request_timeout_secs.flatten();
         // Absent and explicit-null both insert NULL = cache pricing disabled.
         let cache_read_value: Option<i64> = req.cache_read_cost_per_token.flatten();
 
@@ -710,7 +740,8 @@ impl ModelRepository {
                         context_length, verifiable, is_active, owned_by,
                         provider_type, provider_config, attestation_supported,
                         input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters,
-                        is_ready, deprecation_date, openrouter_slug, allow_free
+                        is_ready, deprecation_date, openrouter_slug, allow_free,
+                        default_max_tokens, max_max_tokens, reject_over_max_tokens, request_timeout_secs
                     ) VALUES (
                         $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11,
                         COALESCE($12, $13),
@@ -722,14 +753,15 @@ impl ModelRepository {
                         COALESCE($23, ARRAY[]::TEXT[]),
                         COALESCE($24, ARRAY[]::TEXT[]),
                         $25, $26, $27, $28,
-                        COALESCE($29, false)
+                        COALESCE($29, false),
+                        $30, $31, COALESCE($32, false), $33
                     )
                     ON CONFLICT (model_name) DO NOTHING
                     RETURNING id, model_name, model_display_name, model_description, model_icon,
                               input_cost_per_token, output_cost_per_token, cost_per_image, cache_read_cost_per_token,
                               context_length, verifiable, is_active, owned_by, created_at, updated_at,
                               provider_type, provider_config, attestation_supported,
-                              input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free
+                              input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free, default_max_tokens, max_max_tokens, reject_over_max_tokens, request_timeout_secs
                     "#,
                     &[
                         &model_name,
@@ -761,6 +793,10 @@ impl ModelRepository {
                         &deprecation_date_value,
                         &openrouter_slug_value,
                         &req.allow_free,
+                        &req.default_max_tokens,
+                        &req.max_max_tokens,
+                        &req.reject_over_max_tokens,
+                        &request_timeout_value,
                     ],
                 )
                 .await
@@ -812,17 +848,19 @@ impl ModelRepository {
                         context_length, verifiable, is_active, owned_by,
                         provider_type, provider_config, attestation_supported,
                         input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters,
-                        is_ready, deprecation_date, openrouter_slug, allow_free
+                        is_ready, deprecation_date, openrouter_slug, allow_free,
+                        default_max_tokens, max_max_tokens, reject_over_max_tokens, request_timeout_secs
                     ) VALUES (
                         $1, $2, $3, $4, $5, $6, $7, $8,
                         $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
-                        $19, $20, $21, $22, $23, $24, $25, $26, $27, $28
+                        $19, $20, $21, $22, $23, $24, $25, $26, $27, $28,
+                        $29, $30, $31, $32
                     )
                     RETURNING id, model_name, model_display_name, model_description, model_icon,
                               input_cost_per_token, output_cost_per_token, cost_per_image, cache_read_cost_per_token,
                               context_length, verifiable, is_active, owned_by, created_at, updated_at,
                               provider_type, provider_config, attestation_supported,
-                              input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free
+                              input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free, default_max_tokens, max_max_tokens, reject_over_max_tokens, request_timeout_secs
                     "#,
                     &[
                         &model.model_name,
@@ -853,6 +891,10 @@ impl ModelRepository {
                         &model.deprecation_date,
                         &model.openrouter_slug,
                         &model.allow_free,
+                        &model.default_max_tokens,
+                        &model.max_max_tokens,
+                        &model.reject_over_max_tokens,
+                        &model.request_timeout_secs,
                     ],
                 )
                 .await
@@ -1047,7 +1089,7 @@ impl ModelRepository {
                               input_cost_per_token, output_cost_per_token, cost_per_image, cache_read_cost_per_token,
                               context_length, verifiable, is_active, owned_by, created_at, updated_at,
                               provider_type, provider_config, attestation_supported,
-                              input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free
+                              input_modalities, output_modalities, inference_url, hugging_face_id, quantization, max_output_length, supported_sampling_parameters, supported_features, datacenters, is_ready, deprecation_date, openrouter_slug, allow_free, default_max_tokens, max_max_tokens, reject_over_max_tokens, request_timeout_secs
                     "#,
                     &[&model_name],
                 )
@@ -1265,6 +1307,18 @@ impl ModelRepository {
     /// Resolve a model identifier (alias or canonical name) and return the full model details
     /// Returns None if the model is not found or not active
     pub async fn resolve_and_get_model(&self, identifier: &str) -> Result<Option<Model>> {
+        self.resolve_and_get_model_for_organization(None, identifier)
+            .await
+    }
+
+    /// Like [`Self::resolve_and_get_model`], but when `organization_id` is set
+    /// that organization's private aliases are matched too and win over
+    /// canonical names and global aliases.
+    pub async fn resolve_and_get_model_for_organization(
+        &self,
+        organization_id: Option<uuid::Uuid>,
+        identifier: &str,
+    ) -> Result<Option<Model>> {
         let row = retry_db!("resolve_and_get_model", {
             let client = self
                 .pool
@@ -1308,6 +1362,10 @@ impl ModelRepository {
                         m.deprecation_date,
                         m.openrouter_slug,
                         m.allow_free,
+                        m.default_max_tokens,
+                        m.max_max_tokens,
+                        m.reject_over_max_tokens,
+                        m.request_timeout_secs,
                         COALESCE(
                             array_agg(ma_all.alias_name)
                             FILTER (WHERE ma_all.alias_name IS NOT NULL),
@@ -1327,11 +1385,25 @@ impl ModelRepository {
                             AND ma_match.alias_name = $1
                             AND ma_match.is_active = true
                         )
+                        OR EXISTS (
+                            SELECT 1
+                            FROM organization_model_aliases oma
+                            WHERE oma.canonical_model_id = m.id
+                            AND oma.organization_id = $2
+                            AND oma.alias_name = $1
+                        )
                     )
                     GROUP BY m.id
+                    ORDER BY EXISTS (
+                        SELECT 1
+                        FROM organization_model_aliases oma
+                        WHERE oma.canonical_model_id = m.id
+                        AND oma.organization_id = $2
+                        AND oma.alias_name = $1
+                    ) DESC
                     LIMIT 1;
                     "#,
-                    &[&identifier],
+                    &[&identifier, &organization_id],
                 )
                 .await
                 .map_err(map_db_error)
@@ -1392,6 +1464,10 @@ impl ModelRepository {
             deprecation_date: row.try_get("deprecation_date").ok().flatten(),
             openrouter_slug: row.try_get("openrouter_slug").ok().flatten(),
             allow_free: row.try_get("allow_free").unwrap_or(false),
+            default_max_tokens: row.try_get("default_max_tokens").ok().flatten(),
+            max_max_tokens: row.try_get("max_max_tokens").ok().flatten(),
+            reject_over_max_tokens: row.try_get("reject_over_max_tokens").unwrap_or(false),
+            request_timeout_secs: row.try_get("request_timeout_secs").ok().flatten(),
         }
     }
 
@@ -1453,12 +1529,14 @@ impl ModelRepository {
     }
 
     /// Get all active models with inference_url set.
-    /// Returns (model_name, inference_url, context_length) triples for direct routing.
+    /// Returns one `InferenceEndpoint` per endpoint for direct routing.
     /// A row whose `provider_config` declares a `long_context` tier expands into
     /// TWO entries under the same model name (base fleet + long-context URL, each
     /// with its own declared capacity) — see
     /// `services::inference_provider_pool::expand_inference_endpoints`.
-    pub async fn get_inference_url_models(&self) -> Result<Vec<(String, String, Option<u32>)>> {
+    pub async fn get_inference_url_models(
+        &self,
+    ) -> Result<Vec<services::inference_provider_pool::InferenceEndpoint>> {
         let rows = retry_db!("get_inference_url_models", {
             let client = self
                 .pool
@@ -1470,7 +1548,8 @@ impl ModelRepository {
             client
                 .query(
                     r#"
-                    SELECT model_name, inference_url, context_length, provider_config
+                    SELECT model_name, inference_url, context_length, provider_config,
+                           request_timeout_secs
                     FROM models
                     WHERE is_active = true
                       AND inference_url IS NOT NULL
@@ -1489,11 +1568,13 @@ impl ModelRepository {
                 let inference_url: String = row.get("inference_url");
                 let context_length: i32 = row.get("context_length");
                 let provider_config: Option<serde_json::Value> = row.get("provider_config");
+                let request_timeout_secs: Option<i32> = row.get("request_timeout_secs");
                 services::inference_provider_pool::expand_inference_endpoints(
                     &model_name,
                     &inference_url,
                     Some(context_length as u32),
                     provider_config.as_ref(),
+                    request_timeout_secs.and_then(|secs| u32::try_from(secs).ok()),
                 )
             })
             .collect();
@@ -1522,6 +1603,7 @@ impl ModelRepository {
                         m.hugging_face_id, m.quantization, m.max_output_length,
                         m.supported_sampling_parameters, m.supported_features, m.datacenters,
                         m.is_ready, m.deprecation_date, m.openrouter_slug, m.allow_free,
+                        m.default_max_tokens, m.max_max_tokens, m.reject_over_max_tokens, m.request_timeout_secs,
                         COALESCE(array_agg(a.alias_name) FILTER (WHERE a.alias_name IS NOT NULL), '{}') AS aliases
                     FROM models m
                     LEFT JOIN model_aliases a ON a.canonical_model_id = m.id AND a.is_active = true
@@ -1564,13 +1646,53 @@ impl services::inference_provider_pool::ExternalModelsSource for ModelRepository
 
     async fn fetch_inference_url_models(
         &self,
-    ) -> Result<Vec<(String, String, Option<u32>)>, String> {
+    ) -> Result<Vec<services::inference_provider_pool::InferenceEndpoint>, String> {
         self.get_inference_url_models()
             .await
             .map_err(|e| format!("Failed to fetch inference_url models: {e}"))
     }
 }
 
+/// Convert a resolved catalog row into the services-layer model view.
+fn model_with_pricing(m: Model) -> services::models::ModelWithPricing {
+    services::models::ModelWithPricing {
+        id: m.id,
+        model_name: m.model_name,
+        model_display_name: m.model_display_name,
+        model_description: m.model_description,
+        model_icon: m.model_icon,
+        input_cost_per_token: m.input_cost_per_token,
+        output_cost_per_token: m.output_cost_per_token,
+        cost_per_image: m.cost_per_image,
+        cache_read_cost_per_token: m.cache_read_cost_per_token,
+        context_length: m.context_length,
+        verifiable: m.verifiable,
+        aliases: m.aliases,
+        owned_by: m.owned_by,
+        provider_type: m.provider_type,
+        provider_config: m.provider_config,
+        attestation_supported: m.attestation_supported,
+        input_modalities: m.input_modalities,
+        output_modalities: m.output_modalities,
+        inference_url: m.inference_url,
+        hugging_face_id: m.hugging_face_id,
+        quantization: m.quantization,
+        max_output_length: m.max_output_length,
+        supported_sampling_parameters: m.supported_sampling_parameters,
+        supported_features: m.supported_features,
+        datacenters: m.datacenters,
+        is_ready: m.is_ready,
+        deprecation_date: m.deprecation_date,
+        openrouter_slug: m.openrouter_slug,
+        max_tokens_limits: services::models::MaxTokensLimits {
+            default_max_tokens: m.default_max_tokens,
+            max_max_tokens: m.max_max_tokens,
+            reject_over_max: m.reject_over_max_tokens,
+        },
+        created_at: m.created_at,
+    }
+}
+
 // Implement ModelsRepository trait from services
 #[async_trait]
 impl services::models::ModelsRepository for ModelRepository {
@@ -1607,6 +1729,11 @@ impl services::models::ModelsRepository for ModelRepository {
                 is_ready: m.is_ready,
                 deprecation_date: m.deprecation_date,
                 openrouter_slug: m.openrouter_slug,
+                max_tokens_limits: services::models::MaxTokensLimits {
+                    default_max_tokens: m.default_max_tokens,
+                    max_max_tokens: m.max_max_tokens,
+                    reject_over_max: m.reject_over_max_tokens,
+                },
                 created_at: m.created_at,
             })
             .collect())
@@ -1646,6 +1773,11 @@ impl services::models::ModelsRepository for ModelRepository {
             is_ready: m.is_ready,
             deprecation_date: m.deprecation_date,
             openrouter_slug: m.openrouter_slug,
+            max_tokens_limits: services::models::MaxTokensLimits {
+                default_max_tokens: m.default_max_tokens,
+                max_max_tokens: m.max_max_tokens,
+                reject_over_max: m.reject_over_max_tokens,
+            },
             created_at: m.created_at,
         }))
     }
@@ -1655,37 +1787,18 @@ impl services::models::ModelsRepository for ModelRepository {
         identifier: &str,
     ) -> Result<Option<services::models::ModelWithPricing>> {
         let model_opt = self.resolve_and_get_model(identifier).await?;
-        Ok(model_opt.map(|m| services::models::ModelWithPricing {
-            id: m.id,
-            model_name: m.model_name,
-            model_display_name: m.model_display_name,
-            model_description: m.model_description,
-            model_icon: m.model_icon,
-            input_cost_per_token: m.input_cost_per_token,
-            output_cost_per_token: m.output_cost_per_token,
-            cost_per_image: m.cost_per_image,
-            cache_read_cost_per_token: m.cache_read_cost_per_token,
-            context_length: m.context_length,
-            verifiable: m.verifiable,
-            aliases: m.aliases,
-            owned_by: m.owned_by,
-            provider_type: m.provider_type,
-            provider_config: m.provider_config,
-            attestation_supported: m.attestation_supported,
-            input_modalities: m.input_modalities,
-            output_modalities: m.output_modalities,
-            inference_url: m.inference_url,
-            hugging_face_id: m.hugging_face_id,
-            quantization: m.quantization,
-            max_output_length: m.max_output_length,
-            supported_sampling_parameters: m.supported_sampling_parameters,
-            supported_features: m.supported_features,
-            datacenters: m.datacenters,
-            is_ready: m.is_ready,
-            deprecation_date: m.deprecation_date,
-            openrouter_slug: m.openrouter_slug,
-            created_at: m.created_at,
-        }))
+        Ok(model_opt.map(model_with_pricing))
+    }
+
+    async fn resolve_and_get_model_for_organization(
+        &self,
+        organization_id: uuid::Uuid,
+        identifier: &str,
+    ) -> Result<Option<services::models::ModelWithPricing>> {
+        let model_opt = self
+            .resolve_and_get_model_for_organization(Some(organization_id), identifier)
+            .await?;
+        Ok(model_opt.map(model_with_pricing))
     }
 
     async fn get_configured_model_names(&self) -> Result<Vec<String>> {
@@ -70,6 +70,33 @@ impl ModelAliasRepository {
         Ok(aliases)
     }
 
+    /// Get the names of a model's active aliases, sorted by name
+    pub async fn get_active_alias_names(&self, canonical_model_id: &Uuid) -> Result<Vec<String>> {
+        let rows = retry_db!("get_active_model_alias_names", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query(
+                    r#"
+                    SELECT alias_name
+                    FROM model_aliases
+                    WHERE canonical_model_id = $1 AND is_active = true
+                    ORDER BY alias_name ASC
+                    "#,
+                    &[&canonical_model_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(rows.iter().map(|row| row.get("alias_name")).collect())
+    }
+
     /// Helper method to convert database row to ModelAlias
     fn row_to_alias(&self, row: &Row) -> ModelAlias {
         ModelAlias {
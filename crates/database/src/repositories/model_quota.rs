@@ -0,0 +1,174 @@
+use crate::pool::DbPool;
+use crate::repositories::utils::map_db_error;
+use crate::retry_db;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use services::common::RepositoryError;
+use services::usage::{ModelQuota, ModelQuotaPeriod};
+use tokio_postgres::Row;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct ModelQuotaRepository {
+    pool: DbPool,
+}
+
+impl ModelQuotaRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_quota(row: &Row) -> Result<ModelQuota> {
+        let period: String = row.get("period");
+        Ok(ModelQuota {
+            organization_id: row.get("organization_id"),
+            model_name: row.get("model_name"),
+            period: ModelQuotaPeriod::parse(&period)
+                .with_context(|| format!("Unknown model quota period '{period}'"))?,
+            token_limit: row.get("token_limit"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl services::usage::ModelQuotaRepository for ModelQuotaRepository {
+    async fn list_quotas(&self, organization_id: Uuid) -> Result<Vec<ModelQuota>> {
+        let rows = retry_db!("list_model_quotas", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query(
+                    r#"
+                    SELECT q.organization_id, m.model_name, q.period, q.token_limit,
+                           q.created_at, q.updated_at
+                    FROM model_quotas q
+                    JOIN models m ON m.id = q.model_id
+                    WHERE q.organization_id = $1
+                    ORDER BY m.model_name, q.period
+                    "#,
+                    &[&organization_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        rows.iter().map(Self::row_to_quota).collect()
+    }
+
+    async fn get_model_tokens_since(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+        since: DateTime<Utc>,
+    ) -> Result<i64> {
+        let row = retry_db!("get_model_tokens_since", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_one(
+                    r#"
+                    SELECT COALESCE(SUM(l.total_tokens), 0)::BIGINT AS used_tokens
+                    FROM organization_usage_log l
+                    JOIN models m ON m.id = l.model_id
+                    WHERE l.organization_id = $1
+                      AND m.model_name = $2
+                      AND l.created_at >= $3
+                    "#,
+                    &[&organization_id, &model_name, &since],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(row.get("used_tokens"))
+    }
+
+    async fn upsert_quota(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+        period: ModelQuotaPeriod,
+        token_limit: i64,
+    ) -> Result<Option<ModelQuota>> {
+        let period = period.as_str();
+        let row = retry_db!("upsert_model_quota", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt(
+                    r#"
+                    WITH target AS (
+                        SELECT id, model_name
+                        FROM models
+                        WHERE model_name = $2 AND is_active = true
+                    )
+                    INSERT INTO model_quotas (organization_id, model_id, period, token_limit)
+                    SELECT $1, target.id, $3, $4 FROM target
+                    ON CONFLICT (organization_id, model_id, period) DO UPDATE
+                    SET token_limit = EXCLUDED.token_limit,
+                        updated_at = NOW()
+                    RETURNING organization_id,
+                              (SELECT model_name FROM target) AS model_name,
+                              period, token_limit, created_at, updated_at
+                    "#,
+                    &[&organization_id, &model_name, &period, &token_limit],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        row.as_ref().map(Self::row_to_quota).transpose()
+    }
+
+    async fn delete_quota(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+        period: ModelQuotaPeriod,
+    ) -> Result<bool> {
+        let period = period.as_str();
+        let deleted = retry_db!("delete_model_quota", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .execute(
+                    r#"
+                    DELETE FROM model_quotas q
+                    USING models m
+                    WHERE m.id = q.model_id
+                      AND q.organization_id = $1
+                      AND m.model_name = $2
+                      AND q.period = $3
+                    "#,
+                    &[&organization_id, &model_name, &period],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(deleted > 0)
+    }
+}
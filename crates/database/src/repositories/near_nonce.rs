@@ -1,6 +1,6 @@
 use crate::pool::DbPool;
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use services::auth::NearNonceRepository;
 
 pub struct PostgresNearNonceRepository {
@@ -29,12 +29,44 @@ impl NearNonceRepository for PostgresNearNonceRepository {
     async fn cleanup_expired_nonces(&self) -> anyhow::Result<u64> {
         let client = self.pool.get().await?;
         let cutoff = Utc::now() - Duration::minutes(10);
-        let deleted = client
+        let deleted_used = client
             .execute(
                 "DELETE FROM near_used_nonces WHERE used_at < $1",
                 &[&cutoff],
             )
             .await?;
-        Ok(deleted)
+        let deleted_issued = client
+            .execute(
+                "DELETE FROM near_issued_nonces WHERE expires_at < NOW()",
+                &[],
+            )
+            .await?;
+        Ok(deleted_used + deleted_issued)
+    }
+
+    async fn store_issued_nonce(
+        &self,
+        nonce_hex: &str,
+        expires_at: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        client
+            .execute(
+                "INSERT INTO near_issued_nonces (nonce_hex, expires_at) VALUES ($1, $2)",
+                &[&nonce_hex, &expires_at],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn is_issued_nonce_active(&self, nonce_hex: &str) -> anyhow::Result<bool> {
+        let client = self.pool.get().await?;
+        let row = client
+            .query_opt(
+                "SELECT 1 FROM near_issued_nonces WHERE nonce_hex = $1 AND expires_at > NOW()",
+                &[&nonce_hex],
+            )
+            .await?;
+        Ok(row.is_some())
     }
 }
@@ -342,54 +342,81 @@ impl PgOrganizationRepository {
         request: DbAddOrganizationMemberRequest,
         invited_by: Uuid,
     ) -> Result<DbOrganizationMember, RepositoryError> {
-        // Check if user is already a member
-        let existing = retry_db!("check_if_user_is_member", {
-            let client = self
-                .pool
-                .get()
-                .await
-                .context("Failed to get database connection")
-                .map_err(RepositoryError::PoolError)?;
-
-            client
-            .query_opt(
-                "SELECT * FROM organization_members WHERE organization_id = $1 AND user_id = $2",
-                &[&org_id, &request.user_id],
-            )
-            .await
-            .map_err(map_db_error)
-        })?;
-
-        if existing.is_some() {
-            return Err(RepositoryError::AlreadyExists);
-        }
-
         let id = Uuid::new_v4();
 
         let row = retry_db!("add_member_to_organization", {
             let now = Utc::now();
-            let client = self
+            let mut client = self
                 .pool
                 .get()
                 .await
                 .context("Failed to get database connection")
                 .map_err(RepositoryError::PoolError)?;
 
-            client.query_one(
-            r#"
+            let transaction = client
+                .transaction()
+                .await
+                .context("Failed to start transaction")
+                .map_err(RepositoryError::DatabaseError)?;
+
+            // Lock the organization row so concurrent adds serialize on the
+            // seat count below.
+            let max_members: Option<i32> = transaction
+                .query_opt(
+                    "SELECT max_members FROM organizations WHERE id = $1 FOR UPDATE",
+                    &[&org_id],
+                )
+                .await
+                .map_err(map_db_error)?
+                .and_then(|row| row.get("max_members"));
+
+            let existing = transaction
+                .query_opt(
+                    "SELECT 1 FROM organization_members WHERE organization_id = $1 AND user_id = $2",
+                    &[&org_id, &request.user_id],
+                )
+                .await
+                .map_err(map_db_error)?;
+            if existing.is_some() {
+                return Err(RepositoryError::AlreadyExists);
+            }
+
+            if let Some(max_members) = max_members {
+                let members: i64 = transaction
+                    .query_one(
+                        "SELECT COUNT(*) AS count FROM organization_members WHERE organization_id = $1",
+                        &[&org_id],
+                    )
+                    .await
+                    .map_err(map_db_error)?
+                    .get("count");
+                if members >= i64::from(max_members) {
+                    return Err(RepositoryError::SeatLimitExceeded);
+                }
+            }
+
+            let row = transaction
+                .query_one(
+                    r#"
             INSERT INTO organization_members (id, organization_id, user_id, role, joined_at, invited_by)
             VALUES ($1, $2, $3, $4, $5, $6)
             RETURNING *
             "#,
-            &[
-                &id,
-                &org_id,
-                &request.user_id,
-                &request.role.to_string().to_lowercase(),
-                &now,
-                &invited_by,
-            ],
-        ).await.map_err(map_db_error)
+                    &[
+                        &id,
+                        &org_id,
+                        &request.user_id,
+                        &request.role.to_string().to_lowercase(),
+                        &now,
+                        &invited_by,
+                    ],
+                )
+                .await
+                .map_err(map_db_error)?;
+
+            transaction.commit().await.map_err(map_db_error)?;
+
+            Ok(row)
         })?;
 
         debug!(
@@ -465,8 +492,16 @@ impl PgOrganizationRepository {
         org_id: Uuid,
         limit: i64,
         offset: i64,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<DbOrganizationMember>, RepositoryError> {
-        let rows = retry_db!("list_members_with_pagination", {
+        let keyset = KeysetSql::new("joined_at", "user_id", false, cursor.as_ref(), 4);
+        let query = format!(
+            "SELECT * FROM organization_members WHERE organization_id = $1{} ORDER BY {} LIMIT $2 OFFSET $3",
+            keyset.predicate, keyset.order_by
+        );
+        let (cursor_key, cursor_id) = KeysetSql::params(cursor.as_ref());
+
+        let mut rows = retry_db!("list_members_with_pagination", {
             let client = self
                 .pool
                 .get()
@@ -474,14 +509,17 @@ impl PgOrganizationRepository {
                 .context("Failed to get database connection")
                 .map_err(RepositoryError::PoolError)?;
 
-            client
-            .query(
-                "SELECT * FROM organization_members WHERE organization_id = $1 ORDER BY joined_at DESC LIMIT $2 OFFSET $3",
-                &[&org_id, &limit, &offset],
-            )
-            .await
-            .map_err(map_db_error)
+            let mut params: Vec<&(dyn tokio_postgres::types::ToSql + Sync)> =
+                vec![&org_id, &limit, &offset];
+            if cursor.is_some() {
+                params.push(&cursor_key);
+                params.push(&cursor_id);
+            }
+            client.query(&query, &params).await.map_err(map_db_error)
         })?;
+        if keyset.reversed {
+            rows.reverse();
+        }
 
         rows.into_iter()
             .map(|row| {
@@ -729,9 +767,10 @@ impl OrganizationRepository for PgOrganizationRepository {
         org_id: Uuid,
         limit: i64,
         offset: i64,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<OrganizationMember>, RepositoryError> {
         let db_members = self
-            .list_members_paginated_internal(org_id, limit, offset)
+            .list_members_paginated_internal(org_id, limit, offset, cursor)
             .await?;
         db_members
             .into_iter()
@@ -763,6 +802,29 @@ impl OrganizationRepository for PgOrganizationRepository {
         Ok(row.get("count"))
     }
 
+    async fn get_max_members(&self, org_id: Uuid) -> Result<Option<i64>, RepositoryError> {
+        let row = retry_db!("get_organization_max_members", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt(
+                    "SELECT max_members FROM organizations WHERE id = $1",
+                    &[&org_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(row
+            .and_then(|r| r.get::<_, Option<i32>>("max_members"))
+            .map(i64::from))
+    }
+
     async fn count_organizations_by_user(&self, user_id: Uuid) -> Result<i64, RepositoryError> {
         let row = retry_db!("count_organizations_by_user", {
             let client = self
@@ -796,6 +858,7 @@ impl OrganizationRepository for PgOrganizationRepository {
         offset: i64,
         order_by: Option<OrganizationOrderBy>,
         order_direction: Option<OrganizationOrderDirection>,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<Organization>, RepositoryError> {
         let order_by = order_by.unwrap_or(OrganizationOrderBy::CreatedAt);
         let order_direction = order_direction.unwrap_or(OrganizationOrderDirection::Asc);
@@ -804,12 +867,16 @@ impl OrganizationRepository for PgOrganizationRepository {
             OrganizationOrderBy::CreatedAt => "created_at",
         };
 
-        let order_dir = match order_direction {
-            OrganizationOrderDirection::Asc => "ASC",
-            OrganizationOrderDirection::Desc => "DESC",
-        };
+        let keyset = KeysetSql::new(
+            &format!("o.{order_by_column}"),
+            "o.id",
+            matches!(order_direction, OrganizationOrderDirection::Asc),
+            cursor.as_ref(),
+            4,
+        );
+        let (cursor_key, cursor_id) = KeysetSql::params(cursor.as_ref());
 
-        let rows = retry_db!("list_organizations_by_user", {
+        let mut rows = retry_db!("list_organizations_by_user", {
             let client = self
                 .pool
                 .get()
@@ -817,22 +884,33 @@ impl OrganizationRepository for PgOrganizationRepository {
                 .context("Failed to get database connection")
                 .map_err(RepositoryError::PoolError)?;
 
+            let mut params: Vec<&(dyn tokio_postgres::types::ToSql + Sync)> =
+                vec![&user_id, &limit, &offset];
+            if cursor.is_some() {
+                params.push(&cursor_key);
+                params.push(&cursor_id);
+            }
             client
                 .query(
                     &format!(
                         "
                     SELECT DISTINCT o.* FROM organizations o
                     INNER JOIN organization_members om ON o.id = om.organization_id
-                    WHERE om.user_id = $1 AND o.is_active = true
-                    ORDER BY o.{order_by_column} {order_dir}
+                    WHERE om.user_id = $1 AND o.is_active = true{predicate}
+                    ORDER BY {order_by}
                     LIMIT $2 OFFSET $3
-                "
+                ",
+                        predicate = keyset.predicate,
+                        order_by = keyset.order_by,
                     ),
-                    &[&user_id, &limit, &offset],
+                    &params,
                 )
                 .await
                 .map_err(map_db_error)
         })?;
+        if keyset.reversed {
+            rows.reverse();
+        }
 
         let mut organizations = Vec::new();
         for row in rows {
@@ -856,6 +934,7 @@ impl OrganizationRepository for PgOrganizationRepository {
         offset: i64,
         order_by: Option<OrganizationOrderBy>,
         order_direction: Option<OrganizationOrderDirection>,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<OrganizationWithRole>, RepositoryError> {
         let order_by = order_by.unwrap_or(OrganizationOrderBy::CreatedAt);
         let order_direction = order_direction.unwrap_or(OrganizationOrderDirection::Asc);
@@ -864,12 +943,16 @@ impl OrganizationRepository for PgOrganizationRepository {
             OrganizationOrderBy::CreatedAt => "created_at",
         };
 
-        let order_dir = match order_direction {
-            OrganizationOrderDirection::Asc => "ASC",
-            OrganizationOrderDirection::Desc => "DESC",
-        };
+        let keyset = KeysetSql::new(
+            &format!("o.{order_by_column}"),
+            "o.id",
+            matches!(order_direction, OrganizationOrderDirection::Asc),
+            cursor.as_ref(),
+            4,
+        );
+        let (cursor_key, cursor_id) = KeysetSql::params(cursor.as_ref());
 
-        let rows = retry_db!("list_organizations_with_roles_by_user", {
+        let mut rows = retry_db!("list_organizations_with_roles_by_user", {
             let client = self
                 .pool
                 .get()
@@ -877,6 +960,12 @@ impl OrganizationRepository for PgOrganizationRepository {
                 .context("Failed to get database connection")
                 .map_err(RepositoryError::PoolError)?;
 
+            let mut params: Vec<&(dyn tokio_postgres::types::ToSql + Sync)> =
+                vec![&user_id, &limit, &offset];
+            if cursor.is_some() {
+                params.push(&cursor_key);
+                params.push(&cursor_id);
+            }
             client
                 .query(
                     &format!(
@@ -891,16 +980,21 @@ impl OrganizationRepository for PgOrganizationRepository {
                         ORDER BY joined_at ASC
                         LIMIT 1
                     ) owner_om ON true
-                    WHERE om.user_id = $1 AND o.is_active = true
-                    ORDER BY o.{order_by_column} {order_dir}
+                    WHERE om.user_id = $1 AND o.is_active = true{predicate}
+                    ORDER BY {order_by}
                     LIMIT $2 OFFSET $3
-                "
+                ",
+                        predicate = keyset.predicate,
+                        order_by = keyset.order_by,
                     ),
-                    &[&user_id, &limit, &offset],
+                    &params,
                 )
                 .await
                 .map_err(map_db_error)
         })?;
+        if keyset.reversed {
+            rows.reverse();
+        }
 
         rows.into_iter()
             .map(|row| {
@@ -960,3 +1054,76 @@ impl services::completions::ports::OrganizationConcurrentLimitRepository
         }))
     }
 }
+
+// Implementation of OrganizationModelAccessRepository for completions service
+#[async_trait]
+impl services::completions::ports::OrganizationModelAccessRepository for PgOrganizationRepository {
+    async fn get_allowed_models(&self, org_id: Uuid) -> Result<Option<Vec<String>>> {
+        let row = retry_db!("get_organization_allowed_models", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt(
+                    "SELECT allowed_models FROM organizations WHERE id = $1 AND is_active = true",
+                    &[&org_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(row.and_then(|r| r.get::<_, Option<Vec<String>>>("allowed_models")))
+    }
+}
+
+/// SQL fragments for keyset pagination over `(sort_column, id_column)`.
+struct KeysetSql {
+    /// Extra `AND (...)` WHERE predicate; empty without a cursor. References
+    /// `$first_param` (sort key) and `$first_param + 1` (id).
+    predicate: String,
+    order_by: String,
+    /// Paging backwards scans in reverse list order; flip rows afterwards.
+    reversed: bool,
+}
+
+impl KeysetSql {
+    fn new(
+        sort_column: &str,
+        id_column: &str,
+        ascending: bool,
+        cursor: Option<&PageCursor>,
+        first_param: usize,
+    ) -> Self {
+        let reversed = matches!(cursor, Some(PageCursor::Before(_)));
+        let scan_ascending = ascending != reversed;
+        let (comparison, direction) = if scan_ascending {
+            (">", "ASC")
+        } else {
+            ("<", "DESC")
+        };
+        let predicate = match cursor {
+            Some(_) => format!(
+                " AND ({sort_column}, {id_column}) {comparison} (${first_param}, ${})",
+                first_param + 1
+            ),
+            None => String::new(),
+        };
+        Self {
+            predicate,
+            order_by: format!("{sort_column} {direction}, {id_column} {direction}"),
+            reversed,
+        }
+    }
+
+    /// Bind values for the predicate (placeholders when there is no cursor).
+    fn params(cursor: Option<&PageCursor>) -> (chrono::DateTime<Utc>, Uuid) {
+        match cursor {
+            Some(PageCursor::After(c) | PageCursor::Before(c)) => (c.sort_key, c.id),
+            None => (chrono::DateTime::<Utc>::UNIX_EPOCH, Uuid::nil()),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::pool::DbPool;
+use crate::repositories::utils::map_db_error;
+use crate::retry_db;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use services::common::RepositoryError;
+use services::models::OrganizationModelAlias;
+use tokio_postgres::Row;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct OrganizationModelAliasRepository {
+    pool: DbPool,
+}
+
+impl OrganizationModelAliasRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_alias(row: &Row) -> OrganizationModelAlias {
+        OrganizationModelAlias {
+            organization_id: row.get("organization_id"),
+            alias_name: row.get("alias_name"),
+            canonical_model_name: row.get("canonical_model_name"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+}
+
+#[async_trait]
+impl services::models::OrganizationModelAliasRepository for OrganizationModelAliasRepository {
+    async fn list_aliases(&self, organization_id: Uuid) -> Result<Vec<OrganizationModelAlias>> {
+        let rows = retry_db!("list_organization_model_aliases", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query(
+                    r#"
+                    SELECT oma.organization_id, oma.alias_name,
+                           m.model_name AS canonical_model_name,
+                           oma.created_at, oma.updated_at
+                    FROM organization_model_aliases oma
+                    JOIN models m ON m.id = oma.canonical_model_id
+                    WHERE oma.organization_id = $1
+                    ORDER BY oma.alias_name
+                    "#,
+                    &[&organization_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(rows.iter().map(Self::row_to_alias).collect())
+    }
+
+    async fn upsert_alias(
+        &self,
+        organization_id: Uuid,
+        alias_name: &str,
+        canonical_model_name: &str,
+    ) -> Result<Option<OrganizationModelAlias>> {
+        let row = retry_db!("upsert_organization_model_alias", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt(
+                    r#"
+                    WITH target AS (
+                        SELECT id, model_name
+                        FROM models
+                        WHERE model_name = $3 AND is_active = true
+                    )
+                    INSERT INTO organization_model_aliases (
+                        organization_id, alias_name, canonical_model_id
+                    )
+                    SELECT $1, $2, target.id FROM target
+                    ON CONFLICT (organization_id, alias_name) DO UPDATE
+                    SET canonical_model_id = EXCLUDED.canonical_model_id,
+                        updated_at = NOW()
+                    RETURNING organization_id, alias_name,
+                              (SELECT model_name FROM target) AS canonical_model_name,
+                              created_at, updated_at
+                    "#,
+                    &[&organization_id, &alias_name, &canonical_model_name],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(row.as_ref().map(Self::row_to_alias))
+    }
+
+    async fn delete_alias(&self, organization_id: Uuid, alias_name: &str) -> Result<bool> {
+        let deleted = retry_db!("delete_organization_model_alias", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .execute(
+                    "DELETE FROM organization_model_aliases WHERE organization_id = $1 AND alias_name = $2",
+                    &[&organization_id, &alias_name],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(deleted > 0)
+    }
+}
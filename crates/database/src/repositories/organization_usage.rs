@@ -63,6 +63,37 @@ impl OrganizationUsageRepository {
         Ok(total_spend)
     }
 
+    /// Get total spend for a workspace from `since` (inclusive) onwards
+    pub async fn get_workspace_spend_since(
+        &self,
+        workspace_id: Uuid,
+        since: chrono::DateTime<Utc>,
+    ) -> Result<i64> {
+        let row = retry_db!("get_workspace_spend_since", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_one(
+                    r#"
+                    SELECT COALESCE(SUM(total_cost), 0)::BIGINT as total_spend
+                    FROM organization_usage_log
+                    WHERE workspace_id = $1 AND created_at >= $2
+                    "#,
+                    &[&workspace_id, &since],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        let total_spend: i64 = row.get("total_spend");
+        Ok(total_spend)
+    }
+
     /// Record usage and update balance atomically.
     ///
     /// When `inference_id` is set, this is idempotent: duplicate inserts for the
@@ -101,8 +132,8 @@ impl OrganizationUsageRepository {
                         input_cost, output_cost, total_cost,
                         inference_type, created_at, ttft_ms, avg_itl_ms, inference_id,
                         provider_request_id, stop_reason, response_id, image_count,
-                        served_provider_tier, served_provider_type, served_via_fallback
-                    ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25)
+                        served_provider_tier, served_provider_type, served_via_fallback, estimated
+                    ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26)
                     ON CONFLICT (organization_id, inference_id) WHERE inference_id IS NOT NULL DO NOTHING
                     RETURNING *
                     "#,
@@ -132,6 +163,7 @@ impl OrganizationUsageRepository {
                         &served_provider_tier,
                         &served_provider_type,
                         &request.served_via_fallback,
+                        &request.estimated,
                     ],
                 )
                 .await
@@ -460,6 +492,46 @@ impl OrganizationUsageRepository {
             .collect())
     }
 
+    /// Spend per organization per clock hour since `start_date`.
+    pub async fn get_hourly_spend_since(
+        &self,
+        start_date: chrono::DateTime<Utc>,
+    ) -> Result<Vec<HourlySpend>> {
+        let rows = retry_db!("get_hourly_organization_spend", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query(
+                    r#"
+                    SELECT
+                        organization_id,
+                        date_trunc('hour', created_at)       AS hour,
+                        COALESCE(SUM(total_cost), 0)::BIGINT AS total_cost
+                    FROM organization_usage_log
+                    WHERE created_at >= $1
+                    GROUP BY organization_id, date_trunc('hour', created_at)
+                    "#,
+                    &[&start_date],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| HourlySpend {
+                organization_id: row.get("organization_id"),
+                hour: row.get("hour"),
+                total_cost: row.get("total_cost"),
+            })
+            .collect())
+    }
+
     fn row_to_usage_log(&self, row: &Row, was_inserted: bool) -> Result<OrganizationUsageLog> {
         // Parse stop_reason from string to enum
         let stop_reason_str: Option<String> = row.get("stop_reason");
@@ -542,10 +614,13 @@ impl OrganizationUsageRepository {
     }
 
     /// Get the stop reason for a specific provider request ID (e.g., chatcmpl-xxx)
-    /// Used to check if a chat completion was stopped due to client disconnect
+    /// Used to check if a chat completion was stopped due to client disconnect.
+    /// Rows recorded without a provider request ID fall back to matching any of
+    /// `inference_ids`; a direct provider request ID match wins.
     pub async fn get_stop_reason_by_provider_request_id(
         &self,
         provider_request_id: &str,
+        inference_ids: &[Uuid],
     ) -> Result<Option<StopReason>> {
         let row_opt = retry_db!("get_stop_reason_by_provider_request_id", {
             let client = self
@@ -557,8 +632,12 @@ impl OrganizationUsageRepository {
 
             client
                 .query_opt(
-                    r#"SELECT stop_reason FROM organization_usage_log WHERE provider_request_id = $1"#,
-                    &[&provider_request_id],
+                    r#"SELECT stop_reason FROM organization_usage_log
+                       WHERE provider_request_id = $1
+                          OR (provider_request_id IS NULL AND inference_id = ANY($2))
+                       ORDER BY (provider_request_id IS NOT NULL) DESC
+                       LIMIT 1"#,
+                    &[&provider_request_id, &inference_ids],
                 )
                 .await
                 .map_err(map_db_error)
@@ -570,6 +649,34 @@ impl OrganizationUsageRepository {
         }))
     }
 
+    /// Resolve a hashed inference ID to the provider request ID (e.g., chatcmpl-xxx)
+    /// recorded with it. Used to serve chat signatures looked up by inference ID.
+    pub async fn get_provider_request_id_by_inference_id(
+        &self,
+        inference_id: Uuid,
+    ) -> Result<Option<String>> {
+        let row_opt = retry_db!("get_provider_request_id_by_inference_id", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt(
+                    r#"SELECT provider_request_id FROM organization_usage_log
+                       WHERE inference_id = $1 AND provider_request_id IS NOT NULL
+                       LIMIT 1"#,
+                    &[&inference_id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(row_opt.map(|row| row.get("provider_request_id")))
+    }
+
     /// Get costs by inference IDs (for HuggingFace billing integration)
     /// Returns costs for all requested inference_ids that belong to the organization
     /// Missing inference_ids will have cost = 0
@@ -644,6 +751,13 @@ pub struct UsageByModel {
     pub request_count: i64,
 }
 
+#[derive(Debug, Clone)]
+pub struct HourlySpend {
+    pub organization_id: Uuid,
+    pub hour: chrono::DateTime<Utc>,
+    pub total_cost: i64,
+}
+
 fn parse_served_provider_tier(value: Option<String>) -> Result<Option<ServedProviderTier>> {
     value
         .as_deref()
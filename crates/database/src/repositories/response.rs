@@ -571,6 +571,8 @@ impl ResponseRepositoryTrait for PgResponseRepository {
         // Update the response in the database
         // Note: output_message column was removed in migration V0021
         // Messages are now stored as response_items instead
+        // A cancelled response is final: a completion that lands after the
+        // cancel leaves it untouched.
         let rows_affected = retry_db!("update_response", {
             let now = Utc::now();
             let client = self
@@ -588,6 +590,7 @@ impl ResponseRepositoryTrait for PgResponseRepository {
                         usage = COALESCE($2, usage),
                         updated_at = $3
                     WHERE id = $4 AND workspace_id = $5
+                      AND status <> 'cancelled'
                     "#,
                     &[&status_str, &usage, &now, &response_uuid, &workspace_id.0],
                 )
@@ -596,8 +599,9 @@ impl ResponseRepositoryTrait for PgResponseRepository {
         })?;
 
         if rows_affected == 0 {
-            // Response not found or doesn't belong to this workspace
-            return Ok(None);
+            // Not found, in another workspace, or cancelled; the latter comes
+            // back unchanged
+            return self.get_by_id(response_id, workspace_id).await;
         }
 
         // Fetch the updated response
@@ -727,10 +731,37 @@ impl ResponseRepositoryTrait for PgResponseRepository {
 
     async fn cancel(
         &self,
-        _response_id: ResponseId,
-        _workspace_id: WorkspaceId,
+        response_id: ResponseId,
+        workspace_id: WorkspaceId,
     ) -> Result<Option<ResponseObject>, anyhow::Error> {
-        unimplemented!("cancel not yet implemented")
+        // Only responses that are still running can move to cancelled; a
+        // finished response is returned unchanged so the caller can tell the
+        // two cases apart.
+        retry_db!("cancel_response", {
+            let now = Utc::now();
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .execute(
+                    r#"
+                    UPDATE responses
+                    SET status = 'cancelled',
+                        updated_at = $1
+                    WHERE id = $2 AND workspace_id = $3
+                      AND status IN ('in_progress', 'queued')
+                    "#,
+                    &[&now, &response_id.0, &workspace_id.0],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        self.get_by_id(response_id, workspace_id).await
     }
 
     async fn list_by_workspace(
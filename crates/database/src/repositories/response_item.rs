@@ -216,10 +216,62 @@ impl PgResponseItemsRepository {
         Ok(item)
     }
 
+    /// Resolve an `after` item cursor to its `(created_at, id)` position.
+    ///
+    /// The cursor must reference an item that belongs to this conversation and
+    /// this workspace. Unknown and foreign cursors are rejected identically
+    /// (`RepositoryError::NotFound`) so they cannot be used to probe other
+    /// conversations or workspaces.
+    async fn conversation_item_cursor(
+        &self,
+        conversation_id: ConversationId,
+        workspace_id: &services::workspace::WorkspaceId,
+        after: Option<&str>,
+    ) -> Result<Option<(chrono::DateTime<Utc>, Uuid)>> {
+        let Some(after) = after else {
+            return Ok(None);
+        };
+        let after_uuid = Self::extract_uuid_from_item_id(after);
+
+        let cursor_row = retry_db!("validate_conversation_item_cursor", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt(
+                    r#"
+                    SELECT ri.created_at, ri.id
+                    FROM response_items ri
+                    JOIN responses r ON ri.response_id = r.id
+                    WHERE ri.id = $1
+                      AND ri.conversation_id = $2
+                      AND r.workspace_id = $3
+                    "#,
+                    &[&after_uuid, &conversation_id.0, &workspace_id.0],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        let Some(cursor_row) = cursor_row else {
+            return Err(anyhow::Error::new(RepositoryError::NotFound(
+                "pagination cursor".to_string(),
+            )));
+        };
+
+        let cursor_created_at: chrono::DateTime<Utc> = cursor_row.try_get("created_at")?;
+        let cursor_id: Uuid = cursor_row.try_get("id")?;
+        Ok(Some((cursor_created_at, cursor_id)))
+    }
+
     /// Helper method to extract or generate UUID from item ID string
     /// If the item_id is already a valid UUID or contains one (e.g., "msg_abc123"), use it.
     /// Otherwise, generate a new UUID (for external provider IDs like OpenAI's "call_xxx").
-    fn extract_uuid_from_item_id(item_id: &str) -> Uuid {
+    pub(crate) fn extract_uuid_from_item_id(item_id: &str) -> Uuid {
         // First try parsing as a UUID directly
         if let Ok(uuid) = Uuid::parse_str(item_id) {
             return uuid;
@@ -491,50 +543,9 @@ impl ResponseItemRepositoryTrait for PgResponseItemsRepository {
         after: Option<String>,
         limit: i64,
     ) -> Result<Vec<ResponseOutputItem>> {
-        // Extract UUID from the after item ID if provided
-        let after_uuid = after.as_ref().map(|id| Self::extract_uuid_from_item_id(id));
-
-        // Validate the pagination cursor before using it: it must reference an
-        // item that belongs to this conversation and this workspace. Unknown
-        // and foreign cursors are rejected identically so they cannot be used
-        // to probe other conversations or workspaces.
-        let after_position = if let Some(after_uuid) = after_uuid {
-            let cursor_row = retry_db!("validate_conversation_item_cursor", {
-                let client = self
-                    .pool
-                    .get()
-                    .await
-                    .context("Failed to get database connection")
-                    .map_err(RepositoryError::PoolError)?;
-
-                client
-                    .query_opt(
-                        r#"
-                        SELECT ri.created_at, ri.id
-                        FROM response_items ri
-                        JOIN responses r ON ri.response_id = r.id
-                        WHERE ri.id = $1
-                          AND ri.conversation_id = $2
-                          AND r.workspace_id = $3
-                        "#,
-                        &[&after_uuid, &conversation_id.0, &workspace_id.0],
-                    )
-                    .await
-                    .map_err(map_db_error)
-            })?;
-
-            let Some(cursor_row) = cursor_row else {
-                return Err(anyhow::Error::new(RepositoryError::NotFound(
-                    "pagination cursor".to_string(),
-                )));
-            };
-
-            let cursor_created_at: chrono::DateTime<Utc> = cursor_row.try_get("created_at")?;
-            let cursor_id: Uuid = cursor_row.try_get("id")?;
-            Some((cursor_created_at, cursor_id))
-        } else {
-            None
-        };
+        let after_position = self
+            .conversation_item_cursor(conversation_id, &workspace_id, after.as_deref())
+            .await?;
 
         let rows = retry_db!("list_response_items_by_conversation", {
             let client = self
@@ -602,4 +613,75 @@ impl ResponseItemRepositoryTrait for PgResponseItemsRepository {
 
         rows.into_iter().map(|row| self.row_to_item(row)).collect()
     }
+
+    async fn search_by_conversation(
+        &self,
+        conversation_id: ConversationId,
+        workspace_id: services::workspace::WorkspaceId,
+        query: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<ResponseOutputItem>> {
+        let after_position = self
+            .conversation_item_cursor(conversation_id, &workspace_id, after.as_deref())
+            .await?;
+
+        // Match against the text of message content parts only; escape LIKE
+        // metacharacters so the query is a literal substring.
+        let pattern = format!(
+            "%{}%",
+            query
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        );
+        let (cursor_created_at, cursor_id) = after_position.unzip();
+
+        let rows = retry_db!("search_response_items_by_conversation", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query(
+                    r#"
+                    SELECT
+                        ri.*,
+                        r.previous_response_id,
+                        r.next_response_ids,
+                        r.created_at as response_created_at,
+                        r.model
+                    FROM response_items ri
+                    JOIN responses r ON ri.response_id = r.id
+                    WHERE ri.conversation_id = $1
+                      AND r.workspace_id = $2
+                      AND ri.item->>'type' = 'message'
+                      AND jsonb_typeof(ri.item->'content') = 'array'
+                      AND EXISTS (
+                          SELECT 1
+                          FROM jsonb_array_elements(ri.item->'content') AS part
+                          WHERE part->>'text' ILIKE $3 ESCAPE '\'
+                      )
+                      AND ($4::timestamptz IS NULL OR (ri.created_at, ri.id) > ($4, $5))
+                    ORDER BY ri.created_at ASC, ri.id ASC
+                    LIMIT $6
+                    "#,
+                    &[
+                        &conversation_id.0,
+                        &workspace_id.0,
+                        &pattern,
+                        &cursor_created_at,
+                        &cursor_id,
+                        &limit,
+                    ],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        rows.into_iter().map(|row| self.row_to_item(row)).collect()
+    }
 }
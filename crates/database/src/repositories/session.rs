@@ -10,6 +10,12 @@ use std::sync::OnceLock;
 use tracing::debug;
 use uuid::Uuid;
 
+/// Granularity of `last_used_at` tracking, in seconds.
+const LAST_USED_RESOLUTION_SECS: f64 = 60.0;
+
+/// Maximum stored length of a derived device label.
+const MAX_DEVICE_LABEL_LEN: usize = 128;
+
 pub struct SessionRepository {
     pool: DbPool,
 }
@@ -52,6 +58,50 @@ impl SessionRepository {
         pattern.replace_all(user_agent, "").trim().to_string()
     }
 
+    /// Derive a short, human-readable device label ("Chrome on macOS") from a
+    /// raw User-Agent for session listings. Falls back to the first product
+    /// token (e.g. "curl") when no known browser or platform is recognized.
+    fn describe_device(user_agent: &str) -> Option<String> {
+        // Order matters: Edge and Opera UAs also mention Chrome, Chrome UAs
+        // mention Safari, and iOS/Android UAs mention macOS/Linux.
+        const BROWSERS: &[(&str, &str)] = &[
+            ("Edg/", "Edge"),
+            ("OPR/", "Opera"),
+            ("Firefox/", "Firefox"),
+            ("CriOS/", "Chrome"),
+            ("Chrome/", "Chrome"),
+            ("Safari/", "Safari"),
+        ];
+        const PLATFORMS: &[(&str, &str)] = &[
+            ("iPhone", "iOS"),
+            ("iPad", "iPadOS"),
+            ("Android", "Android"),
+            ("Windows", "Windows"),
+            ("Mac OS X", "macOS"),
+            ("CrOS", "ChromeOS"),
+            ("Linux", "Linux"),
+        ];
+
+        let find = |table: &[(&str, &'static str)]| {
+            table
+                .iter()
+                .find(|(needle, _)| user_agent.contains(needle))
+                .map(|(_, label)| *label)
+        };
+
+        let label = match (find(BROWSERS), find(PLATFORMS)) {
+            (Some(browser), Some(platform)) => format!("{browser} on {platform}"),
+            (Some(only), None) | (None, Some(only)) => only.to_string(),
+            (None, None) => user_agent
+                .split(['/', ' '])
+                .find(|token| !token.is_empty())?
+                .chars()
+                .take(MAX_DEVICE_LABEL_LEN)
+                .collect(),
+        };
+        Some(label)
+    }
+
     /// Create a new refresh token session
     pub async fn create(
         &self,
@@ -66,6 +116,7 @@ impl SessionRepository {
 
         // Normalize user agent to remove version numbers before storing
         let normalized_user_agent = Self::normalize_user_agent(&user_agent);
+        let device_label = Self::describe_device(&user_agent);
 
         let row = retry_db!("create_new_refresh_token", {
             let now = Utc::now();
@@ -88,9 +139,9 @@ impl SessionRepository {
                     r#"
             INSERT INTO refresh_tokens (
                 id, user_id, token_hash, created_at, expires_at,
-                ip_address, user_agent
+                ip_address, user_agent, device_label, last_used_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $4)
             RETURNING *
             "#,
                     &[
@@ -101,6 +152,7 @@ impl SessionRepository {
                         &expires_at,
                         &ip_address,
                         &normalized_user_agent,
+                        &device_label,
                     ],
                 )
                 .await
@@ -238,10 +290,42 @@ impl SessionRepository {
         Ok(result > 0)
     }
 
+    /// Record that a session was just used to authenticate.
+    ///
+    /// Writes are throttled to one per [`LAST_USED_RESOLUTION_SECS`] per
+    /// session so that authenticating every request does not turn into a
+    /// write per request.
+    pub async fn touch(&self, session_id: Uuid) -> Result<()> {
+        retry_db!("touch_refresh_token_session", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .execute(
+                    r#"
+                UPDATE refresh_tokens
+                SET last_used_at = NOW()
+                WHERE id = $1 AND last_used_at < NOW() - make_interval(secs => $2)
+                "#,
+                    &[&session_id, &LAST_USED_RESOLUTION_SECS],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(())
+    }
+
     /// Rotates a refresh token session.
     ///
     /// This operation atomically updates the token hash and expiration time in the database,
     /// invalidating the old token. This ensures that the previous token can no longer be used.
+    /// The retired hash is recorded in `refresh_token_rotations` together with its generation
+    /// so that a later replay can be detected by [`Self::revoke_family_on_reuse`].
     ///
     /// The old_token_hash is included in the WHERE clause to prevent race conditions where
     /// two requests try to rotate the same token simultaneously. If the token was already
@@ -274,10 +358,16 @@ impl SessionRepository {
             client
                 .query_opt(
                     r#"
-                UPDATE refresh_tokens
-                SET token_hash = $1, expires_at = $2
-                WHERE id = $3 AND token_hash = $4
-                RETURNING *
+                WITH rotated AS (
+                    UPDATE refresh_tokens
+                    SET token_hash = $1, expires_at = $2, generation = generation + 1
+                    WHERE id = $3 AND token_hash = $4
+                    RETURNING *
+                ), retired AS (
+                    INSERT INTO refresh_token_rotations (token_hash, family_id, generation)
+                    SELECT $4, id, generation - 1 FROM rotated
+                )
+                SELECT * FROM rotated
                 "#,
                     &[
                         &new_token_hash,
@@ -320,6 +410,41 @@ impl SessionRepository {
         Ok(result > 0)
     }
 
+    /// Revoke the token family a retired refresh token belongs to.
+    ///
+    /// If `session_token` was already rotated out of a live session, the
+    /// session row is deleted (its rotation history cascades) and its ID is
+    /// returned. Returns `None` for tokens that were never issued or whose
+    /// family is already gone.
+    pub async fn revoke_family_on_reuse(&self, session_token: &str) -> Result<Option<Uuid>> {
+        let token_hash = Self::hash_session_token(session_token);
+
+        let row = retry_db!("revoke_refresh_token_family_on_reuse", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt(
+                    r#"
+                DELETE FROM refresh_tokens
+                WHERE id = (
+                    SELECT family_id FROM refresh_token_rotations WHERE token_hash = $1
+                )
+                RETURNING id
+                "#,
+                    &[&token_hash],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        Ok(row.map(|row| row.get("id")))
+    }
+
     /// Revoke all refresh token sessions for a user
     pub async fn revoke_all_for_user(&self, user_id: Uuid) -> Result<usize> {
         let result = retry_db!("revoke_all_refresh_token_sessions", {
@@ -372,10 +497,27 @@ impl SessionRepository {
             expires_at: row.get("expires_at"),
             ip_address: row.get("ip_address"),
             user_agent: row.get("user_agent"),
+            generation: row.get("generation"),
+            device_label: row.get("device_label"),
+            last_used_at: row.get("last_used_at"),
         })
     }
 }
 
+fn to_service_session(db_session: Session) -> services::auth::Session {
+    services::auth::Session {
+        id: services::auth::SessionId(db_session.id),
+        user_id: services::auth::UserId(db_session.user_id),
+        token_hash: db_session.token_hash,
+        created_at: db_session.created_at,
+        expires_at: db_session.expires_at,
+        ip_address: db_session.ip_address,
+        user_agent: db_session.user_agent,
+        device_label: db_session.device_label,
+        last_used_at: db_session.last_used_at,
+    }
+}
+
 // Implement the service trait
 #[async_trait::async_trait]
 impl services::auth::SessionRepository for SessionRepository {
@@ -390,17 +532,7 @@ impl services::auth::SessionRepository for SessionRepository {
             .create(user_id.0, ip_address, user_agent, expires_in_hours)
             .await?;
 
-        let service_session = services::auth::Session {
-            id: services::auth::SessionId(db_session.id),
-            user_id: services::auth::UserId(db_session.user_id),
-            token_hash: db_session.token_hash,
-            created_at: db_session.created_at,
-            expires_at: db_session.expires_at,
-            ip_address: db_session.ip_address,
-            user_agent: db_session.user_agent,
-        };
-
-        Ok((service_session, token))
+        Ok((to_service_session(db_session), token))
     }
 
     async fn validate(
@@ -410,15 +542,7 @@ impl services::auth::SessionRepository for SessionRepository {
     ) -> anyhow::Result<Option<services::auth::Session>> {
         let maybe_session = self.validate(&session_token.0, user_agent).await?;
 
-        Ok(maybe_session.map(|db_session| services::auth::Session {
-            id: services::auth::SessionId(db_session.id),
-            user_id: services::auth::UserId(db_session.user_id),
-            token_hash: db_session.token_hash,
-            created_at: db_session.created_at,
-            expires_at: db_session.expires_at,
-            ip_address: db_session.ip_address,
-            user_agent: db_session.user_agent,
-        }))
+        Ok(maybe_session.map(to_service_session))
     }
 
     async fn get_by_id(
@@ -427,15 +551,7 @@ impl services::auth::SessionRepository for SessionRepository {
     ) -> anyhow::Result<Option<services::auth::Session>> {
         let maybe_session = self.get_by_id(session_id.0).await?;
 
-        Ok(maybe_session.map(|db_session| services::auth::Session {
-            id: services::auth::SessionId(db_session.id),
-            user_id: services::auth::UserId(db_session.user_id),
-            token_hash: db_session.token_hash,
-            created_at: db_session.created_at,
-            expires_at: db_session.expires_at,
-            ip_address: db_session.ip_address,
-            user_agent: db_session.user_agent,
-        }))
+        Ok(maybe_session.map(to_service_session))
     }
 
     async fn list_by_user(
@@ -444,18 +560,7 @@ impl services::auth::SessionRepository for SessionRepository {
     ) -> anyhow::Result<Vec<services::auth::Session>> {
         let db_sessions = self.list_by_user(user_id.0).await?;
 
-        Ok(db_sessions
-            .into_iter()
-            .map(|db_session| services::auth::Session {
-                id: services::auth::SessionId(db_session.id),
-                user_id: services::auth::UserId(db_session.user_id),
-                token_hash: db_session.token_hash,
-                created_at: db_session.created_at,
-                expires_at: db_session.expires_at,
-                ip_address: db_session.ip_address,
-                user_agent: db_session.user_agent,
-            })
-            .collect())
+        Ok(db_sessions.into_iter().map(to_service_session).collect())
     }
 
     async fn extend(
@@ -479,17 +584,21 @@ impl services::auth::SessionRepository for SessionRepository {
         let (db_session, token) =
             SessionRepository::rotate(self, session_id.0, old_token_hash, expires_in_hours).await?;
 
-        let service_session = services::auth::Session {
-            id: services::auth::SessionId(db_session.id),
-            user_id: services::auth::UserId(db_session.user_id),
-            token_hash: db_session.token_hash,
-            created_at: db_session.created_at,
-            expires_at: db_session.expires_at,
-            ip_address: db_session.ip_address,
-            user_agent: db_session.user_agent,
-        };
+        Ok((to_service_session(db_session), token))
+    }
 
-        Ok((service_session, token))
+    async fn touch(&self, session_id: services::auth::SessionId) -> anyhow::Result<()> {
+        self.touch(session_id.0).await
+    }
+
+    async fn revoke_family_on_reuse(
+        &self,
+        session_token: services::auth::SessionToken,
+    ) -> anyhow::Result<Option<services::auth::SessionId>> {
+        Ok(self
+            .revoke_family_on_reuse(&session_token.0)
+            .await?
+            .map(services::auth::SessionId))
     }
 
     async fn revoke_all_for_user(&self, user_id: services::auth::UserId) -> anyhow::Result<usize> {
@@ -500,3 +609,50 @@ impl services::auth::SessionRepository for SessionRepository {
         self.cleanup_expired().await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_device_recognizes_browser_and_platform() {
+        let cases = [
+            (
+                "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36",
+                "Chrome on macOS",
+            ),
+            (
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36 Edg/129.0.0.0",
+                "Edge on Windows",
+            ),
+            (
+                "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Mobile/15E148 Safari/604.1",
+                "Safari on iOS",
+            ),
+            (
+                "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Mobile Safari/537.36",
+                "Chrome on Android",
+            ),
+            (
+                "Mozilla/5.0 (X11; Linux x86_64; rv:131.0) Gecko/20100101 Firefox/131.0",
+                "Firefox on Linux",
+            ),
+        ];
+        for (user_agent, expected) in cases {
+            assert_eq!(
+                SessionRepository::describe_device(user_agent).as_deref(),
+                Some(expected),
+                "{user_agent}"
+            );
+        }
+    }
+
+    #[test]
+    fn describe_device_falls_back_to_product_token() {
+        assert_eq!(
+            SessionRepository::describe_device("curl/8.4.0").as_deref(),
+            Some("curl")
+        );
+        assert_eq!(SessionRepository::describe_device("   "), None);
+    }
+}
@@ -2,8 +2,8 @@ use crate::models::RecordUsageRequest;
 use crate::repositories::OrganizationUsageRepository;
 use chrono::{DateTime, Utc};
 use services::usage::ports::{
-    InferenceCost, InferenceUsageHistoryQuery, InferenceUsageReportQuery, InferenceUsageReportRow,
-    OrganizationBalanceInfo, UsageByModelEntry, UsageLogEntry,
+    HourlyOrganizationSpend, InferenceCost, InferenceUsageHistoryQuery, InferenceUsageReportQuery,
+    InferenceUsageReportRow, OrganizationBalanceInfo, UsageByModelEntry, UsageLogEntry,
 };
 use uuid::Uuid;
 
@@ -37,6 +37,7 @@ impl services::usage::ports::UsageRepository for OrganizationUsageRepository {
             served_provider_tier: request.provider_attribution.served_provider_tier,
             served_provider_type: request.provider_attribution.served_provider_type,
             served_via_fallback: request.provider_attribution.served_via_fallback,
+            estimated: request.estimated,
         };
 
         let log = self.record_usage(db_request).await?;
@@ -219,8 +220,17 @@ impl services::usage::ports::UsageRepository for OrganizationUsageRepository {
     async fn get_stop_reason_by_provider_request_id(
         &self,
         provider_request_id: &str,
+        inference_ids: &[Uuid],
     ) -> anyhow::Result<Option<services::usage::StopReason>> {
-        self.get_stop_reason_by_provider_request_id(provider_request_id)
+        self.get_stop_reason_by_provider_request_id(provider_request_id, inference_ids)
+            .await
+    }
+
+    async fn get_provider_request_id_by_inference_id(
+        &self,
+        inference_id: Uuid,
+    ) -> anyhow::Result<Option<String>> {
+        self.get_provider_request_id_by_inference_id(inference_id)
             .await
     }
 
@@ -246,6 +256,22 @@ impl services::usage::ports::UsageRepository for OrganizationUsageRepository {
             .collect())
     }
 
+    async fn get_hourly_spend_since(
+        &self,
+        start_date: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<HourlyOrganizationSpend>> {
+        let rows = self.get_hourly_spend_since(start_date).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| HourlyOrganizationSpend {
+                organization_id: r.organization_id,
+                hour: r.hour,
+                total_cost: r.total_cost,
+            })
+            .collect())
+    }
+
     async fn list_inference_usage_report(
         &self,
         query: InferenceUsageReportQuery,
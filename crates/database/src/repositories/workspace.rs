@@ -283,6 +283,12 @@ impl WorkspaceRepository {
         if let Some(ref settings) = request.settings {
             query.push_str(&format!(", settings = ${param_index}"));
             params.push(settings);
+            param_index += 1;
+        }
+
+        if let Some(ref default_params) = request.default_params {
+            query.push_str(&format!(", default_params = ${param_index}"));
+            params.push(default_params);
         }
 
         query.push_str(" WHERE id = $1 AND is_active = true RETURNING *");
@@ -310,6 +316,116 @@ impl WorkspaceRepository {
         }
     }
 
+    /// Set or clear (`None`) a workspace's monthly budget
+    pub async fn update_monthly_budget(
+        &self,
+        id: Uuid,
+        monthly_budget: Option<i64>,
+    ) -> Result<Option<Workspace>, RepositoryError> {
+        let row = retry_db!("update_workspace_monthly_budget", {
+            let client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            client
+                .query_opt(
+                    "UPDATE workspaces SET monthly_budget = $1, updated_at = NOW() WHERE id = $2 AND is_active = true RETURNING *",
+                    &[&monthly_budget, &id],
+                )
+                .await
+                .map_err(map_db_error)
+        })?;
+
+        match row {
+            Some(row) => Ok(Some(
+                self.row_to_workspace(row)
+                    .map_err(RepositoryError::DataConversionError)?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Move an active workspace from `from_organization_id` to
+    /// `to_organization_id`. Returns `None` when the workspace is missing,
+    /// inactive, or no longer owned by `from_organization_id`. The workspace's
+    /// batches and MCP connectors move with it; usage already logged keeps its
+    /// original `organization_id`.
+    pub async fn transfer(
+        &self,
+        id: Uuid,
+        from_organization_id: Uuid,
+        to_organization_id: Uuid,
+    ) -> Result<Option<Workspace>, RepositoryError> {
+        let row = retry_db!("transfer_workspace", {
+            let mut client = self
+                .pool
+                .get()
+                .await
+                .context("Failed to get database connection")
+                .map_err(RepositoryError::PoolError)?;
+
+            let transaction = client
+                .transaction()
+                .await
+                .context("Failed to start transaction")
+                .map_err(RepositoryError::DatabaseError)?;
+
+            let current = transaction
+                .query_opt(
+                    "SELECT organization_id FROM workspaces WHERE id = $1 AND is_active = true FOR UPDATE",
+                    &[&id],
+                )
+                .await
+                .map_err(map_db_error)?;
+
+            let owned_by_source = current
+                .map(|row| row.get::<_, Uuid>("organization_id") == from_organization_id)
+                .unwrap_or(false);
+            if !owned_by_source {
+                return Ok(None);
+            }
+
+            let row = transaction
+                .query_one(
+                    "UPDATE workspaces SET organization_id = $2, updated_at = NOW() WHERE id = $1 RETURNING *",
+                    &[&id, &to_organization_id],
+                )
+                .await
+                .map_err(map_db_error)?;
+
+            // Rows that carry both ids move with the workspace, so pending
+            // batches bill the new organization and connectors stay visible.
+            for statement in [
+                "UPDATE batches SET organization_id = $2, updated_at = NOW() WHERE workspace_id = $1",
+                "UPDATE mcp_connectors SET organization_id = $2, updated_at = NOW() WHERE workspace_id = $1",
+            ] {
+                transaction
+                    .execute(statement, &[&id, &to_organization_id])
+                    .await
+                    .map_err(map_db_error)?;
+            }
+
+            transaction
+                .commit()
+                .await
+                .context("Failed to commit transaction")
+                .map_err(RepositoryError::DatabaseError)?;
+
+            Ok(Some(row))
+        })?;
+
+        match row {
+            Some(row) => Ok(Some(
+                self.row_to_workspace(row)
+                    .map_err(RepositoryError::DataConversionError)?,
+            )),
+            None => Ok(None),
+        }
+    }
+
     /// Delete (deactivate) a workspace
     pub async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
         let rows_affected = retry_db!("deactivate_workspace", {
@@ -344,6 +460,8 @@ impl WorkspaceRepository {
             updated_at: row.get("updated_at"),
             is_active: row.get("is_active"),
             settings: row.get("settings"),
+            default_params: row.get("default_params"),
+            monthly_budget: row.get("monthly_budget"),
         })
     }
 
@@ -392,6 +510,8 @@ impl WorkspaceRepository {
                     updated_at: row.get("updated_at"),
                     is_active: row.get("is_active"),
                     settings: row.get("settings"),
+                    default_params: row.get("default_params"),
+                    monthly_budget: row.get("monthly_budget"),
                 };
 
                 let organization = crate::models::Organization {
@@ -529,11 +649,17 @@ impl services::workspace::ports::WorkspaceRepository for WorkspaceRepository {
         name: Option<String>,
         description: Option<String>,
         settings: Option<serde_json::Value>,
+        default_params: Option<services::workspace::WorkspaceDefaultParams>,
     ) -> Result<Option<services::workspace::Workspace>, RepositoryError> {
+        let default_params = default_params
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| RepositoryError::DataConversionError(e.into()))?;
         let request = crate::models::UpdateWorkspaceRequest {
             name,
             description,
             settings,
+            default_params,
         };
         match self.update(workspace_id.0, request).await? {
             Some(db_workspace) => Ok(Some(db_workspace_to_workspace_service(db_workspace))),
@@ -541,6 +667,29 @@ impl services::workspace::ports::WorkspaceRepository for WorkspaceRepository {
         }
     }
 
+    async fn update_monthly_budget(
+        &self,
+        workspace_id: services::workspace::WorkspaceId,
+        monthly_budget: Option<i64>,
+    ) -> Result<Option<services::workspace::Workspace>, RepositoryError> {
+        Ok(self
+            .update_monthly_budget(workspace_id.0, monthly_budget)
+            .await?
+            .map(db_workspace_to_workspace_service))
+    }
+
+    async fn transfer(
+        &self,
+        workspace_id: services::workspace::WorkspaceId,
+        from_organization_id: services::organization::OrganizationId,
+        to_organization_id: services::organization::OrganizationId,
+    ) -> Result<Option<services::workspace::Workspace>, RepositoryError> {
+        Ok(self
+            .transfer(workspace_id.0, from_organization_id.0, to_organization_id.0)
+            .await?
+            .map(db_workspace_to_workspace_service))
+    }
+
     async fn delete(
         &self,
         workspace_id: services::workspace::WorkspaceId,
@@ -614,5 +763,11 @@ fn db_workspace_to_workspace_service(
         updated_at: db_workspace.updated_at,
         is_active: db_workspace.is_active,
         settings: db_workspace.settings,
+        // Written only through the typed update path, so a parse failure means
+        // the column was edited out of band; treat it as "no defaults".
+        default_params: db_workspace
+            .default_params
+            .and_then(|value| serde_json::from_value(value).ok()),
+        monthly_budget: db_workspace.monthly_budget,
     }
 }
@@ -564,7 +564,7 @@ impl Provider {
 }
 
 /// Internal `extra` keys that must never reach Chutes (a third party): the
-/// tracing identifiers and the client-facing-E2EE markers. `ChatCompletionParams`
+/// tracing identifiers, forwarded client headers and the client-facing-E2EE markers. `ChatCompletionParams`
 /// flattens `extra` into the top-level body, so these would otherwise leak.
 const INTERNAL_KEYS: &[&str] = {
     use crate::attested::nearai::{encryption_headers as eh, tracing_headers as th};
@@ -572,6 +572,7 @@ const INTERNAL_KEYS: &[&str] = {
         th::REQUEST_ID,
         th::ORG_ID,
         th::WORKSPACE_ID,
+        crate::attested::nearai::PASSTHROUGH_HEADERS_KEY,
         eh::SIGNING_ALGO,
         eh::CLIENT_PUB_KEY,
         eh::MODEL_PUB_KEY,
@@ -1461,6 +1462,7 @@ impl InferenceProvider for Provider {
             response,
             raw_bytes,
             serving_tier: crate::ProviderTier::Attested3p,
+            inference_id: None,
         })
     }
 
@@ -2842,6 +2844,7 @@ mod tests {
             th::REQUEST_ID,
             th::ORG_ID,
             th::WORKSPACE_ID,
+            crate::attested::nearai::PASSTHROUGH_HEADERS_KEY,
             eh::SIGNING_ALGO,
             eh::CLIENT_PUB_KEY,
             eh::MODEL_PUB_KEY,
@@ -1,7 +1,7 @@
 mod fleet;
 mod prefix_router;
 
-use crate::spki_verifier::{FingerprintState, SharedTlsRoots};
+use crate::spki_verifier::{ClientIdentity, FingerprintState, SharedTlsRoots};
 use crate::{
     models::StreamOptions, sse_parser::new_sse_parser, ImageEditError, ImageGenerationError,
     PrivacyClassifyError, RerankError, ScoreError, *,
@@ -104,6 +104,22 @@ pub(crate) mod tracing_headers {
     pub const WORKSPACE_ID: &str = "x_workspace_id";
 }
 
+/// Build a W3C `traceparent` whose trace-id is the request UUID and whose
+/// parent-id is fresh for this outbound hop. `None` if the ID is not a UUID.
+fn traceparent_for_request_id(request_id: &str) -> Option<HeaderValue> {
+    let trace_id = uuid::Uuid::parse_str(request_id).ok()?;
+    let span_id = uuid::Uuid::new_v4().as_u64_pair().1;
+    HeaderValue::from_str(&format!("00-{}-{span_id:016x}-01", trace_id.simple())).ok()
+}
+
+/// Key in params.extra for allowlisted client headers forwarded upstream.
+///
+/// The value is a JSON object of lowercased `x-` header name to string value,
+/// filtered against the operator allowlist by cloud-api before it gets here.
+/// `prepare_passthrough_headers` turns it into HTTP headers and strips it from
+/// the body; other providers only strip it.
+pub(crate) const PASSTHROUGH_HEADERS_KEY: &str = "x_passthrough_headers";
+
 /// Encryption header keys used in params.extra for passing encryption information.
 /// `pub(crate)` so other providers (e.g. the Chutes path) can strip/reject these
 /// internal client-E2EE markers instead of hardcoding the strings.
@@ -142,6 +158,14 @@ pub struct Config {
     pub completion_timeout_seconds: i64,
     /// Total per-request timeout for control-plane operations and streaming TTFB.
     pub control_timeout_seconds: i64,
+    /// PEM client certificate (chain) for backends that require mutual TLS.
+    /// Must be set together with `client_key_path`.
+    pub client_cert_path: Option<String>,
+    /// PEM private key matching `client_cert_path`.
+    pub client_key_path: Option<String>,
+    /// PEM bundle of extra CA certificates for `https://` backends whose
+    /// certificates are issued by a private CA.
+    pub ca_bundle_path: Option<String>,
 }
 
 impl Config {
@@ -161,6 +185,9 @@ impl Config {
     /// the **completion** timeout only (control stays at its default / env value).
     /// When `None`, both timeouts are read from env vars:
     /// `VLLM_PROVIDER_COMPLETION_TIMEOUT` and `VLLM_PROVIDER_CONTROL_TIMEOUT`.
+    /// Client certificate paths are always read from
+    /// `VLLM_PROVIDER_CLIENT_CERT_PATH` / `VLLM_PROVIDER_CLIENT_KEY_PATH`, and
+    /// the CA bundle from `VLLM_PROVIDER_CA_BUNDLE_PATH`.
     pub fn new(base_url: String, api_key: Option<String>, timeout_seconds: Option<i64>) -> Self {
         let completion = timeout_seconds.unwrap_or_else(Self::completion_timeout_from_env);
         let control = Self::control_timeout_from_env();
@@ -169,9 +196,20 @@ impl Config {
             api_key,
             completion_timeout_seconds: completion,
             control_timeout_seconds: control,
+            client_cert_path: std::env::var(ClientIdentity::CERT_PATH_ENV).ok(),
+            client_key_path: std::env::var(ClientIdentity::KEY_PATH_ENV).ok(),
+            ca_bundle_path: std::env::var(SharedTlsRoots::CA_BUNDLE_PATH_ENV).ok(),
         }
     }
 
+    /// Load the configured mTLS client identity, if any.
+    pub fn client_identity(&self) -> Result<Option<ClientIdentity>, String> {
+        ClientIdentity::from_optional_paths(
+            self.client_cert_path.as_deref(),
+            self.client_key_path.as_deref(),
+        )
+    }
+
     /// Read the completion timeout from env, falling back to the default.
     pub fn completion_timeout_from_env() -> i64 {
         std::env::var("VLLM_PROVIDER_COMPLETION_TIMEOUT")
@@ -602,7 +640,19 @@ impl Provider {
         backend_verifier: Option<Arc<dyn crate::BackendVerifier>>,
         inline_verify_concurrency: usize,
     ) -> Self {
-        let tls_roots = SharedTlsRoots::load();
+        // The binary refuses to start with a broken client cert config; if the
+        // files go bad afterwards, a provider built during rediscovery must not
+        // panic. Without the cert, mTLS backends reject the handshake and the
+        // failure surfaces as a normal connection error.
+        let client_identity = match config.client_identity() {
+            Ok(identity) => identity.map(Arc::new),
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load vLLM client certificate; connecting without mTLS");
+                None
+            }
+        };
+        let tls_roots = SharedTlsRoots::load_with_ca_bundle(config.ca_bundle_path.as_deref())
+            .with_client_identity(client_identity);
 
         // reqwest's read_timeout is a per-chunk idle timeout. For non-streaming
         // chat completion the connection is silent the entire inference time
@@ -613,7 +663,7 @@ impl Provider {
         let control_timeout = config.control_timeout();
 
         // General-purpose client for non-completion requests
-        let client = Client::builder()
+        let client = crate::timing::apply(Client::builder())
             .use_preconfigured_tls(tls_roots.build_config(fingerprint_state.clone()))
             .connect_timeout(Duration::from_secs(5))
             .pool_idle_timeout(Duration::from_secs(90))
@@ -624,7 +674,7 @@ impl Provider {
         // Fallback client: like the general client but with completion-timeout
         // read settings, so it can be used for long-running inference requests
         // when inline bucket verification fails.
-        let fallback_client = Client::builder()
+        let fallback_client = crate::timing::apply(Client::builder())
             .use_preconfigured_tls(tls_roots.build_config(fingerprint_state.clone()))
             .connect_timeout(Duration::from_secs(5))
             .pool_idle_timeout(Duration::from_secs(90))
@@ -648,7 +698,7 @@ impl Provider {
         } else {
             (0..crate::rotation::MAX_FANOUT)
                 .map(|_| {
-                    let builder = Client::builder()
+                    let builder = crate::timing::apply(Client::builder())
                         .use_preconfigured_tls(tls_roots.build_config(fingerprint_state.clone()))
                         .pool_max_idle_per_host(1)
                         .http2_adaptive_window(true)
@@ -809,6 +859,11 @@ impl Fleet {
             if let Ok(value) = HeaderValue::from_str(id) {
                 headers.insert("X-Request-Id", value);
             }
+            // traceparent — same ID as a W3C trace-id, so tracing backends on
+            // the upstream side join this hop to the gateway's trace
+            if let Some(traceparent) = traceparent_for_request_id(id) {
+                headers.insert("traceparent", traceparent);
+            }
         }
 
         // X-Org-Id — organisation that owns the API key
@@ -834,6 +889,37 @@ impl Fleet {
         }
     }
 
+    /// Forward allowlisted client headers from `extra` as HTTP headers and
+    /// remove the key so it does not leak into the JSON body.
+    ///
+    /// Must run after every gateway-owned header is set: a passthrough header
+    /// never replaces an existing one, and anything that is not `x-` prefixed
+    /// (e.g. `Authorization`) is dropped regardless of what reached `extra`.
+    fn prepare_passthrough_headers(
+        &self,
+        headers: &mut reqwest::header::HeaderMap,
+        extra: &mut std::collections::HashMap<String, serde_json::Value>,
+    ) {
+        let Some(serde_json::Value::Object(passthrough)) = extra.remove(PASSTHROUGH_HEADERS_KEY)
+        else {
+            return;
+        };
+        for (name, value) in passthrough {
+            if !name.to_ascii_lowercase().starts_with("x-") {
+                continue;
+            }
+            let Ok(name) = reqwest::header::HeaderName::from_bytes(name.as_bytes()) else {
+                continue;
+            };
+            if headers.contains_key(&name) {
+                continue;
+            }
+            if let Some(value) = value.as_str().and_then(|v| HeaderValue::from_str(v).ok()) {
+                headers.insert(name, value);
+            }
+        }
+    }
+
     /// Send a streaming HTTP POST request with TTFB timeout protection.
     ///
     /// Uses `tokio::time::timeout` only around `.send()` so the timeout applies to TTFB only
@@ -853,7 +939,7 @@ impl Fleet {
         let ttfb_timeout_secs = self.config.control_timeout_seconds.max(0) as u64;
         let response = tokio::time::timeout(
             self.config.control_timeout(),
-            client.post(url).headers(headers).json(params).send(),
+            crate::timing::send(client.post(url).headers(headers).json(params)),
         )
         .await
         // TTFB stalls indicate the same backend is stuck — surface as
@@ -940,13 +1026,14 @@ impl Fleet {
                     continue;
                 }
             };
-            let send_res = client
-                .post(&url)
-                .headers(headers.clone())
-                .json(params)
-                .timeout(timeout)
-                .send()
-                .await;
+            let send_res = crate::timing::send(
+                client
+                    .post(&url)
+                    .headers(headers.clone())
+                    .json(params)
+                    .timeout(timeout),
+            )
+            .await;
             let response = match send_res {
                 Ok(r) => r,
                 Err(e) => {
@@ -1014,6 +1101,7 @@ impl Fleet {
                 response: chat_completion_response,
                 raw_bytes,
                 serving_tier: crate::ProviderTier::Near,
+                inference_id: None,
             });
         }
         Err(last_error)
@@ -1702,6 +1790,8 @@ impl InferenceProvider for Fleet {
         self.prepare_tracing_headers(&mut headers, &mut streaming_params.extra);
         // Prepare encryption headers
         self.prepare_encryption_headers(&mut headers, &mut streaming_params.extra);
+        // Allowlisted client headers last, so they cannot replace the above
+        self.prepare_passthrough_headers(&mut headers, &mut streaming_params.extra);
 
         // Select the backend rotation index: prefix affinity → same backend →
         // prefix cache hit, with latency steering off a pathologically slow
@@ -1848,6 +1938,8 @@ impl InferenceProvider for Fleet {
         self.prepare_tracing_headers(&mut headers, &mut non_streaming_params.extra);
         // Prepare encryption headers
         self.prepare_encryption_headers(&mut headers, &mut non_streaming_params.extra);
+        // Allowlisted client headers last, so they cannot replace the above
+        self.prepare_passthrough_headers(&mut headers, &mut non_streaming_params.extra);
 
         let timeout_secs = self.config.completion_timeout_seconds.max(0) as u64;
         let timeout = Duration::from_secs(timeout_secs);
@@ -1873,15 +1965,15 @@ impl InferenceProvider for Fleet {
         let index = match self.select_index(&non_streaming_params.messages) {
             None => {
                 let url = format!("{}/v1/chat/completions", self.config.base_url);
-                let response = self
-                    .fallback_client
-                    .post(&url)
-                    .headers(headers.clone())
-                    .json(&non_streaming_params)
-                    .timeout(timeout)
-                    .send()
-                    .await
-                    .map_err(map_send_err)?;
+                let response = crate::timing::send(
+                    self.fallback_client
+                        .post(&url)
+                        .headers(headers.clone())
+                        .json(&non_streaming_params)
+                        .timeout(timeout),
+                )
+                .await
+                .map_err(map_send_err)?;
                 if !response.status().is_success() {
                     let status_code = response.status().as_u16();
                     let error_text = response
@@ -1903,6 +1995,7 @@ impl InferenceProvider for Fleet {
                     response: chat_completion_response,
                     raw_bytes,
                     serving_tier: crate::ProviderTier::Near,
+                    inference_id: None,
                 });
             }
             Some(i) => i,
@@ -1916,12 +2009,13 @@ impl InferenceProvider for Fleet {
         let index_client = self.get_or_verify_index_client(index).await?;
 
         let send = |client: &Client, hdrs: reqwest::header::HeaderMap| {
-            client
-                .post(&url)
-                .headers(hdrs)
-                .json(&non_streaming_params)
-                .timeout(timeout)
-                .send()
+            crate::timing::send(
+                client
+                    .post(&url)
+                    .headers(hdrs)
+                    .json(&non_streaming_params)
+                    .timeout(timeout),
+            )
         };
 
         let response = match send(&index_client, headers.clone()).await {
@@ -2002,6 +2096,7 @@ impl InferenceProvider for Fleet {
             response: chat_completion_response,
             raw_bytes,
             serving_tier: crate::ProviderTier::Near,
+            inference_id: None,
         })
     }
 
@@ -2782,6 +2877,9 @@ mod tests {
             api_key: None,
             completion_timeout_seconds: 30,
             control_timeout_seconds: 30,
+            client_cert_path: None,
+            client_key_path: None,
+            ca_bundle_path: None,
         })
     }
 
@@ -2884,12 +2982,75 @@ mod tests {
             api_key: None,
             completion_timeout_seconds: -5,
             control_timeout_seconds: -10,
+            client_cert_path: None,
+            client_key_path: None,
+            ca_bundle_path: None,
         };
         // Conversion to Duration must not panic on negative values.
         assert_eq!(cfg.completion_timeout(), Duration::ZERO);
         assert_eq!(cfg.control_timeout(), Duration::ZERO);
     }
 
+    #[test]
+    fn vllm_config_client_identity_requires_both_paths() {
+        let mut cfg = Config {
+            base_url: "http://x".to_string(),
+            api_key: None,
+            completion_timeout_seconds: 30,
+            control_timeout_seconds: 30,
+            client_cert_path: None,
+            client_key_path: None,
+            ca_bundle_path: None,
+        };
+        assert!(cfg.client_identity().unwrap().is_none());
+
+        cfg.client_cert_path = Some("/tmp/cert.pem".to_string());
+        assert!(cfg.client_identity().is_err());
+    }
+
+    #[test]
+    fn provider_with_unreadable_client_cert_still_builds() {
+        // A bad cert path is logged, not fatal: the provider comes up without
+        // mTLS and the backend rejects the handshake instead.
+        let provider = Provider::new(Config {
+            base_url: "http://x".to_string(),
+            api_key: None,
+            completion_timeout_seconds: 30,
+            control_timeout_seconds: 30,
+            client_cert_path: Some("/nonexistent/cert.pem".to_string()),
+            client_key_path: Some("/nonexistent/key.pem".to_string()),
+            ca_bundle_path: None,
+        });
+        assert_eq!(
+            provider.config().client_cert_path.as_deref(),
+            Some("/nonexistent/cert.pem")
+        );
+    }
+
+    #[test]
+    fn provider_accepts_http_and_https_backends() {
+        // The scheme comes from the configured base URL; an unreadable CA
+        // bundle is logged and the provider falls back to the native roots.
+        for (base_url, ca_bundle_path) in [
+            ("http://10.0.0.5:8000", None),
+            (
+                "https://vllm.internal.example:8443",
+                Some("/nonexistent/ca.pem".to_string()),
+            ),
+        ] {
+            let provider = Provider::new(Config {
+                base_url: base_url.to_string(),
+                api_key: None,
+                completion_timeout_seconds: 30,
+                control_timeout_seconds: 30,
+                client_cert_path: None,
+                client_key_path: None,
+                ca_bundle_path,
+            });
+            assert_eq!(provider.config().base_url, base_url);
+        }
+    }
+
     #[test]
     fn timeout_error_display_includes_operation_and_seconds() {
         let err = CompletionError::Timeout {
@@ -2979,6 +3140,16 @@ mod tests {
             headers.get("X-Workspace-Id").and_then(|v| v.to_str().ok()),
             Some("cccc-dddd")
         );
+        let traceparent = headers
+            .get("traceparent")
+            .and_then(|v| v.to_str().ok())
+            .expect("UUID request IDs also go out as a traceparent");
+        assert!(
+            traceparent.starts_with("00-550e8400e29b41d4a716446655440000-"),
+            "got: {traceparent}"
+        );
+        assert!(traceparent.ends_with("-01"), "got: {traceparent}");
+        assert_eq!(traceparent.len(), 55);
     }
 
     #[test]
@@ -2995,6 +3166,44 @@ mod tests {
         assert!(headers.get("X-Request-Id").is_none());
         assert!(headers.get("X-Org-Id").is_none());
         assert!(headers.get("X-Workspace-Id").is_none());
+        assert!(headers.get("traceparent").is_none());
+    }
+
+    #[test]
+    fn test_prepare_passthrough_headers_forwards_without_overriding() {
+        let provider = create_test_provider();
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("Authorization", HeaderValue::from_static("Bearer backend"));
+        headers.insert("X-Request-Id", HeaderValue::from_static("gateway-id"));
+        let mut extra = std::collections::HashMap::new();
+        extra.insert(
+            PASSTHROUGH_HEADERS_KEY.to_string(),
+            serde_json::json!({
+                "x-routing-hint": "pool-a",
+                "x-request-id": "client-id",
+                "authorization": "Bearer client",
+                "x-bad-value": "line\nbreak",
+            }),
+        );
+
+        provider
+            .fleet
+            .prepare_passthrough_headers(&mut headers, &mut extra);
+
+        assert!(!extra.contains_key(PASSTHROUGH_HEADERS_KEY));
+        assert_eq!(
+            headers.get("x-routing-hint").and_then(|v| v.to_str().ok()),
+            Some("pool-a")
+        );
+        assert_eq!(
+            headers.get("X-Request-Id").and_then(|v| v.to_str().ok()),
+            Some("gateway-id")
+        );
+        assert_eq!(
+            headers.get("Authorization").and_then(|v| v.to_str().ok()),
+            Some("Bearer backend")
+        );
+        assert!(headers.get("x-bad-value").is_none());
     }
 
     #[test]
@@ -3280,6 +3489,9 @@ mod tests {
                 api_key: None,
                 completion_timeout_seconds: 30,
                 control_timeout_seconds: 30,
+                client_cert_path: None,
+                client_key_path: None,
+                ca_bundle_path: None,
             },
             Arc::new(std::sync::RwLock::new(
                 crate::spki_verifier::FingerprintState::Bootstrap,
@@ -3315,6 +3527,9 @@ mod tests {
                 api_key: None,
                 completion_timeout_seconds: 30,
                 control_timeout_seconds: 30,
+                client_cert_path: None,
+                client_key_path: None,
+                ca_bundle_path: None,
             },
             Arc::new(std::sync::RwLock::new(
                 crate::spki_verifier::FingerprintState::Bootstrap,
@@ -3367,6 +3582,9 @@ mod tests {
                 api_key: None,
                 completion_timeout_seconds: 30,
                 control_timeout_seconds: 30,
+                client_cert_path: None,
+                client_key_path: None,
+                ca_bundle_path: None,
             },
             Arc::new(std::sync::RwLock::new(
                 crate::spki_verifier::FingerprintState::Bootstrap,
@@ -3413,6 +3631,9 @@ mod tests {
                 api_key: None,
                 completion_timeout_seconds: 30,
                 control_timeout_seconds: 30,
+                client_cert_path: None,
+                client_key_path: None,
+                ca_bundle_path: None,
             },
             Arc::new(std::sync::RwLock::new(
                 crate::spki_verifier::FingerprintState::Bootstrap,
@@ -3461,6 +3682,9 @@ mod tests {
                 api_key: None,
                 completion_timeout_seconds: 30,
                 control_timeout_seconds: 30,
+                client_cert_path: None,
+                client_key_path: None,
+                ca_bundle_path: None,
             },
             Arc::new(std::sync::RwLock::new(
                 crate::spki_verifier::FingerprintState::Bootstrap,
@@ -3522,6 +3746,9 @@ mod tests {
                 api_key: None,
                 completion_timeout_seconds: 30,
                 control_timeout_seconds: 30,
+                client_cert_path: None,
+                client_key_path: None,
+                ca_bundle_path: None,
             },
             Arc::new(std::sync::RwLock::new(
                 crate::spki_verifier::FingerprintState::Bootstrap,
@@ -3611,6 +3838,9 @@ mod tests {
                 api_key: None,
                 completion_timeout_seconds: 1,
                 control_timeout_seconds: 30,
+                client_cert_path: None,
+                client_key_path: None,
+                ca_bundle_path: None,
             },
             Arc::new(std::sync::RwLock::new(
                 crate::spki_verifier::FingerprintState::Bootstrap,
@@ -3720,6 +3950,9 @@ mod tests {
                 api_key: None,
                 completion_timeout_seconds: 30,
                 control_timeout_seconds: 30,
+                client_cert_path: None,
+                client_key_path: None,
+                ca_bundle_path: None,
             },
             Arc::new(std::sync::RwLock::new(
                 // Need at least one pinned fingerprint so pre_warm doesn't
@@ -3790,6 +4023,9 @@ mod tests {
             api_key: None,
             completion_timeout_seconds: 30,
             control_timeout_seconds: 30,
+            client_cert_path: None,
+            client_key_path: None,
+            ca_bundle_path: None,
         }));
 
         // In legacy mode index clients are eagerly pre-filled at construction.
@@ -3845,6 +4081,9 @@ mod tests {
                     api_key: None,
                     completion_timeout_seconds: 30,
                     control_timeout_seconds: 30,
+                    client_cert_path: None,
+                    client_key_path: None,
+                    ca_bundle_path: None,
                 },
                 Arc::new(std::sync::RwLock::new(state)),
                 Arc::new(CountingVerifier {
@@ -4013,6 +4252,9 @@ mod tests {
             api_key: None,
             completion_timeout_seconds: 30,
             control_timeout_seconds: 30,
+            client_cert_path: None,
+            client_key_path: None,
+            ca_bundle_path: None,
         });
         provider.set_backend_count(3);
         assert_eq!(provider.fleet.rotation_count(), 3);
@@ -4044,6 +4286,9 @@ mod tests {
             api_key: None,
             completion_timeout_seconds: 30,
             control_timeout_seconds: 30,
+            client_cert_path: None,
+            client_key_path: None,
+            ca_bundle_path: None,
         });
         provider.set_backend_count(10_000);
         assert_eq!(provider.fleet.rotation_count(), crate::rotation::MAX_FANOUT);
@@ -4059,6 +4304,9 @@ mod tests {
             api_key: None,
             completion_timeout_seconds: 30,
             control_timeout_seconds: 30,
+            client_cert_path: None,
+            client_key_path: None,
+            ca_bundle_path: None,
         });
         assert_eq!(provider.fleet.rotation_count(), 0);
     }
@@ -4074,6 +4322,9 @@ mod tests {
             api_key: None,
             completion_timeout_seconds: 30,
             control_timeout_seconds: 30,
+            client_cert_path: None,
+            client_key_path: None,
+            ca_bundle_path: None,
         });
         provider.set_backend_count(count);
         provider
@@ -4209,6 +4460,9 @@ mod tests {
                 api_key: None,
                 completion_timeout_seconds: 30,
                 control_timeout_seconds: 30,
+                client_cert_path: None,
+                client_key_path: None,
+                ca_bundle_path: None,
             },
             Arc::new(std::sync::RwLock::new(
                 crate::spki_verifier::FingerprintState::Bootstrap,
@@ -4461,6 +4715,9 @@ mod tests {
             api_key: None,
             completion_timeout_seconds: 5,
             control_timeout_seconds: 5,
+            client_cert_path: None,
+            client_key_path: None,
+            ca_bundle_path: None,
         })
     }
 
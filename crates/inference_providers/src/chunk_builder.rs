@@ -296,6 +296,7 @@ mod tests {
             completion_tokens: 20,
             total_tokens: 30,
             prompt_tokens_details: None,
+            completion_tokens_details: None,
         };
         let chunk = ctx.finish_chunk(Some(FinishReason::Stop), usage);
 
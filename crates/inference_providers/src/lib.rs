@@ -64,6 +64,7 @@ pub mod non_attested;
 pub mod rotation;
 pub mod spki_verifier;
 pub mod sse_parser;
+pub mod timing;
 
 // Attested NEAR-AI fleet provider. Use the module path (`nearai::Provider`,
 // `nearai::Config`) rather than a bare re-export to keep the names unambiguous.
@@ -88,9 +89,10 @@ pub use models::{
     FinishReason, FunctionChoice, FunctionDefinition, ImageData, ImageEditError, ImageEditParams,
     ImageEditResponse, ImageEditResponseWithBytes, ImageGenerationError, ImageGenerationParams,
     ImageGenerationResponse, ImageGenerationResponseWithBytes, MessageRole, ModelInfo,
-    PrivacyClassifyError, RerankError, RerankParams, RerankResponse, RerankResult, RerankUsage,
-    ScoreError, ScoreParams, ScoreResponse, ScoreResult, ScoreUsage, StreamChunk, StreamOptions,
-    TokenUsage, ToolChoice, ToolDefinition, TranscriptionSegment, TranscriptionWord,
+    PrivacyClassifyError, ProviderFailure, RerankError, RerankParams, RerankResponse, RerankResult,
+    RerankUsage, ScoreError, ScoreParams, ScoreResponse, ScoreResult, ScoreUsage, StreamChunk,
+    StreamOptions, TokenUsage, ToolChoice, ToolDefinition, TranscriptionSegment, TranscriptionWord,
+    MAX_LOGIT_BIAS, MAX_SAMPLING_PENALTY, MAX_STOP_SEQUENCES,
 };
 pub use sse_parser::{
     new_external_sse_parser, new_sse_parser, BufferedSSEParser, SSEEvent, SSEEventParser, SSEParser,
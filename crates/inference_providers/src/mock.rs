@@ -10,15 +10,15 @@ use crate::{
     ChatDelta, ChatResponseMessage, ChatSignature, CompletionChunk, CompletionError,
     CompletionParams, EmbeddingError, FinishReason, FunctionCallDelta, ImageData, ImageEditError,
     ImageEditParams, ImageEditResponseWithBytes, ImageGenerationError, ImageGenerationParams,
-    ImageGenerationResponse, ImageGenerationResponseWithBytes, ListModelsError, MessageRole,
-    ModelInfo, ModelsResponse, PrivacyClassifyError, RerankError, RerankParams, RerankResponse,
-    RerankResult, RerankUsage, SSEEvent, ScoreError, ScoreParams, ScoreResponse, ScoreResult,
-    ScoreUsage, StreamChunk, StreamingResult, TokenUsage, ToolCallDelta, TranscriptionSegment,
-    TranscriptionWord,
+    ImageGenerationResponse, ImageGenerationResponseWithBytes, ListModelsError, LogProbs,
+    MessageRole, ModelInfo, ModelsResponse, PrivacyClassifyError, RerankError, RerankParams,
+    RerankResponse, RerankResult, RerankUsage, SSEEvent, ScoreError, ScoreParams, ScoreResponse,
+    ScoreResult, ScoreUsage, StreamChunk, StreamingResult, TokenLogProb, TokenUsage, ToolCallDelta,
+    TopLogProb, TranscriptionSegment, TranscriptionWord,
 };
 use async_trait::async_trait;
 use bytes::Bytes;
-use futures_util::stream;
+use futures_util::{stream, StreamExt};
 use sha2::{Digest, Sha256};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -91,6 +91,67 @@ struct SignatureHashes {
     response_hash: String,
 }
 
+/// Fixed secp256k1 private key used to sign `ecdsa` chat signatures in
+/// attestation fixture mode. Test-only; never trust it outside the mock.
+pub const MOCK_ECDSA_SIGNING_KEY: [u8; 32] = [0x42; 32];
+
+/// Fixed Ed25519 private key used to sign `ed25519` chat signatures in
+/// attestation fixture mode. Test-only; never trust it outside the mock.
+pub const MOCK_ED25519_SIGNING_KEY: [u8; 32] = [0x24; 32];
+
+/// `(signing_address, signing_public_key)` the fixture keys attest to, encoded
+/// like inference-proxy reports them: an `0x` Ethereum address plus the
+/// 64-byte uncompressed point for `ecdsa`, the hex public key (both fields)
+/// for `ed25519`. Returns `None` for any other algorithm.
+pub fn fixture_signing_identity(signing_algo: &str) -> Option<(String, String)> {
+    match signing_algo {
+        "ecdsa" => {
+            let key = k256::ecdsa::SigningKey::from_slice(&MOCK_ECDSA_SIGNING_KEY)
+                .expect("fixture ECDSA key is a valid scalar");
+            let point = key.verifying_key().to_encoded_point(false);
+            let public_key = &point.as_bytes()[1..];
+            let address_hash = sha3::Keccak256::digest(public_key);
+            Some((
+                format!("0x{}", hex::encode(&address_hash[12..])),
+                hex::encode(public_key),
+            ))
+        }
+        "ed25519" => {
+            let key = ed25519_dalek::SigningKey::from_bytes(&MOCK_ED25519_SIGNING_KEY);
+            let public_key = hex::encode(key.verifying_key().to_bytes());
+            Some((public_key.clone(), public_key))
+        }
+        _ => None,
+    }
+}
+
+/// Sign `text` with the fixture key for `signing_algo`, in the wire format the
+/// gateway verifies: Ethereum signed-message `r || s || v` (`0x`-prefixed) for
+/// `ecdsa`, a raw 64-byte signature for `ed25519`.
+fn fixture_sign(signing_algo: &str, text: &str) -> Option<String> {
+    match signing_algo {
+        "ecdsa" => {
+            let key = k256::ecdsa::SigningKey::from_slice(&MOCK_ECDSA_SIGNING_KEY)
+                .expect("fixture ECDSA key is a valid scalar");
+            let mut hasher = sha3::Keccak256::new();
+            hasher.update(format!("\x19Ethereum Signed Message:\n{}", text.len()).as_bytes());
+            hasher.update(text.as_bytes());
+            let (signature, recid) = key
+                .sign_prehash_recoverable(&hasher.finalize())
+                .expect("fixture ECDSA signing cannot fail");
+            let mut signature_bytes = signature.to_bytes().to_vec();
+            signature_bytes.push(27 + (recid.to_byte() & 1));
+            Some(format!("0x{}", hex::encode(signature_bytes)))
+        }
+        "ed25519" => {
+            use ed25519_dalek::Signer;
+            let key = ed25519_dalek::SigningKey::from_bytes(&MOCK_ED25519_SIGNING_KEY);
+            Some(hex::encode(key.sign(text.as_bytes()).to_bytes()))
+        }
+        _ => None,
+    }
+}
+
 /// Request matcher for conditional responses
 #[derive(Clone)]
 pub enum RequestMatcher {
@@ -222,6 +283,14 @@ pub struct ResponseTemplate {
     /// request's model param — simulates external backends that answer with
     /// their upstream model name (`provider_config.model_name` overrides).
     model_override: Option<String>,
+    /// Delay before the first streamed chunk (simulates slow prompt processing).
+    first_chunk_delay: Option<std::time::Duration>,
+    /// Delay before a non-streaming response is returned.
+    response_delay: Option<std::time::Duration>,
+    /// `system_fingerprint` reported on the response and on every chunk.
+    system_fingerprint: Option<String>,
+    /// Stream without any usage (simulates backends that never send a usage chunk).
+    omit_usage: bool,
 }
 
 impl ResponseTemplate {
@@ -235,9 +304,19 @@ impl ResponseTemplate {
             tool_calls: None,
             cache_tokens: None,
             model_override: None,
+            first_chunk_delay: None,
+            response_delay: None,
+            system_fingerprint: None,
+            omit_usage: false,
         }
     }
 
+    /// Report `fingerprint` as the backend's `system_fingerprint`.
+    pub fn with_system_fingerprint(mut self, fingerprint: impl Into<String>) -> Self {
+        self.system_fingerprint = Some(fingerprint.into());
+        self
+    }
+
     /// Echo `model` in responses instead of the request's model param
     /// (simulates upstream model-name overrides on external providers).
     pub fn with_model(mut self, model: impl Into<String>) -> Self {
@@ -252,10 +331,18 @@ impl ResponseTemplate {
     }
 
     fn token_usage(&self, input_tokens: i32, output_tokens: i32) -> TokenUsage {
-        match self.cache_tokens {
+        let mut usage = match self.cache_tokens {
             Some(c) => TokenUsage::new_with_cache(input_tokens, output_tokens, c),
             None => TokenUsage::new(input_tokens, output_tokens),
+        };
+        // Reasoning is streamed first, one token per word, and counts toward
+        // completion_tokens; report it in completion_tokens_details like vLLM.
+        if let Some(reasoning) = &self.reasoning_content {
+            let reasoning_tokens = (reasoning.split(' ').count() as i32).min(output_tokens);
+            usage.completion_tokens_details =
+                Some(serde_json::json!({ "reasoning_tokens": reasoning_tokens }));
         }
+        usage
     }
 
     /// Set reasoning content for this template
@@ -277,6 +364,25 @@ impl ResponseTemplate {
         self
     }
 
+    /// Delay the first streamed chunk by `delay`
+    pub fn with_first_chunk_delay(mut self, delay: std::time::Duration) -> Self {
+        self.first_chunk_delay = Some(delay);
+        self
+    }
+
+    /// Stream without usage on any chunk and without the final usage chunk,
+    /// while still finishing normally with a finish_reason and `[DONE]`.
+    pub fn without_usage(mut self) -> Self {
+        self.omit_usage = true;
+        self
+    }
+
+    /// Delay the non-streaming response by `delay`
+    pub fn with_response_delay(mut self, delay: std::time::Duration) -> Self {
+        self.response_delay = Some(delay);
+        self
+    }
+
     /// Add tool calls to this response
     pub fn with_tool_calls(mut self, tool_calls: Vec<ToolCall>) -> Self {
         self.tool_calls = Some(tool_calls);
@@ -292,8 +398,13 @@ impl ResponseTemplate {
         input_tokens: i32,
     ) -> ChatCompletionResponse {
         let model = self.model_override.clone().unwrap_or(model);
-        // Calculate output tokens as word count of content
-        let output_tokens = self.content.split_whitespace().count() as i32;
+        // Calculate output tokens as word count of content, plus reasoning
+        // words as in the streamed response
+        let output_tokens = self.content.split_whitespace().count() as i32
+            + self
+                .reasoning_content
+                .as_ref()
+                .map_or(0, |reasoning| reasoning.split(' ').count() as i32);
 
         // Convert tool calls if present
         let tool_calls = self.tool_calls.as_ref().map(|calls| {
@@ -347,7 +458,7 @@ impl ResponseTemplate {
                 extra: Default::default(),
             }],
             service_tier: None,
-            system_fingerprint: None,
+            system_fingerprint: self.system_fingerprint.clone(),
             usage: self.token_usage(input_tokens, output_tokens),
             prompt_logprobs: None,
             prompt_token_ids: None,
@@ -385,7 +496,7 @@ impl ResponseTemplate {
                     object: "chat.completion.chunk".to_string(),
                     created,
                     model: model.clone(),
-                    system_fingerprint: None,
+                    system_fingerprint: self.system_fingerprint.clone(),
                     choices: vec![ChatChoice {
                         index: 0,
                         delta: Some(ChatDelta {
@@ -431,7 +542,7 @@ impl ResponseTemplate {
                     object: "chat.completion.chunk".to_string(),
                     created,
                     model: model.clone(),
-                    system_fingerprint: None,
+                    system_fingerprint: self.system_fingerprint.clone(),
                     choices: vec![ChatChoice {
                         index: 0,
                         delta: Some(ChatDelta {
@@ -469,7 +580,7 @@ impl ResponseTemplate {
                     object: "chat.completion.chunk".to_string(),
                     created,
                     model: model.clone(),
-                    system_fingerprint: None,
+                    system_fingerprint: self.system_fingerprint.clone(),
                     choices: vec![ChatChoice {
                         index: 0,
                         delta: Some(ChatDelta {
@@ -521,7 +632,7 @@ impl ResponseTemplate {
                         object: "chat.completion.chunk".to_string(),
                         created,
                         model: model.clone(),
-                        system_fingerprint: None,
+                        system_fingerprint: self.system_fingerprint.clone(),
                         choices: vec![ChatChoice {
                             index: 0,
                             delta: Some(ChatDelta {
@@ -562,7 +673,7 @@ impl ResponseTemplate {
             object: "chat.completion.chunk".to_string(),
             created,
             model,
-            system_fingerprint: None,
+            system_fingerprint: self.system_fingerprint.clone(),
             choices: vec![],
             usage: Some(self.token_usage(input_tokens, output_token_count)),
             prompt_token_ids: None,
@@ -570,6 +681,13 @@ impl ResponseTemplate {
             extra: Default::default(),
         });
 
+        if self.omit_usage {
+            chunks.pop();
+            for chunk in &mut chunks {
+                chunk.usage = None;
+            }
+        }
+
         chunks
     }
 }
@@ -623,6 +741,15 @@ pub struct MockProvider {
     last_chat_params: Arc<Mutex<Option<ChatCompletionParams>>>,
     /// When true, get_attestation_report returns an error (simulates blocked/broken backend)
     fail_attestation: Arc<std::sync::atomic::AtomicBool>,
+    /// When true, get_attestation_report echoes a fixed nonce instead of the
+    /// requested one (simulates a replayed / stale attestation report)
+    stale_attestation_nonce: Arc<std::sync::atomic::AtomicBool>,
+    /// Delay before get_attestation_report answers (simulates a slow backend)
+    attestation_delay: Option<std::time::Duration>,
+    /// When true, signatures and attestation reports are produced with the
+    /// fixed fixture keys (see [`fixture_signing_identity`]) instead of
+    /// placeholder values, so they verify like a real TEE backend's.
+    attestation_fixture: Arc<std::sync::atomic::AtomicBool>,
     /// Trust tier reported by [`InferenceProvider::tier`]; defaults to
     /// `NonAttested`. Set via [`MockProvider::with_tier`] to exercise tiered
     /// provider selection (e.g. a `Near` primary with an `Attested3p` fallback).
@@ -640,6 +767,15 @@ pub struct MockProvider {
     /// order. Lets lifecycle tests assert the signature-fetch routing pin was
     /// released. `std::sync::Mutex` because the trait method is synchronous.
     unpinned_chat_ids: Arc<std::sync::Mutex<Vec<String>>>,
+    /// Delay applied to every [`InferenceProvider::get_signature`] call
+    /// (simulates a slow signature endpoint).
+    signature_delay: Option<std::time::Duration>,
+    /// Number of [`InferenceProvider::get_signature`] calls received.
+    signature_requests: Arc<std::sync::atomic::AtomicUsize>,
+    /// Number of [`InferenceProvider::get_attestation_report`] calls received.
+    attestation_requests: Arc<std::sync::atomic::AtomicUsize>,
+    /// Number of non-streaming [`InferenceProvider::chat_completion`] calls received.
+    chat_completion_requests: Arc<std::sync::atomic::AtomicUsize>,
 }
 
 impl MockProvider {
@@ -668,11 +804,18 @@ impl MockProvider {
             })),
             last_chat_params: Arc::new(Mutex::new(None)),
             fail_attestation: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            stale_attestation_nonce: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            attestation_delay: None,
+            attestation_fixture: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             tier: crate::ProviderTier::NonAttested,
             provider_source: crate::ProviderSource::External,
             supports_streaming: true,
             supports_client_e2ee: true,
             unpinned_chat_ids: Arc::new(std::sync::Mutex::new(Vec::new())),
+            signature_delay: None,
+            signature_requests: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+            attestation_requests: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+            chat_completion_requests: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
         }
     }
 
@@ -693,11 +836,18 @@ impl MockProvider {
             })),
             last_chat_params: Arc::new(Mutex::new(None)),
             fail_attestation: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            stale_attestation_nonce: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            attestation_delay: None,
+            attestation_fixture: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             tier: crate::ProviderTier::NonAttested,
             provider_source: crate::ProviderSource::External,
             supports_streaming: true,
             supports_client_e2ee: true,
             unpinned_chat_ids: Arc::new(std::sync::Mutex::new(Vec::new())),
+            signature_delay: None,
+            signature_requests: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+            attestation_requests: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+            chat_completion_requests: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
         }
     }
 
@@ -716,11 +866,18 @@ impl MockProvider {
             })),
             last_chat_params: Arc::new(Mutex::new(None)),
             fail_attestation: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            stale_attestation_nonce: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            attestation_delay: None,
+            attestation_fixture: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             tier: crate::ProviderTier::NonAttested,
             provider_source: crate::ProviderSource::External,
             supports_streaming: true,
             supports_client_e2ee: true,
             unpinned_chat_ids: Arc::new(std::sync::Mutex::new(Vec::new())),
+            signature_delay: None,
+            signature_requests: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+            attestation_requests: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+            chat_completion_requests: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
         }
     }
 
@@ -751,12 +908,39 @@ impl MockProvider {
         self
     }
 
+    /// Delay every get_attestation_report call by `delay` (simulates a slow backend).
+    pub fn with_attestation_delay(mut self, delay: std::time::Duration) -> Self {
+        self.attestation_delay = Some(delay);
+        self
+    }
+
+    /// Delay every get_signature call by `delay` (simulates a slow backend).
+    pub fn with_signature_delay(mut self, delay: std::time::Duration) -> Self {
+        self.signature_delay = Some(delay);
+        self
+    }
+
     /// Make get_attestation_report return an error (simulates blocked/broken backend).
     pub fn set_fail_attestation(&self, fail: bool) {
         self.fail_attestation
             .store(fail, std::sync::atomic::Ordering::Relaxed);
     }
 
+    /// Make get_attestation_report echo a nonce other than the requested one
+    /// (simulates a replayed / stale attestation report).
+    pub fn set_stale_attestation_nonce(&self, stale: bool) {
+        self.stale_attestation_nonce
+            .store(stale, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Sign chat signatures and attestation reports with the fixture keys
+    /// (see [`fixture_signing_identity`]) so verification can be exercised
+    /// end-to-end without a TEE.
+    pub fn set_attestation_fixture(&self, enabled: bool) {
+        self.attestation_fixture
+            .store(enabled, std::sync::atomic::Ordering::Relaxed);
+    }
+
     /// Get the last chat completion params received by the mock provider
     pub async fn last_chat_params(&self) -> Option<ChatCompletionParams> {
         self.last_chat_params.lock().await.clone()
@@ -772,6 +956,24 @@ impl MockProvider {
             .unwrap_or_default()
     }
 
+    /// Number of get_signature calls received so far.
+    pub fn signature_request_count(&self) -> usize {
+        self.signature_requests
+            .load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Number of get_attestation_report calls received so far.
+    pub fn attestation_request_count(&self) -> usize {
+        self.attestation_requests
+            .load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Number of non-streaming chat_completion calls received so far.
+    pub fn chat_completion_request_count(&self) -> usize {
+        self.chat_completion_requests
+            .load(std::sync::atomic::Ordering::Relaxed)
+    }
+
     /// Register request and response hashes for a chat_id
     /// This allows MockProvider to return signatures in the correct format "request_hash:response_hash"
     pub async fn register_signature_hashes(
@@ -1029,6 +1231,9 @@ impl crate::InferenceProvider for MockProvider {
         let created = self.current_timestamp();
         let model = params.model.clone();
         let mut chunks = response_template.generate_chunks(id, created, model, input_tokens);
+        if let Some(top_n) = Self::requested_top_logprobs(&params) {
+            Self::attach_chunk_logprobs(&mut chunks, top_n);
+        }
 
         // If disconnect simulation is enabled, truncate chunks (simulates client disconnect)
         // The stream will end abruptly without the final usage chunk
@@ -1096,6 +1301,11 @@ impl crate::InferenceProvider for MockProvider {
                 .chain(stream_error.into_iter().map(Err)),
         );
 
+        if let Some(delay) = response_template.first_chunk_delay {
+            let delayed = stream::once(tokio::time::sleep(delay)).filter_map(|_| async { None });
+            return Ok(Box::pin(delayed.chain(stream)));
+        }
+
         Ok(Box::pin(stream))
     }
 
@@ -1104,6 +1314,8 @@ impl crate::InferenceProvider for MockProvider {
         params: ChatCompletionParams,
         request_hash: String,
     ) -> Result<ChatCompletionResponseWithBytes, CompletionError> {
+        self.chat_completion_requests
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
         *self.last_chat_params.lock().await = Some(params.clone());
 
         // Check for invalid model
@@ -1133,6 +1345,10 @@ impl crate::InferenceProvider for MockProvider {
                 .unwrap_or_else(|| config.default_response.clone())
         };
 
+        if let Some(delay) = response_template.response_delay {
+            tokio::time::sleep(delay).await;
+        }
+
         // Calculate input tokens from messages (rough estimate: 1 word ≈ 1 token)
         let input_tokens: i32 = params
             .messages
@@ -1144,8 +1360,11 @@ impl crate::InferenceProvider for MockProvider {
         let input_tokens = input_tokens.max(6);
 
         // Keep a stable chat_id for both the response and signature registration.
-        let response =
+        let mut response =
             response_template.generate_response(id.clone(), created, model, input_tokens);
+        if let Some(top_n) = Self::requested_top_logprobs(&params) {
+            Self::attach_response_logprobs(&mut response, top_n);
+        }
 
         let raw_bytes = serde_json::to_vec(&response)
             .map_err(|e| CompletionError::CompletionError(format!("Failed to serialize: {e}")))?;
@@ -1159,6 +1378,7 @@ impl crate::InferenceProvider for MockProvider {
             response,
             raw_bytes,
             serving_tier: self.tier(),
+            inference_id: None,
         })
     }
 
@@ -1425,6 +1645,11 @@ impl crate::InferenceProvider for MockProvider {
         chat_id: &str,
         signing_algo: Option<String>,
     ) -> Result<ChatSignature, CompletionError> {
+        self.signature_requests
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        if let Some(delay) = self.signature_delay {
+            tokio::time::sleep(delay).await;
+        }
         let signing_algo = signing_algo.unwrap_or_else(|| "ecdsa".to_string());
 
         // Check if we have registered hashes for this chat_id
@@ -1433,6 +1658,26 @@ impl crate::InferenceProvider for MockProvider {
             // Return signature in the correct format "request_hash:response_hash"
             let signature_text =
                 format!("{}:{}", sig_hashes.request_hash, sig_hashes.response_hash);
+            if self
+                .attestation_fixture
+                .load(std::sync::atomic::Ordering::Relaxed)
+            {
+                let unsupported = || {
+                    CompletionError::CompletionError(format!(
+                        "Unsupported signing algorithm: {signing_algo}"
+                    ))
+                };
+                let signature =
+                    fixture_sign(&signing_algo, &signature_text).ok_or_else(unsupported)?;
+                let (signing_address, _) =
+                    fixture_signing_identity(&signing_algo).ok_or_else(unsupported)?;
+                return Ok(ChatSignature {
+                    text: signature_text,
+                    signature,
+                    signing_address,
+                    signing_algo,
+                });
+            }
             // Generate a deterministic mock signature based on the hashes and algorithm
             use std::collections::hash_map::DefaultHasher;
             use std::hash::{Hash, Hasher};
@@ -1458,14 +1703,24 @@ impl crate::InferenceProvider for MockProvider {
         }
     }
 
+    /// Whitespace word count, matching the mock's usage accounting.
+    async fn count_tokens(&self, _model: &str, text: String) -> Option<u64> {
+        Some(text.split_whitespace().count() as u64)
+    }
+
     async fn get_attestation_report(
         &self,
         model: String,
         signing_algo: Option<String>,
-        _nonce: Option<String>,
+        nonce: Option<String>,
         _signing_address: Option<String>,
         _include_tls_fingerprint: bool,
     ) -> Result<serde_json::Map<String, serde_json::Value>, AttestationError> {
+        self.attestation_requests
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        if let Some(delay) = self.attestation_delay {
+            tokio::time::sleep(delay).await;
+        }
         if self
             .fail_attestation
             .load(std::sync::atomic::Ordering::Relaxed)
@@ -1481,18 +1736,58 @@ impl crate::InferenceProvider for MockProvider {
             serde_json::Value::String("mock-attestation".to_string()),
         );
 
-        // Include signing_public_key for encryption tests
-        // ECDSA: 128 hex chars (64 bytes) - uncompressed point (x and y coordinates, 32 bytes each)
-        // Ed25519: 64 hex chars (32 bytes) - public key bytes
-        let mock_signing_public_key = match signing_algo.as_deref() {
+        if self
+            .attestation_fixture
+            .load(std::sync::atomic::Ordering::Relaxed)
+        {
+            let algo = signing_algo.as_deref().unwrap_or("ecdsa");
+            let (signing_address, signing_public_key) =
+                fixture_signing_identity(algo).ok_or_else(|| {
+                    AttestationError::FetchError(format!("Unsupported signing algorithm: {algo}"))
+                })?;
+            report.insert(
+                "signing_algo".to_string(),
+                serde_json::Value::String(algo.to_string()),
+            );
+            report.insert(
+                "signing_address".to_string(),
+                serde_json::Value::String(signing_address),
+            );
+            report.insert(
+                "signing_public_key".to_string(),
+                serde_json::Value::String(signing_public_key),
+            );
+        } else {
+            // Include signing_public_key for encryption tests
+            // ECDSA: 128 hex chars (64 bytes) - uncompressed point (x and y coordinates, 32 bytes each)
+            // Ed25519: 64 hex chars (32 bytes) - public key bytes
+            let mock_signing_public_key = match signing_algo.as_deref() {
             Some("ecdsa") => "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
             Some("ed25519") => "fedcba9876543210fedcba9876543210fedcba9876543210fedcba9876543210",
             _ => "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef", // default to ecdsa format (128 hex chars)
         };
-        report.insert(
-            "signing_public_key".to_string(),
-            serde_json::Value::String(mock_signing_public_key.to_string()),
-        );
+            report.insert(
+                "signing_public_key".to_string(),
+                serde_json::Value::String(mock_signing_public_key.to_string()),
+            );
+        }
+
+        // Echo the caller's nonce like a real backend; the stale toggle returns a
+        // fixed nonce instead so freshness checks can be exercised.
+        if let Some(nonce) = nonce {
+            let request_nonce = if self
+                .stale_attestation_nonce
+                .load(std::sync::atomic::Ordering::Relaxed)
+            {
+                "00".repeat(32)
+            } else {
+                nonce
+            };
+            report.insert(
+                "request_nonce".to_string(),
+                serde_json::Value::String(request_nonce),
+            );
+        }
 
         Ok(report)
     }
@@ -1562,6 +1857,85 @@ impl crate::InferenceProvider for MockProvider {
 }
 
 impl MockProvider {
+    /// Number of top alternatives to emit when the request asked for logprobs,
+    /// or `None` when it did not (mirrors vLLM, which omits logprobs unless asked).
+    /// Client requests carry these as pass-through `extra` fields.
+    fn requested_top_logprobs(params: &ChatCompletionParams) -> Option<usize> {
+        let logprobs = params
+            .logprobs
+            .or_else(|| params.extra.get("logprobs").and_then(|v| v.as_bool()));
+        let top_logprobs = params
+            .top_logprobs
+            .or_else(|| params.extra.get("top_logprobs").and_then(|v| v.as_i64()));
+        (logprobs == Some(true)).then(|| top_logprobs.unwrap_or(0).max(0) as usize)
+    }
+
+    /// Deterministic logprob entry for the `position`-th generated token. The
+    /// sampled token is always the first of its `top_n` alternatives.
+    fn mock_token_logprob(token: &str, position: usize, top_n: usize) -> TokenLogProb {
+        let logprob = -0.125 * (position + 1) as f64;
+        let top_logprobs = (0..top_n)
+            .map(|rank| {
+                let alt = if rank == 0 {
+                    token.to_string()
+                } else {
+                    format!("alt{rank}")
+                };
+                TopLogProb {
+                    bytes: Some(alt.as_bytes().to_vec()),
+                    token: alt,
+                    logprob: logprob - rank as f64,
+                }
+            })
+            .collect();
+        TokenLogProb {
+            token: token.to_string(),
+            logprob,
+            bytes: Some(token.as_bytes().to_vec()),
+            top_logprobs: Some(top_logprobs),
+        }
+    }
+
+    /// Attach per-token logprobs to a non-streaming response's content.
+    fn attach_response_logprobs(response: &mut ChatCompletionResponse, top_n: usize) {
+        for choice in &mut response.choices {
+            let Some(content) = choice.message.content.as_deref() else {
+                continue;
+            };
+            let tokens = content
+                .split(' ')
+                .enumerate()
+                .map(|(i, word)| {
+                    let token = if i == 0 {
+                        word.to_string()
+                    } else {
+                        format!(" {word}")
+                    };
+                    Self::mock_token_logprob(&token, i, top_n)
+                })
+                .collect();
+            choice.logprobs = Some(LogProbs {
+                content: Some(tokens),
+                refusal: None,
+            });
+        }
+    }
+
+    /// Attach a single-token logprobs entry to every streamed content delta.
+    fn attach_chunk_logprobs(chunks: &mut [ChatCompletionChunk], top_n: usize) {
+        let mut position = 0;
+        for choice in chunks.iter_mut().flat_map(|c| c.choices.iter_mut()) {
+            let Some(token) = choice.delta.as_ref().and_then(|d| d.content.clone()) else {
+                continue;
+            };
+            choice.logprobs = Some(LogProbs {
+                content: Some(vec![Self::mock_token_logprob(&token, position, top_n)]),
+                refusal: None,
+            });
+            position += 1;
+        }
+    }
+
     /// Count tokens in content (handles serde_json::Value)
     fn count_tokens_in_content(content: &serde_json::Value) -> i32 {
         match content {
@@ -223,8 +223,13 @@ pub struct ChatCompletionParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
 
-    /// Stop sequences (up to 4)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Stop sequences (up to [`MAX_STOP_SEQUENCES`]). Accepts OpenAI's single
+    /// string form on input; always serialized as an array.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_stop"
+    )]
     pub stop: Option<Vec<String>>,
 
     /// Frequency penalty (-2.0 to 2.0)
@@ -316,8 +321,12 @@ pub struct CompletionParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
 
-    /// Stop sequences
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Stop sequences. Accepts a single string or an array.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_stop"
+    )]
     pub stop: Option<Vec<String>>,
 
     /// Frequency penalty (-2.0 to 2.0)
@@ -369,6 +378,10 @@ pub enum FinishReason {
     ContentFilter,
     #[serde(alias = "function_call")]
     ToolCalls,
+    /// Backend-specific reason outside the OpenAI set (e.g. vLLM's `abort`).
+    /// Kept verbatim so an unexpected value cannot fail the final chunk.
+    #[serde(untagged)]
+    Other(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -378,6 +391,10 @@ pub struct TokenUsage {
     pub total_tokens: i32,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prompt_tokens_details: Option<serde_json::Value>,
+    /// Upstream `completion_tokens_details` (e.g. `reasoning_tokens`),
+    /// forwarded as reported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_tokens_details: Option<serde_json::Value>,
 }
 
 impl TokenUsage {
@@ -387,6 +404,7 @@ impl TokenUsage {
             completion_tokens,
             total_tokens: prompt_tokens + completion_tokens,
             prompt_tokens_details: None,
+            completion_tokens_details: None,
         }
     }
 
@@ -402,6 +420,7 @@ impl TokenUsage {
             completion_tokens,
             total_tokens: prompt_tokens + completion_tokens,
             prompt_tokens_details: details,
+            completion_tokens_details: None,
         }
     }
 
@@ -421,6 +440,24 @@ impl TokenUsage {
             .unwrap_or(0);
         n.min(self.prompt_tokens).max(0)
     }
+
+    /// Reasoning tokens from completion_tokens_details.reasoning_tokens.
+    /// Returns 0 if missing or invalid. They are part of `completion_tokens`
+    /// (OpenAI convention), so the value is clamped to [0, completion_tokens]
+    /// and never billed on top of it.
+    pub fn reasoning_tokens(&self) -> i32 {
+        let Some(ref details) = self.completion_tokens_details else {
+            return 0;
+        };
+        let Some(v) = details.get("reasoning_tokens") else {
+            return 0;
+        };
+        let n = v
+            .as_i64()
+            .and_then(|n64| i32::try_from(n64).ok())
+            .unwrap_or(0);
+        n.min(self.completion_tokens).max(0)
+    }
 }
 
 /// Audio output data (for Qwen3-Omni and similar models)
@@ -552,8 +589,12 @@ pub struct TextChoice {
 /// Log probabilities for chat completion tokens
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogProbs {
-    /// Log probabilities for each token
-    pub content: Vec<TokenLogProb>,
+    /// Log probabilities for each content token (null when the choice has none)
+    #[serde(default)]
+    pub content: Option<Vec<TokenLogProb>>,
+    /// Log probabilities for each refusal token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refusal: Option<Vec<TokenLogProb>>,
 }
 
 /// Log probabilities for text completion tokens
@@ -576,9 +617,10 @@ pub struct TokenLogProb {
     /// The token
     pub token: String,
     /// Log probability of the token
-    pub logprob: f32,
-    /// UTF-8 bytes of the token
-    pub bytes: Vec<u8>,
+    pub logprob: f64,
+    /// UTF-8 bytes of the token (null when the token has no byte representation)
+    #[serde(default)]
+    pub bytes: Option<Vec<u8>>,
     /// Top alternative tokens at this position
     #[serde(skip_serializing_if = "Option::is_none")]
     pub top_logprobs: Option<Vec<TopLogProb>>,
@@ -590,9 +632,10 @@ pub struct TopLogProb {
     /// The token
     pub token: String,
     /// Log probability of the token
-    pub logprob: f32,
-    /// UTF-8 bytes of the token
-    pub bytes: Vec<u8>,
+    pub logprob: f64,
+    /// UTF-8 bytes of the token (null when the token has no byte representation)
+    #[serde(default)]
+    pub bytes: Option<Vec<u8>>,
 }
 
 /// Generic streaming chunk that can represent either format
@@ -675,6 +718,11 @@ pub struct ChatCompletionResponseWithBytes {
     /// Populated by each provider implementation so callers can surface it as an
     /// `x-serving-provider` response header without reaching back into the pool.
     pub serving_tier: crate::ProviderTier,
+
+    /// Gateway inference id the usage row for this request was recorded under.
+    /// Providers leave this `None`; the completion service fills it in so a
+    /// coalesced follower reports its own id rather than the leader's.
+    pub inference_id: Option<uuid::Uuid>,
 }
 
 /// Choice in a complete (non-streaming) chat completion response
@@ -860,6 +908,10 @@ pub enum CompletionError {
     /// Client should refresh their attestation report and retry.
     #[error("No provider found for encryption key: {0}")]
     NoPubKeyProvider(String),
+    /// The model is not in the pool and provider discovery has not succeeded
+    /// yet (cold start), so it may simply not be loaded. Client should retry.
+    #[error("Model not ready: {0}")]
+    ModelWarming(String),
     /// The request exceeded the per-call timeout configured on the provider.
     /// Distinct from connection errors: the request was sent and the server is
     /// (probably) still working — retrying the same request would just hit the
@@ -872,6 +924,45 @@ pub enum CompletionError {
         operation: String,
         timeout_seconds: u64,
     },
+    /// Every provider attempt failed. `last_error` is the error that decides
+    /// the response (status code, message); `failures` summarizes each
+    /// attempt without any upstream text, so it is safe to return to clients.
+    #[error("{last_error}")]
+    AllProvidersFailed {
+        last_error: Box<CompletionError>,
+        failures: Vec<ProviderFailure>,
+    },
+}
+
+impl CompletionError {
+    /// The error that decides the response, unwrapping the all-providers
+    /// summary if present.
+    pub fn last_error(&self) -> &CompletionError {
+        match self {
+            CompletionError::AllProvidersFailed { last_error, .. } => last_error.last_error(),
+            other => other,
+        }
+    }
+
+    /// Per-attempt failure summary; empty unless every provider failed.
+    pub fn provider_failures(&self) -> &[ProviderFailure] {
+        match self {
+            CompletionError::AllProvidersFailed { failures, .. } => failures,
+            _ => &[],
+        }
+    }
+}
+
+/// One failed provider attempt, in the order attempted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderFailure {
+    /// 1-based attempt number across all retry rounds
+    pub attempt: u32,
+    /// Upstream HTTP status, when the provider answered with one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    /// Low-cardinality failure category (e.g. `http_5xx`, `timeout`)
+    pub category: String,
 }
 
 /// Parameters for image generation requests
@@ -1165,6 +1256,36 @@ pub struct AudioTranscriptionResponse {
     pub id: Option<String>,
 }
 
+/// Maximum number of stop sequences accepted by OpenAI-compatible APIs.
+pub const MAX_STOP_SEQUENCES: usize = 4;
+
+/// Largest magnitude accepted for `frequency_penalty` / `presence_penalty`.
+pub const MAX_SAMPLING_PENALTY: f32 = 2.0;
+
+/// Largest magnitude accepted for a `logit_bias` value.
+pub const MAX_LOGIT_BIAS: f64 = 100.0;
+
+/// Deserialize OpenAI's `stop` union (a single string or an array of strings)
+/// into a list, so callers never have to care which shape the client sent.
+fn deserialize_stop<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrVec {
+        Single(String),
+        Many(Vec<String>),
+    }
+
+    Ok(
+        Option::<StringOrVec>::deserialize(deserializer)?.map(|stop| match stop {
+            StringOrVec::Single(s) => vec![s],
+            StringOrVec::Many(v) => v,
+        }),
+    )
+}
+
 /// Custom deserializer to handle duration as either string or number
 fn deserialize_duration<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
 where
@@ -1245,6 +1366,47 @@ pub fn detect_audio_content_type(filename: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn chat_params_stop_accepts_string_and_array() {
+        let single: ChatCompletionParams = serde_json::from_value(serde_json::json!({
+            "model": "m",
+            "messages": [],
+            "stop": "\n",
+        }))
+        .unwrap();
+        assert_eq!(single.stop, Some(vec!["\n".to_string()]));
+
+        let many: ChatCompletionParams = serde_json::from_value(serde_json::json!({
+            "model": "m",
+            "messages": [],
+            "stop": ["a", "b"],
+        }))
+        .unwrap();
+        assert_eq!(many.stop, Some(vec!["a".to_string(), "b".to_string()]));
+
+        let absent: ChatCompletionParams = serde_json::from_value(serde_json::json!({
+            "model": "m",
+            "messages": [],
+        }))
+        .unwrap();
+        assert_eq!(absent.stop, None);
+
+        // Always forwarded upstream as an array, whichever shape came in.
+        let forwarded = serde_json::to_value(&single).unwrap();
+        assert_eq!(forwarded["stop"], serde_json::json!(["\n"]));
+    }
+
+    #[test]
+    fn completion_params_stop_accepts_string() {
+        let params: CompletionParams = serde_json::from_value(serde_json::json!({
+            "model": "m",
+            "prompt": "hi",
+            "stop": "END",
+        }))
+        .unwrap();
+        assert_eq!(params.stop, Some(vec!["END".to_string()]));
+    }
+
     #[test]
     fn model_info_advertised_context_length_uses_backend_metadata() {
         let model = ModelInfo {
@@ -1447,6 +1609,40 @@ mod tests {
         assert_eq!(before, after, "string content must be byte-identical");
     }
 
+    #[test]
+    fn test_reasoning_delta_and_usage_details_round_trip() {
+        let chunk = r#"{
+            "id":"chatcmpl-1",
+            "object":"chat.completion.chunk",
+            "created":1760402549,
+            "model":"deepseek-ai/DeepSeek-V3.1",
+            "choices":[{"index":0,"delta":{"reasoning_content":"Let me think"},"finish_reason":null}],
+            "usage":{"prompt_tokens":5,"completion_tokens":12,"total_tokens":17,
+                     "completion_tokens_details":{"reasoning_tokens":10}}
+        }"#;
+        let parsed: ChatCompletionChunk = serde_json::from_str(chunk).unwrap();
+        let delta = parsed.choices[0].delta.as_ref().unwrap();
+        assert_eq!(delta.reasoning_content.as_deref(), Some("Let me think"));
+        let usage = parsed.usage.as_ref().unwrap();
+        assert_eq!(usage.reasoning_tokens(), 10);
+
+        let reserialized = serde_json::to_value(&parsed).unwrap();
+        assert_eq!(
+            reserialized["choices"][0]["delta"]["reasoning_content"],
+            "Let me think"
+        );
+        assert_eq!(
+            reserialized["usage"]["completion_tokens_details"]["reasoning_tokens"],
+            10
+        );
+
+        // Reasoning is part of completion_tokens; an inconsistent report is clamped.
+        let mut usage = TokenUsage::new(5, 3);
+        usage.completion_tokens_details = Some(serde_json::json!({"reasoning_tokens": 10}));
+        assert_eq!(usage.reasoning_tokens(), 3);
+        assert_eq!(TokenUsage::new(5, 3).reasoning_tokens(), 0);
+    }
+
     #[test]
     fn test_chat_completion_response_deserialization() {
         let json_response = r#"{
@@ -1502,6 +1698,52 @@ mod tests {
         assert_eq!(response.usage.total_tokens, 17);
     }
 
+    #[test]
+    fn test_chat_completion_logprobs_round_trip() {
+        // Full-precision logprobs, null `bytes` and a null `content` array
+        // must all survive a parse/serialize cycle unchanged.
+        let logprobs = serde_json::json!({
+            "content": [{
+                "token": " world",
+                "logprob": -0.0012345678901234,
+                "bytes": [32, 119, 111, 114, 108, 100],
+                "top_logprobs": [
+                    {"token": " world", "logprob": -0.0012345678901234, "bytes": [32, 119, 111, 114, 108, 100]},
+                    {"token": "<0xFF>", "logprob": -7.25, "bytes": null}
+                ]
+            }]
+        });
+        let response = serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 1760402549,
+            "model": "Qwen/Qwen3-30B-A3B-Instruct-2507",
+            "choices": [
+                {
+                    "index": 0,
+                    "message": {"role": "assistant", "content": " world"},
+                    "logprobs": logprobs,
+                    "finish_reason": "stop"
+                },
+                {
+                    "index": 1,
+                    "message": {"role": "assistant", "content": null},
+                    "logprobs": {"content": null},
+                    "finish_reason": "stop"
+                }
+            ],
+            "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}
+        });
+
+        let parsed: ChatCompletionResponse = serde_json::from_value(response).unwrap();
+        let mapped = serde_json::to_value(&parsed).unwrap();
+        assert_eq!(mapped["choices"][0]["logprobs"], logprobs);
+        assert_eq!(
+            mapped["choices"][1]["logprobs"],
+            serde_json::json!({"content": null})
+        );
+    }
+
     #[test]
     fn test_chat_completion_response_deserialization_glm() {
         let json_resp = r#"{
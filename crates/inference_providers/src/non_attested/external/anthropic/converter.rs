@@ -771,6 +771,7 @@ impl SSEEventParser for AnthropicEventParser {
                     completion_tokens: 0,
                     total_tokens: prompt_tokens,
                     prompt_tokens_details: state.prompt_tokens_details(),
+                    completion_tokens_details: None,
                 };
                 Ok(Some(StreamChunk::Chat(
                     ctx.role_chunk_with_usage(Some(early_usage)),
@@ -847,6 +848,7 @@ impl SSEEventParser for AnthropicEventParser {
                     completion_tokens: state.output_tokens,
                     total_tokens: prompt_tokens + state.output_tokens,
                     prompt_tokens_details: state.prompt_tokens_details(),
+                    completion_tokens_details: None,
                 };
                 Ok(Some(StreamChunk::Chat(
                     ctx.finish_chunk(finish_reason, token_usage),
@@ -109,6 +109,7 @@ fn map_usage(usage: &AnthropicUsage) -> TokenUsage {
         completion_tokens: usage.output_tokens,
         total_tokens: prompt_tokens + usage.output_tokens,
         prompt_tokens_details,
+        completion_tokens_details: None,
     }
 }
 
@@ -418,6 +419,7 @@ impl ExternalBackend for AnthropicBackend {
             response: openai_response,
             raw_bytes: serialized_bytes,
             serving_tier: crate::ProviderTier::NonAttested,
+            inference_id: None,
         })
     }
 }
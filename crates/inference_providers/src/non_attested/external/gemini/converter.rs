@@ -828,6 +828,7 @@ impl SSEEventParser for GeminiEventParser {
                     completion_tokens: state.completion_tokens,
                     total_tokens: state.prompt_tokens + state.completion_tokens,
                     prompt_tokens_details: None,
+                    completion_tokens_details: None,
                 }),
             )
         } else if is_first {
@@ -844,6 +845,7 @@ impl SSEEventParser for GeminiEventParser {
                 completion_tokens: state.completion_tokens,
                 total_tokens: state.prompt_tokens + state.completion_tokens,
                 prompt_tokens_details: None,
+                completion_tokens_details: None,
             });
             chunk
         } else if let Some(t) = text {
@@ -855,6 +857,7 @@ impl SSEEventParser for GeminiEventParser {
                 completion_tokens: state.completion_tokens,
                 total_tokens: state.prompt_tokens + state.completion_tokens,
                 prompt_tokens_details: None,
+                completion_tokens_details: None,
             });
             chunk
         } else {
@@ -866,6 +869,7 @@ impl SSEEventParser for GeminiEventParser {
                     completion_tokens: state.completion_tokens,
                     total_tokens: state.prompt_tokens + state.completion_tokens,
                     prompt_tokens_details: None,
+                    completion_tokens_details: None,
                 },
             )
         };
@@ -100,6 +100,7 @@ fn convert_to_openai_response(
             completion_tokens: gemini_response.usage_metadata.candidates_token_count,
             total_tokens: gemini_response.usage_metadata.total_token_count,
             prompt_tokens_details: None,
+            completion_tokens_details: None,
         },
         prompt_logprobs: None,
         prompt_token_ids: None,
@@ -397,6 +398,7 @@ impl ExternalBackend for GeminiBackend {
             response: openai_response,
             raw_bytes: serialized_bytes,
             serving_tier: crate::ProviderTier::NonAttested,
+            inference_id: None,
         })
     }
 
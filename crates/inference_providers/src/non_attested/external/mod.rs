@@ -51,8 +51,9 @@ pub use openai_compatible::OpenAiCompatibleBackend;
 /// Strip cloud-api internal tracing keys from `extra` before forwarding params
 /// to external providers.
 ///
-/// These keys (`x_request_id`, `x_org_id`, `x_workspace_id`) are injected by the
-/// completion service so the vLLM provider can forward them as HTTP headers.
+/// These keys (`x_request_id`, `x_org_id`, `x_workspace_id`, and the
+/// allowlisted client headers under `x_passthrough_headers`) are injected by
+/// the completion service so the vLLM provider can forward them as HTTP headers.
 /// External providers use `#[serde(flatten)]` on `extra`, so any remaining keys
 /// are serialised as top-level JSON body fields — unknown fields that strict
 /// providers (Anthropic, Gemini) may reject with a 400/422.
@@ -61,6 +62,7 @@ fn strip_internal_tracing_keys(extra: &mut std::collections::HashMap<String, ser
     extra.remove(tracing_headers::REQUEST_ID);
     extra.remove(tracing_headers::ORG_ID);
     extra.remove(tracing_headers::WORKSPACE_ID);
+    extra.remove(crate::attested::nearai::PASSTHROUGH_HEADERS_KEY);
 }
 
 fn merge_json_defaults(target: &mut serde_json::Value, defaults: &serde_json::Value) {
@@ -327,6 +327,7 @@ impl ExternalBackend for OpenAiCompatibleBackend {
             response: parsed,
             raw_bytes,
             serving_tier: crate::ProviderTier::NonAttested,
+            inference_id: None,
         })
     }
 
@@ -426,6 +426,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::FinishReason;
     use futures_util::StreamExt;
 
     #[tokio::test]
@@ -530,6 +531,42 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_sse_parser_parses_chunk_logprobs() {
+        // vLLM emits one logprobs entry per streamed token; `bytes` may be
+        // null and the raw bytes must still be forwarded unchanged.
+        let packet = concat!(
+            "data: {\"id\":\"1\",\"object\":\"chat.completion.chunk\",\"created\":1234567890,\"model\":\"test\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Hi\"},",
+            "\"logprobs\":{\"content\":[{\"token\":\"Hi\",\"logprob\":-0.0123456789012,\"bytes\":[72,105],",
+            "\"top_logprobs\":[{\"token\":\"Hi\",\"logprob\":-0.0123456789012,\"bytes\":[72,105]},{\"token\":\"<unk>\",\"logprob\":-4.5,\"bytes\":null}]}]},",
+            "\"finish_reason\":null}]}\n\n",
+        );
+
+        let mock_stream =
+            futures_util::stream::iter(vec![Ok::<_, reqwest::Error>(bytes::Bytes::from(packet))]);
+
+        let parser = new_sse_parser(mock_stream, true);
+        let events: Vec<SSEEvent> = parser.map(|e| e.unwrap()).collect().await;
+        let event = &events[0];
+        assert!(event.raw_passthrough);
+        assert!(packet.as_bytes().starts_with(&event.raw_bytes));
+
+        let Some(StreamChunk::Chat(chunk)) = &event.chunk else {
+            panic!("expected a parsed chat chunk");
+        };
+        let content = chunk.choices[0]
+            .logprobs
+            .as_ref()
+            .and_then(|l| l.content.as_ref())
+            .expect("logprobs content should be parsed");
+        assert_eq!(content.len(), 1);
+        assert_eq!(content[0].token, "Hi");
+        assert_eq!(content[0].logprob, -0.0123456789012);
+        let top = content[0].top_logprobs.as_ref().unwrap();
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[1].bytes, None);
+    }
+
     #[tokio::test]
     async fn test_sse_parser_skips_comments_and_empty_lines() {
         let packet = concat!(
@@ -954,4 +991,111 @@ mod tests {
         };
         assert!(!blank.is_done_marker());
     }
+
+    /// Parse a chat stream that ends with `finish_reason` (a raw JSON
+    /// fragment), returning the final parsed chunk.
+    async fn final_chat_chunk(finish_reason: &str, is_external: bool) -> ChatCompletionChunk {
+        let stream = format!(
+            concat!(
+                "data: {{\"id\":\"1\",\"object\":\"chat.completion.chunk\",\"created\":1234567890,\"model\":\"test\",\"choices\":[{{\"index\":0,\"delta\":{{\"content\":\"Hi\"}},\"finish_reason\":null}}]}}\n\n",
+                "data: {{\"id\":\"1\",\"object\":\"chat.completion.chunk\",\"created\":1234567890,\"model\":\"test\",\"choices\":[{{\"index\":0,\"delta\":{{}},\"finish_reason\":{}}}]}}\n\n",
+                "data: {{\"id\":\"1\",\"object\":\"chat.completion.chunk\",\"created\":1234567890,\"model\":\"test\",\"choices\":[],\"usage\":{{\"prompt_tokens\":3,\"completion_tokens\":1,\"total_tokens\":4}}}}\n\n",
+                "data: [DONE]\n\n",
+            ),
+            finish_reason
+        );
+        let mock_stream =
+            futures_util::stream::iter(vec![Ok::<_, reqwest::Error>(bytes::Bytes::from(stream))]);
+        let parser = if is_external {
+            new_external_sse_parser(mock_stream, true)
+        } else {
+            new_sse_parser(mock_stream, true)
+        };
+        let events: Vec<_> = parser.collect().await;
+
+        let chunks: Vec<ChatCompletionChunk> = events
+            .into_iter()
+            .map(|e| e.expect("stream should parse without errors"))
+            .filter_map(|e| match e.chunk {
+                Some(StreamChunk::Chat(chunk)) => Some(chunk),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(chunks.len(), 3, "all data events should parse");
+        chunks
+            .into_iter()
+            .find(|c| {
+                c.choices
+                    .iter()
+                    .any(|choice| choice.finish_reason.is_some())
+            })
+            .expect("a chunk should carry finish_reason")
+    }
+
+    #[tokio::test]
+    async fn test_sse_parser_preserves_each_finish_reason() {
+        let cases = [
+            ("\"stop\"", FinishReason::Stop, "\"stop\""),
+            ("\"length\"", FinishReason::Length, "\"length\""),
+            ("\"tool_calls\"", FinishReason::ToolCalls, "\"tool_calls\""),
+            (
+                "\"content_filter\"",
+                FinishReason::ContentFilter,
+                "\"content_filter\"",
+            ),
+            // Legacy OpenAI spelling normalizes to tool_calls
+            (
+                "\"function_call\"",
+                FinishReason::ToolCalls,
+                "\"tool_calls\"",
+            ),
+        ];
+        for is_external in [false, true] {
+            for (wire, expected, reserialized) in &cases {
+                let chunk = final_chat_chunk(wire, is_external).await;
+                assert_eq!(chunk.choices[0].finish_reason.as_ref(), Some(expected));
+
+                // The route layer re-serializes parsed chunks for clients.
+                let json = serde_json::to_value(&chunk).unwrap();
+                assert_eq!(
+                    json["choices"][0]["finish_reason"].to_string(),
+                    *reserialized,
+                    "finish_reason {wire} must survive re-serialization"
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sse_parser_keeps_unknown_finish_reason_instead_of_failing() {
+        // vLLM/SGLang report `abort` when a request is cancelled backend-side.
+        // Previously this failed the whole final chunk with InvalidResponse.
+        let chunk = final_chat_chunk("\"abort\"", false).await;
+        assert_eq!(
+            chunk.choices[0].finish_reason,
+            Some(FinishReason::Other("abort".to_string()))
+        );
+        let json = serde_json::to_value(&chunk).unwrap();
+        assert_eq!(json["choices"][0]["finish_reason"], "abort");
+    }
+
+    #[tokio::test]
+    async fn test_sse_parser_preserves_text_completion_finish_reason() {
+        let stream = concat!(
+            "data: {\"id\":\"1\",\"object\":\"text_completion\",\"created\":1234567890,\"model\":\"test\",\"choices\":[{\"index\":0,\"text\":\"Hi\",\"finish_reason\":null}]}\n\n",
+            "data: {\"id\":\"1\",\"object\":\"text_completion\",\"created\":1234567890,\"model\":\"test\",\"choices\":[{\"index\":0,\"text\":\"\",\"finish_reason\":\"length\"}]}\n\n",
+        );
+        let mock_stream =
+            futures_util::stream::iter(vec![Ok::<_, reqwest::Error>(bytes::Bytes::from(stream))]);
+        let events: Vec<_> = new_sse_parser(mock_stream, false).collect().await;
+
+        let reasons: Vec<Option<FinishReason>> = events
+            .into_iter()
+            .filter_map(|e| match e.unwrap().chunk {
+                Some(StreamChunk::Text(chunk)) => Some(chunk.choices[0].finish_reason.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(reasons, vec![None, Some(FinishReason::Length)]);
+    }
 }
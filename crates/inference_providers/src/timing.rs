@@ -0,0 +1,169 @@
+//! HTTP-level timing for provider requests: connection setup (DNS + TCP +
+//! TLS) and time to first byte (response headers).
+//!
+//! Timings are collected per task. A caller wraps a provider call in
+//! [`capture`]; clients built with [`apply`] report the setup time of any new
+//! connection, and requests sent through [`send`] report their time to
+//! response headers. Each [`send`] starts from a clean slate, so after
+//! retries only the final attempt is reported. Outside [`capture`] nothing is
+//! recorded.
+
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// Connection and first-byte timing of the last provider HTTP request made
+/// inside [`capture`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProviderTiming {
+    /// Time to open a new connection; `None` when a pooled connection was reused.
+    pub connect: Option<Duration>,
+    /// Time from sending the request to receiving the response headers.
+    pub ttfb: Option<Duration>,
+}
+
+tokio::task_local! {
+    static TIMING: Cell<ProviderTiming>;
+}
+
+/// Run `future`, returning its output and the timing of the last provider
+/// request it sent.
+pub async fn capture<F: Future>(future: F) -> (F::Output, ProviderTiming) {
+    TIMING
+        .scope(Cell::new(ProviderTiming::default()), async move {
+            let output = future.await;
+            (output, TIMING.with(Cell::get))
+        })
+        .await
+}
+
+fn update(f: impl FnOnce(&mut ProviderTiming)) {
+    let _ = TIMING.try_with(|cell| {
+        let mut timing = cell.get();
+        f(&mut timing);
+        cell.set(timing);
+    });
+}
+
+/// Record the time taken to open a connection for the current request.
+pub fn record_connect(duration: Duration) {
+    update(|timing| timing.connect = Some(duration));
+}
+
+/// Record the time to response headers for the current request.
+pub fn record_ttfb(duration: Duration) {
+    update(|timing| timing.ttfb = Some(duration));
+}
+
+/// Send `request`, recording its time to response headers.
+pub async fn send(request: reqwest::RequestBuilder) -> reqwest::Result<reqwest::Response> {
+    update(|timing| *timing = ProviderTiming::default());
+    let start = Instant::now();
+    let response = request.send().await?;
+    record_ttfb(start.elapsed());
+    Ok(response)
+}
+
+/// Report new-connection setup time from clients built with `builder`.
+pub fn apply(builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+    builder.connector_layer(ConnectTimingLayer)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ConnectTimingLayer;
+
+impl<S> tower_layer::Layer<S> for ConnectTimingLayer {
+    type Service = ConnectTiming<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ConnectTiming { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ConnectTiming<S> {
+    inner: S,
+}
+
+impl<S, R> tower_service::Service<R> for ConnectTiming<S>
+where
+    S: tower_service::Service<R>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = ConnectTimingFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        ConnectTimingFuture {
+            inner: Box::pin(self.inner.call(request)),
+            start: Instant::now(),
+        }
+    }
+}
+
+struct ConnectTimingFuture<F> {
+    inner: Pin<Box<F>>,
+    start: Instant,
+}
+
+impl<F, T, E> Future for ConnectTimingFuture<F>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    type Output = Result<T, E>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = std::task::ready!(self.inner.as_mut().poll(cx));
+        if result.is_ok() {
+            record_connect(self.start.elapsed());
+        }
+        Poll::Ready(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn captures_connect_only_for_new_connections() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        let client = apply(reqwest::Client::builder()).build().unwrap();
+
+        let (response, first) = capture(send(client.get(server.uri()))).await;
+        assert!(response.unwrap().status().is_success());
+        assert!(first.connect.is_some());
+        assert!(first.ttfb.is_some());
+
+        let (response, second) = capture(send(client.get(server.uri()))).await;
+        assert!(response.unwrap().status().is_success());
+        assert_eq!(second.connect, None, "pooled connection is reused");
+        assert!(second.ttfb.is_some());
+    }
+
+    #[tokio::test]
+    async fn send_outside_capture_records_nothing() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        let client = apply(reqwest::Client::builder()).build().unwrap();
+
+        assert!(send(client.get(server.uri())).await.is_ok());
+        let ((), timing) = capture(async {}).await;
+        assert_eq!(timing, ProviderTiming::default());
+    }
+}
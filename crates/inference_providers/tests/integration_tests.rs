@@ -32,6 +32,9 @@ fn create_test_provider() -> Box<dyn InferenceProvider> {
             api_key: std::env::var("VLLM_API_KEY").ok(),
             completion_timeout_seconds: timeout,
             control_timeout_seconds: timeout,
+            client_cert_path: None,
+            client_key_path: None,
+            ca_bundle_path: None,
         };
         Box::new(nearai::Provider::new(config))
     } else {
@@ -704,6 +707,9 @@ async fn test_image_generation_real() {
         // Image generation can take longer.
         completion_timeout_seconds: 120,
         control_timeout_seconds: 30,
+        client_cert_path: None,
+        client_key_path: None,
+        ca_bundle_path: None,
     };
     let provider = nearai::Provider::new(config);
 
@@ -24,7 +24,8 @@ use crate::email::{
     EmailDeliveryOutcome, EmailSender, ModelDeprecationEmail, PricingChangeEmail,
     PricingChangeEmailModel,
 };
-use crate::models::ModelsServiceTrait;
+use crate::models::{ModelsError, ModelsServiceTrait};
+use crate::usage::CurrencyRates;
 
 const MODEL_DEPRECATION_USAGE_WINDOW_DAYS: i64 = 30;
 const MODEL_PRICING_CHANGE_USAGE_WINDOW_DAYS: i64 = 30;
@@ -44,6 +45,8 @@ pub struct AdminServiceImpl {
     /// changes take effect immediately instead of waiting for the 5-minute TTL.
     completion_service: Arc<dyn CompletionServiceTrait>,
     email_sender: Arc<dyn EmailSender>,
+    /// Currencies accepted for organization spend limits.
+    currency_rates: CurrencyRates,
 }
 
 impl AdminServiceImpl {
@@ -58,9 +61,15 @@ impl AdminServiceImpl {
             models_service,
             completion_service,
             email_sender,
+            currency_rates: CurrencyRates::default(),
         }
     }
 
+    pub fn with_currency_rates(mut self, currency_rates: CurrencyRates) -> Self {
+        self.currency_rates = currency_rates;
+        self
+    }
+
     async fn validate_and_load_model_deprecation(
         &self,
         model_name: &str,
@@ -367,6 +376,50 @@ impl AdminService for AdminServiceImpl {
         Ok(results)
     }
 
+    async fn preview_batch_upsert_models(
+        &self,
+        models: &BatchUpdateModelAdminRequest,
+    ) -> Result<BatchUpsertPreview, AdminError> {
+        if models.is_empty() {
+            return Err(AdminError::InvalidPricing(
+                "At least one model must be provided".to_string(),
+            ));
+        }
+
+        // Same validation as a real run, so a clean dry run means the real
+        // run would be accepted.
+        for (model_name, request) in models {
+            Self::validate_model_request(model_name, request, Arc::clone(&self.repository)).await?;
+        }
+
+        // Sorted so repeated dry runs of the same batch diff identically.
+        let mut model_names: Vec<&String> = models.keys().collect();
+        model_names.sort();
+
+        let mut preview = BatchUpsertPreview::default();
+        for model_name in model_names {
+            let current = self
+                .repository
+                .get_model_upsert_baseline(model_name)
+                .await
+                .map_err(|e| AdminError::InternalError(e.to_string()))?;
+            match current {
+                None => preview.would_create.push(model_name.clone()),
+                Some(current) => {
+                    let changes =
+                        Self::diff_model_upsert(model_name, &current, &models[model_name]);
+                    if changes.is_empty() {
+                        preview.would_noop.push(model_name.clone());
+                    } else {
+                        preview.would_update.extend(changes);
+                    }
+                }
+            }
+        }
+
+        Ok(preview)
+    }
+
     async fn get_model_history(
         &self,
         model_name: &str,
@@ -480,10 +533,10 @@ impl AdminService for AdminServiceImpl {
     async fn update_organization_limits(
         &self,
         organization_id: uuid::Uuid,
-        limits: OrganizationLimitsUpdate,
+        mut limits: OrganizationLimitsUpdate,
     ) -> Result<OrganizationLimits, AdminError> {
         // Validate limits
-        Self::validate_organization_limits(&limits)?;
+        limits.currency = self.validate_organization_limits(&limits)?;
 
         let updated_limits = self
             .repository
@@ -618,6 +671,10 @@ impl AdminService for AdminServiceImpl {
             verifiable: None,
             is_active: None,
             allow_free: None,
+            default_max_tokens: None,
+            max_max_tokens: None,
+            reject_over_max_tokens: None,
+            request_timeout_secs: None,
             aliases: None,
             owned_by: None,
             provider_type: None,
@@ -1099,6 +1156,102 @@ impl AdminService for AdminServiceImpl {
             })
     }
 
+    async fn update_organization_allowed_models(
+        &self,
+        organization_id: uuid::Uuid,
+        allowed_models: Option<Vec<String>>,
+    ) -> Result<Option<Vec<String>>, AdminError> {
+        // Store canonical names so the completion-time check is a plain
+        // comparison against the resolved model
+        let mut canonical = Vec::new();
+        for identifier in allowed_models.unwrap_or_default() {
+            let model = self
+                .models_service
+                .resolve_and_get_model(identifier.trim())
+                .await
+                .map_err(|e| match e {
+                    ModelsError::NotFound(_) => {
+                        AdminError::ModelNotFound(format!("Model '{identifier}' not found"))
+                    }
+                    other => AdminError::InternalError(other.to_string()),
+                })?;
+            if !canonical.contains(&model.model_name) {
+                canonical.push(model.model_name);
+            }
+        }
+        let allowed_models = (!canonical.is_empty()).then_some(canonical);
+
+        self.repository
+            .update_organization_allowed_models(organization_id, allowed_models.clone())
+            .await
+            .map_err(|e| {
+                let error_msg = e.to_string();
+                if error_msg.contains("not found") || error_msg.contains("inactive") {
+                    AdminError::OrganizationNotFound(format!(
+                        "Organization '{}' not found",
+                        organization_id
+                    ))
+                } else {
+                    AdminError::InternalError(error_msg)
+                }
+            })?;
+
+        self.completion_service
+            .invalidate_org_allowed_models(organization_id)
+            .await;
+
+        Ok(allowed_models)
+    }
+
+    async fn get_organization_allowed_models(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<Option<Vec<String>>, AdminError> {
+        self.repository
+            .get_organization_allowed_models(organization_id)
+            .await
+            .map_err(|e| {
+                let error_msg = e.to_string();
+                if error_msg.contains("not found") || error_msg.contains("inactive") {
+                    AdminError::OrganizationNotFound(format!(
+                        "Organization '{}' not found",
+                        organization_id
+                    ))
+                } else {
+                    AdminError::InternalError(error_msg)
+                }
+            })
+    }
+
+    async fn update_organization_seat_limit(
+        &self,
+        organization_id: uuid::Uuid,
+        max_members: Option<u32>,
+    ) -> Result<OrganizationSeatLimit, AdminError> {
+        if max_members == Some(0) || max_members.is_some_and(|max| max > i32::MAX as u32) {
+            return Err(AdminError::InvalidLimits(
+                "Seat limit must be a positive integer".to_string(),
+            ));
+        }
+
+        self.repository
+            .update_organization_max_members(organization_id, max_members)
+            .await
+            .map_err(|e| Self::map_organization_lookup_error(organization_id, e))?;
+
+        self.get_organization_seat_limit(organization_id).await
+    }
+
+    async fn get_organization_seat_limit(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<OrganizationSeatLimit, AdminError> {
+        self.repository
+            .get_organization_seat_limit(organization_id)
+            .await
+            .map_err(|e| Self::map_organization_lookup_error(organization_id, e))
+    }
+
     async fn list_organizations(
         &self,
         limit: i64,
@@ -1240,6 +1393,193 @@ impl AdminService for AdminServiceImpl {
 }
 
 impl AdminServiceImpl {
+    /// Repository errors for a per-organization setting: a missing or
+    /// inactive organization is a 404, anything else is internal.
+    fn map_organization_lookup_error(
+        organization_id: uuid::Uuid,
+        error: anyhow::Error,
+    ) -> AdminError {
+        let error_msg = error.to_string();
+        if error_msg.contains("not found") || error_msg.contains("inactive") {
+            AdminError::OrganizationNotFound(format!("Organization '{organization_id}' not found"))
+        } else {
+            AdminError::InternalError(error_msg)
+        }
+    }
+
+    /// Field-level changes `request` would make to `current`. Omitted request
+    /// fields leave the stored value alone and never show up as changes;
+    /// aliases compare as a set since an upsert replaces them wholesale.
+    fn diff_model_upsert(
+        model_name: &str,
+        current: &ModelUpsertBaseline,
+        request: &UpdateModelAdminRequest,
+    ) -> Vec<ModelFieldChange> {
+        struct Diff<'a> {
+            model_name: &'a str,
+            changes: Vec<ModelFieldChange>,
+        }
+
+        impl Diff<'_> {
+            fn field<T: PartialEq + serde::Serialize>(
+                &mut self,
+                field: &str,
+                old: T,
+                new: Option<T>,
+            ) {
+                let Some(new) = new else { return };
+                if old != new {
+                    self.changes.push(ModelFieldChange {
+                        model_name: self.model_name.to_string(),
+                        field: field.to_string(),
+                        old: serde_json::to_value(old).unwrap_or_default(),
+                        new: serde_json::to_value(new).unwrap_or_default(),
+                    });
+                }
+            }
+        }
+
+        let p = &current.pricing;
+        let r = request;
+        let mut d = Diff {
+            model_name,
+            changes: Vec::new(),
+        };
+
+        d.field(
+            "input_cost_per_token",
+            p.input_cost_per_token,
+            r.input_cost_per_token,
+        );
+        d.field(
+            "output_cost_per_token",
+            p.output_cost_per_token,
+            r.output_cost_per_token,
+        );
+        d.field("cost_per_image", p.cost_per_image, r.cost_per_image);
+        d.field(
+            "cache_read_cost_per_token",
+            p.cache_read_cost_per_token,
+            r.cache_read_cost_per_token,
+        );
+        d.field(
+            "model_display_name",
+            &p.model_display_name,
+            r.model_display_name.as_ref(),
+        );
+        d.field(
+            "model_description",
+            &p.model_description,
+            r.model_description.as_ref(),
+        );
+        d.field(
+            "model_icon",
+            p.model_icon.as_ref(),
+            r.model_icon.as_ref().map(Some),
+        );
+        d.field("context_length", p.context_length, r.context_length);
+        d.field("verifiable", p.verifiable, r.verifiable);
+        d.field("is_active", p.is_active, r.is_active);
+        d.field("allow_free", current.allow_free, r.allow_free);
+        d.field(
+            "default_max_tokens",
+            current.default_max_tokens,
+            r.default_max_tokens.map(Some),
+        );
+        d.field(
+            "max_max_tokens",
+            current.max_max_tokens,
+            r.max_max_tokens.map(Some),
+        );
+        d.field(
+            "reject_over_max_tokens",
+            current.reject_over_max_tokens,
+            r.reject_over_max_tokens,
+        );
+        d.field(
+            "request_timeout_secs",
+            p.request_timeout_secs,
+            r.request_timeout_secs,
+        );
+
+        let mut old_aliases = p.aliases.clone();
+        old_aliases.sort();
+        let new_aliases = r.aliases.as_ref().map(|aliases| {
+            let mut aliases = aliases.clone();
+            aliases.sort();
+            aliases.dedup();
+            aliases
+        });
+        d.field("aliases", old_aliases, new_aliases);
+
+        d.field("owned_by", &p.owned_by, r.owned_by.as_ref());
+        d.field("provider_type", &p.provider_type, r.provider_type.as_ref());
+        d.field(
+            "provider_config",
+            p.provider_config.as_ref(),
+            r.provider_config.as_ref().map(Some),
+        );
+        d.field(
+            "attestation_supported",
+            p.attestation_supported,
+            r.attestation_supported,
+        );
+        d.field(
+            "input_modalities",
+            p.input_modalities.as_ref(),
+            r.input_modalities.as_ref().map(Some),
+        );
+        d.field(
+            "output_modalities",
+            p.output_modalities.as_ref(),
+            r.output_modalities.as_ref().map(Some),
+        );
+        d.field(
+            "inference_url",
+            p.inference_url.as_ref(),
+            r.inference_url.as_ref().map(Some),
+        );
+        d.field(
+            "hugging_face_id",
+            p.hugging_face_id.as_ref(),
+            r.hugging_face_id.as_ref().map(Some),
+        );
+        d.field(
+            "quantization",
+            p.quantization.as_ref(),
+            r.quantization.as_ref().map(Some),
+        );
+        d.field(
+            "max_output_length",
+            p.max_output_length,
+            r.max_output_length.map(Some),
+        );
+        d.field(
+            "supported_sampling_parameters",
+            &p.supported_sampling_parameters,
+            r.supported_sampling_parameters.as_ref(),
+        );
+        d.field(
+            "supported_features",
+            &p.supported_features,
+            r.supported_features.as_ref(),
+        );
+        d.field(
+            "datacenters",
+            p.datacenters.as_ref(),
+            r.datacenters.as_ref().map(Some),
+        );
+        d.field("is_ready", p.is_ready, r.is_ready);
+        d.field("deprecation_date", p.deprecation_date, r.deprecation_date);
+        d.field(
+            "openrouter_slug",
+            p.openrouter_slug.as_ref(),
+            r.openrouter_slug.as_ref().map(Option::as_ref),
+        );
+
+        d.changes
+    }
+
     async fn validate_model_request(
         model_name: &str,
         request: &UpdateModelAdminRequest,
@@ -1335,9 +1675,13 @@ impl AdminServiceImpl {
         Ok(())
     }
 
-    fn validate_organization_limits(limits: &OrganizationLimitsUpdate) -> Result<(), AdminError> {
-        // All amounts use fixed scale 9 (nano-dollars) and USD - no scale/currency validation needed
-
+    /// Returns the canonical currency code to store. Amounts use fixed scale 9
+    /// in every currency; non-USD currencies must have a configured rate so
+    /// the usage check can convert them to USD.
+    fn validate_organization_limits(
+        &self,
+        limits: &OrganizationLimitsUpdate,
+    ) -> Result<String, AdminError> {
         // Validate amount is non-negative
         if limits.spend_limit < 0 {
             return Err(AdminError::InvalidLimits(
@@ -1345,6 +1689,15 @@ impl AdminServiceImpl {
             ));
         }
 
-        Ok(())
+        let currency = self
+            .currency_rates
+            .validate(&limits.currency)
+            .map_err(|e| AdminError::InvalidLimits(e.to_string()))?;
+        // Reject amounts that could not be compared against spend.
+        self.currency_rates
+            .to_usd_nanos(limits.spend_limit, &currency)
+            .map_err(|e| AdminError::InvalidLimits(e.to_string()))?;
+
+        Ok(currency)
     }
 }
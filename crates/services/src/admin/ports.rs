@@ -22,6 +22,17 @@ pub struct UpdateModelAdminRequest {
     pub is_active: Option<bool>,
     /// If true, allows activation even with zero pricing.
     pub allow_free: Option<bool>,
+    /// `max_tokens` applied when the client sends none.
+    pub default_max_tokens: Option<i32>,
+    /// Upper bound on client-requested `max_tokens`.
+    pub max_max_tokens: Option<i32>,
+    /// If true, requests above `max_max_tokens` are rejected instead of clamped.
+    pub reject_over_max_tokens: Option<bool>,
+    /// Inference request timeout in seconds; unset uses the global default.
+    ///
+    /// Tri-state: `None` = leave unchanged, `Some(None)` = clear to NULL,
+    /// `Some(Some(v))` = set to `v`.
+    pub request_timeout_secs: Option<Option<i32>>,
     pub aliases: Option<Vec<String>>,
     pub owned_by: Option<String>,
     // Provider configuration
@@ -99,6 +110,8 @@ pub struct ModelPricing {
     pub output_modalities: Option<Vec<String>>,
     /// Base URL for the model's inference endpoint
     pub inference_url: Option<String>,
+    /// Inference request timeout in seconds. `None` = global default.
+    pub request_timeout_secs: Option<i32>,
     // OpenRouter-compatibility fields
     pub hugging_face_id: Option<String>,
     pub quantization: Option<String>,
@@ -113,6 +126,39 @@ pub struct ModelPricing {
     pub openrouter_slug: Option<String>,
 }
 
+/// Stored state of a model as a batch upsert sees it: the persisted pricing
+/// and metadata plus the request-shaping fields `ModelPricing` leaves out.
+#[derive(Debug, Clone)]
+pub struct ModelUpsertBaseline {
+    pub pricing: ModelPricing,
+    pub allow_free: bool,
+    pub default_max_tokens: Option<i32>,
+    pub max_max_tokens: Option<i32>,
+    pub reject_over_max_tokens: bool,
+}
+
+/// One field a batch upsert would change on an existing model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelFieldChange {
+    pub model_name: String,
+    /// Request field name (snake_case, as in `UpdateModelAdminRequest`).
+    pub field: String,
+    pub old: serde_json::Value,
+    pub new: serde_json::Value,
+}
+
+/// Result of diffing a batch upsert against current records without
+/// persisting it.
+#[derive(Debug, Clone, Default)]
+pub struct BatchUpsertPreview {
+    /// Models that do not exist yet and would be inserted.
+    pub would_create: Vec<String>,
+    /// Field-level changes to existing models.
+    pub would_update: Vec<ModelFieldChange>,
+    /// Existing models the request would leave unchanged.
+    pub would_noop: Vec<String>,
+}
+
 /// Model history entry - includes pricing, context length, and other model attributes
 /// All costs use fixed scale of 9 (nano-dollars) and USD currency
 #[derive(Debug, Clone)]
@@ -202,6 +248,14 @@ pub struct OrganizationLimitsHistoryEntry {
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// An organization's seat limit alongside the seats in use
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrganizationSeatLimit {
+    /// None means unlimited
+    pub max_members: Option<u32>,
+    pub member_count: i64,
+}
+
 /// User information for admin endpoints
 #[derive(Debug, Clone)]
 pub struct UserInfo {
@@ -611,6 +665,13 @@ pub trait AdminRepository: Send + Sync {
         request: UpdateModelAdminRequest,
     ) -> Result<ModelPricing, anyhow::Error>;
 
+    /// Fetch the stored state of a model by name, including its active
+    /// aliases. Returns `None` if the model does not exist (new model).
+    async fn get_model_upsert_baseline(
+        &self,
+        model_name: &str,
+    ) -> Result<Option<ModelUpsertBaseline>, anyhow::Error>;
+
     /// Fetch the current pricing costs and allow_free flag for a model by name.
     /// Returns `None` if the model does not exist (new model).
     /// Returns `Some((input_cost, output_cost, cost_per_image, cache_read_cost_per_token, allow_free))`,
@@ -862,6 +923,34 @@ pub trait AdminRepository: Send + Sync {
         organization_id: uuid::Uuid,
     ) -> Result<Option<u32>, anyhow::Error>;
 
+    /// Replace the organization's model allowlist (canonical names)
+    /// Set to None to allow all models
+    async fn update_organization_allowed_models(
+        &self,
+        organization_id: uuid::Uuid,
+        allowed_models: Option<Vec<String>>,
+    ) -> Result<(), anyhow::Error>;
+
+    /// Get the organization's model allowlist
+    /// Returns None if all models are allowed
+    async fn get_organization_allowed_models(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<Option<Vec<String>>, anyhow::Error>;
+
+    /// Set the organization's seat limit; None removes it
+    async fn update_organization_max_members(
+        &self,
+        organization_id: uuid::Uuid,
+        max_members: Option<u32>,
+    ) -> Result<(), anyhow::Error>;
+
+    /// Get the organization's seat limit and current member count
+    async fn get_organization_seat_limit(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<OrganizationSeatLimit, anyhow::Error>;
+
     /// List all organizations with pagination (admin only)
     async fn list_all_organizations(
         &self,
@@ -963,6 +1052,13 @@ pub trait AdminService: Send + Sync {
         models: BatchUpdateModelAdminRequest,
     ) -> Result<BatchUpdateModelAdminResponse, AdminError>;
 
+    /// Validate a batch upsert and diff it against current records without
+    /// persisting anything (admin only)
+    async fn preview_batch_upsert_models(
+        &self,
+        models: &BatchUpdateModelAdminRequest,
+    ) -> Result<BatchUpsertPreview, AdminError>;
+
     /// Get complete history for a model with pagination (admin only) - includes pricing and other attributes
     async fn get_model_history(
         &self,
@@ -1107,6 +1203,37 @@ pub trait AdminService: Send + Sync {
         organization_id: uuid::Uuid,
     ) -> Result<Option<u32>, AdminError>;
 
+    /// Replace the organization's model allowlist (admin only)
+    /// Names and aliases are resolved to canonical names before storing;
+    /// None or an empty list allows all models. Returns the stored list.
+    async fn update_organization_allowed_models(
+        &self,
+        organization_id: uuid::Uuid,
+        allowed_models: Option<Vec<String>>,
+    ) -> Result<Option<Vec<String>>, AdminError>;
+
+    /// Get the organization's model allowlist (admin only)
+    /// Returns None if all models are allowed
+    async fn get_organization_allowed_models(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<Option<Vec<String>>, AdminError>;
+
+    /// Set the organization's seat limit (admin only)
+    /// None removes the limit. Lowering it below the current member count
+    /// keeps existing members but blocks new ones until seats free up.
+    async fn update_organization_seat_limit(
+        &self,
+        organization_id: uuid::Uuid,
+        max_members: Option<u32>,
+    ) -> Result<OrganizationSeatLimit, AdminError>;
+
+    /// Get the organization's seat limit and member count (admin only)
+    async fn get_organization_seat_limit(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<OrganizationSeatLimit, AdminError>;
+
     /// List all organizations with pagination (admin only)
     async fn list_organizations(
         &self,
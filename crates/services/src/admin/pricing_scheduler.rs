@@ -191,6 +191,10 @@ impl ModelPricingScheduler {
             verifiable: None,
             is_active: None,
             allow_free: None,
+            default_max_tokens: None,
+            max_max_tokens: None,
+            reject_over_max_tokens: None,
+            request_timeout_secs: None,
             aliases: None,
             owned_by: None,
             provider_type: None,
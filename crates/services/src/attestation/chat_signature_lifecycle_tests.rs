@@ -8,6 +8,8 @@
 //! (private-field struct literals are legal inside `crate::attestation`) with
 //! a real `InferenceProviderPool` seeded via `store_chat_id_mapping` and a
 //! `MockProvider` that records unpin calls.
+//!
+//! Also covers the single-flight guard on `store_chat_signature_from_provider`.
 
 use std::sync::{Arc, Mutex};
 
@@ -143,6 +145,14 @@ impl ModelsRepository for EmptyModelsRepository {
         Ok(None)
     }
 
+    async fn resolve_and_get_model_for_organization(
+        &self,
+        _organization_id: Uuid,
+        identifier: &str,
+    ) -> anyhow::Result<Option<ModelWithPricing>> {
+        self.resolve_and_get_model(identifier).await
+    }
+
     async fn get_configured_model_names(&self) -> anyhow::Result<Vec<String>> {
         Ok(Vec::new())
     }
@@ -154,6 +164,7 @@ impl MetricsServiceTrait for NoopMetricsService {
     fn record_latency(&self, _name: &str, _duration: std::time::Duration, _tags: &[&str]) {}
     fn record_count(&self, _name: &str, _value: i64, _tags: &[&str]) {}
     fn record_histogram(&self, _name: &str, _value: f64, _tags: &[&str]) {}
+    fn record_gauge(&self, _name: &str, _value: f64, _tags: &[&str]) {}
 }
 
 struct NoopUsageRepository;
@@ -211,10 +222,18 @@ impl UsageRepository for NoopUsageRepository {
     async fn get_stop_reason_by_provider_request_id(
         &self,
         _provider_request_id: &str,
+        _inference_ids: &[Uuid],
     ) -> anyhow::Result<Option<StopReason>> {
         Ok(None)
     }
 
+    async fn get_provider_request_id_by_inference_id(
+        &self,
+        _inference_id: Uuid,
+    ) -> anyhow::Result<Option<String>> {
+        Ok(None)
+    }
+
     async fn get_usage_by_model(
         &self,
         _organization_id: Uuid,
@@ -223,6 +242,13 @@ impl UsageRepository for NoopUsageRepository {
         Ok(Vec::new())
     }
 
+    async fn get_hourly_spend_since(
+        &self,
+        _start_date: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<crate::usage::ports::HourlyOrganizationSpend>> {
+        Ok(Vec::new())
+    }
+
     async fn list_inference_usage_report(
         &self,
         _query: InferenceUsageReportQuery,
@@ -275,6 +301,8 @@ fn lifecycle_service(
         gateway_quote_collector: Arc::new(DstackGatewayQuoteCollector),
         model_attestation_collector: Arc::new(ProviderPoolModelAttestationCollector::new(pool)),
         report_cache: None,
+        signature_fetches_in_flight: Default::default(),
+        inference_id_namespace: Default::default(),
     }
 }
 
@@ -388,3 +416,47 @@ async fn release_chat_signature_pin_is_a_noop_without_a_mapping() {
     service.release_chat_signature_pin("chatcmpl-unknown").await;
     assert!(repository.stored().is_empty());
 }
+
+#[tokio::test]
+async fn concurrent_provider_signature_stores_for_one_chat_id_fetch_once() {
+    let chat_id = "chatcmpl-lifecycle-concurrent-store";
+    let pool = Arc::new(InferenceProviderPool::new(
+        None,
+        ExternalProvidersConfig::default(),
+    ));
+    // The delay keeps the first fetch in flight while the others arrive.
+    let provider =
+        Arc::new(MockProvider::new().with_signature_delay(std::time::Duration::from_millis(50)));
+    pool.store_chat_id_mapping(chat_id.to_string(), provider.clone())
+        .await;
+    let repository = RecordingRepository::default();
+    let service = lifecycle_service(Arc::new(repository.clone()), pool);
+
+    let results = futures::future::join_all(
+        (0..5).map(|_| service.store_chat_signature_from_provider(chat_id)),
+    )
+    .await;
+
+    assert!(results.iter().all(|r| r.is_ok()), "{results:?}");
+    assert_eq!(
+        provider.signature_request_count(),
+        2,
+        "one provider fetch per signing algorithm, not per caller"
+    );
+    assert_eq!(repository.stored().len(), 2, "one DB write per algorithm");
+    assert!(
+        service
+            .signature_fetches_in_flight
+            .lock()
+            .unwrap()
+            .is_empty(),
+        "the in-flight entry must be removed once the fetch finishes"
+    );
+
+    // A store after the coalesced fetch finished runs a fresh fetch.
+    service
+        .store_chat_signature_from_provider(chat_id)
+        .await
+        .unwrap();
+    assert_eq!(provider.signature_request_count(), 4);
+}
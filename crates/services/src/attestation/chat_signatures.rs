@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use uuid::Uuid;
 
@@ -22,6 +22,8 @@ impl AttestationService {
         let signing_algo = signing_algo
             .map(|s| s.to_lowercase())
             .unwrap_or_else(|| "ecdsa".to_string());
+        let resolved = self.resolve_inference_id(chat_id).await?;
+        let chat_id = resolved.as_deref().unwrap_or(chat_id);
 
         match self
             .repository
@@ -51,9 +53,59 @@ impl AttestationService {
         }
     }
 
+    /// Clients that only kept the `Inference-Id` UUID (the hashed form stored
+    /// on usage rows) can look signatures up by it: map it back to the chat_id
+    /// recorded with the usage. Returns `None` for anything that isn't a known
+    /// inference ID, so raw chat ids pass through untouched.
+    async fn resolve_inference_id(&self, id: &str) -> Result<Option<String>, AttestationError> {
+        let Ok(inference_id) = Uuid::parse_str(id) else {
+            return Ok(None);
+        };
+        self.usage_repository
+            .get_provider_request_id_by_inference_id(inference_id)
+            .await
+            .map_err(|e| AttestationError::RepositoryError(e.to_string()))
+    }
+
+    /// Single-flight wrapper around [`Self::fetch_and_store_provider_signature`]:
+    /// concurrent calls for the same `chat_id` (streaming tail racing the
+    /// cancel/verify path) share one provider fetch and one DB write, and all
+    /// receive its result. A call arriving after the fetch finished starts a
+    /// new one.
     pub(in crate::attestation) async fn store_chat_signature_from_provider_impl(
         &self,
         chat_id: &str,
+    ) -> Result<(), AttestationError> {
+        let cell = self
+            .signature_fetches_in_flight
+            .lock()
+            .map_err(|_| {
+                AttestationError::InternalError("signature fetch map lock poisoned".to_string())
+            })?
+            .entry(chat_id.to_string())
+            .or_default()
+            .clone();
+
+        // If the running fetch is cancelled, a waiting caller takes over.
+        let result = cell
+            .get_or_init(|| self.fetch_and_store_provider_signature(chat_id))
+            .await
+            .clone();
+
+        if let Ok(mut in_flight) = self.signature_fetches_in_flight.lock() {
+            if in_flight
+                .get(chat_id)
+                .is_some_and(|current| Arc::ptr_eq(current, &cell))
+            {
+                in_flight.remove(chat_id);
+            }
+        }
+        result
+    }
+
+    async fn fetch_and_store_provider_signature(
+        &self,
+        chat_id: &str,
     ) -> Result<(), AttestationError> {
         let start_time = std::time::Instant::now();
         let provider = self
@@ -229,7 +281,10 @@ impl AttestationService {
                 .map_err(|e| AttestationError::RepositoryError(e.to_string()))?
         } else {
             self.usage_repository
-                .get_stop_reason_by_provider_request_id(chat_id)
+                .get_stop_reason_by_provider_request_id(
+                    chat_id,
+                    &self.inference_id_namespace.lookup_candidates(chat_id),
+                )
                 .await
                 .map_err(|e| AttestationError::RepositoryError(e.to_string()))?
         };
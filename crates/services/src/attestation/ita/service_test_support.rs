@@ -95,6 +95,8 @@ fn service_with_collectors(
         gateway_quote_collector: Arc::new(gateway_collector),
         model_attestation_collector: Arc::new(model_collector),
         report_cache: None,
+        signature_fetches_in_flight: Default::default(),
+        inference_id_namespace: Default::default(),
     }
 }
 
@@ -250,6 +252,14 @@ impl ModelsRepository for FakeModelsRepository {
         Ok((identifier == "alias-model" || identifier == "canonical-model").then(canonical_model))
     }
 
+    async fn resolve_and_get_model_for_organization(
+        &self,
+        _organization_id: Uuid,
+        identifier: &str,
+    ) -> anyhow::Result<Option<ModelWithPricing>> {
+        self.resolve_and_get_model(identifier).await
+    }
+
     async fn get_configured_model_names(&self) -> anyhow::Result<Vec<String>> {
         Ok(vec!["canonical-model".to_string()])
     }
@@ -285,6 +295,7 @@ fn canonical_model() -> ModelWithPricing {
         is_ready: None,
         deprecation_date: None,
         openrouter_slug: None,
+        max_tokens_limits: Default::default(),
         created_at: chrono::Utc::now(),
     }
 }
@@ -316,6 +327,7 @@ impl MetricsServiceTrait for NoopMetricsService {
     fn record_latency(&self, _name: &str, _duration: Duration, _tags: &[&str]) {}
     fn record_count(&self, _name: &str, _value: i64, _tags: &[&str]) {}
     fn record_histogram(&self, _name: &str, _value: f64, _tags: &[&str]) {}
+    fn record_gauge(&self, _name: &str, _value: f64, _tags: &[&str]) {}
 }
 
 struct NoopUsageRepository;
@@ -373,10 +385,18 @@ impl UsageRepository for NoopUsageRepository {
     async fn get_stop_reason_by_provider_request_id(
         &self,
         _provider_request_id: &str,
+        _inference_ids: &[Uuid],
     ) -> anyhow::Result<Option<StopReason>> {
         Ok(None)
     }
 
+    async fn get_provider_request_id_by_inference_id(
+        &self,
+        _inference_id: Uuid,
+    ) -> anyhow::Result<Option<String>> {
+        Ok(None)
+    }
+
     async fn get_usage_by_model(
         &self,
         _organization_id: Uuid,
@@ -385,6 +405,13 @@ impl UsageRepository for NoopUsageRepository {
         Ok(Vec::new())
     }
 
+    async fn get_hourly_spend_since(
+        &self,
+        _start_date: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<crate::usage::ports::HourlyOrganizationSpend>> {
+        Ok(Vec::new())
+    }
+
     async fn list_inference_usage_report(
         &self,
         _query: InferenceUsageReportQuery,
@@ -142,6 +142,18 @@ impl AttestationService {
             gateway_quote_collector: Arc::new(DstackGatewayQuoteCollector),
             model_attestation_collector,
             report_cache,
+            signature_fetches_in_flight: Default::default(),
+            inference_id_namespace: Default::default(),
         })
     }
+
+    /// Look up usage rows for chat IDs hashed under `namespace` rather than
+    /// the default one.
+    pub fn with_inference_id_namespace(
+        mut self,
+        namespace: crate::completions::InferenceIdNamespace,
+    ) -> Self {
+        self.inference_id_namespace = namespace;
+        self
+    }
 }
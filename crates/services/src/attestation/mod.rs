@@ -14,6 +14,7 @@ pub mod ports;
 mod report;
 pub mod report_data;
 mod service_trait;
+pub mod signature_verification;
 pub mod verification;
 
 use std::sync::Arc;
@@ -29,8 +30,13 @@ pub use gateway_quote::{DstackGatewayQuoteCollector, GatewayQuoteCollector, Gate
 pub use ita::{ModelAttestationCollector, ModelAttestationInput};
 pub use measurement::MeasurementPolicy;
 pub use models::{AttestationError, ChatSignature, SignatureKind, SignatureLookupResult};
+pub use report::verify_report_nonce;
 pub(in crate::attestation) use report::{decode_nonce_hex, generate_nonce_hex};
 pub use report_data::{ReportDataVerifier, StrictBoundReportDataVerifier};
+pub use signature_verification::{
+    verify_chat_signature, verify_ecdsa_signature, verify_ed25519_signature, verify_signature,
+    SignatureVerification, SignatureVerificationError,
+};
 pub use verification::{AttestationVerificationError, AttestationVerifier, VerifiedAttestation};
 
 use crate::{
@@ -67,4 +73,13 @@ pub struct AttestationService {
     /// the GPU evidence — serving a cached report for a different nonce would
     /// defeat the freshness/replay guarantee.
     report_cache: Option<moka::future::Cache<String, Arc<models::AttestationReport>>>,
+    /// In-flight provider signature fetches keyed by chat_id; see
+    /// `store_chat_signature_from_provider_impl`.
+    signature_fetches_in_flight: SignatureFetchesInFlight,
+    /// Namespace chat IDs are hashed under when looking up their usage rows.
+    inference_id_namespace: crate::completions::InferenceIdNamespace,
 }
+
+type SignatureFetchesInFlight = std::sync::Mutex<
+    std::collections::HashMap<String, Arc<tokio::sync::OnceCell<Result<(), AttestationError>>>>,
+>;
@@ -1,3 +1,4 @@
+use inference_providers::ProviderTier;
 use serde::{Deserialize, Serialize};
 /// Error types for attestation operations
 #[derive(Debug, Clone, thiserror::Error)]
@@ -20,6 +21,11 @@ pub enum AttestationError {
     #[error("Internal error: {0}")]
     InternalError(String),
 
+    /// The returned report does not embed the caller-supplied nonce, so it
+    /// cannot be shown to be fresh (possible replay or stale cache).
+    #[error("Attestation nonce mismatch: {0}")]
+    NonceMismatch(String),
+
     #[error("ITA attestation is unavailable: {reason}")]
     ItaUnavailable { reason: String },
 
@@ -175,4 +181,44 @@ pub struct AttestationReport {
     pub tls_certificate: Option<String>,
 }
 
+/// Outcome of checking one provider's attestation report for a model.
+#[derive(Debug, Clone)]
+pub struct ProviderAttestationCheck {
+    /// Position of the provider in the model's routing order.
+    pub provider_index: usize,
+    pub tier: ProviderTier,
+    /// The provider returned a report.
+    pub report_fetched: bool,
+    /// The report echoes the nonce it was requested with.
+    pub nonce_verified: bool,
+    /// The report's quote verified and binds the nonce.
+    pub quote_verified: bool,
+    /// Why the check failed; `None` when it passed.
+    pub failure: Option<String>,
+    pub attestation: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl ProviderAttestationCheck {
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Attestation verified across every provider serving a model, bound to one
+/// gateway-generated nonce.
+#[derive(Debug, Clone)]
+pub struct ModelAttestationVerification {
+    pub model: String,
+    pub signing_algo: String,
+    pub nonce: String,
+    pub providers: Vec<ProviderAttestationCheck>,
+}
+
+impl ModelAttestationVerification {
+    /// True when every provider passed.
+    pub fn verified(&self) -> bool {
+        !self.providers.is_empty() && self.providers.iter().all(|p| p.passed())
+    }
+}
+
 pub type DstackAppInfo = dstack_sdk::dstack_client::InfoResponse;
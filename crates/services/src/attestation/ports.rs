@@ -1,7 +1,9 @@
 use crate::attestation::ita::{ItaTokenQuery, ItaTokenResponse};
 use crate::attestation::models::{
-    AttestationError, AttestationReport, ChatSignature, SignatureLookupResult,
+    AttestationError, AttestationReport, ChatSignature, ModelAttestationVerification,
+    SignatureLookupResult,
 };
+use crate::attestation::signature_verification::{verify_chat_signature, SignatureVerification};
 use async_trait::async_trait;
 use inference_providers::ProviderTier;
 
@@ -16,6 +18,24 @@ pub trait AttestationServiceTrait: Send + Sync {
         signing_algo: Option<String>,
     ) -> Result<SignatureLookupResult, AttestationError>;
 
+    /// Look up the stored signature for `chat_id` and verify it server-side.
+    ///
+    /// Returns the verified message components whether or not the signature
+    /// checks out (`verified` carries the outcome); a missing or unavailable
+    /// signature is [`AttestationError::SignatureNotFound`].
+    async fn verify_chat_signature(
+        &self,
+        chat_id: &str,
+        signing_algo: Option<String>,
+    ) -> Result<SignatureVerification, AttestationError> {
+        match self.get_chat_signature(chat_id, signing_algo).await? {
+            SignatureLookupResult::Found(signature) => Ok(verify_chat_signature(&signature)),
+            SignatureLookupResult::Unavailable { message, .. } => {
+                Err(AttestationError::SignatureNotFound(message))
+            }
+        }
+    }
+
     /// Fetch signature from provider and store it in the database
     /// This should be called when a completion finishes
     async fn store_chat_signature_from_provider(
@@ -83,6 +103,45 @@ pub trait AttestationServiceTrait: Send + Sync {
         provider_filter: Option<ProviderTier>,
     ) -> Result<AttestationReport, AttestationError>;
 
+    /// Fetch a hardware attestation report bound to a caller-supplied nonce.
+    ///
+    /// Unlike [`Self::get_attestation_report`] the nonce is mandatory and the
+    /// returned report is checked to embed it (see
+    /// [`crate::attestation::verify_report_nonce`]), so callers get either a
+    /// provably fresh report or [`AttestationError::NonceMismatch`].
+    async fn get_attestation_quote(
+        &self,
+        model: Option<String>,
+        signing_algo: Option<String>,
+        nonce: String,
+        signing_address: Option<String>,
+        include_tls_fingerprint: bool,
+        provider_filter: Option<ProviderTier>,
+    ) -> Result<AttestationReport, AttestationError> {
+        let report = self
+            .get_attestation_report(
+                model,
+                signing_algo,
+                Some(nonce.clone()),
+                signing_address,
+                include_tls_fingerprint,
+                provider_filter,
+            )
+            .await?;
+        crate::attestation::verify_report_nonce(&report, &nonce)?;
+        Ok(report)
+    }
+
+    /// Fetch attestation reports from every provider serving `model`, bound
+    /// to a fresh gateway-generated nonce, and check each one individually.
+    /// A provider failing its check is reported in the result, not as an
+    /// error; errors are reserved for an unknown model or no providers.
+    async fn verify_model_attestation(
+        &self,
+        model: String,
+        signing_algo: Option<String>,
+    ) -> Result<ModelAttestationVerification, AttestationError>;
+
     async fn get_ita_attestation_token(
         &self,
         query: ItaTokenQuery,
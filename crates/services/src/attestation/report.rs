@@ -2,7 +2,11 @@ use std::sync::Arc;
 
 use rand_core::{OsRng, RngCore};
 
-use super::{models::AttestationReport, AttestationError, AttestationService, GatewayQuoteInput};
+use super::{
+    models::{AttestationReport, ModelAttestationVerification, ProviderAttestationCheck},
+    AttestationError, AttestationService, GatewayQuoteInput,
+};
+use crate::inference_provider_pool::ProviderAttestationFetch;
 use crate::metrics::consts::{
     get_environment, METRIC_ATTESTATION_REPORT_CACHE, TAG_ENVIRONMENT, TAG_RESULT,
 };
@@ -61,6 +65,126 @@ fn report_cache_key(
     )
 }
 
+/// Check that `report` is bound to the caller-supplied `nonce`.
+///
+/// The gateway quote must carry the nonce both as `request_nonce` and in
+/// `report_data[32:64]` (the bytes sealed inside the TDX quote), and every model
+/// attestation must echo it back as `request_nonce`. A report that fails any of
+/// these checks cannot be shown to be fresh and is rejected with
+/// [`AttestationError::NonceMismatch`].
+pub fn verify_report_nonce(
+    report: &AttestationReport,
+    nonce: &str,
+) -> Result<(), AttestationError> {
+    let nonce_bytes = decode_nonce_hex(nonce)?;
+
+    let gateway = &report.gateway_attestation;
+    if !gateway.request_nonce.eq_ignore_ascii_case(nonce) {
+        return Err(AttestationError::NonceMismatch(
+            "gateway attestation request_nonce does not match the requested nonce".to_string(),
+        ));
+    }
+    let report_data = hex::decode(
+        gateway
+            .report_data
+            .strip_prefix("0x")
+            .unwrap_or(&gateway.report_data),
+    )
+    .map_err(|e| {
+        AttestationError::NonceMismatch(format!("gateway report_data is not valid hex: {e}"))
+    })?;
+    if report_data.len() != 64 || report_data[32..64] != nonce_bytes[..] {
+        return Err(AttestationError::NonceMismatch(
+            "gateway report_data[32:64] does not embed the requested nonce".to_string(),
+        ));
+    }
+
+    for attestation in &report.model_attestations {
+        let model = attestation
+            .get("model")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown");
+        match attestation.get("request_nonce").and_then(|v| v.as_str()) {
+            Some(echoed) if echoed.eq_ignore_ascii_case(nonce) => {}
+            Some(_) => {
+                return Err(AttestationError::NonceMismatch(format!(
+                    "model attestation for '{model}' was issued for a different nonce"
+                )));
+            }
+            None => {
+                return Err(AttestationError::NonceMismatch(format!(
+                    "model attestation for '{model}' does not include request_nonce"
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check one provider's outcome for a multi-provider verification: the report
+/// must have been fetched, must echo `nonce` as `request_nonce`, and its quote
+/// must have verified. Failure reasons are fixed labels; upstream error text
+/// is only logged.
+pub(in crate::attestation) fn check_provider_attestation(
+    provider_index: usize,
+    fetch: ProviderAttestationFetch,
+    nonce: &str,
+) -> ProviderAttestationCheck {
+    let tier = fetch.tier;
+    let attestation = match fetch.report {
+        Ok(attestation) => attestation,
+        Err(e) => {
+            tracing::warn!(
+                provider_index,
+                tier = tier.as_str(),
+                error = %e,
+                "Provider attestation fetch failed during model verification"
+            );
+            return ProviderAttestationCheck {
+                provider_index,
+                tier,
+                report_fetched: false,
+                nonce_verified: false,
+                quote_verified: false,
+                failure: Some("attestation report could not be fetched".to_string()),
+                attestation: None,
+            };
+        }
+    };
+
+    let nonce_failure = match attestation.get("request_nonce").and_then(|v| v.as_str()) {
+        Some(echoed) if echoed.eq_ignore_ascii_case(nonce) => None,
+        Some(_) => Some("report was issued for a different nonce"),
+        None => Some("report does not include request_nonce"),
+    };
+    let quote_verified = match fetch.quote_verification {
+        Some(Ok(())) => true,
+        Some(Err(e)) => {
+            tracing::warn!(
+                provider_index,
+                tier = tier.as_str(),
+                error = %e,
+                "Provider attestation quote failed verification during model verification"
+            );
+            false
+        }
+        None => false,
+    };
+    let failure = nonce_failure
+        .or((!quote_verified).then_some("attestation quote failed verification"))
+        .map(str::to_string);
+    ProviderAttestationCheck {
+        provider_index,
+        tier,
+        report_fetched: true,
+        nonce_verified: nonce_failure.is_none(),
+        quote_verified,
+        failure,
+        attestation: Some(attestation),
+    }
+}
+
 fn normalize_signing_algo(signing_algo: Option<&str>) -> Result<String, AttestationError> {
     let algo = signing_algo
         .map(str::to_lowercase)
@@ -76,6 +200,51 @@ fn normalize_signing_algo(signing_algo: Option<&str>) -> Result<String, Attestat
 }
 
 impl AttestationService {
+    pub(in crate::attestation) async fn verify_model_attestation_impl(
+        &self,
+        model: String,
+        signing_algo: Option<String>,
+    ) -> Result<ModelAttestationVerification, AttestationError> {
+        let algo = normalize_signing_algo(signing_algo.as_deref())?;
+        let canonical = self
+            .models_repository
+            .resolve_and_get_model(&model)
+            .await
+            .map_err(|e| AttestationError::ProviderError(format!("Failed to resolve model: {e}")))?
+            .ok_or_else(|| {
+                AttestationError::ProviderError(format!(
+                    "Model '{model}' not found. It's not a valid model name or alias."
+                ))
+            })?
+            .model_name;
+
+        // A fresh gateway-generated nonce: every provider must prove it
+        // produced its report for this request.
+        let nonce = generate_nonce_hex();
+        let reports = self
+            .inference_provider_pool
+            .get_attestation_reports_from_all_providers(
+                &canonical,
+                Some(algo.clone()),
+                nonce.clone(),
+            )
+            .await
+            .map_err(|e| AttestationError::ProviderError(e.to_string()))?;
+
+        let providers = reports
+            .into_iter()
+            .enumerate()
+            .map(|(index, fetch)| check_provider_attestation(index, fetch, &nonce))
+            .collect();
+
+        Ok(ModelAttestationVerification {
+            model: canonical,
+            signing_algo: algo,
+            nonce,
+            providers,
+        })
+    }
+
     pub(in crate::attestation) async fn get_attestation_report_impl(
         &self,
         model: Option<String>,
@@ -376,3 +545,173 @@ mod cache_key_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod nonce_freshness_tests {
+    use super::{check_provider_attestation, verify_report_nonce};
+    use crate::attestation::models::{AttestationReport, DstackCpuQuote};
+    use crate::attestation::AttestationError;
+    use crate::inference_provider_pool::ProviderAttestationFetch;
+
+    const NONCE: &str = "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef";
+    const OTHER_NONCE: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+    fn report_for(gateway_nonce: &str, model_nonce: Option<&str>) -> AttestationReport {
+        let mut report_data = vec![0u8; 64];
+        report_data[32..64].copy_from_slice(&hex::decode(gateway_nonce).unwrap());
+        let mut model = serde_json::Map::new();
+        model.insert("model".to_string(), serde_json::json!("test-model"));
+        if let Some(nonce) = model_nonce {
+            model.insert("request_nonce".to_string(), serde_json::json!(nonce));
+        }
+        AttestationReport {
+            gateway_attestation: DstackCpuQuote {
+                signing_address: "0xabc".to_string(),
+                signing_algo: "ecdsa".to_string(),
+                intel_quote: "00".to_string(),
+                event_log: "[]".to_string(),
+                report_data: hex::encode(report_data),
+                request_nonce: gateway_nonce.to_string(),
+                info: serde_json::json!({}),
+                vpc: None,
+                tls_cert_fingerprint: None,
+            },
+            model_attestations: vec![model],
+            tls_certificate: None,
+        }
+    }
+
+    #[test]
+    fn accepts_report_embedding_requested_nonce() {
+        let report = report_for(NONCE, Some(NONCE));
+        assert!(verify_report_nonce(&report, NONCE).is_ok());
+        // Hex comparison is case-insensitive.
+        assert!(verify_report_nonce(&report, &NONCE.to_uppercase()).is_ok());
+    }
+
+    #[test]
+    fn rejects_stale_gateway_quote() {
+        let report = report_for(OTHER_NONCE, Some(NONCE));
+        assert!(matches!(
+            verify_report_nonce(&report, NONCE),
+            Err(AttestationError::NonceMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_gateway_report_data_without_nonce() {
+        let mut report = report_for(NONCE, Some(NONCE));
+        report.gateway_attestation.report_data = hex::encode([0u8; 64]);
+        assert!(matches!(
+            verify_report_nonce(&report, NONCE),
+            Err(AttestationError::NonceMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_model_attestation_with_other_or_missing_nonce() {
+        let stale = report_for(NONCE, Some(OTHER_NONCE));
+        assert!(matches!(
+            verify_report_nonce(&stale, NONCE),
+            Err(AttestationError::NonceMismatch(_))
+        ));
+        let missing = report_for(NONCE, None);
+        assert!(matches!(
+            verify_report_nonce(&missing, NONCE),
+            Err(AttestationError::NonceMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_nonce_as_invalid_parameter() {
+        let report = report_for(NONCE, Some(NONCE));
+        assert!(matches!(
+            verify_report_nonce(&report, "ff"),
+            Err(AttestationError::InvalidParameter(_))
+        ));
+    }
+
+    fn provider_report(nonce: Option<&str>) -> serde_json::Map<String, serde_json::Value> {
+        let mut report = serde_json::Map::new();
+        report.insert("model".to_string(), serde_json::json!("test-model"));
+        if let Some(nonce) = nonce {
+            report.insert("request_nonce".to_string(), serde_json::json!(nonce));
+        }
+        report
+    }
+
+    fn fetched(
+        tier: inference_providers::ProviderTier,
+        report: serde_json::Map<String, serde_json::Value>,
+        quote_verification: Result<(), String>,
+    ) -> ProviderAttestationFetch {
+        ProviderAttestationFetch {
+            tier,
+            report: Ok(report),
+            quote_verification: Some(quote_verification),
+        }
+    }
+
+    #[test]
+    fn provider_check_passes_when_report_echoes_nonce_and_quote_verifies() {
+        let check = check_provider_attestation(
+            0,
+            fetched(
+                inference_providers::ProviderTier::Near,
+                provider_report(Some(NONCE)),
+                Ok(()),
+            ),
+            NONCE,
+        );
+        assert!(check.passed());
+        assert!(check.report_fetched && check.nonce_verified && check.quote_verified);
+        assert!(check.attestation.is_some());
+    }
+
+    #[test]
+    fn provider_check_fails_when_quote_does_not_verify() {
+        // Echoing the nonce alone is a claim any upstream can make.
+        let check = check_provider_attestation(
+            0,
+            fetched(
+                inference_providers::ProviderTier::Near,
+                provider_report(Some(NONCE)),
+                Err("missing field intel_quote from upstream".to_string()),
+            ),
+            NONCE,
+        );
+        assert!(!check.passed());
+        assert!(check.report_fetched && check.nonce_verified && !check.quote_verified);
+        let failure = check.failure.unwrap();
+        assert_eq!(failure, "attestation quote failed verification");
+    }
+
+    #[test]
+    fn provider_check_fails_on_fetch_error_or_wrong_nonce() {
+        let failed_fetch = check_provider_attestation(
+            1,
+            ProviderAttestationFetch {
+                tier: inference_providers::ProviderTier::Attested3p,
+                report: Err(inference_providers::models::AttestationError::FetchError(
+                    "upstream said no".to_string(),
+                )),
+                quote_verification: None,
+            },
+            NONCE,
+        );
+        assert!(!failed_fetch.passed());
+        assert!(!failed_fetch.report_fetched && !failed_fetch.quote_verified);
+        // Upstream error text is logged, never surfaced.
+        assert!(!failed_fetch.failure.unwrap().contains("upstream said no"));
+
+        for report in [provider_report(Some(OTHER_NONCE)), provider_report(None)] {
+            let check = check_provider_attestation(
+                0,
+                fetched(inference_providers::ProviderTier::Near, report, Ok(())),
+                NONCE,
+            );
+            assert!(!check.passed());
+            assert!(check.report_fetched && !check.nonce_verified);
+        }
+    }
+}
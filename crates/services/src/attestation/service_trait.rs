@@ -2,7 +2,7 @@ use async_trait::async_trait;
 
 use super::{
     ita::{ItaTokenQuery, ItaTokenResponse},
-    models::AttestationReport,
+    models::{AttestationReport, ModelAttestationVerification},
     ports, AttestationError, AttestationService, SignatureLookupResult,
 };
 use inference_providers::ProviderTier;
@@ -78,6 +78,15 @@ impl ports::AttestationServiceTrait for AttestationService {
         .await
     }
 
+    async fn verify_model_attestation(
+        &self,
+        model: String,
+        signing_algo: Option<String>,
+    ) -> Result<ModelAttestationVerification, AttestationError> {
+        self.verify_model_attestation_impl(model, signing_algo)
+            .await
+    }
+
     async fn get_ita_attestation_token(
         &self,
         query: ItaTokenQuery,
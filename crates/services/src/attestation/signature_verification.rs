@@ -0,0 +1,283 @@
+//! Verification of stored chat/response signatures.
+//!
+//! Mirrors the signing side in `gateway_signatures.rs` (and the provider TEEs):
+//! - `ecdsa`: secp256k1 over the Ethereum signed-message digest
+//!   (`keccak256("\x19Ethereum Signed Message:\n{len}{text}")`), 65-byte
+//!   `r || s || v` signature, checked by recovering the signer's Ethereum address.
+//! - `ed25519`: strict Ed25519 over the raw text, checked against the 32-byte
+//!   public key stored as the signing address.
+
+use ed25519_dalek::{Signature as Ed25519Signature, VerifyingKey as Ed25519VerifyingKey};
+use k256::ecdsa::{RecoveryId, Signature as EcdsaSignature, VerifyingKey as EcdsaVerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+use super::models::{ChatSignature, SignatureKind};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SignatureVerificationError {
+    #[error("Unsupported signing algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+
+    #[error("Invalid format: {0}")]
+    InvalidFormat(String),
+
+    #[error("Signature mismatch: {0}")]
+    Mismatch(String),
+}
+
+/// Outcome of verifying a stored signature, with the components that were checked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureVerification {
+    pub verified: bool,
+    pub signing_algo: String,
+    /// Ethereum address (`ecdsa`) or hex public key (`ed25519`) the signature was checked against.
+    pub signing_address: String,
+    pub request_hash: Option<String>,
+    pub response_hash: Option<String>,
+    /// The exact signed message.
+    pub message: String,
+    pub signature_kind: Option<SignatureKind>,
+    /// Why verification failed; `None` when `verified` is true.
+    pub error: Option<String>,
+}
+
+/// Build the Ethereum signed-message digest used for `ecdsa` signatures.
+fn ethereum_message_hash(message: &str) -> [u8; 32] {
+    let message_bytes = message.as_bytes();
+    let prefix = format!("\x19Ethereum Signed Message:\n{}", message_bytes.len());
+    let mut hasher = Keccak256::new();
+    hasher.update(prefix.as_bytes());
+    hasher.update(message_bytes);
+    hasher.finalize().into()
+}
+
+fn decode_hex(field: &str, value: &str) -> Result<Vec<u8>, SignatureVerificationError> {
+    hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|e| SignatureVerificationError::InvalidFormat(format!("{field} hex decode: {e}")))
+}
+
+/// Verify an Ethereum-style recoverable ECDSA signature over `message` against
+/// `signing_address` (20-byte Ethereum address, hex, optional `0x`).
+pub fn verify_ecdsa_signature(
+    message: &str,
+    signature_hex: &str,
+    signing_address: &str,
+) -> Result<(), SignatureVerificationError> {
+    let signature_bytes = decode_hex("signature", signature_hex)?;
+    if signature_bytes.len() != 65 {
+        return Err(SignatureVerificationError::InvalidFormat(format!(
+            "ECDSA signature must be 65 bytes, got {}",
+            signature_bytes.len()
+        )));
+    }
+
+    // Ethereum v = 27 + recovery_bit
+    let ethereum_v = signature_bytes[64];
+    if ethereum_v != 27 && ethereum_v != 28 {
+        return Err(SignatureVerificationError::InvalidFormat(format!(
+            "ECDSA recovery byte must be 27 or 28, got {ethereum_v}"
+        )));
+    }
+    let signature = EcdsaSignature::from_slice(&signature_bytes[..64]).map_err(|e| {
+        SignatureVerificationError::InvalidFormat(format!("ECDSA signature parse: {e}"))
+    })?;
+    let recovery_id = RecoveryId::try_from(ethereum_v - 27).map_err(|e| {
+        SignatureVerificationError::InvalidFormat(format!("ECDSA recovery id: {e}"))
+    })?;
+
+    let recovered_key = EcdsaVerifyingKey::recover_from_prehash(
+        &ethereum_message_hash(message),
+        &signature,
+        recovery_id,
+    )
+    .map_err(|e| SignatureVerificationError::Mismatch(format!("public key recovery: {e}")))?;
+
+    // Ethereum address = last 20 bytes of keccak256(uncompressed pubkey without 0x04)
+    let encoded_point = recovered_key.to_encoded_point(false);
+    let address_hash = Keccak256::digest(&encoded_point.as_bytes()[1..]);
+    let recovered_address = hex::encode(&address_hash[12..]);
+
+    let expected_address = signing_address
+        .strip_prefix("0x")
+        .unwrap_or(signing_address);
+    if !recovered_address.eq_ignore_ascii_case(expected_address) {
+        return Err(SignatureVerificationError::Mismatch(format!(
+            "recovered address 0x{recovered_address} does not match signing address"
+        )));
+    }
+    Ok(())
+}
+
+/// Verify a strict Ed25519 signature over `message` against `public_key_hex`
+/// (32-byte public key, hex, optional `0x`).
+pub fn verify_ed25519_signature(
+    message: &str,
+    signature_hex: &str,
+    public_key_hex: &str,
+) -> Result<(), SignatureVerificationError> {
+    let signature_bytes = decode_hex("signature", signature_hex)?;
+    let signature = Ed25519Signature::from_slice(&signature_bytes).map_err(|e| {
+        SignatureVerificationError::InvalidFormat(format!("Ed25519 signature parse: {e}"))
+    })?;
+
+    let public_key_bytes = decode_hex("public key", public_key_hex)?;
+    let public_key_bytes: [u8; 32] = public_key_bytes.as_slice().try_into().map_err(|_| {
+        SignatureVerificationError::InvalidFormat(format!(
+            "Ed25519 public key must be 32 bytes, got {}",
+            public_key_bytes.len()
+        ))
+    })?;
+    let public_key = Ed25519VerifyingKey::from_bytes(&public_key_bytes).map_err(|e| {
+        SignatureVerificationError::InvalidFormat(format!("Ed25519 public key parse: {e}"))
+    })?;
+
+    public_key
+        .verify_strict(message.as_bytes(), &signature)
+        .map_err(|e| SignatureVerificationError::Mismatch(e.to_string()))
+}
+
+/// Dispatch to the verifier for `signing_algo` (`ecdsa` or `ed25519`).
+pub fn verify_signature(
+    signing_algo: &str,
+    message: &str,
+    signature_hex: &str,
+    signing_address: &str,
+) -> Result<(), SignatureVerificationError> {
+    match signing_algo.to_ascii_lowercase().as_str() {
+        "ecdsa" => verify_ecdsa_signature(message, signature_hex, signing_address),
+        "ed25519" => verify_ed25519_signature(message, signature_hex, signing_address),
+        other => Err(SignatureVerificationError::UnsupportedAlgorithm(
+            other.to_string(),
+        )),
+    }
+}
+
+/// Split a signed text into `(request_hash, response_hash)`.
+///
+/// Gateway signatures sign `"{request_hash}:{response_hash}"`; provider TEE
+/// signatures sign `"{model_id}:{request_hash}:{response_hash}"`. Model ids may
+/// themselves contain `:`, so the hashes are taken from the right.
+fn split_signed_hashes(text: &str) -> (Option<String>, Option<String>) {
+    let mut parts = text.rsplitn(3, ':');
+    let response_hash = parts.next();
+    let request_hash = parts.next();
+    match (request_hash, response_hash) {
+        (Some(request), Some(response)) => (Some(request.to_string()), Some(response.to_string())),
+        _ => (None, None),
+    }
+}
+
+/// Verify a stored chat signature and report the components that were checked.
+pub fn verify_chat_signature(signature: &ChatSignature) -> SignatureVerification {
+    let (request_hash, response_hash) = split_signed_hashes(&signature.text);
+    let result = verify_signature(
+        &signature.signing_algo,
+        &signature.text,
+        &signature.signature,
+        &signature.signing_address,
+    );
+    SignatureVerification {
+        verified: result.is_ok(),
+        signing_algo: signature.signing_algo.clone(),
+        signing_address: signature.signing_address.clone(),
+        request_hash,
+        response_hash,
+        message: signature.text.clone(),
+        signature_kind: signature.signature_kind,
+        error: result.err().map(|e| e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use k256::ecdsa::SigningKey as EcdsaSigningKey;
+
+    const TEXT: &str = "aaaa:bbbb";
+
+    fn ecdsa_signature(text: &str) -> ChatSignature {
+        let key = EcdsaSigningKey::from_slice(&[7u8; 32]).unwrap();
+        let (signature, recid) = key
+            .sign_prehash_recoverable(&ethereum_message_hash(text))
+            .unwrap();
+        let mut signature_bytes = signature.to_bytes().to_vec();
+        signature_bytes.push(27 + (recid.to_byte() & 1));
+
+        let encoded_point = key.verifying_key().to_encoded_point(false);
+        let address_hash = Keccak256::digest(&encoded_point.as_bytes()[1..]);
+        ChatSignature {
+            text: text.to_string(),
+            signature: format!("0x{}", hex::encode(signature_bytes)),
+            signing_address: format!("0x{}", hex::encode(&address_hash[12..])),
+            signing_algo: "ecdsa".to_string(),
+            signature_kind: Some(SignatureKind::Gateway),
+        }
+    }
+
+    fn ed25519_signature(text: &str) -> ChatSignature {
+        let key = SigningKey::from_bytes(&[9u8; 32]);
+        ChatSignature {
+            text: text.to_string(),
+            signature: hex::encode(key.sign(text.as_bytes()).to_bytes()),
+            signing_address: hex::encode(key.verifying_key().to_bytes()),
+            signing_algo: "ed25519".to_string(),
+            signature_kind: Some(SignatureKind::Gateway),
+        }
+    }
+
+    #[test]
+    fn valid_ecdsa_signature_verifies() {
+        let result = verify_chat_signature(&ecdsa_signature(TEXT));
+        assert!(result.verified, "unexpected error: {:?}", result.error);
+        assert_eq!(result.signing_algo, "ecdsa");
+        assert_eq!(result.request_hash.as_deref(), Some("aaaa"));
+        assert_eq!(result.response_hash.as_deref(), Some("bbbb"));
+        assert_eq!(result.message, TEXT);
+        assert!(result.error.is_none());
+    }
+
+    #[test]
+    fn valid_ed25519_signature_verifies() {
+        let result = verify_chat_signature(&ed25519_signature(TEXT));
+        assert!(result.verified, "unexpected error: {:?}", result.error);
+        assert_eq!(result.signing_algo, "ed25519");
+        assert_eq!(result.request_hash.as_deref(), Some("aaaa"));
+        assert_eq!(result.response_hash.as_deref(), Some("bbbb"));
+    }
+
+    #[test]
+    fn tampered_response_hash_fails_for_both_algorithms() {
+        for mut signature in [ecdsa_signature(TEXT), ed25519_signature(TEXT)] {
+            signature.text = "aaaa:cccc".to_string();
+            let result = verify_chat_signature(&signature);
+            assert!(
+                !result.verified,
+                "{} must reject tampering",
+                result.signing_algo
+            );
+            assert_eq!(result.response_hash.as_deref(), Some("cccc"));
+            assert!(result.error.is_some());
+        }
+    }
+
+    #[test]
+    fn provider_text_hashes_are_taken_from_the_right() {
+        assert_eq!(
+            split_signed_hashes("org/model:v1:req:resp"),
+            (Some("req".to_string()), Some("resp".to_string()))
+        );
+        assert_eq!(split_signed_hashes("no-separator"), (None, None));
+    }
+
+    #[test]
+    fn unknown_algorithm_is_rejected() {
+        assert_eq!(
+            verify_signature("rsa", TEXT, "00", "00"),
+            Err(SignatureVerificationError::UnsupportedAlgorithm(
+                "rsa".to_string()
+            ))
+        );
+    }
+}
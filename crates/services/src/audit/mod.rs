@@ -0,0 +1,250 @@
+//! Compliance audit trail for chat completions.
+//!
+//! When enabled, the completion service hands one [`AuditEntry`] per request
+//! to an [`AuditSink`]. Writes happen in the background and never affect the
+//! request outcome. Bodies are optional and always pass through
+//! [`redact_pii`] before reaching the sink, so raw customer content is never
+//! persisted by this path.
+
+pub mod ports;
+
+pub use ports::{AuditEntry, AuditSink};
+
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, OnceLock};
+
+/// Upper bound on each stored body. Longer bodies are cut at a character
+/// boundary and suffixed with [`TRUNCATION_MARKER`].
+pub const MAX_AUDIT_BODY_BYTES: usize = 256 * 1024;
+
+const TRUNCATION_MARKER: &str = "…[truncated]";
+const EMAIL_PLACEHOLDER: &str = "[REDACTED_EMAIL]";
+const CARD_PLACEHOLDER: &str = "[REDACTED_CARD]";
+
+/// Mask obvious PII: email addresses and card-like numbers (13–19 digits,
+/// optionally grouped with spaces or dashes).
+pub fn redact_pii(text: &str) -> String {
+    static EMAIL: OnceLock<Regex> = OnceLock::new();
+    static CARD: OnceLock<Regex> = OnceLock::new();
+
+    let email = EMAIL.get_or_init(|| {
+        Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}")
+            .expect("Failed to compile email pattern")
+    });
+    let card = CARD.get_or_init(|| {
+        Regex::new(r"\b(?:\d[ -]?){12,18}\d\b").expect("Failed to compile card pattern")
+    });
+
+    let text = email.replace_all(text, EMAIL_PLACEHOLDER);
+    card.replace_all(&text, CARD_PLACEHOLDER).into_owned()
+}
+
+fn truncate_body(mut body: String) -> String {
+    if body.len() <= MAX_AUDIT_BODY_BYTES {
+        return body;
+    }
+    let mut cut = MAX_AUDIT_BODY_BYTES;
+    while !body.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    body.truncate(cut);
+    body.push_str(TRUNCATION_MARKER);
+    body
+}
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Completion-service handle on the audit sink.
+#[derive(Clone)]
+pub struct AuditLogger {
+    sink: Arc<dyn AuditSink>,
+    store_bodies: bool,
+}
+
+impl AuditLogger {
+    pub fn new(sink: Arc<dyn AuditSink>, store_bodies: bool) -> Self {
+        Self { sink, store_bodies }
+    }
+
+    /// Whether callers should capture request/response bodies at all.
+    pub fn stores_bodies(&self) -> bool {
+        self.store_bodies
+    }
+
+    /// Redact and write `entry` in the background. Failures are logged with
+    /// IDs only and never surface to the caller.
+    pub fn record(&self, mut entry: AuditEntry) {
+        if self.store_bodies {
+            entry.request_body = entry.request_body.map(|b| truncate_body(redact_pii(&b)));
+            entry.response_body = entry.response_body.map(|b| truncate_body(redact_pii(&b)));
+        } else {
+            entry.request_body = None;
+            entry.response_body = None;
+        }
+
+        let sink = self.sink.clone();
+        tokio::spawn(async move {
+            let request_id = entry.request_id;
+            let organization_id = entry.organization_id;
+            if let Err(e) = sink.record(entry).await {
+                tracing::error!(
+                    %request_id,
+                    %organization_id,
+                    error = %e,
+                    "Failed to write completion audit entry"
+                );
+            }
+        });
+    }
+}
+
+/// Incrementally hashes (and optionally buffers) a streamed response so the
+/// audit entry can be written once the stream ends.
+pub struct StreamAuditor {
+    logger: AuditLogger,
+    entry: AuditEntry,
+    hasher: Sha256,
+    body: Option<Vec<u8>>,
+}
+
+impl StreamAuditor {
+    /// `entry` carries the request-side fields; the response hash and body
+    /// are filled in by [`Self::finish`].
+    pub fn new(logger: AuditLogger, entry: AuditEntry) -> Self {
+        let body = logger.stores_bodies().then(Vec::new);
+        Self {
+            logger,
+            entry,
+            hasher: Sha256::new(),
+            body,
+        }
+    }
+
+    pub fn observe(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+        if let Some(body) = &mut self.body {
+            // Keep one byte past the limit so truncation is still detected.
+            let room = (MAX_AUDIT_BODY_BYTES + 1).saturating_sub(body.len());
+            body.extend_from_slice(&bytes[..bytes.len().min(room)]);
+        }
+    }
+
+    pub fn finish(self) {
+        let mut entry = self.entry;
+        entry.response_hash = hex::encode(self.hasher.finalize());
+        entry.response_body = self
+            .body
+            .map(|body| String::from_utf8_lossy(&body).into_owned());
+        self.logger.record(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct CapturingSink {
+        entries: Mutex<Vec<AuditEntry>>,
+    }
+
+    #[async_trait::async_trait]
+    impl AuditSink for CapturingSink {
+        async fn record(&self, entry: AuditEntry) -> anyhow::Result<()> {
+            self.entries.lock().unwrap().push(entry);
+            Ok(())
+        }
+    }
+
+    fn entry(request_body: &str) -> AuditEntry {
+        AuditEntry {
+            request_id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            workspace_id: Uuid::new_v4(),
+            api_key_id: Uuid::new_v4(),
+            model: "test-model".to_string(),
+            request_hash: "req".to_string(),
+            response_hash: String::new(),
+            request_body: Some(request_body.to_string()),
+            response_body: None,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    async fn drain(sink: &CapturingSink) -> Vec<AuditEntry> {
+        for _ in 0..100 {
+            if !sink.entries.lock().unwrap().is_empty() {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        std::mem::take(&mut *sink.entries.lock().unwrap())
+    }
+
+    #[test]
+    fn redacts_emails_and_card_numbers() {
+        let redacted = redact_pii(
+            "mail jane.doe+x@example.co.uk, card 4111 1111 1111 1111 or 5500-0000-0000-0004",
+        );
+        assert_eq!(
+            redacted,
+            "mail [REDACTED_EMAIL], card [REDACTED_CARD] or [REDACTED_CARD]"
+        );
+    }
+
+    #[test]
+    fn leaves_short_numbers_alone() {
+        let text = "order 12345 costs 99.50 on 2024-01-02";
+        assert_eq!(redact_pii(text), text);
+    }
+
+    #[tokio::test]
+    async fn bodies_are_redacted_when_stored() {
+        let sink = Arc::new(CapturingSink::default());
+        let logger = AuditLogger::new(sink.clone(), true);
+        logger.record(entry("contact me at a@b.io"));
+
+        let entries = drain(&sink).await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].request_body.as_deref(),
+            Some("contact me at [REDACTED_EMAIL]")
+        );
+    }
+
+    #[tokio::test]
+    async fn bodies_are_dropped_when_not_stored() {
+        let sink = Arc::new(CapturingSink::default());
+        let logger = AuditLogger::new(sink.clone(), false);
+        logger.record(entry("contact me at a@b.io"));
+
+        let entries = drain(&sink).await;
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].request_body.is_none());
+        assert_eq!(entries[0].request_hash, "req");
+    }
+
+    #[tokio::test]
+    async fn stream_auditor_hashes_all_bytes_and_truncates_body() {
+        let sink = Arc::new(CapturingSink::default());
+        let logger = AuditLogger::new(sink.clone(), true);
+        let mut auditor = StreamAuditor::new(logger, entry(""));
+        let chunk = vec![b'a'; MAX_AUDIT_BODY_BYTES];
+        auditor.observe(&chunk);
+        auditor.observe(b"tail");
+        auditor.finish();
+
+        let entries = drain(&sink).await;
+        let mut all = chunk.clone();
+        all.extend_from_slice(b"tail");
+        assert_eq!(entries[0].response_hash, sha256_hex(&all));
+        let body = entries[0].response_body.as_deref().unwrap();
+        assert!(body.ends_with(TRUNCATION_MARKER));
+        assert_eq!(body.len(), MAX_AUDIT_BODY_BYTES + TRUNCATION_MARKER.len());
+    }
+}
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// One audited chat completion.
+///
+/// Hashes are hex-encoded SHA-256: `request_hash` is the API layer's
+/// `body_hash` of the client request, `response_hash` covers the response
+/// bytes as returned by the provider. Bodies are only present when body
+/// storage is enabled, and are PII-redacted before they reach a sink.
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub request_id: Uuid,
+    pub organization_id: Uuid,
+    pub workspace_id: Uuid,
+    pub api_key_id: Uuid,
+    pub model: String,
+    pub request_hash: String,
+    pub response_hash: String,
+    pub request_body: Option<String>,
+    pub response_body: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Destination for completion audit entries (e.g. a database table).
+#[async_trait]
+pub trait AuditSink: Send + Sync {
+    async fn record(&self, entry: AuditEntry) -> anyhow::Result<()>;
+}
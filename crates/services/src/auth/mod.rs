@@ -2,7 +2,7 @@ pub mod near;
 pub mod oauth;
 pub mod ports;
 
-pub use near::NearAuthService;
+pub use near::{IssuedNonce, NearAuthService, NearSignatureVerifier};
 pub use oauth::OAuthManager;
 pub use ports::*;
 use tracing::{debug, error, info, warn};
@@ -23,6 +23,9 @@ pub const MAX_USER_AGENT_LEN: usize = 4096;
 
 const API_KEY_CACHE_MAX_CAPACITY: u64 = 10_000;
 const API_KEY_CACHE_TTL_SECS: u64 = 30;
+const SESSION_TOUCH_CACHE_MAX_CAPACITY: u64 = 100_000;
+/// Matches the database-side `last_used_at` resolution.
+const SESSION_TOUCH_INTERVAL_SECS: u64 = 60;
 const BLOOM_FILTER_ITEMS: usize = 10_000_000;
 const BLOOM_FILTER_FP_RATE: f64 = 0.001;
 const BLOOM_FILTER_SYNC_INTERVAL_SECS: u64 = 10;
@@ -165,6 +168,8 @@ impl AuthServiceTrait for AuthService {
                     debug!("Access token session is expired or bound to another user");
                     return Err(AuthError::SessionNotFound);
                 }
+
+                self.touch_session(session.id).await;
             }
             None => {
                 // Legacy access token issued before session binding. During
@@ -194,10 +199,34 @@ impl AuthServiceTrait for AuthService {
             return Err(AuthError::UserAgentTooLong(MAX_USER_AGENT_LEN));
         }
 
-        self.session_repository
-            .validate(refresh_token, user_agent)
+        let session = self
+            .session_repository
+            .validate(refresh_token.clone(), user_agent)
             .await
-            .map_err(|e| AuthError::InternalError(format!("Failed to validate session: {e}")))
+            .map_err(|e| AuthError::InternalError(format!("Failed to validate session: {e}")))?;
+        if let Some(session) = &session {
+            self.touch_session(session.id.clone()).await;
+            return Ok(Some(session.clone()));
+        }
+
+        // A refresh token that was already rotated out is being replayed:
+        // either the legitimate client or an attacker holds a stale copy, and
+        // we cannot tell which, so the whole session family is revoked.
+        let revoked = self
+            .session_repository
+            .revoke_family_on_reuse(refresh_token)
+            .await
+            .map_err(|e| {
+                AuthError::InternalError(format!("Failed to check refresh token reuse: {e}"))
+            })?;
+        if let Some(session_id) = revoked {
+            warn!(
+                session_id = %session_id,
+                "Refresh token reuse detected; revoked session family"
+            );
+        }
+
+        Ok(None)
     }
 
     async fn validate_session_refresh(
@@ -242,6 +271,32 @@ impl AuthServiceTrait for AuthService {
             .map_err(|e| AuthError::InternalError(format!("Failed to revoke session: {e}")))
     }
 
+    async fn list_sessions(&self, user_id: UserId) -> Result<Vec<Session>, AuthError> {
+        self.session_repository
+            .list_by_user(user_id)
+            .await
+            .map_err(|e| AuthError::InternalError(format!("Failed to list sessions: {e}")))
+    }
+
+    async fn revoke_session(
+        &self,
+        user_id: UserId,
+        session_id: SessionId,
+    ) -> Result<bool, AuthError> {
+        let session = self
+            .session_repository
+            .get_by_id(session_id.clone())
+            .await
+            .map_err(|e| AuthError::InternalError(format!("Failed to get session: {e}")))?;
+
+        // Another user's session is reported exactly like a missing one so
+        // session IDs cannot be probed.
+        match session {
+            Some(session) if session.user_id == user_id => self.logout(session_id).await,
+            _ => Ok(false),
+        }
+    }
+
     async fn rotate_session(
         &self,
         user_id: UserId,
@@ -482,6 +537,10 @@ impl AuthService {
             .max_capacity(API_KEY_CACHE_MAX_CAPACITY)
             .time_to_live(Duration::from_secs(API_KEY_CACHE_TTL_SECS))
             .build();
+        let session_touch_cache: SessionTouchCache = Cache::builder()
+            .max_capacity(SESSION_TOUCH_CACHE_MAX_CAPACITY)
+            .time_to_live(Duration::from_secs(SESSION_TOUCH_INTERVAL_SECS))
+            .build();
 
         let bloom = Bloom::new_for_fp_rate(BLOOM_FILTER_ITEMS, BLOOM_FILTER_FP_RATE)
             .expect("bloom filter creation failed");
@@ -504,10 +563,26 @@ impl AuthService {
             api_key_cache,
             api_key_bloom_filter,
             bloom_filter_ready,
+            session_touch_cache,
             require_session_bound_access_tokens,
         }
     }
 
+    /// Best-effort `last_used_at` bookkeeping for session listings; a failed
+    /// write never fails the authentication it piggybacks on. At most one
+    /// write per session per [`SESSION_TOUCH_INTERVAL_SECS`] leaves this
+    /// process, so authenticated traffic does not cost a database round trip
+    /// per request.
+    async fn touch_session(&self, session_id: SessionId) {
+        if self.session_touch_cache.contains_key(&session_id.0) {
+            return;
+        }
+        self.session_touch_cache.insert(session_id.0, ()).await;
+        if let Err(e) = self.session_repository.touch(session_id.clone()).await {
+            warn!(session_id = %session_id, error = %e, "Failed to record session last use");
+        }
+    }
+
     fn spawn_bloom_filter_sync(
         api_key_repository: Arc<dyn ApiKeyRepository>,
         bloom_filter: ApiKeyBloomFilter,
@@ -613,7 +688,7 @@ mod tests {
         Organization, OrganizationError, OrganizationId, OrganizationInvitation,
         OrganizationInvitationEmailDelivery, OrganizationInvitationWithDetails, OrganizationMember,
         OrganizationMemberWithUser, OrganizationOrderBy, OrganizationOrderDirection,
-        OrganizationRepository, OrganizationServiceTrait, OrganizationWithRole,
+        OrganizationRepository, OrganizationServiceTrait, OrganizationWithRole, PageCursor,
         UpdateOrganizationMemberRequest, UpdateOrganizationRequest,
     };
     use crate::workspace::{
@@ -763,6 +838,15 @@ mod tests {
         async fn revoke(&self, _: SessionId) -> anyhow::Result<bool> {
             unimplemented!()
         }
+        async fn touch(&self, _: SessionId) -> anyhow::Result<()> {
+            unimplemented!()
+        }
+        async fn revoke_family_on_reuse(
+            &self,
+            _: SessionToken,
+        ) -> anyhow::Result<Option<SessionId>> {
+            unimplemented!()
+        }
         async fn revoke_all_for_user(&self, _: UserId) -> anyhow::Result<usize> {
             unimplemented!()
         }
@@ -774,12 +858,14 @@ mod tests {
     /// In-memory session repository for exercising session binding/revocation.
     struct InMemorySessionRepo {
         sessions: Mutex<std::collections::HashMap<Uuid, Session>>,
+        touches: Mutex<usize>,
     }
 
     impl InMemorySessionRepo {
         fn new() -> Self {
             Self {
                 sessions: Mutex::new(std::collections::HashMap::new()),
+                touches: Mutex::new(0),
             }
         }
 
@@ -792,6 +878,8 @@ mod tests {
                 expires_at,
                 ip_address: None,
                 user_agent: "Test Agent".to_string(),
+                device_label: None,
+                last_used_at: Utc::now(),
             };
             self.sessions
                 .lock()
@@ -844,6 +932,16 @@ mod tests {
                 .remove(&session_id.0)
                 .is_some())
         }
+        async fn touch(&self, _: SessionId) -> anyhow::Result<()> {
+            *self.touches.lock().unwrap() += 1;
+            Ok(())
+        }
+        async fn revoke_family_on_reuse(
+            &self,
+            _: SessionToken,
+        ) -> anyhow::Result<Option<SessionId>> {
+            unimplemented!()
+        }
         async fn revoke_all_for_user(&self, _: UserId) -> anyhow::Result<usize> {
             unimplemented!()
         }
@@ -910,6 +1008,9 @@ mod tests {
         async fn revoke(&self, _: ApiKeyId) -> Result<bool, RepositoryError> {
             unimplemented!()
         }
+        async fn revoke_all_by_workspace(&self, _: WorkspaceId) -> Result<u64, RepositoryError> {
+            unimplemented!()
+        }
         async fn get_all_active_key_hashes(&self) -> Result<Vec<String>, RepositoryError> {
             Ok(vec![])
         }
@@ -978,12 +1079,17 @@ mod tests {
             _: Uuid,
             _: i64,
             _: i64,
+            _: Option<PageCursor>,
         ) -> Result<Vec<OrganizationMember>, RepositoryError> {
             unimplemented!()
         }
         async fn get_member_count(&self, _: Uuid) -> Result<i64, RepositoryError> {
             unimplemented!()
         }
+
+        async fn get_max_members(&self, _: Uuid) -> Result<Option<i64>, RepositoryError> {
+            unimplemented!()
+        }
         async fn count_organizations_by_user(&self, _: Uuid) -> Result<i64, RepositoryError> {
             unimplemented!()
         }
@@ -994,6 +1100,7 @@ mod tests {
             _: i64,
             _: Option<OrganizationOrderBy>,
             _: Option<OrganizationOrderDirection>,
+            _: Option<PageCursor>,
         ) -> Result<Vec<Organization>, RepositoryError> {
             unimplemented!()
         }
@@ -1005,6 +1112,7 @@ mod tests {
             _: i64,
             _: Option<OrganizationOrderBy>,
             _: Option<OrganizationOrderDirection>,
+            _: Option<PageCursor>,
         ) -> Result<Vec<OrganizationWithRole>, RepositoryError> {
             unimplemented!()
         }
@@ -1060,6 +1168,22 @@ mod tests {
             _: Option<String>,
             _: Option<String>,
             _: Option<serde_json::Value>,
+            _: Option<crate::workspace::WorkspaceDefaultParams>,
+        ) -> Result<Option<Workspace>, RepositoryError> {
+            unimplemented!()
+        }
+        async fn update_monthly_budget(
+            &self,
+            _: WorkspaceId,
+            _: Option<i64>,
+        ) -> Result<Option<Workspace>, RepositoryError> {
+            unimplemented!()
+        }
+        async fn transfer(
+            &self,
+            _: WorkspaceId,
+            _: OrganizationId,
+            _: OrganizationId,
         ) -> Result<Option<Workspace>, RepositoryError> {
             unimplemented!()
         }
@@ -1126,6 +1250,7 @@ mod tests {
             _: i64,
             _: Option<OrganizationOrderBy>,
             _: Option<OrganizationOrderDirection>,
+            _: Option<PageCursor>,
         ) -> Result<Vec<OrganizationWithRole>, OrganizationError> {
             unimplemented!()
         }
@@ -1187,6 +1312,7 @@ mod tests {
             _: UserId,
             _: i64,
             _: i64,
+            _: Option<PageCursor>,
         ) -> Result<Vec<OrganizationMemberWithUser>, OrganizationError> {
             unimplemented!()
         }
@@ -1331,6 +1457,7 @@ mod tests {
             api_key_cache: moka::future::Cache::builder().build(),
             api_key_bloom_filter: Arc::new(RwLock::new(bloom)),
             bloom_filter_ready: Arc::new(AtomicBool::new(false)),
+            session_touch_cache: moka::future::Cache::builder().build(),
             require_session_bound_access_tokens,
         }
     }
@@ -1421,6 +1548,34 @@ mod tests {
         assert_eq!(validated.id, user.id);
     }
 
+    #[tokio::test]
+    async fn test_repeated_access_touches_session_once_per_interval() {
+        let user = make_user("alice@example.com", "google");
+        let user_repo = Arc::new(MockUserRepo::with_user(user.clone()));
+        let session_repo = Arc::new(InMemorySessionRepo::new());
+        let service = build_auth_service_with_sessions(user_repo, session_repo.clone(), false);
+
+        let (access_token, _session, _refresh_token) = service
+            .create_session(
+                user.id.clone(),
+                None,
+                "Test Agent".to_string(),
+                TEST_ENCODING_KEY.to_string(),
+                1,
+                24,
+            )
+            .await
+            .unwrap();
+
+        for _ in 0..3 {
+            service
+                .validate_session_access(access_token.clone(), TEST_ENCODING_KEY.to_string())
+                .await
+                .unwrap();
+        }
+        assert_eq!(*session_repo.touches.lock().unwrap(), 1);
+    }
+
     #[tokio::test]
     async fn test_logout_revokes_session_and_invalidates_access_token() {
         let user = make_user("alice@example.com", "google");
@@ -1,3 +1,4 @@
+use async_trait::async_trait;
 use chrono::{DateTime, Duration, Utc};
 use config::NearConfig;
 use near_api::{signer::NEP413Payload, types::Signature, AccountId, NetworkConfig, PublicKey};
@@ -46,12 +47,73 @@ pub struct SignedMessage {
     pub state: Option<String>,
 }
 
+/// A server-issued nonce for the challenge/response login flow, with the
+/// NEP-413 fields the wallet must sign alongside it
+#[derive(Debug, Clone)]
+pub struct IssuedNonce {
+    pub nonce: [u8; 32],
+    pub message: String,
+    pub recipient: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Checks a NEP-413 signature and that the signing key is a full access key
+/// of the claimed account
+#[async_trait]
+pub trait NearSignatureVerifier: Send + Sync {
+    async fn verify(
+        &self,
+        signed_message: &SignedMessage,
+        payload: &NEP413Payload,
+    ) -> Result<bool, NearAuthError>;
+}
+
+/// Verifier that looks the key up on chain through NEAR RPC. Used for every
+/// account, implicit ones included: their original key can be deleted or
+/// rotated, so matching the account ID alone does not prove ownership.
+pub struct RpcNearSignatureVerifier {
+    network_config: NetworkConfig,
+}
+
+impl RpcNearSignatureVerifier {
+    pub fn new(config: &NearConfig) -> Self {
+        let rpc_url = Url::parse(&config.rpc_url).unwrap_or_else(|_| {
+            panic!(
+                "Invalid NEAR RPC URL in configuration: '{}'",
+                config.rpc_url
+            )
+        });
+        Self {
+            network_config: NetworkConfig::from_rpc_url(&config.network_id, rpc_url),
+        }
+    }
+}
+
+#[async_trait]
+impl NearSignatureVerifier for RpcNearSignatureVerifier {
+    async fn verify(
+        &self,
+        signed_message: &SignedMessage,
+        payload: &NEP413Payload,
+    ) -> Result<bool, NearAuthError> {
+        payload
+            .verify(
+                &signed_message.account_id,
+                signed_message.public_key,
+                &signed_message.signature,
+                &self.network_config,
+            )
+            .await
+            .map_err(|e| NearAuthError::SignatureVerificationFailed(e.to_string()))
+    }
+}
+
 /// Helper to verify NEP-413 signed messages and create sessions
 pub struct NearAuthService {
     auth_service: Arc<dyn AuthServiceTrait>,
     nonce_repository: Arc<dyn NearNonceRepository>,
     config: NearConfig,
-    network_config: NetworkConfig,
+    signature_verifier: Arc<dyn NearSignatureVerifier>,
 }
 
 /// Validates that a nonce timestamp is within the acceptable window
@@ -92,21 +154,22 @@ impl NearAuthService {
         nonce_repository: Arc<dyn NearNonceRepository>,
         config: NearConfig,
     ) -> Self {
-        let rpc_url = Url::parse(&config.rpc_url).unwrap_or_else(|_| {
-            panic!(
-                "Invalid NEAR RPC URL in configuration: '{}'",
-                config.rpc_url
-            )
-        });
-        let network_config = NetworkConfig::from_rpc_url(&config.network_id, rpc_url);
+        let signature_verifier = Arc::new(RpcNearSignatureVerifier::new(&config));
         Self {
             auth_service,
             nonce_repository,
             config,
-            network_config,
+            signature_verifier,
         }
     }
 
+    /// Replace the RPC-backed signature verifier (e.g. with a fixed key set
+    /// in tests)
+    pub fn with_signature_verifier(mut self, verifier: Arc<dyn NearSignatureVerifier>) -> Self {
+        self.signature_verifier = verifier;
+        self
+    }
+
     async fn cleanup_nonces(&self) {
         if let Err(err) = self.nonce_repository.cleanup_expired_nonces().await {
             tracing::warn!("Failed to cleanup expired NEAR nonces: {}", err);
@@ -182,41 +245,142 @@ impl NearAuthService {
             anyhow::anyhow!(e)
         })?;
 
-        // 5. Verify signature AND public key ownership via near-api
-        let is_valid = payload
-            .verify(
-                &signed_message.account_id,
-                signed_message.public_key,
-                &signed_message.signature,
-                &self.network_config,
-            )
+        // 5. Verify signature AND public key ownership
+        self.verify_signature(&signed_message, &payload)
             .await
-            .map_err(|e| {
-                anyhow::anyhow!(NearAuthError::SignatureVerificationFailed(e.to_string()))
-            })?;
-
-        if !is_valid {
-            return Err(anyhow::anyhow!(NearAuthError::InvalidSignature));
-        }
+            .map_err(|e| anyhow::anyhow!(e))?;
 
         // 6. Consume nonce AFTER signature verification (replay protection)
         // This prevents attackers from burning legitimate nonces with invalid signatures
-        let nonce_hex = hex::encode(payload.nonce);
-        let nonce_consumed = self
+        self.consume_nonce(&payload.nonce, &account_id)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        // 7-8. Find or create user and open a session
+        self.create_session(account_id, ip_address, user_agent, encoding_key)
+            .await
+    }
+
+    /// Issue a single-use nonce for the challenge/response login flow.
+    ///
+    /// The nonce keeps the NEP-413 layout (8-byte big-endian millisecond
+    /// timestamp followed by 24 random bytes) so the timestamp checks shared
+    /// with [`Self::verify_and_authenticate`] apply unchanged.
+    pub async fn issue_nonce(&self) -> Result<IssuedNonce, NearAuthError> {
+        use rand::RngExt;
+
+        self.cleanup_nonces().await;
+
+        let issued_at = Utc::now();
+        let mut nonce = [0u8; 32];
+        nonce[..8].copy_from_slice(&(issued_at.timestamp_millis() as u64).to_be_bytes());
+        rand::rng().fill(&mut nonce[8..]);
+        let expires_at = issued_at + Duration::milliseconds(MAX_NONCE_AGE_MS as i64);
+
+        self.nonce_repository
+            .store_issued_nonce(&hex::encode(nonce), expires_at)
+            .await
+            .map_err(|e| NearAuthError::InternalError(format!("Failed to store nonce: {e}")))?;
+
+        Ok(IssuedNonce {
+            nonce,
+            message: EXPECTED_MESSAGE.to_string(),
+            recipient: self.config.expected_recipient.clone(),
+            expires_at,
+        })
+    }
+
+    /// Redeem a nonce from [`Self::issue_nonce`]: verify the wallet's NEP-413
+    /// signature over it and open a session for the signing account.
+    pub async fn verify_issued_nonce_and_authenticate(
+        &self,
+        signed_message: SignedMessage,
+        nonce: [u8; 32],
+        ip_address: Option<String>,
+        user_agent: String,
+        encoding_key: String,
+    ) -> anyhow::Result<(String, Session, String)> {
+        let account_id = signed_message.account_id.to_string();
+
+        tracing::info!(
+            "NEAR nonce authentication attempt for account: {}",
+            account_id
+        );
+
+        self.cleanup_nonces().await;
+
+        let nonce_active = self
             .nonce_repository
-            .consume_nonce(&nonce_hex)
+            .is_issued_nonce_active(&hex::encode(nonce))
             .await
             .map_err(|e| {
                 anyhow::anyhow!(NearAuthError::InternalError(format!(
-                    "Failed to consume nonce: {e}"
+                    "Failed to look up nonce: {e}"
                 )))
             })?;
+        if !nonce_active {
+            return Err(anyhow::anyhow!(NearAuthError::InvalidNonce(
+                "nonce was not issued or has expired".to_string()
+            )));
+        }
+
+        let payload = NEP413Payload {
+            message: EXPECTED_MESSAGE.to_string(),
+            nonce,
+            recipient: self.config.expected_recipient.clone(),
+            callback_url: None,
+        };
+        self.verify_signature(&signed_message, &payload)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        self.consume_nonce(&nonce, &account_id)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        self.create_session(account_id, ip_address, user_agent, encoding_key)
+            .await
+    }
+
+    /// Check the signature over `payload` and that the key is a full access
+    /// key of the account.
+    async fn verify_signature(
+        &self,
+        signed_message: &SignedMessage,
+        payload: &NEP413Payload,
+    ) -> Result<(), NearAuthError> {
+        if self
+            .signature_verifier
+            .verify(signed_message, payload)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(NearAuthError::InvalidSignature)
+        }
+    }
+
+    async fn consume_nonce(&self, nonce: &[u8; 32], account_id: &str) -> Result<(), NearAuthError> {
+        let nonce_consumed = self
+            .nonce_repository
+            .consume_nonce(&hex::encode(nonce))
+            .await
+            .map_err(|e| NearAuthError::InternalError(format!("Failed to consume nonce: {e}")))?;
         if !nonce_consumed {
             tracing::warn!("NEAR signature replay detected for account {}", account_id);
-            return Err(anyhow::anyhow!(NearAuthError::ReplayAttack));
+            return Err(NearAuthError::ReplayAttack);
         }
+        Ok(())
+    }
 
-        // 7. Find or create user via AuthService
+    async fn create_session(
+        &self,
+        account_id: String,
+        ip_address: Option<String>,
+        user_agent: String,
+        encoding_key: String,
+    ) -> anyhow::Result<(String, Session, String)> {
+        // Find or create user via AuthService
         let oauth_info = OAuthUserInfo {
             provider: "near".to_string(),
             provider_user_id: account_id.clone(),
@@ -231,7 +395,7 @@ impl NearAuthService {
             .await
             .map_err(|e| anyhow::anyhow!(NearAuthError::InternalError(e.to_string())))?;
 
-        // 8. Create session via AuthService (dual-token system)
+        // Create session via AuthService (dual-token system)
         let (access_token, session, refresh_token) = self
             .auth_service
             .create_session(
@@ -1,5 +1,5 @@
 use super::ports::{AuthError, OAuthUserInfo};
-use config::OAuthProviderConfig;
+use config::{GenericOidcConfig, OAuthProviderConfig};
 use oauth2::{
     basic::BasicClient, AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken,
     PkceCodeChallenge, PkceCodeVerifier, RedirectUrl, Scope, TokenResponse, TokenUrl,
@@ -22,10 +22,20 @@ type ConfiguredClient = oauth2::Client<
     oauth2::EndpointSet,
 >;
 
+/// Generic OIDC provider: configured client plus the endpoints the OAuth2
+/// client itself doesn't carry.
+struct OidcProvider {
+    client: ConfiguredClient,
+    issuer: String,
+    userinfo_url: String,
+    scopes: Vec<String>,
+}
+
 /// OAuth2 authentication manager
 pub struct OAuthManager {
     github_client: Option<ConfiguredClient>,
     google_client: Option<ConfiguredClient>,
+    oidc_provider: Option<OidcProvider>,
     http_client: Client,
 }
 
@@ -33,14 +43,18 @@ impl OAuthManager {
     pub fn new(
         github_config: Option<OAuthProviderConfig>,
         google_config: Option<OAuthProviderConfig>,
+        oidc_config: Option<GenericOidcConfig>,
     ) -> Result<Self, AuthError> {
         let github_client = github_config.map(Self::create_github_client).transpose()?;
 
         let google_client = google_config.map(Self::create_google_client).transpose()?;
 
+        let oidc_provider = oidc_config.map(Self::create_oidc_provider).transpose()?;
+
         Ok(Self {
             github_client,
             google_client,
+            oidc_provider,
             http_client: Client::new(),
         })
     }
@@ -83,6 +97,33 @@ impl OAuthManager {
         Ok(client)
     }
 
+    fn create_oidc_provider(config: GenericOidcConfig) -> Result<OidcProvider, AuthError> {
+        let auth_url = AuthUrl::new(config.authorization_url)
+            .map_err(|e| AuthError::ConfigError(format!("Invalid OIDC auth URL: {e}")))?;
+
+        let token_url = TokenUrl::new(config.token_url)
+            .map_err(|e| AuthError::ConfigError(format!("Invalid OIDC token URL: {e}")))?;
+
+        reqwest::Url::parse(&config.userinfo_url)
+            .map_err(|e| AuthError::ConfigError(format!("Invalid OIDC userinfo URL: {e}")))?;
+
+        let client = BasicClient::new(ClientId::new(config.client_id))
+            .set_client_secret(ClientSecret::new(config.client_secret))
+            .set_auth_uri(auth_url)
+            .set_token_uri(token_url)
+            .set_redirect_uri(
+                RedirectUrl::new(config.redirect_url)
+                    .map_err(|e| AuthError::ConfigError(format!("Invalid redirect URL: {e}")))?,
+            );
+
+        Ok(OidcProvider {
+            client,
+            issuer: config.issuer,
+            userinfo_url: config.userinfo_url,
+            scopes: config.scopes,
+        })
+    }
+
     /// Generate authorization URL for GitHub
     pub fn github_auth_url(&self) -> Result<(String, String), AuthError> {
         let client = self
@@ -122,6 +163,29 @@ impl OAuthManager {
         ))
     }
 
+    /// Generate authorization URL for the generic OIDC provider with PKCE
+    pub fn oidc_auth_url(&self) -> Result<(String, String, String), AuthError> {
+        let provider = self
+            .oidc_provider
+            .as_ref()
+            .ok_or_else(|| AuthError::ConfigError("OIDC not configured".to_string()))?;
+
+        let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+
+        let (auth_url, csrf_state) = provider
+            .client
+            .authorize_url(CsrfToken::new_random)
+            .add_scopes(provider.scopes.iter().cloned().map(Scope::new))
+            .set_pkce_challenge(pkce_challenge)
+            .url();
+
+        Ok((
+            auth_url.to_string(),
+            csrf_state.secret().to_string(),
+            pkce_verifier.secret().to_string(),
+        ))
+    }
+
     /// Handle GitHub OAuth callback
     pub async fn handle_github_callback(
         &self,
@@ -218,6 +282,41 @@ impl OAuthManager {
         Ok((oauth_info, access_token.to_string()))
     }
 
+    /// Handle generic OIDC callback
+    pub async fn handle_oidc_callback(
+        &self,
+        code: String,
+        _state: String,
+        pkce_verifier_str: String,
+    ) -> Result<(OAuthUserInfo, String), AuthError> {
+        let provider = self
+            .oidc_provider
+            .as_ref()
+            .ok_or_else(|| AuthError::ConfigError("OIDC not configured".to_string()))?;
+
+        debug!("Exchanging OIDC code for token");
+
+        let token = provider
+            .client
+            .exchange_code(AuthorizationCode::new(code))
+            .set_pkce_verifier(PkceCodeVerifier::new(pkce_verifier_str))
+            .request_async(&self.http_client)
+            .await
+            .map_err(|e| AuthError::OAuthError(format!("Token exchange failed: {e}")))?;
+
+        let access_token = token.access_token().secret();
+
+        let user_info = self.fetch_oidc_user(provider, access_token).await?;
+        let oauth_info = user_info.into_oauth_user_info(&provider.issuer)?;
+
+        debug!(
+            provider = "oidc",
+            provider_user_id = %oauth_info.provider_user_id,
+            "OAuth user authenticated"
+        );
+        Ok((oauth_info, access_token.to_string()))
+    }
+
     /// Fetch GitHub user information
     async fn fetch_github_user(&self, access_token: &str) -> Result<GitHubUser, AuthError> {
         let response = self
@@ -301,6 +400,35 @@ impl OAuthManager {
             .await
             .map_err(|e| AuthError::AuthFailed(format!("Failed to parse Google user: {e}")))
     }
+
+    /// Fetch userinfo claims from the generic OIDC provider
+    async fn fetch_oidc_user(
+        &self,
+        provider: &OidcProvider,
+        access_token: &str,
+    ) -> Result<OidcUserInfo, AuthError> {
+        let response = self
+            .http_client
+            .get(&provider.userinfo_url)
+            .header("Authorization", format!("Bearer {access_token}"))
+            .header("Accept", "application/json")
+            .header("User-Agent", "cloud-api")
+            .send()
+            .await
+            .map_err(|e| AuthError::NetworkError(format!("Failed to fetch OIDC userinfo: {e}")))?;
+
+        if !response.status().is_success() {
+            return Err(AuthError::AuthFailed(format!(
+                "OIDC userinfo endpoint returned status: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| AuthError::AuthFailed(format!("Failed to parse OIDC userinfo: {e}")))
+    }
 }
 
 #[derive(Deserialize)]
@@ -331,3 +459,161 @@ struct GoogleUser {
     #[serde(default)]
     picture: Option<String>,
 }
+
+/// Standard OIDC userinfo claims. `id`, `login` and `avatar_url` aliases
+/// cover OAuth-only servers such as GitHub Enterprise, whose user endpoint
+/// returns a numeric `id` rather than `sub`. Such servers must still assert
+/// `email_verified`; see `into_oauth_user_info`.
+#[derive(Debug, Deserialize)]
+struct OidcUserInfo {
+    #[serde(alias = "id")]
+    sub: OidcSubject,
+    #[serde(default)]
+    iss: Option<String>,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    email_verified: Option<bool>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default, alias = "login")]
+    preferred_username: Option<String>,
+    #[serde(default, alias = "avatar_url")]
+    picture: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OidcSubject {
+    String(String),
+    Number(i64),
+}
+
+impl OidcUserInfo {
+    /// Map claims to our user model. Emails the IdP does not mark verified
+    /// (including a missing `email_verified` claim) are rejected: the email
+    /// decides admin-domain privileges.
+    fn into_oauth_user_info(self, expected_issuer: &str) -> Result<OAuthUserInfo, AuthError> {
+        if let Some(iss) = &self.iss {
+            if iss.trim_end_matches('/') != expected_issuer.trim_end_matches('/') {
+                return Err(AuthError::AuthFailed(
+                    "OIDC userinfo issuer does not match the configured issuer".to_string(),
+                ));
+            }
+        }
+        let provider_user_id = match self.sub {
+            OidcSubject::String(sub) if !sub.is_empty() => sub,
+            OidcSubject::Number(id) => id.to_string(),
+            OidcSubject::String(_) => {
+                return Err(AuthError::AuthFailed(
+                    "OIDC userinfo has an empty subject".to_string(),
+                ))
+            }
+        };
+        if self.email_verified != Some(true) {
+            return Err(AuthError::AuthFailed(
+                "OIDC user email is not verified".to_string(),
+            ));
+        }
+        let email = self
+            .email
+            .filter(|e| !e.is_empty())
+            .ok_or_else(|| AuthError::AuthFailed("OIDC user has no email".to_string()))?;
+        let username = self
+            .preferred_username
+            .filter(|u| !u.is_empty())
+            .unwrap_or_else(|| email.split('@').next().unwrap_or("user").to_string());
+
+        Ok(OAuthUserInfo {
+            provider: "oidc".to_string(),
+            provider_user_id,
+            email,
+            username,
+            display_name: self.name,
+            avatar_url: self.picture,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claims(value: serde_json::Value) -> OidcUserInfo {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn oidc_claims_map_to_user_info() {
+        let info = claims(serde_json::json!({
+            "sub": "abc-123",
+            "iss": "https://idp.example.com/",
+            "email": "alice@example.com",
+            "email_verified": true,
+            "name": "Alice",
+            "preferred_username": "alice"
+        }))
+        .into_oauth_user_info("https://idp.example.com")
+        .unwrap();
+
+        assert_eq!(info.provider, "oidc");
+        assert_eq!(info.provider_user_id, "abc-123");
+        assert_eq!(info.email, "alice@example.com");
+        assert_eq!(info.username, "alice");
+        assert_eq!(info.display_name.as_deref(), Some("Alice"));
+    }
+
+    #[test]
+    fn github_enterprise_user_maps_numeric_id_and_login() {
+        let info = claims(serde_json::json!({
+            "id": 42,
+            "login": "octocat",
+            "email": "octocat@ghe.example.com",
+            "email_verified": true,
+            "avatar_url": "https://ghe.example.com/avatars/42"
+        }))
+        .into_oauth_user_info("https://ghe.example.com")
+        .unwrap();
+
+        assert_eq!(info.provider_user_id, "42");
+        assert_eq!(info.username, "octocat");
+        assert_eq!(
+            info.avatar_url.as_deref(),
+            Some("https://ghe.example.com/avatars/42")
+        );
+    }
+
+    #[test]
+    fn oidc_rejects_wrong_issuer_unverified_or_missing_email() {
+        let wrong_issuer = claims(serde_json::json!({
+            "sub": "a", "iss": "https://evil.example.com", "email": "a@example.com",
+            "email_verified": true
+        }));
+        assert!(wrong_issuer
+            .into_oauth_user_info("https://idp.example.com")
+            .is_err());
+
+        let unverified = claims(serde_json::json!({
+            "sub": "a", "email": "a@example.com", "email_verified": false
+        }));
+        assert!(unverified
+            .into_oauth_user_info("https://idp.example.com")
+            .is_err());
+
+        let no_email = claims(serde_json::json!({ "sub": "a", "email_verified": true }));
+        assert!(no_email
+            .into_oauth_user_info("https://idp.example.com")
+            .is_err());
+    }
+
+    #[test]
+    fn oidc_rejects_email_without_verified_claim() {
+        let info = claims(serde_json::json!({
+            "sub": "a", "email": "admin@example.com"
+        }));
+        assert!(matches!(
+            info.into_oauth_user_info("https://idp.example.com"),
+            Err(AuthError::AuthFailed(_))
+        ));
+    }
+}
@@ -14,6 +14,7 @@ use crate::workspace::{ApiKey, ApiKeyRepository, WorkspaceId, WorkspaceRepositor
 pub type ApiKeyCache = Cache<String, ApiKey>;
 pub type ApiKeyBloomFilter = Arc<RwLock<Bloom<String>>>;
 pub type BloomFilterReady = Arc<AtomicBool>;
+pub type SessionTouchCache = Cache<Uuid, ()>;
 
 // Domain ID types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -210,6 +211,9 @@ pub struct Session {
     pub expires_at: DateTime<Utc>,
     pub ip_address: Option<String>,
     pub user_agent: String,
+    /// Human-readable device derived from the User-Agent at login
+    pub device_label: Option<String>,
+    pub last_used_at: DateTime<Utc>,
 }
 
 #[async_trait]
@@ -243,6 +247,17 @@ pub trait SessionRepository: Send + Sync {
 
     async fn revoke(&self, session_id: SessionId) -> anyhow::Result<bool>;
 
+    /// Record that the session was just used to authenticate (throttled)
+    async fn touch(&self, session_id: SessionId) -> anyhow::Result<()>;
+
+    /// Revoke the session a previously rotated refresh token belonged to.
+    /// Returns the revoked session ID when `session_token` is a retired token
+    /// of a live session, `None` otherwise.
+    async fn revoke_family_on_reuse(
+        &self,
+        session_token: SessionToken,
+    ) -> anyhow::Result<Option<SessionId>>;
+
     async fn revoke_all_for_user(&self, user_id: UserId) -> anyhow::Result<usize>;
 
     async fn cleanup_expired(&self) -> anyhow::Result<usize>;
@@ -256,8 +271,19 @@ pub trait NearNonceRepository: Send + Sync {
     /// Returns false if the nonce was already used (replay attack).
     async fn consume_nonce(&self, nonce_hex: &str) -> anyhow::Result<bool>;
 
-    /// Clean up expired nonces (older than 10 minutes)
+    /// Clean up expired nonces (used more than 10 minutes ago, or issued
+    /// and past their expiry)
     async fn cleanup_expired_nonces(&self) -> anyhow::Result<u64>;
+
+    /// Record a server-issued nonce that may be redeemed until `expires_at`.
+    async fn store_issued_nonce(
+        &self,
+        nonce_hex: &str,
+        expires_at: DateTime<Utc>,
+    ) -> anyhow::Result<()>;
+
+    /// Whether the nonce was issued by this server and has not expired yet.
+    async fn is_issued_nonce_active(&self, nonce_hex: &str) -> anyhow::Result<bool>;
 }
 
 // Service interfaces
@@ -317,6 +343,17 @@ pub trait AuthServiceTrait: Send + Sync {
     /// Logout (revoke session)
     async fn logout(&self, session_id: SessionId) -> Result<bool, AuthError>;
 
+    /// List the user's active sessions, most recent first
+    async fn list_sessions(&self, user_id: UserId) -> Result<Vec<Session>, AuthError>;
+
+    /// Revoke one of the user's sessions. Returns `false` when the session
+    /// does not exist or belongs to another user.
+    async fn revoke_session(
+        &self,
+        user_id: UserId,
+        session_id: SessionId,
+    ) -> Result<bool, AuthError>;
+
     /// Rotate a refresh token session (refresh token rotation)
     /// This atomically updates the token hash and expiration, ensuring only one valid token at a time.
     /// The old_token_hash is used to prevent race conditions where multiple requests try to rotate the same token.
@@ -357,6 +394,9 @@ pub struct AuthService {
     pub api_key_cache: ApiKeyCache,
     pub api_key_bloom_filter: ApiKeyBloomFilter,
     pub bloom_filter_ready: BloomFilterReady,
+    /// Sessions this process recorded a use for recently, so the per-request
+    /// `last_used_at` bookkeeping skips the database until the entry expires.
+    pub session_touch_cache: SessionTouchCache,
     /// Reject access tokens without a `sid` claim (legacy tokens issued
     /// before session binding). See `AuthConfig::require_session_bound_access_tokens`.
     pub require_session_bound_access_tokens: bool,
@@ -465,6 +505,8 @@ impl MockAuthService {
             expires_at,
             ip_address: ip_address.or(Some("127.0.0.1".to_string())),
             user_agent,
+            device_label: None,
+            last_used_at: chrono::Utc::now(),
         };
 
         (access_token, session, session_token)
@@ -610,6 +652,19 @@ impl AuthServiceTrait for MockAuthService {
         Ok(true) // Mock logout always succeeds
     }
 
+    async fn list_sessions(&self, user_id: UserId) -> Result<Vec<Session>, AuthError> {
+        let (_, session, _) = self.create_mock_session(user_id);
+        Ok(vec![session])
+    }
+
+    async fn revoke_session(
+        &self,
+        _user_id: UserId,
+        _session_id: SessionId,
+    ) -> Result<bool, AuthError> {
+        Ok(true)
+    }
+
     async fn rotate_session(
         &self,
         _user_id: UserId,
@@ -0,0 +1,461 @@
+//! Server-side tool execution for chat completions.
+//!
+//! Opt-in per request via `auto_tool_execution: true`. The tools exposed by
+//! the workspace's MCP connectors are offered to the model alongside any
+//! client-defined tools. When a turn ends in tool calls that all name MCP
+//! tools, the calls are executed through [`McpService`], the results are
+//! appended as `tool` messages, and the model is called again, up to
+//! `max_iterations` model calls in total. A turn that calls any other tool
+//! (e.g. a client-defined function) is returned to the client unchanged, as
+//! is the last turn once the iteration budget is spent.
+//!
+//! Each model call goes through [`CompletionServiceTrait`], so every turn is
+//! billed and attested exactly like a standalone completion. The loop rewrites
+//! the request (MCP tools, forced non-streaming, appended tool turns), so each
+//! turn is signed over the hash of the body built for that turn rather than
+//! the client's original body; see [`turn_body_hash`].
+
+use crate::completions::ports::{
+    CompletionError, CompletionMessage, CompletionRequest, CompletionServiceTrait,
+    CompletionToolCall,
+};
+use crate::mcp::{ContentHelpers, McpConnectorId, McpService};
+use crate::workspace::WorkspaceId;
+use inference_providers::{ChatCompletionResponseWithBytes, TokenUsage};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::Instrument;
+
+/// Request body field that opts a chat completion into the tool loop.
+pub const AUTO_TOOL_EXECUTION_BODY_FIELD: &str = "auto_tool_execution";
+
+/// Default cap on model calls per request when the loop is enabled.
+pub const DEFAULT_MAX_ITERATIONS: u32 = 5;
+
+/// Tool result fed back to the model (and the client) when a call fails.
+/// The underlying error is only logged: it can carry connector internals.
+const TOOL_EXECUTION_FAILED_OUTPUT: &str = "Tool execution failed";
+
+/// Remove the opt-in field from the forwarded body and report whether it
+/// was set. Always stripped so strict upstream schemas never see it.
+pub fn take_body_field(extra: &mut HashMap<String, serde_json::Value>) -> bool {
+    extra
+        .remove(AUTO_TOOL_EXECUTION_BODY_FIELD)
+        .is_some_and(|value| value.as_bool() == Some(true))
+}
+
+/// A tool call the server is about to execute.
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolCallEvent {
+    pub iteration: u32,
+    /// Id of the model turn that requested the call, so each intermediate
+    /// turn's signature can be looked up.
+    pub completion_id: String,
+    pub call_id: String,
+    pub name: String,
+    pub arguments: String,
+}
+
+/// The outcome of an executed tool call, as fed back to the model.
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolResultEvent {
+    pub iteration: u32,
+    pub completion_id: String,
+    pub call_id: String,
+    pub name: String,
+    pub is_error: bool,
+    pub output: String,
+}
+
+#[derive(Debug)]
+pub enum ToolLoopEvent {
+    ToolCall(ToolCallEvent),
+    ToolResult(ToolResultEvent),
+    /// The final model turn, untouched so its `raw_bytes` still match its
+    /// signature. `total_usage` sums the usage of every model call made.
+    Completed {
+        response: Box<ChatCompletionResponseWithBytes>,
+        total_usage: TokenUsage,
+    },
+}
+
+pub struct AutoToolExecutionService {
+    completion_service: Arc<dyn CompletionServiceTrait>,
+    mcp_service: Arc<dyn McpService>,
+    max_iterations: u32,
+}
+
+impl AutoToolExecutionService {
+    pub fn new(
+        completion_service: Arc<dyn CompletionServiceTrait>,
+        mcp_service: Arc<dyn McpService>,
+        max_iterations: u32,
+    ) -> Self {
+        Self {
+            completion_service,
+            mcp_service,
+            max_iterations: max_iterations.max(1),
+        }
+    }
+
+    /// Run the tool loop for a (non-streaming) completion request.
+    ///
+    /// Tool events are yielded as they happen and the final turn last. An
+    /// error ends the stream. Dropping the stream stops the loop before the
+    /// next model or tool call.
+    pub fn run(
+        self: &Arc<Self>,
+        request: CompletionRequest,
+    ) -> ReceiverStream<Result<ToolLoopEvent, CompletionError>> {
+        let (tx, rx) = mpsc::channel(16);
+        let service = self.clone();
+        tokio::spawn(
+            async move {
+                if let Err(err) = service.run_loop(request, &tx).await {
+                    let _ = tx.send(Err(err)).await;
+                }
+            }
+            .instrument(tracing::Span::current()),
+        );
+        ReceiverStream::new(rx)
+    }
+
+    async fn run_loop(
+        &self,
+        mut request: CompletionRequest,
+        tx: &mpsc::Sender<Result<ToolLoopEvent, CompletionError>>,
+    ) -> Result<(), CompletionError> {
+        request.stream = Some(false);
+        // Upstreams reject stream options on non-streaming calls
+        request.extra.remove("stream_options");
+        let connectors = self.offer_workspace_tools(&mut request).await?;
+
+        let mut usage = TokenUsage::new(0, 0);
+        for iteration in 1..=self.max_iterations {
+            request.body_hash = turn_body_hash(&request);
+            let response = self
+                .completion_service
+                .create_chat_completion(request.clone())
+                .await?;
+            add_usage(&mut usage, &response.response.usage);
+
+            let message = response
+                .response
+                .choices
+                .first()
+                .map(|choice| choice.message.clone());
+            let tool_calls = message
+                .as_ref()
+                .and_then(|message| message.tool_calls.clone())
+                .unwrap_or_default();
+            let executable = !tool_calls.is_empty()
+                && tool_calls.iter().all(|call| {
+                    call.function
+                        .name
+                        .as_ref()
+                        .is_some_and(|name| connectors.contains_key(name))
+                });
+
+            if !executable || iteration == self.max_iterations {
+                let _ = tx
+                    .send(Ok(ToolLoopEvent::Completed {
+                        response: Box::new(response),
+                        total_usage: usage,
+                    }))
+                    .await;
+                return Ok(());
+            }
+
+            let calls: Vec<CompletionToolCall> = tool_calls
+                .into_iter()
+                .map(|call| CompletionToolCall {
+                    id: call
+                        .id
+                        .unwrap_or_else(|| format!("call_{}", uuid::Uuid::new_v4().simple())),
+                    name: call.function.name.unwrap_or_default(),
+                    arguments: call.function.arguments.unwrap_or_default(),
+                    thought_signature: call.thought_signature,
+                })
+                .collect();
+            request.messages.push(CompletionMessage {
+                role: "assistant".to_string(),
+                content: message
+                    .and_then(|message| message.content)
+                    .map(serde_json::Value::String)
+                    .unwrap_or(serde_json::Value::Null),
+                tool_call_id: None,
+                tool_calls: Some(calls.clone()),
+            });
+
+            for call in calls {
+                let event = ToolCallEvent {
+                    iteration,
+                    completion_id: response.response.id.clone(),
+                    call_id: call.id.clone(),
+                    name: call.name.clone(),
+                    arguments: call.arguments.clone(),
+                };
+                if tx.send(Ok(ToolLoopEvent::ToolCall(event))).await.is_err() {
+                    return Ok(());
+                }
+
+                let connector_id = connectors[&call.name].clone();
+                let (is_error, output) = self
+                    .execute_tool(connector_id, WorkspaceId(request.workspace_id), &call)
+                    .await;
+                request.messages.push(CompletionMessage {
+                    role: "tool".to_string(),
+                    content: serde_json::Value::String(output.clone()),
+                    tool_call_id: Some(call.id.clone()),
+                    tool_calls: None,
+                });
+
+                let event = ToolResultEvent {
+                    iteration,
+                    completion_id: response.response.id.clone(),
+                    call_id: call.id,
+                    name: call.name,
+                    is_error,
+                    output,
+                };
+                if tx.send(Ok(ToolLoopEvent::ToolResult(event))).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add the workspace's MCP tools to the request's `tools` and return the
+    /// names the loop may execute, mapped to their connector. Client-defined
+    /// tools win on a name clash and are never executed server-side; the
+    /// first connector to expose a name wins among connectors.
+    async fn offer_workspace_tools(
+        &self,
+        request: &mut CompletionRequest,
+    ) -> Result<HashMap<String, McpConnectorId>, CompletionError> {
+        let connectors = self
+            .mcp_service
+            .list_connectors(WorkspaceId(request.workspace_id))
+            .await
+            .map_err(|e| {
+                CompletionError::InternalError(format!("Failed to list connectors: {e}"))
+            })?;
+
+        let mut tools = request
+            .extra
+            .get("tools")
+            .and_then(|tools| tools.as_array())
+            .cloned()
+            .unwrap_or_default();
+        let client_tool_names: Vec<String> = tools
+            .iter()
+            .filter_map(|tool| tool["function"]["name"].as_str().map(str::to_string))
+            .collect();
+
+        let mut executable = HashMap::new();
+        for connector in connectors.into_iter().filter(|c| c.is_active) {
+            let connector_tools = match self.mcp_service.connect_connector(&connector).await {
+                Ok(()) => self.mcp_service.get_connector_tools(&connector.id).await,
+                Err(e) => Err(e),
+            };
+            let connector_tools = match connector_tools {
+                Ok(connector_tools) => connector_tools,
+                Err(e) => {
+                    // One unreachable connector must not fail the completion
+                    tracing::warn!(
+                        connector_id = %connector.id,
+                        error = %e,
+                        "Skipping MCP connector for auto tool execution"
+                    );
+                    continue;
+                }
+            };
+
+            for tool in connector_tools {
+                let name = tool.name.to_string();
+                if client_tool_names.contains(&name) || executable.contains_key(&name) {
+                    continue;
+                }
+                tools.push(serde_json::json!({
+                    "type": "function",
+                    "function": {
+                        "name": name,
+                        "description": tool.description,
+                        "parameters": serde_json::Value::Object((*tool.input_schema).clone()),
+                    }
+                }));
+                executable.insert(name, connector.id.clone());
+            }
+        }
+
+        if !tools.is_empty() {
+            request
+                .extra
+                .insert("tools".to_string(), serde_json::Value::Array(tools));
+        }
+        Ok(executable)
+    }
+
+    /// Execute one tool call. Failures are reported to the model as an
+    /// error result rather than aborting the loop.
+    async fn execute_tool(
+        &self,
+        connector_id: McpConnectorId,
+        workspace_id: WorkspaceId,
+        call: &CompletionToolCall,
+    ) -> (bool, String) {
+        let arguments = if call.arguments.trim().is_empty() {
+            None
+        } else {
+            match serde_json::from_str::<serde_json::Value>(&call.arguments) {
+                Ok(value) if value.is_object() => Some(value),
+                _ => {
+                    return (
+                        true,
+                        "Invalid tool arguments: expected a JSON object".to_string(),
+                    )
+                }
+            }
+        };
+
+        match self
+            .mcp_service
+            .invoke_connector_tool(
+                connector_id.clone(),
+                workspace_id,
+                call.name.clone(),
+                arguments,
+            )
+            .await
+        {
+            Ok(result) => {
+                let output = result
+                    .content
+                    .as_text()
+                    .unwrap_or_else(|| result.content.to_string_representation());
+                (result.is_error.unwrap_or(false), output)
+            }
+            Err(e) => {
+                tracing::warn!(
+                    connector_id = %connector_id,
+                    error = %e,
+                    "MCP tool execution failed"
+                );
+                (true, TOOL_EXECUTION_FAILED_OUTPUT.to_string())
+            }
+        }
+    }
+}
+
+/// SHA-256 (hex) of the chat completion body the loop sends for one turn:
+/// the model, messages and sampling parameters plus the forwarded extra
+/// fields. The client's `body_hash` covers a body no turn actually sends.
+fn turn_body_hash(request: &CompletionRequest) -> String {
+    let mut body = serde_json::Map::new();
+    body.insert("model".to_string(), request.model.clone().into());
+    body.insert(
+        "messages".to_string(),
+        serde_json::to_value(&request.messages).unwrap_or_default(),
+    );
+    let params = [
+        ("max_tokens", serde_json::to_value(request.max_tokens)),
+        ("temperature", serde_json::to_value(request.temperature)),
+        ("top_p", serde_json::to_value(request.top_p)),
+        ("stop", serde_json::to_value(&request.stop)),
+        ("n", serde_json::to_value(request.n)),
+        ("stream", serde_json::to_value(request.stream)),
+    ];
+    for (name, value) in params {
+        if let Some(value) = value.ok().filter(|value| !value.is_null()) {
+            body.insert(name.to_string(), value);
+        }
+    }
+    let mut extra: Vec<_> = request.extra.iter().collect();
+    extra.sort_by_key(|(name, _)| name.as_str());
+    for (name, value) in extra {
+        body.entry(name.clone()).or_insert_with(|| value.clone());
+    }
+    let bytes = serde_json::to_vec(&serde_json::Value::Object(body)).unwrap_or_default();
+    crate::audit::sha256_hex(&bytes)
+}
+
+fn add_usage(total: &mut TokenUsage, turn: &TokenUsage) {
+    total.prompt_tokens += turn.prompt_tokens;
+    total.completion_tokens += turn.completion_tokens;
+    total.total_tokens += turn.total_tokens;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn take_body_field_strips_and_reports_opt_in() {
+        let mut extra = HashMap::from([
+            (AUTO_TOOL_EXECUTION_BODY_FIELD.to_string(), json!(true)),
+            ("seed".to_string(), json!(1)),
+        ]);
+        assert!(take_body_field(&mut extra));
+        assert!(!extra.contains_key(AUTO_TOOL_EXECUTION_BODY_FIELD));
+        assert!(extra.contains_key("seed"));
+
+        let mut extra =
+            HashMap::from([(AUTO_TOOL_EXECUTION_BODY_FIELD.to_string(), json!("true"))]);
+        assert!(!take_body_field(&mut extra));
+        assert!(extra.is_empty());
+
+        assert!(!take_body_field(&mut HashMap::new()));
+    }
+
+    fn request() -> CompletionRequest {
+        CompletionRequest {
+            request_id: uuid::Uuid::new_v4(),
+            model: "test-model".to_string(),
+            messages: vec![CompletionMessage {
+                role: "user".to_string(),
+                content: json!("What time is it?"),
+                tool_call_id: None,
+                tool_calls: None,
+            }],
+            max_tokens: None,
+            temperature: Some(0.2),
+            top_p: None,
+            stop: None,
+            stream: Some(false),
+            n: None,
+            user_id: crate::UserId(uuid::Uuid::new_v4()),
+            api_key_id: uuid::Uuid::new_v4().to_string(),
+            organization_id: uuid::Uuid::new_v4(),
+            workspace_id: uuid::Uuid::new_v4(),
+            metadata: None,
+            store: None,
+            body_hash: "client-body-hash".to_string(),
+            response_id: None,
+            skip_provider_chat_signature: false,
+            priority: Default::default(),
+            extra: HashMap::from([("tools".to_string(), json!([]))]),
+        }
+    }
+
+    #[test]
+    fn turn_body_hash_covers_the_body_each_turn_sends() {
+        let mut request = request();
+        let first = turn_body_hash(&request);
+        assert_eq!(first, turn_body_hash(&request.clone()));
+        assert_ne!(first, request.body_hash);
+
+        request.messages.push(CompletionMessage {
+            role: "tool".to_string(),
+            content: json!("12:00"),
+            tool_call_id: Some("call_1".to_string()),
+            tool_calls: None,
+        });
+        assert_ne!(turn_body_hash(&request), first);
+    }
+}
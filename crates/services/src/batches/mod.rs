@@ -0,0 +1,341 @@
+pub mod ports;
+pub mod processor;
+
+pub use ports::{
+    Batch, BatchLineOutcome, BatchLineResult, BatchLineStatus, BatchRepository, BatchRequestCounts,
+    BatchRequestExecutor, BatchRequestLine, BatchStatus, CreateBatchParams,
+};
+pub use processor::BatchProcessor;
+
+use crate::{
+    common::RepositoryError,
+    files::{FileServiceError, FileServiceTrait},
+};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::{collections::HashSet, sync::Arc};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// The only endpoint batches can target for now
+pub const BATCH_ENDPOINT_CHAT_COMPLETIONS: &str = "/v1/chat/completions";
+/// The only supported completion window (matches OpenAI)
+pub const BATCH_COMPLETION_WINDOW: &str = "24h";
+/// Maximum number of request lines in one batch
+pub const MAX_BATCH_REQUESTS: usize = 10_000;
+/// Maximum length of a line's `custom_id`
+pub const MAX_CUSTOM_ID_LENGTH: usize = 256;
+
+#[derive(Debug, Error)]
+pub enum BatchError {
+    #[error("{0}")]
+    InvalidRequest(String),
+    #[error("Batch not found")]
+    NotFound,
+    #[error("File error: {0}")]
+    File(#[from] FileServiceError),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] RepositoryError),
+}
+
+/// One request line as submitted, inline or as a line of a JSONL input file
+/// (`{"custom_id": ..., "method": "POST", "url": "/v1/chat/completions", "body": {...}}`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchInputLine {
+    pub custom_id: String,
+    #[serde(default)]
+    pub method: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    pub body: serde_json::Value,
+}
+
+/// Where the batch's request lines come from
+#[derive(Debug, Clone)]
+pub enum BatchInput {
+    Inline(Vec<BatchInputLine>),
+    /// An uploaded file with purpose `batch`, one JSON request line per line
+    File(Uuid),
+}
+
+/// Parameters for creating a batch
+#[derive(Debug, Clone)]
+pub struct CreateBatchRequest {
+    pub organization_id: Uuid,
+    pub workspace_id: Uuid,
+    pub api_key_id: Uuid,
+    pub created_by_user_id: Uuid,
+    pub endpoint: String,
+    pub completion_window: String,
+    pub input: BatchInput,
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[async_trait]
+pub trait BatchServiceTrait: Send + Sync {
+    /// Validate the request lines and persist the batch for the background
+    /// processor. Lines are checked for shape here; request bodies are only
+    /// validated when each line runs.
+    async fn create_batch(&self, request: CreateBatchRequest) -> Result<Batch, BatchError>;
+
+    /// Get a batch (workspace-scoped) with its per-line results
+    async fn get_batch(
+        &self,
+        batch_id: Uuid,
+        workspace_id: Uuid,
+    ) -> Result<(Batch, Vec<BatchLineResult>), BatchError>;
+}
+
+pub struct BatchServiceImpl {
+    repository: Arc<dyn BatchRepository>,
+    files_service: Arc<dyn FileServiceTrait + Send + Sync>,
+}
+
+impl BatchServiceImpl {
+    pub fn new(
+        repository: Arc<dyn BatchRepository>,
+        files_service: Arc<dyn FileServiceTrait + Send + Sync>,
+    ) -> Self {
+        Self {
+            repository,
+            files_service,
+        }
+    }
+
+    async fn read_input_file(
+        &self,
+        file_id: Uuid,
+        workspace_id: Uuid,
+    ) -> Result<Vec<BatchInputLine>, BatchError> {
+        let (file, content) = match self
+            .files_service
+            .get_file_content(file_id, workspace_id)
+            .await
+        {
+            Ok(found) => found,
+            Err(FileServiceError::NotFound) => {
+                return Err(BatchError::InvalidRequest(format!(
+                    "Input file not found: {}{file_id}",
+                    crate::id_prefixes::PREFIX_FILE
+                )))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        if file.purpose != "batch" {
+            return Err(BatchError::InvalidRequest(format!(
+                "Input file must have purpose 'batch', got '{}'",
+                file.purpose
+            )));
+        }
+        parse_jsonl_input(&content)
+    }
+}
+
+#[async_trait]
+impl BatchServiceTrait for BatchServiceImpl {
+    async fn create_batch(&self, request: CreateBatchRequest) -> Result<Batch, BatchError> {
+        if request.endpoint != BATCH_ENDPOINT_CHAT_COMPLETIONS {
+            return Err(BatchError::InvalidRequest(format!(
+                "Unsupported endpoint '{}'. Supported endpoints: {BATCH_ENDPOINT_CHAT_COMPLETIONS}",
+                request.endpoint
+            )));
+        }
+        if request.completion_window != BATCH_COMPLETION_WINDOW {
+            return Err(BatchError::InvalidRequest(format!(
+                "Unsupported completion_window '{}'. Supported values: {BATCH_COMPLETION_WINDOW}",
+                request.completion_window
+            )));
+        }
+        if request
+            .metadata
+            .as_ref()
+            .is_some_and(|metadata| !metadata.is_object())
+        {
+            return Err(BatchError::InvalidRequest(
+                "metadata must be an object".to_string(),
+            ));
+        }
+
+        let (input_file_id, input_lines) = match request.input {
+            BatchInput::Inline(lines) => (None, lines),
+            BatchInput::File(file_id) => (
+                Some(file_id),
+                self.read_input_file(file_id, request.workspace_id).await?,
+            ),
+        };
+        let lines = validate_input_lines(input_lines, &request.endpoint)?;
+
+        let batch = self
+            .repository
+            .create(CreateBatchParams {
+                organization_id: request.organization_id,
+                workspace_id: request.workspace_id,
+                api_key_id: request.api_key_id,
+                created_by_user_id: request.created_by_user_id,
+                endpoint: request.endpoint,
+                completion_window: request.completion_window,
+                input_file_id,
+                metadata: request.metadata,
+                lines,
+            })
+            .await?;
+        tracing::info!(
+            batch_id = %batch.id,
+            workspace_id = %batch.workspace_id,
+            total_requests = batch.request_counts.total,
+            "Created batch"
+        );
+        Ok(batch)
+    }
+
+    async fn get_batch(
+        &self,
+        batch_id: Uuid,
+        workspace_id: Uuid,
+    ) -> Result<(Batch, Vec<BatchLineResult>), BatchError> {
+        let batch = self
+            .repository
+            .get_by_id_and_workspace(batch_id, workspace_id)
+            .await?
+            .ok_or(BatchError::NotFound)?;
+        let results = self.repository.list_line_results(batch.id).await?;
+        Ok((batch, results))
+    }
+}
+
+/// Parse a JSONL input file; blank lines are skipped.
+fn parse_jsonl_input(content: &[u8]) -> Result<Vec<BatchInputLine>, BatchError> {
+    let text = std::str::from_utf8(content)
+        .map_err(|_| BatchError::InvalidRequest("Input file must be UTF-8 JSONL".to_string()))?;
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).map_err(|e| {
+                BatchError::InvalidRequest(format!(
+                    "Invalid request on line {} of the input file: {e}",
+                    index + 1
+                ))
+            })
+        })
+        .collect()
+}
+
+fn validate_input_lines(
+    lines: Vec<BatchInputLine>,
+    endpoint: &str,
+) -> Result<Vec<BatchRequestLine>, BatchError> {
+    if lines.is_empty() {
+        return Err(BatchError::InvalidRequest(
+            "A batch must contain at least one request".to_string(),
+        ));
+    }
+    if lines.len() > MAX_BATCH_REQUESTS {
+        return Err(BatchError::InvalidRequest(format!(
+            "A batch can contain at most {MAX_BATCH_REQUESTS} requests, got {}",
+            lines.len()
+        )));
+    }
+
+    let mut seen_custom_ids = HashSet::with_capacity(lines.len());
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let invalid = |reason: String| {
+                BatchError::InvalidRequest(format!("Request {index} is invalid: {reason}"))
+            };
+            if line.custom_id.is_empty() || line.custom_id.len() > MAX_CUSTOM_ID_LENGTH {
+                return Err(invalid(format!(
+                    "custom_id must be between 1 and {MAX_CUSTOM_ID_LENGTH} characters"
+                )));
+            }
+            if !seen_custom_ids.insert(line.custom_id.clone()) {
+                return Err(invalid(format!("duplicate custom_id '{}'", line.custom_id)));
+            }
+            if line
+                .method
+                .as_deref()
+                .is_some_and(|method| !method.eq_ignore_ascii_case("POST"))
+            {
+                return Err(invalid("method must be POST".to_string()));
+            }
+            if line.url.as_deref().is_some_and(|url| url != endpoint) {
+                return Err(invalid(format!(
+                    "url must match the batch endpoint {endpoint}"
+                )));
+            }
+            if !line.body.is_object() {
+                return Err(invalid("body must be a JSON object".to_string()));
+            }
+            if line.body.get("stream").and_then(|v| v.as_bool()) == Some(true) {
+                return Err(invalid("streaming is not supported in batches".to_string()));
+            }
+            Ok(BatchRequestLine {
+                line_index: index as i32,
+                custom_id: line.custom_id,
+                body: line.body,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn line(custom_id: &str) -> BatchInputLine {
+        BatchInputLine {
+            custom_id: custom_id.to_string(),
+            method: Some("POST".to_string()),
+            url: Some(BATCH_ENDPOINT_CHAT_COMPLETIONS.to_string()),
+            body: json!({"model": "m", "messages": []}),
+        }
+    }
+
+    #[test]
+    fn test_validate_input_lines_assigns_indexes() {
+        let lines =
+            validate_input_lines(vec![line("a"), line("b")], BATCH_ENDPOINT_CHAT_COMPLETIONS)
+                .unwrap();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].line_index, 1);
+        assert_eq!(lines[1].custom_id, "b");
+    }
+
+    #[test]
+    fn test_validate_input_lines_rejects_bad_lines() {
+        let endpoint = BATCH_ENDPOINT_CHAT_COMPLETIONS;
+        assert!(validate_input_lines(vec![], endpoint).is_err());
+        assert!(validate_input_lines(vec![line("a"), line("a")], endpoint).is_err());
+
+        let mut wrong_url = line("a");
+        wrong_url.url = Some("/v1/embeddings".to_string());
+        assert!(validate_input_lines(vec![wrong_url], endpoint).is_err());
+
+        let mut streaming = line("a");
+        streaming.body = json!({"model": "m", "messages": [], "stream": true});
+        assert!(validate_input_lines(vec![streaming], endpoint).is_err());
+
+        let mut not_object = line("a");
+        not_object.body = json!("hello");
+        assert!(validate_input_lines(vec![not_object], endpoint).is_err());
+    }
+
+    #[test]
+    fn test_parse_jsonl_input() {
+        let content = concat!(
+            r#"{"custom_id":"a","method":"POST","url":"/v1/chat/completions","body":{"model":"m"}}"#,
+            "\n\n",
+            r#"{"custom_id":"b","body":{"model":"m"}}"#,
+            "\n"
+        );
+        let lines = parse_jsonl_input(content.as_bytes()).unwrap();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].custom_id, "b");
+
+        let err = parse_jsonl_input(b"{\"custom_id\":\"a\"}\nnot json").unwrap_err();
+        assert!(err.to_string().contains("line 1"), "{err}");
+    }
+}
@@ -0,0 +1,197 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::common::RepositoryError;
+
+/// Batch lifecycle. A batch is `validating` from creation until a worker
+/// claims it, `in_progress` while its lines run, and `completed` once every
+/// line has a result (individual lines may still have failed).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+    Validating,
+    InProgress,
+    Completed,
+}
+
+impl BatchStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BatchStatus::Validating => "validating",
+            BatchStatus::InProgress => "in_progress",
+            BatchStatus::Completed => "completed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "validating" => Some(BatchStatus::Validating),
+            "in_progress" => Some(BatchStatus::InProgress),
+            "completed" => Some(BatchStatus::Completed),
+            _ => None,
+        }
+    }
+}
+
+/// Status of a single request line within a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchLineStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+impl BatchLineStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BatchLineStatus::Pending => "pending",
+            BatchLineStatus::Completed => "completed",
+            BatchLineStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(BatchLineStatus::Pending),
+            "completed" => Some(BatchLineStatus::Completed),
+            "failed" => Some(BatchLineStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchRequestCounts {
+    pub total: i32,
+    pub completed: i32,
+    pub failed: i32,
+}
+
+/// Domain model for a batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Batch {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub workspace_id: Uuid,
+    pub api_key_id: Uuid,
+    pub created_by_user_id: Uuid,
+    pub endpoint: String,
+    pub completion_window: String,
+    pub input_file_id: Option<Uuid>,
+    pub output_file_id: Option<Uuid>,
+    pub status: BatchStatus,
+    pub metadata: Option<serde_json::Value>,
+    pub request_counts: BatchRequestCounts,
+    pub created_at: DateTime<Utc>,
+    pub in_progress_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// One request line of a batch, as submitted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchRequestLine {
+    pub line_index: i32,
+    pub custom_id: String,
+    /// Request body for the batch endpoint (e.g. a chat completion request)
+    pub body: serde_json::Value,
+}
+
+/// Per-line result of a batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchLineResult {
+    pub line_index: i32,
+    pub custom_id: String,
+    pub status: BatchLineStatus,
+    /// HTTP status the synchronous endpoint would have returned
+    pub status_code: Option<u16>,
+    /// Response (or error) body the synchronous endpoint would have returned
+    pub response_body: Option<serde_json::Value>,
+}
+
+/// Parameters for creating a batch record together with its lines
+#[derive(Debug, Clone)]
+pub struct CreateBatchParams {
+    pub organization_id: Uuid,
+    pub workspace_id: Uuid,
+    pub api_key_id: Uuid,
+    pub created_by_user_id: Uuid,
+    pub endpoint: String,
+    pub completion_window: String,
+    pub input_file_id: Option<Uuid>,
+    pub metadata: Option<serde_json::Value>,
+    pub lines: Vec<BatchRequestLine>,
+}
+
+/// Repository trait for batch operations
+#[async_trait]
+pub trait BatchRepository: Send + Sync {
+    async fn create(&self, params: CreateBatchParams) -> Result<Batch, RepositoryError>;
+
+    async fn get_by_id_and_workspace(
+        &self,
+        id: Uuid,
+        workspace_id: Uuid,
+    ) -> Result<Option<Batch>, RepositoryError>;
+
+    async fn list_line_results(
+        &self,
+        batch_id: Uuid,
+    ) -> Result<Vec<BatchLineResult>, RepositoryError>;
+
+    /// Atomically claim up to `limit` batches for processing: new
+    /// (`validating`) batches, plus `in_progress` batches whose claim was
+    /// released or is older than `stale_after`. Claimed batches are moved to
+    /// `in_progress` with a fresh claim timestamp.
+    async fn claim_batches(
+        &self,
+        limit: i64,
+        stale_after: chrono::Duration,
+    ) -> Result<Vec<Batch>, RepositoryError>;
+
+    async fn list_pending_lines(
+        &self,
+        batch_id: Uuid,
+    ) -> Result<Vec<BatchRequestLine>, RepositoryError>;
+
+    /// Store a line's result, bump the batch counters and refresh the claim.
+    async fn record_line_result(
+        &self,
+        batch_id: Uuid,
+        line_index: i32,
+        succeeded: bool,
+        status_code: u16,
+        response_body: serde_json::Value,
+    ) -> Result<(), RepositoryError>;
+
+    /// Give up the claim on an `in_progress` batch so a later pass resumes it.
+    async fn release_claim(&self, batch_id: Uuid) -> Result<(), RepositoryError>;
+
+    async fn mark_completed(
+        &self,
+        batch_id: Uuid,
+        output_file_id: Uuid,
+    ) -> Result<(), RepositoryError>;
+}
+
+/// Outcome of running one batch line
+#[derive(Debug, Clone)]
+pub enum BatchLineOutcome {
+    /// The line ran (successfully or not); the status and body are its result.
+    Done {
+        status_code: u16,
+        body: serde_json::Value,
+    },
+    /// Organization limits don't allow running the line right now; leave it
+    /// pending and resume the batch on a later pass.
+    Deferred,
+}
+
+/// Runs a single batch line against the batch endpoint. Implemented by the
+/// API layer, which owns request parsing and error-to-HTTP mapping.
+#[async_trait]
+pub trait BatchRequestExecutor: Send + Sync {
+    async fn execute(&self, batch: &Batch, line: &BatchRequestLine) -> BatchLineOutcome;
+}
@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use tracing::{error, info, warn};
+
+use super::ports::{Batch, BatchLineOutcome, BatchRepository, BatchRequestExecutor};
+use crate::files::{FileServiceTrait, UploadFileParams};
+
+/// A claim older than this (the processing instance crashed or hung) lets
+/// another instance resume the batch. Refreshed after every line.
+const STALE_CLAIM_AFTER_SECS: i64 = 600;
+/// Max batches claimed per tick.
+const CLAIM_BATCH_LIMIT: i64 = 5;
+
+/// Background task that runs the request lines of `POST /v1/batches` jobs.
+///
+/// Lines of a batch run one at a time through the executor, so a batch never
+/// holds more than one of its organization's concurrent-request slots. Lines
+/// the executor defers (organization at its limit) stay pending and the batch
+/// is resumed on a later tick. When every line has a result, the results are
+/// written to a JSONL output file and the batch is marked `completed`.
+///
+/// Multi-instance safe: batches are claimed with `FOR UPDATE SKIP LOCKED`.
+pub struct BatchProcessor {
+    repository: Arc<dyn BatchRepository>,
+    executor: Arc<dyn BatchRequestExecutor>,
+    files_service: Arc<dyn FileServiceTrait + Send + Sync>,
+    task_handle: tokio::sync::Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl BatchProcessor {
+    pub fn new(
+        repository: Arc<dyn BatchRepository>,
+        executor: Arc<dyn BatchRequestExecutor>,
+        files_service: Arc<dyn FileServiceTrait + Send + Sync>,
+    ) -> Self {
+        Self {
+            repository,
+            executor,
+            files_service,
+            task_handle: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Start the periodic processing task. If `interval_secs` is 0, this is a
+    /// no-op (used by test servers, which drive `run_once` directly).
+    pub async fn start(self: Arc<Self>, interval_secs: u64) {
+        if interval_secs == 0 {
+            info!("Batch processor disabled (interval is 0)");
+            return;
+        }
+
+        let handle = tokio::spawn({
+            let processor = self.clone();
+            async move {
+                let mut interval =
+                    tokio::time::interval(tokio::time::Duration::from_secs(interval_secs));
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = processor.run_once().await {
+                        error!(error = %e, "Batch processor tick failed");
+                    }
+                }
+            }
+        });
+
+        let mut task_handle = self.task_handle.lock().await;
+        *task_handle = Some(handle);
+        info!(
+            "Batch processor started with interval: {} seconds",
+            interval_secs
+        );
+    }
+
+    /// Cancel the background task. An interrupted batch keeps its claim and
+    /// is resumed by any instance once the claim goes stale.
+    pub async fn shutdown(&self) {
+        let mut task_handle = self.task_handle.lock().await;
+        if let Some(handle) = task_handle.take() {
+            handle.abort();
+            info!("Batch processor task cancelled");
+        }
+    }
+
+    /// One processing pass: claim open batches and run their pending lines.
+    /// Public so tests can drive it deterministically.
+    pub async fn run_once(&self) -> anyhow::Result<()> {
+        let claimed = self
+            .repository
+            .claim_batches(
+                CLAIM_BATCH_LIMIT,
+                chrono::Duration::seconds(STALE_CLAIM_AFTER_SECS),
+            )
+            .await?;
+        for batch in claimed {
+            if let Err(e) = self.process_batch(&batch).await {
+                error!(batch_id = %batch.id, error = %e, "Failed to process batch");
+                if let Err(release_err) = self.repository.release_claim(batch.id).await {
+                    error!(
+                        batch_id = %batch.id,
+                        error = %release_err,
+                        "Failed to release batch claim"
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn process_batch(&self, batch: &Batch) -> anyhow::Result<()> {
+        let pending = self.repository.list_pending_lines(batch.id).await?;
+        for line in pending {
+            match self.executor.execute(batch, &line).await {
+                BatchLineOutcome::Done { status_code, body } => {
+                    self.repository
+                        .record_line_result(
+                            batch.id,
+                            line.line_index,
+                            (200..300).contains(&status_code),
+                            status_code,
+                            body,
+                        )
+                        .await?;
+                }
+                BatchLineOutcome::Deferred => {
+                    info!(
+                        batch_id = %batch.id,
+                        line_index = line.line_index,
+                        "Batch line deferred by organization limits; resuming on a later pass"
+                    );
+                    self.repository.release_claim(batch.id).await?;
+                    return Ok(());
+                }
+            }
+        }
+
+        let output_file_id = self.write_output_file(batch).await?;
+        self.repository
+            .mark_completed(batch.id, output_file_id)
+            .await?;
+        info!(batch_id = %batch.id, "Completed batch");
+        Ok(())
+    }
+
+    /// Write the per-line results as JSONL (`{"custom_id", "response":
+    /// {"status_code", "body"}}` per line, in submission order).
+    async fn write_output_file(&self, batch: &Batch) -> anyhow::Result<uuid::Uuid> {
+        let results = self.repository.list_line_results(batch.id).await?;
+        let mut output = Vec::new();
+        for result in results {
+            if result.status_code.is_none() {
+                warn!(
+                    batch_id = %batch.id,
+                    line_index = result.line_index,
+                    "Batch line has no result while writing output"
+                );
+            }
+            serde_json::to_writer(
+                &mut output,
+                &serde_json::json!({
+                    "custom_id": result.custom_id,
+                    "response": {
+                        "status_code": result.status_code,
+                        "body": result.response_body,
+                    },
+                }),
+            )?;
+            output.push(b'\n');
+        }
+
+        let file = self
+            .files_service
+            .upload_file(UploadFileParams {
+                filename: format!("batch_{}_output.jsonl", batch.id),
+                file_data: output,
+                content_type: "text/plain".to_string(),
+                purpose: "batch".to_string(),
+                workspace_id: batch.workspace_id,
+                uploaded_by_api_key_id: batch.api_key_id,
+                expires_at: None,
+            })
+            .await?;
+        Ok(file.id)
+    }
+}
@@ -9,6 +9,51 @@ pub const REPORTING_TOKEN_LENGTH: usize = 36;
 /// Maximum serialized size for metadata blobs (e.g. conversation metadata, response metadata)
 pub const MAX_METADATA_SIZE_BYTES: usize = 16 * 1024;
 
+/// OpenAI limits for request `metadata`: at most 16 string key/value pairs.
+pub const MAX_METADATA_KEYS: usize = 16;
+pub const MAX_METADATA_KEY_LENGTH: usize = 64;
+pub const MAX_METADATA_VALUE_LENGTH: usize = 512;
+
+/// Check `metadata` against the OpenAI limits ([`MAX_METADATA_KEYS`],
+/// [`MAX_METADATA_KEY_LENGTH`], [`MAX_METADATA_VALUE_LENGTH`]) and return it as
+/// typed key/value pairs. `null` is treated as absent. Lengths are counted in
+/// characters.
+pub fn extract_metadata(
+    metadata: &serde_json::Value,
+) -> Result<Option<std::collections::BTreeMap<&str, &str>>, String> {
+    let object = match metadata {
+        serde_json::Value::Null => return Ok(None),
+        serde_json::Value::Object(object) => object,
+        _ => return Err("metadata must be an object of string key/value pairs".to_string()),
+    };
+    if object.len() > MAX_METADATA_KEYS {
+        return Err(format!(
+            "metadata can have at most {MAX_METADATA_KEYS} keys, got {}",
+            object.len()
+        ));
+    }
+    object
+        .iter()
+        .map(|(key, value)| {
+            if key.chars().count() > MAX_METADATA_KEY_LENGTH {
+                return Err(format!(
+                    "metadata keys can be at most {MAX_METADATA_KEY_LENGTH} characters long"
+                ));
+            }
+            let Some(value) = value.as_str() else {
+                return Err("metadata values must be strings".to_string());
+            };
+            if value.chars().count() > MAX_METADATA_VALUE_LENGTH {
+                return Err(format!(
+                    "metadata values can be at most {MAX_METADATA_VALUE_LENGTH} characters long"
+                ));
+            }
+            Ok((key.as_str(), value))
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
 /// Encryption header keys used in params.extra for passing encryption information
 /// These keys are used to pass encryption headers from API routes to completion services.
 /// Note: These use underscores (x_signing_algo) for params.extra HashMap keys,
@@ -26,6 +71,15 @@ pub mod encryption_headers {
     pub const ENCRYPT_ALL_FIELDS: &str = "x_encrypt_all_fields";
 }
 
+/// Key in params.extra carrying allowlisted client headers (a JSON object of
+/// lowercased header name to value) that self-hosted providers forward upstream.
+pub const PASSTHROUGH_HEADERS_KEY: &str = "x_passthrough_headers";
+
+/// Key in params.extra carrying the client's `x-max-fallback-attempts` header:
+/// the most distinct providers the pool may try for this request. Removed by
+/// the pool before the request reaches a provider.
+pub const MAX_FALLBACK_ATTEMPTS_KEY: &str = "x_max_fallback_attempts";
+
 pub fn generate_api_key() -> String {
     format!(
         "{}{}",
@@ -85,6 +139,8 @@ pub enum RepositoryError {
     ValidationFailed(String),
     #[error("Cannot delete due to existing dependencies: {0}")]
     DependencyExists(String),
+    #[error("Organization has reached its seat limit")]
+    SeatLimitExceeded,
     #[error("Transaction conflict, please retry")]
     TransactionConflict,
     #[error("Database connection failed: {0}")]
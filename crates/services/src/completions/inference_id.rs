@@ -0,0 +1,113 @@
+//! Deterministic `inference_id`s: UUID v5 hashes of the provider's response id
+//! (e.g. `chatcmpl-abc123`), returned as the `Inference-Id` header and stored
+//! on usage rows.
+//!
+//! The namespace is operator-configurable. Ids were originally hashed under
+//! `Uuid::NAMESPACE_DNS`; while the legacy lookup is enabled, code resolving a
+//! provider id back to its usage row also matches rows recorded under that
+//! namespace, so rows written before the switch stay reachable.
+
+use uuid::Uuid;
+
+/// Namespace every `inference_id` was hashed under before it became
+/// configurable.
+pub const LEGACY_INFERENCE_ID_NAMESPACE: Uuid = Uuid::NAMESPACE_DNS;
+
+/// The namespace `inference_id`s are hashed under, and whether lookups also
+/// match the legacy namespace. Built from `ServerConfig` and carried by the
+/// services and routes that derive ids; the default matches the config
+/// defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InferenceIdNamespace {
+    current: Uuid,
+    legacy_lookup: bool,
+}
+
+impl Default for InferenceIdNamespace {
+    fn default() -> Self {
+        Self::new(config::DEFAULT_INFERENCE_ID_NAMESPACE, true)
+    }
+}
+
+impl From<&config::ServerConfig> for InferenceIdNamespace {
+    fn from(server: &config::ServerConfig) -> Self {
+        Self::new(
+            server.inference_id_namespace,
+            server.inference_id_legacy_lookup,
+        )
+    }
+}
+
+impl InferenceIdNamespace {
+    /// `current` must not be the namespace reserved for externally reported
+    /// usage; `ApiConfig::validate` rejects that configuration at startup.
+    pub fn new(current: Uuid, legacy_lookup: bool) -> Self {
+        Self {
+            current,
+            legacy_lookup,
+        }
+    }
+
+    /// Hash inference ID to UUID deterministically (v5, this namespace).
+    /// Takes the full ID including prefix (e.g., "chatcmpl-abc123") and returns a stable UUID
+    pub fn hash(&self, full_id: &str) -> Uuid {
+        Uuid::new_v5(&self.current, full_id.as_bytes())
+    }
+
+    /// Every `inference_id` a usage row for `full_id` may have been recorded
+    /// under: the current hash first, then the legacy one while the transition
+    /// lookup is enabled.
+    pub fn lookup_candidates(&self, full_id: &str) -> Vec<Uuid> {
+        let mut candidates = vec![self.hash(full_id)];
+        if self.legacy_lookup && self.current != LEGACY_INFERENCE_ID_NAMESPACE {
+            candidates.push(Uuid::new_v5(
+                &LEGACY_INFERENCE_ID_NAMESPACE,
+                full_id.as_bytes(),
+            ));
+        }
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURRENT: Uuid = Uuid::from_u128(0x1cb03b71_9cc2_5ca6_b570_48d771631099);
+    const LEGACY: Uuid = Uuid::from_u128(0x3c1a53d2_91b0_5b8a_aa2c_7fc3feb5b05b);
+
+    #[test]
+    fn ids_hash_stably_under_the_default_namespace() {
+        let namespace = InferenceIdNamespace::default();
+        assert_eq!(namespace.hash("chatcmpl-abc123"), CURRENT);
+        assert_eq!(namespace.hash("chatcmpl-abc123"), CURRENT);
+        assert_ne!(namespace.hash("chatcmpl-abc124"), CURRENT);
+    }
+
+    #[test]
+    fn lookup_matches_both_namespaces_during_transition() {
+        let transition = InferenceIdNamespace::default();
+        assert_eq!(
+            transition.lookup_candidates("chatcmpl-abc123"),
+            vec![CURRENT, LEGACY]
+        );
+
+        let done = InferenceIdNamespace::new(config::DEFAULT_INFERENCE_ID_NAMESPACE, false);
+        assert_eq!(done.lookup_candidates("chatcmpl-abc123"), vec![CURRENT]);
+    }
+
+    #[test]
+    fn legacy_namespace_as_current_yields_a_single_candidate() {
+        let legacy = InferenceIdNamespace::new(LEGACY_INFERENCE_ID_NAMESPACE, true);
+        assert_eq!(legacy.lookup_candidates("chatcmpl-abc123"), vec![LEGACY]);
+    }
+
+    #[test]
+    fn namespaces_are_independent_per_instance() {
+        let other = InferenceIdNamespace::new(Uuid::from_u128(42), true);
+        assert_ne!(
+            other.hash("chatcmpl-abc123"),
+            InferenceIdNamespace::default().hash("chatcmpl-abc123")
+        );
+    }
+}
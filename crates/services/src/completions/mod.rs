@@ -1,10 +1,15 @@
+mod inference_id;
 pub mod ports;
+mod prompt_capture;
+
+pub use inference_id::{InferenceIdNamespace, LEGACY_INFERENCE_ID_NAMESPACE};
+pub use prompt_capture::capture_assembled_prompt;
 
 use crate::attestation::ports::AttestationServiceTrait;
 use crate::inference_provider_pool::InferenceProviderPool;
 use crate::models::ModelsRepository;
 use crate::responses::models::ResponseId;
-use crate::usage::{RecordUsageServiceRequest, UsageServiceTrait};
+use crate::usage::{ModelQuotaCheckResult, RecordUsageServiceRequest, UsageServiceTrait};
 use inference_providers::{ChatMessage, MessageRole, SSEEvent, StreamChunk, StreamingResult};
 use moka::future::Cache;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -30,12 +35,6 @@ enum StreamState {
     Done,
 }
 
-/// Hash inference ID to UUID deterministically using MD5 (v5)
-/// Takes the full ID including prefix (e.g., "chatcmpl-abc123") and returns a stable UUID
-pub fn hash_inference_id_to_uuid(full_id: &str) -> Uuid {
-    Uuid::new_v5(&Uuid::NAMESPACE_DNS, full_id.as_bytes())
-}
-
 /// Get input bucket tag based on token count for metrics breakdown
 /// Buckets: 0-1k, 1-4k, 4-16k, 16-32k, 32-64k, 64-128k, 128k+
 /// Per-request prefix-cache hit rate as a percentage (cache-read / prompt
@@ -53,6 +52,22 @@ fn cache_hit_rate_percent(cached_tokens: i32, prompt_tokens: i32) -> Option<f64>
     Some((cached as f64 / prompt_tokens as f64) * 100.0)
 }
 
+/// Whether a streamed delta carries generated text (content, reasoning or
+/// tool-call name/arguments), as opposed to a role-only or empty delta.
+fn delta_has_output(delta: &inference_providers::ChatDelta) -> bool {
+    let non_empty = |text: &Option<String>| text.as_deref().is_some_and(|t| !t.is_empty());
+    non_empty(&delta.content)
+        || non_empty(&delta.reasoning_content)
+        || non_empty(&delta.reasoning)
+        || delta.tool_calls.as_ref().is_some_and(|calls| {
+            calls.iter().any(|call| {
+                call.function
+                    .as_ref()
+                    .is_some_and(|f| non_empty(&f.name) || non_empty(&f.arguments))
+            })
+        })
+}
+
 fn get_input_bucket(token_count: i32) -> &'static str {
     match token_count {
         0..=1000 => "0-1k",
@@ -65,6 +80,27 @@ fn get_input_bucket(token_count: i32) -> &'static str {
     }
 }
 
+/// Validated sampling penalties lifted out of a request's `extra` map.
+struct SamplingPenalties {
+    frequency_penalty: Option<f32>,
+    presence_penalty: Option<f32>,
+    logit_bias: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Prompt-token estimate for a stream whose provider never reported usage:
+/// the byte heuristic plus media/template overhead. It is computed before the
+/// request is handed to the provider so the stream never holds a copy of the
+/// prompt.
+fn estimate_prompt_tokens(params: &inference_providers::ChatCompletionParams) -> i32 {
+    let estimate = crate::inference_provider_pool::context_routing::estimate_input(params);
+    i32::try_from(
+        estimate
+            .countable_tokens
+            .saturating_add(estimate.uncounted_tokens),
+    )
+    .unwrap_or(i32::MAX)
+}
+
 struct InterceptStream<S>
 where
     S: Stream<Item = Result<SSEEvent, inference_providers::CompletionError>> + Unpin,
@@ -96,6 +132,8 @@ where
     // Pre-allocated low-cardinality metric tags (for Datadog/OTLP)
     metric_tags: Vec<String>,
     concurrent_counter: Option<Arc<AtomicU32>>,
+    /// Namespace the chat ID is hashed under to derive the inference_id
+    inference_id_namespace: InferenceIdNamespace,
     /// Last received usage stats from streaming chunks
     last_usage_stats: Option<inference_providers::TokenUsage>,
     /// Last chat ID from streaming chunks (for attestation and inference_id)
@@ -119,6 +157,15 @@ where
     /// Callback to report observed TTFT back to the provider pool for latency-aware
     /// routing. Called once with the backend TTFT (ms) from record_usage_and_metrics.
     latency_reporter: Option<super::inference_provider_pool::ProviderLatencyReporter>,
+    /// Compliance audit capture; written once from Drop when the stream ends.
+    audit: Option<crate::audit::StreamAuditor>,
+    /// Deltas carrying content, reasoning or tool-call text; the output-token
+    /// estimate when the provider never reports usage.
+    streamed_deltas: i32,
+    /// The provider sent its `[DONE]` terminator.
+    received_done: bool,
+    /// Prompt-token estimate for the estimated-usage fallback.
+    estimated_prompt_tokens: Option<i32>,
 }
 
 impl<S> InterceptStream<S>
@@ -170,8 +217,18 @@ where
         })
     }
 
+    /// The provider finished normally (`[DONE]` or a finish_reason) after
+    /// streaming output, but never sent a usage chunk.
+    fn finished_without_usage(&self) -> bool {
+        self.last_usage_stats.is_none()
+            && self.stream_completed
+            && self.last_error.is_none()
+            && (self.received_done || self.last_finish_reason.is_some())
+            && self.streamed_deltas > 0
+    }
+
     /// Record usage and metrics. Called from Drop to ensure it always runs.
-    fn record_usage_and_metrics(&self) {
+    fn record_usage_and_metrics(&mut self) {
         let request_id = self.request_id;
         let organization_id = self.organization_id;
         let workspace_id = self.workspace_id;
@@ -191,6 +248,7 @@ where
         )
         .entered();
 
+        let mut estimated = false;
         let (input_tokens, output_tokens, cache_read_tokens, chat_id) = match (
             &self.last_usage_stats,
             &self.last_chat_id,
@@ -201,6 +259,18 @@ where
                 usage.cached_tokens(),
                 chat_id.clone(),
             ),
+            (None, Some(chat_id)) if self.finished_without_usage() => {
+                tracing::warn!(%chat_id, %organization_id, %model_id, model = %self.model_name,
+                    "Stream finished without usage stats; recording estimated usage");
+                let chat_id = chat_id.clone();
+                estimated = true;
+                (
+                    self.estimated_prompt_tokens.unwrap_or(0),
+                    self.streamed_deltas,
+                    0,
+                    chat_id,
+                )
+            }
             (None, None) => {
                 // Distinguish client disconnect / provider error from truly unexpected cases.
                 // Client disconnects and provider errors are expected — usage is only sent
@@ -261,7 +331,7 @@ where
             }
         };
 
-        let inference_id = hash_inference_id_to_uuid(&chat_id);
+        let inference_id = self.inference_id_namespace.hash(&chat_id);
 
         // Create span with full context for async task
         let span = tracing::info_span!(
@@ -287,6 +357,7 @@ where
         }
 
         let e2e_duration = self.service_start_time.elapsed();
+        let provider_duration = self.provider_start_time.elapsed();
         let first_token_time = self.first_token_time;
         let stream_completed = self.stream_completed;
         let provider_attribution = self.provider_attribution;
@@ -297,9 +368,7 @@ where
             None
         };
 
-        let input_bucket = get_input_bucket(input_tokens);
         let mut metric_tags = self.metric_tags.clone();
-        metric_tags.push(format!("{TAG_INPUT_BUCKET}:{input_bucket}"));
 
         // Spawn critical billing operations on blocking thread pool with timeout.
         // The tokio runtime waits for blocking tasks during graceful shutdown,
@@ -309,6 +378,9 @@ where
             handle_clone.block_on(
                 async move {
                     let result = tokio::time::timeout(Duration::from_secs(2), async move {
+                        let input_bucket = get_input_bucket(input_tokens);
+                        metric_tags.push(format!("{TAG_INPUT_BUCKET}:{input_bucket}"));
+
                         let stop_reason = if let Some(ref err) = last_error {
                             Some(crate::usage::StopReason::from_completion_error(err))
                         } else if !stream_completed {
@@ -339,6 +411,7 @@ where
                                 response_id,
                                 image_count: None,
                                 provider_attribution,
+                                estimated,
                             })
                             .await
                             .is_err()
@@ -349,6 +422,11 @@ where
                         // Record metrics
                         let tags: Vec<&str> = metric_tags.iter().map(|s| s.as_str()).collect();
                         metrics_service.record_latency(METRIC_LATENCY_TOTAL, e2e_duration, &tags);
+                        metrics_service.record_latency(
+                            METRIC_LATENCY_PROVIDER_TOTAL,
+                            provider_duration,
+                            &tags,
+                        );
 
                         if let Some(first_token_instant) = first_token_time {
                             let decoding_duration = first_token_instant.elapsed();
@@ -419,11 +497,18 @@ where
                 StreamState::Streaming => {
                     match Pin::new(&mut self.inner).poll_next(cx) {
                         Poll::Ready(Some(Ok(ref event))) => {
+                            if let Some(audit) = &mut self.audit {
+                                audit.observe(&event.raw_bytes);
+                            }
+
                             // Control events (blank lines, comments, [DONE])
                             // carry no tokens: pass them through untouched so
                             // the route can forward their raw bytes, but keep
                             // them out of TTFT/ITL metrics and chat tracking.
                             if event.chunk.is_none() {
+                                if event.is_done_marker() {
+                                    self.received_done = true;
+                                }
                                 return Poll::Ready(Some(Ok(event.clone())));
                             }
 
@@ -465,11 +550,23 @@ where
                                     self.last_usage_stats = Some(usage.clone());
                                 }
 
-                                // Track finish_reason from the final chunk (only set once at end)
-                                if let Some(choice) = chat_chunk.choices.first() {
-                                    if let Some(ref reason) = choice.finish_reason {
-                                        self.last_finish_reason = Some(reason.clone());
-                                    }
+                                self.streamed_deltas += chat_chunk
+                                    .choices
+                                    .iter()
+                                    .filter(|choice| {
+                                        choice.delta.as_ref().is_some_and(delta_has_output)
+                                    })
+                                    .count()
+                                    as i32;
+
+                                // Track finish_reason from the final chunk. Look at every
+                                // choice: with n > 1 the finishing choice need not be first.
+                                if let Some(reason) = chat_chunk
+                                    .choices
+                                    .iter()
+                                    .find_map(|choice| choice.finish_reason.as_ref())
+                                {
+                                    self.last_finish_reason = Some(reason.clone());
                                 }
                             }
                             return Poll::Ready(Some(Ok(event.clone())));
@@ -514,6 +611,10 @@ where
 
         // Always record usage in Drop (async, fire-and-forget)
         self.record_usage_and_metrics();
+
+        if let Some(audit) = self.audit.take() {
+            audit.finish();
+        }
     }
 }
 
@@ -559,11 +660,79 @@ pub struct CompletionServiceImpl {
     org_concurrent_limits: Cache<Uuid, u32>,
     /// Repository for fetching organization concurrent limits
     organization_limit_repository: Arc<dyn ports::OrganizationConcurrentLimitRepository>,
+    /// Cache for per-organization model allowlists (5-minute TTL)
+    org_allowed_models: Cache<Uuid, Option<Arc<Vec<String>>>>,
+    /// Repository for fetching organization model allowlists
+    organization_model_access_repository: Arc<dyn ports::OrganizationModelAccessRepository>,
+    /// Compliance audit trail; `None` unless enabled by configuration
+    audit: Option<crate::audit::AuditLogger>,
+    /// In-flight coalesced non-streaming chat completions; see
+    /// `chat_completion_single_flight`.
+    chat_completions_in_flight: ChatCompletionsInFlight,
+    /// Window within which an identical streaming start is rejected while the
+    /// first is still being established; `None` disables the guard.
+    stream_dedup_window: Option<Duration>,
+    stream_starts_in_flight: Arc<StreamStartsInFlight>,
+    /// Requests per minute allowed per `(api_key_id, user)`; 0 disables.
+    end_user_requests_per_minute: u32,
+    /// Requests seen in the current one-minute window per `(api_key_id, user)`.
+    end_user_requests: Cache<(Uuid, String), Arc<AtomicU32>>,
+    /// Handling of `json_schema` response formats the model can't honor.
+    json_schema_fallback: config::JsonSchemaFallbackPolicy,
+    /// Namespace provider chat IDs are hashed under to derive inference_ids.
+    inference_id_namespace: InferenceIdNamespace,
+}
+
+/// Upstream result shared by coalesced chat completions.
+type CoalescedChatCompletion = Result<
+    crate::inference_provider_pool::AttributedChatCompletion,
+    inference_providers::CompletionError,
+>;
+
+/// In-flight chat completions keyed by `(organization_id, workspace_id,
+/// body_hash)`. The body hash is taken before workspace default params are
+/// applied, so the workspace is part of the key.
+type ChatCompletionsInFlight = std::sync::Mutex<
+    std::collections::HashMap<
+        (Uuid, Uuid, String),
+        Arc<tokio::sync::OnceCell<CoalescedChatCompletion>>,
+    >,
+>;
+
+/// Streaming starts still being established, keyed by `(api_key_id, body_hash)`
+/// and holding when each one began.
+type StreamStartsInFlight = std::sync::Mutex<std::collections::HashMap<(Uuid, String), Instant>>;
+
+/// Claim on a streaming start; releases it once the upstream stream yields
+/// its first event (or the start fails or is cancelled).
+struct StreamStartGuard {
+    in_flight: Arc<StreamStartsInFlight>,
+    key: (Uuid, String),
+    started: Instant,
+}
+
+impl Drop for StreamStartGuard {
+    fn drop(&mut self) {
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        // A start claimed after this one's window expired owns the entry now.
+        if in_flight.get(&self.key) == Some(&self.started) {
+            in_flight.remove(&self.key);
+        }
+    }
 }
 
 /// TTL for organization concurrent limit cache (5 minutes)
 const ORG_LIMIT_CACHE_TTL_SECS: u64 = 300;
 
+/// Longest accepted client `user` identifier.
+const MAX_END_USER_LENGTH: usize = 256;
+
+/// Distinct `(api_key_id, user)` pairs tracked by the end-user rate limiter.
+const END_USER_RATE_LIMIT_CAPACITY: u64 = 100_000;
+
 /// TTL for concurrent count cache entries (10 minutes).
 /// Safety net: if a counter gets stuck (e.g., due to a panic or proxy not propagating
 /// client disconnection), the entry expires and is replaced with a fresh zero counter.
@@ -652,6 +821,7 @@ impl CompletionServiceImpl {
         metrics_service: Arc<dyn MetricsServiceTrait>,
         models_repository: Arc<dyn ModelsRepository>,
         organization_limit_repository: Arc<dyn ports::OrganizationConcurrentLimitRepository>,
+        organization_model_access_repository: Arc<dyn ports::OrganizationModelAccessRepository>,
     ) -> Self {
         let concurrent_counts = Cache::builder()
             .max_capacity(100_000)
@@ -663,6 +833,10 @@ impl CompletionServiceImpl {
             .time_to_live(Duration::from_secs(ORG_LIMIT_CACHE_TTL_SECS))
             .max_capacity(10_000)
             .build();
+        let org_allowed_models = Cache::builder()
+            .time_to_live(Duration::from_secs(ORG_LIMIT_CACHE_TTL_SECS))
+            .max_capacity(10_000)
+            .build();
 
         Self {
             inference_provider_pool,
@@ -674,7 +848,218 @@ impl CompletionServiceImpl {
             concurrent_limit: DEFAULT_CONCURRENT_LIMIT,
             org_concurrent_limits,
             organization_limit_repository,
+            org_allowed_models,
+            organization_model_access_repository,
+            audit: None,
+            chat_completions_in_flight: Default::default(),
+            stream_dedup_window: None,
+            stream_starts_in_flight: Default::default(),
+            end_user_requests_per_minute: 0,
+            end_user_requests: Cache::builder()
+                .time_to_live(Duration::from_secs(60))
+                .max_capacity(END_USER_RATE_LIMIT_CAPACITY)
+                .build(),
+            json_schema_fallback: config::JsonSchemaFallbackPolicy::default(),
+            inference_id_namespace: InferenceIdNamespace::default(),
+        }
+    }
+
+    /// Write an audit entry for every chat completion through `audit`.
+    pub fn with_audit_logger(mut self, audit: crate::audit::AuditLogger) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// Reject a streaming start identical to one from the same API key (same
+    /// body hash) that began less than `window` ago and is still waiting on
+    /// the provider, as client retries do during a slow first token. A zero
+    /// window leaves the guard off.
+    pub fn with_stream_dedup_window(mut self, window: Duration) -> Self {
+        self.stream_dedup_window = (!window.is_zero()).then_some(window);
+        self
+    }
+
+    /// Limit each end user (the client's `user` field) of an API key to
+    /// `requests_per_minute` chat completions, so one abusive end user can't
+    /// exhaust a tenant's capacity. 0 leaves the limiter off.
+    pub fn with_end_user_rate_limit(mut self, requests_per_minute: u32) -> Self {
+        self.end_user_requests_per_minute = requests_per_minute;
+        self
+    }
+
+    /// Reject or downgrade `response_format: json_schema` on models whose
+    /// catalog lacks the `structured_outputs` feature. Passthrough (the
+    /// default) forwards it unchanged.
+    pub fn with_json_schema_fallback(mut self, policy: config::JsonSchemaFallbackPolicy) -> Self {
+        self.json_schema_fallback = policy;
+        self
+    }
+
+    /// Hash provider chat IDs into inference_ids under `namespace` rather
+    /// than the default one.
+    pub fn with_inference_id_namespace(mut self, namespace: InferenceIdNamespace) -> Self {
+        self.inference_id_namespace = namespace;
+        self
+    }
+
+    /// Count a request against `(api_key_id, end_user)`'s one-minute window.
+    async fn check_end_user_rate_limit(
+        &self,
+        api_key_id: Uuid,
+        end_user: Option<&str>,
+    ) -> Result<(), ports::CompletionError> {
+        let limit = self.end_user_requests_per_minute;
+        let Some(end_user) = end_user.filter(|_| limit > 0) else {
+            return Ok(());
+        };
+        let counter = self
+            .end_user_requests
+            .get_with((api_key_id, end_user.to_string()), async {
+                Arc::new(AtomicU32::new(0))
+            })
+            .await;
+        if counter.fetch_add(1, Ordering::Relaxed) >= limit {
+            return Err(ports::CompletionError::RateLimitExceeded(format!(
+                "Rate limit exceeded for this end user: {limit} requests per minute"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Claim the streaming start for `(api_key_id, body_hash)`, failing with
+    /// `DuplicateRequest` if an identical start is still in its window.
+    fn claim_stream_start(
+        &self,
+        api_key_id: Uuid,
+        body_hash: &str,
+    ) -> Result<Option<StreamStartGuard>, ports::CompletionError> {
+        let Some(window) = self.stream_dedup_window else {
+            return Ok(None);
+        };
+        if body_hash.is_empty() {
+            return Ok(None);
+        }
+
+        let key = (api_key_id, body_hash.to_string());
+        let now = Instant::now();
+        let mut in_flight = self
+            .stream_starts_in_flight
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if in_flight
+            .get(&key)
+            .is_some_and(|started| now.duration_since(*started) < window)
+        {
+            return Err(ports::CompletionError::DuplicateRequest(
+                "An identical streaming request is still starting; retry once it has responded"
+                    .to_string(),
+            ));
+        }
+        in_flight.insert(key.clone(), now);
+        Ok(Some(StreamStartGuard {
+            in_flight: self.stream_starts_in_flight.clone(),
+            key,
+            started: now,
+        }))
+    }
+
+    /// Whether a non-streaming chat completion may share an upstream call with
+    /// identical concurrent requests: it must be deterministic (`temperature: 0`)
+    /// and fully described by its body hash, i.e. carry no per-client headers
+    /// (E2EE keys, signing algorithm, passthrough headers, fallback cap) that
+    /// the hash omits.
+    fn is_coalescible(params: &inference_providers::ChatCompletionParams, body_hash: &str) -> bool {
+        use crate::common::encryption_headers;
+
+        !body_hash.is_empty()
+            && params.temperature == Some(0.0)
+            && ![
+                encryption_headers::SIGNING_ALGO,
+                encryption_headers::CLIENT_PUB_KEY,
+                encryption_headers::MODEL_PUB_KEY,
+                encryption_headers::ENCRYPTION_VERSION,
+                encryption_headers::ENCRYPT_ALL_FIELDS,
+                crate::common::PASSTHROUGH_HEADERS_KEY,
+                crate::common::MAX_FALLBACK_ATTEMPTS_KEY,
+            ]
+            .iter()
+            .any(|key| params.extra.contains_key(*key))
+    }
+
+    /// Span around the provider pool call (selection plus upstream attempts);
+    /// `outcome` is recorded once the call returns.
+    fn provider_request_span(model: &str) -> tracing::Span {
+        tracing::info_span!(
+            "provider_request",
+            model = %model,
+            outcome = tracing::field::Empty
+        )
+    }
+
+    /// Single-flight wrapper around the pool's non-streaming chat completion:
+    /// coalescible requests from the same workspace with the same body hash
+    /// share one provider call and all receive its result. A request arriving
+    /// after the call finished starts a new one.
+    ///
+    /// Returns the result and whether it came from another request's call.
+    async fn chat_completion_single_flight(
+        &self,
+        organization_id: Uuid,
+        workspace_id: Uuid,
+        params: inference_providers::ChatCompletionParams,
+        body_hash: String,
+    ) -> (CoalescedChatCompletion, bool) {
+        if !Self::is_coalescible(&params, &body_hash) {
+            let result = self
+                .inference_provider_pool
+                .chat_completion_with_attribution(params, body_hash)
+                .await;
+            return (result, false);
+        }
+
+        let key = (organization_id, workspace_id, body_hash.clone());
+        let cell = self
+            .chat_completions_in_flight
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .entry(key.clone())
+            .or_default()
+            .clone();
+
+        // If the running call is cancelled, a waiting caller takes over.
+        let mut led = false;
+        let result = cell
+            .get_or_init(|| {
+                led = true;
+                self.inference_provider_pool
+                    .chat_completion_with_attribution(params, body_hash)
+            })
+            .await
+            .clone();
+
+        let mut in_flight = self
+            .chat_completions_in_flight
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if in_flight
+            .get(&key)
+            .is_some_and(|current| Arc::ptr_eq(current, &cell))
+        {
+            in_flight.remove(&key);
         }
+        (result, !led)
+    }
+
+    /// Serialize the upstream request for the audit trail, only when bodies
+    /// are being stored (the logger redacts them before persisting).
+    fn audit_request_body(
+        &self,
+        params: &inference_providers::ChatCompletionParams,
+    ) -> Option<String> {
+        self.audit
+            .as_ref()
+            .filter(|audit| audit.stores_bodies())
+            .and_then(|_| serde_json::to_string(params).ok())
     }
 
     /// Extract tools and tool_choice from the extra HashMap if present and
@@ -760,6 +1145,109 @@ impl CompletionServiceImpl {
         }
     }
 
+    /// Promote an integer `seed` from `extra` to the typed param, so every
+    /// provider (not only those that forward unknown fields) receives it.
+    /// A non-integer value is left in `extra` for the backend to reject.
+    fn extract_seed_from_extra(
+        extra: &mut std::collections::HashMap<String, serde_json::Value>,
+    ) -> Option<i64> {
+        let seed = extra.get("seed").and_then(serde_json::Value::as_i64)?;
+        extra.remove("seed");
+        Some(seed)
+    }
+
+    /// Move the client's `user` (its own end-user identifier, forwarded to
+    /// the provider for abuse tracking) out of `extra`, where it would
+    /// otherwise collide with the typed `user` field.
+    fn extract_end_user_from_extra(
+        extra: &mut std::collections::HashMap<String, serde_json::Value>,
+    ) -> Result<Option<String>, ports::CompletionError> {
+        match extra.remove("user") {
+            None | Some(serde_json::Value::Null) => Ok(None),
+            Some(serde_json::Value::String(user))
+                if user.chars().count() <= MAX_END_USER_LENGTH =>
+            {
+                Ok(Some(user).filter(|user| !user.is_empty()))
+            }
+            Some(serde_json::Value::String(_)) => Err(ports::CompletionError::InvalidParams(
+                format!("user must be at most {MAX_END_USER_LENGTH} characters"),
+            )),
+            Some(_) => Err(ports::CompletionError::InvalidParams(
+                "user must be a string".to_string(),
+            )),
+        }
+    }
+
+    /// Enforce the OpenAI `metadata` limits on both the typed field (Responses
+    /// API) and the copy chat requests forward through `extra`.
+    fn validate_metadata(
+        metadata: Option<&serde_json::Value>,
+        extra: &std::collections::HashMap<String, serde_json::Value>,
+    ) -> Result<(), ports::CompletionError> {
+        for metadata in metadata.into_iter().chain(extra.get("metadata")) {
+            crate::common::extract_metadata(metadata)
+                .map_err(ports::CompletionError::InvalidParams)?;
+        }
+        Ok(())
+    }
+
+    /// Move `frequency_penalty`, `presence_penalty` and `logit_bias` out of
+    /// `extra` (where the API layer forwards them) into their typed slots,
+    /// rejecting values outside the OpenAI ranges before any provider is
+    /// called. vLLM otherwise answers out-of-range penalties with a 400 that
+    /// is masked as a provider failure.
+    fn extract_sampling_penalties_from_extra(
+        extra: &mut std::collections::HashMap<String, serde_json::Value>,
+    ) -> Result<SamplingPenalties, ports::CompletionError> {
+        let mut penalty = |name: &str| -> Result<Option<f32>, ports::CompletionError> {
+            let Some(value) = extra.remove(name).filter(|value| !value.is_null()) else {
+                return Ok(None);
+            };
+            let max = inference_providers::MAX_SAMPLING_PENALTY;
+            match value.as_f64() {
+                Some(penalty) if (-f64::from(max)..=f64::from(max)).contains(&penalty) => {
+                    Ok(Some(penalty as f32))
+                }
+                _ => Err(ports::CompletionError::InvalidParams(format!(
+                    "{name} must be a number between -{max} and {max}"
+                ))),
+            }
+        };
+        let frequency_penalty = penalty("frequency_penalty")?;
+        let presence_penalty = penalty("presence_penalty")?;
+
+        let logit_bias = match extra.remove("logit_bias") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::Object(bias)) => {
+                let max = inference_providers::MAX_LOGIT_BIAS;
+                for (token, value) in &bias {
+                    if token.parse::<u32>().is_err() {
+                        return Err(ports::CompletionError::InvalidParams(format!(
+                            "logit_bias keys must be token IDs, got '{token}'"
+                        )));
+                    }
+                    if !value.as_f64().is_some_and(|v| (-max..=max).contains(&v)) {
+                        return Err(ports::CompletionError::InvalidParams(format!(
+                            "logit_bias values must be numbers between -{max} and {max}"
+                        )));
+                    }
+                }
+                Some(bias)
+            }
+            Some(_) => {
+                return Err(ports::CompletionError::InvalidParams(
+                    "logit_bias must be an object mapping token IDs to bias values".to_string(),
+                ))
+            }
+        };
+
+        Ok(SamplingPenalties {
+            frequency_penalty,
+            presence_penalty,
+            logit_bias,
+        })
+    }
+
     fn is_json_object_response_format(
         extra: &std::collections::HashMap<String, serde_json::Value>,
     ) -> bool {
@@ -770,6 +1258,102 @@ impl CompletionServiceImpl {
             == Some("json_object")
     }
 
+    /// Apply `policy` to a `response_format: json_schema` request for a model
+    /// without the `structured_outputs` feature: reject it, or rewrite it to
+    /// `json_object` so the client still gets JSON, just unvalidated.
+    fn apply_json_schema_fallback(
+        policy: config::JsonSchemaFallbackPolicy,
+        supported_features: &[String],
+        model_name: &str,
+        extra: &mut std::collections::HashMap<String, serde_json::Value>,
+    ) -> Result<(), ports::CompletionError> {
+        let is_json_schema = extra
+            .get("response_format")
+            .and_then(|format| format.get("type"))
+            .and_then(|kind| kind.as_str())
+            == Some("json_schema");
+        if !is_json_schema
+            || supported_features
+                .iter()
+                .any(|feature| feature == "structured_outputs")
+        {
+            return Ok(());
+        }
+
+        match policy {
+            config::JsonSchemaFallbackPolicy::Passthrough => Ok(()),
+            config::JsonSchemaFallbackPolicy::Reject => {
+                Err(ports::CompletionError::InvalidParams(format!(
+                    "Model '{model_name}' does not support response_format type \"json_schema\"; use \"json_object\" instead"
+                )))
+            }
+            config::JsonSchemaFallbackPolicy::Downgrade => {
+                extra.insert(
+                    "response_format".to_string(),
+                    serde_json::json!({"type": "json_object"}),
+                );
+                tracing::debug!(
+                    model = model_name,
+                    "Downgraded json_schema response_format to json_object"
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Reject `tool_choice` values vLLM would otherwise answer with a masked
+    /// 400: a mode other than `auto` / `none` / `required`, or a forced
+    /// function that is not declared in `tools`. Object shapes that did not
+    /// parse as a [`inference_providers::ToolChoice`] are left for upstream.
+    fn validate_tool_choice(
+        tools: Option<&[inference_providers::ToolDefinition]>,
+        tool_choice: Option<&inference_providers::ToolChoice>,
+        extra: &std::collections::HashMap<String, serde_json::Value>,
+    ) -> Result<(), ports::CompletionError> {
+        match tool_choice {
+            None => Ok(()),
+            Some(inference_providers::ToolChoice::String(mode)) => {
+                if ["auto", "none", "required"].contains(&mode.as_str()) {
+                    Ok(())
+                } else {
+                    Err(ports::CompletionError::InvalidParams(
+                        "tool_choice must be \"auto\", \"none\", \"required\" or a function"
+                            .to_string(),
+                    ))
+                }
+            }
+            Some(inference_providers::ToolChoice::Function { type_, function }) => {
+                if type_ != "function" {
+                    return Err(ports::CompletionError::InvalidParams(
+                        "tool_choice type must be \"function\"".to_string(),
+                    ));
+                }
+                // Tools that did not parse into typed definitions stay in
+                // `extra`; look up their function names there.
+                let declared = match tools {
+                    Some(tools) => tools.iter().any(|tool| tool.function.name == function.name),
+                    None => extra
+                        .get("tools")
+                        .and_then(serde_json::Value::as_array)
+                        .is_some_and(|tools| {
+                            tools.iter().any(|tool| {
+                                tool.pointer("/function/name")
+                                    .and_then(serde_json::Value::as_str)
+                                    == Some(function.name.as_str())
+                            })
+                        }),
+                };
+                if declared {
+                    Ok(())
+                } else {
+                    Err(ports::CompletionError::InvalidParams(
+                        "tool_choice forces a function that is not declared in tools".to_string(),
+                    ))
+                }
+            }
+        }
+    }
+
     fn has_forced_function_tool_choice(
         tool_choice: &Option<inference_providers::ToolChoice>,
     ) -> bool {
@@ -852,6 +1436,87 @@ impl CompletionServiceImpl {
             .await
     }
 
+    /// Reject models outside the organization's allowlist (cached). An
+    /// absent or empty allowlist allows every model. Lookup failures are not
+    /// cached and reject the request rather than bypass the allowlist.
+    async fn reject_if_model_not_allowed(
+        &self,
+        organization_id: Uuid,
+        canonical_name: &str,
+    ) -> Result<(), ports::CompletionError> {
+        let repo = self.organization_model_access_repository.clone();
+        let allowed = self
+            .org_allowed_models
+            .try_get_with(organization_id, async move {
+                repo.get_allowed_models(organization_id)
+                    .await
+                    .map(|models| models.filter(|m| !m.is_empty()).map(Arc::new))
+            })
+            .await
+            .map_err(|e| {
+                tracing::error!(
+                    organization_id = %organization_id,
+                    error = %e,
+                    "Failed to fetch org model allowlist"
+                );
+                ports::CompletionError::InternalError("Failed to check model access".to_string())
+            })?;
+
+        match allowed {
+            Some(models) if !models.iter().any(|m| m == canonical_name) => {
+                Err(ports::CompletionError::ModelNotAllowed(format!(
+                    "Model '{canonical_name}' is not allowed for this organization"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reject the request once the organization has used up a per-model
+    /// token quota for `canonical_name` in the current period.
+    async fn reject_if_model_quota_exceeded(
+        &self,
+        organization_id: Uuid,
+        canonical_name: &str,
+    ) -> Result<(), ports::CompletionError> {
+        let result = self
+            .usage_service
+            .check_model_quota(organization_id, canonical_name)
+            .await
+            .map_err(|e| {
+                tracing::error!(
+                    organization_id = %organization_id,
+                    error = %e,
+                    "Failed to check model quota"
+                );
+                ports::CompletionError::InternalError("Failed to check model quota".to_string())
+            })?;
+
+        match result {
+            ModelQuotaCheckResult::Allowed => Ok(()),
+            ModelQuotaCheckResult::Exceeded {
+                period,
+                used_tokens,
+                token_limit,
+                reset_at,
+            } => {
+                tracing::warn!(
+                    organization_id = %organization_id,
+                    model = %canonical_name,
+                    %period,
+                    used_tokens,
+                    token_limit,
+                    "Model quota exceeded"
+                );
+                Err(ports::CompletionError::QuotaExceeded(format!(
+                    "Token quota for model '{canonical_name}' exceeded for this {period}. \
+                     Used: {used_tokens}, Limit: {token_limit}. Resets at {}.",
+                    reset_at.to_rfc3339()
+                )))
+            }
+        }
+    }
+
     /// Create low-cardinality metric tags for a request
     ///
     /// Reject E2EE requests for models that don't support attestation (external providers).
@@ -897,6 +1562,152 @@ impl CompletionServiceImpl {
         Ok(())
     }
 
+    /// Apply the model's `max_tokens` policy: fill in the default when the
+    /// client sent no output budget, and clamp (or reject, per model) requests
+    /// above the cap. Chat requests carry `max_completion_tokens` through
+    /// `extra`, so that key is checked and clamped alongside the typed fields.
+    fn apply_max_tokens_limits(
+        limits: crate::models::MaxTokensLimits,
+        model_name: &str,
+        params: &mut inference_providers::ChatCompletionParams,
+    ) -> Result<(), ports::CompletionError> {
+        const EXTRA_KEY: &str = "max_completion_tokens";
+        let cap = limits.max_max_tokens.map(i64::from);
+        let extra_budget = params
+            .extra
+            .get(EXTRA_KEY)
+            .and_then(serde_json::Value::as_i64);
+        let requested = [
+            params.max_tokens,
+            params.max_completion_tokens,
+            extra_budget,
+        ]
+        .into_iter()
+        .flatten()
+        .max();
+        let Some(requested) = requested else {
+            if let Some(default) = limits.default_max_tokens.map(i64::from) {
+                params.max_tokens = Some(cap.map_or(default, |cap| default.min(cap)));
+            }
+            return Ok(());
+        };
+        let Some(cap) = cap.filter(|cap| requested > *cap) else {
+            return Ok(());
+        };
+        if limits.reject_over_max {
+            return Err(ports::CompletionError::InvalidParams(format!(
+                "max_tokens {requested} exceeds the maximum of {cap} for model '{model_name}'"
+            )));
+        }
+        for field in [&mut params.max_tokens, &mut params.max_completion_tokens] {
+            if let Some(value) = field.as_mut() {
+                *value = (*value).min(cap);
+            }
+        }
+        if extra_budget.is_some_and(|budget| budget > cap) {
+            params
+                .extra
+                .insert(EXTRA_KEY.to_string(), serde_json::json!(cap));
+        }
+        Ok(())
+    }
+
+    /// Reject requests whose prompt plus output budget cannot fit in the
+    /// model's context window, instead of paying for a provider round trip
+    /// that fails with a backend-specific error. The byte heuristic only
+    /// screens out requests that clearly fit; anything closer is decided on an
+    /// exact provider token count, and if that is unavailable the guard is
+    /// skipped rather than blocking the request. Encrypted (E2EE) payloads are
+    /// skipped too: their ciphertext says nothing about the plaintext length.
+    async fn reject_if_exceeds_context_window(
+        &self,
+        context_length: i32,
+        params: &inference_providers::ChatCompletionParams,
+    ) -> Result<(), ports::CompletionError> {
+        if context_length <= 0
+            || params
+                .extra
+                .contains_key(crate::common::encryption_headers::MODEL_PUB_KEY)
+        {
+            return Ok(());
+        }
+        let max_tokens = params
+            .max_completion_tokens
+            .or(params.max_tokens)
+            .unwrap_or(0)
+            .max(0) as u64;
+
+        let estimate = crate::inference_provider_pool::context_routing::estimate_input(params);
+        let heuristic_tokens = (estimate.countable_tokens as f64
+            * crate::inference_provider_pool::context_routing::safety_factor())
+        .ceil() as u64
+            + estimate.uncounted_tokens;
+        if heuristic_tokens + max_tokens <= context_length as u64 {
+            return Ok(());
+        }
+
+        let Some(prompt_tokens) = self
+            .inference_provider_pool
+            .count_prompt_tokens(&params.model, params)
+            .await
+        else {
+            tracing::debug!(
+                model = %params.model,
+                "Token count unavailable; skipping context-window guard"
+            );
+            return Ok(());
+        };
+        Self::check_context_window(prompt_tokens, max_tokens, context_length, &params.model)
+    }
+
+    fn check_context_window(
+        prompt_tokens: u64,
+        max_tokens: u64,
+        context_length: i32,
+        model_name: &str,
+    ) -> Result<(), ports::CompletionError> {
+        let required = prompt_tokens.saturating_add(max_tokens);
+        if required > context_length as u64 {
+            return Err(ports::CompletionError::InvalidParams(format!(
+                "Model '{model_name}' has a context window of {context_length} tokens, \
+                 but the request needs {required} ({prompt_tokens} prompt tokens + \
+                 {max_tokens} max_tokens). Shorten the prompt or lower max_tokens."
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reject more than [`inference_providers::MAX_STOP_SEQUENCES`] stop
+    /// sequences. OpenAI caps `stop` at 4; vLLM accepts more, so without this
+    /// check the same request behaves differently depending on the backend.
+    fn validate_stop_sequences(stop: Option<&[String]>) -> Result<(), ports::CompletionError> {
+        if let Some(stop) = stop {
+            if stop.len() > inference_providers::MAX_STOP_SEQUENCES {
+                return Err(ports::CompletionError::InvalidParams(format!(
+                    "stop may contain at most {} sequences, got {}",
+                    inference_providers::MAX_STOP_SEQUENCES,
+                    stop.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Record the HTTP-level connect and first-byte split of a provider call.
+    /// Connect is only present when a new connection was opened.
+    fn record_provider_timing(
+        metrics_service: &dyn MetricsServiceTrait,
+        timing: inference_providers::timing::ProviderTiming,
+        tags: &[&str],
+    ) {
+        if let Some(connect) = timing.connect {
+            metrics_service.record_latency(METRIC_LATENCY_PROVIDER_CONNECT, connect, tags);
+        }
+        if let Some(ttfb) = timing.ttfb {
+            metrics_service.record_latency(METRIC_LATENCY_PROVIDER_TTFB, ttfb, tags);
+        }
+    }
+
     /// These tags are used for OTLP/Datadog metrics and should only include
     /// low-cardinality values to minimize costs (~98% savings vs high-cardinality).
     /// High-cardinality data (org/workspace/key) is tracked via database analytics.
@@ -1104,9 +1915,12 @@ impl CompletionServiceImpl {
                     message: "The encryption key is no longer valid. Please refresh your attestation report and retry.".to_string(),
                 }
             }
+            inference_providers::CompletionError::ModelWarming(msg) => {
+                ports::CompletionError::ModelWarming(msg.clone())
+            }
             inference_providers::CompletionError::CompletionError(msg) => {
                 if msg.contains("not found in any configured provider") {
-                    ports::CompletionError::InvalidModel(msg.clone())
+                    ports::CompletionError::ModelNotFound(msg.clone())
                 } else {
                     tracing::error!(
                         %organization_id,
@@ -1169,18 +1983,36 @@ impl CompletionServiceImpl {
                             .to_string(),
                 }
             }
+            inference_providers::CompletionError::AllProvidersFailed {
+                last_error,
+                failures,
+            } => ports::CompletionError::ProvidersFailed {
+                error: Box::new(Self::map_provider_error(
+                    model,
+                    last_error,
+                    operation,
+                    organization_id,
+                )),
+                provider_failures: failures.clone(),
+            },
         }
     }
 
     /// Record an error metric with the appropriate error type tag
     fn record_error(&self, error: &ports::CompletionError, model_name: Option<&str>) {
-        let error_type = match error {
+        let error_type = match error.root() {
             ports::CompletionError::InvalidModel(_) => ERROR_TYPE_INVALID_MODEL,
+            ports::CompletionError::ModelNotAllowed(_) => ERROR_TYPE_MODEL_NOT_ALLOWED,
             ports::CompletionError::InvalidParams(_) => ERROR_TYPE_INVALID_PARAMS,
             ports::CompletionError::RateLimitExceeded(_) => ERROR_TYPE_RATE_LIMIT,
+            ports::CompletionError::QuotaExceeded(_) => ERROR_TYPE_QUOTA_EXCEEDED,
             ports::CompletionError::ProviderError { .. } => ERROR_TYPE_INFERENCE_ERROR,
             ports::CompletionError::ServiceOverloaded(_) => ERROR_TYPE_SERVICE_OVERLOADED,
+            ports::CompletionError::DuplicateRequest(_) => ERROR_TYPE_DUPLICATE_REQUEST,
+            ports::CompletionError::ModelWarming(_) => ERROR_TYPE_MODEL_WARMING,
+            ports::CompletionError::ModelNotFound(_) => ERROR_TYPE_MODEL_NOT_FOUND,
             ports::CompletionError::InternalError(_) => ERROR_TYPE_INTERNAL_ERROR,
+            ports::CompletionError::ProvidersFailed { .. } => ERROR_TYPE_INFERENCE_ERROR,
         };
 
         let environment = get_environment();
@@ -1199,11 +2031,63 @@ impl CompletionServiceImpl {
             .record_count(METRIC_REQUEST_ERRORS, 1, &tags_str);
     }
 
-    /// Convert completion messages to chat messages for inference providers
-    fn prepare_chat_messages(messages: &[ports::CompletionMessage]) -> Vec<ChatMessage> {
+    /// Map a client-supplied role onto the provider role, or `None` if unknown.
+    fn message_role(role: &str) -> Option<MessageRole> {
+        match role {
+            "system" | "developer" => Some(MessageRole::System),
+            "user" => Some(MessageRole::User),
+            "assistant" => Some(MessageRole::Assistant),
+            "tool" => Some(MessageRole::Tool),
+            _ => None,
+        }
+    }
+
+    /// Null, `""` and `[]` carry nothing for the provider to act on.
+    fn is_empty_content(content: &serde_json::Value) -> bool {
+        match content {
+            serde_json::Value::Null => true,
+            serde_json::Value::String(text) => text.is_empty(),
+            serde_json::Value::Array(parts) => parts.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Convert completion messages to chat messages for inference providers,
+    /// rejecting conversations the provider could not make sense of: no
+    /// messages, an unknown role, or a message with empty content that neither
+    /// makes tool calls nor answers one.
+    fn prepare_chat_messages(
+        messages: &[ports::CompletionMessage],
+    ) -> Result<Vec<ChatMessage>, ports::CompletionError> {
+        if messages.is_empty() {
+            return Err(ports::CompletionError::InvalidParams(
+                "messages must contain at least one message".to_string(),
+            ));
+        }
         messages
             .iter()
-            .map(|msg| {
+            .enumerate()
+            .map(|(idx, msg)| {
+                let role = Self::message_role(&msg.role).ok_or_else(|| {
+                    ports::CompletionError::InvalidParams(format!(
+                        "messages[{idx}] has an unsupported role; expected one of \
+                         system, developer, user, assistant, tool"
+                    ))
+                })?;
+                let has_tool_calls = msg
+                    .tool_calls
+                    .as_ref()
+                    .is_some_and(|calls| !calls.is_empty());
+                if Self::is_empty_content(&msg.content)
+                    && !has_tool_calls
+                    && msg.tool_call_id.is_none()
+                {
+                    return Err(ports::CompletionError::InvalidParams(format!(
+                        "messages[{idx}] ({}) has empty content",
+                        msg.role
+                    )));
+                }
+
                 // Convert tool_calls from CompletionToolCall to inference_providers::models::ToolCall
                 let tool_calls = msg.tool_calls.as_ref().map(|calls| {
                     calls
@@ -1221,18 +2105,13 @@ impl CompletionServiceImpl {
                         .collect()
                 });
 
-                ChatMessage {
-                    role: match msg.role.as_str() {
-                        "system" | "developer" => MessageRole::System,
-                        "assistant" => MessageRole::Assistant,
-                        "tool" => MessageRole::Tool,
-                        _ => MessageRole::User,
-                    },
+                Ok(ChatMessage {
+                    role,
                     content: Some(msg.content.clone()),
                     name: None,
                     tool_call_id: msg.tool_call_id.clone(),
                     tool_calls,
-                }
+                })
             })
             .collect()
     }
@@ -1242,9 +2121,21 @@ impl CompletionServiceImpl {
         organization_id: Uuid,
         model_id: Uuid,
         model_name: &str,
+        priority: ports::RequestPriority,
     ) -> Result<Arc<AtomicU32>, ports::CompletionError> {
+        // Every endpoint admits its request here, so the allowlist and the
+        // per-model quotas cover chat, embeddings, audio, rerank and score
+        // alike.
+        if let Err(err) = self.ensure_model_allowed(organization_id, model_name).await {
+            self.record_error(&err, Some(model_name));
+            return Err(err);
+        }
+
         // Get the dynamic limit for this organization (cached with 5-min TTL)
         let limit = self.get_org_concurrent_limit(organization_id).await;
+        // Low priority stops short of the limit, leaving headroom for the
+        // rest of the organization's traffic on the same counter.
+        let admission_limit = priority.admission_limit(limit);
 
         let counter = self
             .concurrent_counts
@@ -1255,19 +2146,27 @@ impl CompletionServiceImpl {
 
         loop {
             let current = counter.load(Ordering::Acquire);
-            if current >= limit {
+            if current >= admission_limit {
                 tracing::warn!(
                     organization_id = %organization_id,
                     model_id = %model_id,
                     model_name = %model_name,
                     current_count = current,
                     limit = limit,
+                    admission_limit = admission_limit,
+                    priority = ?priority,
                     "Organization concurrent request limit exceeded for model"
                 );
-                let msg = format!(
+                let mut msg = format!(
                     "Concurrent request limit exceeded for model {}. Organization limit: {} concurrent requests per model.",
                     model_name, limit
                 );
+                if admission_limit < limit {
+                    msg.push_str(&format!(
+                        " {} requests may use up to {admission_limit} of them.",
+                        priority.label()
+                    ));
+                }
                 self.record_error(
                     &ports::CompletionError::RateLimitExceeded(msg.clone()),
                     Some(model_name),
@@ -1303,6 +2202,8 @@ impl CompletionServiceImpl {
         store_provider_chat_signature: bool,
         provider_attribution: crate::usage::ProviderAttribution,
         latency_reporter: Option<super::inference_provider_pool::ProviderLatencyReporter>,
+        audit: Option<crate::audit::StreamAuditor>,
+        estimated_prompt_tokens: Option<i32>,
     ) -> StreamingResult {
         // Create low-cardinality metric tags (no org/workspace/key - those go to database)
         let metric_tags = Self::create_metric_tags(&model_name);
@@ -1337,6 +2238,7 @@ impl CompletionServiceImpl {
             total_itl_ms: 0.0,
             metric_tags,
             concurrent_counter,
+            inference_id_namespace: self.inference_id_namespace,
             last_usage_stats: None,
             last_chat_id: None,
             stream_completed: false,
@@ -1348,6 +2250,10 @@ impl CompletionServiceImpl {
             store_provider_chat_signature,
             provider_attribution,
             latency_reporter,
+            audit,
+            streamed_deltas: 0,
+            received_done: false,
+            estimated_prompt_tokens,
         };
         Box::pin(intercepted_stream)
     }
@@ -1355,6 +2261,11 @@ impl CompletionServiceImpl {
 
 #[async_trait::async_trait]
 impl ports::CompletionServiceTrait for CompletionServiceImpl {
+    #[tracing::instrument(
+        name = "chat_completion",
+        skip_all,
+        fields(model = %request.model, stream = true)
+    )]
     async fn create_chat_completion_stream(
         &self,
         request: ports::CompletionRequest,
@@ -1375,12 +2286,67 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
         };
         let is_streaming = request.stream.unwrap_or(false);
 
-        let chat_messages = Self::prepare_chat_messages(&request.messages);
+        // Held until the first upstream event (or until the start fails).
+        let stream_start = if is_streaming {
+            match self.claim_stream_start(api_key_id, &request.body_hash) {
+                Ok(guard) => guard,
+                Err(err) => {
+                    self.record_error(&err, None);
+                    return Err(err);
+                }
+            }
+        } else {
+            None
+        };
+
+        if let Err(err) = Self::validate_stop_sequences(request.stop.as_deref()) {
+            self.record_error(&err, None);
+            return Err(err);
+        }
+
+        let chat_messages = match Self::prepare_chat_messages(&request.messages) {
+            Ok(chat_messages) => chat_messages,
+            Err(err) => {
+                self.record_error(&err, None);
+                return Err(err);
+            }
+        };
 
         // Extract tools from extra if present (Responses API puts them there)
         let mut extra = request.extra.clone();
         let (tools, tool_choice) = Self::extract_tools_from_extra(&mut extra);
+        if let Err(err) = Self::validate_tool_choice(tools.as_deref(), tool_choice.as_ref(), &extra)
+        {
+            self.record_error(&err, None);
+            return Err(err);
+        }
         let stream_options = Self::extract_stream_options_from_extra(&mut extra);
+        let seed = Self::extract_seed_from_extra(&mut extra);
+        let penalties = match Self::extract_sampling_penalties_from_extra(&mut extra) {
+            Ok(penalties) => penalties,
+            Err(err) => {
+                self.record_error(&err, None);
+                return Err(err);
+            }
+        };
+        if let Err(err) = Self::validate_metadata(request.metadata.as_ref(), &extra) {
+            self.record_error(&err, None);
+            return Err(err);
+        }
+        let end_user = match Self::extract_end_user_from_extra(&mut extra) {
+            Ok(end_user) => end_user,
+            Err(err) => {
+                self.record_error(&err, None);
+                return Err(err);
+            }
+        };
+        if let Err(err) = self
+            .check_end_user_rate_limit(api_key_id, end_user.as_deref())
+            .await
+        {
+            self.record_error(&err, None);
+            return Err(err);
+        }
 
         // Inject tracing correlation IDs into extra so the inference provider
         // forwards them as X-Request-Id / X-Org-Id / X-Workspace-Id headers.
@@ -1397,13 +2363,17 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
             tools,
             max_completion_tokens: None,
             n: request.n,
-            frequency_penalty: None,
-            presence_penalty: None,
-            logit_bias: None,
+            frequency_penalty: penalties.frequency_penalty,
+            presence_penalty: penalties.presence_penalty,
+            logit_bias: penalties.logit_bias,
             logprobs: None,
             top_logprobs: None,
-            user: Some(request.user_id.to_string()),
-            seed: None,
+            user: Some(
+                end_user
+                    .clone()
+                    .unwrap_or_else(|| request.user_id.to_string()),
+            ),
+            seed,
             tool_choice,
             parallel_tool_calls: None,
             // Drop metadata if store is not explicitly enabled (OpenAI requirement)
@@ -1418,13 +2388,27 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
             extra,
         };
 
-        // Resolve model name (could be an alias) and get model details in a single DB call
+        // Resolve model name (could be an org or global alias) and get model details in a single DB call
         // This also validates that the model exists and is active
-        let model = match self
+        let resolve_span = tracing::info_span!(
+            "resolve_model",
+            model = %request.model,
+            outcome = tracing::field::Empty
+        );
+        let resolved = self
             .models_repository
-            .resolve_and_get_model(&request.model)
-            .await
-        {
+            .resolve_and_get_model_for_organization(request.organization_id, &request.model)
+            .instrument(resolve_span.clone())
+            .await;
+        resolve_span.record(
+            "outcome",
+            match &resolved {
+                Ok(Some(_)) => "ok",
+                Ok(None) => "not_found",
+                Err(_) => "error",
+            },
+        );
+        let model = match resolved {
             Ok(Some(m)) => m,
             Ok(None) => {
                 let err = ports::CompletionError::InvalidModel(format!(
@@ -1455,10 +2439,31 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
             );
             chat_params.model = canonical_name.clone();
         }
+        if let Err(err) = Self::apply_json_schema_fallback(
+            self.json_schema_fallback,
+            &model.supported_features,
+            canonical_name,
+            &mut chat_params.extra,
+        ) {
+            self.record_error(&err, Some(canonical_name));
+            return Err(err);
+        }
         Self::apply_deepseek_v4_flash_thinking_compat(canonical_name, &mut chat_params);
 
+        if let Err(err) =
+            Self::apply_max_tokens_limits(model.max_tokens_limits, canonical_name, &mut chat_params)
+        {
+            self.record_error(&err, Some(canonical_name));
+            return Err(err);
+        }
+
         let counter = self
-            .try_acquire_concurrent_slot(organization_id, model.id, canonical_name)
+            .try_acquire_concurrent_slot(
+                organization_id,
+                model.id,
+                canonical_name,
+                request.priority,
+            )
             .await?;
 
         // RAII guard protects against panics during stream creation.
@@ -1473,25 +2478,42 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
 
         Self::reject_n_gt_1_if_unsupported(model.attestation_supported, request.n, canonical_name)?;
 
+        self.reject_if_exceeds_context_window(model.context_length, &chat_params)
+            .await?;
+
         let provider_start_time = Instant::now();
 
         // Compute routing hints from the request messages for adaptive load balancing.
         let routing_hints = super::inference_provider_pool::ChatRoutingHints {
             prefix_hash: Some(compute_prefix_hash(&chat_params.messages)),
             estimated_tokens: Some(estimate_input_tokens(&chat_params.messages)),
+            ..Default::default()
         };
 
+        prompt_capture::record(&chat_params);
+        let audit_request_body = self.audit_request_body(&chat_params);
+        let estimated_prompt_tokens = estimate_prompt_tokens(&chat_params);
+
         // Get the LLM stream
-        let attributed_stream = match self
-            .inference_provider_pool
-            .chat_completion_stream_with_attribution(
-                chat_params,
-                request.body_hash.clone(),
-                routing_hints,
-            )
-            .await
-        {
-            Ok(pair) => pair,
+        let provider_span = Self::provider_request_span(canonical_name);
+        let (result, provider_timing) = inference_providers::timing::capture(
+            self.inference_provider_pool
+                .chat_completion_stream_with_attribution(
+                    chat_params,
+                    request.body_hash.clone(),
+                    routing_hints,
+                ),
+        )
+        .instrument(provider_span.clone())
+        .await;
+        provider_span.record("outcome", if result.is_ok() { "ok" } else { "error" });
+        let attributed_stream = match result {
+            Ok(pair) => {
+                let tags = Self::create_metric_tags(&model.model_name);
+                let tags: Vec<&str> = tags.iter().map(|s| s.as_str()).collect();
+                Self::record_provider_timing(self.metrics_service.as_ref(), provider_timing, &tags);
+                pair
+            }
             Err(e) => {
                 // Guard will decrement counter on drop
                 let err = Self::map_provider_error(
@@ -1504,7 +2526,17 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
                 return Err(err);
             }
         };
-        let llm_stream = attributed_stream.stream;
+        let llm_stream = match stream_start {
+            // The start counts as established once the first upstream event arrives.
+            Some(stream_start) => {
+                let mut stream_start = Some(stream_start);
+                Box::pin(futures_util::StreamExt::inspect(
+                    attributed_stream.stream,
+                    move |_| drop(stream_start.take()),
+                )) as StreamingResult
+            }
+            None => attributed_stream.stream,
+        };
         let provider_attribution = attributed_stream.provider_attribution;
         let latency_reporter = attributed_stream.latency_reporter;
 
@@ -1537,12 +2569,35 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
                 !request.skip_provider_chat_signature,
                 provider_attribution,
                 Some(latency_reporter),
+                self.audit.clone().map(|audit| {
+                    crate::audit::StreamAuditor::new(
+                        audit,
+                        crate::audit::AuditEntry {
+                            request_id,
+                            organization_id,
+                            workspace_id,
+                            api_key_id,
+                            model: model.model_name.clone(),
+                            request_hash: request.body_hash.clone(),
+                            response_hash: String::new(),
+                            request_body: audit_request_body,
+                            response_body: None,
+                            created_at: chrono::Utc::now(),
+                        },
+                    )
+                }),
+                Some(estimated_prompt_tokens),
             )
             .await;
 
         Ok(event_stream)
     }
 
+    #[tracing::instrument(
+        name = "chat_completion",
+        skip_all,
+        fields(model = %request.model, stream = false)
+    )]
     async fn create_chat_completion(
         &self,
         request: ports::CompletionRequest,
@@ -1551,12 +2606,48 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
         let organization_id = request.organization_id;
         let workspace_id = request.workspace_id;
         let request_id = request.request_id;
-        let chat_messages = Self::prepare_chat_messages(&request.messages);
+
+        if let Err(err) = Self::validate_stop_sequences(request.stop.as_deref()) {
+            self.record_error(&err, None);
+            return Err(err);
+        }
+
+        let chat_messages = match Self::prepare_chat_messages(&request.messages) {
+            Ok(chat_messages) => chat_messages,
+            Err(err) => {
+                self.record_error(&err, None);
+                return Err(err);
+            }
+        };
 
         // Extract tools from extra if present (Responses API puts them there)
         let mut extra = request.extra.clone();
         let (tools, tool_choice) = Self::extract_tools_from_extra(&mut extra);
+        if let Err(err) = Self::validate_tool_choice(tools.as_deref(), tool_choice.as_ref(), &extra)
+        {
+            self.record_error(&err, None);
+            return Err(err);
+        }
         let stream_options = Self::extract_stream_options_from_extra(&mut extra);
+        let seed = Self::extract_seed_from_extra(&mut extra);
+        let penalties = match Self::extract_sampling_penalties_from_extra(&mut extra) {
+            Ok(penalties) => penalties,
+            Err(err) => {
+                self.record_error(&err, None);
+                return Err(err);
+            }
+        };
+        if let Err(err) = Self::validate_metadata(request.metadata.as_ref(), &extra) {
+            self.record_error(&err, None);
+            return Err(err);
+        }
+        let end_user = match Self::extract_end_user_from_extra(&mut extra) {
+            Ok(end_user) => end_user,
+            Err(err) => {
+                self.record_error(&err, None);
+                return Err(err);
+            }
+        };
 
         // Inject tracing correlation IDs into extra so the inference provider
         // forwards them as X-Request-Id / X-Org-Id / X-Workspace-Id headers.
@@ -1571,15 +2662,19 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
             stop: request.stop,
             stream: Some(false),
             tools,
-            max_completion_tokens: None,
-            n: request.n,
-            frequency_penalty: None,
-            presence_penalty: None,
-            logit_bias: None,
+            max_completion_tokens: None,
+            n: request.n,
+            frequency_penalty: penalties.frequency_penalty,
+            presence_penalty: penalties.presence_penalty,
+            logit_bias: penalties.logit_bias,
             logprobs: None,
             top_logprobs: None,
-            user: Some(request.user_id.to_string()),
-            seed: None,
+            user: Some(
+                end_user
+                    .clone()
+                    .unwrap_or_else(|| request.user_id.to_string()),
+            ),
+            seed,
             tool_choice,
             parallel_tool_calls: None,
             // Drop metadata if store is not explicitly enabled (OpenAI requirement)
@@ -1594,13 +2689,27 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
             extra,
         };
 
-        // Resolve model name (could be an alias) and get model details in a single DB call
+        // Resolve model name (could be an org or global alias) and get model details in a single DB call
         // This also validates that the model exists and is active
-        let model = match self
+        let resolve_span = tracing::info_span!(
+            "resolve_model",
+            model = %request.model,
+            outcome = tracing::field::Empty
+        );
+        let resolved = self
             .models_repository
-            .resolve_and_get_model(&request.model)
-            .await
-        {
+            .resolve_and_get_model_for_organization(request.organization_id, &request.model)
+            .instrument(resolve_span.clone())
+            .await;
+        resolve_span.record(
+            "outcome",
+            match &resolved {
+                Ok(Some(_)) => "ok",
+                Ok(None) => "not_found",
+                Err(_) => "error",
+            },
+        );
+        let model = match resolved {
             Ok(Some(m)) => m,
             Ok(None) => {
                 let err = ports::CompletionError::InvalidModel(format!(
@@ -1630,6 +2739,13 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
                 return Err(err);
             }
         };
+        if let Err(err) = self
+            .check_end_user_rate_limit(api_key_id, end_user.as_deref())
+            .await
+        {
+            self.record_error(&err, Some(canonical_name));
+            return Err(err);
+        }
 
         // Update params with canonical name if it's different
         if canonical_name != &request.model {
@@ -1640,11 +2756,32 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
             );
             chat_params.model = canonical_name.clone();
         }
+        if let Err(err) = Self::apply_json_schema_fallback(
+            self.json_schema_fallback,
+            &model.supported_features,
+            canonical_name,
+            &mut chat_params.extra,
+        ) {
+            self.record_error(&err, Some(canonical_name));
+            return Err(err);
+        }
         Self::apply_deepseek_v4_flash_thinking_compat(canonical_name, &mut chat_params);
 
         let organization_id = request.organization_id;
+        if let Err(err) =
+            Self::apply_max_tokens_limits(model.max_tokens_limits, canonical_name, &mut chat_params)
+        {
+            self.record_error(&err, Some(canonical_name));
+            return Err(err);
+        }
+
         let counter = self
-            .try_acquire_concurrent_slot(organization_id, model.id, canonical_name)
+            .try_acquire_concurrent_slot(
+                organization_id,
+                model.id,
+                canonical_name,
+                request.priority,
+            )
             .await?;
 
         // RAII guard ensures slot is released on drop (panic, error, or success)
@@ -1658,11 +2795,32 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
 
         Self::reject_n_gt_1_if_unsupported(model.attestation_supported, request.n, canonical_name)?;
 
+        self.reject_if_exceeds_context_window(model.context_length, &chat_params)
+            .await?;
+
+        prompt_capture::record(&chat_params);
+        let audit_request_body = self.audit_request_body(&chat_params);
+
         let provider_start_time = Instant::now();
-        let result = self
-            .inference_provider_pool
-            .chat_completion_with_attribution(chat_params, request.body_hash.clone())
+        let provider_span = Self::provider_request_span(canonical_name);
+        let ((result, coalesced), provider_timing) =
+            inference_providers::timing::capture(self.chat_completion_single_flight(
+                organization_id,
+                workspace_id,
+                chat_params,
+                request.body_hash.clone(),
+            ))
+            .instrument(provider_span.clone())
             .await;
+        provider_span.record("outcome", if result.is_ok() { "ok" } else { "error" });
+        if coalesced {
+            tracing::debug!(
+                %request_id,
+                %organization_id,
+                model = %canonical_name,
+                "Coalesced chat completion with an identical in-flight request"
+            );
+        }
 
         let attributed_response = match result {
             Ok(response) => response,
@@ -1677,15 +2835,16 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
                 return Err(err);
             }
         };
-        let response_with_bytes = attributed_response.response;
+        let mut response_with_bytes = attributed_response.response;
         let provider_attribution = attributed_response.provider_attribution;
 
         let e2e_latency = service_start_time.elapsed();
         let backend_latency = provider_start_time.elapsed();
         let queue_time = provider_start_time.duration_since(service_start_time);
 
-        // Store attestation signature (only for models that support TEE attestation)
-        if model.attestation_supported {
+        // Store attestation signature (only for models that support TEE attestation).
+        // A coalesced request shares the chat_id whose signature the leader stores.
+        if model.attestation_supported && !coalesced {
             let attestation_service = self.attestation_service.clone();
             let chat_id = response_with_bytes.response.id.clone();
             let model_name = model.model_name.clone();
@@ -1731,6 +2890,16 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
             metrics_service.record_latency(METRIC_LATENCY_TTFT, backend_latency, &tags_str);
             metrics_service.record_latency(METRIC_LATENCY_TTFT_TOTAL, e2e_latency, &tags_str);
             metrics_service.record_latency(METRIC_LATENCY_TOTAL, e2e_latency, &tags_str);
+            metrics_service.record_latency(
+                METRIC_LATENCY_PROVIDER_TOTAL,
+                backend_latency,
+                &tags_str,
+            );
+            CompletionServiceImpl::record_provider_timing(
+                metrics_service.as_ref(),
+                provider_timing,
+                &tags_str,
+            );
 
             if backend_latency.as_secs_f64() > 0.0 {
                 let tps = output_tokens as f64 / backend_latency.as_secs_f64();
@@ -1754,9 +2923,17 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
         let input_tokens = response_with_bytes.response.usage.prompt_tokens;
         let output_tokens = response_with_bytes.response.usage.completion_tokens;
         let cache_read_tokens = response_with_bytes.response.usage.cached_tokens();
-        // Hash the full chat ID to UUID for storage
+        // Hash the full chat ID to UUID for storage. A coalesced request shares the
+        // chat ID, so it gets its own inference_id to be billed alongside the leader
+        // rather than deduplicated by usage idempotency.
         let provider_request_id = response_with_bytes.response.id.clone();
-        let inference_id = hash_inference_id_to_uuid(&provider_request_id);
+        let inference_id = if coalesced {
+            self.inference_id_namespace
+                .hash(&format!("{provider_request_id}:{request_id}"))
+        } else {
+            self.inference_id_namespace.hash(&provider_request_id)
+        };
+        response_with_bytes.inference_id = Some(inference_id);
         let response_id = request.response_id;
 
         // Extract finish_reason from provider response
@@ -1786,6 +2963,7 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
                 response_id,
                 image_count: None,
                 provider_attribution,
+                estimated: false,
             })
             .await
             .map_err(|e| {
@@ -1803,6 +2981,23 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
             api_key_id
         );
 
+        if let Some(audit) = &self.audit {
+            audit.record(crate::audit::AuditEntry {
+                request_id,
+                organization_id,
+                workspace_id,
+                api_key_id,
+                model: model.model_name.clone(),
+                request_hash: request.body_hash.clone(),
+                response_hash: crate::audit::sha256_hex(&response_with_bytes.raw_bytes),
+                request_body: audit_request_body,
+                response_body: audit
+                    .stores_bodies()
+                    .then(|| String::from_utf8_lossy(&response_with_bytes.raw_bytes).into_owned()),
+                created_at: chrono::Utc::now(),
+            });
+        }
+
         Ok(response_with_bytes)
     }
 
@@ -1816,7 +3011,12 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
     ) -> Result<inference_providers::AudioTranscriptionResponse, ports::CompletionError> {
         // Acquire concurrent request slot to enforce organization limits
         let counter = self
-            .try_acquire_concurrent_slot(organization_id, model_id, model_name)
+            .try_acquire_concurrent_slot(
+                organization_id,
+                model_id,
+                model_name,
+                ports::RequestPriority::Normal,
+            )
             .await?;
 
         // RAII guard ensures slot is released on drop (panic, error, or success)
@@ -1880,7 +3080,12 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
     ) -> Result<inference_providers::RerankResponse, ports::CompletionError> {
         // Acquire concurrent request slot to enforce organization limits
         let counter = self
-            .try_acquire_concurrent_slot(organization_id, model_id, model_name)
+            .try_acquire_concurrent_slot(
+                organization_id,
+                model_id,
+                model_name,
+                ports::RequestPriority::Normal,
+            )
             .await?;
 
         // Create RAII guard to ensure slot is released on drop (panic, error, or success)
@@ -1932,7 +3137,12 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
         extra: std::collections::HashMap<String, serde_json::Value>,
     ) -> Result<bytes::Bytes, ports::CompletionError> {
         let counter = self
-            .try_acquire_concurrent_slot(organization_id, model_id, model_name)
+            .try_acquire_concurrent_slot(
+                organization_id,
+                model_id,
+                model_name,
+                ports::RequestPriority::Normal,
+            )
             .await?;
         let _guard = ConcurrentSlotGuard::new(counter);
 
@@ -1980,7 +3190,12 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
         extra: std::collections::HashMap<String, serde_json::Value>,
     ) -> Result<bytes::Bytes, ports::CompletionError> {
         let counter = self
-            .try_acquire_concurrent_slot(organization_id, model_id, model_name)
+            .try_acquire_concurrent_slot(
+                organization_id,
+                model_id,
+                model_name,
+                ports::RequestPriority::Normal,
+            )
             .await?;
         let _guard = ConcurrentSlotGuard::new(counter);
 
@@ -2029,7 +3244,12 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
     ) -> Result<inference_providers::ScoreResponse, ports::CompletionError> {
         // Acquire concurrent request slot to enforce organization limits
         let counter = self
-            .try_acquire_concurrent_slot(organization_id, model_id, model_name)
+            .try_acquire_concurrent_slot(
+                organization_id,
+                model_id,
+                model_name,
+                ports::RequestPriority::Normal,
+            )
             .await?;
 
         // Create RAII guard to ensure slot is released on drop (panic, error, or success)
@@ -2082,6 +3302,17 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
         self.models_repository.get_model_by_name(model_name).await
     }
 
+    async fn ensure_model_allowed(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+    ) -> Result<(), ports::CompletionError> {
+        self.reject_if_model_not_allowed(organization_id, model_name)
+            .await?;
+        self.reject_if_model_quota_exceeded(organization_id, model_name)
+            .await
+    }
+
     fn get_inference_provider_pool(
         &self,
     ) -> std::sync::Arc<crate::inference_provider_pool::InferenceProviderPool> {
@@ -2091,12 +3322,20 @@ impl ports::CompletionServiceTrait for CompletionServiceImpl {
     async fn invalidate_org_concurrent_limit(&self, org_id: Uuid) {
         self.org_concurrent_limits.invalidate(&org_id).await;
     }
+
+    async fn invalidate_org_allowed_models(&self, org_id: Uuid) {
+        self.org_allowed_models.invalidate(&org_id).await;
+    }
 }
 
 pub use ports::*;
 
+#[cfg(test)]
+mod priority_admission_tests;
 #[cfg(test)]
 mod provider_attribution_tests;
+#[cfg(test)]
+mod provider_timing_tests;
 
 #[cfg(test)]
 mod tests {
@@ -2108,6 +3347,130 @@ mod tests {
     use inference_providers::models::{ChatChoice, ChatCompletionChunk, FinishReason, TokenUsage};
     use std::time::Duration;
 
+    #[test]
+    fn test_prepare_chat_messages_preserves_content_parts() {
+        let parts = serde_json::json!([
+            {"type": "text", "text": "Describe this"},
+            {"type": "image_url", "image_url": {"url": "data:image/png;base64,iVBORw0KGgo="}}
+        ]);
+        let messages = vec![
+            ports::CompletionMessage {
+                role: "user".to_string(),
+                content: parts.clone(),
+                tool_call_id: None,
+                tool_calls: None,
+            },
+            ports::CompletionMessage {
+                role: "assistant".to_string(),
+                content: serde_json::Value::String("A small image".to_string()),
+                tool_call_id: None,
+                tool_calls: None,
+            },
+        ];
+
+        let chat_messages = CompletionServiceImpl::prepare_chat_messages(&messages).unwrap();
+
+        assert_eq!(chat_messages[0].content, Some(parts));
+        assert_eq!(
+            chat_messages[1].content,
+            Some(serde_json::Value::String("A small image".to_string()))
+        );
+    }
+
+    fn message(role: &str, content: serde_json::Value) -> ports::CompletionMessage {
+        ports::CompletionMessage {
+            role: role.to_string(),
+            content,
+            tool_call_id: None,
+            tool_calls: None,
+        }
+    }
+
+    fn expect_invalid_params(
+        result: Result<Vec<ChatMessage>, ports::CompletionError>,
+        needle: &str,
+    ) {
+        match result {
+            Err(ports::CompletionError::InvalidParams(msg)) => {
+                assert!(msg.contains(needle), "got: {msg}");
+            }
+            other => panic!("Expected InvalidParams, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_prepare_chat_messages_rejects_empty_messages() {
+        expect_invalid_params(
+            CompletionServiceImpl::prepare_chat_messages(&[]),
+            "at least one message",
+        );
+    }
+
+    #[test]
+    fn test_prepare_chat_messages_rejects_unknown_role() {
+        let messages = vec![
+            message("system", serde_json::json!("Be brief")),
+            message("narrator", serde_json::json!("Hello")),
+        ];
+        expect_invalid_params(
+            CompletionServiceImpl::prepare_chat_messages(&messages),
+            "messages[1] has an unsupported role",
+        );
+    }
+
+    #[test]
+    fn test_prepare_chat_messages_rejects_empty_content_without_tool_calls() {
+        for content in [
+            serde_json::Value::Null,
+            serde_json::json!(""),
+            serde_json::json!([]),
+        ] {
+            let messages = vec![message("user", content)];
+            expect_invalid_params(
+                CompletionServiceImpl::prepare_chat_messages(&messages),
+                "messages[0] (user) has empty content",
+            );
+        }
+    }
+
+    #[test]
+    fn test_prepare_chat_messages_accepts_valid_conversation() {
+        let messages = vec![
+            message("developer", serde_json::json!("Use tools when helpful")),
+            message("user", serde_json::json!("Weather in Paris?")),
+            ports::CompletionMessage {
+                tool_calls: Some(vec![ports::CompletionToolCall {
+                    id: "call_1".to_string(),
+                    name: "get_weather".to_string(),
+                    arguments: r#"{"city":"Paris"}"#.to_string(),
+                    thought_signature: None,
+                }]),
+                ..message("assistant", serde_json::Value::Null)
+            },
+            ports::CompletionMessage {
+                tool_call_id: Some("call_1".to_string()),
+                ..message("tool", serde_json::json!(""))
+            },
+            message("assistant", serde_json::json!("It is sunny.")),
+        ];
+
+        let chat_messages = CompletionServiceImpl::prepare_chat_messages(&messages).unwrap();
+
+        let roles: Vec<_> = chat_messages.iter().map(|m| m.role.clone()).collect();
+        assert_eq!(
+            roles,
+            vec![
+                MessageRole::System,
+                MessageRole::User,
+                MessageRole::Assistant,
+                MessageRole::Tool,
+                MessageRole::Assistant,
+            ]
+        );
+        assert_eq!(chat_messages[2].tool_calls.as_ref().map(Vec::len), Some(1));
+        assert_eq!(chat_messages[3].tool_call_id.as_deref(), Some("call_1"));
+    }
+
     #[tokio::test]
     async fn test_intercept_stream_metrics() {
         let metrics_service = Arc::new(CapturingMetricsService::new());
@@ -2157,6 +3520,7 @@ mod tests {
                     completion_tokens: 20,
                     total_tokens: 30,
                     prompt_tokens_details: None,
+                    completion_tokens_details: None,
                 }),
                 prompt_token_ids: None,
                 system_fingerprint: None,
@@ -2192,6 +3556,7 @@ mod tests {
             total_itl_ms: 0.0,
             metric_tags,
             concurrent_counter: None,
+            inference_id_namespace: InferenceIdNamespace::default(),
             last_usage_stats: None,
             last_chat_id: None,
             stream_completed: false,
@@ -2203,6 +3568,10 @@ mod tests {
             store_provider_chat_signature: true,
             provider_attribution: crate::usage::ProviderAttribution::default(),
             latency_reporter: None,
+            audit: None,
+            streamed_deltas: 0,
+            received_done: false,
+            estimated_prompt_tokens: None,
         };
 
         // Consume the stream
@@ -2292,6 +3661,7 @@ mod tests {
                     completion_tokens: 20,
                     total_tokens: 30,
                     prompt_tokens_details: Some(serde_json::json!({"cached_tokens": 7})),
+                    completion_tokens_details: None,
                 }),
                 prompt_token_ids: None,
                 system_fingerprint: None,
@@ -2322,6 +3692,7 @@ mod tests {
             total_itl_ms: 0.0,
             metric_tags: CompletionServiceImpl::create_metric_tags("test-model"),
             concurrent_counter: None,
+            inference_id_namespace: InferenceIdNamespace::default(),
             last_usage_stats: None,
             last_chat_id: None,
             stream_completed: false,
@@ -2333,6 +3704,10 @@ mod tests {
             store_provider_chat_signature: true,
             provider_attribution: crate::usage::ProviderAttribution::default(),
             latency_reporter: None,
+            audit: None,
+            streamed_deltas: 0,
+            received_done: false,
+            estimated_prompt_tokens: None,
         };
         let _ = intercept_stream.collect::<Vec<_>>().await;
         // Wait for the fire-and-forget usage/metrics task spawned in Drop to finish.
@@ -2423,6 +3798,7 @@ mod tests {
                     completion_tokens: 20,
                     total_tokens: 30,
                     prompt_tokens_details: None,
+                    completion_tokens_details: None,
                 }),
                 prompt_token_ids: None,
                 modality: None,
@@ -2462,6 +3838,7 @@ mod tests {
             total_itl_ms: 0.0,
             metric_tags,
             concurrent_counter: None,
+            inference_id_namespace: InferenceIdNamespace::default(),
             last_usage_stats: None,
             last_chat_id: None,
             stream_completed: false,
@@ -2473,6 +3850,10 @@ mod tests {
             store_provider_chat_signature: true,
             provider_attribution: crate::usage::ProviderAttribution::default(),
             latency_reporter: None,
+            audit: None,
+            streamed_deltas: 0,
+            received_done: false,
+            estimated_prompt_tokens: None,
         };
 
         // Consume the stream
@@ -2551,6 +3932,7 @@ mod tests {
                     completion_tokens: 1,
                     total_tokens: 6,
                     prompt_tokens_details: None,
+                    completion_tokens_details: None,
                 }),
                 prompt_token_ids: None,
                 modality: None,
@@ -2585,6 +3967,7 @@ mod tests {
             total_itl_ms: 0.0,
             metric_tags,
             concurrent_counter: None,
+            inference_id_namespace: InferenceIdNamespace::default(),
             last_usage_stats: None,
             last_chat_id: None,
             stream_completed: false,
@@ -2596,6 +3979,10 @@ mod tests {
             store_provider_chat_signature: true,
             provider_attribution: crate::usage::ProviderAttribution::default(),
             latency_reporter: None,
+            audit: None,
+            streamed_deltas: 0,
+            received_done: false,
+            estimated_prompt_tokens: None,
         };
 
         let _ = intercept_stream.collect::<Vec<_>>().await;
@@ -2792,6 +4179,7 @@ mod tests {
                 total_itl_ms: 0.0,
                 metric_tags: vec![],
                 concurrent_counter: Some(counter.clone()),
+                inference_id_namespace: InferenceIdNamespace::default(),
                 last_usage_stats: None,
                 last_chat_id: None,
                 stream_completed: false,
@@ -2803,6 +4191,10 @@ mod tests {
                 store_provider_chat_signature: true,
                 provider_attribution: crate::usage::ProviderAttribution::default(),
                 latency_reporter: None,
+                audit: None,
+                streamed_deltas: 0,
+                received_done: false,
+                estimated_prompt_tokens: None,
             };
             // InterceptStream goes out of scope here and Drop is called
         }
@@ -2964,17 +4356,31 @@ mod tests {
         let result =
             CompletionServiceImpl::map_provider_error("test-model", &error, "test", Uuid::nil());
         match result {
-            ports::CompletionError::InvalidModel(msg) => {
+            ports::CompletionError::ModelNotFound(msg) => {
                 assert!(
                     msg.contains("not found"),
                     "Message should be preserved, got: {}",
                     msg
                 );
             }
-            other => panic!("Expected InvalidModel, got {:?}", other),
+            other => panic!("Expected ModelNotFound, got {:?}", other),
         }
     }
 
+    #[test]
+    fn test_map_provider_error_model_warming() {
+        let error = inference_providers::CompletionError::ModelWarming(
+            "Model 'test-model' is not ready yet".to_string(),
+        );
+        let result =
+            CompletionServiceImpl::map_provider_error("test-model", &error, "test", Uuid::nil());
+        assert!(
+            matches!(result, ports::CompletionError::ModelWarming(_)),
+            "Expected ModelWarming, got {:?}",
+            result
+        );
+    }
+
     #[test]
     fn test_map_provider_error_connection_error_becomes_502() {
         let error = inference_providers::CompletionError::CompletionError(
@@ -3528,6 +4934,74 @@ mod tests {
         assert!(extra.contains_key("tool_choice"));
     }
 
+    #[test]
+    fn validate_tool_choice_accepts_modes_and_declared_functions() {
+        let mut extra = std::collections::HashMap::new();
+        extra.insert(
+            "tools".to_string(),
+            serde_json::json!([{
+                "type": "function",
+                "function": {"name": "get_weather", "parameters": {}}
+            }]),
+        );
+        extra.insert(
+            "tool_choice".to_string(),
+            serde_json::json!({"type": "function", "function": {"name": "get_weather"}}),
+        );
+        let (tools, tool_choice) = CompletionServiceImpl::extract_tools_from_extra(&mut extra);
+        assert!(CompletionServiceImpl::validate_tool_choice(
+            tools.as_deref(),
+            tool_choice.as_ref(),
+            &extra
+        )
+        .is_ok());
+
+        for mode in ["auto", "none", "required"] {
+            let tool_choice = inference_providers::ToolChoice::String(mode.to_string());
+            assert!(CompletionServiceImpl::validate_tool_choice(
+                tools.as_deref(),
+                Some(&tool_choice),
+                &extra
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn validate_tool_choice_rejects_unknown_mode_and_undeclared_function() {
+        let tools = vec![inference_providers::ToolDefinition {
+            type_: "function".to_string(),
+            function: inference_providers::FunctionDefinition {
+                name: "get_weather".to_string(),
+                description: None,
+                parameters: serde_json::json!({}),
+            },
+        }];
+        let extra = std::collections::HashMap::new();
+
+        let forced = |name: &str| inference_providers::ToolChoice::Function {
+            type_: "function".to_string(),
+            function: inference_providers::FunctionChoice {
+                name: name.to_string(),
+            },
+        };
+        for (tools, tool_choice) in [
+            (Some(tools.as_slice()), forced("get_time")),
+            (None, forced("get_weather")),
+            (
+                Some(tools.as_slice()),
+                inference_providers::ToolChoice::String("any".to_string()),
+            ),
+        ] {
+            match CompletionServiceImpl::validate_tool_choice(tools, Some(&tool_choice), &extra) {
+                Err(ports::CompletionError::InvalidParams(message)) => {
+                    assert!(message.contains("tool_choice"), "{message}")
+                }
+                other => panic!("Expected InvalidParams, got {other:?}"),
+            }
+        }
+    }
+
     #[test]
     fn extract_stream_options_consumes_typed_options() {
         let mut extra = std::collections::HashMap::new();
@@ -3572,6 +5046,181 @@ mod tests {
         );
     }
 
+    // ── validate_stop_sequences ───────────────────────────────────────────
+
+    #[test]
+    fn validate_stop_sequences_allows_up_to_four() {
+        assert!(CompletionServiceImpl::validate_stop_sequences(None).is_ok());
+        let four: Vec<String> = ["a", "b", "c", "d"].map(String::from).to_vec();
+        assert!(CompletionServiceImpl::validate_stop_sequences(Some(&four)).is_ok());
+    }
+
+    #[test]
+    fn validate_stop_sequences_rejects_over_limit() {
+        let five: Vec<String> = ["a", "b", "c", "d", "e"].map(String::from).to_vec();
+        match CompletionServiceImpl::validate_stop_sequences(Some(&five)) {
+            Err(ports::CompletionError::InvalidParams(msg)) => {
+                assert!(msg.contains("at most 4"), "got: {msg}");
+            }
+            other => panic!("Expected InvalidParams, got {:?}", other),
+        }
+    }
+
+    // ── apply_json_schema_fallback ────────────────────────────────────────
+
+    fn json_schema_extra() -> std::collections::HashMap<String, serde_json::Value> {
+        std::collections::HashMap::from([(
+            "response_format".to_string(),
+            serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {"name": "person", "schema": {"type": "object"}}
+            }),
+        )])
+    }
+
+    #[test]
+    fn json_schema_fallback_rejects_on_model_without_structured_outputs() {
+        let mut extra = json_schema_extra();
+        match CompletionServiceImpl::apply_json_schema_fallback(
+            config::JsonSchemaFallbackPolicy::Reject,
+            &["json_mode".to_string()],
+            "test/plain-model",
+            &mut extra,
+        ) {
+            Err(ports::CompletionError::InvalidParams(msg)) => {
+                assert!(msg.contains("does not support"), "got: {msg}");
+                assert!(msg.contains("json_object"), "got: {msg}");
+            }
+            other => panic!("Expected InvalidParams, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn json_schema_fallback_downgrades_on_model_without_structured_outputs() {
+        let mut extra = json_schema_extra();
+        CompletionServiceImpl::apply_json_schema_fallback(
+            config::JsonSchemaFallbackPolicy::Downgrade,
+            &["json_mode".to_string()],
+            "test/plain-model",
+            &mut extra,
+        )
+        .unwrap();
+        assert_eq!(
+            extra.get("response_format"),
+            Some(&serde_json::json!({"type": "json_object"}))
+        );
+    }
+
+    #[test]
+    fn json_schema_fallback_leaves_supported_or_passthrough_untouched() {
+        for (policy, features) in [
+            (
+                config::JsonSchemaFallbackPolicy::Reject,
+                vec!["structured_outputs".to_string()],
+            ),
+            (
+                config::JsonSchemaFallbackPolicy::Downgrade,
+                vec!["structured_outputs".to_string()],
+            ),
+            (config::JsonSchemaFallbackPolicy::Passthrough, vec![]),
+        ] {
+            let mut extra = json_schema_extra();
+            CompletionServiceImpl::apply_json_schema_fallback(
+                policy,
+                &features,
+                "test/model",
+                &mut extra,
+            )
+            .unwrap();
+            assert_eq!(extra, json_schema_extra(), "policy {policy:?}");
+        }
+    }
+
+    // ── extract_sampling_penalties_from_extra ─────────────────────────────
+
+    #[test]
+    fn sampling_penalties_move_into_typed_fields() {
+        let mut extra = std::collections::HashMap::from([
+            ("frequency_penalty".to_string(), serde_json::json!(-2.0)),
+            ("presence_penalty".to_string(), serde_json::json!(0.5)),
+            (
+                "logit_bias".to_string(),
+                serde_json::json!({"50256": -100, "11": 100}),
+            ),
+            ("top_k".to_string(), serde_json::json!(5)),
+        ]);
+        let penalties =
+            CompletionServiceImpl::extract_sampling_penalties_from_extra(&mut extra).unwrap();
+        assert_eq!(penalties.frequency_penalty, Some(-2.0));
+        assert_eq!(penalties.presence_penalty, Some(0.5));
+        assert_eq!(penalties.logit_bias.map(|bias| bias.len()), Some(2));
+        assert_eq!(extra.len(), 1, "only unrelated keys stay in extra");
+    }
+
+    #[test]
+    fn sampling_penalties_null_is_absent() {
+        let mut extra = std::collections::HashMap::from([
+            ("presence_penalty".to_string(), serde_json::Value::Null),
+            ("logit_bias".to_string(), serde_json::Value::Null),
+        ]);
+        let penalties =
+            CompletionServiceImpl::extract_sampling_penalties_from_extra(&mut extra).unwrap();
+        assert!(penalties.presence_penalty.is_none());
+        assert!(penalties.logit_bias.is_none());
+        assert!(extra.is_empty());
+    }
+
+    #[test]
+    fn sampling_penalties_reject_out_of_range_values() {
+        let cases = [
+            (
+                "presence_penalty",
+                serde_json::json!(2.01),
+                "presence_penalty",
+            ),
+            (
+                "frequency_penalty",
+                serde_json::json!("1"),
+                "frequency_penalty",
+            ),
+            ("logit_bias", serde_json::json!({"1": 100.5}), "between"),
+            ("logit_bias", serde_json::json!({"abc": 1}), "token IDs"),
+            ("logit_bias", serde_json::json!({"1": "1"}), "between"),
+            ("logit_bias", serde_json::json!(5), "object"),
+        ];
+        for (key, value, expected) in cases {
+            let mut extra = std::collections::HashMap::from([(key.to_string(), value.clone())]);
+            match CompletionServiceImpl::extract_sampling_penalties_from_extra(&mut extra) {
+                Err(ports::CompletionError::InvalidParams(msg)) => {
+                    assert!(msg.contains(expected), "{key}={value}: got {msg}");
+                }
+                Err(other) => panic!("{key}={value}: expected InvalidParams, got {other:?}"),
+                Ok(_) => panic!("{key}={value}: expected InvalidParams, got Ok"),
+            }
+        }
+    }
+
+    // ── check_context_window ──────────────────────────────────────────────
+
+    #[test]
+    fn context_window_over_limit_is_rejected_with_limits() {
+        match CompletionServiceImpl::check_context_window(3_000, 2_000, 4_096, "test/model") {
+            Err(ports::CompletionError::InvalidParams(msg)) => {
+                assert!(msg.contains("test/model"), "got: {msg}");
+                assert!(msg.contains("4096"), "got: {msg}");
+                assert!(msg.contains("3000 prompt tokens"), "got: {msg}");
+                assert!(msg.contains("2000 max_tokens"), "got: {msg}");
+            }
+            other => panic!("Expected InvalidParams, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn context_window_at_or_under_limit_is_allowed() {
+        assert!(CompletionServiceImpl::check_context_window(2_096, 2_000, 4_096, "m").is_ok());
+        assert!(CompletionServiceImpl::check_context_window(10, 0, 4_096, "m").is_ok());
+    }
+
     // ── reject_n_gt_1_if_unsupported ──────────────────────────────────────
 
     #[test]
@@ -3636,4 +5285,100 @@ mod tests {
             "n=5 on self-hosted model must be allowed, self-hosted supports n>1"
         );
     }
+
+    // ── apply_max_tokens_limits ───────────────────────────────────────────
+
+    const LIMITS: crate::models::MaxTokensLimits = crate::models::MaxTokensLimits {
+        default_max_tokens: Some(512),
+        max_max_tokens: Some(2_048),
+        reject_over_max: false,
+    };
+
+    fn params_with_budget(
+        max_tokens: Option<i64>,
+        max_completion_tokens: Option<i64>,
+    ) -> inference_providers::ChatCompletionParams {
+        let mut params = chat_params_for_compat_tests("m");
+        params.max_tokens = max_tokens;
+        params.max_completion_tokens = max_completion_tokens;
+        params
+    }
+
+    #[test]
+    fn max_tokens_default_applied_when_omitted() {
+        let mut params = params_with_budget(None, None);
+        CompletionServiceImpl::apply_max_tokens_limits(LIMITS, "m", &mut params).unwrap();
+        assert_eq!(params.max_tokens, Some(512));
+        assert_eq!(params.max_completion_tokens, None);
+    }
+
+    #[test]
+    fn max_tokens_within_limit_unchanged() {
+        let mut params = params_with_budget(Some(1_000), None);
+        CompletionServiceImpl::apply_max_tokens_limits(LIMITS, "m", &mut params).unwrap();
+        assert_eq!(params.max_tokens, Some(1_000));
+
+        let mut params = params_with_budget(None, Some(2_048));
+        CompletionServiceImpl::apply_max_tokens_limits(LIMITS, "m", &mut params).unwrap();
+        assert_eq!(params.max_completion_tokens, Some(2_048));
+        assert_eq!(params.max_tokens, None);
+    }
+
+    #[test]
+    fn max_tokens_over_limit_clamped() {
+        let mut params = params_with_budget(Some(100_000), Some(50_000));
+        CompletionServiceImpl::apply_max_tokens_limits(LIMITS, "m", &mut params).unwrap();
+        assert_eq!(params.max_tokens, Some(2_048));
+        assert_eq!(params.max_completion_tokens, Some(2_048));
+    }
+
+    #[test]
+    fn max_tokens_over_limit_in_extra_clamped() {
+        let mut params = params_with_budget(None, None);
+        params.extra.insert(
+            "max_completion_tokens".to_string(),
+            serde_json::json!(9_999),
+        );
+        CompletionServiceImpl::apply_max_tokens_limits(LIMITS, "m", &mut params).unwrap();
+        assert_eq!(params.extra["max_completion_tokens"], 2_048);
+        assert_eq!(params.max_tokens, None, "default must not be added");
+    }
+
+    #[test]
+    fn max_tokens_over_limit_rejected_when_configured() {
+        let limits = crate::models::MaxTokensLimits {
+            reject_over_max: true,
+            ..LIMITS
+        };
+        let mut params = params_with_budget(None, Some(4_096));
+        match CompletionServiceImpl::apply_max_tokens_limits(limits, "m", &mut params) {
+            Err(ports::CompletionError::InvalidParams(msg)) => {
+                assert!(msg.contains("4096") && msg.contains("2048"), "{msg}");
+            }
+            other => panic!("Expected InvalidParams, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn max_tokens_default_never_exceeds_cap() {
+        let limits = crate::models::MaxTokensLimits {
+            default_max_tokens: Some(8_192),
+            ..LIMITS
+        };
+        let mut params = params_with_budget(None, None);
+        CompletionServiceImpl::apply_max_tokens_limits(limits, "m", &mut params).unwrap();
+        assert_eq!(params.max_tokens, Some(2_048));
+    }
+
+    #[test]
+    fn max_tokens_unset_limits_leave_request_alone() {
+        let mut params = params_with_budget(None, None);
+        CompletionServiceImpl::apply_max_tokens_limits(
+            crate::models::MaxTokensLimits::default(),
+            "m",
+            &mut params,
+        )
+        .unwrap();
+        assert_eq!(params.max_tokens, None);
+    }
 }
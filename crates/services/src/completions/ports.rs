@@ -30,9 +30,18 @@ pub enum CompletionError {
     #[error("Invalid model: {0}")]
     InvalidModel(String),
 
+    /// The model exists but is not on the organization's allowlist
+    #[error("Model not allowed: {0}")]
+    ModelNotAllowed(String),
+
     #[error("Rate limit exceeded: {0}")]
     RateLimitExceeded(String),
 
+    /// The organization used up its token quota for this model in the
+    /// current period; other models are unaffected
+    #[error("Quota exceeded: {0}")]
+    QuotaExceeded(String),
+
     #[error("Invalid parameters: {0}")]
     InvalidParams(String),
 
@@ -42,8 +51,107 @@ pub enum CompletionError {
     #[error("Service overloaded: {0}")]
     ServiceOverloaded(String),
 
+    /// An identical streaming request from the same API key is still being
+    /// established (see `CompletionServiceImpl::with_stream_dedup_window`)
+    #[error("Duplicate request: {0}")]
+    DuplicateRequest(String),
+
+    /// The model is not loaded yet because provider discovery has not
+    /// succeeded since startup; distinct from an unknown model
+    #[error("Model warming: {0}")]
+    ModelWarming(String),
+
+    /// No provider serves the model although discovery has initialized
+    #[error("Model not found: {0}")]
+    ModelNotFound(String),
+
     #[error("Internal error: {0}")]
     InternalError(String),
+
+    /// Every provider attempt failed. `error` decides the response; the
+    /// per-attempt summary is surfaced to clients as structured details.
+    #[error("{error}")]
+    ProvidersFailed {
+        error: Box<CompletionError>,
+        provider_failures: Vec<inference_providers::ProviderFailure>,
+    },
+}
+
+impl CompletionError {
+    /// The error that decides the response, unwrapping `ProvidersFailed`.
+    pub fn root(&self) -> &CompletionError {
+        match self {
+            CompletionError::ProvidersFailed { error, .. } => error.root(),
+            other => other,
+        }
+    }
+
+    /// Per-attempt failure summary; empty unless every provider failed.
+    pub fn provider_failures(&self) -> &[inference_providers::ProviderFailure] {
+        match self {
+            CompletionError::ProvidersFailed {
+                provider_failures, ..
+            } => provider_failures,
+            _ => &[],
+        }
+    }
+}
+
+/// Admission priority for a completion request, taken from the client's
+/// optional `priority` hint. Low-priority requests are capped at half of an
+/// organization's per-model concurrent slots, so the other half stays
+/// available to the rest of its traffic under contention. The hint is
+/// client-asserted, so `high` earns nothing beyond the default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestPriority {
+    High,
+    #[default]
+    Normal,
+    Low,
+}
+
+impl RequestPriority {
+    /// Parse a client `priority` hint. Missing or unknown values map to `Normal`.
+    pub fn from_hint(value: Option<&serde_json::Value>) -> Self {
+        match value.and_then(serde_json::Value::as_str) {
+            Some("high") => Self::High,
+            Some("low") => Self::Low,
+            _ => Self::Normal,
+        }
+    }
+
+    /// Take the string `priority` hint out of a request's `extra` params.
+    /// Any other `priority` (vLLM's integer scheduling priority) is left in
+    /// place for the provider and admits the request as `Normal`.
+    pub fn take_hint(extra: &mut std::collections::HashMap<String, serde_json::Value>) -> Self {
+        if !extra
+            .get("priority")
+            .is_some_and(serde_json::Value::is_string)
+        {
+            return Self::Normal;
+        }
+        Self::from_hint(extra.remove("priority").as_ref())
+    }
+
+    /// Capitalized tier name for client-facing messages.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::High => "High-priority",
+            Self::Normal => "Normal-priority",
+            Self::Low => "Low-priority",
+        }
+    }
+
+    /// Number of concurrent slots (out of the organization's per-model `limit`)
+    /// this priority may occupy: the whole limit for high and normal, half
+    /// (at least one slot) for low.
+    pub fn admission_limit(self, limit: u32) -> u32 {
+        match self {
+            Self::High | Self::Normal => limit,
+            Self::Low => (limit / 2).max(1),
+        }
+    }
 }
 
 // Request/Response models
@@ -73,6 +181,9 @@ pub struct CompletionRequest {
     /// Skip provider-side chat signature fetch/storage because the API route
     /// will store a gateway signature over bytes it rewrites before returning.
     pub skip_provider_chat_signature: bool,
+    /// Admission priority for the per-model concurrent limit
+    #[serde(default)]
+    pub priority: RequestPriority,
 
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -129,6 +240,15 @@ pub trait OrganizationConcurrentLimitRepository: Send + Sync {
     async fn get_concurrent_limit(&self, org_id: Uuid) -> Result<Option<u32>, anyhow::Error>;
 }
 
+/// Repository trait for fetching organization model allowlists
+/// Used by CompletionService to restrict which models an org may call
+#[async_trait]
+pub trait OrganizationModelAccessRepository: Send + Sync {
+    /// Get the canonical model names the organization may call
+    /// Returns None if no allowlist is set (all models allowed)
+    async fn get_allowed_models(&self, org_id: Uuid) -> Result<Option<Vec<String>>, anyhow::Error>;
+}
+
 #[async_trait]
 pub trait CompletionServiceTrait: Send + Sync {
     /// Create a streaming completion
@@ -207,6 +327,16 @@ pub trait CompletionServiceTrait: Send + Sync {
         model_name: &str,
     ) -> Result<Option<crate::models::ModelWithPricing>, anyhow::Error>;
 
+    /// Reject `model_name` (canonical) when it is outside the organization's
+    /// allowlist or the organization has used up one of its token quotas for
+    /// it. Every method above that admits a request enforces this itself;
+    /// endpoints that call the provider pool directly must call it.
+    async fn ensure_model_allowed(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+    ) -> Result<(), CompletionError>;
+
     /// Get the inference provider pool for direct access
     fn get_inference_provider_pool(
         &self,
@@ -219,4 +349,8 @@ pub trait CompletionServiceTrait: Send + Sync {
     /// `organizations.concurrent_limit` so admin changes take effect
     /// immediately instead of waiting for the 5-minute TTL.
     async fn invalidate_org_concurrent_limit(&self, org_id: Uuid);
+
+    /// Drop the cached model allowlist for an organization. Called by the
+    /// admin service after the allowlist is updated.
+    async fn invalidate_org_allowed_models(&self, org_id: Uuid);
 }
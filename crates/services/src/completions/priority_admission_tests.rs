@@ -0,0 +1,151 @@
+use super::*;
+use crate::metrics::capturing::CapturingMetricsService;
+use crate::models::ModelWithPricing;
+use crate::test_utils::{MockAttestationService, MockUsageService};
+use ports::RequestPriority;
+
+const ORG_LIMIT: u32 = 4;
+
+struct EmptyModelsRepository;
+
+#[async_trait::async_trait]
+impl ModelsRepository for EmptyModelsRepository {
+    async fn get_all_active_models(&self) -> Result<Vec<ModelWithPricing>, anyhow::Error> {
+        Ok(Vec::new())
+    }
+
+    async fn get_model_by_name(
+        &self,
+        _model_name: &str,
+    ) -> Result<Option<ModelWithPricing>, anyhow::Error> {
+        Ok(None)
+    }
+
+    async fn resolve_and_get_model(
+        &self,
+        _identifier: &str,
+    ) -> Result<Option<ModelWithPricing>, anyhow::Error> {
+        Ok(None)
+    }
+
+    async fn resolve_and_get_model_for_organization(
+        &self,
+        _organization_id: uuid::Uuid,
+        identifier: &str,
+    ) -> Result<Option<ModelWithPricing>, anyhow::Error> {
+        self.resolve_and_get_model(identifier).await
+    }
+
+    async fn get_configured_model_names(&self) -> Result<Vec<String>, anyhow::Error> {
+        Ok(Vec::new())
+    }
+}
+
+struct FixedLimitRepository;
+
+#[async_trait::async_trait]
+impl ports::OrganizationConcurrentLimitRepository for FixedLimitRepository {
+    async fn get_concurrent_limit(&self, _org_id: Uuid) -> Result<Option<u32>, anyhow::Error> {
+        Ok(Some(ORG_LIMIT))
+    }
+}
+
+#[async_trait::async_trait]
+impl ports::OrganizationModelAccessRepository for FixedLimitRepository {
+    async fn get_allowed_models(
+        &self,
+        _org_id: Uuid,
+    ) -> Result<Option<Vec<String>>, anyhow::Error> {
+        Ok(None)
+    }
+}
+
+fn completion_service() -> CompletionServiceImpl {
+    CompletionServiceImpl::new(
+        Arc::new(InferenceProviderPool::new(
+            None,
+            config::ExternalProvidersConfig::default(),
+        )),
+        Arc::new(MockAttestationService),
+        Arc::new(MockUsageService),
+        Arc::new(CapturingMetricsService::new()),
+        Arc::new(EmptyModelsRepository),
+        Arc::new(FixedLimitRepository),
+        Arc::new(FixedLimitRepository),
+    )
+}
+
+#[test]
+fn priority_hint_parsing_defaults_to_normal() {
+    let hint = |value: serde_json::Value| RequestPriority::from_hint(Some(&value));
+    assert_eq!(hint(serde_json::json!("high")), RequestPriority::High);
+    assert_eq!(hint(serde_json::json!("low")), RequestPriority::Low);
+    assert_eq!(hint(serde_json::json!("normal")), RequestPriority::Normal);
+    assert_eq!(hint(serde_json::json!("urgent")), RequestPriority::Normal);
+    assert_eq!(hint(serde_json::json!(5)), RequestPriority::Normal);
+    assert_eq!(RequestPriority::from_hint(None), RequestPriority::Normal);
+}
+
+#[test]
+fn take_hint_only_consumes_string_priority() {
+    let mut extra =
+        std::collections::HashMap::from([("priority".to_string(), serde_json::json!("low"))]);
+    assert_eq!(RequestPriority::take_hint(&mut extra), RequestPriority::Low);
+    assert!(!extra.contains_key("priority"));
+
+    // vLLM's integer scheduling priority is passed through untouched
+    let mut extra =
+        std::collections::HashMap::from([("priority".to_string(), serde_json::json!(-5))]);
+    assert_eq!(
+        RequestPriority::take_hint(&mut extra),
+        RequestPriority::Normal
+    );
+    assert_eq!(extra["priority"], serde_json::json!(-5));
+}
+
+#[test]
+fn only_low_priority_is_capped_below_the_limit() {
+    assert_eq!(RequestPriority::High.admission_limit(64), 64);
+    assert_eq!(RequestPriority::Normal.admission_limit(64), 64);
+    assert_eq!(RequestPriority::Low.admission_limit(64), 32);
+    assert_eq!(RequestPriority::Normal.admission_limit(1), 1);
+    assert_eq!(RequestPriority::Low.admission_limit(1), 1);
+}
+
+#[tokio::test]
+async fn normal_priority_is_admitted_when_low_priority_is_turned_away() {
+    let service = completion_service();
+    let org_id = Uuid::new_v4();
+    let model_id = Uuid::new_v4();
+    let acquire = |priority| service.try_acquire_concurrent_slot(org_id, model_id, "m", priority);
+
+    // Low-priority traffic fills only its share of the limit.
+    let mut guards = Vec::new();
+    for _ in 0..RequestPriority::Low.admission_limit(ORG_LIMIT) {
+        guards.push(ConcurrentSlotGuard::new(
+            acquire(RequestPriority::Low).await.unwrap(),
+        ));
+    }
+    assert!(matches!(
+        acquire(RequestPriority::Low).await,
+        Err(ports::CompletionError::RateLimitExceeded(_))
+    ));
+
+    // The same contended counter still admits default-priority requests up
+    // to the organization limit, and a `high` hint earns nothing beyond it.
+    while guards.len() < ORG_LIMIT as usize {
+        guards.push(ConcurrentSlotGuard::new(
+            acquire(RequestPriority::Normal).await.unwrap(),
+        ));
+    }
+    for priority in [RequestPriority::Normal, RequestPriority::High] {
+        assert!(matches!(
+            acquire(priority).await,
+            Err(ports::CompletionError::RateLimitExceeded(_))
+        ));
+    }
+
+    // Releasing slots below the low-priority share admits low again.
+    guards.truncate(1);
+    assert!(acquire(RequestPriority::Low).await.is_ok());
+}
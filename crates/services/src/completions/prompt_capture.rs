@@ -0,0 +1,83 @@
+//! Opt-in capture of the chat request assembled for the provider, for prompt
+//! debugging.
+//!
+//! By the time a completion reaches the provider the service has resolved
+//! aliases, applied model token limits and merged tool/sampling extras, and
+//! the API layer has filled workspace defaults. A permitted caller can run
+//! its completion inside [`capture_assembled_prompt`] to get that final
+//! request back. Outside that scope nothing is recorded.
+
+use std::{cell::RefCell, future::Future};
+
+tokio::task_local! {
+    static ASSEMBLED_PROMPT: RefCell<Option<serde_json::Value>>;
+}
+
+/// Keys the service adds to the provider request for its own bookkeeping
+/// (correlation headers, billing user). They are not part of the prompt.
+const INTERNAL_KEYS: [&str; 4] = ["user", "x_request_id", "x_org_id", "x_workspace_id"];
+
+/// Run `future`, returning the last provider request the completion service
+/// assembled along the way (`None` if it never got that far).
+pub async fn capture_assembled_prompt<F: Future>(
+    future: F,
+) -> (F::Output, Option<serde_json::Value>) {
+    ASSEMBLED_PROMPT
+        .scope(RefCell::new(None), async move {
+            let output = future.await;
+            let prompt = ASSEMBLED_PROMPT.with(|prompt| prompt.take());
+            (output, prompt)
+        })
+        .await
+}
+
+/// Record the provider request if the current task is capturing.
+pub(super) fn record(params: &inference_providers::ChatCompletionParams) {
+    let _ = ASSEMBLED_PROMPT.try_with(|prompt| {
+        let Ok(mut value) = serde_json::to_value(params) else {
+            return;
+        };
+        if let Some(obj) = value.as_object_mut() {
+            for key in INTERNAL_KEYS {
+                obj.remove(key);
+            }
+        }
+        *prompt.borrow_mut() = Some(value);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> inference_providers::ChatCompletionParams {
+        serde_json::from_value(serde_json::json!({
+            "model": "canonical/model",
+            "messages": [{"role": "user", "content": "hi"}],
+            "temperature": 0.3,
+            "user": "user-id",
+            "x_request_id": "00000000-0000-0000-0000-000000000000"
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_records_only_inside_capture_scope() {
+        record(&params());
+
+        let (output, prompt) = capture_assembled_prompt(async {
+            record(&params());
+            7
+        })
+        .await;
+        assert_eq!(output, 7);
+        let prompt = prompt.expect("recorded inside scope");
+        assert_eq!(prompt["model"], "canonical/model");
+        assert_eq!(prompt["messages"][0]["content"], "hi");
+        assert!(prompt.get("user").is_none());
+        assert!(prompt.get("x_request_id").is_none());
+
+        let (_, prompt) = capture_assembled_prompt(async {}).await;
+        assert_eq!(prompt, None);
+    }
+}
@@ -5,8 +5,8 @@ use crate::test_utils::{CapturingUsageService, MockAttestationService};
 use std::sync::Arc;
 use std::time::Duration;
 
-struct StaticModelsRepository {
-    model: ModelWithPricing,
+pub(super) struct StaticModelsRepository {
+    pub(super) model: ModelWithPricing,
 }
 
 #[async_trait::async_trait]
@@ -31,12 +31,20 @@ impl ModelsRepository for StaticModelsRepository {
         .then(|| self.model.clone()))
     }
 
+    async fn resolve_and_get_model_for_organization(
+        &self,
+        _organization_id: uuid::Uuid,
+        identifier: &str,
+    ) -> Result<Option<ModelWithPricing>, anyhow::Error> {
+        self.resolve_and_get_model(identifier).await
+    }
+
     async fn get_configured_model_names(&self) -> Result<Vec<String>, anyhow::Error> {
         Ok(vec![self.model.model_name.clone()])
     }
 }
 
-struct StaticOrganizationLimitRepository;
+pub(super) struct StaticOrganizationLimitRepository;
 
 #[async_trait::async_trait]
 impl ports::OrganizationConcurrentLimitRepository for StaticOrganizationLimitRepository {
@@ -45,7 +53,17 @@ impl ports::OrganizationConcurrentLimitRepository for StaticOrganizationLimitRep
     }
 }
 
-fn test_model(model_name: &str) -> ModelWithPricing {
+#[async_trait::async_trait]
+impl ports::OrganizationModelAccessRepository for StaticOrganizationLimitRepository {
+    async fn get_allowed_models(
+        &self,
+        _org_id: Uuid,
+    ) -> Result<Option<Vec<String>>, anyhow::Error> {
+        Ok(None)
+    }
+}
+
+pub(super) fn test_model(model_name: &str) -> ModelWithPricing {
     ModelWithPricing {
         id: Uuid::new_v4(),
         model_name: model_name.to_string(),
@@ -75,11 +93,12 @@ fn test_model(model_name: &str) -> ModelWithPricing {
         is_ready: None,
         deprecation_date: None,
         openrouter_slug: None,
+        max_tokens_limits: Default::default(),
         created_at: chrono::Utc::now(),
     }
 }
 
-fn completion_request(model: &str) -> ports::CompletionRequest {
+pub(super) fn completion_request(model: &str) -> ports::CompletionRequest {
     ports::CompletionRequest {
         request_id: Uuid::new_v4(),
         model: model.to_string(),
@@ -104,6 +123,7 @@ fn completion_request(model: &str) -> ports::CompletionRequest {
         body_hash: "test-body-hash".to_string(),
         response_id: None,
         skip_provider_chat_signature: true,
+        priority: ports::RequestPriority::Normal,
         extra: std::collections::HashMap::new(),
     }
 }
@@ -192,6 +212,7 @@ async fn completion_service_with_mock_providers(
             model: test_model(model_name),
         }),
         Arc::new(StaticOrganizationLimitRepository),
+        Arc::new(StaticOrganizationLimitRepository),
     );
     (service, usage_service)
 }
@@ -0,0 +1,120 @@
+use super::provider_attribution_tests::{
+    completion_request, test_model, StaticModelsRepository, StaticOrganizationLimitRepository,
+};
+use super::*;
+use crate::metrics::capturing::CapturingMetricsService;
+use crate::test_utils::{CapturingUsageService, MockAttestationService};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const COMPLETION_BODY: &str = r#"{"id":"chatcmpl-timing","object":"chat.completion","created":1,"model":"timing-model","choices":[{"index":0,"message":{"role":"assistant","content":"hi"},"finish_reason":"stop"}],"usage":{"prompt_tokens":3,"completion_tokens":1,"total_tokens":4}}"#;
+
+/// Minimal HTTP/1.1 backend answering every request with a fixed chat completion.
+async fn spawn_backend() -> (String, tokio::task::JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let handle = tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                return;
+            };
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 4096];
+                loop {
+                    let n = socket.read(&mut buf).await.unwrap_or(0);
+                    if n == 0 {
+                        return;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&request);
+                    if let Some(header_end) = text.find("\r\n\r\n") {
+                        let content_length = text[..header_end]
+                            .lines()
+                            .find_map(|line| {
+                                let (name, value) = line.split_once(':')?;
+                                name.eq_ignore_ascii_case("content-length")
+                                    .then(|| value.trim().parse::<usize>().ok())?
+                            })
+                            .unwrap_or(0);
+                        if request.len() >= header_end + 4 + content_length {
+                            break;
+                        }
+                    }
+                }
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    COMPLETION_BODY.len(),
+                    COMPLETION_BODY
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    (format!("http://{addr}"), handle)
+}
+
+#[tokio::test]
+async fn completed_request_emits_provider_timing_metrics() {
+    let model_name = "timing-model";
+    let (base_url, backend) = spawn_backend().await;
+
+    let pool = Arc::new(InferenceProviderPool::new(
+        None,
+        config::ExternalProvidersConfig::default(),
+    ));
+    let provider = Arc::new(inference_providers::nearai::Provider::new(
+        inference_providers::nearai::Config::new(base_url, None, Some(5)),
+    ));
+    pool.register_provider(model_name.to_string(), provider)
+        .await;
+
+    let metrics_service = Arc::new(CapturingMetricsService::new());
+    let service = CompletionServiceImpl::new(
+        pool,
+        Arc::new(MockAttestationService),
+        Arc::new(CapturingUsageService::new()),
+        metrics_service.clone(),
+        Arc::new(StaticModelsRepository {
+            model: test_model(model_name),
+        }),
+        Arc::new(StaticOrganizationLimitRepository),
+        Arc::new(StaticOrganizationLimitRepository),
+    );
+
+    service
+        .create_chat_completion(completion_request(model_name))
+        .await
+        .expect("backend should serve the request");
+
+    let expected = [
+        METRIC_LATENCY_PROVIDER_CONNECT,
+        METRIC_LATENCY_PROVIDER_TTFB,
+        METRIC_LATENCY_PROVIDER_TOTAL,
+    ];
+    let model_tag = format!("{TAG_MODEL}:{model_name}");
+    let emitted = tokio::time::timeout(Duration::from_secs(2), async {
+        loop {
+            let metrics = metrics_service.get_metrics();
+            if expected.iter().all(|name| {
+                metrics
+                    .iter()
+                    .any(|m| m.name == *name && m.tags.contains(&model_tag))
+            }) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await;
+    backend.abort();
+    assert!(
+        emitted.is_ok(),
+        "expected {expected:?} tagged {model_tag}, got {:?}",
+        metrics_service
+            .get_metrics()
+            .iter()
+            .map(|m| m.name.clone())
+            .collect::<Vec<_>>()
+    );
+}
@@ -52,6 +52,17 @@ pub trait ConversationRepository: Send + Sync {
         api_key_id: uuid::Uuid,
     ) -> Result<Option<conversations::models::Conversation>>;
 
+    /// Fork a conversation into a new one holding its items up to and including
+    /// `up_to_item_id` (all items when `None`), linked via `parent_conversation_id`.
+    /// Fails with `RepositoryError::NotFound` when the item is not in the conversation.
+    async fn fork_conversation(
+        &self,
+        id: conversations::models::ConversationId,
+        workspace_id: WorkspaceId,
+        api_key_id: uuid::Uuid,
+        up_to_item_id: Option<String>,
+    ) -> Result<Option<conversations::models::Conversation>>;
+
     /// Delete a conversation (will cascade delete associated responses)
     async fn delete(
         &self,
@@ -102,6 +113,13 @@ pub trait ConversationServiceTrait: Send + Sync {
         workspace_id: WorkspaceId,
         api_key_id: uuid::Uuid,
     ) -> Result<Option<conversations::models::Conversation>, conversations::errors::ConversationError>;
+    async fn fork_conversation(
+        &self,
+        conversation_id: conversations::models::ConversationId,
+        workspace_id: WorkspaceId,
+        api_key_id: uuid::Uuid,
+        up_to_item_id: Option<String>,
+    ) -> Result<Option<conversations::models::Conversation>, conversations::errors::ConversationError>;
     async fn delete_conversation(
         &self,
         conversation_id: conversations::models::ConversationId,
@@ -127,6 +145,19 @@ pub trait ConversationServiceTrait: Send + Sync {
         Vec<crate::responses::models::ResponseOutputItem>,
         conversations::errors::ConversationError,
     >;
+    /// Search a conversation's message items for `query` (case-insensitive
+    /// substring over message text), paginated like `list_conversation_items`.
+    async fn search_conversation_items(
+        &self,
+        conversation_id: conversations::models::ConversationId,
+        workspace_id: WorkspaceId,
+        query: String,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<
+        Vec<crate::responses::models::ResponseOutputItem>,
+        conversations::errors::ConversationError,
+    >;
     async fn create_conversation_items(
         &self,
         conversation_id: conversations::models::ConversationId,
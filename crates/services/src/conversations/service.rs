@@ -34,6 +34,9 @@ pub fn parse_uuid_from_prefixed(id: &str, prefix: &str) -> Result<Uuid, errors::
     })
 }
 
+/// Maximum length (in characters) of a conversation item search query.
+const MAX_SEARCH_QUERY_LENGTH: usize = 512;
+
 /// Returns true when a repository error indicates the pagination cursor was
 /// rejected (it does not exist, or belongs to another conversation/workspace).
 fn is_invalid_cursor_error(error: &anyhow::Error) -> bool {
@@ -114,6 +117,7 @@ impl ports::ConversationServiceTrait for ConversationServiceImpl {
             archived_at: db_conversation.archived_at,
             deleted_at: db_conversation.deleted_at,
             cloned_from_id: db_conversation.cloned_from_id,
+            parent_conversation_id: db_conversation.parent_conversation_id,
             root_response_id: Some(root_response_id),
             metadata: db_conversation.metadata,
             created_at: db_conversation.created_at,
@@ -145,6 +149,7 @@ impl ports::ConversationServiceTrait for ConversationServiceImpl {
             archived_at: c.archived_at,
             deleted_at: c.deleted_at,
             cloned_from_id: c.cloned_from_id,
+            parent_conversation_id: c.parent_conversation_id,
             root_response_id: None,
             metadata: c.metadata,
             created_at: c.created_at,
@@ -181,6 +186,7 @@ impl ports::ConversationServiceTrait for ConversationServiceImpl {
             archived_at: c.archived_at,
             deleted_at: c.deleted_at,
             cloned_from_id: c.cloned_from_id,
+            parent_conversation_id: c.parent_conversation_id,
             root_response_id: None,
             metadata: c.metadata,
             created_at: c.created_at,
@@ -217,6 +223,7 @@ impl ports::ConversationServiceTrait for ConversationServiceImpl {
             archived_at: c.archived_at,
             deleted_at: c.deleted_at,
             cloned_from_id: c.cloned_from_id,
+            parent_conversation_id: c.parent_conversation_id,
             root_response_id: None,
             metadata: c.metadata,
             created_at: c.created_at,
@@ -253,6 +260,7 @@ impl ports::ConversationServiceTrait for ConversationServiceImpl {
             archived_at: c.archived_at,
             deleted_at: c.deleted_at,
             cloned_from_id: c.cloned_from_id,
+            parent_conversation_id: c.parent_conversation_id,
             root_response_id: None,
             metadata: c.metadata,
             created_at: c.created_at,
@@ -305,6 +313,7 @@ impl ports::ConversationServiceTrait for ConversationServiceImpl {
             archived_at: c.archived_at,
             deleted_at: c.deleted_at,
             cloned_from_id: c.cloned_from_id,
+            parent_conversation_id: c.parent_conversation_id,
             root_response_id: Some(root_response_id),
             metadata: c.metadata,
             created_at: c.created_at,
@@ -314,6 +323,55 @@ impl ports::ConversationServiceTrait for ConversationServiceImpl {
         Ok(Some(conversation))
     }
 
+    /// Fork a conversation at an optional item
+    async fn fork_conversation(
+        &self,
+        conversation_id: models::ConversationId,
+        workspace_id: WorkspaceId,
+        api_key_id: uuid::Uuid,
+        up_to_item_id: Option<String>,
+    ) -> Result<Option<models::Conversation>, errors::ConversationError> {
+        let db_conversation = self
+            .conv_repo
+            .fork_conversation(
+                conversation_id,
+                workspace_id.clone(),
+                api_key_id,
+                up_to_item_id,
+            )
+            .await
+            .map_err(|e| {
+                if is_invalid_cursor_error(&e) {
+                    errors::ConversationError::InvalidParams(
+                        "up_to_item_id does not reference an item in this conversation".to_string(),
+                    )
+                } else {
+                    errors::ConversationError::InternalError(format!(
+                        "Failed to fork conversation: {e}"
+                    ))
+                }
+            })?;
+
+        let Some(mut c) = db_conversation else {
+            return Ok(None);
+        };
+
+        // Same as clone: surface the copied structural root (created if the fork
+        // point preceded it) so the fork can take parallel first-turn responses.
+        let root_response_id = self
+            .resp_repo
+            .get_or_create_root_response(c.id, workspace_id, api_key_id)
+            .await
+            .map_err(|e| {
+                errors::ConversationError::InternalError(format!(
+                    "Failed to get root response for forked conversation: {e}"
+                ))
+            })?;
+        c.root_response_id = Some(root_response_id);
+
+        Ok(Some(c))
+    }
+
     /// Delete a conversation
     async fn delete_conversation(
         &self,
@@ -475,6 +533,66 @@ impl ports::ConversationServiceTrait for ConversationServiceImpl {
             })
     }
 
+    async fn search_conversation_items(
+        &self,
+        conversation_id: models::ConversationId,
+        workspace_id: WorkspaceId,
+        query: String,
+        after: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<crate::responses::models::ResponseOutputItem>, errors::ConversationError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(errors::ConversationError::InvalidParams(
+                "Search query 'q' must not be empty".to_string(),
+            ));
+        }
+        if query.chars().count() > MAX_SEARCH_QUERY_LENGTH {
+            return Err(errors::ConversationError::InvalidParams(format!(
+                "Search query 'q' must be at most {MAX_SEARCH_QUERY_LENGTH} characters"
+            )));
+        }
+
+        tracing::debug!(
+            "Searching conversation items for conversation_id={}, workspace_id={}, after={:?}, limit={}",
+            conversation_id,
+            workspace_id.0,
+            after,
+            limit
+        );
+
+        // Same ownership rule as listing: unknown and foreign conversations are
+        // indistinguishable NotFound.
+        let conversation = self
+            .conv_repo
+            .get_by_id(conversation_id, workspace_id.clone())
+            .await
+            .map_err(|e| {
+                errors::ConversationError::InternalError(format!(
+                    "Failed to verify conversation: {e}"
+                ))
+            })?;
+
+        if conversation.is_none() {
+            return Err(errors::ConversationError::NotFound);
+        }
+
+        self.response_items_repo
+            .search_by_conversation(conversation_id, workspace_id, query, after, limit)
+            .await
+            .map_err(|e| {
+                if is_invalid_cursor_error(&e) {
+                    errors::ConversationError::InvalidParams(
+                        "Invalid 'after' cursor for this conversation".to_string(),
+                    )
+                } else {
+                    errors::ConversationError::InternalError(format!(
+                        "Failed to search conversation items: {e}"
+                    ))
+                }
+            })
+    }
+
     /// Create items in a conversation (for backfilling)
     async fn create_conversation_items(
         &self,
@@ -629,6 +747,7 @@ impl ports::ConversationServiceTrait for ConversationServiceImpl {
                 archived_at: c.archived_at,
                 deleted_at: c.deleted_at,
                 cloned_from_id: c.cloned_from_id,
+                parent_conversation_id: c.parent_conversation_id,
                 root_response_id: None,
                 metadata: c.metadata,
                 created_at: c.created_at,
@@ -702,6 +821,7 @@ mod tests {
             archived_at: None,
             deleted_at: None,
             cloned_from_id: None,
+            parent_conversation_id: None,
             metadata: serde_json::json!({}),
             created_at: now,
             updated_at: now,
@@ -770,6 +890,7 @@ mod tests {
             archived_at: None,
             deleted_at: None,
             cloned_from_id: None,
+            parent_conversation_id: None,
             root_response_id: None,
             metadata: serde_json::json!({}),
             created_at: now,
@@ -843,6 +964,16 @@ mod tests {
             panic!("clone_conversation must not be called");
         }
 
+        async fn fork_conversation(
+            &self,
+            _id: models::ConversationId,
+            _workspace_id: WorkspaceId,
+            _api_key_id: Uuid,
+            _up_to_item_id: Option<String>,
+        ) -> Result<Option<models::Conversation>> {
+            panic!("fork_conversation must not be called");
+        }
+
         async fn delete(
             &self,
             _id: models::ConversationId,
@@ -1019,6 +1150,21 @@ mod tests {
                 .push((conversation_id.0, workspace_id.0, after));
             Ok(vec![])
         }
+
+        async fn search_by_conversation(
+            &self,
+            conversation_id: models::ConversationId,
+            workspace_id: WorkspaceId,
+            _query: &str,
+            after: Option<String>,
+            _limit: i64,
+        ) -> anyhow::Result<Vec<resp_models::ResponseOutputItem>> {
+            self.list_calls
+                .lock()
+                .unwrap()
+                .push((conversation_id.0, workspace_id.0, after));
+            Ok(vec![])
+        }
     }
 
     fn service_with_owned_conversation(
@@ -1104,6 +1250,69 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_search_conversation_items_foreign_workspace_not_found() {
+        let conversation_id = models::ConversationId(Uuid::new_v4());
+        let owner_workspace = WorkspaceId(Uuid::new_v4());
+
+        let (service, items_repo) =
+            service_with_owned_conversation(conversation_id, owner_workspace.clone());
+
+        let result = service
+            .search_conversation_items(
+                conversation_id,
+                WorkspaceId(Uuid::new_v4()),
+                "hello".to_string(),
+                None,
+                10,
+            )
+            .await;
+        assert!(
+            matches!(result, Err(errors::ConversationError::NotFound)),
+            "foreign-workspace search must return NotFound, got: {result:?}"
+        );
+        assert!(items_repo.list_calls.lock().unwrap().is_empty());
+
+        let result = service
+            .search_conversation_items(
+                conversation_id,
+                owner_workspace.clone(),
+                "hello".to_string(),
+                None,
+                10,
+            )
+            .await;
+        assert!(result.is_ok(), "owner search must succeed: {result:?}");
+        let calls = items_repo.list_calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].1, owner_workspace.0);
+    }
+
+    #[tokio::test]
+    async fn test_search_conversation_items_rejects_blank_query() {
+        let conversation_id = models::ConversationId(Uuid::new_v4());
+        let owner_workspace = WorkspaceId(Uuid::new_v4());
+        let (service, items_repo) =
+            service_with_owned_conversation(conversation_id, owner_workspace.clone());
+
+        for query in ["   ".to_string(), "x".repeat(MAX_SEARCH_QUERY_LENGTH + 1)] {
+            let result = service
+                .search_conversation_items(
+                    conversation_id,
+                    owner_workspace.clone(),
+                    query,
+                    None,
+                    10,
+                )
+                .await;
+            assert!(matches!(
+                result,
+                Err(errors::ConversationError::InvalidParams(_))
+            ));
+        }
+        assert!(items_repo.list_calls.lock().unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_create_conversation_items_foreign_workspace_not_found() {
         let conversation_id = models::ConversationId(Uuid::new_v4());
@@ -30,6 +30,9 @@ pub const PREFIX_FC: &str = "fc_";
 /// Prefix for function call output IDs
 pub const PREFIX_FCO: &str = "fco_";
 
+/// Prefix for batch IDs
+pub const PREFIX_BATCH: &str = "batch_";
+
 /// All known ID prefixes (useful for path normalization in metrics)
 pub const ALL_PREFIXES: &[&str] = &[
     PREFIX_CHATCMPL,
@@ -41,4 +44,5 @@ pub const ALL_PREFIXES: &[&str] = &[
     PREFIX_MCPR,
     PREFIX_FC,
     PREFIX_FCO,
+    PREFIX_BATCH,
 ];
@@ -17,7 +17,8 @@
 //! ```
 //!
 //! [`expand_inference_endpoints`] turns one catalog row into the
-//! `(model_name, inference_url, max_context)` entries the pool registers —
+//! `(model_name, inference_url, max_context, max_concurrency,
+//! request_timeout_secs)` entries the pool registers —
 //! the base entry keeps the row's `inference_url` with
 //! `base_max_context_tokens` as its declared capacity (the catalog
 //! `context_length` stays the customer-facing maximum — the long tier's
@@ -28,15 +29,43 @@
 //!
 //! Without a `long_context` block this is the identity expansion — every
 //! other model registers exactly as before.
+//!
+//! Either entry may also declare a `max_concurrency` (top level for the base
+//! endpoint, inside `long_context` for the long one): the most requests each
+//! gateway replica keeps in flight to that endpoint before skipping it for a
+//! sibling.
+//!
+//! The row's `request_timeout_secs` column applies to every entry: both tiers
+//! serve the same model, so they share its completion timeout.
 
 use std::sync::OnceLock;
 
 use inference_providers::ChatCompletionParams;
 
+/// One endpoint the pool registers for a model with a direct inference URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InferenceEndpoint {
+    pub model_name: String,
+    pub inference_url: String,
+    /// Largest prompt (tokens) routed to this endpoint. `None` = unlimited.
+    pub max_context: Option<u32>,
+    /// Most requests kept in flight to this endpoint before it is skipped for
+    /// a sibling. The limit is per gateway replica: each replica counts only
+    /// its own requests, so the backend can see up to this many times the
+    /// replica count. `None` = unlimited.
+    pub max_concurrency: Option<u32>,
+    /// Completion timeout override. `None` keeps the global default.
+    pub request_timeout_secs: Option<u32>,
+}
+
 /// providerConfig key holding the long-context tier declaration. Snake_case
 /// like the other `provider_config` contents (`base_url`, `model_name`).
 const LONG_CONTEXT_KEY: &str = "long_context";
 
+/// providerConfig key (top level, or inside `long_context`) capping in-flight
+/// requests to the endpoint.
+const MAX_CONCURRENCY_KEY: &str = "max_concurrency";
+
 fn env_f64(name: &str, default: f64) -> f64 {
     std::env::var(name)
         .ok()
@@ -138,9 +167,11 @@ pub(crate) fn estimate_input(params: &ChatCompletionParams) -> InputEstimate {
     }
 }
 
-/// Expand one catalog row into the `(model_name, inference_url, max_context)`
-/// endpoint entries to register. Identity expansion unless `provider_config`
-/// carries a valid `long_context` block (see module docs).
+/// Expand one catalog row into the
+/// `(model_name, inference_url, max_context, max_concurrency,
+/// request_timeout_secs)` endpoint entries to register. Identity expansion
+/// unless `provider_config` carries a valid `long_context` block (see module
+/// docs).
 ///
 /// The long entry requires ALL of: a non-empty `inference_url` different from
 /// the base URL, a `base_max_context_tokens`, and a strictly larger long
@@ -156,7 +187,8 @@ pub fn expand_inference_endpoints(
     inference_url: &str,
     context_length: Option<u32>,
     provider_config: Option<&serde_json::Value>,
-) -> Vec<(String, String, Option<u32>)> {
+    request_timeout_secs: Option<u32>,
+) -> Vec<InferenceEndpoint> {
     let long = provider_config.and_then(|cfg| cfg.get(LONG_CONTEXT_KEY));
 
     let get_u32 = |obj: &serde_json::Value, key: &str| -> Option<u32> {
@@ -166,11 +198,13 @@ pub fn expand_inference_endpoints(
             .filter(|v| *v > 0)
     };
 
-    let mut out = vec![(
-        model_name.to_string(),
-        inference_url.to_string(),
-        context_length,
-    )];
+    let mut out = vec![InferenceEndpoint {
+        model_name: model_name.to_string(),
+        inference_url: inference_url.to_string(),
+        max_context: context_length,
+        max_concurrency: provider_config.and_then(|cfg| get_u32(cfg, MAX_CONCURRENCY_KEY)),
+        request_timeout_secs,
+    }];
 
     let Some(long) = long else {
         return out;
@@ -186,8 +220,14 @@ pub fn expand_inference_endpoints(
 
     match (long_url, base_ctx, long_ctx) {
         (Some(long_url), Some(base_ctx), Some(long_ctx)) if base_ctx < long_ctx => {
-            out[0].2 = Some(base_ctx);
-            out.push((model_name.to_string(), long_url.to_string(), Some(long_ctx)));
+            out[0].max_context = Some(base_ctx);
+            out.push(InferenceEndpoint {
+                model_name: model_name.to_string(),
+                inference_url: long_url.to_string(),
+                max_context: Some(long_ctx),
+                max_concurrency: get_u32(long, MAX_CONCURRENCY_KEY),
+                request_timeout_secs,
+            });
         }
         _ => {
             // Numbers/model only — never customer data.
@@ -246,31 +286,28 @@ mod tests {
         serde_json::from_str(json).unwrap()
     }
 
+    fn endpoint(url: &str, max_context: Option<u32>) -> InferenceEndpoint {
+        InferenceEndpoint {
+            model_name: "m".to_string(),
+            inference_url: url.to_string(),
+            max_context,
+            max_concurrency: None,
+            request_timeout_secs: None,
+        }
+    }
+
     #[test]
     fn expand_without_provider_config_is_identity() {
-        let out = expand_inference_endpoints("m", "https://m.example", Some(131072), None);
-        assert_eq!(
-            out,
-            vec![(
-                "m".to_string(),
-                "https://m.example".to_string(),
-                Some(131072)
-            )]
-        );
+        let out = expand_inference_endpoints("m", "https://m.example", Some(131072), None, None);
+        assert_eq!(out, vec![endpoint("https://m.example", Some(131072))]);
     }
 
     #[test]
     fn expand_without_long_context_key_is_identity() {
         let pc = cfg(r#"{"something_else": true}"#);
-        let out = expand_inference_endpoints("m", "https://m.example", Some(131072), Some(&pc));
-        assert_eq!(
-            out,
-            vec![(
-                "m".to_string(),
-                "https://m.example".to_string(),
-                Some(131072)
-            )]
-        );
+        let out =
+            expand_inference_endpoints("m", "https://m.example", Some(131072), Some(&pc), None);
+        assert_eq!(out, vec![endpoint("https://m.example", Some(131072))]);
     }
 
     #[test]
@@ -282,20 +319,13 @@ mod tests {
             }}"#);
         // Catalog context_length stays the customer-facing 1M; the base
         // fleet's declared capacity comes from base_max_context_tokens.
-        let out = expand_inference_endpoints("m", "https://m.example", Some(1048576), Some(&pc));
+        let out =
+            expand_inference_endpoints("m", "https://m.example", Some(1048576), Some(&pc), None);
         assert_eq!(
             out,
             vec![
-                (
-                    "m".to_string(),
-                    "https://m.example".to_string(),
-                    Some(262144)
-                ),
-                (
-                    "m".to_string(),
-                    "https://m-long.example".to_string(),
-                    Some(1048576)
-                ),
+                endpoint("https://m.example", Some(262144)),
+                endpoint("https://m-long.example", Some(1048576)),
             ]
         );
     }
@@ -308,10 +338,11 @@ mod tests {
                 "inference_url": "https://m-long.example",
                 "base_max_context_tokens": 262144
             }}"#);
-        let out = expand_inference_endpoints("m", "https://m.example", Some(1048576), Some(&pc));
+        let out =
+            expand_inference_endpoints("m", "https://m.example", Some(1048576), Some(&pc), None);
         assert_eq!(out.len(), 2);
-        assert_eq!(out[0].2, Some(262144));
-        assert_eq!(out[1].2, Some(1048576));
+        assert_eq!(out[0].max_context, Some(262144));
+        assert_eq!(out[1].max_context, Some(1048576));
     }
 
     #[test]
@@ -337,20 +368,61 @@ mod tests {
                 r#"{"long_context": {"inference_url": "https://m-long.example", "max_context_tokens": 0, "base_max_context_tokens": -5}}"#,
             ),
         ] {
-            let out =
-                expand_inference_endpoints("m", "https://m.example", Some(1048576), Some(&pc));
+            let out = expand_inference_endpoints(
+                "m",
+                "https://m.example",
+                Some(1048576),
+                Some(&pc),
+                None,
+            );
             assert_eq!(
                 out,
-                vec![(
-                    "m".to_string(),
-                    "https://m.example".to_string(),
-                    Some(1048576)
-                )],
+                vec![endpoint("https://m.example", Some(1048576))],
                 "invalid long block must leave the identity expansion for {pc}"
             );
         }
     }
 
+    #[test]
+    fn expand_reads_max_concurrency_per_endpoint() {
+        let pc = cfg(r#"{"max_concurrency": 64, "long_context": {
+                "inference_url": "https://m-long.example",
+                "base_max_context_tokens": 262144,
+                "max_concurrency": 4
+            }}"#);
+        let out =
+            expand_inference_endpoints("m", "https://m.example", Some(1048576), Some(&pc), None);
+        assert_eq!(out[0].max_concurrency, Some(64));
+        assert_eq!(out[1].max_concurrency, Some(4));
+
+        // Zero / negative / non-numeric limits are ignored (unlimited).
+        for pc in [
+            cfg(r#"{"max_concurrency": 0}"#),
+            cfg(r#"{"max_concurrency": -1}"#),
+            cfg(r#"{"max_concurrency": "8"}"#),
+        ] {
+            let out = expand_inference_endpoints("m", "https://m.example", None, Some(&pc), None);
+            assert_eq!(out[0].max_concurrency, None, "{pc}");
+        }
+    }
+
+    #[test]
+    fn expand_applies_request_timeout_to_every_endpoint() {
+        let pc = cfg(r#"{"long_context": {
+                "inference_url": "https://m-long.example",
+                "base_max_context_tokens": 262144
+            }}"#);
+        let out = expand_inference_endpoints(
+            "m",
+            "https://m.example",
+            Some(1048576),
+            Some(&pc),
+            Some(900),
+        );
+        assert_eq!(out.len(), 2);
+        assert!(out.iter().all(|e| e.request_timeout_secs == Some(900)));
+    }
+
     #[test]
     fn concat_prompt_text_covers_content_forms_tool_calls_and_tools() {
         let params: ChatCompletionParams = serde_json::from_value(serde_json::json!({
@@ -0,0 +1,54 @@
+//! Opt-in capture of raw provider errors for admin debugging.
+//!
+//! Errors leave the pool sanitized (URLs and IPs redacted). An admin request
+//! can run its completion inside [`capture_provider_error_detail`] to also get
+//! the unsanitized text of every failed provider attempt. Outside that scope
+//! nothing is recorded, so regular requests pay nothing and see nothing.
+
+use std::{cell::RefCell, future::Future};
+
+tokio::task_local! {
+    static PROVIDER_ERROR_DETAIL: RefCell<Vec<String>>;
+}
+
+/// Run `future`, collecting the raw text of every provider error the pool
+/// sanitizes along the way. Returns `None` when no provider failed.
+pub async fn capture_provider_error_detail<F: Future>(future: F) -> (F::Output, Option<String>) {
+    PROVIDER_ERROR_DETAIL
+        .scope(RefCell::new(Vec::new()), async move {
+            let output = future.await;
+            let details = PROVIDER_ERROR_DETAIL.with(|details| details.take());
+            (output, (!details.is_empty()).then(|| details.join("; ")))
+        })
+        .await
+}
+
+/// Record a raw provider error if the current task is capturing.
+pub(super) fn record(raw: impl FnOnce() -> String) {
+    let _ = PROVIDER_ERROR_DETAIL.try_with(|details| details.borrow_mut().push(raw()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_records_only_inside_capture_scope() {
+        record(|| "ignored: outside scope".to_string());
+
+        let (output, detail) = capture_provider_error_detail(async {
+            record(|| "error sending request for url (http://10.0.0.1:8000)".to_string());
+            record(|| "HTTP 503".to_string());
+            7
+        })
+        .await;
+        assert_eq!(output, 7);
+        assert_eq!(
+            detail.as_deref(),
+            Some("error sending request for url (http://10.0.0.1:8000); HTTP 503")
+        );
+
+        let (_, detail) = capture_provider_error_detail(async {}).await;
+        assert_eq!(detail, None);
+    }
+}
@@ -1,6 +1,7 @@
 use inference_providers::{InferenceProvider, ProviderSource, ProviderTier, StreamingResult};
 use std::sync::Arc;
 
+#[derive(Clone)]
 pub struct AttributedChatCompletion {
     pub response: inference_providers::ChatCompletionResponseWithBytes,
     pub provider_attribution: crate::usage::ProviderAttribution,
@@ -29,6 +30,9 @@ pub(super) struct ServedProviderResult<T> {
     pub(super) value: T,
     pub(super) provider: Arc<dyn InferenceProvider + Send + Sync>,
     pub(super) provider_attribution: crate::usage::ProviderAttribution,
+    /// In-flight slot on a provider with a declared `max_concurrency`; the
+    /// slot is released when this is dropped. None = provider is unlimited.
+    pub(super) concurrency_permit: Option<tokio::sync::OwnedSemaphorePermit>,
 }
 
 pub(super) fn served_provider_attribution(
@@ -1,7 +1,10 @@
 pub mod admin;
 pub mod attestation;
+pub mod audit;
 pub mod auth;
 pub mod auto_redact;
+pub mod auto_tools;
+pub mod batches;
 pub mod common;
 pub mod completions;
 pub mod conversations;
@@ -18,7 +18,8 @@ use tracing::{debug, error, info, warn};
 /// Cached information about an MCP connector
 #[derive(Clone)]
 struct ConnectorCache {
-    tools: Vec<Tool>,
+    /// `None` until the first successful `tools/list`
+    tools: Option<Vec<Tool>>,
     cached_at: Instant,
     server_info: McpServerInfo,
 }
@@ -206,7 +207,7 @@ impl McpClientManager {
         let client_info = ClientInfo {
             client: client_arc,
             cache: Some(ConnectorCache {
-                tools: Vec::new(), // Will be populated on first tools request
+                tools: None, // Populated on first tools request
                 cached_at: Instant::now(),
                 server_info,
             }),
@@ -252,9 +253,11 @@ impl McpClientManager {
             let clients = self.clients.read().await;
             if let Some(info) = clients.get(connector_id) {
                 if let Some(cache) = &info.cache {
-                    if !cache.is_expired(self.cache_ttl) {
-                        debug!("Using cached tools for connector {}", connector_id);
-                        return Ok(cache.tools.clone());
+                    if let Some(tools) = &cache.tools {
+                        if !cache.is_expired(self.cache_ttl) {
+                            debug!("Using cached tools for connector {}", connector_id);
+                            return Ok(tools.clone());
+                        }
                     }
                 }
             }
@@ -298,7 +301,7 @@ impl McpClientManager {
                 });
 
             info.cache = Some(ConnectorCache {
-                tools,
+                tools: Some(tools),
                 cached_at: Instant::now(),
                 server_info,
             });
@@ -1,10 +1,14 @@
 pub mod manager;
 pub mod ports;
 
+use crate::common::RepositoryError;
 use crate::organization::ports::OrganizationId;
+use crate::responses::tools::mcp::McpToolExecutor;
+use crate::workspace::WorkspaceId;
 use async_trait::async_trait;
 pub use manager::McpClientManager;
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub use ports::*;
 
@@ -12,6 +16,8 @@ pub use ports::*;
 pub struct McpServiceImpl {
     pub mcp_repository: Arc<dyn McpConnectorRepository>,
     pub client_manager: Arc<McpClientManager>,
+    /// Skip the HTTPS and private-address checks on connector URLs (local testing only)
+    pub allow_insecure_server_urls: bool,
 }
 
 impl McpServiceImpl {
@@ -22,18 +28,23 @@ impl McpServiceImpl {
         Self {
             mcp_repository,
             client_manager,
+            allow_insecure_server_urls: false,
         }
     }
 
-    /// Get connector by ID, validating organization access
-    #[allow(dead_code)]
+    pub fn with_insecure_server_urls(mut self, allow: bool) -> Self {
+        self.allow_insecure_server_urls = allow;
+        self
+    }
+
+    /// Get connector by ID, validating workspace access
     async fn get_connector(
         &self,
         connector_id: &McpConnectorId,
-        organization_id: &OrganizationId,
+        workspace_id: &WorkspaceId,
     ) -> Result<McpConnector, McpError> {
         self.mcp_repository
-            .get_by_id(connector_id.clone(), organization_id.clone())
+            .get_by_id(connector_id.clone(), workspace_id.clone())
             .await
             .map_err(|e| McpError::InternalError(format!("Repository error: {e}")))?
             .ok_or(McpError::ConnectorNotFound)
@@ -214,112 +225,118 @@ impl McpService for McpServiceImpl {
         self.client_manager.get_server_info(connector_id).await
     }
 
-    /// Create a new MCP connector
+    /// Register a new MCP connector in a workspace
     async fn create_connector(
         &self,
         organization_id: OrganizationId,
+        workspace_id: WorkspaceId,
+        created_by: Uuid,
         name: String,
         description: Option<String>,
         server_url: String,
         auth: ports::McpAuthConfig,
-        settings: serde_json::Value,
     ) -> Result<McpConnector, McpError> {
-        let connector = self
-            .mcp_repository
-            .create(
-                organization_id,
-                name,
-                description,
-                server_url,
-                auth,
-                settings,
-            )
-            .await
-            .map_err(|e| McpError::InternalError(format!("Failed to create connector: {e}")))?;
-
-        tracing::debug!("Created new MCP connector: {}", connector.name);
-        Ok(connector)
-    }
+        if name.trim().is_empty() {
+            return Err(McpError::InvalidConfiguration(
+                "Connector name must not be empty".to_string(),
+            ));
+        }
+        if self.allow_insecure_server_urls {
+            url::Url::parse(&server_url)
+                .map_err(|e| McpError::InvalidConfiguration(format!("Invalid URL: {e}")))?;
+        } else {
+            McpToolExecutor::validate_server_url(&server_url)
+                .map_err(|e| McpError::InvalidConfiguration(e.to_string()))?;
+        }
+        if !matches!(
+            auth,
+            ports::McpAuthConfig::None | ports::McpAuthConfig::Bearer(_)
+        ) {
+            return Err(McpError::InvalidConfiguration(
+                "Only 'none' and 'bearer' authentication are supported".to_string(),
+            ));
+        }
 
-    /// Update an existing MCP connector
-    async fn update_connector(
-        &self,
-        connector_id: McpConnectorId,
-        organization_id: OrganizationId,
-        name: Option<String>,
-        description: Option<String>,
-        server_url: Option<String>,
-        auth: Option<ports::McpAuthConfig>,
-        settings: Option<serde_json::Value>,
-        is_active: Option<bool>,
-    ) -> Result<Option<McpConnector>, McpError> {
         let connector = self
             .mcp_repository
-            .update(
-                connector_id.clone(),
+            .create(
                 organization_id,
+                workspace_id,
+                created_by,
                 name,
                 description,
                 server_url,
                 auth,
-                settings,
-                is_active,
             )
             .await
-            .map_err(|e| McpError::InternalError(format!("Failed to update connector: {e}")))?;
-
-        if connector.is_some() {
-            tracing::debug!("Updated MCP connector: {}", connector_id);
-        }
+            .map_err(|e| match e.downcast_ref::<RepositoryError>() {
+                Some(RepositoryError::AlreadyExists) => McpError::ConnectorAlreadyExists,
+                _ => McpError::InternalError(format!("Failed to create connector: {e}")),
+            })?;
 
+        tracing::debug!("Created new MCP connector: {}", connector.id);
         Ok(connector)
     }
 
-    /// Delete an MCP connector
+    /// Delete an MCP connector from a workspace
     async fn delete_connector(
         &self,
         connector_id: McpConnectorId,
-        organization_id: OrganizationId,
+        workspace_id: WorkspaceId,
     ) -> Result<bool, McpError> {
-        // Disconnect first if connected
-        if self.client_manager.is_connected(&connector_id).await {
-            self.client_manager.disconnect(&connector_id).await?;
-        }
-
         let deleted = self
             .mcp_repository
-            .delete(connector_id.clone(), organization_id)
+            .delete(connector_id.clone(), workspace_id)
             .await
             .map_err(|e| McpError::InternalError(format!("Failed to delete connector: {e}")))?;
 
+        // Only drop the cached client once the workspace owns the deleted row
         if deleted {
+            self.client_manager.disconnect(&connector_id).await?;
             tracing::debug!("Deleted MCP connector: {}", connector_id);
         }
 
         Ok(deleted)
     }
 
-    /// List all connectors for an organization
+    /// List all connectors registered in a workspace
     async fn list_connectors(
         &self,
-        organization_id: OrganizationId,
-        include_inactive: bool,
+        workspace_id: WorkspaceId,
     ) -> Result<Vec<McpConnector>, McpError> {
         self.mcp_repository
-            .list_by_organization(organization_id, include_inactive)
+            .list_by_workspace(workspace_id)
             .await
             .map_err(|e| McpError::InternalError(format!("Failed to list connectors: {e}")))
     }
 
-    /// Get a connector by ID
+    /// Get a connector by ID within a workspace
     async fn get_connector_by_id(
         &self,
         connector_id: McpConnectorId,
-        organization_id: OrganizationId,
+        workspace_id: WorkspaceId,
     ) -> Result<Option<McpConnector>, McpError> {
         self.mcp_repository
-            .get_by_id(connector_id, organization_id)
+            .get_by_id(connector_id, workspace_id)
             .await
             .map_err(|e| McpError::InternalError(format!("Failed to get connector: {e}")))
     }
+
+    /// Invoke a tool on a workspace connector, connecting on first use
+    async fn invoke_connector_tool(
+        &self,
+        connector_id: McpConnectorId,
+        workspace_id: WorkspaceId,
+        tool_name: String,
+        arguments: Option<serde_json::Value>,
+    ) -> Result<CallToolResult, McpError> {
+        let connector = self.get_connector(&connector_id, &workspace_id).await?;
+        self.connect_connector(&connector).await?;
+        let tools = self.get_connector_tools(&connector_id).await?;
+        if !tools.iter().any(|tool| tool.name == tool_name) {
+            return Err(McpError::ToolNotFound { tool: tool_name });
+        }
+        self.call_connector_tool(&connector_id, tool_name, arguments)
+            .await
+    }
 }
@@ -1,4 +1,5 @@
 use super::super::organization::ports::OrganizationId;
+use super::super::workspace::WorkspaceId;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -45,6 +46,7 @@ impl std::fmt::Display for McpConnectorId {
 pub struct McpConnector {
     pub id: McpConnectorId,
     pub organization_id: OrganizationId,
+    pub workspace_id: WorkspaceId,
     pub name: String,
     pub description: Option<String>,
     pub server_url: String,
@@ -118,6 +120,9 @@ pub enum McpError {
 
     #[error("Connector not found")]
     ConnectorNotFound,
+
+    #[error("A connector with this name already exists")]
+    ConnectorAlreadyExists,
 }
 
 // Repository traits
@@ -127,45 +132,26 @@ pub trait McpConnectorRepository: Send + Sync {
     async fn create(
         &self,
         organization_id: OrganizationId,
+        workspace_id: WorkspaceId,
+        created_by: Uuid,
         name: String,
         description: Option<String>,
         server_url: String,
         auth: McpAuthConfig,
-        settings: serde_json::Value,
     ) -> anyhow::Result<McpConnector>;
 
     async fn get_by_id(
         &self,
         id: McpConnectorId,
-        organization_id: OrganizationId,
-    ) -> anyhow::Result<Option<McpConnector>>;
-
-    #[allow(clippy::too_many_arguments)]
-    async fn update(
-        &self,
-        id: McpConnectorId,
-        organization_id: OrganizationId,
-        name: Option<String>,
-        description: Option<String>,
-        server_url: Option<String>,
-        auth: Option<McpAuthConfig>,
-        settings: Option<serde_json::Value>,
-        is_active: Option<bool>,
+        workspace_id: WorkspaceId,
     ) -> anyhow::Result<Option<McpConnector>>;
 
-    async fn delete(
-        &self,
-        id: McpConnectorId,
-        organization_id: OrganizationId,
-    ) -> anyhow::Result<bool>;
+    async fn delete(&self, id: McpConnectorId, workspace_id: WorkspaceId) -> anyhow::Result<bool>;
 
-    async fn list_by_organization(
+    async fn list_by_workspace(
         &self,
-        organization_id: OrganizationId,
-        include_inactive: bool,
+        workspace_id: WorkspaceId,
     ) -> anyhow::Result<Vec<McpConnector>>;
-
-    async fn count_by_organization(&self, organization_id: OrganizationId) -> anyhow::Result<i64>;
 }
 
 /// Helper functions for working with Content
@@ -271,51 +257,47 @@ pub trait McpService: Send + Sync {
         connector_id: &McpConnectorId,
     ) -> Result<McpServerInfo, McpError>;
 
-    // CRUD Operations for connector management
+    // Workspace-scoped connector management
 
-    /// Create a new MCP connector
+    /// Register a new MCP connector in a workspace
+    #[allow(clippy::too_many_arguments)]
     async fn create_connector(
         &self,
         organization_id: OrganizationId,
+        workspace_id: WorkspaceId,
+        created_by: Uuid,
         name: String,
         description: Option<String>,
         server_url: String,
         auth: McpAuthConfig,
-        settings: serde_json::Value,
     ) -> Result<McpConnector, McpError>;
 
-    /// Update an existing MCP connector
-    #[allow(clippy::too_many_arguments)]
-    async fn update_connector(
-        &self,
-        connector_id: McpConnectorId,
-        organization_id: OrganizationId,
-        name: Option<String>,
-        description: Option<String>,
-        server_url: Option<String>,
-        auth: Option<McpAuthConfig>,
-        settings: Option<serde_json::Value>,
-        is_active: Option<bool>,
-    ) -> Result<Option<McpConnector>, McpError>;
-
-    /// Delete an MCP connector
+    /// Delete an MCP connector from a workspace
     async fn delete_connector(
         &self,
         connector_id: McpConnectorId,
-        organization_id: OrganizationId,
+        workspace_id: WorkspaceId,
     ) -> Result<bool, McpError>;
 
-    /// List all connectors for an organization
+    /// List all connectors registered in a workspace
     async fn list_connectors(
         &self,
-        organization_id: OrganizationId,
-        include_inactive: bool,
+        workspace_id: WorkspaceId,
     ) -> Result<Vec<McpConnector>, McpError>;
 
-    /// Get a connector by ID
+    /// Get a connector by ID within a workspace
     async fn get_connector_by_id(
         &self,
         connector_id: McpConnectorId,
-        organization_id: OrganizationId,
+        workspace_id: WorkspaceId,
     ) -> Result<Option<McpConnector>, McpError>;
+
+    /// Invoke a tool on a workspace connector, connecting on first use
+    async fn invoke_connector_tool(
+        &self,
+        connector_id: McpConnectorId,
+        workspace_id: WorkspaceId,
+        tool_name: String,
+        arguments: Option<serde_json::Value>,
+    ) -> Result<CallToolResult, McpError>;
 }
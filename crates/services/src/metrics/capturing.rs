@@ -14,6 +14,7 @@ pub enum MetricValue {
     Latency(Duration),
     Count(i64),
     Histogram(f64),
+    Gauge(f64),
 }
 
 pub struct CapturingMetricsService {
@@ -66,4 +67,13 @@ impl MetricsServiceTrait for CapturingMetricsService {
             tags: tags.iter().map(|s| s.to_string()).collect(),
         });
     }
+
+    fn record_gauge(&self, name: &str, value: f64, tags: &[&str]) {
+        let mut metrics = self.metrics.lock().unwrap();
+        metrics.push(RecordedMetric {
+            name: name.to_string(),
+            value: MetricValue::Gauge(value),
+            tags: tags.iter().map(|s| s.to_string()).collect(),
+        });
+    }
 }
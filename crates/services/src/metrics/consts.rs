@@ -4,6 +4,9 @@ pub const METRIC_LATENCY_TTFT_TOTAL: &str = "cloud_api.latency.time_to_first_tok
 pub const METRIC_LATENCY_TOTAL: &str = "cloud_api.latency.total";
 pub const METRIC_LATENCY_QUEUE_TIME: &str = "cloud_api.latency.queue_time";
 pub const METRIC_LATENCY_DECODING_TIME: &str = "cloud_api.latency.decoding_time";
+pub const METRIC_LATENCY_PROVIDER_CONNECT: &str = "cloud_api.latency.provider_connect";
+pub const METRIC_LATENCY_PROVIDER_TTFB: &str = "cloud_api.latency.provider_ttfb";
+pub const METRIC_LATENCY_PROVIDER_TOTAL: &str = "cloud_api.latency.provider_total";
 pub const METRIC_TOKENS_PER_SECOND: &str = "cloud_api.tokens_per_second";
 
 // Verification metrics (optional - for signature verification)
@@ -45,6 +48,13 @@ pub const METRIC_PROVIDER_REQUESTS: &str = "cloud_api.provider.requests";
 
 pub const METRIC_PROVIDER_ATTEMPTS: &str = "cloud_api.provider.attempts";
 
+// Failover visibility, tagged `model`, `operation` and `outcome` (the
+// sanitized error kind, e.g. `http_5xx`): `failover` counts failed attempts
+// after which the next provider is tried; `all_providers_failed` counts
+// requests where no provider succeeded.
+pub const METRIC_PROVIDER_FAILOVER: &str = "cloud_api.provider.failover";
+pub const METRIC_PROVIDER_ALL_FAILED: &str = "cloud_api.provider.all_providers_failed";
+
 // Error metrics
 pub const METRIC_REQUEST_ERRORS: &str = "cloud_api.request.errors";
 
@@ -62,6 +72,9 @@ pub const METRIC_BILLED_CACHE_READ_TOKENS: &str = "cloud_api.billed.cache_read_t
 pub const METRIC_BILLED_INPUT_COST_USD: &str = "cloud_api.billed.input_cost_usd";
 pub const METRIC_BILLED_OUTPUT_COST_USD: &str = "cloud_api.billed.output_cost_usd";
 
+// Organization spend anomalies flagged by the background usage anomaly detector
+pub const METRIC_USAGE_SPEND_ANOMALIES: &str = "cloud_api.usage.spend_anomalies";
+
 // Provider data quality metrics
 pub const METRIC_PROVIDER_TOKEN_ANOMALIES: &str = "cloud_api.provider.token_anomalies";
 pub const METRIC_PROVIDER_ZERO_TOKENS: &str = "cloud_api.provider.zero_tokens";
@@ -70,6 +83,12 @@ pub const METRIC_PROVIDER_ZERO_TOKENS: &str = "cloud_api.provider.zero_tokens";
 pub const METRIC_HTTP_REQUESTS: &str = "cloud_api.http.requests";
 pub const METRIC_HTTP_DURATION: &str = "cloud_api.http.duration";
 
+// Database connection-pool gauges, sampled periodically and tagged `pool`
+// (write|read). For a Patroni cluster the read gauge sums every replica pool.
+pub const METRIC_DB_POOL_CONNECTIONS_IN_USE: &str = "cloud_api.db.pool.connections.in_use";
+pub const METRIC_DB_POOL_CONNECTIONS_AVAILABLE: &str = "cloud_api.db.pool.connections.available";
+pub const METRIC_DB_POOL_WAITERS: &str = "cloud_api.db.pool.waiters";
+
 // Low-cardinality tags only (NO org/workspace/api_key - those go to database analytics)
 pub const TAG_MODEL: &str = "model";
 pub const TAG_ENVIRONMENT: &str = "environment";
@@ -80,13 +99,19 @@ pub const TAG_METHOD: &str = "method";
 pub const TAG_REASON: &str = "reason";
 pub const TAG_INPUT_BUCKET: &str = "input_bucket";
 pub const TAG_INFERENCE_TYPE: &str = "inference_type";
+pub const TAG_POOL: &str = "pool";
 
 // Error types for TAG_ERROR_TYPE
 pub const ERROR_TYPE_INVALID_MODEL: &str = "invalid_model";
+pub const ERROR_TYPE_MODEL_NOT_ALLOWED: &str = "model_not_allowed";
 pub const ERROR_TYPE_INVALID_PARAMS: &str = "invalid_params";
 pub const ERROR_TYPE_RATE_LIMIT: &str = "rate_limit";
+pub const ERROR_TYPE_QUOTA_EXCEEDED: &str = "quota_exceeded";
 pub const ERROR_TYPE_INFERENCE_ERROR: &str = "inference_error";
 pub const ERROR_TYPE_SERVICE_OVERLOADED: &str = "service_overloaded";
+pub const ERROR_TYPE_DUPLICATE_REQUEST: &str = "duplicate_request";
+pub const ERROR_TYPE_MODEL_WARMING: &str = "model_warming";
+pub const ERROR_TYPE_MODEL_NOT_FOUND: &str = "model_not_found";
 pub const ERROR_TYPE_INTERNAL_ERROR: &str = "internal_error";
 
 // Failure reasons (for verification)
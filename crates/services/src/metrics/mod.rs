@@ -3,7 +3,7 @@ pub mod consts;
 
 use async_trait::async_trait;
 use opentelemetry::{
-    metrics::{Counter, Histogram, Meter, MeterProvider as _},
+    metrics::{Counter, Gauge, Histogram, Meter, MeterProvider as _},
     KeyValue,
 };
 use opentelemetry_sdk::metrics::SdkMeterProvider;
@@ -14,6 +14,8 @@ pub trait MetricsServiceTrait: Send + Sync {
     fn record_latency(&self, name: &str, duration: Duration, tags: &[&str]);
     fn record_count(&self, name: &str, value: i64, tags: &[&str]);
     fn record_histogram(&self, name: &str, value: f64, tags: &[&str]);
+    /// Record the current value of a point-in-time measurement (e.g. pool size)
+    fn record_gauge(&self, name: &str, value: f64, tags: &[&str]);
 }
 
 pub struct OtlpMetricsService {
@@ -22,6 +24,7 @@ pub struct OtlpMetricsService {
     latency_histograms: std::sync::Mutex<std::collections::HashMap<String, Histogram<u64>>>,
     counters: std::sync::Mutex<std::collections::HashMap<String, Counter<u64>>>,
     value_histograms: std::sync::Mutex<std::collections::HashMap<String, Histogram<f64>>>,
+    gauges: std::sync::Mutex<std::collections::HashMap<String, Gauge<f64>>>,
 }
 
 impl OtlpMetricsService {
@@ -32,6 +35,7 @@ impl OtlpMetricsService {
             latency_histograms: std::sync::Mutex::new(std::collections::HashMap::new()),
             counters: std::sync::Mutex::new(std::collections::HashMap::new()),
             value_histograms: std::sync::Mutex::new(std::collections::HashMap::new()),
+            gauges: std::sync::Mutex::new(std::collections::HashMap::new()),
         }
     }
 
@@ -68,6 +72,15 @@ impl MetricsServiceTrait for OtlpMetricsService {
                 consts::METRIC_LATENCY_DECODING_TIME => {
                     "Time from first token to last token (decoding phase)"
                 }
+                consts::METRIC_LATENCY_PROVIDER_CONNECT => {
+                    "Provider connection setup (DNS + TCP + TLS); new connections only"
+                }
+                consts::METRIC_LATENCY_PROVIDER_TTFB => {
+                    "Time from sending the provider request to its response headers"
+                }
+                consts::METRIC_LATENCY_PROVIDER_TOTAL => {
+                    "Time from the provider request to the end of its response"
+                }
                 consts::METRIC_VERIFICATION_DURATION => "Time to complete verification operation",
                 consts::METRIC_SIGNATURE_CREATION_DURATION => {
                     "Time to create and store gateway signatures"
@@ -182,6 +195,28 @@ impl MetricsServiceTrait for OtlpMetricsService {
         let kv_tags = Self::parse_tags(tags);
         histogram.record(value, &kv_tags);
     }
+
+    fn record_gauge(&self, name: &str, value: f64, tags: &[&str]) {
+        let mut gauges = self.gauges.lock().unwrap();
+        let gauge = gauges.entry(name.to_string()).or_insert_with(|| {
+            let description = match name {
+                consts::METRIC_DB_POOL_CONNECTIONS_IN_USE => "Database connections checked out",
+                consts::METRIC_DB_POOL_CONNECTIONS_AVAILABLE => {
+                    "Idle database connections ready to be checked out"
+                }
+                consts::METRIC_DB_POOL_WAITERS => "Tasks waiting for a database connection",
+                _ => "Point-in-time value",
+            };
+
+            self.meter
+                .f64_gauge(name.to_string())
+                .with_description(description)
+                .build()
+        });
+
+        let kv_tags = Self::parse_tags(tags);
+        gauge.record(value, &kv_tags);
+    }
 }
 
 // Helper functions for creating properly formatted tags
@@ -203,4 +238,5 @@ impl MetricsServiceTrait for MockMetricsService {
     fn record_latency(&self, _name: &str, _duration: Duration, _tags: &[&str]) {}
     fn record_count(&self, _name: &str, _value: i64, _tags: &[&str]) {}
     fn record_histogram(&self, _name: &str, _value: f64, _tags: &[&str]) {}
+    fn record_gauge(&self, _name: &str, _value: f64, _tags: &[&str]) {}
 }
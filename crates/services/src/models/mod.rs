@@ -7,8 +7,12 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use moka::future::Cache;
-pub use ports::{ModelInfo, ModelWithPricing, ModelsError, ModelsRepository, ModelsServiceTrait};
+pub use ports::{
+    MaxTokensLimits, ModelInfo, ModelWithPricing, ModelsError, ModelsRepository,
+    ModelsServiceTrait, OrganizationModelAlias, OrganizationModelAliasRepository,
+};
 use tracing::warn;
+use uuid::Uuid;
 
 use crate::inference_provider_pool::{BackendModelMetadata, InferenceProviderPool};
 
@@ -31,6 +35,15 @@ const MODELS_LIST_CACHE_CAPACITY: u64 = 1;
 /// Cache key used for the single model-list entry.
 const MODELS_LIST_CACHE_KEY: &str = "all";
 
+/// TTL for the per-organization alias maps behind
+/// `resolve_alias_cached_for_organization`. Writes through this service
+/// invalidate the local entry; other instances pick changes up on expiry.
+const ORGANIZATION_ALIASES_CACHE_TTL_SECS: u64 = 60;
+const ORGANIZATION_ALIASES_CACHE_CAPACITY: u64 = 10_000;
+
+/// Longest accepted alias name (`organization_model_aliases.alias_name`).
+const MAX_ALIAS_NAME_LEN: usize = 500;
+
 fn apply_backend_model_metadata(
     models: &mut [ModelWithPricing],
     metadata_by_model: &HashMap<String, BackendModelMetadata>,
@@ -70,6 +83,10 @@ pub struct ModelsServiceImpl {
     /// sentinel since pagination has been dropped — there is only ever one
     /// list to serve.
     models_list_cache: Cache<&'static str, Arc<Vec<ModelWithPricing>>>,
+    /// Storage for organization-private aliases; `None` disables them.
+    organization_alias_repository: Option<Arc<dyn OrganizationModelAliasRepository>>,
+    /// Alias name -> canonical model name, per organization.
+    organization_aliases_cache: Cache<Uuid, Arc<HashMap<String, String>>>,
 }
 
 impl ModelsServiceImpl {
@@ -81,13 +98,36 @@ impl ModelsServiceImpl {
             .max_capacity(MODELS_LIST_CACHE_CAPACITY)
             .time_to_live(Duration::from_secs(MODELS_LIST_CACHE_TTL_SECS))
             .build();
+        let organization_aliases_cache = Cache::builder()
+            .max_capacity(ORGANIZATION_ALIASES_CACHE_CAPACITY)
+            .time_to_live(Duration::from_secs(ORGANIZATION_ALIASES_CACHE_TTL_SECS))
+            .build();
         Self {
             inference_provider_pool,
             models_repository,
             models_list_cache,
+            organization_alias_repository: None,
+            organization_aliases_cache,
         }
     }
 
+    /// Enable organization-private model aliases backed by `repository`.
+    pub fn with_organization_alias_repository(
+        mut self,
+        repository: Arc<dyn OrganizationModelAliasRepository>,
+    ) -> Self {
+        self.organization_alias_repository = Some(repository);
+        self
+    }
+
+    fn organization_alias_repository(
+        &self,
+    ) -> Result<&Arc<dyn OrganizationModelAliasRepository>, ModelsError> {
+        self.organization_alias_repository.as_ref().ok_or_else(|| {
+            ModelsError::InternalError("Organization model aliases are not configured".to_string())
+        })
+    }
+
     /// Fetch the active-models list through the in-process cache, returning
     /// the shared `Arc` so callers that only need to scan the list (e.g.
     /// alias resolution) avoid cloning every entry.
@@ -183,6 +223,124 @@ impl ModelsServiceTrait for ModelsServiceImpl {
             .map(|m| m.model_name.clone())
     }
 
+    async fn resolve_and_get_model_for_organization(
+        &self,
+        organization_id: Uuid,
+        identifier: &str,
+    ) -> Result<ModelWithPricing, ModelsError> {
+        self.models_repository
+            .resolve_and_get_model_for_organization(organization_id, identifier)
+            .await
+            .map_err(|e| ModelsError::InternalError(e.to_string()))?
+            .ok_or_else(|| ModelsError::NotFound(format!("Model '{identifier}' not found")))
+    }
+
+    async fn resolve_alias_cached_for_organization(
+        &self,
+        organization_id: Uuid,
+        identifier: &str,
+    ) -> Option<String> {
+        if let Some(repo) = self.organization_alias_repository.clone() {
+            let aliases = self
+                .organization_aliases_cache
+                .try_get_with(organization_id, async move {
+                    let aliases = repo.list_aliases(organization_id).await?;
+                    Ok::<_, anyhow::Error>(Arc::new(
+                        aliases
+                            .into_iter()
+                            .map(|alias| (alias.alias_name, alias.canonical_model_name))
+                            .collect::<HashMap<_, _>>(),
+                    ))
+                })
+                .await;
+            match aliases {
+                Ok(aliases) => {
+                    if let Some(canonical) = aliases.get(identifier) {
+                        return Some(canonical.clone());
+                    }
+                }
+                Err(e) => {
+                    warn!(%organization_id, error = %e, "Failed to load organization model aliases");
+                }
+            }
+        }
+        self.resolve_alias_cached(identifier).await
+    }
+
+    async fn list_organization_model_aliases(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<OrganizationModelAlias>, ModelsError> {
+        self.organization_alias_repository()?
+            .list_aliases(organization_id)
+            .await
+            .map_err(|e| ModelsError::InternalError(e.to_string()))
+    }
+
+    async fn set_organization_model_alias(
+        &self,
+        organization_id: Uuid,
+        alias_name: &str,
+        canonical_model_name: &str,
+    ) -> Result<OrganizationModelAlias, ModelsError> {
+        let repo = self.organization_alias_repository()?;
+        let alias_name = alias_name.trim();
+        if alias_name.is_empty() || alias_name.len() > MAX_ALIAS_NAME_LEN {
+            return Err(ModelsError::InvalidParams(format!(
+                "Alias name must be between 1 and {MAX_ALIAS_NAME_LEN} characters"
+            )));
+        }
+        // An alias that shadows a canonical name would silently reroute
+        // requests that name that model explicitly.
+        if self
+            .models_repository
+            .get_model_by_name(alias_name)
+            .await
+            .map_err(|e| ModelsError::InternalError(e.to_string()))?
+            .is_some()
+        {
+            return Err(ModelsError::InvalidParams(format!(
+                "'{alias_name}' is a canonical model name and cannot be used as an alias"
+            )));
+        }
+
+        let alias = repo
+            .upsert_alias(organization_id, alias_name, canonical_model_name)
+            .await
+            .map_err(|e| ModelsError::InternalError(e.to_string()))?
+            .ok_or_else(|| {
+                ModelsError::InvalidParams(format!(
+                    "Model '{canonical_model_name}' not found. Aliases must target an active canonical model name."
+                ))
+            })?;
+        self.organization_aliases_cache
+            .invalidate(&organization_id)
+            .await;
+        Ok(alias)
+    }
+
+    async fn delete_organization_model_alias(
+        &self,
+        organization_id: Uuid,
+        alias_name: &str,
+    ) -> Result<(), ModelsError> {
+        let deleted = self
+            .organization_alias_repository()?
+            .delete_alias(organization_id, alias_name)
+            .await
+            .map_err(|e| ModelsError::InternalError(e.to_string()))?;
+        self.organization_aliases_cache
+            .invalidate(&organization_id)
+            .await;
+        if deleted {
+            Ok(())
+        } else {
+            Err(ModelsError::NotFound(format!(
+                "Model alias '{alias_name}' not found"
+            )))
+        }
+    }
+
     async fn get_configured_model_names(&self) -> Result<Vec<String>, ModelsError> {
         self.models_repository
             .get_configured_model_names()
@@ -251,6 +409,14 @@ mod tests {
             Ok(self.resolved_models.get(identifier).cloned())
         }
 
+        async fn resolve_and_get_model_for_organization(
+            &self,
+            _organization_id: Uuid,
+            identifier: &str,
+        ) -> Result<Option<ModelWithPricing>, anyhow::Error> {
+            self.resolve_and_get_model(identifier).await
+        }
+
         async fn get_configured_model_names(&self) -> Result<Vec<String>, anyhow::Error> {
             Ok(self.models_by_name.keys().cloned().collect())
         }
@@ -293,6 +459,7 @@ mod tests {
             is_ready: None,
             deprecation_date: None,
             openrouter_slug: None,
+            max_tokens_limits: Default::default(),
             created_at: chrono::Utc::now(),
         }
     }
@@ -84,10 +84,35 @@ pub struct ModelWithPricing {
     /// OpenRouter `openrouter.slug` override. When set, the public API emits a
     /// nested `openrouter: { slug: <value> }` object; NULL = unset (omitted).
     pub openrouter_slug: Option<String>,
+    /// Output-token caps the completion service applies to requests.
+    pub max_tokens_limits: MaxTokensLimits,
     /// When the model row was created — used as OpenRouter's `created` unix timestamp.
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// Per-model `max_tokens` policy. All limits unset (the default) leaves
+/// requests untouched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MaxTokensLimits {
+    /// Applied when the client sends neither `max_tokens` nor
+    /// `max_completion_tokens`.
+    pub default_max_tokens: Option<i32>,
+    /// Upper bound on the requested value.
+    pub max_max_tokens: Option<i32>,
+    /// Reject requests above `max_max_tokens` instead of clamping them.
+    pub reject_over_max: bool,
+}
+
+/// An organization-private alias for a canonical model name.
+#[derive(Debug, Clone)]
+pub struct OrganizationModelAlias {
+    pub organization_id: Uuid,
+    pub alias_name: String,
+    pub canonical_model_name: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ModelsError {
     #[error("Internal error: {0}")]
@@ -117,11 +142,46 @@ pub trait ModelsRepository: Send + Sync {
         identifier: &str,
     ) -> Result<Option<ModelWithPricing>, anyhow::Error>;
 
+    /// Resolve a model identifier for a request made on behalf of
+    /// `organization_id`: the organization's private aliases take precedence,
+    /// then canonical names and global aliases.
+    async fn resolve_and_get_model_for_organization(
+        &self,
+        organization_id: Uuid,
+        identifier: &str,
+    ) -> Result<Option<ModelWithPricing>, anyhow::Error>;
+
     /// Get list of configured model names (canonical names) from database
     /// Returns only active models that have been configured with pricing
     async fn get_configured_model_names(&self) -> Result<Vec<String>, anyhow::Error>;
 }
 
+/// Repository trait for organization-scoped model aliases
+#[async_trait]
+pub trait OrganizationModelAliasRepository: Send + Sync {
+    /// List the organization's aliases, ordered by alias name
+    async fn list_aliases(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<OrganizationModelAlias>, anyhow::Error>;
+
+    /// Point `alias_name` at the active model named `canonical_model_name`,
+    /// replacing any previous target. Returns None if no such model exists.
+    async fn upsert_alias(
+        &self,
+        organization_id: Uuid,
+        alias_name: &str,
+        canonical_model_name: &str,
+    ) -> Result<Option<OrganizationModelAlias>, anyhow::Error>;
+
+    /// Delete an alias. Returns false if the organization had no such alias.
+    async fn delete_alias(
+        &self,
+        organization_id: Uuid,
+        alias_name: &str,
+    ) -> Result<bool, anyhow::Error>;
+}
+
 #[async_trait]
 pub trait ModelsServiceTrait: Send + Sync {
     /// Get basic model info (from inference providers)
@@ -167,6 +227,43 @@ pub trait ModelsServiceTrait: Send + Sync {
     /// checks must use `resolve_and_get_model`.
     async fn resolve_alias_cached(&self, identifier: &str) -> Option<String>;
 
+    /// Like `resolve_and_get_model`, but the organization's private aliases
+    /// take precedence over global resolution.
+    async fn resolve_and_get_model_for_organization(
+        &self,
+        organization_id: Uuid,
+        identifier: &str,
+    ) -> Result<ModelWithPricing, ModelsError>;
+
+    /// Like `resolve_alias_cached`, but the organization's private aliases
+    /// are consulted first.
+    async fn resolve_alias_cached_for_organization(
+        &self,
+        organization_id: Uuid,
+        identifier: &str,
+    ) -> Option<String>;
+
+    /// List the organization's private model aliases
+    async fn list_organization_model_aliases(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<OrganizationModelAlias>, ModelsError>;
+
+    /// Create or retarget an organization-private alias
+    async fn set_organization_model_alias(
+        &self,
+        organization_id: Uuid,
+        alias_name: &str,
+        canonical_model_name: &str,
+    ) -> Result<OrganizationModelAlias, ModelsError>;
+
+    /// Delete an organization-private alias
+    async fn delete_organization_model_alias(
+        &self,
+        organization_id: Uuid,
+        alias_name: &str,
+    ) -> Result<(), ModelsError>;
+
     /// Get list of configured model names (canonical names) from database
     /// Returns only active models that have been configured with pricing
     async fn get_configured_model_names(&self) -> Result<Vec<String>, ModelsError>;
@@ -78,6 +78,7 @@ impl OrganizationServiceImpl {
             RepositoryError::DependencyExists(msg) => OrganizationError::InvalidParams(format!(
                 "Cannot delete due to dependencies: {msg}"
             )),
+            RepositoryError::SeatLimitExceeded => OrganizationError::SeatLimitExceeded,
             RepositoryError::TransactionConflict => {
                 OrganizationError::InternalError("Transaction conflict, please retry".to_string())
             }
@@ -215,7 +216,7 @@ impl OrganizationServiceImpl {
         order_direction: Option<OrganizationOrderDirection>,
     ) -> Result<Vec<Organization>, OrganizationError> {
         self.repository
-            .list_organizations_by_user(user_id.0, limit, offset, order_by, order_direction)
+            .list_organizations_by_user(user_id.0, limit, offset, order_by, order_direction, None)
             .await
             .map_err(Self::map_repository_error)
     }
@@ -227,6 +228,7 @@ impl OrganizationServiceImpl {
         offset: i64,
         order_by: Option<OrganizationOrderBy>,
         order_direction: Option<OrganizationOrderDirection>,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<OrganizationWithRole>, OrganizationError> {
         self.repository
             .list_organizations_with_roles_by_user(
@@ -235,6 +237,7 @@ impl OrganizationServiceImpl {
                 offset,
                 order_by,
                 order_direction,
+                cursor,
             )
             .await
             .map_err(Self::map_repository_error)
@@ -462,6 +465,7 @@ impl OrganizationServiceImpl {
         requester_id: UserId,
         limit: i64,
         offset: i64,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<OrganizationMemberWithUser>, OrganizationError> {
         // Check if requester is a member
         let org = self.get_organization_impl(organization_id.clone()).await?;
@@ -482,7 +486,7 @@ impl OrganizationServiceImpl {
         // Get members with pagination
         let members = self
             .repository
-            .list_members_paginated(organization_id.0, limit, offset)
+            .list_members_paginated(organization_id.0, limit, offset, cursor)
             .await
             .map_err(Self::map_repository_error)?;
 
@@ -691,7 +695,7 @@ impl OrganizationServiceImpl {
         // Check if removing last owner
         let members = self
             .repository
-            .list_members_paginated(organization_id.0, 1, 0)
+            .list_members_paginated(organization_id.0, 1, 0, None)
             .await
             .map_err(Self::map_repository_error)?;
 
@@ -902,6 +906,32 @@ impl OrganizationServiceImpl {
         Ok(member.role)
     }
 
+    /// Reject up front when the organization has no free seat. Invitations
+    /// don't hold a seat; the limit is enforced again, atomically, when one
+    /// is accepted.
+    async fn ensure_seat_available(
+        &self,
+        organization_id: &OrganizationId,
+    ) -> Result<(), OrganizationError> {
+        let Some(max_members) = self
+            .repository
+            .get_max_members(organization_id.0)
+            .await
+            .map_err(Self::map_repository_error)?
+        else {
+            return Ok(());
+        };
+        let members = self
+            .repository
+            .get_member_count(organization_id.0)
+            .await
+            .map_err(Self::map_repository_error)?;
+        if members >= max_members {
+            return Err(OrganizationError::SeatLimitExceeded);
+        }
+        Ok(())
+    }
+
     /// Create invitations for users (supports unregistered users, private helper)
     async fn create_invitations_impl(
         &self,
@@ -914,6 +944,7 @@ impl OrganizationServiceImpl {
         let requester_role = self
             .get_invitation_requester_role(&organization_id, &requester_id, &org)
             .await?;
+        self.ensure_seat_available(&organization_id).await?;
 
         let mut results = Vec::new();
         let mut successful = 0;
@@ -1150,7 +1181,10 @@ impl OrganizationServiceImpl {
                 invitation.invited_by_user_id.0,
             )
             .await
-            .map_err(|e| OrganizationError::InternalError(format!("Failed to add member: {e}")))?;
+            .map_err(|e| match e {
+                RepositoryError::SeatLimitExceeded => OrganizationError::SeatLimitExceeded,
+                e => OrganizationError::InternalError(format!("Failed to add member: {e}")),
+            })?;
 
         // Mark invitation as accepted
         self.invitation_repository
@@ -1499,6 +1533,7 @@ impl OrganizationServiceTrait for OrganizationServiceImpl {
         offset: i64,
         order_by: Option<OrganizationOrderBy>,
         order_direction: Option<OrganizationOrderDirection>,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<OrganizationWithRole>, OrganizationError> {
         self.list_organizations_with_roles_for_user_impl(
             user_id,
@@ -1506,6 +1541,7 @@ impl OrganizationServiceTrait for OrganizationServiceImpl {
             offset,
             order_by,
             order_direction,
+            cursor,
         )
         .await
     }
@@ -1590,9 +1626,16 @@ impl OrganizationServiceTrait for OrganizationServiceImpl {
         requester_id: UserId,
         limit: i64,
         offset: i64,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<OrganizationMemberWithUser>, OrganizationError> {
-        self.get_members_with_users_paginated_impl(organization_id, requester_id, limit, offset)
-            .await
+        self.get_members_with_users_paginated_impl(
+            organization_id,
+            requester_id,
+            limit,
+            offset,
+            cursor,
+        )
+        .await
     }
 
     async fn invite_members_by_email(
@@ -1911,6 +1954,7 @@ mod tests {
             _: Uuid,
             _: i64,
             _: i64,
+            _: Option<PageCursor>,
         ) -> Result<Vec<OrganizationMember>, RepositoryError> {
             unimplemented!()
         }
@@ -1919,6 +1963,10 @@ mod tests {
             unimplemented!()
         }
 
+        async fn get_max_members(&self, _: Uuid) -> Result<Option<i64>, RepositoryError> {
+            Ok(None)
+        }
+
         async fn count_organizations_by_user(&self, _: Uuid) -> Result<i64, RepositoryError> {
             unimplemented!()
         }
@@ -1930,6 +1978,7 @@ mod tests {
             _: i64,
             _: Option<OrganizationOrderBy>,
             _: Option<OrganizationOrderDirection>,
+            _: Option<PageCursor>,
         ) -> Result<Vec<Organization>, RepositoryError> {
             unimplemented!()
         }
@@ -1941,6 +1990,7 @@ mod tests {
             _: i64,
             _: Option<OrganizationOrderBy>,
             _: Option<OrganizationOrderDirection>,
+            _: Option<PageCursor>,
         ) -> Result<Vec<OrganizationWithRole>, RepositoryError> {
             unimplemented!()
         }
@@ -118,6 +118,9 @@ pub enum OrganizationError {
 
     #[error("User is already a member")]
     AlreadyMember,
+
+    #[error("Organization has reached its seat limit")]
+    SeatLimitExceeded,
 }
 
 #[derive(Debug, Clone)]
@@ -277,6 +280,23 @@ pub enum OrganizationOrderDirection {
     Desc,
 }
 
+/// Keyset position in a listing: the sort key and id of a boundary row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListCursor {
+    pub sort_key: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+/// Keyset pagination relative to a previously returned row. Unlike offsets,
+/// cursors do not drift when rows are inserted or deleted between pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageCursor {
+    /// Rows that come after the cursor in list order
+    After(ListCursor),
+    /// Rows that come before the cursor, still returned in list order
+    Before(ListCursor),
+}
+
 #[async_trait]
 pub trait OrganizationRepository: Send + Sync {
     async fn create(
@@ -303,6 +323,10 @@ pub trait OrganizationRepository: Send + Sync {
 
     async fn delete(&self, id: Uuid) -> Result<bool, RepositoryError>;
 
+    /// Add a member, failing with `RepositoryError::SeatLimitExceeded` if
+    /// the organization is already at its seat limit. The member count is
+    /// checked under a lock on the organization row, so concurrent adds
+    /// cannot overshoot the limit.
     async fn add_member(
         &self,
         org_id: Uuid,
@@ -319,15 +343,21 @@ pub trait OrganizationRepository: Send + Sync {
 
     async fn remove_member(&self, org_id: Uuid, user_id: Uuid) -> Result<bool, RepositoryError>;
 
+    /// Members ordered by `(joined_at, user_id)` descending; cursor ids are
+    /// user ids.
     async fn list_members_paginated(
         &self,
         org_id: Uuid,
         limit: i64,
         offset: i64,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<OrganizationMember>, RepositoryError>;
 
     async fn get_member_count(&self, org_id: Uuid) -> Result<i64, RepositoryError>;
 
+    /// Seat limit (`max_members`) of the organization; None means unlimited.
+    async fn get_max_members(&self, org_id: Uuid) -> Result<Option<i64>, RepositoryError>;
+
     async fn count_organizations_by_user(&self, user_id: Uuid) -> Result<i64, RepositoryError>;
 
     async fn list_organizations_by_user(
@@ -337,6 +367,7 @@ pub trait OrganizationRepository: Send + Sync {
         offset: i64,
         order_by: Option<OrganizationOrderBy>,
         order_direction: Option<OrganizationOrderDirection>,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<Organization>, RepositoryError>;
 
     async fn list_organizations_with_roles_by_user(
@@ -346,6 +377,7 @@ pub trait OrganizationRepository: Send + Sync {
         offset: i64,
         order_by: Option<OrganizationOrderBy>,
         order_direction: Option<OrganizationOrderDirection>,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<OrganizationWithRole>, RepositoryError>;
 }
 
@@ -475,6 +507,7 @@ pub trait OrganizationServiceTrait: Send + Sync {
         offset: i64,
         order_by: Option<OrganizationOrderBy>,
         order_direction: Option<OrganizationOrderDirection>,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<OrganizationWithRole>, OrganizationError>;
 
     /// Count organizations accessible to a user
@@ -541,6 +574,7 @@ pub trait OrganizationServiceTrait: Send + Sync {
         requester_id: UserId,
         limit: i64,
         offset: i64,
+        cursor: Option<PageCursor>,
     ) -> Result<Vec<OrganizationMemberWithUser>, OrganizationError>;
 
     /// Invite members by email (batch operation)
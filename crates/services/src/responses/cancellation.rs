@@ -0,0 +1,109 @@
+//! Cancellation of in-flight responses.
+//!
+//! Each streaming response registers a token while its agent loop runs;
+//! `POST /v1/responses/{id}/cancel` trips it so the loop stops and drops the
+//! upstream completion stream (aborting the provider request) instead of
+//! generating to the end. Tokens are process-local: a cancel that lands on
+//! another replica still marks the response cancelled in the database, but
+//! the generation runs to completion there.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct ResponseCancellationRegistry {
+    /// response id -> (owning workspace id, token)
+    tokens: Mutex<HashMap<Uuid, (Uuid, CancellationToken)>>,
+}
+
+impl ResponseCancellationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track an in-flight response. The entry is removed when the guard drops.
+    pub fn register(
+        self: &Arc<Self>,
+        response_id: Uuid,
+        workspace_id: Uuid,
+    ) -> ResponseCancellationGuard {
+        let token = CancellationToken::new();
+        self.tokens
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(response_id, (workspace_id, token.clone()));
+        ResponseCancellationGuard {
+            registry: self.clone(),
+            response_id,
+            token,
+        }
+    }
+
+    /// Trip the token of an in-flight response owned by `workspace_id`.
+    /// Returns false if no such response is running in this process.
+    pub fn cancel(&self, response_id: Uuid, workspace_id: Uuid) -> bool {
+        let tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        match tokens.get(&response_id) {
+            Some((owner, token)) if *owner == workspace_id => {
+                token.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn remove(&self, response_id: Uuid) {
+        self.tokens
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&response_id);
+    }
+}
+
+/// Registration of one in-flight response; unregisters on drop.
+pub struct ResponseCancellationGuard {
+    registry: Arc<ResponseCancellationRegistry>,
+    response_id: Uuid,
+    token: CancellationToken,
+}
+
+impl ResponseCancellationGuard {
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+}
+
+impl Drop for ResponseCancellationGuard {
+    fn drop(&mut self) {
+        self.registry.remove(self.response_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_trips_token_for_owning_workspace_only() {
+        let registry = Arc::new(ResponseCancellationRegistry::new());
+        let (response_id, workspace_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let guard = registry.register(response_id, workspace_id);
+
+        assert!(!registry.cancel(response_id, Uuid::new_v4()));
+        assert!(!guard.token().is_cancelled());
+
+        assert!(registry.cancel(response_id, workspace_id));
+        assert!(guard.token().is_cancelled());
+    }
+
+    #[test]
+    fn dropping_guard_unregisters_response() {
+        let registry = Arc::new(ResponseCancellationRegistry::new());
+        let (response_id, workspace_id) = (Uuid::new_v4(), Uuid::new_v4());
+        drop(registry.register(response_id, workspace_id));
+
+        assert!(!registry.cancel(response_id, workspace_id));
+    }
+}
@@ -25,6 +25,11 @@ pub enum ResponseError {
     #[error("Previous response not found")]
     PreviousResponseNotFound,
 
+    /// The response does not exist in the caller's workspace (non-enumerating,
+    /// like [`Self::PreviousResponseNotFound`]).
+    #[error("Response not found")]
+    ResponseNotFound,
+
     // ============================================
     // MCP (Model Context Protocol) Errors
     // ============================================
@@ -84,7 +89,8 @@ impl ResponseError {
             ResponseError::McpApprovalRequestNotFound(_)
             | ResponseError::FunctionCallNotFound(_)
             | ResponseError::ConversationNotFound
-            | ResponseError::PreviousResponseNotFound => 404,
+            | ResponseError::PreviousResponseNotFound
+            | ResponseError::ResponseNotFound => 404,
             ResponseError::McpConnectionFailed(_)
             | ResponseError::McpToolDiscoveryFailed(_)
             | ResponseError::McpToolExecutionFailed(_) => 502,
@@ -115,12 +121,16 @@ impl ResponseError {
             | ResponseError::FunctionCallRequired { .. }
             | ResponseError::FunctionCallNotFound(_)
             | ResponseError::ConversationNotFound
-            | ResponseError::PreviousResponseNotFound => true,
+            | ResponseError::PreviousResponseNotFound
+            | ResponseError::ResponseNotFound => true,
             ResponseError::Completion(error) => matches!(
                 error,
                 crate::completions::CompletionError::InvalidModel(_)
+                    | crate::completions::CompletionError::ModelNotFound(_)
+                    | crate::completions::CompletionError::ModelNotAllowed(_)
                     | crate::completions::CompletionError::InvalidParams(_)
                     | crate::completions::CompletionError::RateLimitExceeded(_)
+                    | crate::completions::CompletionError::QuotaExceeded(_)
             ),
             ResponseError::InternalError(_)
             | ResponseError::StreamInterrupted
@@ -158,6 +168,9 @@ impl ResponseError {
             ResponseError::PreviousResponseNotFound => {
                 response_error("Previous response not found", "not_found", None)
             }
+            ResponseError::ResponseNotFound => {
+                response_error("Response not found", "not_found", None)
+            }
             ResponseError::McpConnectionFailed(msg) => {
                 response_error(&format!("MCP connection failed: {msg}"), "mcp_error", None)
             }
@@ -215,10 +228,18 @@ fn completion_http_status_code(error: &crate::completions::CompletionError) -> u
     match error {
         crate::completions::CompletionError::InvalidModel(_)
         | crate::completions::CompletionError::InvalidParams(_) => 400,
-        crate::completions::CompletionError::RateLimitExceeded(_) => 429,
+        crate::completions::CompletionError::ModelNotAllowed(_) => 403,
+        crate::completions::CompletionError::ModelNotFound(_) => 404,
+        crate::completions::CompletionError::RateLimitExceeded(_)
+        | crate::completions::CompletionError::QuotaExceeded(_) => 429,
         crate::completions::CompletionError::ProviderError { status_code, .. } => *status_code,
         crate::completions::CompletionError::ServiceOverloaded(_) => 429,
+        crate::completions::CompletionError::DuplicateRequest(_) => 409,
+        crate::completions::CompletionError::ModelWarming(_) => 503,
         crate::completions::CompletionError::InternalError(_) => 500,
+        crate::completions::CompletionError::ProvidersFailed { error, .. } => {
+            completion_http_status_code(error)
+        }
     }
 }
 
@@ -231,6 +252,16 @@ fn completion_response_error(
             error.param = Some("model".to_string());
             error
         }
+        crate::completions::CompletionError::ModelNotFound(msg) => {
+            let mut error = response_error(msg, "model_not_found", None);
+            error.param = Some("model".to_string());
+            error
+        }
+        crate::completions::CompletionError::ModelNotAllowed(msg) => {
+            let mut error = response_error(msg, "model_not_allowed", None);
+            error.param = Some("model".to_string());
+            error
+        }
         crate::completions::CompletionError::InvalidParams(msg) => {
             response_error(msg, "invalid_request_error", None)
         }
@@ -242,6 +273,9 @@ fn completion_response_error(
             };
             response_error(message, "rate_limit_exceeded", None)
         }
+        crate::completions::CompletionError::QuotaExceeded(msg) => {
+            response_error(msg, "quota_exceeded", None)
+        }
         crate::completions::CompletionError::ProviderError {
             status_code,
             message,
@@ -257,11 +291,20 @@ fn completion_response_error(
         crate::completions::CompletionError::ServiceOverloaded(msg) => {
             response_error(msg, "service_overloaded", None)
         }
+        crate::completions::CompletionError::DuplicateRequest(msg) => {
+            response_error(msg, "duplicate_request", None)
+        }
+        crate::completions::CompletionError::ModelWarming(msg) => {
+            response_error(msg, "model_warming", None)
+        }
         crate::completions::CompletionError::InternalError(msg) => response_error(
             &format!("Internal server error: {msg}"),
             "internal_server_error",
             None,
         ),
+        crate::completions::CompletionError::ProvidersFailed { error, .. } => {
+            completion_response_error(error)
+        }
     }
 }
 
@@ -1,3 +1,4 @@
+pub mod cancellation;
 pub mod citation_tracker;
 pub mod errors;
 pub mod models;
@@ -1186,6 +1186,7 @@ impl CreateResponseRequest {
                     MAX_METADATA_SIZE_BYTES
                 ));
             }
+            crate::common::extract_metadata(metadata)?;
         }
 
         // Validate input message metadata sizes
@@ -1668,6 +1669,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create_response_request_enforces_metadata_limits() {
+        use crate::common::{
+            MAX_METADATA_KEYS, MAX_METADATA_KEY_LENGTH, MAX_METADATA_VALUE_LENGTH,
+        };
+
+        let request_with_metadata = |metadata: serde_json::Value| CreateResponseRequest {
+            model: "gpt-4".to_string(),
+            input: None,
+            instructions: None,
+            conversation: None,
+            previous_response_id: None,
+            max_output_tokens: None,
+            max_tool_calls: None,
+            temperature: None,
+            top_p: None,
+            stream: None,
+            store: None,
+            background: None,
+            tools: None,
+            tool_choice: None,
+            parallel_tool_calls: None,
+            reasoning: None,
+            include: None,
+            metadata: Some(metadata),
+            safety_identifier: None,
+            prompt_cache_key: None,
+        };
+        let metadata_with_keys = |count: usize| {
+            serde_json::Value::Object(
+                (0..count)
+                    .map(|i| (format!("key_{i}"), json!("value")))
+                    .collect(),
+            )
+        };
+
+        assert!(request_with_metadata(metadata_with_keys(MAX_METADATA_KEYS))
+            .validate()
+            .is_ok());
+        assert!(request_with_metadata(json!({
+            "key": "x".repeat(MAX_METADATA_VALUE_LENGTH)
+        }))
+        .validate()
+        .is_ok());
+
+        assert_eq!(
+            request_with_metadata(metadata_with_keys(MAX_METADATA_KEYS + 1))
+                .validate()
+                .unwrap_err(),
+            "metadata can have at most 16 keys, got 17"
+        );
+        assert_eq!(
+            request_with_metadata(json!({
+                "key": "x".repeat(MAX_METADATA_VALUE_LENGTH + 1)
+            }))
+            .validate()
+            .unwrap_err(),
+            "metadata values can be at most 512 characters long"
+        );
+        assert_eq!(
+            request_with_metadata(serde_json::Value::Object(
+                [("k".repeat(MAX_METADATA_KEY_LENGTH + 1), json!("value"))]
+                    .into_iter()
+                    .collect()
+            ))
+            .validate()
+            .unwrap_err(),
+            "metadata keys can be at most 64 characters long"
+        );
+        assert_eq!(
+            request_with_metadata(json!({ "count": 1 }))
+                .validate()
+                .unwrap_err(),
+            "metadata values must be strings"
+        );
+    }
+
     #[test]
     fn test_create_response_request_validates_without_metadata() {
         // Test that request without metadata passes validation
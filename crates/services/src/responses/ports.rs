@@ -128,6 +128,19 @@ pub trait ResponseItemRepositoryTrait: Send + Sync {
         after: Option<String>,
         limit: i64,
     ) -> anyhow::Result<Vec<models::ResponseOutputItem>>;
+    /// List message items of a conversation whose text content contains
+    /// `query` (case-insensitive substring match), in conversation order.
+    ///
+    /// Same workspace constraint and `after` cursor rules as
+    /// [`Self::list_by_conversation`].
+    async fn search_by_conversation(
+        &self,
+        conversation_id: ConversationId,
+        workspace_id: WorkspaceId,
+        query: &str,
+        after: Option<String>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<models::ResponseOutputItem>>;
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -150,4 +163,14 @@ pub trait ResponseServiceTrait: Send + Sync {
         Pin<Box<dyn Stream<Item = models::ResponseStreamEvent> + Send>>,
         errors::ResponseError,
     >;
+
+    /// Cancel an in-progress response and abort its generation.
+    ///
+    /// Returns the cancelled response. Unknown and foreign IDs yield
+    /// `ResponseNotFound`; responses that already finished yield `InvalidParams`.
+    async fn cancel_response(
+        &self,
+        response_id: &str,
+        workspace_id: uuid::Uuid,
+    ) -> Result<models::ResponseObject, errors::ResponseError>;
 }
@@ -24,6 +24,8 @@ enum AgentLoopResult {
     ApprovalRequired,
     /// Agent loop paused due to external function calls requiring client execution
     FunctionCallsRequired,
+    /// Agent loop aborted by `POST /v1/responses/{id}/cancel`
+    Cancelled,
 }
 
 /// Context for processing a response stream
@@ -50,6 +52,7 @@ struct ProcessStreamContext {
     mcp_executor: Option<Arc<tools::McpToolExecutor>>,
     mcp_client_factory: Option<Arc<dyn tools::McpClientFactory>>,
     tool_registry: tools::ToolRegistry,
+    cancellations: Arc<crate::responses::cancellation::ResponseCancellationRegistry>,
 }
 
 pub struct ResponseServiceImpl {
@@ -65,6 +68,8 @@ pub struct ResponseServiceImpl {
     pub organization_service: Arc<dyn crate::organization::OrganizationServiceTrait>,
     /// Optional MCP client factory for testing (if None, uses RealMcpClientFactory)
     pub mcp_client_factory: Option<Arc<dyn tools::McpClientFactory>>,
+    /// Cancellation tokens of the responses currently generating in this process
+    pub cancellations: Arc<crate::responses::cancellation::ResponseCancellationRegistry>,
 }
 
 /// Tag transition states for reasoning content
@@ -101,6 +106,9 @@ impl ResponseServiceImpl {
             file_service,
             organization_service,
             mcp_client_factory: None,
+            cancellations: Arc::new(
+                crate::responses::cancellation::ResponseCancellationRegistry::new(),
+            ),
         }
     }
 
@@ -131,6 +139,9 @@ impl ResponseServiceImpl {
             file_service,
             organization_service,
             mcp_client_factory: Some(mcp_client_factory),
+            cancellations: Arc::new(
+                crate::responses::cancellation::ResponseCancellationRegistry::new(),
+            ),
         }
     }
 }
@@ -185,6 +196,7 @@ impl ports::ResponseServiceTrait for ResponseServiceImpl {
         let file_service = self.file_service.clone();
         let organization_service = self.organization_service.clone();
         let mcp_client_factory = self.mcp_client_factory.clone();
+        let cancellations = self.cancellations.clone();
         let signing_algo_clone = signing_algo.clone();
         let client_pub_key_clone = client_pub_key.clone();
         let model_pub_key_clone = model_pub_key.clone();
@@ -231,6 +243,7 @@ impl ports::ResponseServiceTrait for ResponseServiceImpl {
                 mcp_executor: None,
                 mcp_client_factory,
                 tool_registry,
+                cancellations,
             };
 
             if let Err(e) =
@@ -289,6 +302,45 @@ impl ports::ResponseServiceTrait for ResponseServiceImpl {
 
         Ok(Box::pin(rx))
     }
+
+    async fn cancel_response(
+        &self,
+        response_id: &str,
+        workspace_id: uuid::Uuid,
+    ) -> Result<models::ResponseObject, errors::ResponseError> {
+        let response_uuid = Uuid::parse_str(
+            response_id
+                .strip_prefix(crate::id_prefixes::PREFIX_RESP)
+                .unwrap_or(response_id),
+        )
+        .map_err(|_| errors::ResponseError::ResponseNotFound)?;
+
+        let response = self
+            .response_repository
+            .cancel(
+                models::ResponseId(response_uuid),
+                crate::workspace::WorkspaceId(workspace_id),
+            )
+            .await
+            .map_err(|e| {
+                errors::ResponseError::InternalError(format!("Failed to cancel response: {e}"))
+            })?
+            .ok_or(errors::ResponseError::ResponseNotFound)?;
+
+        if response.status != models::ResponseStatus::Cancelled {
+            return Err(errors::ResponseError::InvalidParams(
+                "Only in-progress responses can be cancelled".to_string(),
+            ));
+        }
+
+        let aborted = self.cancellations.cancel(response_uuid, workspace_id);
+        tracing::info!(
+            response_id = %response.id,
+            aborted_generation = aborted,
+            "Response cancelled"
+        );
+        Ok(response)
+    }
 }
 
 impl ResponseServiceImpl {
@@ -1014,6 +1066,9 @@ impl ResponseServiceImpl {
 
         // Extract response_id from the created response
         let response_id = Self::extract_response_uuid(&initial_response)?;
+        let cancel_guard = context
+            .cancellations
+            .register(response_id.0, context.workspace_id);
 
         // Extract conversation_id from the created response (may have been inherited from previous_response_id)
         let conversation_id = initial_response.conversation.as_ref().and_then(|conv_ref| {
@@ -1186,19 +1241,28 @@ impl ResponseServiceImpl {
         let mut final_response_text = String::new();
 
         // Run the agent loop to process completion and tool calls
-        // Capture errors but continue to save partial data if client disconnected
-        let agent_loop_result = Self::run_agent_loop(
-            &mut ctx,
-            &mut emitter,
-            &mut messages,
-            &mut final_response_text,
-            &mut context,
-            &tools,
-            &tool_choice,
-            max_iterations,
-            &mut iteration,
-        )
-        .await;
+        // Capture errors but continue to save partial data if client disconnected.
+        // A cancel drops the loop future, which drops the upstream completion
+        // stream and aborts the provider request.
+        let agent_loop_result = tokio::select! {
+            biased;
+            _ = cancel_guard.token().cancelled() => {
+                tracing::info!(response_id = %ctx.response_id_str, "Response cancelled; aborting generation");
+                Ok(AgentLoopResult::Cancelled)
+            }
+            result = Self::run_agent_loop(
+                &mut ctx,
+                &mut emitter,
+                &mut messages,
+                &mut final_response_text,
+                &mut context,
+                &tools,
+                &tool_choice,
+                max_iterations,
+                &mut iteration,
+            ) => result,
+        };
+        drop(cancel_guard);
 
         // Determine final response status based on agent loop result
         let (final_status, incomplete_details) = match &agent_loop_result {
@@ -1215,6 +1279,7 @@ impl ResponseServiceImpl {
                     reason: "function_call_required".to_string(),
                 }),
             ),
+            Ok(AgentLoopResult::Cancelled) => (models::ResponseStatus::Cancelled, None),
             Err(errors::ResponseError::Completion(_)) => (models::ResponseStatus::Failed, None),
             Err(ref e) => {
                 // Log error but continue - we want to save partial response even on disconnect
@@ -1364,6 +1429,13 @@ impl ResponseServiceImpl {
             }
         }
 
+        if final_response.status == models::ResponseStatus::Cancelled {
+            // Terminate promptly; the title task keeps running detached.
+            emitter.emit_cancelled(&mut ctx, final_response).await?;
+            tracing::info!("Response stream cancelled");
+            return Ok(());
+        }
+
         // Wait for title generation with a timeout (2 seconds)
         // This ensures the title event is sent before response.completed
         if let Some(handle) = title_task_handle {
@@ -1471,6 +1543,7 @@ impl ResponseServiceImpl {
                 body_hash: process_context.body_hash.to_string(),
                 response_id: Some(ctx.response_id.clone()),
                 skip_provider_chat_signature: false,
+                priority: crate::completions::ports::RequestPriority::Normal,
                 n: None,
                 extra,
             };
@@ -3122,6 +3195,7 @@ impl ResponseServiceImpl {
             body_hash: String::new(),
             response_id: None, // Title generation is not tied to a specific response
             skip_provider_chat_signature: false,
+            priority: crate::completions::ports::RequestPriority::Normal,
             n: None,
             extra: std::collections::HashMap::from([(
                 "chat_template_kwargs".to_string(),
@@ -242,6 +242,36 @@ impl EventEmitter {
         self.send(event).await
     }
 
+    /// Emit response.cancelled event (terminal event of a cancelled response)
+    pub async fn emit_cancelled(
+        &mut self,
+        ctx: &mut ResponseStreamContext,
+        response: models::ResponseObject,
+    ) -> Result<(), errors::ResponseError> {
+        let usage = response.usage.clone();
+        let event = models::ResponseStreamEvent {
+            event_type: "response.cancelled".to_string(),
+            sequence_number: Some(ctx.next_sequence()),
+            response: Some(response),
+            output_index: None,
+            content_index: None,
+            item: None,
+            item_id: None,
+            part: None,
+            delta: None,
+            text: None,
+            error: None,
+            status_code: None,
+            logprobs: None,
+            obfuscation: None,
+            annotation_index: None,
+            annotation: None,
+            conversation_title: None,
+            usage: Some(usage),
+        };
+        self.send(event).await
+    }
+
     /// Emit output_item.added event
     pub async fn emit_item_added(
         &mut self,
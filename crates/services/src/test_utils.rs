@@ -2,7 +2,7 @@
 use crate::{
     attestation::{
         ita::{ItaTokenQuery, ItaTokenResponse},
-        models::{AttestationReport, SignatureLookupResult},
+        models::{AttestationReport, ModelAttestationVerification, SignatureLookupResult},
         ports::AttestationServiceTrait,
         AttestationError,
     },
@@ -70,6 +70,16 @@ impl AttestationServiceTrait for MockAttestationService {
         ))
     }
 
+    async fn verify_model_attestation(
+        &self,
+        _model: String,
+        _signing_algo: Option<String>,
+    ) -> Result<ModelAttestationVerification, AttestationError> {
+        Err(AttestationError::InternalError(
+            "Not implemented".to_string(),
+        ))
+    }
+
     async fn get_ita_attestation_token(
         &self,
         _query: ItaTokenQuery,
@@ -291,6 +301,15 @@ impl UsageServiceTrait for MockUsageService {
         Ok((vec![], 0))
     }
 
+    async fn authorize_api_key_usage_access(
+        &self,
+        _workspace_id: Uuid,
+        _api_key_id: Uuid,
+        _user_id: Uuid,
+    ) -> Result<Uuid, UsageError> {
+        Ok(Uuid::nil())
+    }
+
     async fn get_api_key_usage_history_with_permissions(
         &self,
         _workspace_id: Uuid,
@@ -553,6 +572,15 @@ impl UsageServiceTrait for CapturingUsageService {
         Ok((vec![], 0))
     }
 
+    async fn authorize_api_key_usage_access(
+        &self,
+        _workspace_id: Uuid,
+        _api_key_id: Uuid,
+        _user_id: Uuid,
+    ) -> Result<Uuid, UsageError> {
+        Ok(Uuid::nil())
+    }
+
     async fn get_api_key_usage_history_with_permissions(
         &self,
         _workspace_id: Uuid,
@@ -0,0 +1,582 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+use config::UsageAnomalyConfig;
+use serde::Serialize;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use super::ports::{
+    HourlyOrganizationSpend, SingleInstanceGuard, SingleInstanceLock, UsageRepository,
+};
+use crate::metrics::consts::{get_environment, METRIC_USAGE_SPEND_ANOMALIES, TAG_ENVIRONMENT};
+use crate::metrics::MetricsServiceTrait;
+
+/// Timeout for a single anomaly webhook delivery.
+const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+
+/// Name of the fleet-wide lock that elects the instance running detection.
+pub const USAGE_ANOMALY_DETECTOR_LOCK: &str = "usage_anomaly_detector";
+
+/// An organization whose spend in `hour` exceeded its rolling baseline.
+/// Costs are in nano-dollars (scale 9).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpendAnomaly {
+    pub organization_id: Uuid,
+    pub hour: DateTime<Utc>,
+    pub spend: i64,
+    pub baseline_mean: f64,
+    pub baseline_stddev: f64,
+}
+
+/// Baseline rows (hours before `hour`) loaded while `hour` was current.
+struct CachedBaseline {
+    hour: DateTime<Utc>,
+    rows: Arc<Vec<HourlyOrganizationSpend>>,
+}
+
+/// Background task that flags organizations whose spend in the current hour
+/// is more than `stddev_threshold` standard deviations above the mean of
+/// their hourly spend over the preceding `lookback_hours`.
+///
+/// With a [`SingleInstanceLock`] configured, only the instance holding it
+/// runs detection, so each (organization, hour) is reported once fleet-wide
+/// unless the lock changes hands mid-hour. The baseline hours are aggregated
+/// once per hour; later ticks in the same hour only re-read the current hour.
+pub struct UsageAnomalyDetector {
+    repository: Arc<dyn UsageRepository>,
+    metrics_service: Arc<dyn MetricsServiceTrait>,
+    config: UsageAnomalyConfig,
+    http_client: reqwest::Client,
+    lock: Option<Arc<dyn SingleInstanceLock>>,
+    baseline: std::sync::Mutex<Option<CachedBaseline>>,
+    reported: std::sync::Mutex<HashSet<(Uuid, DateTime<Utc>)>>,
+    task_handle: tokio::sync::Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl UsageAnomalyDetector {
+    pub fn new(
+        repository: Arc<dyn UsageRepository>,
+        metrics_service: Arc<dyn MetricsServiceTrait>,
+        config: UsageAnomalyConfig,
+    ) -> Self {
+        Self {
+            repository,
+            metrics_service,
+            config,
+            http_client: reqwest::Client::new(),
+            lock: None,
+            baseline: Default::default(),
+            reported: Default::default(),
+            task_handle: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Only run the periodic task while holding `lock`.
+    pub fn with_single_instance_lock(mut self, lock: Arc<dyn SingleInstanceLock>) -> Self {
+        self.lock = Some(lock);
+        self
+    }
+
+    /// Start the periodic detection task. If `interval_secs` is 0, this is a
+    /// no-op (used by test servers, which drive `run_once` directly).
+    pub async fn start(self: Arc<Self>) {
+        let interval_secs = self.config.interval_secs;
+        if interval_secs == 0 {
+            info!("Usage anomaly detector disabled (interval is 0)");
+            return;
+        }
+
+        let handle = tokio::spawn({
+            let detector = self.clone();
+            async move {
+                let mut interval =
+                    tokio::time::interval(tokio::time::Duration::from_secs(interval_secs));
+                let mut guard = None;
+                loop {
+                    interval.tick().await;
+                    if !detector.hold_lock(&mut guard).await {
+                        continue;
+                    }
+                    if let Err(e) = detector.run_once(Utc::now()).await {
+                        error!(error = %e, "Usage anomaly detector tick failed");
+                    }
+                }
+            }
+        });
+
+        let mut task_handle = self.task_handle.lock().await;
+        *task_handle = Some(handle);
+        info!(
+            "Usage anomaly detector started with interval: {} seconds",
+            interval_secs
+        );
+    }
+
+    /// Cancel the background task.
+    pub async fn shutdown(&self) {
+        let mut task_handle = self.task_handle.lock().await;
+        if let Some(handle) = task_handle.take() {
+            handle.abort();
+            info!("Usage anomaly detector task cancelled");
+        }
+    }
+
+    /// Whether this instance may run the next tick: true without a lock,
+    /// otherwise keeps `guard` held, re-acquiring it once it is lost. The
+    /// guard is re-verified every tick, so a lock lost with a half-open
+    /// database session is noticed on the next pass.
+    async fn hold_lock(&self, guard: &mut Option<Box<dyn SingleInstanceGuard>>) -> bool {
+        let Some(lock) = &self.lock else {
+            return true;
+        };
+        if let Some(held) = guard.as_ref() {
+            if held.is_held().await {
+                return true;
+            }
+            warn!("Usage anomaly detector lock lost; trying to re-acquire it");
+            // Drop the stale guard first so its session is closed before a new
+            // one is opened.
+            *guard = None;
+        }
+        *guard = match lock.try_acquire().await {
+            Ok(acquired) => acquired,
+            Err(e) => {
+                error!(error = %e, "Failed to acquire usage anomaly detector lock");
+                None
+            }
+        };
+        if guard.is_some() {
+            info!("Usage anomaly detector lock acquired; this instance runs detection");
+        }
+        guard.is_some()
+    }
+
+    /// One detection pass as of `now`; returns the newly detected anomalies.
+    /// Public so tests (and operators) can drive it deterministically.
+    pub async fn run_once(&self, now: DateTime<Utc>) -> anyhow::Result<Vec<SpendAnomaly>> {
+        let current_hour = now.duration_trunc(TimeDelta::hours(1))?;
+        let cached_baseline = self
+            .baseline
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .as_ref()
+            .filter(|cached| cached.hour == current_hour)
+            .map(|cached| cached.rows.clone());
+        let rows = match cached_baseline {
+            Some(baseline) => {
+                let mut rows = baseline.as_ref().clone();
+                rows.extend(self.repository.get_hourly_spend_since(current_hour).await?);
+                rows
+            }
+            None => {
+                let since = current_hour - TimeDelta::hours(i64::from(self.config.lookback_hours));
+                let rows = self.repository.get_hourly_spend_since(since).await?;
+                let baseline = rows
+                    .iter()
+                    .filter(|row| row.hour < current_hour)
+                    .cloned()
+                    .collect();
+                *self
+                    .baseline
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(CachedBaseline {
+                    hour: current_hour,
+                    rows: Arc::new(baseline),
+                });
+                rows
+            }
+        };
+
+        let anomalies: Vec<SpendAnomaly> =
+            detect_spend_anomalies(&rows, current_hour, &self.config)
+                .into_iter()
+                .filter(|anomaly| {
+                    self.reported
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                        .insert((anomaly.organization_id, anomaly.hour))
+                })
+                .collect();
+        // Forget hours that can no longer be current.
+        self.reported
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .retain(|(_, hour)| *hour >= current_hour);
+
+        for anomaly in &anomalies {
+            warn!(
+                organization_id = %anomaly.organization_id,
+                hour = %anomaly.hour,
+                spend = anomaly.spend,
+                baseline_mean = anomaly.baseline_mean,
+                baseline_stddev = anomaly.baseline_stddev,
+                "Organization spend anomaly detected"
+            );
+            let environment_tag = format!("{TAG_ENVIRONMENT}:{}", get_environment());
+            self.metrics_service.record_count(
+                METRIC_USAGE_SPEND_ANOMALIES,
+                1,
+                &[environment_tag.as_str()],
+            );
+            if let Some(url) = &self.config.webhook_url {
+                self.send_webhook(url, anomaly).await;
+            }
+        }
+        Ok(anomalies)
+    }
+
+    async fn send_webhook(&self, url: &str, anomaly: &SpendAnomaly) {
+        let result = self
+            .http_client
+            .post(url)
+            .timeout(std::time::Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+            .json(anomaly)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status);
+        if let Err(e) = result {
+            error!(
+                organization_id = %anomaly.organization_id,
+                status = ?e.status(),
+                "Failed to deliver usage anomaly webhook"
+            );
+        }
+    }
+}
+
+/// Flag organizations whose spend in `current_hour` exceeds the mean of the
+/// preceding `lookback_hours` by more than `stddev_threshold` standard
+/// deviations. Hours without usage count as zero spend in the baseline.
+pub fn detect_spend_anomalies(
+    rows: &[HourlyOrganizationSpend],
+    current_hour: DateTime<Utc>,
+    config: &UsageAnomalyConfig,
+) -> Vec<SpendAnomaly> {
+    let baseline_start = current_hour - TimeDelta::hours(i64::from(config.lookback_hours));
+    let mut by_org: HashMap<Uuid, (i64, Vec<i64>)> = HashMap::new();
+    for row in rows {
+        let (current, baseline) = by_org.entry(row.organization_id).or_default();
+        if row.hour == current_hour {
+            *current += row.total_cost;
+        } else if row.hour >= baseline_start && row.hour < current_hour {
+            baseline.push(row.total_cost);
+        }
+    }
+
+    let hours = f64::from(config.lookback_hours);
+    let mut anomalies: Vec<SpendAnomaly> = by_org
+        .into_iter()
+        .filter(|(_, (current, baseline))| {
+            *current > 0 && baseline.len() >= config.min_baseline_hours as usize
+        })
+        .filter_map(|(organization_id, (current, baseline))| {
+            let mean = baseline.iter().map(|&cost| cost as f64).sum::<f64>() / hours;
+            let squared_deviations: f64 = baseline
+                .iter()
+                .map(|&cost| (cost as f64 - mean).powi(2))
+                .sum::<f64>()
+                + (hours - baseline.len() as f64) * mean.powi(2);
+            let stddev = (squared_deviations / hours).sqrt();
+            (current as f64 > mean + config.stddev_threshold * stddev).then_some(SpendAnomaly {
+                organization_id,
+                hour: current_hour,
+                spend: current,
+                baseline_mean: mean,
+                baseline_stddev: stddev,
+            })
+        })
+        .collect();
+    anomalies.sort_by_key(|anomaly| anomaly.organization_id);
+    anomalies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::capturing::CapturingMetricsService;
+
+    fn config() -> UsageAnomalyConfig {
+        UsageAnomalyConfig {
+            interval_secs: 0,
+            lookback_hours: 24,
+            stddev_threshold: 3.0,
+            min_baseline_hours: 12,
+            webhook_url: None,
+        }
+    }
+
+    fn steady_usage(
+        organization_id: Uuid,
+        current_hour: DateTime<Utc>,
+        hours: i64,
+    ) -> Vec<HourlyOrganizationSpend> {
+        (1..=hours)
+            .map(|offset| HourlyOrganizationSpend {
+                organization_id,
+                hour: current_hour - TimeDelta::hours(offset),
+                // Small jitter so the baseline has a non-zero deviation.
+                total_cost: 1_000_000 + (offset % 3) * 10_000,
+            })
+            .collect()
+    }
+
+    fn current(organization_id: Uuid, hour: DateTime<Utc>, cost: i64) -> HourlyOrganizationSpend {
+        HourlyOrganizationSpend {
+            organization_id,
+            hour,
+            total_cost: cost,
+        }
+    }
+
+    #[test]
+    fn flags_only_the_spiking_organization() {
+        let hour = Utc::now().duration_trunc(TimeDelta::hours(1)).unwrap();
+        let steady = Uuid::new_v4();
+        let spiking = Uuid::new_v4();
+        let mut rows = steady_usage(steady, hour, 24);
+        rows.extend(steady_usage(spiking, hour, 24));
+        rows.push(current(steady, hour, 1_010_000));
+        rows.push(current(spiking, hour, 50_000_000));
+
+        let anomalies = detect_spend_anomalies(&rows, hour, &config());
+
+        assert_eq!(anomalies.len(), 1, "{anomalies:?}");
+        assert_eq!(anomalies[0].organization_id, spiking);
+        assert_eq!(anomalies[0].spend, 50_000_000);
+        assert!(anomalies[0].baseline_mean > 1_000_000.0);
+    }
+
+    #[test]
+    fn skips_organizations_without_enough_history() {
+        let hour = Utc::now().duration_trunc(TimeDelta::hours(1)).unwrap();
+        let new_org = Uuid::new_v4();
+        let mut rows = steady_usage(new_org, hour, 3);
+        rows.push(current(new_org, hour, 50_000_000));
+
+        assert!(detect_spend_anomalies(&rows, hour, &config()).is_empty());
+    }
+
+    /// Serves fixed rows and records the `start_date` of every spend query.
+    struct FixedSpendRepository(
+        Vec<HourlyOrganizationSpend>,
+        std::sync::Mutex<Vec<DateTime<Utc>>>,
+    );
+
+    impl FixedSpendRepository {
+        fn new(rows: Vec<HourlyOrganizationSpend>) -> Self {
+            Self(rows, Default::default())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl crate::usage::ports::UsageRepository for FixedSpendRepository {
+        async fn record_usage(
+            &self,
+            _request: crate::usage::ports::RecordUsageDbRequest,
+        ) -> anyhow::Result<crate::usage::ports::UsageLogEntry> {
+            unimplemented!()
+        }
+        async fn get_balance(
+            &self,
+            _organization_id: Uuid,
+        ) -> anyhow::Result<Option<crate::usage::ports::OrganizationBalanceInfo>> {
+            unimplemented!()
+        }
+        async fn get_usage_history(
+            &self,
+            _organization_id: Uuid,
+            _limit: Option<i64>,
+            _offset: Option<i64>,
+        ) -> anyhow::Result<(Vec<crate::usage::ports::UsageLogEntry>, i64)> {
+            unimplemented!()
+        }
+        async fn get_usage_history_by_api_key(
+            &self,
+            _api_key_id: Uuid,
+            _limit: Option<i64>,
+            _offset: Option<i64>,
+        ) -> anyhow::Result<(Vec<crate::usage::ports::UsageLogEntry>, i64)> {
+            unimplemented!()
+        }
+        async fn get_api_key_spend(&self, _api_key_id: Uuid) -> anyhow::Result<i64> {
+            unimplemented!()
+        }
+        async fn get_costs_by_inference_ids(
+            &self,
+            _organization_id: Uuid,
+            _inference_ids: Vec<Uuid>,
+        ) -> anyhow::Result<Vec<crate::usage::ports::InferenceCost>> {
+            unimplemented!()
+        }
+        async fn get_stop_reason_by_response_id(
+            &self,
+            _response_id: Uuid,
+        ) -> anyhow::Result<Option<crate::usage::StopReason>> {
+            unimplemented!()
+        }
+        async fn get_stop_reason_by_provider_request_id(
+            &self,
+            _provider_request_id: &str,
+            _inference_ids: &[Uuid],
+        ) -> anyhow::Result<Option<crate::usage::StopReason>> {
+            unimplemented!()
+        }
+        async fn get_provider_request_id_by_inference_id(
+            &self,
+            _inference_id: Uuid,
+        ) -> anyhow::Result<Option<String>> {
+            unimplemented!()
+        }
+        async fn get_usage_by_model(
+            &self,
+            _organization_id: Uuid,
+            _start_date: DateTime<Utc>,
+        ) -> anyhow::Result<Vec<crate::usage::ports::UsageByModelEntry>> {
+            unimplemented!()
+        }
+        async fn get_hourly_spend_since(
+            &self,
+            start_date: DateTime<Utc>,
+        ) -> anyhow::Result<Vec<HourlyOrganizationSpend>> {
+            self.1.lock().unwrap().push(start_date);
+            Ok(self
+                .0
+                .iter()
+                .filter(|row| row.hour >= start_date)
+                .cloned()
+                .collect())
+        }
+        async fn list_inference_usage_report(
+            &self,
+            _query: crate::usage::ports::InferenceUsageReportQuery,
+        ) -> anyhow::Result<Vec<crate::usage::ports::InferenceUsageReportRow>> {
+            unimplemented!()
+        }
+        async fn list_inference_usage_history(
+            &self,
+            _query: crate::usage::ports::InferenceUsageHistoryQuery,
+        ) -> anyhow::Result<(Vec<crate::usage::ports::InferenceUsageReportRow>, i64)> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn run_once_emits_metric_for_spike_and_reports_it_once() {
+        let now = Utc::now();
+        let hour = now.duration_trunc(TimeDelta::hours(1)).unwrap();
+        let organization_id = Uuid::new_v4();
+        let mut rows = steady_usage(organization_id, hour, 24);
+        let steady = Arc::new(FixedSpendRepository::new(rows.clone()));
+        rows.push(current(organization_id, hour, 50_000_000));
+        let spiking = Arc::new(FixedSpendRepository::new(rows));
+
+        let metrics = Arc::new(CapturingMetricsService::new());
+        let detector = UsageAnomalyDetector::new(steady, metrics.clone(), config());
+        assert!(detector.run_once(now).await.unwrap().is_empty());
+        assert!(metrics.get_metrics().is_empty());
+
+        let detector = UsageAnomalyDetector::new(spiking, metrics.clone(), config());
+        let anomalies = detector.run_once(now).await.unwrap();
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].organization_id, organization_id);
+        // The same hour is not reported again on the next pass.
+        assert!(detector.run_once(now).await.unwrap().is_empty());
+
+        let metrics = metrics.get_metrics();
+        assert_eq!(metrics.len(), 1, "{metrics:?}");
+        assert_eq!(metrics[0].name, METRIC_USAGE_SPEND_ANOMALIES);
+    }
+
+    #[tokio::test]
+    async fn baseline_is_aggregated_once_per_hour() {
+        let now = Utc::now().duration_trunc(TimeDelta::hours(1)).unwrap();
+        let organization_id = Uuid::new_v4();
+        let mut rows = steady_usage(organization_id, now, 24);
+        rows.push(current(organization_id, now, 50_000_000));
+        let repository = Arc::new(FixedSpendRepository::new(rows));
+        let detector = UsageAnomalyDetector::new(
+            repository.clone(),
+            Arc::new(CapturingMetricsService::new()),
+            config(),
+        );
+
+        assert_eq!(detector.run_once(now).await.unwrap().len(), 1);
+        detector
+            .run_once(now + TimeDelta::minutes(5))
+            .await
+            .unwrap();
+        detector.run_once(now + TimeDelta::hours(1)).await.unwrap();
+
+        assert_eq!(
+            *repository.1.lock().unwrap(),
+            vec![
+                now - TimeDelta::hours(24),
+                now,
+                now + TimeDelta::hours(1) - TimeDelta::hours(24),
+            ]
+        );
+    }
+
+    /// Lock that is either free (handing out guards) or held elsewhere.
+    struct FakeLock {
+        free: bool,
+        held: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    struct FakeGuard(Arc<std::sync::atomic::AtomicBool>);
+
+    #[async_trait::async_trait]
+    impl SingleInstanceGuard for FakeGuard {
+        async fn is_held(&self) -> bool {
+            self.0.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl SingleInstanceLock for FakeLock {
+        async fn try_acquire(&self) -> anyhow::Result<Option<Box<dyn SingleInstanceGuard>>> {
+            if !self.free {
+                return Ok(None);
+            }
+            self.held.store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok(Some(Box::new(FakeGuard(self.held.clone()))))
+        }
+    }
+
+    fn detector_with_lock(
+        free: bool,
+    ) -> (UsageAnomalyDetector, Arc<std::sync::atomic::AtomicBool>) {
+        let held = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let detector = UsageAnomalyDetector::new(
+            Arc::new(FixedSpendRepository::new(Vec::new())),
+            Arc::new(CapturingMetricsService::new()),
+            config(),
+        )
+        .with_single_instance_lock(Arc::new(FakeLock {
+            free,
+            held: held.clone(),
+        }));
+        (detector, held)
+    }
+
+    #[tokio::test]
+    async fn only_the_lock_holder_runs_detection() {
+        let (detector, _) = detector_with_lock(false);
+        let mut guard = None;
+        assert!(!detector.hold_lock(&mut guard).await);
+        assert!(guard.is_none());
+
+        let (detector, held) = detector_with_lock(true);
+        let mut guard = None;
+        assert!(detector.hold_lock(&mut guard).await);
+        assert!(detector.hold_lock(&mut guard).await);
+
+        // A lost lock is re-acquired on the next tick.
+        held.store(false, std::sync::atomic::Ordering::SeqCst);
+        assert!(!guard.as_ref().unwrap().is_held().await);
+        assert!(detector.hold_lock(&mut guard).await);
+        assert!(guard.as_ref().unwrap().is_held().await);
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+/// Currency every spend limit and usage cost is compared in.
+pub const BASE_CURRENCY: &str = "USD";
+
+const MICROS_PER_UNIT: i128 = 1_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CurrencyError {
+    #[error("Unsupported currency '{currency}'; supported currencies: {}", .supported.join(", "))]
+    Unsupported {
+        currency: String,
+        supported: Vec<String>,
+    },
+    #[error("Amount in {0} is too large to convert to USD")]
+    Overflow(String),
+}
+
+/// Static exchange-rate table used to read spend limits denominated in a
+/// currency other than USD. Amounts keep the fixed scale 9 in every currency.
+#[derive(Debug, Clone, Default)]
+pub struct CurrencyRates {
+    usd_rates_micros: HashMap<String, i64>,
+}
+
+impl CurrencyRates {
+    pub fn new(usd_rates_micros: HashMap<String, i64>) -> Self {
+        Self { usd_rates_micros }
+    }
+
+    pub fn from_config(config: &config::CurrencyConfig) -> Self {
+        Self::new(config.usd_rates_micros.clone())
+    }
+
+    /// Supported currency codes, USD first and the rest alphabetically.
+    pub fn supported(&self) -> Vec<String> {
+        let mut others: Vec<String> = self.usd_rates_micros.keys().cloned().collect();
+        others.sort();
+        std::iter::once(BASE_CURRENCY.to_string())
+            .chain(others)
+            .collect()
+    }
+
+    /// Normalize `currency` to its canonical uppercase code, rejecting codes
+    /// with no configured rate.
+    pub fn validate(&self, currency: &str) -> Result<String, CurrencyError> {
+        let code = currency.trim().to_ascii_uppercase();
+        if code == BASE_CURRENCY || self.usd_rates_micros.contains_key(&code) {
+            Ok(code)
+        } else {
+            Err(CurrencyError::Unsupported {
+                currency: currency.to_string(),
+                supported: self.supported(),
+            })
+        }
+    }
+
+    /// Convert a scale-9 `amount` in `currency` to USD nano-dollars, rounding
+    /// toward zero.
+    pub fn to_usd_nanos(&self, amount: i64, currency: &str) -> Result<i64, CurrencyError> {
+        let code = self.validate(currency)?;
+        if code == BASE_CURRENCY {
+            return Ok(amount);
+        }
+        let rate = self.usd_rates_micros[&code];
+        i64::try_from(i128::from(amount) * i128::from(rate) / MICROS_PER_UNIT)
+            .map_err(|_| CurrencyError::Overflow(code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rates() -> CurrencyRates {
+        CurrencyRates::new(HashMap::from([
+            ("EUR".to_string(), 1_080_000),
+            ("USDT".to_string(), 1_000_000),
+        ]))
+    }
+
+    #[test]
+    fn usd_is_always_accepted_unchanged() {
+        assert_eq!(CurrencyRates::default().to_usd_nanos(5, "usd"), Ok(5));
+        assert_eq!(
+            rates().to_usd_nanos(1_000_000_000, "USD"),
+            Ok(1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn configured_currency_is_converted() {
+        assert_eq!(
+            rates().to_usd_nanos(1_000_000_000, "eur"),
+            Ok(1_080_000_000)
+        );
+        assert_eq!(rates().to_usd_nanos(7, "USDT"), Ok(7));
+        assert_eq!(rates().validate(" eur "), Ok("EUR".to_string()));
+    }
+
+    #[test]
+    fn unknown_currency_is_rejected_with_supported_list() {
+        let err = rates().to_usd_nanos(1, "XYZ").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unsupported currency 'XYZ'; supported currencies: USD, EUR, USDT"
+        );
+    }
+
+    #[test]
+    fn conversion_overflow_is_reported() {
+        let rates = CurrencyRates::new(HashMap::from([("GBP".to_string(), 2_000_000)]));
+        assert_eq!(
+            rates.to_usd_nanos(i64::MAX, "GBP"),
+            Err(CurrencyError::Overflow("GBP".to_string()))
+        );
+    }
+}
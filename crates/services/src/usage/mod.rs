@@ -1,3 +1,5 @@
+pub mod anomaly;
+pub mod currency;
 pub mod ports;
 pub mod provider_attribution;
 pub mod reporting;
@@ -10,23 +12,21 @@ use crate::metrics::{
     },
     MetricsServiceTrait,
 };
+use config::EXTERNAL_USAGE_ID_NAMESPACE;
+pub use currency::{CurrencyError, CurrencyRates};
+use moka::future::Cache;
 pub use ports::*;
 pub use provider_attribution::*;
 pub use reporting::*;
 use std::sync::Arc;
+use std::time::Duration;
 use uuid::Uuid;
 
-/// Dedicated UUID v5 namespace for `inference_id`s derived from external `id`s
-/// submitted via `POST /v1/internal/usage`. The internal inference pipeline hashes
-/// provider request ids under `Uuid::NAMESPACE_DNS`; using a different
-/// namespace UUID here makes the two `inference_id` spaces mathematically
-/// disjoint regardless of input — no shape of provider id (even one that
-/// happens to mimic a previous string-prefix scheme) can ever collide with
-/// an externally-submitted record's hash.
-///
-/// Generated once via `uuid5(NAMESPACE_DNS, "external-usage.cloud-api.near.ai")`
-/// and embedded as a constant so the value never drifts.
-const EXTERNAL_USAGE_ID_NAMESPACE: Uuid = Uuid::from_u128(0x1966acaf_9f95_5dab_b8f0_03a51c091314);
+/// TTL for the per-organization quota lists behind `check_model_quota`.
+/// Writes through this service invalidate the local entry; other instances
+/// pick changes up on expiry.
+const MODEL_QUOTAS_CACHE_TTL_SECS: u64 = 60;
+const MODEL_QUOTAS_CACHE_CAPACITY: u64 = 10_000;
 
 /// Compute token-based cost with cache-aware input pricing for token-based chat-style models.
 ///
@@ -129,6 +129,12 @@ pub struct UsageServiceImpl {
     limits_repository: Arc<dyn OrganizationLimitsRepository>,
     workspace_service: Arc<dyn crate::workspace::WorkspaceServiceTrait>,
     metrics_service: Arc<dyn MetricsServiceTrait>,
+    currency_rates: CurrencyRates,
+    /// Storage for per-model token quotas; `None` disables them.
+    model_quota_repository: Option<Arc<dyn ModelQuotaRepository>>,
+    /// Every quota an organization has, so a request for a model without a
+    /// quota needs no database round trip.
+    model_quotas_cache: Cache<Uuid, Arc<Vec<ModelQuota>>>,
 }
 
 impl UsageServiceImpl {
@@ -145,8 +151,72 @@ impl UsageServiceImpl {
             limits_repository,
             workspace_service,
             metrics_service,
+            currency_rates: CurrencyRates::default(),
+            model_quota_repository: None,
+            model_quotas_cache: Cache::builder()
+                .max_capacity(MODEL_QUOTAS_CACHE_CAPACITY)
+                .time_to_live(Duration::from_secs(MODEL_QUOTAS_CACHE_TTL_SECS))
+                .build(),
         }
     }
+
+    /// Exchange rates used to read spend limits stored in a currency other than USD.
+    pub fn with_currency_rates(mut self, currency_rates: CurrencyRates) -> Self {
+        self.currency_rates = currency_rates;
+        self
+    }
+
+    /// Enforce per-model token quotas stored in `repository`.
+    pub fn with_model_quota_repository(
+        mut self,
+        repository: Arc<dyn ModelQuotaRepository>,
+    ) -> Self {
+        self.model_quota_repository = Some(repository);
+        self
+    }
+
+    fn model_quota_repository(&self) -> Result<&Arc<dyn ModelQuotaRepository>, UsageError> {
+        self.model_quota_repository
+            .as_ref()
+            .ok_or_else(|| UsageError::InternalError("Model quotas are not configured".to_string()))
+    }
+
+    /// Sum the organization's active credit rows in USD nano-dollars.
+    ///
+    /// A row in a currency that has no configured rate (a legacy row, or one
+    /// whose rate was removed after it was written) is counted at face value
+    /// as USD with a warning, so a config gap never locks the organization out.
+    async fn current_usd_limit(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Option<OrganizationLimit>, UsageError> {
+        let credits = self
+            .limits_repository
+            .get_current_limit_breakdown(organization_id)
+            .await
+            .map_err(|e| UsageError::InternalError(format!("Failed to get limits: {e}")))?;
+        if credits.is_empty() {
+            return Ok(None);
+        }
+
+        let mut spend_limit: i64 = 0;
+        for credit in &credits {
+            let amount = self
+                .currency_rates
+                .to_usd_nanos(credit.amount, &credit.currency)
+                .unwrap_or_else(|e| {
+                    tracing::warn!(
+                        %organization_id,
+                        credit_type = %credit.credit_type,
+                        error = %e,
+                        "Spend limit is in a currency with no configured rate; counting it as USD"
+                    );
+                    credit.amount
+                });
+            spend_limit = spend_limit.saturating_add(amount);
+        }
+        Ok(Some(OrganizationLimit { spend_limit }))
+    }
 }
 
 #[async_trait::async_trait]
@@ -288,6 +358,7 @@ impl UsageServiceTrait for UsageServiceImpl {
             response_id: request.response_id,
             image_count: request.image_count,
             provider_attribution: request.provider_attribution,
+            estimated: request.estimated,
         };
 
         // Record in database
@@ -524,8 +595,8 @@ impl UsageServiceTrait for UsageServiceImpl {
         // The raw value is stored verbatim as `provider_request_id`, but
         // hashed under a dedicated UUID v5 namespace into `inference_id` so
         // externally-submitted records live in a disjoint id space from the
-        // internal pipeline (which hashes the raw provider id under
-        // `NAMESPACE_DNS`). Idempotency within this endpoint is preserved
+        // internal pipeline (which hashes the raw provider id under its
+        // configured namespace). Idempotency within this endpoint is preserved
         // because v5 is deterministic for a given (namespace, name) pair.
         let provider_request_id = Some(external_id.clone());
         let inference_id = Some(Uuid::new_v5(
@@ -553,6 +624,7 @@ impl UsageServiceTrait for UsageServiceImpl {
             response_id: None,
             image_count,
             provider_attribution,
+            estimated: false,
         };
 
         self.record_usage(service_request).await
@@ -570,12 +642,8 @@ impl UsageServiceTrait for UsageServiceImpl {
             .await
             .map_err(|e| UsageError::InternalError(format!("Failed to get balance: {e}")))?;
 
-        // Get current limits
-        let limit = self
-            .limits_repository
-            .get_current_limits(organization_id)
-            .await
-            .map_err(|e| UsageError::InternalError(format!("Failed to get limits: {e}")))?;
+        // Get current limits, converted to USD
+        let limit = self.current_usd_limit(organization_id).await?;
 
         match (balance, limit) {
             (Some(balance), Some(limit)) => {
@@ -658,13 +726,7 @@ impl UsageServiceTrait for UsageServiceImpl {
         &self,
         organization_id: Uuid,
     ) -> Result<Option<OrganizationLimit>, UsageError> {
-        let limit = self
-            .limits_repository
-            .get_current_limits(organization_id)
-            .await
-            .map_err(|e| UsageError::InternalError(format!("Failed to get limits: {e}")))?;
-
-        Ok(limit)
+        self.current_usd_limit(organization_id).await
     }
 
     async fn get_credit_limits(
@@ -695,15 +757,14 @@ impl UsageServiceTrait for UsageServiceImpl {
         Ok((logs, total))
     }
 
-    /// Get usage history for a specific API key with permission checking
-    async fn get_api_key_usage_history_with_permissions(
+    /// Check the user may manage the workspace's API keys and that the key
+    /// belongs to the workspace; returns the workspace's organization ID.
+    async fn authorize_api_key_usage_access(
         &self,
         workspace_id: Uuid,
         api_key_id: Uuid,
         user_id: Uuid,
-        limit: Option<i64>,
-        offset: Option<i64>,
-    ) -> Result<(Vec<UsageLogEntry>, i64), UsageError> {
+    ) -> Result<Uuid, UsageError> {
         // Check if the user has permission to access this workspace's API keys
         let can_access = self
             .workspace_service
@@ -736,6 +797,30 @@ impl UsageServiceTrait for UsageServiceImpl {
                 UsageError::NotFound("API key not found in this workspace".to_string())
             })?;
 
+        let workspace = self
+            .workspace_service
+            .get_workspace(
+                crate::workspace::WorkspaceId(workspace_id),
+                crate::auth::UserId(user_id),
+            )
+            .await
+            .map_err(|e| UsageError::InternalError(format!("Failed to get workspace: {e}")))?;
+
+        Ok(workspace.organization_id.0)
+    }
+
+    /// Get usage history for a specific API key with permission checking
+    async fn get_api_key_usage_history_with_permissions(
+        &self,
+        workspace_id: Uuid,
+        api_key_id: Uuid,
+        user_id: Uuid,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<(Vec<UsageLogEntry>, i64), UsageError> {
+        self.authorize_api_key_usage_access(workspace_id, api_key_id, user_id)
+            .await?;
+
         // Get the usage history
         let (logs, total) = self
             .usage_repository
@@ -812,11 +897,105 @@ impl UsageServiceTrait for UsageServiceImpl {
                 UsageError::InternalError(format!("Failed to list inference usage history: {e}"))
             })
     }
+
+    /// Usage is counted from the usage log, so a request already in flight
+    /// when the quota is reached can still push usage past it. The
+    /// organization's quotas are cached; usage is only summed for a model
+    /// that has one.
+    async fn check_model_quota(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+    ) -> Result<ModelQuotaCheckResult, UsageError> {
+        let Some(repo) = self.model_quota_repository.clone() else {
+            return Ok(ModelQuotaCheckResult::Allowed);
+        };
+        let quotas = self
+            .model_quotas_cache
+            .try_get_with(organization_id, {
+                let repo = repo.clone();
+                async move { repo.list_quotas(organization_id).await.map(Arc::new) }
+            })
+            .await
+            .map_err(|e| UsageError::InternalError(format!("Failed to get model quotas: {e}")))?;
+
+        let now = chrono::Utc::now();
+        for quota in quotas.iter().filter(|q| q.model_name == model_name) {
+            let used_tokens = repo
+                .get_model_tokens_since(organization_id, model_name, quota.period.start(now))
+                .await
+                .map_err(|e| {
+                    UsageError::InternalError(format!("Failed to get model token usage: {e}"))
+                })?;
+            if used_tokens >= quota.token_limit {
+                return Ok(ModelQuotaCheckResult::Exceeded {
+                    period: quota.period,
+                    used_tokens,
+                    token_limit: quota.token_limit,
+                    reset_at: quota.period.next_start(now),
+                });
+            }
+        }
+        Ok(ModelQuotaCheckResult::Allowed)
+    }
+
+    async fn list_model_quotas(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<ModelQuota>, UsageError> {
+        self.model_quota_repository()?
+            .list_quotas(organization_id)
+            .await
+            .map_err(|e| UsageError::InternalError(format!("Failed to list model quotas: {e}")))
+    }
+
+    async fn set_model_quota(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+        period: ModelQuotaPeriod,
+        token_limit: i64,
+    ) -> Result<ModelQuota, UsageError> {
+        if token_limit <= 0 {
+            return Err(UsageError::ValidationError(
+                "token_limit must be positive".to_string(),
+            ));
+        }
+        let quota = self
+            .model_quota_repository()?
+            .upsert_quota(organization_id, model_name, period, token_limit)
+            .await
+            .map_err(|e| UsageError::InternalError(format!("Failed to save model quota: {e}")))?
+            .ok_or_else(|| UsageError::ModelNotFound(format!("Model '{model_name}' not found")))?;
+        self.model_quotas_cache.invalidate(&organization_id).await;
+        Ok(quota)
+    }
+
+    async fn delete_model_quota(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+        period: ModelQuotaPeriod,
+    ) -> Result<(), UsageError> {
+        let deleted = self
+            .model_quota_repository()?
+            .delete_quota(organization_id, model_name, period)
+            .await
+            .map_err(|e| UsageError::InternalError(format!("Failed to delete model quota: {e}")))?;
+        self.model_quotas_cache.invalidate(&organization_id).await;
+        if deleted {
+            Ok(())
+        } else {
+            Err(UsageError::NotFound(format!(
+                "No {period} quota set for model '{model_name}'"
+            )))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{compute_token_cost, CostBreakdown, ModelPricing, UsageError};
+    use super::{compute_token_cost, CostBreakdown, ModelPricing, ModelQuotaPeriod, UsageError};
     use uuid::Uuid;
 
     fn make_pricing(
@@ -1002,7 +1181,7 @@ mod tests {
     /// the namespace each side uses today.
     #[test]
     fn test_external_id_hash_cannot_collide_with_internal_pipeline() {
-        use crate::completions::hash_inference_id_to_uuid;
+        use crate::completions::InferenceIdNamespace;
         use uuid::Uuid;
 
         // Real provider id formats observed in production responses, plus
@@ -1022,8 +1201,13 @@ mod tests {
         ];
 
         for raw_id in provider_ids {
-            // Internal pipeline: hash_inference_id_to_uuid (NAMESPACE_DNS).
-            let internal_uuid = hash_inference_id_to_uuid(raw_id);
+            // Internal pipeline: the default InferenceIdNamespace, plus the
+            // legacy NAMESPACE_DNS rows still looked up.
+            let internal_uuid = InferenceIdNamespace::default().hash(raw_id);
+            let legacy_uuid = Uuid::new_v5(
+                &crate::completions::LEGACY_INFERENCE_ID_NAMESPACE,
+                raw_id.as_bytes(),
+            );
             // External submission: dedicated namespace UUID.
             let external_uuid =
                 Uuid::new_v5(&super::EXTERNAL_USAGE_ID_NAMESPACE, raw_id.as_bytes());
@@ -1032,13 +1216,14 @@ mod tests {
                 "external POST /v1/internal/usage with id={raw_id:?} must hash to a different \
                  inference_id than the internal pipeline writes for the same provider id"
             );
+            assert_ne!(legacy_uuid, external_uuid, "legacy id={raw_id:?}");
         }
 
         // Cross-direction regression: an external submission of `"foo"`
         // must not collide with an internal pipeline record whose raw
         // provider id is `"api:foo"` (the shape that would have broken
         // the earlier string-prefix scheme).
-        let internal_with_legacy_prefix = hash_inference_id_to_uuid("api:foo");
+        let internal_with_legacy_prefix = InferenceIdNamespace::default().hash("api:foo");
         let external_plain = Uuid::new_v5(&super::EXTERNAL_USAGE_ID_NAMESPACE, b"foo");
         assert_ne!(
             internal_with_legacy_prefix, external_plain,
@@ -1057,4 +1242,30 @@ mod tests {
         );
         assert_eq!(dup_a, dup_b);
     }
+
+    #[test]
+    fn model_quota_periods_reset_at_utc_day_and_month_boundaries() {
+        use chrono::TimeZone;
+        let now = chrono::Utc
+            .with_ymd_and_hms(2026, 12, 31, 17, 45, 0)
+            .unwrap();
+
+        assert_eq!(
+            ModelQuotaPeriod::Day.start(now),
+            chrono::Utc.with_ymd_and_hms(2026, 12, 31, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            ModelQuotaPeriod::Day.next_start(now),
+            chrono::Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            ModelQuotaPeriod::Month.start(now),
+            chrono::Utc.with_ymd_and_hms(2026, 12, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            ModelQuotaPeriod::Month.next_start(now),
+            chrono::Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(ModelQuotaPeriod::parse("week"), None);
+    }
 }
@@ -4,7 +4,7 @@ pub use crate::usage::reporting::{
     InferenceUsageHistoryQuery, InferenceUsageReportCursor, InferenceUsageReportQuery,
     InferenceUsageReportRow,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -151,6 +151,7 @@ impl StopReason {
             inference_providers::FinishReason::Length => StopReason::Length,
             inference_providers::FinishReason::ContentFilter => StopReason::ContentFilter,
             inference_providers::FinishReason::ToolCalls => StopReason::ToolCalls,
+            inference_providers::FinishReason::Other(reason) => StopReason::Other(reason.clone()),
         }
     }
 
@@ -177,12 +178,16 @@ impl StopReason {
             }
             inference_providers::CompletionError::InvalidResponse(_) => StopReason::ProviderError,
             inference_providers::CompletionError::NoPubKeyProvider(_) => StopReason::ProviderError,
+            inference_providers::CompletionError::ModelWarming(_) => StopReason::ProviderError,
             inference_providers::CompletionError::Unknown(_) => StopReason::ProviderError,
             // Internal stop-reason label only (not the client-facing status,
             // which is a 400). Grouped with the other non-timeout/non-ratelimit
             // error variants, matching the siblings above.
             inference_providers::CompletionError::ClientMediaError(_) => StopReason::ProviderError,
             inference_providers::CompletionError::Timeout { .. } => StopReason::Timeout,
+            inference_providers::CompletionError::AllProvidersFailed { last_error, .. } => {
+                Self::from_completion_error(last_error)
+            }
         }
     }
 }
@@ -272,6 +277,15 @@ pub trait UsageServiceTrait: Send + Sync {
         offset: Option<i64>,
     ) -> Result<(Vec<UsageLogEntry>, i64), UsageError>;
 
+    /// Verify the user may read usage for an API key in a workspace (and that
+    /// the key belongs to it). Returns the owning organization's ID.
+    async fn authorize_api_key_usage_access(
+        &self,
+        workspace_id: Uuid,
+        api_key_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Uuid, UsageError>;
+
     /// Get usage history for a specific API key with permission checking
     /// This method verifies the user has access to the workspace and that the API key exists
     /// Returns a tuple of (entries, total_count)
@@ -309,6 +323,49 @@ pub trait UsageServiceTrait: Send + Sync {
         &self,
         query: InferenceUsageHistoryQuery,
     ) -> Result<(Vec<InferenceUsageReportRow>, i64), UsageError>;
+
+    /// Check the organization's per-model token quotas for `model_name`
+    /// (canonical name) against its usage in the current periods.
+    async fn check_model_quota(
+        &self,
+        _organization_id: Uuid,
+        _model_name: &str,
+    ) -> Result<ModelQuotaCheckResult, UsageError> {
+        Ok(ModelQuotaCheckResult::Allowed)
+    }
+
+    /// List the organization's per-model token quotas
+    async fn list_model_quotas(
+        &self,
+        _organization_id: Uuid,
+    ) -> Result<Vec<ModelQuota>, UsageError> {
+        Ok(Vec::new())
+    }
+
+    /// Create or replace the quota for a model and period
+    async fn set_model_quota(
+        &self,
+        _organization_id: Uuid,
+        _model_name: &str,
+        _period: ModelQuotaPeriod,
+        _token_limit: i64,
+    ) -> Result<ModelQuota, UsageError> {
+        Err(UsageError::InternalError(
+            "Model quotas are not supported".to_string(),
+        ))
+    }
+
+    /// Delete the quota for a model and period
+    async fn delete_model_quota(
+        &self,
+        _organization_id: Uuid,
+        _model_name: &str,
+        _period: ModelQuotaPeriod,
+    ) -> Result<(), UsageError> {
+        Err(UsageError::InternalError(
+            "Model quotas are not supported".to_string(),
+        ))
+    }
 }
 
 // ============================================
@@ -363,12 +420,23 @@ pub trait UsageRepository: Send + Sync {
     ) -> anyhow::Result<Option<StopReason>>;
 
     /// Get the stop reason for a specific provider request ID (e.g., chatcmpl-xxx)
-    /// Used to check if a chat completion was stopped due to client disconnect
+    /// Used to check if a chat completion was stopped due to client disconnect.
+    /// Rows recorded without a provider request ID are matched by any of
+    /// `inference_ids` (its hash under each namespace still being looked up).
     async fn get_stop_reason_by_provider_request_id(
         &self,
         provider_request_id: &str,
+        inference_ids: &[Uuid],
     ) -> anyhow::Result<Option<StopReason>>;
 
+    /// Resolve a hashed `inference_id` back to the provider request ID (e.g.,
+    /// chatcmpl-xxx) recorded alongside it in the usage log.
+    /// Used to look up chat signatures for clients that only kept the UUID.
+    async fn get_provider_request_id_by_inference_id(
+        &self,
+        inference_id: Uuid,
+    ) -> anyhow::Result<Option<String>>;
+
     /// Get per-model usage aggregation for an organization since `start_date`.
     async fn get_usage_by_model(
         &self,
@@ -376,6 +444,13 @@ pub trait UsageRepository: Send + Sync {
         start_date: DateTime<Utc>,
     ) -> anyhow::Result<Vec<UsageByModelEntry>>;
 
+    /// Get spend per organization per clock hour since `start_date`. Hours
+    /// without usage are omitted.
+    async fn get_hourly_spend_since(
+        &self,
+        start_date: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<HourlyOrganizationSpend>>;
+
     async fn list_inference_usage_report(
         &self,
         query: InferenceUsageReportQuery,
@@ -398,12 +473,6 @@ pub trait ModelRepository: Send + Sync {
 
 #[async_trait::async_trait]
 pub trait OrganizationLimitsRepository: Send + Sync {
-    /// Get current limits for an organization
-    async fn get_current_limits(
-        &self,
-        organization_id: Uuid,
-    ) -> anyhow::Result<Option<OrganizationLimit>>;
-
     /// Get current limit rows for an organization.
     async fn get_current_limit_breakdown(
         &self,
@@ -411,6 +480,38 @@ pub trait OrganizationLimitsRepository: Send + Sync {
     ) -> anyhow::Result<Vec<OrganizationCreditLimit>>;
 }
 
+#[async_trait::async_trait]
+pub trait ModelQuotaRepository: Send + Sync {
+    /// All quotas for an organization, ordered by model name then period.
+    async fn list_quotas(&self, organization_id: Uuid) -> anyhow::Result<Vec<ModelQuota>>;
+
+    /// Tokens the organization has used on `model_name` since `since`.
+    async fn get_model_tokens_since(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<i64>;
+
+    /// Create or replace the quota for the active model named `model_name`.
+    /// Returns None if no such model exists.
+    async fn upsert_quota(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+        period: ModelQuotaPeriod,
+        token_limit: i64,
+    ) -> anyhow::Result<Option<ModelQuota>>;
+
+    /// Delete a quota. Returns false if the organization had no such quota.
+    async fn delete_quota(
+        &self,
+        organization_id: Uuid,
+        model_name: &str,
+        period: ModelQuotaPeriod,
+    ) -> anyhow::Result<bool>;
+}
+
 // ============================================
 // Service Data Structures
 // ============================================
@@ -525,6 +626,9 @@ pub struct RecordUsageServiceRequest {
     /// Number of images generated (for image generation requests)
     pub image_count: Option<i32>,
     pub provider_attribution: ProviderAttribution,
+    /// Token counts were estimated by the gateway because the provider never
+    /// reported usage
+    pub estimated: bool,
 }
 
 /// Request to record usage (database layer)
@@ -558,6 +662,9 @@ pub struct RecordUsageDbRequest {
     /// Number of images generated (for image generation requests)
     pub image_count: Option<i32>,
     pub provider_attribution: ProviderAttribution,
+    /// Token counts were estimated by the gateway because the provider never
+    /// reported usage
+    pub estimated: bool,
 }
 
 /// Model pricing information
@@ -619,6 +726,87 @@ pub enum UsageCheckResult {
     NoLimitSet, // No spending limit configured - must set limit
 }
 
+/// UTC window over which a model quota counts tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelQuotaPeriod {
+    Day,
+    Month,
+}
+
+impl ModelQuotaPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModelQuotaPeriod::Day => "day",
+            ModelQuotaPeriod::Month => "month",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "day" => Some(ModelQuotaPeriod::Day),
+            "month" => Some(ModelQuotaPeriod::Month),
+            _ => None,
+        }
+    }
+
+    /// Start of the period containing `now`.
+    pub fn start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let day = now
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is always a valid time")
+            .and_utc();
+        match self {
+            ModelQuotaPeriod::Day => day,
+            ModelQuotaPeriod::Month => day
+                .with_day(1)
+                .expect("the first of the month is always a valid date"),
+        }
+    }
+
+    /// Start of the period after the one containing `now`; the quota resets here.
+    pub fn next_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let start = self.start(now);
+        match self {
+            ModelQuotaPeriod::Day => start + chrono::Duration::days(1),
+            ModelQuotaPeriod::Month => start
+                .checked_add_months(chrono::Months::new(1))
+                .expect("the next month start is always representable"),
+        }
+    }
+}
+
+impl std::fmt::Display for ModelQuotaPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A cap on the tokens an organization may use on one model per period
+#[derive(Debug, Clone)]
+pub struct ModelQuota {
+    pub organization_id: Uuid,
+    /// Canonical model name
+    pub model_name: String,
+    pub period: ModelQuotaPeriod,
+    pub token_limit: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Result of checking an organization's quotas for one model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelQuotaCheckResult {
+    Allowed,
+    Exceeded {
+        period: ModelQuotaPeriod,
+        used_tokens: i64,
+        token_limit: i64,
+        reset_at: DateTime<Utc>,
+    },
+}
+
 /// Organization balance information
 /// All amounts use fixed scale of 9 (nano-dollars) and USD currency
 #[derive(Debug, Clone)]
@@ -643,6 +831,32 @@ pub struct UsageByModelEntry {
     pub request_count: i64,
 }
 
+/// An organization's spend in one clock hour.
+/// Cost is in nano-dollars (scale 9).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HourlyOrganizationSpend {
+    pub organization_id: Uuid,
+    /// Start of the hour.
+    pub hour: DateTime<Utc>,
+    pub total_cost: i64,
+}
+
+/// Fleet-wide lock for background work that must run on one instance only.
+#[async_trait::async_trait]
+pub trait SingleInstanceLock: Send + Sync {
+    /// Take the lock without waiting. Returns `None` while another instance
+    /// holds it; the lock is released when the returned guard is dropped.
+    async fn try_acquire(&self) -> anyhow::Result<Option<Box<dyn SingleInstanceGuard>>>;
+}
+
+/// Held [`SingleInstanceLock`].
+#[async_trait::async_trait]
+pub trait SingleInstanceGuard: Send + Sync {
+    /// False once the lock has been lost, e.g. because its database session
+    /// ended or can no longer confirm that it still holds the lock.
+    async fn is_held(&self) -> bool;
+}
+
 /// Usage log entry
 /// All costs use fixed scale of 9 (nano-dollars) and USD currency
 #[derive(Debug, Clone)]
@@ -47,6 +47,9 @@ impl WorkspaceServiceImpl {
             RepositoryError::DependencyExists(msg) => {
                 WorkspaceError::InvalidParams(format!("Cannot delete due to dependencies: {msg}"))
             }
+            RepositoryError::SeatLimitExceeded => {
+                WorkspaceError::InvalidParams("Organization has reached its seat limit".to_string())
+            }
             RepositoryError::TransactionConflict => {
                 WorkspaceError::InternalError("Transaction conflict, please retry".to_string())
             }
@@ -104,6 +107,31 @@ impl WorkspaceServiceImpl {
 
         Ok((workspace, organization))
     }
+
+    /// Require the user to be an owner or admin of the organization
+    async fn check_can_manage_organization(
+        &self,
+        organization_id: OrganizationId,
+        user_id: UserId,
+    ) -> Result<(), WorkspaceError> {
+        let role = self
+            .organization_service
+            .get_user_role(organization_id, user_id)
+            .await
+            .map_err(|e| {
+                WorkspaceError::InternalError(format!(
+                    "Failed to check organization membership: {e}"
+                ))
+            })?;
+
+        if !role.is_some_and(|role| role.can_manage_organization()) {
+            return Err(WorkspaceError::Unauthorized(
+                "User must be an owner or admin of the organization".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -362,6 +390,66 @@ impl WorkspaceServiceTrait for WorkspaceServiceImpl {
             })
     }
 
+    async fn update_workspace_monthly_budget(
+        &self,
+        workspace_id: WorkspaceId,
+        requester_id: UserId,
+        monthly_budget: Option<i64>,
+    ) -> Result<Workspace, WorkspaceError> {
+        // Check permissions
+        self.check_workspace_permission(workspace_id.clone(), requester_id)
+            .await?;
+
+        self.workspace_repository
+            .update_monthly_budget(workspace_id, monthly_budget)
+            .await
+            .map_err(Self::map_repository_error)?
+            .ok_or(WorkspaceError::NotFound)
+    }
+
+    async fn transfer_workspace(
+        &self,
+        workspace_id: WorkspaceId,
+        requester_id: UserId,
+        target_organization_id: OrganizationId,
+    ) -> Result<Workspace, WorkspaceError> {
+        let (workspace, source_organization) = self
+            .workspace_repository
+            .get_workspace_with_organization(workspace_id.clone())
+            .await
+            .map_err(Self::map_repository_error)?
+            .ok_or(WorkspaceError::NotFound)?;
+
+        self.check_can_manage_organization(source_organization.id.clone(), requester_id.clone())
+            .await?;
+        self.check_can_manage_organization(target_organization_id.clone(), requester_id)
+            .await?;
+
+        if source_organization.id.0 == target_organization_id.0 {
+            return Err(WorkspaceError::InvalidParams(
+                "Workspace already belongs to the target organization".to_string(),
+            ));
+        }
+
+        if self
+            .workspace_repository
+            .get_by_name(target_organization_id.0, &workspace.name)
+            .await
+            .map_err(Self::map_repository_error)?
+            .is_some()
+        {
+            return Err(WorkspaceError::AlreadyExists);
+        }
+
+        // Usage already logged keeps the source organization; only requests
+        // made after the move are billed to the target.
+        self.workspace_repository
+            .transfer(workspace_id, source_organization.id, target_organization_id)
+            .await
+            .map_err(Self::map_repository_error)?
+            .ok_or(WorkspaceError::NotFound)
+    }
+
     async fn update_api_key(
         &self,
         workspace_id: WorkspaceId,
@@ -432,6 +520,7 @@ impl WorkspaceServiceTrait for WorkspaceServiceImpl {
         name: Option<String>,
         description: Option<String>,
         settings: Option<serde_json::Value>,
+        default_params: Option<WorkspaceDefaultParams>,
     ) -> Result<Workspace, WorkspaceError> {
         // Check permissions
         self.check_workspace_permission(workspace_id.clone(), requester_id)
@@ -460,7 +549,7 @@ impl WorkspaceServiceTrait for WorkspaceServiceImpl {
 
         // Update the workspace
         self.workspace_repository
-            .update(workspace_id, name, description, settings)
+            .update(workspace_id, name, description, settings, default_params)
             .await
             .map_err(Self::map_repository_error)?
             .ok_or(WorkspaceError::NotFound)
@@ -587,4 +676,21 @@ impl WorkspaceServiceTrait for WorkspaceServiceImpl {
             .await
             .map_err(|e| WorkspaceError::InternalError(format!("Failed to revoke API key: {e}")))
     }
+
+    async fn revoke_all_api_keys(
+        &self,
+        workspace_id: WorkspaceId,
+        requester_id: UserId,
+    ) -> Result<u64, WorkspaceError> {
+        let (_, organization) = self
+            .check_workspace_permission(workspace_id.clone(), requester_id.clone())
+            .await?;
+        self.check_can_manage_organization(organization.id, requester_id)
+            .await?;
+
+        self.api_key_repository
+            .revoke_all_by_workspace(workspace_id)
+            .await
+            .map_err(|e| WorkspaceError::InternalError(format!("Failed to revoke API keys: {e}")))
+    }
 }
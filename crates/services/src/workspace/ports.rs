@@ -38,6 +38,23 @@ pub struct Workspace {
     pub updated_at: DateTime<Utc>,
     pub is_active: bool,
     pub settings: Option<serde_json::Value>,
+    /// Completion defaults applied when a client omits the field.
+    pub default_params: Option<WorkspaceDefaultParams>,
+    /// Monthly spend budget in nano-dollars, enforced on top of the
+    /// organization and per-key limits. `None` means unlimited.
+    pub monthly_budget: Option<i64>,
+}
+
+/// Per-workspace completion defaults. Each field is only applied when the
+/// client request leaves it unset; client-supplied values always win.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceDefaultParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -170,6 +187,23 @@ pub trait WorkspaceRepository: Send + Sync {
         name: Option<String>,
         description: Option<String>,
         settings: Option<serde_json::Value>,
+        default_params: Option<WorkspaceDefaultParams>,
+    ) -> Result<Option<Workspace>, RepositoryError>;
+
+    /// Set or clear (`None`) a workspace's monthly budget
+    async fn update_monthly_budget(
+        &self,
+        workspace_id: WorkspaceId,
+        monthly_budget: Option<i64>,
+    ) -> Result<Option<Workspace>, RepositoryError>;
+
+    /// Atomically move a workspace from one organization to another. Returns
+    /// `None` if the workspace is not active in `from_organization_id`.
+    async fn transfer(
+        &self,
+        workspace_id: WorkspaceId,
+        from_organization_id: OrganizationId,
+        to_organization_id: OrganizationId,
     ) -> Result<Option<Workspace>, RepositoryError>;
 
     /// Delete (deactivate) a workspace
@@ -239,6 +273,13 @@ pub trait ApiKeyRepository: Send + Sync {
     /// Revoke (soft delete) an API key
     async fn revoke(&self, id: ApiKeyId) -> Result<bool, RepositoryError>;
 
+    /// Revoke every not-yet-revoked API key in a workspace in one statement,
+    /// returning how many were revoked
+    async fn revoke_all_by_workspace(
+        &self,
+        workspace_id: WorkspaceId,
+    ) -> Result<u64, RepositoryError>;
+
     /// Get all active key hashes (for Bloom Filter initialization)
     async fn get_all_active_key_hashes(&self) -> Result<Vec<String>, RepositoryError>;
 
@@ -324,6 +365,23 @@ pub trait WorkspaceServiceTrait: Send + Sync {
         requester_id: UserId,
     ) -> Result<bool, WorkspaceError>;
 
+    /// Set or clear a workspace's monthly budget with permission checking
+    async fn update_workspace_monthly_budget(
+        &self,
+        workspace_id: WorkspaceId,
+        requester_id: UserId,
+        monthly_budget: Option<i64>,
+    ) -> Result<Workspace, WorkspaceError>;
+
+    /// Move a workspace, with its API keys, to another organization. The
+    /// requester must be an owner or admin of both organizations.
+    async fn transfer_workspace(
+        &self,
+        workspace_id: WorkspaceId,
+        requester_id: UserId,
+        target_organization_id: OrganizationId,
+    ) -> Result<Workspace, WorkspaceError>;
+
     /// Update API key spend limit with permission checking
     async fn update_api_key_spend_limit(
         &self,
@@ -360,6 +418,7 @@ pub trait WorkspaceServiceTrait: Send + Sync {
         name: Option<String>,
         description: Option<String>,
         settings: Option<serde_json::Value>,
+        default_params: Option<WorkspaceDefaultParams>,
     ) -> Result<Workspace, WorkspaceError>;
 
     /// Delete (deactivate) a workspace with permission checking
@@ -408,4 +467,12 @@ pub trait WorkspaceServiceTrait: Send + Sync {
         api_key_id: ApiKeyId,
         requester_id: UserId,
     ) -> Result<bool, WorkspaceError>;
+
+    /// Revoke all API keys in a workspace, returning how many were revoked.
+    /// The requester must be an owner or admin of the workspace's organization.
+    async fn revoke_all_api_keys(
+        &self,
+        workspace_id: WorkspaceId,
+        requester_id: UserId,
+    ) -> Result<u64, WorkspaceError>;
 }